@@ -0,0 +1,310 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Reverse mode differentiation of large computations within a memory
+//! budget.
+//!
+//! Recording a whole Monte Carlo simulation on one graph needs memory for
+//! every operation on every path. Instead:
+//!
+//! - [`pathwise_gradient`] records one path at a time on top of the
+//!   parameters, accumulates its adjoints, and frees the path's vertices
+//!   with [`Graph::truncate`] before the next path, reusing the memory. The
+//!   tape only ever holds one path.
+//! - [`checkpointed_gradient`] splits a sequential computation (e.g. the
+//!   time steps of a path) into segments mapping a state to the next. The
+//!   forward pass only keeps the state at the start of each segment (the
+//!   checkpoints). The backward pass records each segment again from its
+//!   checkpoint, in reverse order, and propagates the adjoints of its output
+//!   state back to its input state. The tape only ever holds one segment,
+//!   at the cost of evaluating each segment twice.
+
+use crate::autodiff::{variables::variable::Variable, Accumulate, Gradient, Graph};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Value and gradient of a computation differentiated piece by piece.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckpointedGradient {
+    /// Value of the computation.
+    pub value: f64,
+
+    /// Derivatives with respect to the parameters.
+    pub parameters: Vec<f64>,
+
+    /// Derivatives with respect to the initial state (empty for
+    /// [`pathwise_gradient`]).
+    pub initial_state: Vec<f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Mean of `payoff(parameters, path)` over `paths` independent paths, and
+/// its gradient with respect to the parameters, with one path on the tape at
+/// a time.
+#[must_use]
+pub fn pathwise_gradient<F>(parameters: &[f64], paths: usize, payoff: F) -> CheckpointedGradient
+where
+    F: for<'v> Fn(&[Variable<'v>], usize) -> Variable<'v>,
+{
+    let graph = Graph::new();
+    let theta = graph.vars(parameters);
+    let mark = graph.len();
+
+    let mut value = 0.0;
+    let mut gradient = vec![0.0; parameters.len()];
+
+    for path in 0..paths {
+        let y = payoff(&theta, path);
+        let adjoints = y.accumulate();
+
+        value += y.value;
+        for (g, a) in gradient.iter_mut().zip(adjoints.wrt(&theta)) {
+            *g += a;
+        }
+
+        graph.truncate(mark);
+    }
+
+    let n = paths.max(1) as f64;
+
+    CheckpointedGradient {
+        value: value / n,
+        parameters: gradient.iter().map(|g| g / n).collect(),
+        initial_state: Vec::new(),
+    }
+}
+
+/// Value and gradient of `output(parameters, x_n)`, where the state
+/// $x_{k+1} = step(parameters, x_k, k)$ starts from `initial_state`, with
+/// one of the `segments` steps on the tape at a time.
+///
+/// # Panics
+///
+/// Panics if a step changes the size of the state.
+#[must_use]
+pub fn checkpointed_gradient<S, O>(
+    parameters: &[f64],
+    initial_state: &[f64],
+    segments: usize,
+    step: S,
+    output: O,
+) -> CheckpointedGradient
+where
+    S: for<'v> Fn(&[Variable<'v>], &[Variable<'v>], usize) -> Vec<Variable<'v>>,
+    O: for<'v> Fn(&[Variable<'v>], &[Variable<'v>]) -> Variable<'v>,
+{
+    let graph = Graph::new();
+
+    // Forward pass, keeping the state at the start of each segment.
+    let mut checkpoints = Vec::with_capacity(segments);
+    let mut state = initial_state.to_vec();
+
+    for k in 0..segments {
+        let next: Vec<f64> = step(&graph.vars(parameters), &graph.vars(&state), k)
+            .iter()
+            .map(|x| x.value)
+            .collect();
+        assert_eq!(
+            next.len(),
+            state.len(),
+            "a step changed the size of the state"
+        );
+
+        checkpoints.push(std::mem::replace(&mut state, next));
+        graph.clear();
+    }
+
+    let theta = graph.vars(parameters);
+    let x = graph.vars(&state);
+    let y = output(&theta, &x);
+    let adjoints = y.accumulate();
+
+    let value = y.value;
+    let mut parameters_adjoint = adjoints.wrt(&theta);
+    let mut state_adjoint = adjoints.wrt(&x);
+    graph.clear();
+
+    // Backward pass, recording each segment again from its checkpoint.
+    for (k, checkpoint) in checkpoints.iter().enumerate().rev() {
+        let theta = graph.vars(parameters);
+        let x = graph.vars(checkpoint);
+        let next = step(&theta, &x, k);
+
+        let adjoints = weighted_sum(&graph, &next, &state_adjoint).accumulate();
+
+        for (a, b) in parameters_adjoint.iter_mut().zip(adjoints.wrt(&theta)) {
+            *a += b;
+        }
+        state_adjoint = adjoints.wrt(&x);
+        graph.clear();
+    }
+
+    CheckpointedGradient {
+        value,
+        parameters: parameters_adjoint,
+        initial_state: state_adjoint,
+    }
+}
+
+/// The sum of `variables` weighted by their adjoints, whose gradient is the
+/// vector-Jacobian product.
+fn weighted_sum<'v>(graph: &'v Graph, variables: &[Variable<'v>], weights: &[f64]) -> Variable<'v> {
+    let parents: Vec<usize> = variables.iter().map(|x| x.index).collect();
+
+    Variable {
+        graph,
+        index: graph.push_nary(&parents, weights),
+        value: variables
+            .iter()
+            .zip(weights)
+            .map(|(x, w)| x.value * w)
+            .sum(),
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_checkpoint {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::autodiff::Max;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rand_distr::StandardNormal;
+
+    fn normals(paths: usize, steps: usize) -> Vec<Vec<f64>> {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        (0..paths)
+            .map(|_| (0..steps).map(|_| rng.sample(StandardNormal)).collect())
+            .collect()
+    }
+
+    // Euler steps of geometric Brownian motion with parameters (r, sigma).
+    fn euler<'v>(theta: &[Variable<'v>], spot: Variable<'v>, z: &[f64], dt: f64) -> Variable<'v> {
+        z.iter().fold(spot, |s, z| {
+            s + s * theta[0] * dt + s * theta[1] * (dt.sqrt() * z)
+        })
+    }
+
+    #[test]
+    fn test_truncate_frees_vertices() {
+        let graph = Graph::new();
+        let x = graph.var(2.0);
+        let mark = graph.len();
+
+        let y = x.exp() * x;
+        assert!(graph.len() > mark);
+        assert!(!graph.second_order.borrow().is_empty());
+
+        graph.truncate(mark);
+        assert_eq!(graph.len(), mark);
+        assert!(graph.second_order.borrow().is_empty());
+
+        // The graph is still usable, and `y` must no longer be used.
+        let z = x * x;
+        assert_approx_equal!(z.accumulate().wrt(&x), 4.0, 1e-15);
+        assert_eq!(graph.len(), mark + 1);
+        assert!(y.index > z.index);
+    }
+
+    // Discounted call payoff of one path, with parameters (r, sigma, spot).
+    fn call<'v>(theta: &[Variable<'v>], z: &[f64]) -> Variable<'v> {
+        let s_t = euler(theta, theta[2], z, 1.0 / z.len() as f64);
+
+        Max::max(&(s_t - 100.0), 0.0) * (theta[0] * -1.0).exp()
+    }
+
+    // Ten Euler steps of each path in the state, with parameters (r, sigma).
+    fn step<'v>(
+        theta: &[Variable<'v>],
+        state: &[Variable<'v>],
+        z: &[Vec<f64>],
+        k: usize,
+    ) -> Vec<Variable<'v>> {
+        state
+            .iter()
+            .zip(z)
+            .map(|(s, z)| euler(theta, *s, &z[10 * k..10 * (k + 1)], 1.0 / 60.0))
+            .collect()
+    }
+
+    // Mean of the discounted call payoffs of the paths in the state.
+    fn price<'v>(theta: &[Variable<'v>], state: &[Variable<'v>]) -> Variable<'v> {
+        state
+            .iter()
+            .map(|s| Max::max(&(*s - 100.0), 0.0))
+            .sum::<Variable>()
+            * ((theta[0] * -1.0).exp() / state.len() as f64)
+    }
+
+    #[test]
+    fn test_pathwise_gradient_matches_one_tape() {
+        let z = normals(200, 12);
+        let parameters = [0.03, 0.2, 100.0];
+
+        let pathwise = pathwise_gradient(&parameters, z.len(), |theta, path| call(theta, &z[path]));
+
+        // The whole simulation on one tape.
+        let graph = Graph::new();
+        let theta = graph.vars(&parameters);
+        let price = z.iter().map(|z| call(&theta, z)).sum::<Variable>() / z.len() as f64;
+        let gradient = price.accumulate().wrt(&theta);
+
+        assert_approx_equal!(pathwise.value, price.value, 1e-10);
+        for (a, b) in pathwise.parameters.iter().zip(&gradient) {
+            assert_approx_equal!(*a, *b, 1e-10);
+        }
+
+        // Delta of an at-the-money call is about one half.
+        assert!((0.4..0.7).contains(&pathwise.parameters[2]));
+    }
+
+    #[test]
+    fn test_checkpointed_gradient_matches_one_tape() {
+        // 1,000 paths in the state, 60 steps in 6 segments of 10 steps.
+        let z = normals(1_000, 60);
+        let parameters = [0.03, 0.2];
+        let spots = vec![100.0; z.len()];
+
+        let checkpointed = checkpointed_gradient(
+            &parameters,
+            &spots,
+            6,
+            |theta, state, k| step(theta, state, &z, k),
+            price,
+        );
+
+        // The whole simulation on one tape.
+        let graph = Graph::new();
+        let theta = graph.vars(&parameters);
+        let initial = graph.vars(&spots);
+        let state = (0..6).fold(initial.clone(), |state, k| step(&theta, &state, &z, k));
+        let price = price(&theta, &state);
+        let adjoints = price.accumulate();
+
+        assert_approx_equal!(checkpointed.value, price.value, 1e-10);
+        for (a, b) in checkpointed.parameters.iter().zip(adjoints.wrt(&theta)) {
+            assert_approx_equal!(*a, b, 1e-10);
+        }
+
+        // Delta is the sum of the derivatives with respect to the spots of
+        // all the paths.
+        let delta: f64 = checkpointed.initial_state.iter().sum();
+        let expected: f64 = adjoints.wrt(&initial).iter().sum();
+        assert_approx_equal!(delta, expected, 1e-10);
+    }
+}
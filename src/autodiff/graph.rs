@@ -29,6 +29,12 @@ use std::cell::RefCell;
 pub struct Graph {
     /// Vector containing the vertices in the Wengert List.
     pub vertices: RefCell<Vec<Vertex>>,
+
+    /// Second order partials of the vertices of nonlinear operations, as
+    /// `(vertex, a, b, d2 vertex / da db)` with `a >= b`, in increasing order
+    /// of the vertex. Entries for the same pair add up, and vertices without
+    /// an entry are linear in their parents.
+    pub second_order: RefCell<Vec<(usize, usize, usize, f64)>>,
 }
 // pub struct Graph(RefCell<Rc<[Vertex]>>);
 
@@ -47,6 +53,7 @@ impl Graph {
     pub const fn new() -> Self {
         Self {
             vertices: RefCell::new(Vec::new()),
+            second_order: RefCell::new(Vec::new()),
             // vertices: RefCell::new(Rc::new([])),
         }
     }
@@ -57,6 +64,7 @@ impl Graph {
     pub fn with_capacity(capacity: usize) -> Self {
         Graph {
             vertices: RefCell::new(Vec::with_capacity(capacity)),
+            second_order: RefCell::new(Vec::new()),
             // vertices: RefCell::new(Rc::new([])),
         }
     }
@@ -66,8 +74,15 @@ impl Graph {
     #[inline]
     pub fn join(&self, other: &Self) -> Self {
         let graph = self.clone();
+        let offset = self.len();
+        let second_order = other.second_order.borrow().clone();
         let other = other.vertices.borrow_mut().clone();
         graph.vertices.borrow_mut().extend(other);
+        graph.second_order.borrow_mut().extend(
+            second_order
+                .into_iter()
+                .map(|(index, a, b, partial)| (index + offset, a, b, partial)),
+        );
         graph
     }
 
@@ -105,6 +120,23 @@ impl Graph {
     #[inline]
     pub fn clear(&self) {
         self.vertices.borrow_mut().clear();
+        self.second_order.borrow_mut().clear();
+    }
+
+    /// Frees the vertices from `len` onwards, keeping the memory allocated
+    /// for reuse. Variables pushed after the first `len` vertices must not
+    /// be used afterwards.
+    #[inline]
+    pub fn truncate(&self, len: usize) {
+        self.vertices.borrow_mut().truncate(len);
+
+        let mut second_order = self.second_order.borrow_mut();
+        while second_order
+            .last()
+            .is_some_and(|(vertex, ..)| *vertex >= len)
+        {
+            second_order.pop();
+        }
     }
 
     /// Zeroes the adjoints in the graph.
@@ -114,6 +146,7 @@ impl Graph {
             .borrow_mut()
             .iter_mut()
             .for_each(|vertex| vertex.partials = [0.0; 2]);
+        self.second_order.borrow_mut().clear();
     }
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -184,6 +217,89 @@ impl Graph {
 
         len
     }
+
+    /// Pushes a vertex of a nonlinear operation to the graph, with the second
+    /// order partials `[d2/dx2, d2/dxdy, d2/dy2]` with respect to its
+    /// parents, which are needed for Hessians.
+    #[inline]
+    pub fn push_second_order(
+        &self,
+        arity: Arity,
+        parents: &[usize],
+        partials: &[f64],
+        second_partials: [f64; 3],
+    ) -> usize {
+        let index = self.push(arity, parents, partials);
+        let vertex = self.vertices.borrow()[index];
+        let [x, y] = vertex.parents;
+        let [xx, xy, yy] = second_partials;
+
+        // Both parents may be the same vertex, e.g. for `x * x`.
+        if x == y {
+            self.record_second_order(index, &[(x, x, xx + 2.0 * xy + yy)]);
+        } else {
+            self.record_second_order(index, &[(x, x, xx), (x, y, xy), (y, y, yy)]);
+        }
+
+        index
+    }
+
+    /// Pushes a vertex with any number of parents to the graph, as for
+    /// [`Graph::push_nary`], with its second order partials given as
+    /// `(a, b, d2 vertex / da db)` for the parents `a` and `b` (one entry for
+    /// each pair with `a != b`, and entries for the same pair add up).
+    #[inline]
+    pub fn push_nary_second_order(
+        &self,
+        parents: &[usize],
+        partials: &[f64],
+        second_partials: &[(usize, usize, f64)],
+    ) -> usize {
+        let index = self.push_nary(parents, partials);
+        self.record_second_order(index, second_partials);
+
+        index
+    }
+
+    /// Records the non-zero second order partials of the vertex `index`.
+    fn record_second_order(&self, index: usize, second_partials: &[(usize, usize, f64)]) {
+        let mut second_order = self.second_order.borrow_mut();
+
+        for &(a, b, partial) in second_partials {
+            if partial != 0.0 {
+                second_order.push((index, a.max(b), a.min(b), partial));
+            }
+        }
+    }
+
+    /// Pushes a vertex with any number of parents to the graph, such as an
+    /// element of the result of a matrix operation, and returns its index.
+    ///
+    /// Vertices have at most two parents, so the partials are chained
+    /// through intermediate binary vertices whose first partial is one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parents` and `partials` have different lengths.
+    #[inline]
+    pub fn push_nary(&self, parents: &[usize], partials: &[f64]) -> usize {
+        assert_eq!(parents.len(), partials.len());
+
+        match parents.len() {
+            0 => self.push(Arity::Nullary, &[], &[]),
+            1 => self.push(Arity::Unary, parents, partials),
+            _ => {
+                let first = self.push(Arity::Binary, &parents[..2], &partials[..2]);
+
+                parents[2..].iter().zip(&partials[2..]).fold(
+                    first,
+                    |previous, (&parent, &partial)| {
+                        self.push(Arity::Binary, &[previous, parent], &[1.0, partial])
+                    },
+                )
+            }
+        }
+    }
 }
 
 // /// Nullary operator pushback.
@@ -0,0 +1,278 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Second order derivatives from the graph, with the edge pushing algorithm
+//! (Gower and Mello, 2012).
+//!
+//! Nonlinear operations record their second order partials on the graph
+//! alongside the first order ones. One reverse sweep then accumulates the
+//! adjoints together with the Hessian: each vertex, in reverse order, pushes
+//! the second order derivatives with respect to itself onto its parents
+//! through its partials, and adds its own second order partials weighted by
+//! its adjoint. This gives the whole Hessian of one output, e.g. gamma, vanna
+//! and volga of an option price, for about the cost of the function.
+
+use crate::autodiff::variables::variable::Variable;
+use std::collections::HashMap;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Gradient and Hessian of a `Variable` with respect to the variables of its
+/// graph.
+#[derive(Debug, Clone)]
+pub struct Hessian {
+    /// Adjoints of the vertices, as returned by `accumulate()`.
+    pub gradient: Vec<f64>,
+
+    /// Second order derivatives with respect to the vertices `(i, j)`, with
+    /// `j <= i`, stored in row `i`.
+    rows: Vec<HashMap<usize, f64>>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Hessian {
+    /// Second order derivative with respect to `x` and `y`.
+    #[must_use]
+    pub fn wrt(&self, x: &Variable, y: &Variable) -> f64 {
+        let (i, j) = (x.index.max(y.index), x.index.min(y.index));
+
+        self.rows
+            .get(i)
+            .and_then(|row| row.get(&j))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Hessian matrix with respect to `variables`.
+    #[must_use]
+    pub fn matrix(&self, variables: &[Variable]) -> Vec<Vec<f64>> {
+        variables
+            .iter()
+            .map(|x| variables.iter().map(|y| self.wrt(x, y)).collect())
+            .collect()
+    }
+}
+
+impl<'v> Variable<'v> {
+    /// Reverse accumulate the gradient and the Hessian of the variable.
+    #[must_use]
+    pub fn accumulate_hessian(&self) -> Hessian {
+        let vertices = self.graph.vertices.borrow();
+        let second_order = self.graph.second_order.borrow();
+        let output = self.index;
+
+        let mut gradient = vec![0.0; vertices.len()];
+        gradient[output] = 1.0; // SEED
+
+        let mut rows: Vec<HashMap<usize, f64>> = vec![HashMap::new(); output + 1];
+        let mut second_partials = second_order
+            .iter()
+            .rev()
+            .skip_while(|(vertex, ..)| *vertex > output)
+            .peekable();
+
+        for i in (0..=output).rev() {
+            let adjoint = gradient[i];
+
+            // Creating: the second order partials of the vertex.
+            while let Some(&(_, a, b, partial)) = second_partials.next_if(|(v, ..)| *v == i) {
+                add(&mut rows, a, b, adjoint * partial);
+            }
+
+            // The distinct parents, without the references of unary and
+            // nullary vertices to themselves.
+            let vertex = vertices[i];
+            let mut parents: Vec<(usize, f64)> = Vec::with_capacity(2);
+            for (&parent, &partial) in vertex.parents.iter().zip(&vertex.partials) {
+                if parent == i {
+                    continue;
+                }
+                match parents.iter_mut().find(|(p, _)| *p == parent) {
+                    Some((_, c)) => *c += partial,
+                    None => parents.push((parent, partial)),
+                }
+            }
+
+            // The inputs keep their second order derivatives.
+            if parents.is_empty() {
+                continue;
+            }
+
+            // Pushing: replace the vertex by its parents.
+            for (p, w) in std::mem::take(&mut rows[i]) {
+                if p == i {
+                    for (a, &(j, cj)) in parents.iter().enumerate() {
+                        for &(k, ck) in &parents[a..] {
+                            add(&mut rows, j, k, cj * ck * w);
+                        }
+                    }
+                } else {
+                    for &(j, cj) in &parents {
+                        let symmetric = if j == p { 2.0 } else { 1.0 };
+                        add(&mut rows, j, p, symmetric * cj * w);
+                    }
+                }
+            }
+
+            for &(j, cj) in &parents {
+                gradient[j] += adjoint * cj;
+            }
+        }
+
+        Hessian { gradient, rows }
+    }
+}
+
+/// Add `value` to the second order derivative with respect to `(a, b)`.
+fn add(rows: &mut [HashMap<usize, f64>], a: usize, b: usize, value: f64) {
+    if value != 0.0 {
+        *rows[a.max(b)].entry(a.min(b)).or_insert(0.0) += value;
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_hessian {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::autodiff::{Accumulate, Gradient, Graph, Log, Powf, VariableMatrix};
+    use nalgebra::DMatrix;
+    use std::f64::consts::{PI, SQRT_2};
+
+    #[test]
+    fn test_hessian_of_scalar_function() {
+        // f(x, y) = x^2 y + sin(x) exp(y) + ln(x) / y + x^y + log_y(x)
+        let f = |x: f64, y: f64| x * x * y + x.sin() * y.exp() + x.ln() / y + x.powf(y) + x.log(y);
+
+        let graph = Graph::new();
+        let x = graph.var(1.3);
+        let y = graph.var(2.1);
+        let z = x * x * y + x.sin() * y.exp() + x.ln() / y + x.powf(y) + x.log(y);
+
+        let hessian = z.accumulate_hessian();
+        assert_approx_equal!(z.value, f(1.3, 2.1), 1e-14);
+
+        // The gradient is the one of `accumulate()`.
+        let gradient = z.accumulate();
+        assert_approx_equal!(hessian.gradient.wrt(&x), gradient.wrt(&x), 1e-14);
+        assert_approx_equal!(hessian.gradient.wrt(&y), gradient.wrt(&y), 1e-14);
+
+        // Central differences of the function.
+        let h = 1e-4;
+        let fxx = (f(1.3 + h, 2.1) - 2.0 * f(1.3, 2.1) + f(1.3 - h, 2.1)) / (h * h);
+        let fyy = (f(1.3, 2.1 + h) - 2.0 * f(1.3, 2.1) + f(1.3, 2.1 - h)) / (h * h);
+        let fxy = (f(1.3 + h, 2.1 + h) - f(1.3 + h, 2.1 - h) - f(1.3 - h, 2.1 + h)
+            + f(1.3 - h, 2.1 - h))
+            / (4.0 * h * h);
+
+        let matrix = hessian.matrix(&[x, y]);
+        assert_approx_equal!(matrix[0][0], fxx, 1e-5);
+        assert_approx_equal!(matrix[1][1], fyy, 1e-5);
+        assert_approx_equal!(matrix[0][1], fxy, 1e-5);
+        assert_approx_equal!(matrix[1][0], fxy, 1e-5);
+    }
+
+    #[test]
+    fn test_black_scholes_second_order_greeks() {
+        let (s, k, r, v, t) = (100.0, 105.0, 0.03, 0.25, 0.75);
+
+        let graph = Graph::new();
+        let spot = graph.var(s);
+        let vol = graph.var(v);
+
+        fn cdf(x: Variable) -> Variable {
+            ((x / SQRT_2).erf() + 1.0) * 0.5
+        }
+
+        let d1 = ((spot / k).ln() + (vol * vol * 0.5 + r) * t) / (vol * t.sqrt());
+        let d2 = d1 - vol * t.sqrt();
+        let call = spot * cdf(d1) - cdf(d2) * k * (-r * t).exp();
+
+        let hessian = call.accumulate_hessian();
+
+        let d1 = d1.value;
+        let d2 = d2.value;
+        let pdf = (-0.5 * d1 * d1).exp() / (2.0 * PI).sqrt();
+
+        assert_approx_equal!(hessian.wrt(&spot, &spot), pdf / (s * v * t.sqrt()), 1e-12);
+        assert_approx_equal!(hessian.wrt(&spot, &vol), -pdf * d2 / v, 1e-12);
+        assert_approx_equal!(
+            hessian.wrt(&vol, &vol),
+            s * pdf * t.sqrt() * d1 * d2 / v,
+            1e-10
+        );
+    }
+
+    #[test]
+    fn test_hessian_of_matrix_operations() {
+        let sigma = DMatrix::from_row_slice(2, 2, &[0.04, 0.006, 0.006, 0.09]);
+        let a = DMatrix::from_row_slice(2, 2, &[3.0, 1.0, 0.5, 2.0]);
+
+        // f(w, A) = w' S w + c' A^-1 w
+        let gradient = |w: &[f64], a: &DMatrix<f64>| {
+            let graph = Graph::new();
+            let weights = VariableMatrix::vector(&graph, w);
+            let matrix = VariableMatrix::new(&graph, a);
+            let covariance = VariableMatrix::new(&graph, &sigma);
+            let c = VariableMatrix::vector(&graph, &[1.0, -2.0]);
+
+            let f =
+                weights.dot(&(&covariance * &weights)) + c.dot(&matrix.solve(&weights).unwrap());
+            let hessian = f.accumulate_hessian();
+
+            let variables: Vec<Variable> = weights
+                .as_slice()
+                .iter()
+                .chain(matrix.as_slice())
+                .copied()
+                .collect();
+
+            (f.accumulate().wrt(&variables), hessian.matrix(&variables))
+        };
+
+        let w = [0.6, 0.4];
+        let (_, hessian) = gradient(&w, &a);
+
+        // Compare with central differences of the gradient, with respect to
+        // w and the elements of A (in column-major order).
+        let h = 1e-6;
+        for m in 0..6 {
+            let bump = |e: f64| {
+                let mut w = w;
+                let mut a = a.clone();
+                if m < 2 {
+                    w[m] += e;
+                } else {
+                    a[m - 2] += e;
+                }
+                gradient(&w, &a).0
+            };
+            let (up, down) = (bump(h), bump(-h));
+
+            for n in 0..6 {
+                assert_approx_equal!(hessian[n][m], (up[n] - down[n]) / (2.0 * h), 1e-7);
+            }
+        }
+
+        // The solve is linear in w, so the Hessian with respect to w is 2 S.
+        for i in 0..2 {
+            for j in 0..2 {
+                assert_approx_equal!(hessian[i][j], 2.0 * sigma[(i, j)], 1e-14);
+            }
+        }
+    }
+}
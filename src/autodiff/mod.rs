@@ -65,10 +65,18 @@
 pub mod accumulate;
 pub use accumulate::*;
 
+/// Checkpointing and tape memory management.
+pub mod checkpoint;
+pub use checkpoint::*;
+
 /// Implements the gradient computation.
 pub mod gradient;
 pub use gradient::*;
 
+/// Implements the Hessian computation.
+pub mod hessian;
+pub use hessian::*;
+
 /// The Graph (aka. tape or Wengert List).
 pub mod graph;
 pub use graph::*;
@@ -121,4 +129,4 @@ pub mod variables {
     /// Base trait for all `Variable`s.
     pub mod variable;
 }
-pub use variables::{ndarray::*, variable::*};
+pub use variables::{nalgebra::*, ndarray::*, variable::*};
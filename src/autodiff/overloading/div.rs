@@ -116,10 +116,11 @@ impl<'v> Div<Variable<'v>> for f64 {
         Variable {
             graph: other.graph,
             value: self / other.value,
-            index: other.graph.push(
+            index: other.graph.push_second_order(
                 Arity::Binary,
                 &[other.index, other.index],
                 &[0.0, -self / (other.value * other.value)],
+                [0.0, 0.0, 2.0 * self / other.value.powi(3)],
             ),
         }
     }
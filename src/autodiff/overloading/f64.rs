@@ -80,10 +80,11 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.acos(),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[((1.0 - self.value.powi(2)).sqrt()).recip().neg()],
+                [-self.value / (1.0 - self.value.powi(2)).powf(1.5), 0.0, 0.0],
             ),
         }
     }
@@ -108,10 +109,11 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.acosh(),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[((self.value - 1.0).sqrt() * (self.value + 1.0).sqrt()).recip()],
+                [-self.value / (self.value.powi(2) - 1.0).powf(1.5), 0.0, 0.0],
             ),
         }
     }
@@ -138,7 +140,7 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.asin(),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[if (self.value > -1.0) && (self.value < 1.0) {
@@ -146,6 +148,7 @@ impl<'v> Variable<'v> {
                 } else {
                     f64::NAN
                 }],
+                [self.value / (1.0 - self.value.powi(2)).powf(1.5), 0.0, 0.0],
             ),
         }
     }
@@ -171,10 +174,11 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.asinh(),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[((1.0 + self.value.powi(2)).sqrt()).recip()],
+                [-self.value / (1.0 + self.value.powi(2)).powf(1.5), 0.0, 0.0],
             ),
         }
     }
@@ -200,10 +204,15 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.atan(),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[((1.0 + self.value.powi(2)).recip())],
+                [
+                    -2.0 * self.value / (1.0 + self.value.powi(2)).powi(2),
+                    0.0,
+                    0.0,
+                ],
             ),
         }
     }
@@ -230,10 +239,15 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.atanh(),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[((1.0 - self.value.powi(2)).recip())],
+                [
+                    2.0 * self.value / (1.0 - self.value.powi(2)).powi(2),
+                    0.0,
+                    0.0,
+                ],
             ),
         }
     }
@@ -260,10 +274,11 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.cbrt(),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[((3.0 * self.value.powf(2.0 / 3.0)).recip())],
+                [-2.0 / (9.0 * self.value.cbrt().powi(5)), 0.0, 0.0],
             ),
         }
     }
@@ -291,9 +306,12 @@ impl<'v> Variable<'v> {
             graph: self.graph,
             value: self.value.cos(),
             // index: self.graph.push_unary(self.index, self.value.sin().neg()),
-            index: self
-                .graph
-                .push(Arity::Unary, &[self.index], &[self.value.sin().neg()]),
+            index: self.graph.push_second_order(
+                Arity::Unary,
+                &[self.index],
+                &[self.value.sin().neg()],
+                [self.value.cos().neg(), 0.0, 0.0],
+            ),
         }
     }
 
@@ -319,9 +337,12 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.cosh(),
-            index: self
-                .graph
-                .push(Arity::Unary, &[self.index], &[self.value.sinh()]),
+            index: self.graph.push_second_order(
+                Arity::Unary,
+                &[self.index],
+                &[self.value.sinh()],
+                [self.value.cosh(), 0.0, 0.0],
+            ),
         }
     }
 
@@ -346,9 +367,12 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.exp(),
-            index: self
-                .graph
-                .push(Arity::Unary, &[self.index], &[self.value.exp()]),
+            index: self.graph.push_second_order(
+                Arity::Unary,
+                &[self.index],
+                &[self.value.exp()],
+                [self.value.exp(), 0.0, 0.0],
+            ),
         }
     }
 
@@ -374,10 +398,11 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.exp2(),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[2_f64.powf(self.value) * 2_f64.ln()],
+                [2_f64.powf(self.value) * 2_f64.ln().powi(2), 0.0, 0.0],
             ),
         }
     }
@@ -405,9 +430,12 @@ impl<'v> Variable<'v> {
             graph: self.graph,
             value: self.value.exp_m1(),
             // index: self.graph.push_unary(self.index, self.value.exp()),
-            index: self
-                .graph
-                .push(Arity::Unary, &[self.index], &[self.value.exp()]),
+            index: self.graph.push_second_order(
+                Arity::Unary,
+                &[self.index],
+                &[self.value.exp()],
+                [self.value.exp(), 0.0, 0.0],
+            ),
         }
     }
 
@@ -432,9 +460,12 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.ln(),
-            index: self
-                .graph
-                .push(Arity::Unary, &[self.index], &[self.value.recip()]),
+            index: self.graph.push_second_order(
+                Arity::Unary,
+                &[self.index],
+                &[self.value.recip()],
+                [self.value.powi(2).recip().neg(), 0.0, 0.0],
+            ),
         }
     }
 
@@ -460,14 +491,17 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.ln_1p(),
-            index: self
-                .graph
-                .push(Arity::Unary, &[self.index], &[(1.0 + self.value).recip()]),
+            index: self.graph.push_second_order(
+                Arity::Unary,
+                &[self.index],
+                &[(1.0 + self.value).recip()],
+                [(1.0 + self.value).powi(2).recip().neg(), 0.0, 0.0],
+            ),
         }
     }
 
     /// Logarithm (base 10).
-    /// d/dx log_10(x) = 1 / x
+    /// d/dx log_10(x) = 1 / (x * ln(10))
     ///
     /// ```
     /// use RustQuant::assert_approx_equal;
@@ -480,7 +514,7 @@ impl<'v> Variable<'v> {
     /// let grad = z.accumulate();
     ///
     /// assert_approx_equal!(z.value,      0.00000000000, 1e-10);
-    /// assert_approx_equal!(grad.wrt(&x), 1.00000000000, 1e-10);
+    /// assert_approx_equal!(grad.wrt(&x), 0.43429448190, 1e-10);
     /// ```
     #[must_use]
     #[inline]
@@ -488,14 +522,21 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.log10(),
-            index: self
-                .graph
-                .push(Arity::Unary, &[self.index], &[self.value.recip()]),
+            index: self.graph.push_second_order(
+                Arity::Unary,
+                &[self.index],
+                &[(self.value * std::f64::consts::LN_10).recip()],
+                [
+                    (self.value.powi(2) * std::f64::consts::LN_10).recip().neg(),
+                    0.0,
+                    0.0,
+                ],
+            ),
         }
     }
 
     /// Logarithm (base 2).
-    /// d/dx log_2(x) = 1 / x
+    /// d/dx log_2(x) = 1 / (x * ln(2))
     ///
     /// ```
     /// use RustQuant::assert_approx_equal;
@@ -508,7 +549,7 @@ impl<'v> Variable<'v> {
     /// let grad = z.accumulate();
     ///
     /// assert_approx_equal!(z.value,      0.00000000000, 1e-10);
-    /// assert_approx_equal!(grad.wrt(&x), 1.00000000000, 1e-10);
+    /// assert_approx_equal!(grad.wrt(&x), 1.44269504089, 1e-10);
     /// ```
     #[must_use]
     #[inline]
@@ -516,9 +557,16 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.log2(),
-            index: self
-                .graph
-                .push(Arity::Unary, &[self.index], &[self.value.recip()]),
+            index: self.graph.push_second_order(
+                Arity::Unary,
+                &[self.index],
+                &[(self.value * std::f64::consts::LN_2).recip()],
+                [
+                    (self.value.powi(2) * std::f64::consts::LN_2).recip().neg(),
+                    0.0,
+                    0.0,
+                ],
+            ),
         }
     }
 
@@ -555,10 +603,11 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.recip(),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[self.value.powi(2).recip().neg()],
+                [2.0 * self.value.powi(3).recip(), 0.0, 0.0],
             ),
         }
     }
@@ -585,9 +634,12 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.sin(),
-            index: self
-                .graph
-                .push(Arity::Unary, &[self.index], &[self.value.cos()]),
+            index: self.graph.push_second_order(
+                Arity::Unary,
+                &[self.index],
+                &[self.value.cos()],
+                [self.value.sin().neg(), 0.0, 0.0],
+            ),
         }
     }
 
@@ -613,9 +665,12 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.sinh(),
-            index: self
-                .graph
-                .push(Arity::Unary, &[self.index], &[self.value.cosh()]),
+            index: self.graph.push_second_order(
+                Arity::Unary,
+                &[self.index],
+                &[self.value.cosh()],
+                [self.value.sinh(), 0.0, 0.0],
+            ),
         }
     }
 
@@ -640,10 +695,11 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.sqrt(),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[(2.0 * self.value.sqrt()).recip()],
+                [(4.0 * self.value.powf(1.5)).recip().neg(), 0.0, 0.0],
             ),
         }
     }
@@ -670,10 +726,11 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.tan(),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[(self.value.cos().powi(2)).recip()],
+                [2.0 * self.value.tan() / self.value.cos().powi(2), 0.0, 0.0],
             ),
         }
     }
@@ -700,10 +757,15 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value.tanh(),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[(self.value.cosh().powi(2)).recip()],
+                [
+                    -2.0 * self.value.tanh() / self.value.cosh().powi(2),
+                    0.0,
+                    0.0,
+                ],
             ),
         }
     }
@@ -760,14 +822,18 @@ mod test_overloading_f64 {
 
         // GRADIENTS
         assert_approx_equal!((-x).accumulate().wrt(&x), -1.0, EPS);
-        assert_approx_equal!(x.log2().accumulate().wrt(&x), 1.0, EPS);
+        assert_approx_equal!(x.log2().accumulate().wrt(&x), std::f64::consts::LOG2_E, EPS);
         assert_approx_equal!(x.exp2().accumulate().wrt(&x), 1.386_294_361_119_890_6, EPS);
         assert_approx_equal!(x.exp_m1().accumulate().wrt(&x), std::f64::consts::E, EPS);
         assert_approx_equal!(x.ln().accumulate().wrt(&x), 1.0, EPS);
         assert_approx_equal!(x.ln().accumulate().wrt(&x), 1.0, EPS);
         assert_approx_equal!(x.ln_1p().accumulate().wrt(&x), 0.5, EPS);
-        assert_approx_equal!(x.log10().accumulate().wrt(&x), 1.0, EPS);
-        assert_approx_equal!(x.log2().accumulate().wrt(&x), 1.0, EPS);
+        assert_approx_equal!(
+            x.log10().accumulate().wrt(&x),
+            std::f64::consts::LOG10_E,
+            EPS
+        );
+        assert_approx_equal!(x.log2().accumulate().wrt(&x), std::f64::consts::LOG2_E, EPS);
         assert_approx_equal!(x.recip().accumulate().wrt(&x), -1.0, EPS);
         assert_approx_equal!(x.sqrt().accumulate().wrt(&x), 0.5, EPS);
         assert_approx_equal!(x.cbrt().accumulate().wrt(&x), 0.333_333_333_333_333_3, EPS);
@@ -785,4 +851,23 @@ mod test_overloading_f64 {
         assert!(x.asin().accumulate().wrt(&x).is_nan());
         assert!(x.acos().accumulate().wrt(&x).is_nan());
     }
+
+    #[test]
+    fn test_logarithm_gradients() {
+        let g = Graph::new();
+
+        let x = g.var(2.5);
+
+        // d/dx log_b(x) = 1 / (x * ln(b))
+        assert_approx_equal!(
+            x.log2().accumulate().wrt(&x),
+            0.4 * std::f64::consts::LOG2_E,
+            EPS
+        );
+        assert_approx_equal!(
+            x.log10().accumulate().wrt(&x),
+            0.4 * std::f64::consts::LOG10_E,
+            EPS
+        );
+    }
 }
@@ -26,6 +26,7 @@ pub trait Log<T> {
 // f(x,y) = log_{x}(y)
 // df/dx = -ln(y) / (x * ln^2(x))
 // df/dy = 1 / (y * ln(x))
+// (self is y and base is x)
 impl<'v> Log<Variable<'v>> for Variable<'v> {
     type Output = Variable<'v>;
 
@@ -34,12 +35,18 @@ impl<'v> Log<Variable<'v>> for Variable<'v> {
         Self::Output {
             graph: self.graph,
             value: f64::log(self.value, base.value),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Binary,
                 &[self.index, base.index],
                 &[
-                    -f64::ln(self.value) / (base.value * f64::ln(base.value).powi(2)),
                     1.0 / (self.value * f64::ln(base.value)),
+                    -f64::ln(self.value) / (base.value * f64::ln(base.value).powi(2)),
+                ],
+                [
+                    -1.0 / (self.value.powi(2) * f64::ln(base.value)),
+                    -1.0 / (self.value * base.value * f64::ln(base.value).powi(2)),
+                    f64::ln(self.value) * (f64::ln(base.value) + 2.0)
+                        / (base.value.powi(2) * f64::ln(base.value).powi(3)),
                 ],
             ),
         }
@@ -58,13 +65,19 @@ impl<'v> Log<Variable<'v>> for f64 {
         Self::Output {
             graph: base.graph,
             value: f64::log(*self, base.value),
-            index: base.graph.push(
+            index: base.graph.push_second_order(
                 Arity::Binary,
                 &[base.index, base.index],
                 &[
                     -f64::ln(*self) / (base.value * f64::ln(base.value).powi(2)),
                     0.0,
                 ],
+                [
+                    f64::ln(*self) * (f64::ln(base.value) + 2.0)
+                        / (base.value.powi(2) * f64::ln(base.value).powi(3)),
+                    0.0,
+                    0.0,
+                ],
             ),
         }
     }
@@ -82,11 +95,37 @@ impl<'v> Log<f64> for Variable<'v> {
         Self::Output {
             graph: self.graph,
             value: f64::log(self.value, base),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Binary,
                 &[self.index, self.index],
                 &[0.0, 1.0 / (f64::ln(base) * self.value)],
+                [0.0, 0.0, -1.0 / (f64::ln(base) * self.value.powi(2))],
             ),
         }
     }
 }
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_log {
+    use crate::assert_approx_equal;
+    use crate::autodiff::*;
+
+    #[test]
+    fn test_log_gradients() {
+        let g = Graph::new();
+
+        let x = g.var(8.0);
+        let b = g.var(2.0);
+        let z = x.log(b);
+
+        let grad = z.accumulate();
+
+        assert_approx_equal!(z.value, 3.0, 1e-12);
+        assert_approx_equal!(grad.wrt(&x), 1.0 / (8.0 * 2_f64.ln()), 1e-12);
+        assert_approx_equal!(grad.wrt(&b), -3.0 / (2.0 * 2_f64.ln()), 1e-12);
+    }
+}
@@ -67,10 +67,11 @@ impl<'v> Mul<Variable<'v>> for Variable<'v> {
         Variable {
             graph: self.graph,
             value: self.value * other.value,
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Binary,
                 &[self.index, other.index],
                 &[other.value, self.value],
+                [0.0, 1.0, 0.0],
             ),
         }
     }
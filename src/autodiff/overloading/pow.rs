@@ -32,13 +32,19 @@ impl<'v> Powf<Variable<'v>> for Variable<'v> {
         Self::Output {
             graph: self.graph,
             value: self.value.powf(other.value),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Binary,
                 &[self.index, other.index],
                 &[
                     other.value * f64::powf(self.value, other.value - 1.),
                     f64::powf(self.value, other.value) * f64::ln(self.value),
                 ],
+                [
+                    other.value * (other.value - 1.0) * f64::powf(self.value, other.value - 2.0),
+                    f64::powf(self.value, other.value - 1.0)
+                        * (1.0 + other.value * f64::ln(self.value)),
+                    f64::powf(self.value, other.value) * f64::ln(self.value).powi(2),
+                ],
             ),
         }
     }
@@ -53,10 +59,11 @@ impl<'v> Powf<f64> for Variable<'v> {
         Self::Output {
             graph: self.graph,
             value: f64::powf(self.value, n),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Binary,
                 &[self.index, self.index],
                 &[n * f64::powf(self.value, n - 1.0), 0.0],
+                [n * (n - 1.0) * f64::powf(self.value, n - 2.0), 0.0, 0.0],
             ),
         }
     }
@@ -71,10 +78,15 @@ impl<'v> Powf<Variable<'v>> for f64 {
         Self::Output {
             graph: other.graph,
             value: f64::powf(*self, other.value),
-            index: other.graph.push(
+            index: other.graph.push_second_order(
                 Arity::Binary,
                 &[other.index, other.index],
-                &[0.0, other.value * f64::powf(*self, other.value - 1.0)],
+                &[0.0, f64::powf(*self, other.value) * f64::ln(*self)],
+                [
+                    0.0,
+                    0.0,
+                    f64::powf(*self, other.value) * f64::ln(*self).powi(2),
+                ],
             ),
         }
     }
@@ -100,13 +112,19 @@ impl<'v> Powi<Variable<'v>> for Variable<'v> {
         Self::Output {
             graph: self.graph,
             value: self.value.powf(other.value),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Binary,
                 &[self.index, other.index],
                 &[
                     other.value * f64::powf(self.value, other.value - 1.),
                     f64::powf(self.value, other.value) * f64::ln(self.value),
                 ],
+                [
+                    other.value * (other.value - 1.0) * f64::powf(self.value, other.value - 2.0),
+                    f64::powf(self.value, other.value - 1.0)
+                        * (1.0 + other.value * f64::ln(self.value)),
+                    f64::powf(self.value, other.value) * f64::ln(self.value).powi(2),
+                ],
             ),
         }
     }
@@ -121,10 +139,15 @@ impl<'v> Powi<i32> for Variable<'v> {
         Self::Output {
             graph: self.graph,
             value: f64::powi(self.value, n),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Binary,
                 &[self.index, self.index],
                 &[f64::from(n) * f64::powi(self.value, n - 1), 0.0],
+                [
+                    f64::from(n) * f64::from(n - 1) * f64::powi(self.value, n - 2),
+                    0.0,
+                    0.0,
+                ],
             ),
         }
     }
@@ -139,10 +162,15 @@ impl<'v> Powi<Variable<'v>> for f64 {
         Self::Output {
             graph: other.graph,
             value: f64::powf(*self, other.value),
-            index: other.graph.push(
+            index: other.graph.push_second_order(
                 Arity::Binary,
                 &[other.index, other.index],
-                &[0.0, other.value * f64::powf(*self, other.value - 1.0)],
+                &[0.0, f64::powf(*self, other.value) * f64::ln(*self)],
+                [
+                    0.0,
+                    0.0,
+                    f64::powf(*self, other.value) * f64::ln(*self).powi(2),
+                ],
             ),
         }
     }
@@ -154,6 +182,21 @@ impl<'v> Powi<Variable<'v>> for f64 {
 
 #[cfg(test)]
 mod test_overload {
+    use crate::assert_approx_equal;
+    use crate::autodiff::*;
+
+    #[test]
+    fn test_f64_base_gradients() {
+        let g = Graph::new();
+
+        let x = g.var(2.0);
+
+        // d/dx 3^x = 3^x * ln(3)
+        assert_approx_equal!(3.0.powf(x).value, 9.0, 1e-12);
+        assert_approx_equal!(3.0.powf(x).accumulate().wrt(&x), 9.0 * 3_f64.ln(), 1e-12);
+        assert_approx_equal!(3.0.powi(x).accumulate().wrt(&x), 9.0 * 3_f64.ln(), 1e-12);
+    }
+
     // use crate::autodiff::{Graph, Variable};
 
     // #[test]
@@ -38,10 +38,15 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: errorfunctions::RealErrorFunctions::erf(self.value),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[2.0 * self.value.powi(2).neg().exp() / PI.sqrt()],
+                [
+                    -4.0 * self.value * self.value.powi(2).neg().exp() / PI.sqrt(),
+                    0.0,
+                    0.0,
+                ],
             ),
         }
     }
@@ -70,10 +75,15 @@ impl<'v> Variable<'v> {
         Variable {
             graph: self.graph,
             value: errorfunctions::RealErrorFunctions::erfc(self.value),
-            index: self.graph.push(
+            index: self.graph.push_second_order(
                 Arity::Unary,
                 &[self.index],
                 &[((2.0 * self.value.powi(2).neg().exp()).neg() / PI.sqrt())],
+                [
+                    4.0 * self.value * self.value.powi(2).neg().exp() / PI.sqrt(),
+                    0.0,
+                    0.0,
+                ],
             ),
         }
     }
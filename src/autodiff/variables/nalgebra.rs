@@ -7,196 +7,481 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-// use crate::autodiff::variable::Variable;
-// use nalgebra::{DMatrix, DVector};
-
-// /// A matrix of `Variable`s.
-// pub struct VariableMatrix<'v> {
-//     data: DMatrix<Variable<'v>>,
-// }
-
-// /// A vector of `Variable`s.
-// pub struct VariableVector<'v> {
-//     data: DVector<Variable<'v>>,
-// }
-
-// impl std::ops::Add<VariableMatrix<'static>> for VariableMatrix<'static> {
-//     type Output = VariableMatrix<'static>;
-
-//     fn add(self, rhs: VariableMatrix<'static>) -> Self::Output {
-//         VariableMatrix {
-//             data: self.data + rhs.data,
-//         }
-//     }
-// }
-
-// #[cfg(test)]
-// mod tests_nalgebra_variable {
-//     use crate::autodiff::Graph;
-
-//     use super::*;
-
-//     #[test]
-//     fn test_matrix() {
-//         let graph = Graph::new();
-
-//         let a = VariableMatrix {
-//             data: DMatrix::from_row_slice(2, 2, &graph.vars(&[1.0, 2.0, 3.0, 4.0])),
-//         };
-//         let b = VariableMatrix {
-//             data: DMatrix::from_row_slice(2, 2, &graph.vars(&[5.0, 6.0, 7.0, 8.0])),
-//         };
-
-//         let c = a + b;
-
-//         println!("{:?}", c.data);
-
-//         // assert_eq!(
-//         //     c.data,
-//         //     DMatrix::from_row_slice(2, 2, &[5.0, 12.0, 21.0, 32.0])
-//         // );
-//     }
-// }
-
-// impl<'v> VariableMatrix<'v> {
-// pub fn new(rows: usize, cols: usize) -> Self {
-//     VariableMatrix {
-//         data: DMatrix::zeros(rows, cols),
-//     }
-// }
-
-// pub fn from_row_slice(rows: usize, cols: usize, elements: &[Variable]) -> Self {
-//     VariableMatrix {
-//         data: DMatrix::from_row_slice(rows, cols, elements),
-//     }
-// }
-
-// pub fn from_column_slice(rows: usize, cols: usize, data: &[Variable]) -> Self {
-//     VariableMatrix {
-//         data: DMatrix::from_column_slice(rows, cols, data),
-//     }
-// }
-
-// pub fn from_fn<F>(rows: usize, cols: usize, f: F) -> Self
-// where
-//     F: Fn(usize, usize) -> Variable<'v>,
-// {
-//     VariableMatrix {
-//         data: DMatrix::from_fn(rows, cols, f),
-//     }
-// }
-
-// pub fn map<F>(&self, f: F) -> Self
-// where
-//     F: Fn(Variable<'v>) -> Variable<'v>,
-// {
-//     VariableMatrix {
-//         data: self.data.map(f),
-//     }
-// }
-
-// pub fn map_mut<F>(&mut self, f: F)
-// where
-//     F: FnMut(Variable<'v>) -> Variable<'v>,
-// {
-//     self.data.map_mut(f);
-// }
-
-// pub fn rows(&self) -> usize {
-//     self.data.nrows()
-// }
-
-// pub fn cols(&self) -> usize {
-//     self.data.ncols()
-// }
-
-// pub fn get(&self, row: usize, col: usize) -> Variable<'v> {
-//     self.data[(row, col)]
-// }
-
-// pub fn set(&mut self, row: usize, col: usize, value: Variable<'v>) {
-//     self.data[(row, col)] = value;
-// }
-
-// pub fn component_mul(&self, other: &VariableMatrix<'v>) -> VariableMatrix<'v> {
-//     VariableMatrix {
-//         data: self.data.component_mul(&other.data),
-//     }
-// }
-
-// pub fn matrix_mul(&self, other: &VariableMatrix<'v>) -> VariableMatrix<'v> {
-//     VariableMatrix {
-//         data: self.data * &other.data,
-//     }
-// }
-
-// pub fn transpose(&self) -> VariableMatrix<'v> {
-//     VariableMatrix {
-//         data: self.data.transpose(),
-//     }
-// }
-
-// pub fn trace(&self) -> Variable<'v> {
-//     self.data.trace()
-// }
-
-// pub fn determinant(&self) -> Variable<'v> {
-//     self.data.determinant()
-// }
-
-// pub fn inverse(&self) -> VariableMatrix<'v> {
-//     VariableMatrix {
-//         data: self.data.try_inverse().unwrap(),
-//     }
-// }
-
-// pub fn solve(&self, rhs: &VariableVector<'v>) -> VariableVector<'v> {
-//     VariableVector {
-//         data: self.data.solve(&rhs.data).unwrap(),
-//     }
-// }
-// }
-
-// // pub type VariableMatrix<'a> = nalgebra::DMatrix<Variable<'a>>;
-// // pub type VariableVector<'a> = nalgebra::DVector<Variable<'a>>;
-
-// /// Trait to implement the `Matrix` struct.
-// pub trait Matrix<'v> {
-//     // fn new(graph: &'v Graph, rows: usize, cols: usize) -> Self;
-//     // fn get(&self, row: usize, col: usize) -> Variable<'v>;
-//     // fn set(&mut self, row: usize, col: usize, value: Variable<'v>);
-
-//     /// Component-wise multiplication.
-//     fn component_mul(&self, other: &DMatrix<Variable<'v>>) -> DMatrix<Variable<'v>>;
-
-//     // /// Matrix multiplication.
-//     // fn matrix_mul(&self, other: &DMatrix<Variable<'v>>) -> DMatrix<Variable<'v>>;
-// }
-
-// impl Matrix<'v> for DMatrix<Variable<'v>> {
-//     fn component_mul(&self, other: &DMatrix<Variable<'v>>) -> DMatrix<Variable<'v>> {
-//         nalgebra::Matrix::component_mul(self, other)
-//     }
-
-//     // fn matrix_mul(&self, other: &DMatrix<Variable<'v>>) -> DMatrix<Variable<'v>> {
-//     //     self * other
-//     // }
-// }
-
-// #[cfg(test)]
-// mod test_nalgebra {
-//     use super::*;
-
-//     #[test]
-//     fn test_component_mul() {
-//         let graph = Graph::new();
-//         let a = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
-//         let b = DMatrix::from_row_slice(2, 2, &[5.0, 6.0, 7.0, 8.0]);
-//         let a_var = a.map(|x| Variable::new(&graph, 0, x));
-//         let b_var = b.map(|x| Variable::new(&graph, 0, x));
-//         let c_var = a_var.component_mul(&b_var);
-//         let c = c_var.map(|x| x.value);
-//         let c_expected = DMatrix::from_row_slice(2, 2, &[5.0, 12.0, 21.0, 32.0]);
-//         assert_eq!(c, c_expected);
-//     }
-// }
+//! Matrices of `Variable`s, with differentiable linear algebra.
+//!
+//! `nalgebra` matrices need `'static` elements, so a [`VariableMatrix`]
+//! stores its `Variable`s itself (in column-major order, like `nalgebra`)
+//! and computes values with `nalgebra`.
+//!
+//! The matrix product, dot product and linear solve are pushed to the graph
+//! as one vertex per element of the result, whose partials are those of the
+//! whole operation, rather than as a vertex per scalar multiplication and
+//! addition. For $X = A^{-1} B$ these are
+//!
+//! $$
+//! \frac{\partial X_{ij}}{\partial B_{kj}} = (A^{-1})_{ik} \qquad
+//! \frac{\partial X_{ij}}{\partial A_{kl}} = -(A^{-1})_{ik} X_{lj}
+//! $$
+//!
+//! Vectors are matrices with one column.
+
+use crate::autodiff::{variables::variable::Variable, Graph};
+use nalgebra::DMatrix;
+use std::ops::{Index, Mul};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// A matrix of `Variable`s.
+#[derive(Debug, Clone)]
+pub struct VariableMatrix<'v> {
+    /// Pointer to the graph.
+    pub graph: &'v Graph,
+
+    /// Number of rows.
+    nrows: usize,
+
+    /// Number of columns.
+    ncols: usize,
+
+    /// Elements, in column-major order.
+    data: Vec<Variable<'v>>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<'v> VariableMatrix<'v> {
+    /// Add the elements of `values` to the graph as new variables.
+    #[must_use]
+    pub fn new(graph: &'v Graph, values: &DMatrix<f64>) -> Self {
+        Self {
+            graph,
+            nrows: values.nrows(),
+            ncols: values.ncols(),
+            data: graph.vars(values.as_slice()),
+        }
+    }
+
+    /// Add the elements of `values` to the graph as a column vector of new
+    /// variables.
+    #[must_use]
+    pub fn vector(graph: &'v Graph, values: &[f64]) -> Self {
+        Self::new(graph, &DMatrix::from_column_slice(values.len(), 1, values))
+    }
+
+    /// Matrix of existing variables, given in column-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no variables, or not `nrows * ncols` of them.
+    #[must_use]
+    pub fn from_column_slice(nrows: usize, ncols: usize, variables: &[Variable<'v>]) -> Self {
+        assert!(
+            !variables.is_empty(),
+            "a matrix needs at least one variable"
+        );
+        assert_eq!(variables.len(), nrows * ncols);
+
+        Self {
+            graph: variables[0].graph,
+            nrows,
+            ncols,
+            data: variables.to_vec(),
+        }
+    }
+
+    /// Matrix of existing variables, given in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no variables, or not `nrows * ncols` of them.
+    #[must_use]
+    pub fn from_row_slice(nrows: usize, ncols: usize, variables: &[Variable<'v>]) -> Self {
+        Self::from_column_slice(ncols, nrows, variables).transpose()
+    }
+
+    /// Number of rows.
+    #[must_use]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// Number of columns.
+    #[must_use]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// The variables, in column-major order.
+    #[must_use]
+    pub fn as_slice(&self) -> &[Variable<'v>] {
+        &self.data
+    }
+
+    /// Values of the variables.
+    #[must_use]
+    pub fn value(&self) -> DMatrix<f64> {
+        DMatrix::from_iterator(self.nrows, self.ncols, self.data.iter().map(|x| x.value))
+    }
+
+    /// Transpose of the matrix.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        self.with_elements(self.ncols, self.nrows, |i, j| self[(j, i)])
+    }
+
+    /// Sum of the products of the elements of two matrices of the same
+    /// shape, which is the dot product of two vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes differ.
+    #[must_use]
+    pub fn dot(&self, rhs: &Self) -> Variable<'v> {
+        assert_eq!(
+            (self.nrows, self.ncols),
+            (rhs.nrows, rhs.ncols),
+            "the dot product needs matrices of the same shape"
+        );
+
+        self.sum_of_products(&self.data, &rhs.data)
+    }
+
+    /// Solution $X$ of $A X = B$, where $A$ is this square matrix, or `None`
+    /// if it is singular.
+    ///
+    /// Each element of $X$ has $O(n^4)$ second order partials with respect
+    /// to $A$, so this is meant for small systems.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, or $B$ has another number of
+    /// rows.
+    #[must_use]
+    pub fn solve(&self, rhs: &Self) -> Option<Self> {
+        let n = self.nrows;
+        assert_eq!(self.ncols, n, "only square systems can be solved");
+        assert_eq!(rhs.nrows, n, "the right-hand side has the wrong size");
+
+        let lu = self.value().lu();
+        let inverse = lu.try_inverse()?;
+        let x = &inverse * rhs.value();
+
+        let g = &inverse;
+
+        Some(self.with_elements(n, rhs.ncols, |i, j| {
+            let mut parents = Vec::with_capacity(n * (n + 1));
+            let mut partials = Vec::with_capacity(n * (n + 1));
+            let mut second_partials = Vec::new();
+
+            for k in 0..n {
+                parents.push(rhs[(k, j)].index);
+                partials.push(g[(i, k)]);
+
+                for l in 0..n {
+                    parents.push(self[(k, l)].index);
+                    partials.push(-g[(i, k)] * x[(l, j)]);
+
+                    // d2 X_ij / dA_kl dB_mj = -G_ik G_lm
+                    for m in 0..n {
+                        second_partials.push(pair(
+                            &self[(k, l)],
+                            &rhs[(m, j)],
+                            -g[(i, k)] * g[(l, m)],
+                        ));
+                    }
+
+                    // d2 X_ij / dA_kl dA_pq = G_ip G_qk X_lj + G_ik G_lp X_qj
+                    for (p, q) in (0..n).flat_map(|p| (0..n).map(move |q| (p, q))) {
+                        if (p, q) < (k, l) {
+                            continue;
+                        }
+
+                        let partial =
+                            g[(i, p)] * g[(q, k)] * x[(l, j)] + g[(i, k)] * g[(l, p)] * x[(q, j)];
+
+                        second_partials.push(if (p, q) == (k, l) {
+                            (self[(k, l)].index, self[(k, l)].index, partial)
+                        } else {
+                            pair(&self[(k, l)], &self[(p, q)], partial)
+                        });
+                    }
+                }
+            }
+
+            self.node(x[(i, j)], &parents, &partials, &second_partials)
+        }))
+    }
+
+    /// Lower triangular Cholesky factor $L$ of this symmetric matrix, with
+    /// $A = L L^\top$, or `None` if it is not positive definite. Only the
+    /// lower triangle of the matrix is read.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    #[must_use]
+    pub fn cholesky(&self) -> Option<Self> {
+        let n = self.nrows;
+        assert_eq!(self.ncols, n, "only square matrices have a Cholesky factor");
+
+        let zero = self.graph.var(0.0);
+        let mut l = Self {
+            graph: self.graph,
+            nrows: n,
+            ncols: n,
+            data: vec![zero; n * n],
+        };
+
+        for j in 0..n {
+            let diagonal = (0..j).fold(self[(j, j)], |d, k| d - l[(j, k)] * l[(j, k)]);
+
+            if diagonal.value.is_nan() || diagonal.value <= 0.0 {
+                return None;
+            }
+
+            let pivot = diagonal.sqrt();
+            l.data[j + j * n] = pivot;
+
+            for i in (j + 1)..n {
+                let below = (0..j).fold(self[(i, j)], |s, k| s - l[(i, k)] * l[(j, k)]);
+                l.data[i + j * n] = below / pivot;
+            }
+        }
+
+        Some(l)
+    }
+
+    /// Matrix of the given shape, with the elements given by `f(i, j)`.
+    fn with_elements<F>(&self, nrows: usize, ncols: usize, f: F) -> Self
+    where
+        F: Fn(usize, usize) -> Variable<'v>,
+    {
+        Self {
+            graph: self.graph,
+            nrows,
+            ncols,
+            data: (0..ncols)
+                .flat_map(|j| (0..nrows).map(move |i| (i, j)))
+                .map(|(i, j)| f(i, j))
+                .collect(),
+        }
+    }
+
+    /// Sum of the products of the elements of `a` and `b`.
+    fn sum_of_products(&self, a: &[Variable<'v>], b: &[Variable<'v>]) -> Variable<'v> {
+        let parents: Vec<usize> = a.iter().chain(b).map(|x| x.index).collect();
+        let partials: Vec<f64> = b.iter().chain(a).map(|x| x.value).collect();
+        let second_partials: Vec<(usize, usize, f64)> =
+            a.iter().zip(b).map(|(a, b)| pair(a, b, 1.0)).collect();
+        let value = a.iter().zip(b).map(|(a, b)| a.value * b.value).sum();
+
+        self.node(value, &parents, &partials, &second_partials)
+    }
+
+    /// Variable for the result of an operation with the given parents.
+    fn node(
+        &self,
+        value: f64,
+        parents: &[usize],
+        partials: &[f64],
+        second_partials: &[(usize, usize, f64)],
+    ) -> Variable<'v> {
+        Variable {
+            graph: self.graph,
+            index: self
+                .graph
+                .push_nary_second_order(parents, partials, second_partials),
+            value,
+        }
+    }
+}
+
+/// Second order partial with respect to the operands `a` and `b` at two
+/// different positions, which counts twice if they are the same variable.
+fn pair(a: &Variable, b: &Variable, partial: f64) -> (usize, usize, f64) {
+    if a.index == b.index {
+        (a.index, a.index, 2.0 * partial)
+    } else {
+        (a.index, b.index, partial)
+    }
+}
+
+impl<'v> Index<(usize, usize)> for VariableMatrix<'v> {
+    type Output = Variable<'v>;
+
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        assert!(i < self.nrows && j < self.ncols, "index out of bounds");
+
+        &self.data[i + j * self.nrows]
+    }
+}
+
+impl<'v> Mul<&VariableMatrix<'v>> for &VariableMatrix<'v> {
+    type Output = VariableMatrix<'v>;
+
+    /// Matrix product.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of columns of the left-hand side is not the
+    /// number of rows of the right-hand side.
+    fn mul(self, rhs: &VariableMatrix<'v>) -> Self::Output {
+        assert_eq!(
+            self.ncols, rhs.nrows,
+            "the matrix product needs matching dimensions"
+        );
+
+        self.with_elements(self.nrows, rhs.ncols, |i, j| {
+            let row: Vec<Variable<'v>> = (0..self.ncols).map(|k| self[(i, k)]).collect();
+            let column: Vec<Variable<'v>> = (0..self.ncols).map(|k| rhs[(k, j)]).collect();
+
+            self.sum_of_products(&row, &column)
+        })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_nalgebra_variable {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::autodiff::{Accumulate, Gradient};
+
+    fn covariance() -> DMatrix<f64> {
+        DMatrix::from_row_slice(
+            3,
+            3,
+            &[0.04, 0.006, 0.002, 0.006, 0.09, 0.009, 0.002, 0.009, 0.0225],
+        )
+    }
+
+    #[test]
+    fn test_portfolio_volatility_gradient() {
+        let graph = Graph::new();
+        let sigma = covariance();
+        let w = [0.5, 0.3, 0.2];
+
+        let weights = VariableMatrix::vector(&graph, &w);
+        let covariance = VariableMatrix::new(&graph, &sigma);
+
+        // Volatility of the portfolio, sqrt(w' S w).
+        let volatility = weights.dot(&(&covariance * &weights)).sqrt();
+        let gradient = volatility.accumulate();
+
+        let w = DMatrix::from_column_slice(3, 1, &w);
+        let marginal = &sigma * &w / volatility.value;
+        assert_approx_equal!(
+            volatility.value,
+            (w.transpose() * &sigma * &w)[(0, 0)].sqrt(),
+            1e-15
+        );
+
+        for i in 0..3 {
+            assert_approx_equal!(gradient.wrt(&weights[(i, 0)]), marginal[(i, 0)], 1e-14);
+
+            // d vol / d S_ij = w_i w_j / (2 vol)
+            for j in 0..3 {
+                assert_approx_equal!(
+                    gradient.wrt(&covariance[(i, j)]),
+                    w[i] * w[j] / (2.0 * volatility.value),
+                    1e-14
+                );
+            }
+        }
+
+        // Euler allocation: the risk contributions add up to the volatility.
+        let contributions: f64 = (0..3).map(|i| w[i] * marginal[(i, 0)]).sum();
+        assert_approx_equal!(contributions, volatility.value, 1e-14);
+    }
+
+    #[test]
+    fn test_solve_gradient() {
+        let graph = Graph::new();
+        let a = DMatrix::from_row_slice(3, 3, &[4.0, 1.0, 0.5, 1.0, 3.0, 0.2, 0.5, 0.2, 2.0]);
+        let b = [1.0, 2.0, 3.0];
+
+        let matrix = VariableMatrix::new(&graph, &a);
+        let rhs = VariableMatrix::vector(&graph, &b);
+        let x = matrix.solve(&rhs).unwrap();
+
+        let expected = a
+            .clone()
+            .lu()
+            .solve(&DMatrix::from_column_slice(3, 1, &b))
+            .unwrap();
+        for i in 0..3 {
+            assert_approx_equal!(x[(i, 0)].value, expected[(i, 0)], 1e-14);
+        }
+
+        // The gradient of c' x is y = A^-T c wrt b, and -y x' wrt A.
+        let c = VariableMatrix::vector(&graph, &[1.0, -1.0, 0.5]);
+        let gradient = c.dot(&x).accumulate();
+        let y = a
+            .transpose()
+            .lu()
+            .solve(&DMatrix::from_column_slice(3, 1, &[1.0, -1.0, 0.5]))
+            .unwrap();
+
+        for k in 0..3 {
+            assert_approx_equal!(gradient.wrt(&rhs[(k, 0)]), y[(k, 0)], 1e-14);
+            for l in 0..3 {
+                assert_approx_equal!(
+                    gradient.wrt(&matrix[(k, l)]),
+                    -y[(k, 0)] * expected[(l, 0)],
+                    1e-14
+                );
+            }
+        }
+
+        // Singular systems have no solution.
+        let singular = VariableMatrix::new(&graph, &DMatrix::from_element(2, 2, 1.0));
+        assert!(singular
+            .solve(&VariableMatrix::vector(&graph, &[1.0, 2.0]))
+            .is_none());
+    }
+
+    #[test]
+    fn test_cholesky_gradient() {
+        let graph = Graph::new();
+        let sigma = covariance();
+
+        let matrix = VariableMatrix::new(&graph, &sigma);
+        let l = matrix.cholesky().unwrap();
+
+        let product = (&l * &l.transpose()).value();
+        for (a, b) in product.iter().zip(sigma.iter()) {
+            assert_approx_equal!(*a, *b, 1e-15);
+        }
+
+        // log det S = 2 sum log L_jj, whose gradient is the inverse of S
+        // (twice the off-diagonal entries, as only the lower triangle is read).
+        let log_det = (0..3).map(|j| l[(j, j)].ln() * 2.0).sum::<Variable>();
+        let gradient = log_det.accumulate();
+        let inverse = sigma.try_inverse().unwrap();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = match i.cmp(&j) {
+                    std::cmp::Ordering::Less => 0.0,
+                    std::cmp::Ordering::Equal => inverse[(i, i)],
+                    std::cmp::Ordering::Greater => 2.0 * inverse[(i, j)],
+                };
+                assert_approx_equal!(gradient.wrt(&matrix[(i, j)]), expected, 1e-10);
+            }
+        }
+
+        // Not positive definite.
+        let indefinite = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 2.0, 1.0]);
+        assert!(VariableMatrix::new(&graph, &indefinite)
+            .cholesky()
+            .is_none());
+    }
+}
@@ -10,7 +10,8 @@
 //! Submodule of cashflows for defining legs.
 //! A leg is a sequence of cashflows.
 
-use super::Cashflow;
+use super::{Cashflow, SimpleCashflow};
+use crate::time::Schedule;
 use time::OffsetDateTime;
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -81,6 +82,47 @@ impl<C: Cashflow> Leg<C> {
     }
 }
 
+impl Leg<SimpleCashflow> {
+    /// Creates a fixed-rate leg paying `rate` on the notional outstanding in
+    /// each coupon period of `schedule`, e.g. for amortizing or sinking-fund
+    /// structures. Pass the same notional for every period for a bullet leg.
+    ///
+    /// Each coupon is `notionals[i] * rate * tau_i`, where `tau_i` is the
+    /// period's year fraction under the schedule's day count convention,
+    /// and is paid on the period's payment date.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not one notional per coupon period.
+    #[must_use]
+    pub fn fixed_rate(schedule: &Schedule, rate: f64, notionals: &[f64]) -> Self {
+        let periods = schedule.coupon_periods();
+
+        assert_eq!(
+            notionals.len(),
+            periods.len(),
+            "There must be one notional per coupon period."
+        );
+
+        let cashflows = periods
+            .iter()
+            .zip(notionals)
+            .map(|(period, notional)| {
+                let accrual = schedule
+                    .day_counting_convention
+                    .day_count_factor(period.start, period.end);
+
+                SimpleCashflow::new(
+                    notional * rate * accrual,
+                    period.payment.midnight().assume_utc(),
+                )
+            })
+            .collect();
+
+        Self::new(cashflows)
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // UNIT TESTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -159,4 +201,43 @@ mod tests_legs {
         assert!(!leg.is_active(now - Duration::days(1)));
         assert!(!leg.is_active(now + Duration::days(61)));
     }
+
+    // Test to verify the `fixed_rate` constructor with amortizing notionals.
+    #[test]
+    fn test_fixed_rate_amortizing_leg() {
+        use crate::time::{
+            countries::north_america::united_states::UnitedStatesCalendar, DateRollingConvention,
+            DayCountConvention, Frequency,
+        };
+        use time::macros::date;
+
+        let schedule = Schedule::regular(
+            date!(2024 - 01 - 02),
+            date!(2026 - 01 - 02),
+            Frequency::SemiAnnually,
+            &UnitedStatesCalendar,
+            DateRollingConvention::ModifiedFollowing,
+            DayCountConvention::Actual_360,
+        )
+        .unwrap();
+
+        let bullet = Leg::fixed_rate(&schedule, 0.05, &[100.0; 4]);
+        let amortizing = Leg::fixed_rate(&schedule, 0.05, &[100.0, 75.0, 50.0, 25.0]);
+
+        assert_eq!(bullet.size(), 4);
+
+        // Each coupon scales with the outstanding notional.
+        for (i, (a, b)) in amortizing
+            .cashflows()
+            .iter()
+            .zip(bullet.cashflows())
+            .enumerate()
+        {
+            assert_approx_equal!(a.amount(), b.amount() * (4 - i) as f64 / 4.0, 1e-12);
+            assert_eq!(a.date(), b.date());
+        }
+
+        let df = |_| 0.95;
+        assert!(amortizing.npv(df) < bullet.npv(df));
+    }
 }
@@ -26,3 +26,7 @@ pub use legs::*;
 /// Quotes (price, yield, etc).
 pub mod quotes;
 pub use quotes::*;
+
+/// Overnight-index (compounded daily) coupons.
+pub mod overnight;
+pub use overnight::*;
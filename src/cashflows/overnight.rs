@@ -0,0 +1,505 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Overnight-index (compounded daily) coupons, such as SOFR, SONIA, or ESTR.
+//!
+//! The compounded rate over an accrual period $[t_s, t_e]$ with business days
+//! $d_0 = t_s < d_1 < \dots < d_n = t_e$ is:
+//!
+//! $$
+//! R = \left[ \prod_{i=0}^{n-1} \left( 1 + r_i \tau(d_i, d_{i+1}) \right) - 1 \right] \frac{1}{\tau(t_s, t_e)}
+//! $$
+//!
+//! where $r_i$ is the overnight rate fixed for business day $d_i$.
+//! Rates observed on or after the curve's initial date are implied from the
+//! curve; earlier observations (e.g. when a lookback reaches back before
+//! today) must be supplied as historical fixings.
+
+use crate::cashflows::Cashflow;
+use crate::data::Curve;
+use crate::error::RustQuantError;
+use crate::time::{advance_business_days, Calendar, DayCountConvention};
+use std::collections::BTreeMap;
+use time::{Date, OffsetDateTime};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Conventions for compounding an overnight index over an accrual period.
+#[derive(Debug, Clone, Copy)]
+pub struct OvernightConventions {
+    /// Number of business days each fixing is observed before the
+    /// accrual day it applies to.
+    pub lookback_days: i64,
+
+    /// Number of business days at the end of the period for which the rate
+    /// is frozen at the last fixing before the lockout.
+    pub lockout_days: i64,
+
+    /// Number of business days between the end of the accrual period and
+    /// the payment date.
+    pub payment_delay_days: i64,
+
+    /// Whether the lookback shifts the whole observation period rather than
+    /// each fixing. With an observation shift, the rates are compounded over
+    /// the period `lookback_days` business days before the accrual period,
+    /// weighted by the days of the observation period.
+    pub observation_shift: bool,
+
+    /// Day count convention used to accrue each overnight rate.
+    pub day_count_convention: DayCountConvention,
+}
+
+/// Floating cashflow paying a compounded overnight rate.
+#[derive(Debug, Clone, Copy)]
+pub struct OvernightIndexedCashflow {
+    /// Notional of the cashflow.
+    pub notional: f64,
+
+    /// Spread added to the compounded rate.
+    pub spread: f64,
+
+    /// Start date of the accrual period.
+    pub accrual_start: Date,
+
+    /// End date of the accrual period.
+    pub accrual_end: Date,
+
+    /// Payment date of the cashflow.
+    pub payment_date: Date,
+
+    /// Compounded overnight rate over the accrual period.
+    pub compounded_rate: f64,
+
+    /// Year fraction of the accrual period.
+    pub accrual_factor: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Default for OvernightConventions {
+    /// No lookback, lockout or payment delay, with Actual/360 accrual.
+    fn default() -> Self {
+        Self {
+            lookback_days: 0,
+            lockout_days: 0,
+            payment_delay_days: 0,
+            observation_shift: false,
+            day_count_convention: DayCountConvention::Actual_360,
+        }
+    }
+}
+
+/// Returns the daily accrual periods `(d_i, d_{i+1})` between `start` and
+/// `end`, where each `d_i` is a business day and the last period ends on `end`.
+/// Weekends and holidays are absorbed into the period of the preceding
+/// business day.
+pub fn overnight_accrual_periods<K: Calendar + ?Sized>(
+    start: Date,
+    end: Date,
+    calendar: &K,
+) -> Vec<(Date, Date)> {
+    let mut periods = Vec::new();
+    let mut date = advance_business_days(start, 0, calendar);
+
+    while date < end {
+        let next = advance_business_days(date, 1, calendar).min(end);
+        periods.push((date, next));
+        date = next;
+    }
+
+    periods
+}
+
+/// Returns the observation period of each daily accrual period of
+/// [`overnight_accrual_periods`]: the overnight period starting
+/// `lookback_days` business days before the accrual day, whose rate
+/// applies to that accrual day.
+pub fn overnight_observation_periods<K: Calendar + ?Sized>(
+    start: Date,
+    end: Date,
+    calendar: &K,
+    lookback_days: i64,
+) -> Vec<(Date, Date)> {
+    overnight_accrual_periods(start, end, calendar)
+        .iter()
+        .map(|(date, _)| {
+            let observation = advance_business_days(*date, -lookback_days, calendar);
+
+            (observation, advance_business_days(observation, 1, calendar))
+        })
+        .collect()
+}
+
+/// Compounded daily overnight rate between `start` and `end`, using the
+/// curve's overnight forwards and Actual/360 accrual.
+/// See [`compounded_overnight_rate_with_conventions`] for lookback, lockout
+/// and historical fixings.
+///
+/// # Errors
+/// - `RustQuantError::MissingInput` if the period starts before the curve.
+pub fn compounded_overnight_rate<C, K>(
+    start: Date,
+    end: Date,
+    curve: &C,
+    calendar: &K,
+) -> Result<f64, RustQuantError>
+where
+    C: Curve + ?Sized,
+    K: Calendar + ?Sized,
+{
+    compounded_overnight_rate_with_conventions(
+        start,
+        end,
+        curve,
+        calendar,
+        &OvernightConventions::default(),
+        &BTreeMap::new(),
+    )
+}
+
+/// Compounded daily overnight rate between `start` and `end`, with the given
+/// lookback (of each fixing, or with an observation shift) and lockout.
+///
+/// Rates observed on or after the curve's initial date are the curve's
+/// overnight forwards; rates observed before it are taken from `fixings`,
+/// keyed by observation date.
+///
+/// # Errors
+/// - `RustQuantError::MissingInput` if a rate is observed before the curve's
+///   initial date and there is no fixing for it.
+pub fn compounded_overnight_rate_with_conventions<C, K>(
+    start: Date,
+    end: Date,
+    curve: &C,
+    calendar: &K,
+    conventions: &OvernightConventions,
+    fixings: &BTreeMap<Date, f64>,
+) -> Result<f64, RustQuantError>
+where
+    C: Curve + ?Sized,
+    K: Calendar + ?Sized,
+{
+    let dcc = conventions.day_count_convention;
+
+    // An observation shift compounds over the shifted period itself.
+    let (start, end, lookback_days) = if conventions.observation_shift {
+        (
+            advance_business_days(start, -conventions.lookback_days, calendar),
+            advance_business_days(end, -conventions.lookback_days, calendar),
+            0,
+        )
+    } else {
+        (start, end, conventions.lookback_days)
+    };
+
+    let periods = overnight_accrual_periods(start, end, calendar);
+
+    // Overnight rate of each observation period, from a historical fixing
+    // or from the curve's forwards.
+    let mut rates = overnight_observation_periods(start, end, calendar, lookback_days)
+        .into_iter()
+        .map(|(observation, observation_end)| {
+            if observation < curve.initial_date() {
+                return fixings.get(&observation).copied().ok_or_else(|| {
+                    RustQuantError::MissingInput(format!(
+                        "no overnight fixing for {observation}, before the curve starts on {}",
+                        curve.initial_date()
+                    ))
+                });
+            }
+
+            let tau = dcc.day_count_factor(observation, observation_end);
+
+            Ok(
+                (curve.discount_factor(observation) / curve.discount_factor(observation_end) - 1.0)
+                    / tau,
+            )
+        })
+        .collect::<Result<Vec<f64>, RustQuantError>>()?;
+
+    // Freeze the last `lockout_days` rates.
+    let lockout = usize::try_from(conventions.lockout_days).unwrap_or(0);
+
+    if lockout > 0 && lockout < rates.len() {
+        let frozen = rates[rates.len() - lockout - 1];
+        let n = rates.len();
+        rates[n - lockout..].fill(frozen);
+    }
+
+    let growth: f64 = periods
+        .iter()
+        .zip(rates.iter())
+        .map(|((from, to), rate)| 1.0 + rate * dcc.day_count_factor(*from, *to))
+        .product();
+
+    Ok((growth - 1.0) / dcc.day_count_factor(start, end))
+}
+
+impl OvernightIndexedCashflow {
+    /// Create a new overnight-indexed cashflow, fixing the compounded rate
+    /// from the given curve and historical fixings.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a rate is observed before the
+    ///   curve's initial date and there is no fixing for it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<C, K>(
+        notional: f64,
+        spread: f64,
+        accrual_start: Date,
+        accrual_end: Date,
+        curve: &C,
+        calendar: &K,
+        conventions: &OvernightConventions,
+        fixings: &BTreeMap<Date, f64>,
+    ) -> Result<Self, RustQuantError>
+    where
+        C: Curve + ?Sized,
+        K: Calendar + ?Sized,
+    {
+        let compounded_rate = compounded_overnight_rate_with_conventions(
+            accrual_start,
+            accrual_end,
+            curve,
+            calendar,
+            conventions,
+            fixings,
+        )?;
+
+        Ok(Self {
+            notional,
+            spread,
+            accrual_start,
+            accrual_end,
+            payment_date: advance_business_days(
+                accrual_end,
+                conventions.payment_delay_days,
+                calendar,
+            ),
+            compounded_rate,
+            accrual_factor: conventions
+                .day_count_convention
+                .day_count_factor(accrual_start, accrual_end),
+        })
+    }
+}
+
+impl Cashflow for OvernightIndexedCashflow {
+    fn amount(&self) -> f64 {
+        self.notional * (self.compounded_rate + self.spread) * self.accrual_factor
+    }
+
+    fn date(&self) -> OffsetDateTime {
+        self.payment_date.midnight().assume_utc()
+    }
+
+    fn npv<F>(&self, df: F) -> f64
+    where
+        F: Fn(OffsetDateTime) -> f64,
+    {
+        self.amount() * df(self.date())
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_overnight {
+    use super::*;
+    use crate::data::YieldCurve;
+    use crate::time::countries::north_america::united_states::UnitedStatesCalendar;
+    use std::collections::BTreeMap;
+    use time::macros::date;
+
+    fn flat_curve(rate: f64) -> YieldCurve {
+        YieldCurve::new(BTreeMap::from([(date!(2023 - 12 - 01), rate)]))
+    }
+
+    #[test]
+    fn test_accrual_periods_over_holiday() {
+        // 4 July 2024 is a Thursday holiday, followed by a weekend.
+        let periods = overnight_accrual_periods(
+            date!(2024 - 07 - 01),
+            date!(2024 - 07 - 08),
+            &UnitedStatesCalendar,
+        );
+
+        let days: Vec<i64> = periods
+            .iter()
+            .map(|(from, to)| (*to - *from).whole_days())
+            .collect();
+
+        assert_eq!(periods.len(), 4);
+        assert_eq!(days, vec![1, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_flat_curve_compounded_rate() {
+        let curve = flat_curve(0.05);
+        let (start, end) = (date!(2024 - 01 - 02), date!(2024 - 04 - 02));
+
+        let rate = compounded_overnight_rate(start, end, &curve, &UnitedStatesCalendar).unwrap();
+
+        // Without a lookback, the daily growth factors telescope to the
+        // simple forward rate over the whole period.
+        let tau = DayCountConvention::Actual_360.day_count_factor(start, end);
+        let simple = (curve.discount_factor(start) / curve.discount_factor(end) - 1.0) / tau;
+
+        assert_approx_equal!(rate, simple, 1e-12);
+        assert!((rate - 0.05).abs() < 2e-3);
+    }
+
+    #[test]
+    fn test_lookback_observation_periods() {
+        // Tuesday 2 January to Monday 8 January 2024, with 1 January a holiday.
+        let observations = overnight_observation_periods(
+            date!(2024 - 01 - 02),
+            date!(2024 - 01 - 08),
+            &UnitedStatesCalendar,
+            2,
+        );
+
+        assert_eq!(
+            observations,
+            vec![
+                (date!(2023 - 12 - 28), date!(2023 - 12 - 29)),
+                (date!(2023 - 12 - 29), date!(2024 - 01 - 02)),
+                (date!(2024 - 01 - 02), date!(2024 - 01 - 03)),
+                (date!(2024 - 01 - 03), date!(2024 - 01 - 04)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lookback_before_curve_requires_fixings() {
+        let curve = flat_curve(0.04);
+        let (start, end) = (date!(2023 - 12 - 01), date!(2023 - 12 - 05));
+        let conventions = OvernightConventions {
+            lookback_days: 2,
+            ..OvernightConventions::default()
+        };
+
+        // The accrual days are Friday 1 (over the weekend) and Monday 4
+        // December, observed on Wednesday 29 and Thursday 30 November,
+        // before the curve starts on 1 December.
+        let rate = |fixings: &BTreeMap<Date, f64>| {
+            compounded_overnight_rate_with_conventions(
+                start,
+                end,
+                &curve,
+                &UnitedStatesCalendar,
+                &conventions,
+                fixings,
+            )
+        };
+
+        assert!(rate(&BTreeMap::new()).is_err());
+        assert!(rate(&BTreeMap::from([(date!(2023 - 11 - 29), 0.05)])).is_err());
+
+        let fixings =
+            BTreeMap::from([(date!(2023 - 11 - 29), 0.05), (date!(2023 - 11 - 30), 0.06)]);
+        let expected = ((1.0 + 0.05 * 3.0 / 360.0) * (1.0 + 0.06 / 360.0) - 1.0) * 360.0 / 4.0;
+
+        assert_approx_equal!(rate(&fixings).unwrap(), expected, 1e-12);
+    }
+
+    #[test]
+    fn test_lookback_lockout_and_payment_delay() {
+        let curve = flat_curve(0.04);
+        let conventions = OvernightConventions {
+            lookback_days: 2,
+            lockout_days: 2,
+            payment_delay_days: 2,
+            ..OvernightConventions::default()
+        };
+
+        let cashflow = OvernightIndexedCashflow::new(
+            1_000_000.0,
+            0.0,
+            date!(2024 - 01 - 02),
+            date!(2024 - 04 - 02),
+            &curve,
+            &UnitedStatesCalendar,
+            &conventions,
+            &BTreeMap::new(),
+        )
+        .unwrap();
+
+        // On a flat curve the shifts barely change the compounded rate.
+        let plain = compounded_overnight_rate(
+            date!(2024 - 01 - 02),
+            date!(2024 - 04 - 02),
+            &curve,
+            &UnitedStatesCalendar,
+        )
+        .unwrap();
+
+        assert!((cashflow.compounded_rate - plain).abs() < 1e-5);
+        assert_eq!(cashflow.payment_date, date!(2024 - 04 - 04));
+        assert!(cashflow.amount() > 0.0);
+    }
+
+    #[test]
+    fn test_observation_shift() {
+        // The curve starts after the period, so every rate is a fixing.
+        let curve = YieldCurve::new(BTreeMap::from([(date!(2024 - 02 - 01), 0.04)]));
+        let fixings = BTreeMap::from([
+            (date!(2024 - 01 - 04), 0.05),
+            (date!(2024 - 01 - 05), 0.06),
+            (date!(2024 - 01 - 08), 0.07),
+            (date!(2024 - 01 - 09), 0.08),
+        ]);
+
+        let rate = |observation_shift| {
+            let conventions = OvernightConventions {
+                lookback_days: 2,
+                observation_shift,
+                ..OvernightConventions::default()
+            };
+
+            compounded_overnight_rate_with_conventions(
+                date!(2024 - 01 - 08),
+                date!(2024 - 01 - 12),
+                &curve,
+                &UnitedStatesCalendar,
+                &conventions,
+                &fixings,
+            )
+            .unwrap()
+        };
+
+        // Monday 8 to Friday 12 January, observed from Thursday 4 to
+        // Wednesday 10 January: the Friday fixing spans the weekend.
+        let shifted = ((1.0 + 0.05 / 360.0)
+            * (1.0 + 0.06 * 3.0 / 360.0)
+            * (1.0 + 0.07 / 360.0)
+            * (1.0 + 0.08 / 360.0)
+            - 1.0)
+            * 360.0
+            / 6.0;
+
+        // Without the shift, each accrual day has its own weight.
+        let lookback = ((1.0 + 0.05 / 360.0)
+            * (1.0 + 0.06 / 360.0)
+            * (1.0 + 0.07 / 360.0)
+            * (1.0 + 0.08 / 360.0)
+            - 1.0)
+            * 360.0
+            / 4.0;
+
+        assert_approx_equal!(rate(true), shifted, 1e-12);
+        assert_approx_equal!(rate(false), lookback, 1e-12);
+    }
+}
@@ -7,6 +7,9 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
 /// Trait to define financial quotes.
 pub trait Quote {
     /// Quote value.
@@ -59,6 +62,124 @@ impl Quote for SimpleQuote {
     }
 }
 
+/// Trait for objects that depend on an observable (e.g. a [`ObservableQuote`])
+/// and must be told when it changes.
+pub trait Observer {
+    /// Called by the observable when its value changes.
+    fn update(&self);
+}
+
+/// Quote that notifies its observers when its value changes.
+///
+/// Observers are held weakly, so dropping a dependent object unregisters it.
+/// This mirrors the quote/handle mechanism of QuantLib, letting dependent
+/// curves or instruments recompute lazily after a scenario update.
+#[derive(Default)]
+pub struct ObservableQuote {
+    value: Cell<Option<f64>>,
+    observers: RefCell<Vec<Weak<dyn Observer>>>,
+}
+
+/// A lazily computed value, invalidated by the observables it is registered with.
+#[derive(Debug, Default)]
+pub struct LazyValue {
+    cached: Cell<Option<f64>>,
+}
+
+impl ObservableQuote {
+    /// Create a new observable quote.
+    #[must_use]
+    pub fn new(value: Option<f64>) -> Self {
+        Self {
+            value: Cell::new(value),
+            observers: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Set the quote value, notifying the observers if it changed.
+    /// Returns the change in value (zero if either value is missing).
+    pub fn set_value(&self, value: Option<f64>) -> f64 {
+        let old = self.value.get();
+
+        if old == value {
+            return 0.0;
+        }
+
+        self.value.set(value);
+        self.notify_observers();
+
+        match (old, value) {
+            (Some(old_value), Some(new_value)) => new_value - old_value,
+            _ => 0.0,
+        }
+    }
+
+    /// Register an observer to be notified when the value changes.
+    pub fn register_observer<O: Observer + 'static>(&self, observer: &Rc<O>) {
+        let observer: Rc<dyn Observer> = observer.clone();
+        self.observers.borrow_mut().push(Rc::downgrade(&observer));
+    }
+
+    /// Notify all live observers, dropping those that no longer exist.
+    ///
+    /// The observers are collected before any is called, so an observer
+    /// may register new observers (or read this quote) from its `update`.
+    pub fn notify_observers(&self) {
+        let live: Vec<Rc<dyn Observer>> = {
+            let mut observers = self.observers.borrow_mut();
+            observers.retain(|observer| observer.strong_count() > 0);
+            observers.iter().filter_map(Weak::upgrade).collect()
+        };
+
+        for observer in live {
+            observer.update();
+        }
+    }
+}
+
+impl Quote for ObservableQuote {
+    fn value(&self) -> Option<f64> {
+        self.value.get()
+    }
+
+    fn is_valid(&self) -> bool {
+        self.value.get().is_some()
+    }
+}
+
+impl LazyValue {
+    /// Create a new (not yet computed) lazy value.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached value, computing it with `f` if it has been
+    /// invalidated (or never computed).
+    pub fn get_or_compute<F: FnOnce() -> f64>(&self, f: F) -> f64 {
+        if let Some(value) = self.cached.get() {
+            return value;
+        }
+
+        let value = f();
+        self.cached.set(Some(value));
+
+        value
+    }
+
+    /// Whether the value must be recomputed on the next query.
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        self.cached.get().is_none()
+    }
+}
+
+impl Observer for LazyValue {
+    fn update(&self) {
+        self.cached.set(None);
+    }
+}
+
 /// Derived quote type.
 pub struct DerivedQuote<F>
 where
@@ -67,3 +188,140 @@ where
     _value: Option<f64>,
     _function: F,
 }
+
+#[cfg(test)]
+mod tests_quotes {
+    use super::*;
+    use crate::data::{Curve, YieldCurve};
+    use crate::instruments::options::{BlackScholesMerton, TypeFlag};
+    use time::macros::date;
+
+    fn call_price(spot: f64) -> f64 {
+        BlackScholesMerton::new(
+            0.05,
+            spot,
+            100.0,
+            0.2,
+            0.05,
+            Some(date!(2024 - 01 - 05)),
+            date!(2025 - 01 - 05),
+            TypeFlag::Call,
+        )
+        .price()
+    }
+
+    #[test]
+    fn test_quote_update_invalidates_cached_price() {
+        let spot = ObservableQuote::new(Some(100.0));
+        let cached_price = Rc::new(LazyValue::new());
+        spot.register_observer(&cached_price);
+
+        let evaluations = Cell::new(0);
+        let price = || {
+            cached_price.get_or_compute(|| {
+                evaluations.set(evaluations.get() + 1);
+                call_price(spot.value().unwrap())
+            })
+        };
+
+        let initial = price();
+        assert_eq!(evaluations.get(), 1);
+
+        // Setting the same value keeps the cached price.
+        assert_eq!(spot.set_value(Some(100.0)), 0.0);
+        assert!(!cached_price.is_dirty());
+        assert_eq!(price(), initial);
+        assert_eq!(evaluations.get(), 1);
+
+        // A new spot invalidates it, and the price is recomputed lazily.
+        spot.set_value(Some(105.0));
+        assert!(cached_price.is_dirty());
+        assert!(price() > initial);
+        assert_eq!(evaluations.get(), 2);
+    }
+
+    #[test]
+    fn test_quote_update_invalidates_curve_discount_factor() {
+        let dates = [
+            date!(2024 - 01 - 05),
+            date!(2025 - 01 - 05),
+            date!(2026 - 01 - 05),
+        ];
+        let short_rate = ObservableQuote::new(Some(0.04));
+        let long_rate = ObservableQuote::new(Some(0.05));
+
+        let cached_discount_factor = Rc::new(LazyValue::new());
+        short_rate.register_observer(&cached_discount_factor);
+        long_rate.register_observer(&cached_discount_factor);
+
+        let discount_factor = || {
+            cached_discount_factor.get_or_compute(|| {
+                let rates = [
+                    0.03,
+                    short_rate.value().unwrap(),
+                    long_rate.value().unwrap(),
+                ];
+
+                YieldCurve::from_dates_and_rates(&dates, &rates)
+                    .discount_factor(date!(2025 - 07 - 05))
+            })
+        };
+
+        let initial = discount_factor();
+
+        // Bumping either quote rebuilds the curve on the next query.
+        long_rate.set_value(Some(0.06));
+        assert!(cached_discount_factor.is_dirty());
+
+        let bumped = discount_factor();
+        assert!(bumped < initial);
+
+        short_rate.set_value(Some(0.04));
+        assert!(!cached_discount_factor.is_dirty());
+        assert_eq!(discount_factor(), bumped);
+    }
+
+    /// Registers another observer on the quote it observes when notified.
+    struct Registrar {
+        quote: Rc<ObservableQuote>,
+        late_observer: Rc<LazyValue>,
+    }
+
+    impl Observer for Registrar {
+        fn update(&self) {
+            self.quote.register_observer(&self.late_observer);
+        }
+    }
+
+    #[test]
+    fn test_observer_can_register_during_notification() {
+        let quote = Rc::new(ObservableQuote::new(Some(1.0)));
+        let late_observer = Rc::new(LazyValue::new());
+        let registrar = Rc::new(Registrar {
+            quote: quote.clone(),
+            late_observer: late_observer.clone(),
+        });
+        quote.register_observer(&registrar);
+
+        late_observer.get_or_compute(|| 1.0);
+        quote.set_value(Some(2.0));
+
+        // Registered during the first notification, invalidated by the second.
+        assert!(!late_observer.is_dirty());
+        quote.set_value(Some(3.0));
+        assert!(late_observer.is_dirty());
+    }
+
+    #[test]
+    fn test_dropped_observers_are_unregistered() {
+        let quote = ObservableQuote::new(Some(1.0));
+
+        {
+            let observer = Rc::new(LazyValue::new());
+            quote.register_observer(&observer);
+        }
+
+        quote.set_value(Some(2.0));
+        assert!(quote.observers.borrow().is_empty());
+    }
+}
@@ -0,0 +1,217 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Cross-currency discount curves.
+//!
+//! A foreign-currency cash flow collateralized in the domestic currency is
+//! discounted on a foreign curve implied by the domestic curve and the FX
+//! forwards, via covered interest parity:
+//!
+//! $$
+//! F(t) = S \frac{P_f(t)}{P_d(t)} \implies P_f(t) = P_d(t) \frac{F(t)}{S}
+//! $$
+//!
+//! where $S$ is the spot rate and $F(t)$ the outright forward rate, both in
+//! units of domestic currency per unit of foreign currency. A cross-currency
+//! basis spread $b(t)$, paid on the foreign leg, is added to the
+//! continuously-compounded foreign zero rate: $P_f(t) \to P_f(t) e^{-b(t) t}$.
+
+use crate::data::{Compounding, Curve, YieldCurve};
+use crate::error::RustQuantError;
+use crate::time::DayCountConvention;
+use std::collections::BTreeMap;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Market quotes at one pillar of a cross-currency curve.
+#[derive(Debug, Clone, Copy)]
+pub struct CrossCurrencyQuote {
+    /// Pillar (delivery) date.
+    pub date: Date,
+
+    /// FX forward points, in units of the spot rate:
+    /// the outright forward is `spot + forward_points`.
+    pub forward_points: f64,
+
+    /// Cross-currency basis spread on the foreign leg
+    /// (e.g. `-0.002` for -20bp).
+    pub basis_spread: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Foreign discount curve implied by a domestic discount curve, the FX spot
+/// rate, and FX forward points and cross-currency basis spreads at each pillar.
+///
+/// The domestic curve must start at the valuation date. The result stores
+/// continuously-compounded zero rates (`Actual/Actual ISDA` from the
+/// valuation date), so its discount factors are consistent with
+/// [`Curve::discount_factor`].
+///
+/// # Errors
+/// - `RustQuantError::InvalidArgument` if there are no quotes, a pillar is
+///   not after the valuation date, or the spot or a forward is not positive.
+pub fn foreign_discount_curve<C: Curve>(
+    valuation_date: Date,
+    fx_spot: f64,
+    domestic_curve: &C,
+    quotes: &[CrossCurrencyQuote],
+) -> Result<YieldCurve, RustQuantError> {
+    if quotes.is_empty() {
+        return Err(RustQuantError::InvalidArgument(
+            "at least one cross-currency quote is required".to_string(),
+        ));
+    }
+    if fx_spot <= 0.0 {
+        return Err(RustQuantError::InvalidArgument(format!(
+            "the FX spot rate must be positive, got {fx_spot}"
+        )));
+    }
+
+    let day_count_convention = DayCountConvention::default();
+    let mut discount_factors = BTreeMap::from([(valuation_date, 1.0)]);
+
+    for quote in quotes {
+        let forward = fx_spot + quote.forward_points;
+
+        if quote.date <= valuation_date {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "pillar {} is not after the valuation date {valuation_date}",
+                quote.date
+            )));
+        }
+        if forward <= 0.0 {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the FX forward for {} must be positive, got {forward}",
+                quote.date
+            )));
+        }
+
+        let t = day_count_convention.day_count_factor(valuation_date, quote.date);
+        let covered_parity = domestic_curve.discount_factor(quote.date) * forward / fx_spot;
+
+        discount_factors.insert(
+            quote.date,
+            covered_parity * f64::exp(-quote.basis_spread * t),
+        );
+    }
+
+    Ok(YieldCurve::from_discount_factors(
+        &discount_factors,
+        Compounding::Continuous,
+        day_count_convention,
+    ))
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_cross_currency {
+    use super::*;
+    use crate::{assert_approx_equal, RUSTQUANT_EPSILON};
+    use time::macros::date;
+
+    const SPOT: f64 = 1.10;
+
+    fn domestic_curve() -> YieldCurve {
+        YieldCurve::from_dates_and_rates(
+            &[
+                date!(2024 - 01 - 02),
+                date!(2024 - 07 - 02),
+                date!(2025 - 01 - 02),
+                date!(2027 - 01 - 04),
+            ],
+            &[0.050, 0.050, 0.048, 0.045],
+        )
+    }
+
+    fn quotes(basis_spread: f64) -> Vec<CrossCurrencyQuote> {
+        [
+            (date!(2024 - 07 - 02), 0.0080),
+            (date!(2025 - 01 - 02), 0.0150),
+            (date!(2027 - 01 - 04), 0.0380),
+        ]
+        .into_iter()
+        .map(|(date, forward_points)| CrossCurrencyQuote {
+            date,
+            forward_points,
+            basis_spread,
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_zero_basis_is_covered_interest_parity() {
+        let domestic = domestic_curve();
+        let foreign =
+            foreign_discount_curve(date!(2024 - 01 - 02), SPOT, &domestic, &quotes(0.0)).unwrap();
+
+        // The foreign curve reprices the FX forwards: F = S P_f / P_d.
+        for quote in quotes(0.0) {
+            let implied_forward =
+                SPOT * foreign.discount_factor(quote.date) / domestic.discount_factor(quote.date);
+
+            assert_approx_equal!(implied_forward, SPOT + quote.forward_points, 1e-12);
+        }
+
+        assert_approx_equal!(
+            foreign.discount_factor(date!(2024 - 01 - 02)),
+            1.0,
+            RUSTQUANT_EPSILON
+        );
+    }
+
+    #[test]
+    fn test_positive_basis_shifts_foreign_curve() {
+        let domestic = domestic_curve();
+        let valuation_date = date!(2024 - 01 - 02);
+        let spread = 0.0025;
+
+        let flat = foreign_discount_curve(valuation_date, SPOT, &domestic, &quotes(0.0)).unwrap();
+        let shifted =
+            foreign_discount_curve(valuation_date, SPOT, &domestic, &quotes(spread)).unwrap();
+
+        // A positive basis raises the foreign zero rates by the spread,
+        // i.e. lowers the discount factors by exp(-b t).
+        for quote in quotes(spread) {
+            let t = DayCountConvention::default().day_count_factor(valuation_date, quote.date);
+
+            assert_approx_equal!(
+                shifted.rate(quote.date) - flat.rate(quote.date),
+                spread,
+                1e-12
+            );
+            assert_approx_equal!(
+                shifted.discount_factor(quote.date) / flat.discount_factor(quote.date),
+                f64::exp(-spread * t),
+                1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_cross_currency_invalid_inputs() {
+        let domestic = domestic_curve();
+
+        assert!(foreign_discount_curve(date!(2024 - 01 - 02), SPOT, &domestic, &[]).is_err());
+        assert!(
+            foreign_discount_curve(date!(2024 - 01 - 02), -1.0, &domestic, &quotes(0.0)).is_err()
+        );
+        assert!(
+            foreign_discount_curve(date!(2025 - 06 - 02), SPOT, &domestic, &quotes(0.0)).is_err()
+        );
+    }
+}
@@ -11,7 +11,8 @@
 // IMPORTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use crate::time::DayCountConvention;
+use crate::math::interpolation::{fitted_interpolator, Interpolator, LinearInterpolator};
+use crate::time::{DayCountConvention, Frequency};
 use std::{collections::BTreeMap, time::Duration};
 use time::Date;
 
@@ -48,7 +49,9 @@ pub trait Curve {
     fn update_rate(&mut self, date: Date, rate: f64);
 
     /// Create a new curve from a set of dates and rates.
-    fn from_dates_and_rates(dates: &[Date], rates: &[f64]) -> Self;
+    fn from_dates_and_rates(dates: &[Date], rates: &[f64]) -> Self
+    where
+        Self: Sized;
 
     /// Create a new curve from an initial date, a set of rates, and a set of
     /// durations.
@@ -61,18 +64,21 @@ pub trait Curve {
         initial_date: Date,
         rates: &[f64],
         durations: &[Duration],
-    ) -> Self;
+    ) -> Self
+    where
+        Self: Sized;
 
     /// Function to find the interval of dates that contains the given date.
     /// The interval is defined by the two dates that are closest to the given
     /// date, just before and just after.
     fn find_date_interval(&self, date: Date) -> (Date, Date);
 
-    /// Returns the rate for the given date, using linear interpolation for
-    /// dates between the curve's initial and terminal dates.
-    /// If the date is outside the curve's range, we panic.
+    /// Returns the rate for the given date, interpolating between the
+    /// curve's points for dates between its initial and terminal dates.
+    /// Outside the curve's range, the rate is extrapolated flat from the
+    /// nearest end point.
     ///
-    /// We use the following formula for the interpolation:
+    /// For linear interpolation we have the formula:
     ///
     /// $$
     /// y = \frac{y_0 (x_1 - x) + y_1 (x - x_0)}{x_1 - x_0}
@@ -109,16 +115,40 @@ pub trait Curve {
 
 #[allow(clippy::module_name_repetitions)]
 /// Yield curve struct.
-pub struct YieldCurve {
+///
+/// The curve is generic over the interpolation scheme used between its
+/// points, which defaults to linear interpolation of the zero rates.
+#[derive(Debug, Clone)]
+pub struct YieldCurve<I: Interpolator<f64, f64> = LinearInterpolator<f64, f64>> {
     /// Map of dates and rates.
     /// The dates are the keys and the rates are the values.
     /// The reason for using a [BTreeMap] is that it is sorted by date,
     /// which makes sense for a term structure.
+    ///
+    /// Note: use [`update_rate`](Curve::update_rate) to modify the rates,
+    /// so that the interpolator is re-fitted.
     pub rates: BTreeMap<Date, f64>,
+
+    /// Interpolator fitted to the rates (indexed by Julian day).
+    pub interpolator: I,
     // /// A model for the curve.
     // pub model: Option<M>,
 }
 
+/// Compounding convention used to convert between zero rates and
+/// discount factors.
+#[derive(Debug, Clone, Copy)]
+pub enum Compounding {
+    /// Simple interest: $P(t) = 1 / (1 + r t)$.
+    Simple,
+
+    /// Discrete compounding $n$ times per year: $P(t) = (1 + r / n)^{-n t}$.
+    Compounded(Frequency),
+
+    /// Continuous compounding: $P(t) = e^{-r t}$.
+    Continuous,
+}
+
 /// Curve error enum.
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone, Copy)]
@@ -134,15 +164,213 @@ pub enum CurveError {
 // Implementations, functions, and macros
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+impl Compounding {
+    /// Discount factor implied by the zero rate `rate` over a year fraction `t`.
+    #[must_use]
+    pub fn discount_factor(&self, rate: f64, t: f64) -> f64 {
+        match self {
+            Self::Simple => 1.0 / (1.0 + rate * t),
+            Self::Compounded(frequency) => {
+                let n = frequency.times_in_year() as f64;
+
+                (1.0 + rate / n).powf(-n * t)
+            }
+            Self::Continuous => f64::exp(-rate * t),
+        }
+    }
+
+    /// Zero rate implied by the discount factor `df` over a year fraction `t`.
+    /// This is the inverse of [`discount_factor`](Compounding::discount_factor),
+    /// and is undefined for `t = 0`.
+    #[must_use]
+    pub fn zero_rate(&self, df: f64, t: f64) -> f64 {
+        match self {
+            Self::Simple => (1.0 / df - 1.0) / t,
+            Self::Compounded(frequency) => {
+                let n = frequency.times_in_year() as f64;
+
+                n * (df.powf(-1.0 / (n * t)) - 1.0)
+            }
+            Self::Continuous => -df.ln() / t,
+        }
+    }
+}
+
 impl YieldCurve {
-    /// Creates a new yield curve.
+    /// Creates a new yield curve, with linear interpolation of the rates.
     #[must_use]
     pub fn new(rates: BTreeMap<Date, f64>) -> Self {
-        Self { rates }
+        Self::interpolated(rates)
+    }
+
+    /// Creates a new linearly-interpolated yield curve from a set of dates
+    /// and rates. See [`Curve::from_dates_and_rates`] for other interpolators.
+    #[must_use]
+    pub fn from_dates_and_rates(dates: &[Date], rates: &[f64]) -> Self {
+        <Self as Curve>::from_dates_and_rates(dates, rates)
+    }
+}
+
+impl<I: Interpolator<f64, f64> + Default> YieldCurve<I> {
+    /// Creates a new yield curve, interpolating the rates with `I`.
+    #[must_use]
+    pub fn interpolated(rates: BTreeMap<Date, f64>) -> Self {
+        let mut curve = Self {
+            rates,
+            interpolator: I::default(),
+        };
+        curve.fit_interpolator();
+
+        curve
+    }
+
+    /// Re-fit the interpolator to the curve's points.
+    fn fit_interpolator(&mut self) {
+        if self.rates.len() < 2 {
+            return;
+        }
+
+        let xs: Vec<f64> = self
+            .rates
+            .keys()
+            .map(|d| d.to_julian_day() as f64)
+            .collect();
+        let ys: Vec<f64> = self.rates.values().copied().collect();
+
+        // The dates are unique and sorted, so the knots are always valid.
+        self.interpolator = fitted_interpolator(&xs, &ys)
+            .expect("Curve points should always be valid interpolation knots.");
+    }
+
+    /// Returns the continuously-compounded forward rate between two dates,
+    /// implied by the curve's discount factors:
+    ///
+    /// $$
+    /// f(t_1, t_2) = \frac{\ln P(t_1) - \ln P(t_2)}{t_2 - t_1}
+    /// $$
+    pub fn forward_rate(&self, start: Date, end: Date) -> f64 {
+        let tau = DayCountConvention::default().day_count_factor(start, end);
+
+        (self.discount_factor(start).ln() - self.discount_factor(end).ln()) / tau
+    }
+
+    /// Returns the continuously-compounded zero rate at time `t`, in years
+    /// (Actual/365 Fixed) from the curve's initial date. As for
+    /// [`rate`](Curve::rate), the rate is extrapolated flat outside the curve.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the curve has no points.
+    pub fn zero_rate_at(&self, t: f64) -> f64 {
+        match self.rates.len() {
+            0 => panic!("The curve has no points."),
+            1 => *self.rates.values().next().unwrap(),
+            _ => {
+                let (start, end) = self.interpolator.range();
+                let x = self.initial_date().to_julian_day() as f64 + 365.0 * t;
+
+                self.interpolator
+                    .interpolate(x.clamp(start, end))
+                    .expect("Times clamped to the curve's range can be interpolated.")
+            }
+        }
+    }
+
+    /// Returns the discount factor at time `t` (see [`zero_rate_at`](YieldCurve::zero_rate_at)):
+    ///
+    /// $$
+    /// P(t) = e^{- r(t) \cdot t}
+    /// $$
+    pub fn discount_factor_at(&self, t: f64) -> f64 {
+        f64::exp(-self.zero_rate_at(t) * t)
+    }
+
+    /// Returns the continuously-compounded forward rate between times `t1`
+    /// and `t2` (see [`zero_rate_at`](YieldCurve::zero_rate_at)):
+    ///
+    /// $$
+    /// f(t_1, t_2) = \frac{r(t_2) t_2 - r(t_1) t_1}{t_2 - t_1}
+    /// $$
+    pub fn forward_rate_at(&self, t1: f64, t2: f64) -> f64 {
+        (self.zero_rate_at(t2) * t2 - self.zero_rate_at(t1) * t1) / (t2 - t1)
+    }
+
+    /// Converts the curve's points to discount factors, treating the rates
+    /// as zero rates with the given compounding and day count convention,
+    /// measured from the curve's initial date.
+    pub fn to_discount_factors(
+        &self,
+        compounding: Compounding,
+        day_count_convention: DayCountConvention,
+    ) -> BTreeMap<Date, f64> {
+        let initial_date = self.initial_date();
+
+        self.rates
+            .iter()
+            .map(|(date, rate)| {
+                let t = day_count_convention.day_count_factor(initial_date, *date);
+
+                (*date, compounding.discount_factor(*rate, t))
+            })
+            .collect()
+    }
+
+    /// Creates a yield curve from a map of discount factors, converting them
+    /// to zero rates with the given compounding and day count convention,
+    /// measured from the earliest date.
+    ///
+    /// The zero rate is undefined where no time has passed (at the earliest
+    /// date, whose discount factor is one), so there it is extrapolated
+    /// linearly in time from the first two points after it (or flat, if
+    /// there is only one). Every other point keeps its own zero rate.
+    #[must_use]
+    pub fn from_discount_factors(
+        discount_factors: &BTreeMap<Date, f64>,
+        compounding: Compounding,
+        day_count_convention: DayCountConvention,
+    ) -> Self {
+        let Some(initial_date) = discount_factors.keys().next().copied() else {
+            return Self::interpolated(BTreeMap::new());
+        };
+
+        let times: BTreeMap<Date, f64> = discount_factors
+            .keys()
+            .map(|date| {
+                (
+                    *date,
+                    day_count_convention.day_count_factor(initial_date, *date),
+                )
+            })
+            .collect();
+
+        let mut rates: BTreeMap<Date, f64> = discount_factors
+            .iter()
+            .filter(|(date, _)| times[*date] > 0.0)
+            .map(|(date, df)| (*date, compounding.zero_rate(*df, times[date])))
+            .collect();
+
+        let short_end: Vec<(f64, f64)> = rates
+            .iter()
+            .take(2)
+            .map(|(date, rate)| (times[date], *rate))
+            .collect();
+        let short_rate = match short_end[..] {
+            [(t_1, r_1), (t_2, r_2)] => r_1 - (r_2 - r_1) * t_1 / (t_2 - t_1),
+            [(_, r_1)] => r_1,
+            _ => 0.0,
+        };
+
+        for (date, t) in &times {
+            if *t <= 0.0 {
+                rates.insert(*date, short_rate);
+            }
+        }
+
+        Self::interpolated(rates)
     }
 }
 
-impl Curve for YieldCurve {
+impl<I: Interpolator<f64, f64> + Default> Curve for YieldCurve<I> {
     fn initial_date(&self) -> Date {
         *self.rates.keys().min().unwrap()
     }
@@ -154,6 +382,7 @@ impl Curve for YieldCurve {
     #[allow(clippy::similar_names)]
     fn update_rate(&mut self, date: Date, rate: f64) {
         self.rates.insert(date, rate);
+        self.fit_interpolator();
     }
 
     #[allow(clippy::similar_names)]
@@ -164,7 +393,7 @@ impl Curve for YieldCurve {
             rates_map.insert(*date, *rate);
         }
 
-        Self { rates: rates_map }
+        Self::interpolated(rates_map)
     }
 
     #[allow(clippy::similar_names)]
@@ -189,10 +418,11 @@ impl Curve for YieldCurve {
             0 => panic!("The curve has no points."),
             1 => *self.rates.values().next().unwrap(),
             _ => {
-                let (x0, x1) = self.find_date_interval(date);
-                let (y0, y1) = (*self.rates.get(&x0).unwrap(), *self.rates.get(&x1).unwrap());
+                let date = date.clamp(self.initial_date(), self.terminal_date());
 
-                (y0 * (x1 - date) + y1 * (date - x0)) / (x1 - x0)
+                self.interpolator
+                    .interpolate(date.to_julian_day() as f64)
+                    .expect("Dates clamped to the curve's range can be interpolated.")
             }
         }
     }
@@ -217,6 +447,7 @@ impl Curve for YieldCurve {
 mod tests_curves {
     use super::*;
     use crate::time::today;
+    use crate::{assert_approx_equal, RUSTQUANT_EPSILON};
     use std::collections::BTreeMap;
     use time::Duration;
     use time::OffsetDateTime;
@@ -333,4 +564,179 @@ mod tests_curves {
 
         assert!(df1 > df2 && df2 > df3);
     }
+
+    #[test]
+    fn test_yield_curve_interpolators() {
+        use crate::math::interpolation::{CubicSpline, HermiteMonotone};
+
+        let t0 = OffsetDateTime::UNIX_EPOCH.date();
+        let dates = [
+            t0,
+            t0 + Duration::days(180),
+            t0 + Duration::days(365),
+            t0 + Duration::days(730),
+            t0 + Duration::days(1825),
+        ];
+        let curved = [0.010, 0.025, 0.030, 0.034, 0.036];
+
+        let linear =
+            YieldCurve::<LinearInterpolator<f64, f64>>::from_dates_and_rates(&dates, &curved);
+        let spline = YieldCurve::<CubicSpline>::from_dates_and_rates(&dates, &curved);
+        let hermite = YieldCurve::<HermiteMonotone>::from_dates_and_rates(&dates, &curved);
+
+        // All interpolators reproduce the knots exactly.
+        for (date, rate) in dates.iter().zip(curved.iter()) {
+            assert_approx_equal!(linear.rate(*date), *rate, RUSTQUANT_EPSILON);
+            assert_approx_equal!(spline.rate(*date), *rate, RUSTQUANT_EPSILON);
+            assert_approx_equal!(hermite.rate(*date), *rate, RUSTQUANT_EPSILON);
+        }
+
+        // Between knots, the concave zero curve lies above its chords,
+        // so the smooth interpolators give higher forward rates early on.
+        let (start, end) = (t0 + Duration::days(400), t0 + Duration::days(500));
+
+        assert!(spline.forward_rate(start, end) > linear.forward_rate(start, end));
+        assert!(hermite.forward_rate(start, end) > linear.forward_rate(start, end));
+
+        // With linear zero rates, every scheme gives the same forward rates.
+        let straight: Vec<f64> = dates
+            .iter()
+            .map(|d| 0.01 + 0.00001 * (*d - t0).whole_days() as f64)
+            .collect();
+
+        let linear =
+            YieldCurve::<LinearInterpolator<f64, f64>>::from_dates_and_rates(&dates, &straight);
+        let spline = YieldCurve::<CubicSpline>::from_dates_and_rates(&dates, &straight);
+        let hermite = YieldCurve::<HermiteMonotone>::from_dates_and_rates(&dates, &straight);
+
+        assert_approx_equal!(
+            spline.forward_rate(start, end),
+            linear.forward_rate(start, end),
+            1e-10
+        );
+        assert_approx_equal!(
+            hermite.forward_rate(start, end),
+            linear.forward_rate(start, end),
+            1e-10
+        );
+    }
+
+    #[test]
+    fn test_rate_extrapolates_flat() {
+        let t0 = OffsetDateTime::UNIX_EPOCH.date();
+        let curve = YieldCurve::from_dates_and_rates(
+            &[t0 + Duration::days(30), t0 + Duration::days(60)],
+            &[0.025, 0.03],
+        );
+
+        assert_approx_equal!(curve.rate(t0), 0.025, RUSTQUANT_EPSILON);
+        assert_approx_equal!(
+            curve.rate(t0 + Duration::days(365)),
+            0.03,
+            RUSTQUANT_EPSILON
+        );
+    }
+
+    #[test]
+    fn test_discount_factor_round_trip() {
+        let t0 = OffsetDateTime::UNIX_EPOCH.date();
+        let dates = [
+            t0,
+            t0 + Duration::days(90),
+            t0 + Duration::days(365),
+            t0 + Duration::days(1095),
+        ];
+        for compounding in [
+            Compounding::Simple,
+            Compounding::Compounded(Frequency::SemiAnnually),
+            Compounding::Continuous,
+        ] {
+            for dcc in [
+                DayCountConvention::Actual_365_Fixed,
+                DayCountConvention::Thirty_360_ISDA,
+            ] {
+                // Strictly upward sloping zero rates, linear in time.
+                let rates: Vec<f64> = dates
+                    .iter()
+                    .map(|date| 0.015 + 0.005 * dcc.day_count_factor(t0, *date))
+                    .collect();
+                let curve = YieldCurve::from_dates_and_rates(&dates, &rates);
+
+                let discount_factors = curve.to_discount_factors(compounding, dcc);
+
+                // Zero-time discount factor is one.
+                assert_approx_equal!(discount_factors[&t0], 1.0, RUSTQUANT_EPSILON);
+
+                let recovered = YieldCurve::<LinearInterpolator<f64, f64>>::from_discount_factors(
+                    &discount_factors,
+                    compounding,
+                    dcc,
+                );
+
+                for (date, rate) in &curve.rates {
+                    assert_approx_equal!(recovered.rates[date], *rate, 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_time_based_rates() {
+        use crate::data::{LogLinearDiscount, MonotoneConvex};
+
+        let t0 = OffsetDateTime::UNIX_EPOCH.date();
+        let dates = [
+            t0,
+            t0 + Duration::days(365),
+            t0 + Duration::days(730),
+            t0 + Duration::days(1825),
+        ];
+        let rates = [0.02, 0.02, 0.03, 0.035];
+
+        let linear = YieldCurve::from_dates_and_rates(&dates, &rates);
+        let log_linear = YieldCurve::<LogLinearDiscount>::from_dates_and_rates(&dates, &rates);
+        let convex = YieldCurve::<MonotoneConvex>::from_dates_and_rates(&dates, &rates);
+
+        assert_approx_equal!(linear.zero_rate_at(2.0), 0.03, RUSTQUANT_EPSILON);
+        assert_approx_equal!(linear.zero_rate_at(1.5), 0.025, RUSTQUANT_EPSILON);
+        assert_approx_equal!(
+            linear.discount_factor_at(2.0),
+            f64::exp(-0.06),
+            RUSTQUANT_EPSILON
+        );
+
+        // Log-linear discount factors: the forward rate is flat between pillars.
+        assert_approx_equal!(log_linear.forward_rate_at(1.0, 2.0), 0.04, 1e-12);
+        assert_approx_equal!(log_linear.forward_rate_at(1.2, 1.7), 0.04, 1e-12);
+        assert_approx_equal!(
+            log_linear.discount_factor_at(1.5),
+            f64::exp(-0.02 - 0.5 * 0.04),
+            1e-12
+        );
+
+        // Monotone convex preserves the discrete forwards, but not locally.
+        assert_approx_equal!(convex.forward_rate_at(1.0, 2.0), 0.04, 1e-12);
+        assert!((convex.forward_rate_at(1.0, 1.1) - 0.04).abs() > 1e-4);
+
+        // Flat extrapolation of the zero rate beyond the last pillar.
+        assert_approx_equal!(convex.zero_rate_at(10.0), 0.035, RUSTQUANT_EPSILON);
+    }
+
+    #[test]
+    fn test_compounding_conventions() {
+        // 5% annually compounded over two years.
+        let annual = Compounding::Compounded(Frequency::Annually);
+
+        assert_approx_equal!(annual.discount_factor(0.05, 2.0), 1.0 / 1.1025, 1e-12);
+        assert_approx_equal!(
+            Compounding::Simple.discount_factor(0.05, 2.0),
+            1.0 / 1.1,
+            1e-12
+        );
+        assert_approx_equal!(
+            Compounding::Continuous.zero_rate(f64::exp(-0.1), 2.0),
+            0.05,
+            1e-12
+        );
+    }
 }
@@ -0,0 +1,478 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Zero rate interpolation schemes for yield curves.
+//!
+//! These interpolators take zero rates $r_i$ at times $x_i$, and interpolate
+//! the integrated forward rate $r(x) (x - x_0)$ from the first knot, i.e.
+//! minus the log discount factor, rather than the zero rates themselves:
+//!
+//! - [`LogLinearDiscount`]: the log discount factors are linear, so the
+//!   forward rates are flat between the knots.
+//! - [`MonotoneConvex`]: the monotone convex scheme of Hagan and West (2006),
+//!   with continuous forward rates that preserve the discrete forwards.
+//!
+//! Both reproduce the knots, and can be used as the interpolator of a
+//! [`YieldCurve`](crate::data::YieldCurve).
+
+use crate::math::interpolation::{
+    check_knots, insert_knot, locate, sorted_knots, InterpolationError, Interpolator,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Log-linear discount factor interpolator (flat forward rates).
+#[derive(Debug, Clone, Default)]
+pub struct LogLinearDiscount {
+    /// X-axis values (times) for the interpolator.
+    pub xs: Vec<f64>,
+
+    /// Y-axis values (zero rates) for the interpolator.
+    pub ys: Vec<f64>,
+
+    /// Whether the interpolator has been fitted.
+    pub fitted: bool,
+}
+
+/// Monotone convex interpolator of Hagan and West.
+#[derive(Debug, Clone, Default)]
+pub struct MonotoneConvex {
+    /// X-axis values (times) for the interpolator.
+    pub xs: Vec<f64>,
+
+    /// Y-axis values (zero rates) for the interpolator.
+    pub ys: Vec<f64>,
+
+    /// Whether the interpolator has been fitted.
+    pub fitted: bool,
+
+    /// Discrete forward rates of the intervals.
+    discrete_forwards: Vec<f64>,
+
+    /// Instantaneous forward rates at the knots.
+    forwards: Vec<f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Integrated forward rates $r_i (x_i - x_0)$ at the knots.
+fn integrated_forwards(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    xs.iter().zip(ys).map(|(x, y)| y * (x - xs[0])).collect()
+}
+
+impl LogLinearDiscount {
+    /// Create a new (unfitted) log-linear discount interpolator through the
+    /// knots `(xs, ys)`.
+    ///
+    /// # Errors
+    /// - `InterpolationError::UnequalLength` if ```xs.length() != ys.length()```.
+    pub fn new(xs: Vec<f64>, ys: Vec<f64>) -> Result<Self, InterpolationError> {
+        let (xs, ys) = sorted_knots(xs, ys)?;
+
+        Ok(Self {
+            xs,
+            ys,
+            fitted: false,
+        })
+    }
+}
+
+impl Interpolator<f64, f64> for LogLinearDiscount {
+    fn fit(&mut self) -> Result<(), InterpolationError> {
+        check_knots(&self.xs)?;
+
+        self.fitted = true;
+
+        Ok(())
+    }
+
+    fn interpolate(&self, point: f64) -> Result<f64, InterpolationError> {
+        if !self.fitted {
+            return Err(InterpolationError::Unfitted);
+        }
+
+        let i = locate(&self.xs, point)?;
+
+        if point == self.xs[0] {
+            return Ok(self.ys[0]);
+        }
+
+        let (x_0, x_1) = (self.xs[i] - self.xs[0], self.xs[i + 1] - self.xs[0]);
+        let weight = (point - self.xs[i]) / (self.xs[i + 1] - self.xs[i]);
+
+        Ok(
+            ((1.0 - weight) * self.ys[i] * x_0 + weight * self.ys[i + 1] * x_1)
+                / (point - self.xs[0]),
+        )
+    }
+
+    /// The zero rate $r(x) = I(x) / (x - x_0)$ of the integrated forward
+    /// rate $I$ has the derivative $(f(x) - r(x)) / (x - x_0)$, with the
+    /// flat forward rate $f$ of the interval. It is flat on the first interval.
+    fn derivative(&self, point: f64) -> Result<f64, InterpolationError> {
+        let rate = self.interpolate(point)?;
+        let i = locate(&self.xs, point)?;
+
+        if point == self.xs[0] {
+            return Ok(0.0);
+        }
+
+        let x_0 = self.xs[0];
+        let forward = (self.ys[i + 1] * (self.xs[i + 1] - x_0) - self.ys[i] * (self.xs[i] - x_0))
+            / (self.xs[i + 1] - self.xs[i]);
+
+        Ok((forward - rate) / (point - x_0))
+    }
+
+    fn range(&self) -> (f64, f64) {
+        (*self.xs.first().unwrap(), *self.xs.last().unwrap())
+    }
+
+    fn add_point(&mut self, point: (f64, f64)) {
+        insert_knot(&mut self.xs, &mut self.ys, point);
+        self.fitted = false;
+    }
+}
+
+impl MonotoneConvex {
+    /// Create a new (unfitted) monotone convex interpolator through the
+    /// knots `(xs, ys)`.
+    ///
+    /// # Errors
+    /// - `InterpolationError::UnequalLength` if ```xs.length() != ys.length()```.
+    pub fn new(xs: Vec<f64>, ys: Vec<f64>) -> Result<Self, InterpolationError> {
+        let (xs, ys) = sorted_knots(xs, ys)?;
+
+        Ok(Self {
+            xs,
+            ys,
+            ..Self::default()
+        })
+    }
+
+    /// Returns the instantaneous forward rate at `x`.
+    ///
+    /// # Errors
+    /// - `InterpolationError::Unfitted` if the interpolator has not been fitted.
+    /// - `InterpolationError::OutsideOfRange` if `x` is outside the knots.
+    pub fn forward_rate(&self, x: f64) -> Result<f64, InterpolationError> {
+        let (i, s) = self.interval(x)?;
+        let (g_0, g_1) = self.boundary_values(i);
+
+        Ok(self.discrete_forwards[i] + Self::g(g_0, g_1, s))
+    }
+
+    /// Index of the interval containing `x`, and the position of `x` within it.
+    fn interval(&self, x: f64) -> Result<(usize, f64), InterpolationError> {
+        if !self.fitted {
+            return Err(InterpolationError::Unfitted);
+        }
+
+        let i = locate(&self.xs, x)?;
+
+        Ok((i, (x - self.xs[i]) / (self.xs[i + 1] - self.xs[i])))
+    }
+
+    /// Deviations of the knot forwards from the discrete forward of interval `i`.
+    fn boundary_values(&self, i: usize) -> (f64, f64) {
+        (
+            self.forwards[i] - self.discrete_forwards[i],
+            self.forwards[i + 1] - self.discrete_forwards[i],
+        )
+    }
+
+    /// Deviation $g(s)$ of the forward rate from the discrete forward, its
+    /// integral $G(s) = \int_0^s g(u) du$, which vanishes at $s = 1$, and its
+    /// slope $g'(s)$.
+    #[allow(clippy::similar_names)]
+    fn deviation(g_0: f64, g_1: f64, s: f64) -> (f64, f64, f64) {
+        // Integral of c ((eta - u) / eta)^2 over [0, min(s, eta)].
+        let left = |c: f64, eta: f64| {
+            let m = s.min(eta);
+            c * (eta - (eta - m).powi(3) / (eta * eta)) / 3.0
+        };
+        // Integral of c ((u - eta) / (1 - eta))^2 over [eta, max(s, eta)].
+        let right = |c: f64, eta: f64| {
+            let m = s.max(eta);
+            c * (m - eta).powi(3) / (3.0 * (1.0 - eta).powi(2))
+        };
+
+        if g_0 == 0.0 && g_1 == 0.0 {
+            (0.0, 0.0, 0.0)
+        } else if (g_0 < 0.0 && -0.5 * g_0 <= g_1 && g_1 <= -2.0 * g_0)
+            || (g_0 > 0.0 && -0.5 * g_0 >= g_1 && g_1 >= -2.0 * g_0)
+        {
+            // Region (i): a quadratic.
+            let g = g_0 * (1.0 - 4.0 * s + 3.0 * s * s) + g_1 * (-2.0 * s + 3.0 * s * s);
+            let integral = g_0 * (s - 2.0 * s * s + s.powi(3)) + g_1 * (-s * s + s.powi(3));
+            let slope = g_0 * (-4.0 + 6.0 * s) + g_1 * (-2.0 + 6.0 * s);
+
+            (g, integral, slope)
+        } else if (g_0 < 0.0 && g_1 > -2.0 * g_0) || (g_0 > 0.0 && g_1 < -2.0 * g_0) {
+            // Region (ii): flat, then a quadratic to the right end.
+            let eta = (g_1 + 2.0 * g_0) / (g_1 - g_0);
+            let (g, slope) = if s <= eta {
+                (g_0, 0.0)
+            } else {
+                (
+                    g_0 + (g_1 - g_0) * ((s - eta) / (1.0 - eta)).powi(2),
+                    2.0 * (g_1 - g_0) * (s - eta) / (1.0 - eta).powi(2),
+                )
+            };
+
+            (g, g_0 * s + right(g_1 - g_0, eta), slope)
+        } else if (g_0 > 0.0 && 0.0 > g_1 && g_1 > -0.5 * g_0)
+            || (g_0 < 0.0 && 0.0 < g_1 && g_1 < -0.5 * g_0)
+        {
+            // Region (iii): a quadratic from the left end, then flat.
+            let eta = 3.0 * g_1 / (g_1 - g_0);
+            let (g, slope) = if s < eta {
+                (
+                    g_1 + (g_0 - g_1) * ((eta - s) / eta).powi(2),
+                    -2.0 * (g_0 - g_1) * (eta - s) / (eta * eta),
+                )
+            } else {
+                (g_1, 0.0)
+            };
+
+            (g, g_1 * s + left(g_0 - g_1, eta), slope)
+        } else {
+            // Region (iv): two quadratics meeting at their minimum (or maximum).
+            let eta = g_1 / (g_1 + g_0);
+            let a = -g_0 * g_1 / (g_0 + g_1);
+            let (g, slope) = if s <= eta {
+                (
+                    a + (g_0 - a) * ((eta - s) / eta).powi(2),
+                    -2.0 * (g_0 - a) * (eta - s) / (eta * eta),
+                )
+            } else {
+                (
+                    a + (g_1 - a) * ((s - eta) / (1.0 - eta)).powi(2),
+                    2.0 * (g_1 - a) * (s - eta) / (1.0 - eta).powi(2),
+                )
+            };
+
+            (g, a * s + left(g_0 - a, eta) + right(g_1 - a, eta), slope)
+        }
+    }
+
+    /// Deviation $g(s)$ of the forward rate from the discrete forward.
+    fn g(g_0: f64, g_1: f64, s: f64) -> f64 {
+        Self::deviation(g_0, g_1, s).0
+    }
+}
+
+impl Interpolator<f64, f64> for MonotoneConvex {
+    fn fit(&mut self) -> Result<(), InterpolationError> {
+        check_knots(&self.xs)?;
+
+        let xs = &self.xs;
+        let n = xs.len() - 1;
+        let integrated = integrated_forwards(xs, &self.ys);
+
+        let discrete_forwards: Vec<f64> = (0..n)
+            .map(|i| (integrated[i + 1] - integrated[i]) / (xs[i + 1] - xs[i]))
+            .collect();
+
+        let mut forwards = vec![discrete_forwards[0]; n + 1];
+
+        for i in 1..n {
+            forwards[i] = ((xs[i] - xs[i - 1]) * discrete_forwards[i]
+                + (xs[i + 1] - xs[i]) * discrete_forwards[i - 1])
+                / (xs[i + 1] - xs[i - 1]);
+        }
+
+        if n > 1 {
+            forwards[0] = discrete_forwards[0] - 0.5 * (forwards[1] - discrete_forwards[0]);
+            forwards[n] =
+                discrete_forwards[n - 1] - 0.5 * (forwards[n - 1] - discrete_forwards[n - 1]);
+        }
+
+        self.discrete_forwards = discrete_forwards;
+        self.forwards = forwards;
+        self.fitted = true;
+
+        Ok(())
+    }
+
+    fn interpolate(&self, point: f64) -> Result<f64, InterpolationError> {
+        let (i, s) = self.interval(point)?;
+
+        if point == self.xs[0] {
+            return Ok(self.ys[0]);
+        }
+
+        let (g_0, g_1) = self.boundary_values(i);
+        let h = self.xs[i + 1] - self.xs[i];
+
+        let integrated = self.ys[i] * (self.xs[i] - self.xs[0])
+            + h * (self.discrete_forwards[i] * s + Self::deviation(g_0, g_1, s).1);
+
+        Ok(integrated / (point - self.xs[0]))
+    }
+
+    /// The zero rate $r(x) = I(x) / (x - x_0)$ of the integrated forward
+    /// rate $I$ has the derivative $(f(x) - r(x)) / (x - x_0)$, with the
+    /// instantaneous forward rate $f$, and $f'(x_0) / 2$ at the first knot.
+    fn derivative(&self, point: f64) -> Result<f64, InterpolationError> {
+        let (i, s) = self.interval(point)?;
+        let (g_0, g_1) = self.boundary_values(i);
+        let (g, _, slope) = Self::deviation(g_0, g_1, s);
+
+        if point == self.xs[0] {
+            return Ok(0.5 * slope / (self.xs[1] - self.xs[0]));
+        }
+
+        let forward = self.discrete_forwards[i] + g;
+
+        Ok((forward - self.interpolate(point)?) / (point - self.xs[0]))
+    }
+
+    fn range(&self) -> (f64, f64) {
+        (*self.xs.first().unwrap(), *self.xs.last().unwrap())
+    }
+
+    fn add_point(&mut self, point: (f64, f64)) {
+        insert_knot(&mut self.xs, &mut self.ys, point);
+        self.fitted = false;
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_curve_interpolation {
+    use super::*;
+    use crate::assert_approx_equal;
+
+    const XS: [f64; 5] = [0.0, 0.5, 1.0, 2.0, 5.0];
+    const YS: [f64; 5] = [0.030, 0.030, 0.034, 0.031, 0.040];
+
+    #[test]
+    fn test_log_linear_discount_flat_forwards() {
+        let mut interpolator = LogLinearDiscount::new(XS.to_vec(), YS.to_vec()).unwrap();
+        interpolator.fit().unwrap();
+
+        for (x, y) in XS.iter().zip(YS.iter()) {
+            assert_approx_equal!(interpolator.interpolate(*x).unwrap(), *y, 1e-14);
+        }
+
+        // The forward rate is the discrete forward anywhere in (1, 2).
+        let forward = |a: f64, b: f64| {
+            let (r_a, r_b) = (
+                interpolator.interpolate(a).unwrap(),
+                interpolator.interpolate(b).unwrap(),
+            );
+            (r_b * b - r_a * a) / (b - a)
+        };
+
+        assert_approx_equal!(forward(1.2, 1.4), forward(1.0, 2.0), 1e-12);
+        assert_approx_equal!(forward(1.0, 2.0), 0.031 * 2.0 - 0.034, 1e-12);
+    }
+
+    #[test]
+    fn test_monotone_convex_preserves_discrete_forwards() {
+        let mut interpolator = MonotoneConvex::new(XS.to_vec(), YS.to_vec()).unwrap();
+        interpolator.fit().unwrap();
+
+        for (x, y) in XS.iter().zip(YS.iter()) {
+            assert_approx_equal!(interpolator.interpolate(*x).unwrap(), *y, 1e-14);
+        }
+
+        // Average of the instantaneous forwards over each interval.
+        for w in XS.windows(2) {
+            let n = 1000;
+            let h = (w[1] - w[0]) / n as f64;
+            let average = (0..n)
+                .map(|k| {
+                    interpolator
+                        .forward_rate(w[0] + (k as f64 + 0.5) * h)
+                        .unwrap()
+                })
+                .sum::<f64>()
+                / n as f64;
+
+            let integral = |x: f64| interpolator.interpolate(x).unwrap() * x;
+
+            assert_approx_equal!(
+                average,
+                (integral(w[1]) - integral(w[0])) / (w[1] - w[0]),
+                1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn test_monotone_convex_continuous_forwards() {
+        let mut interpolator = MonotoneConvex::new(XS.to_vec(), YS.to_vec()).unwrap();
+        interpolator.fit().unwrap();
+
+        for x in &XS[1..XS.len() - 1] {
+            let eps = 1e-9;
+
+            assert_approx_equal!(
+                interpolator.forward_rate(x - eps).unwrap(),
+                interpolator.forward_rate(x + eps).unwrap(),
+                1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn test_curve_interpolators_derivative() {
+        let mut log_linear = LogLinearDiscount::new(XS.to_vec(), YS.to_vec()).unwrap();
+        let mut monotone_convex = MonotoneConvex::new(XS.to_vec(), YS.to_vec()).unwrap();
+        log_linear.fit().unwrap();
+        monotone_convex.fit().unwrap();
+
+        let h = 1e-6;
+
+        for x in [0.25, 0.7, 1.5, 3.0, 4.9] {
+            for interpolator in [
+                &log_linear as &dyn Interpolator<f64, f64>,
+                &monotone_convex as &dyn Interpolator<f64, f64>,
+            ] {
+                let numerical = (interpolator.interpolate(x + h).unwrap()
+                    - interpolator.interpolate(x - h).unwrap())
+                    / (2.0 * h);
+
+                assert_approx_equal!(interpolator.derivative(x).unwrap(), numerical, 1e-6);
+            }
+        }
+
+        // The derivative is continuous at the first knot.
+        assert_eq!(log_linear.derivative(0.0), Ok(0.0));
+        assert_approx_equal!(
+            monotone_convex.derivative(0.0).unwrap(),
+            monotone_convex.derivative(1e-7).unwrap(),
+            1e-5
+        );
+    }
+
+    #[test]
+    fn test_curve_interpolators_errors() {
+        let interpolator = MonotoneConvex::new(XS.to_vec(), YS.to_vec()).unwrap();
+        assert_eq!(
+            interpolator.interpolate(1.0),
+            Err(InterpolationError::Unfitted)
+        );
+
+        let mut interpolator = LogLinearDiscount::new(vec![0.0], vec![0.03]).unwrap();
+        assert_eq!(
+            interpolator.fit(),
+            Err(InterpolationError::InsufficientData)
+        );
+    }
+}
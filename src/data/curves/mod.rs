@@ -14,3 +14,15 @@ pub use curve::*;
 /// Term structure data.
 pub mod term_structure;
 pub use term_structure::*;
+
+/// Zero rate interpolation schemes for yield curves.
+pub mod interpolation;
+pub use interpolation::*;
+
+/// Rate helpers for curve bootstrapping.
+pub mod rate_helpers;
+pub use rate_helpers::*;
+
+/// Cross-currency discount curves.
+pub mod cross_currency;
+pub use cross_currency::*;
@@ -0,0 +1,783 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Rate helpers are the market instruments used as inputs when bootstrapping
+//! a yield curve. Each helper knows its quoted rate, the date of its last
+//! cash flow, and how to compute the rate it would have under a given curve.
+//!
+//! The bootstrapper sorts the helpers by maturity and solves, one pillar at
+//! a time, for the zero rate at each maturity such that the helper's implied
+//! quote matches its market quote.
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::data::{Curve, YieldCurve};
+use crate::error::RustQuantError;
+use crate::math::interpolation::Interpolator;
+use crate::math::rootfinding::{BrentSolver, Solver};
+use crate::time::{
+    add_months, advance_business_days, imm_date, Calendar, DateRoller, DateRollingConvention,
+    DayCountConvention, Frequency,
+};
+use std::collections::BTreeMap;
+use time::{Date, Month};
+
+/// Maximum number of passes over the helpers when bootstrapping, for
+/// interpolation schemes where a pillar also moves the curve before it.
+const MAX_BOOTSTRAP_PASSES: usize = 100;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Base trait for curve construction inputs.
+pub trait RateHelper {
+    /// The quoted market rate of the instrument.
+    fn quote(&self) -> f64;
+
+    /// The date of the instrument's last cash flow.
+    /// This is the pillar date the bootstrapper solves for.
+    fn maturity_date(&self) -> Date;
+
+    /// The rate implied by the given curve for this instrument.
+    fn implied_quote(&self, curve: &dyn Curve) -> f64;
+}
+
+/// Money-market deposit rate helper.
+#[derive(Debug, Clone, Copy)]
+pub struct DepositRateHelper {
+    /// Quoted simple deposit rate.
+    pub rate: f64,
+
+    /// Number of business days between the trade date and the start date.
+    /// Deposits fix on the trade date, so these are also its fixing days.
+    pub spot_lag: i64,
+
+    /// Start of the accrual period.
+    pub start_date: Date,
+
+    /// End of the accrual period.
+    pub maturity_date: Date,
+
+    /// Day count convention of the deposit.
+    pub day_count_convention: DayCountConvention,
+}
+
+/// Forward rate agreement (FRA) rate helper.
+#[derive(Debug, Clone, Copy)]
+pub struct FraRateHelper {
+    /// Quoted simple forward rate.
+    pub rate: f64,
+
+    /// Start of the forward accrual period.
+    pub start_date: Date,
+
+    /// End of the forward accrual period.
+    pub maturity_date: Date,
+
+    /// Day count convention of the FRA.
+    pub day_count_convention: DayCountConvention,
+}
+
+/// Interest rate futures rate helper (e.g. three-month SOFR or Euribor futures).
+///
+/// The futures rate implied by the price, less the convexity adjustment, is
+/// bootstrapped as the forward rate over the underlying accrual period.
+#[derive(Debug, Clone, Copy)]
+pub struct FuturesRateHelper {
+    /// Quoted futures price, e.g. 96.25 for a rate of 3.75%.
+    pub price: f64,
+
+    /// Convexity adjustment, subtracted from the futures rate to give the forward rate.
+    pub convexity_adjustment: f64,
+
+    /// Start of the underlying accrual period.
+    pub start_date: Date,
+
+    /// End of the underlying accrual period.
+    pub maturity_date: Date,
+
+    /// Day count convention of the underlying rate.
+    pub day_count_convention: DayCountConvention,
+}
+
+/// Vanilla fixed-for-floating swap rate helper (single curve).
+#[derive(Debug, Clone, Copy)]
+pub struct SwapRateHelper {
+    /// Quoted par swap rate.
+    pub rate: f64,
+
+    /// Number of business days between the trade date and the start date.
+    pub spot_lag: i64,
+
+    /// Start date of the swap.
+    pub start_date: Date,
+
+    /// Maturity date of the swap.
+    pub maturity_date: Date,
+
+    /// Payment frequency of the fixed leg.
+    pub fixed_frequency: Frequency,
+
+    /// Day count convention of the fixed leg.
+    pub day_count_convention: DayCountConvention,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Simply-compounded forward rate between two dates implied by a curve.
+fn simple_forward_rate(
+    curve: &dyn Curve,
+    start: Date,
+    end: Date,
+    day_count_convention: DayCountConvention,
+) -> f64 {
+    let tau = day_count_convention.day_count_factor(start, end);
+
+    (curve.discount_factor(start) / curve.discount_factor(end) - 1.0) / tau
+}
+
+impl DepositRateHelper {
+    /// Create a new deposit rate helper from explicit accrual dates.
+    pub fn new(
+        rate: f64,
+        start_date: Date,
+        maturity_date: Date,
+        day_count_convention: DayCountConvention,
+    ) -> Self {
+        Self {
+            rate,
+            spot_lag: 0,
+            start_date,
+            maturity_date,
+            day_count_convention,
+        }
+    }
+
+    /// Create a new deposit rate helper from a reference (trade) date,
+    /// a spot lag in business days, and a tenor in months.
+    ///
+    /// The start date is the reference date advanced by `spot_lag`
+    /// business days, and the maturity date is the start date plus the
+    /// tenor, rolled with the modified following convention.
+    pub fn with_spot_lag<C: Calendar>(
+        rate: f64,
+        reference_date: Date,
+        spot_lag: i64,
+        tenor_months: i32,
+        calendar: &C,
+        day_count_convention: DayCountConvention,
+    ) -> Self {
+        let start_date = advance_business_days(reference_date, spot_lag, calendar);
+        let maturity_date = calendar.roll_date(
+            add_months(start_date, tenor_months),
+            &DateRollingConvention::ModifiedFollowing,
+        );
+
+        Self {
+            rate,
+            spot_lag,
+            start_date,
+            maturity_date,
+            day_count_convention,
+        }
+    }
+}
+
+impl RateHelper for DepositRateHelper {
+    fn quote(&self) -> f64 {
+        self.rate
+    }
+
+    fn maturity_date(&self) -> Date {
+        self.maturity_date
+    }
+
+    fn implied_quote(&self, curve: &dyn Curve) -> f64 {
+        simple_forward_rate(
+            curve,
+            self.start_date,
+            self.maturity_date,
+            self.day_count_convention,
+        )
+    }
+}
+
+impl FraRateHelper {
+    /// Create a new FRA rate helper.
+    pub fn new(
+        rate: f64,
+        start_date: Date,
+        maturity_date: Date,
+        day_count_convention: DayCountConvention,
+    ) -> Self {
+        Self {
+            rate,
+            start_date,
+            maturity_date,
+            day_count_convention,
+        }
+    }
+}
+
+impl RateHelper for FraRateHelper {
+    fn quote(&self) -> f64 {
+        self.rate
+    }
+
+    fn maturity_date(&self) -> Date {
+        self.maturity_date
+    }
+
+    fn implied_quote(&self, curve: &dyn Curve) -> f64 {
+        simple_forward_rate(
+            curve,
+            self.start_date,
+            self.maturity_date,
+            self.day_count_convention,
+        )
+    }
+}
+
+impl FuturesRateHelper {
+    /// Create a new futures rate helper from explicit accrual dates.
+    pub fn new(
+        price: f64,
+        start_date: Date,
+        maturity_date: Date,
+        day_count_convention: DayCountConvention,
+    ) -> Self {
+        Self {
+            price,
+            convexity_adjustment: 0.0,
+            start_date,
+            maturity_date,
+            day_count_convention,
+        }
+    }
+
+    /// Create a new futures rate helper for a quarterly IMM contract,
+    /// accruing from the IMM date of the contract month to the next one.
+    pub fn imm(
+        price: f64,
+        year: i32,
+        month: Month,
+        day_count_convention: DayCountConvention,
+    ) -> Self {
+        let start_date = imm_date(year, month);
+        let end = add_months(start_date, 3);
+
+        Self::new(
+            price,
+            start_date,
+            imm_date(end.year(), end.month()),
+            day_count_convention,
+        )
+    }
+
+    /// Set the convexity adjustment of the futures rate.
+    #[must_use]
+    pub fn with_convexity_adjustment(mut self, convexity_adjustment: f64) -> Self {
+        self.convexity_adjustment = convexity_adjustment;
+        self
+    }
+}
+
+impl RateHelper for FuturesRateHelper {
+    /// Forward rate implied by the futures price:
+    ///
+    /// $$
+    /// F = 1 - \frac{P}{100} - c
+    /// $$
+    fn quote(&self) -> f64 {
+        1.0 - self.price / 100.0 - self.convexity_adjustment
+    }
+
+    fn maturity_date(&self) -> Date {
+        self.maturity_date
+    }
+
+    fn implied_quote(&self, curve: &dyn Curve) -> f64 {
+        simple_forward_rate(
+            curve,
+            self.start_date,
+            self.maturity_date,
+            self.day_count_convention,
+        )
+    }
+}
+
+impl SwapRateHelper {
+    /// Create a new swap rate helper from explicit start and maturity dates.
+    pub fn new(
+        rate: f64,
+        start_date: Date,
+        maturity_date: Date,
+        fixed_frequency: Frequency,
+        day_count_convention: DayCountConvention,
+    ) -> Self {
+        Self {
+            rate,
+            spot_lag: 0,
+            start_date,
+            maturity_date,
+            fixed_frequency,
+            day_count_convention,
+        }
+    }
+
+    /// Create a new swap rate helper from a reference (trade) date,
+    /// a spot lag in business days, and a tenor in years.
+    ///
+    /// The start date is the reference date advanced by `spot_lag`
+    /// business days, and the maturity date is the start date plus the
+    /// tenor, rolled with the modified following convention.
+    pub fn with_spot_lag<C: Calendar>(
+        rate: f64,
+        reference_date: Date,
+        spot_lag: i64,
+        tenor_years: i32,
+        fixed_frequency: Frequency,
+        calendar: &C,
+        day_count_convention: DayCountConvention,
+    ) -> Self {
+        let start_date = advance_business_days(reference_date, spot_lag, calendar);
+        let maturity_date = calendar.roll_date(
+            add_months(start_date, 12 * tenor_years),
+            &DateRollingConvention::ModifiedFollowing,
+        );
+
+        Self {
+            rate,
+            spot_lag,
+            start_date,
+            maturity_date,
+            fixed_frequency,
+            day_count_convention,
+        }
+    }
+
+    /// Fixed leg payment dates, generated backwards from the maturity date
+    /// so that any stub period falls at the front of the swap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixed leg frequency is not a whole number of months.
+    pub fn fixed_leg_dates(&self) -> Vec<Date> {
+        let times_in_year = self.fixed_frequency.times_in_year();
+
+        assert!(
+            times_in_year <= 12 && 12 % times_in_year == 0,
+            "Fixed leg frequency must be a whole number of months."
+        );
+
+        let months = (12 / times_in_year) as i32;
+
+        let mut dates = vec![self.maturity_date];
+        let mut n = 1;
+
+        loop {
+            let date = add_months(self.maturity_date, -months * n);
+
+            if date <= self.start_date {
+                break;
+            }
+
+            dates.push(date);
+            n += 1;
+        }
+
+        dates.reverse();
+        dates
+    }
+}
+
+impl RateHelper for SwapRateHelper {
+    fn quote(&self) -> f64 {
+        self.rate
+    }
+
+    fn maturity_date(&self) -> Date {
+        self.maturity_date
+    }
+
+    /// Par swap rate: the ratio of the floating leg value to the fixed
+    /// leg annuity, both discounted off the same curve:
+    ///
+    /// $$
+    /// S = \frac{P(t_0) - P(t_n)}{\sum_{i=1}^{n} \tau_i P(t_i)}
+    /// $$
+    fn implied_quote(&self, curve: &dyn Curve) -> f64 {
+        let dates = self.fixed_leg_dates();
+
+        let mut annuity = 0.0;
+        let mut previous = self.start_date;
+
+        for date in dates {
+            let tau = self.day_count_convention.day_count_factor(previous, date);
+            annuity += tau * curve.discount_factor(date);
+            previous = date;
+        }
+
+        (curve.discount_factor(self.start_date) - curve.discount_factor(self.maturity_date))
+            / annuity
+    }
+}
+
+impl YieldCurve {
+    /// Bootstrap a yield curve from a set of rate helpers, interpolating
+    /// the zero rates linearly. See [`YieldCurve::bootstrap_interpolated`].
+    ///
+    /// # Errors
+    ///
+    /// As for [`YieldCurve::bootstrap_interpolated`].
+    pub fn bootstrap(
+        reference_date: Date,
+        helpers: &[Box<dyn RateHelper>],
+    ) -> Result<Self, RustQuantError> {
+        Self::bootstrap_interpolated(reference_date, helpers)
+    }
+}
+
+impl<I: Interpolator<f64, f64> + Default> YieldCurve<I> {
+    /// Bootstrap a yield curve from a set of rate helpers, with the
+    /// interpolation scheme `I` (e.g. [`LogLinearDiscount`](crate::data::LogLinearDiscount)
+    /// or [`MonotoneConvex`](crate::data::MonotoneConvex)).
+    ///
+    /// The helpers are sorted by maturity, and the zero rate at each
+    /// maturity is solved for (using Brent's method) such that the helper's
+    /// implied quote reprices its market quote. The curve is flat between
+    /// the reference date and the first pillar.
+    ///
+    /// Where a pillar also moves the curve before it (e.g. monotone convex),
+    /// the pillars are solved again, with the rest of the curve fixed,
+    /// until the zero rates converge.
+    ///
+    /// # Errors
+    ///
+    /// - `RustQuantError::InvalidArgument` if no helpers are given, or if
+    ///   a helper matures on or before the reference date, or if two
+    ///   helpers share a maturity date.
+    /// - `RustQuantError::ComputationError` if a pillar cannot be solved.
+    /// - `RustQuantError::NotConverged` if the zero rates do not converge.
+    pub fn bootstrap_interpolated(
+        reference_date: Date,
+        helpers: &[Box<dyn RateHelper>],
+    ) -> Result<Self, RustQuantError> {
+        if helpers.is_empty() {
+            return Err(RustQuantError::InvalidArgument(
+                "At least one rate helper is required.".to_string(),
+            ));
+        }
+
+        let mut sorted: Vec<&dyn RateHelper> = helpers.iter().map(AsRef::as_ref).collect();
+        sorted.sort_by_key(|helper| helper.maturity_date());
+
+        for pair in sorted.windows(2) {
+            if pair[0].maturity_date() == pair[1].maturity_date() {
+                return Err(RustQuantError::InvalidArgument(format!(
+                    "Multiple rate helpers mature on {}.",
+                    pair[0].maturity_date()
+                )));
+            }
+        }
+
+        if let Some(helper) = sorted.first() {
+            let maturity = helper.maturity_date();
+
+            if maturity <= reference_date {
+                return Err(RustQuantError::InvalidArgument(format!(
+                    "Rate helper matures on {maturity}, on or before the reference date {reference_date}."
+                )));
+            }
+        }
+
+        let mut rates: BTreeMap<Date, f64> = BTreeMap::new();
+
+        for pass in 0..MAX_BOOTSTRAP_PASSES {
+            let previous = rates.clone();
+
+            for (i, helper) in sorted.iter().enumerate() {
+                let maturity = helper.maturity_date();
+
+                let objective = |rate: f64| {
+                    let mut trial = rates.clone();
+                    trial.insert(maturity, rate);
+
+                    // Keep the curve flat before the first pillar.
+                    if i == 0 {
+                        trial.insert(reference_date, rate);
+                    }
+
+                    helper.implied_quote(&Self::interpolated(trial)) - helper.quote()
+                };
+
+                let rate = BrentSolver::default()
+                    .solve(objective, -1.0, 1.0)
+                    .map_err(|_| {
+                        RustQuantError::ComputationError(format!(
+                            "Failed to solve for the zero rate at {maturity}."
+                        ))
+                    })?;
+
+                rates.insert(maturity, rate);
+
+                if i == 0 {
+                    rates.insert(reference_date, rate);
+                }
+            }
+
+            let converged = pass > 0
+                && rates
+                    .iter()
+                    .all(|(date, rate)| (rate - previous[date]).abs() < 1e-12);
+
+            if converged {
+                return Ok(Self::interpolated(rates));
+            }
+        }
+
+        Err(RustQuantError::NotConverged(format!(
+            "Bootstrapped zero rates did not converge in {MAX_BOOTSTRAP_PASSES} passes."
+        )))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_rate_helpers {
+    use super::*;
+    use crate::assert_approx_equal;
+    use time::macros::date;
+
+    fn helpers(reference_date: Date) -> Vec<Box<dyn RateHelper>> {
+        let dcc = DayCountConvention::Actual_360;
+
+        vec![
+            Box::new(SwapRateHelper::new(
+                0.0410,
+                reference_date,
+                date!(2029 - 01 - 02),
+                Frequency::Annually,
+                dcc,
+            )),
+            Box::new(DepositRateHelper::new(
+                0.0350,
+                reference_date,
+                date!(2024 - 04 - 02),
+                dcc,
+            )),
+            Box::new(DepositRateHelper::new(
+                0.0365,
+                reference_date,
+                date!(2024 - 07 - 02),
+                dcc,
+            )),
+            Box::new(SwapRateHelper::new(
+                0.0385,
+                reference_date,
+                date!(2026 - 01 - 02),
+                Frequency::SemiAnnually,
+                dcc,
+            )),
+            Box::new(SwapRateHelper::new(
+                0.0375,
+                reference_date,
+                date!(2025 - 01 - 02),
+                Frequency::Annually,
+                dcc,
+            )),
+        ]
+    }
+
+    #[test]
+    fn test_bootstrap_reprices_helpers() {
+        let reference_date = date!(2024 - 01 - 02);
+        let helpers = helpers(reference_date);
+
+        let curve = YieldCurve::bootstrap(reference_date, &helpers).unwrap();
+
+        // One pillar per helper, plus the reference date.
+        assert_eq!(curve.rates.len(), helpers.len() + 1);
+
+        for helper in &helpers {
+            assert_approx_equal!(helper.implied_quote(&curve), helper.quote(), 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_with_fra() {
+        let reference_date = date!(2024 - 01 - 02);
+        let dcc = DayCountConvention::Actual_360;
+
+        let helpers: Vec<Box<dyn RateHelper>> = vec![
+            Box::new(DepositRateHelper::new(
+                0.0350,
+                reference_date,
+                date!(2024 - 04 - 02),
+                dcc,
+            )),
+            Box::new(FraRateHelper::new(
+                0.0370,
+                date!(2024 - 04 - 02),
+                date!(2024 - 07 - 02),
+                dcc,
+            )),
+        ];
+
+        let curve = YieldCurve::bootstrap(reference_date, &helpers).unwrap();
+
+        for helper in &helpers {
+            assert_approx_equal!(helper.implied_quote(&curve), helper.quote(), 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_interpolation_schemes() {
+        use crate::data::{LogLinearDiscount, MonotoneConvex};
+
+        let reference_date = date!(2024 - 01 - 02);
+        let dcc = DayCountConvention::Actual_360;
+
+        let mut helpers = helpers(reference_date);
+        helpers.push(Box::new(
+            FuturesRateHelper::imm(96.30, 2024, Month::September, dcc)
+                .with_convexity_adjustment(0.0002),
+        ));
+
+        let log_linear =
+            YieldCurve::<LogLinearDiscount>::bootstrap_interpolated(reference_date, &helpers)
+                .unwrap();
+        let convex =
+            YieldCurve::<MonotoneConvex>::bootstrap_interpolated(reference_date, &helpers).unwrap();
+
+        for helper in &helpers {
+            assert_approx_equal!(helper.implied_quote(&log_linear), helper.quote(), 1e-10);
+            assert_approx_equal!(helper.implied_quote(&convex), helper.quote(), 1e-10);
+        }
+
+        // The schemes differ between the pillars.
+        assert!((log_linear.zero_rate_at(0.5) - convex.zero_rate_at(0.5)).abs() > 1e-6);
+    }
+
+    #[test]
+    fn test_futures_rate_helper() {
+        let futures =
+            FuturesRateHelper::imm(96.25, 2024, Month::March, DayCountConvention::Actual_360)
+                .with_convexity_adjustment(0.0005);
+
+        assert_eq!(futures.start_date, date!(2024 - 03 - 20));
+        assert_eq!(futures.maturity_date, date!(2024 - 06 - 19));
+        assert_approx_equal!(futures.quote(), 0.0375 - 0.0005, 1e-15);
+    }
+
+    #[test]
+    fn test_swap_fixed_leg_dates() {
+        let swap = SwapRateHelper::new(
+            0.04,
+            date!(2024 - 01 - 02),
+            date!(2026 - 01 - 02),
+            Frequency::SemiAnnually,
+            DayCountConvention::Actual_360,
+        );
+
+        assert_eq!(
+            swap.fixed_leg_dates(),
+            vec![
+                date!(2024 - 07 - 02),
+                date!(2025 - 01 - 02),
+                date!(2025 - 07 - 02),
+                date!(2026 - 01 - 02),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bootstrap_invalid_inputs() {
+        let reference_date = date!(2024 - 01 - 02);
+        let dcc = DayCountConvention::Actual_360;
+
+        assert!(YieldCurve::bootstrap(reference_date, &[]).is_err());
+
+        let duplicated: Vec<Box<dyn RateHelper>> = vec![
+            Box::new(DepositRateHelper::new(
+                0.035,
+                reference_date,
+                date!(2024 - 04 - 02),
+                dcc,
+            )),
+            Box::new(DepositRateHelper::new(
+                0.036,
+                reference_date,
+                date!(2024 - 04 - 02),
+                dcc,
+            )),
+        ];
+
+        assert!(YieldCurve::bootstrap(reference_date, &duplicated).is_err());
+    }
+
+    #[test]
+    fn test_deposit_spot_lag_over_weekend() {
+        use crate::time::countries::north_america::united_states::UnitedStatesCalendar;
+
+        // Friday 5th January 2024, T+2 lands on Tuesday 9th January.
+        let reference_date = date!(2024 - 01 - 05);
+        let deposit = DepositRateHelper::with_spot_lag(
+            0.05,
+            reference_date,
+            2,
+            3,
+            &UnitedStatesCalendar,
+            DayCountConvention::Actual_360,
+        );
+
+        assert_eq!(deposit.start_date, date!(2024 - 01 - 09));
+        assert_eq!(deposit.maturity_date, date!(2024 - 04 - 09));
+        assert_eq!(deposit.spot_lag, 2);
+
+        let helpers: Vec<Box<dyn RateHelper>> = vec![Box::new(deposit)];
+        let curve = YieldCurve::bootstrap(reference_date, &helpers).unwrap();
+
+        // The discount factor over the shifted accrual period reflects
+        // the simple deposit rate, not the period from the reference date.
+        let tau = (date!(2024 - 04 - 09) - date!(2024 - 01 - 09)).whole_days() as f64 / 360.0;
+        let df_ratio = curve.discount_factor(deposit.maturity_date)
+            / curve.discount_factor(deposit.start_date);
+
+        assert_approx_equal!(df_ratio, 1.0 / (1.0 + 0.05 * tau), 1e-10);
+        assert!(curve.discount_factor(deposit.start_date) < 1.0);
+    }
+
+    #[test]
+    fn test_swap_spot_lag() {
+        use crate::time::countries::north_america::united_states::UnitedStatesCalendar;
+
+        let swap = SwapRateHelper::with_spot_lag(
+            0.04,
+            date!(2024 - 01 - 05),
+            2,
+            2,
+            Frequency::Annually,
+            &UnitedStatesCalendar,
+            DayCountConvention::Actual_360,
+        );
+
+        assert_eq!(swap.start_date, date!(2024 - 01 - 09));
+        assert_eq!(swap.maturity_date, date!(2026 - 01 - 09));
+        assert_eq!(swap.spot_lag, 2);
+    }
+}
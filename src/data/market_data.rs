@@ -0,0 +1,438 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Market data snapshots.
+//!
+//! A [`MarketData`] bundles everything needed to price a set of instruments
+//! as of a single valuation date, so that a valuation can be reproduced from
+//! one object rather than a collection of loose arguments.
+
+use crate::data::{Curve, Surface2D, VolatilitySurface, YieldCurve};
+use crate::error::RustQuantError;
+use crate::time::DayCountConvention;
+use std::collections::BTreeMap;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Volatility data held by a [`MarketData`] snapshot.
+#[derive(Debug, Clone)]
+pub enum MarketVolatility {
+    /// A single volatility for all expiries and strikes.
+    Constant(f64),
+
+    /// A term structure of volatilities by expiry date.
+    TermStructure(VolatilityTermStructure),
+
+    /// A surface of volatilities by time to expiry (in years) and strike.
+    Surface(Surface2D),
+
+    /// An implied volatility surface of smiles by time to expiry (in years).
+    Smile(VolatilitySurface),
+}
+
+/// Term structure of volatilities by expiry date.
+///
+/// Total variance $\sigma^2 t$ is interpolated linearly in the time to
+/// expiry between pillars, and the volatility is extrapolated flat outside
+/// them. Times are Actual/365 (Fixed) year fractions from the valuation
+/// date of the lookup.
+#[derive(Debug, Clone)]
+pub struct VolatilityTermStructure {
+    /// Volatilities, keyed by expiry date.
+    pub volatilities: BTreeMap<Date, f64>,
+}
+
+/// Snapshot of the market as of a valuation date.
+#[derive(Debug, Clone)]
+pub struct MarketData {
+    /// Valuation date of the snapshot.
+    pub valuation_date: Date,
+
+    /// Curves, keyed by name (e.g. "USD-SOFR").
+    pub curves: BTreeMap<String, YieldCurve>,
+
+    /// Volatility data (optional).
+    pub volatility: Option<MarketVolatility>,
+
+    /// Spot quotes, keyed by name (e.g. "AAPL").
+    pub spots: BTreeMap<String, f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl VolatilityTermStructure {
+    /// Create a new term structure from (expiry date, volatility) pillars.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there are no pillars or a
+    ///   volatility is not positive.
+    pub fn new(pillars: &[(Date, f64)]) -> Result<Self, RustQuantError> {
+        if pillars.is_empty() {
+            return Err(RustQuantError::InvalidArgument(
+                "a volatility term structure needs at least one pillar".to_string(),
+            ));
+        }
+        if let Some((date, volatility)) = pillars.iter().find(|(_, v)| v.is_nan() || *v <= 0.0) {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the volatility at {date} must be positive, got {volatility}"
+            )));
+        }
+
+        Ok(Self {
+            volatilities: pillars.iter().copied().collect(),
+        })
+    }
+
+    /// Returns the volatility to the given expiry, as seen from the
+    /// valuation date.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the expiry is before the
+    ///   valuation date, or no pillar is after the valuation date.
+    pub fn volatility(&self, valuation_date: Date, expiry: Date) -> Result<f64, RustQuantError> {
+        if expiry < valuation_date {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "expiry {expiry} is before the valuation date {valuation_date}"
+            )));
+        }
+
+        let year_fraction = |date: Date| {
+            DayCountConvention::Actual_365_Fixed.day_count_factor(valuation_date, date)
+        };
+
+        let mut pillars = self
+            .volatilities
+            .range(valuation_date..)
+            .filter(|(date, _)| **date > valuation_date);
+
+        let Some((first_date, first_volatility)) = pillars.next() else {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the volatility term structure has no pillar after {valuation_date}"
+            )));
+        };
+        if expiry <= *first_date {
+            return Ok(*first_volatility);
+        }
+
+        let mut left = (year_fraction(*first_date), *first_volatility);
+
+        for (date, volatility) in pillars {
+            let right = (year_fraction(*date), *volatility);
+
+            if expiry <= *date {
+                let t = year_fraction(expiry);
+                let w = (t - left.0) / (right.0 - left.0);
+                let variance = (1.0 - w) * left.1.powi(2) * left.0 + w * right.1.powi(2) * right.0;
+
+                return Ok((variance / t).sqrt());
+            }
+
+            left = right;
+        }
+
+        Ok(left.1)
+    }
+}
+
+impl MarketData {
+    /// Create a new, empty market data snapshot.
+    #[must_use]
+    pub fn new(valuation_date: Date) -> Self {
+        Self {
+            valuation_date,
+            curves: BTreeMap::new(),
+            volatility: None,
+            spots: BTreeMap::new(),
+        }
+    }
+
+    /// Add (or replace) a named curve.
+    pub fn add_curve(&mut self, name: &str, curve: YieldCurve) {
+        self.curves.insert(name.to_string(), curve);
+    }
+
+    /// Add (or replace) a named spot quote.
+    pub fn add_spot(&mut self, name: &str, spot: f64) {
+        self.spots.insert(name.to_string(), spot);
+    }
+
+    /// Set the volatility data.
+    pub fn set_volatility(&mut self, volatility: MarketVolatility) {
+        self.volatility = Some(volatility);
+    }
+
+    /// Returns the curve with the given name.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if there is no such curve, or it
+    ///   has no points.
+    pub fn curve(&self, name: &str) -> Result<&YieldCurve, RustQuantError> {
+        match self.curves.get(name) {
+            Some(curve) if !curve.rates.is_empty() => Ok(curve),
+            Some(_) => Err(RustQuantError::MissingInput(format!(
+                "curve '{name}' in market data as of {} has no points",
+                self.valuation_date
+            ))),
+            None => Err(RustQuantError::MissingInput(format!(
+                "no curve named '{name}' in market data as of {}",
+                self.valuation_date
+            ))),
+        }
+    }
+
+    /// Discount factor from the valuation date to `date` on the named curve,
+    /// i.e. $P(t_0, T) = P(T) / P(t_0)$ for a curve starting before the
+    /// valuation date. Discount factors of a curve starting on or after the
+    /// valuation date are used as they are.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if there is no such curve.
+    /// - `RustQuantError::InvalidArgument` if `date` is before the valuation date.
+    pub fn discount_factor(&self, name: &str, date: Date) -> Result<f64, RustQuantError> {
+        let curve = self.curve(name)?;
+
+        if date < self.valuation_date {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "cannot discount from {date}, before the valuation date {}",
+                self.valuation_date
+            )));
+        }
+
+        let start = self.valuation_date.max(curve.initial_date());
+
+        Ok(curve.discount_factor(date) / curve.discount_factor(start))
+    }
+
+    /// Returns the spot quote with the given name.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if there is no such quote.
+    pub fn spot(&self, name: &str) -> Result<f64, RustQuantError> {
+        self.spots.get(name).copied().ok_or_else(|| {
+            RustQuantError::MissingInput(format!(
+                "no spot quote named '{name}' in market data as of {}",
+                self.valuation_date
+            ))
+        })
+    }
+
+    /// Returns the volatility for the given expiry date and strike.
+    /// Times to expiry on a surface are Actual/365 (Fixed) year fractions
+    /// from the valuation date.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if the snapshot has no volatility data.
+    /// - `RustQuantError::InvalidArgument` if the expiry is before the
+    ///   valuation date.
+    /// - `RustQuantError::ComputationError` if the point is outside the surface.
+    pub fn volatility(&self, expiry: Date, strike: f64) -> Result<f64, RustQuantError> {
+        if expiry < self.valuation_date {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "expiry {expiry} is before the valuation date {}",
+                self.valuation_date
+            )));
+        }
+
+        match &self.volatility {
+            None => Err(RustQuantError::MissingInput(format!(
+                "no volatility data in market data as of {}",
+                self.valuation_date
+            ))),
+            Some(MarketVolatility::Constant(volatility)) => Ok(*volatility),
+            Some(MarketVolatility::TermStructure(term_structure)) => {
+                term_structure.volatility(self.valuation_date, expiry)
+            }
+            Some(MarketVolatility::Surface(surface)) => {
+                let t = DayCountConvention::Actual_365_Fixed
+                    .day_count_factor(self.valuation_date, expiry);
+
+                surface.value(t, strike).map_err(|e| {
+                    RustQuantError::ComputationError(format!(
+                        "volatility surface lookup failed at ({t}, {strike}): {e:?}"
+                    ))
+                })
+            }
+            Some(MarketVolatility::Smile(surface)) => surface.volatility(
+                DayCountConvention::Actual_365_Fixed.day_count_factor(self.valuation_date, expiry),
+                strike,
+            ),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_market_data {
+    use super::*;
+    use crate::assert_approx_equal;
+    use time::macros::date;
+
+    #[test]
+    fn test_market_data_lookups() {
+        let valuation_date = date!(2024 - 01 - 05);
+        let mut market = MarketData::new(valuation_date);
+
+        market.add_curve(
+            "USD",
+            YieldCurve::from_dates_and_rates(&[valuation_date], &[0.05]),
+        );
+        market.add_spot("AAPL", 185.0);
+        market.set_volatility(MarketVolatility::Constant(0.2));
+
+        assert_approx_equal!(
+            market.curve("USD").unwrap().rate(date!(2025 - 01 - 05)),
+            0.05,
+            1e-12
+        );
+        assert_approx_equal!(market.spot("AAPL").unwrap(), 185.0, 1e-12);
+        assert_approx_equal!(
+            market.volatility(date!(2024 - 06 - 05), 100.0).unwrap(),
+            0.2,
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_market_data_missing_inputs() {
+        let market = MarketData::new(date!(2024 - 01 - 05));
+
+        let error = market.curve("EUR").unwrap_err().to_string();
+        assert!(error.contains("no curve named 'EUR'"));
+
+        let error = market.spot("MSFT").unwrap_err().to_string();
+        assert!(error.contains("no spot quote named 'MSFT'"));
+
+        assert!(matches!(
+            market.volatility(date!(2024 - 06 - 05), 100.0),
+            Err(RustQuantError::MissingInput(_))
+        ));
+
+        let mut market = MarketData::new(date!(2024 - 01 - 05));
+        market.add_curve("EMPTY", YieldCurve::new(BTreeMap::new()));
+        market.set_volatility(MarketVolatility::Constant(0.2));
+
+        assert!(matches!(
+            market.curve("EMPTY"),
+            Err(RustQuantError::MissingInput(_))
+        ));
+        assert!(matches!(
+            market.volatility(date!(2023 - 12 - 29), 100.0),
+            Err(RustQuantError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_discount_factor_from_valuation_date() {
+        let curve_date = date!(2024 - 01 - 05);
+        let valuation_date = date!(2024 - 07 - 05);
+        let mut market = MarketData::new(valuation_date);
+
+        market.add_curve(
+            "USD",
+            YieldCurve::from_dates_and_rates(&[curve_date], &[0.05]),
+        );
+
+        let curve = market.curve("USD").unwrap();
+        let maturity = date!(2025 - 07 - 07);
+
+        assert_approx_equal!(
+            market.discount_factor("USD", maturity).unwrap(),
+            curve.discount_factor(maturity) / curve.discount_factor(valuation_date),
+            1e-12
+        );
+        assert_approx_equal!(
+            market.discount_factor("USD", valuation_date).unwrap(),
+            1.0,
+            1e-12
+        );
+        assert!(market.discount_factor("USD", curve_date).is_err());
+    }
+
+    #[test]
+    fn test_volatility_term_structure() {
+        let valuation_date = date!(2024 - 01 - 05);
+        let (t1, t2) = (date!(2024 - 07 - 05), date!(2025 - 01 - 04));
+
+        let term_structure = VolatilityTermStructure::new(&[(t1, 0.30), (t2, 0.20)]).unwrap();
+        let mut market = MarketData::new(valuation_date);
+        market.set_volatility(MarketVolatility::TermStructure(term_structure));
+
+        // Pillars are reproduced, and extrapolated flat.
+        assert_approx_equal!(market.volatility(t1, 100.0).unwrap(), 0.30, 1e-12);
+        assert_approx_equal!(market.volatility(t2, 100.0).unwrap(), 0.20, 1e-12);
+        assert_approx_equal!(
+            market.volatility(date!(2024 - 02 - 05), 100.0).unwrap(),
+            0.30,
+            1e-12
+        );
+        assert_approx_equal!(
+            market.volatility(date!(2030 - 01 - 04), 100.0).unwrap(),
+            0.20,
+            1e-12
+        );
+
+        // Total variance is linear in time between the pillars.
+        let year_fraction =
+            |date| DayCountConvention::Actual_365_Fixed.day_count_factor(valuation_date, date);
+        let expiry = date!(2024 - 10 - 04);
+        let w =
+            (year_fraction(expiry) - year_fraction(t1)) / (year_fraction(t2) - year_fraction(t1));
+        let variance = (1.0 - w) * 0.09 * year_fraction(t1) + w * 0.04 * year_fraction(t2);
+
+        assert_approx_equal!(
+            market.volatility(expiry, 100.0).unwrap(),
+            (variance / year_fraction(expiry)).sqrt(),
+            1e-12
+        );
+
+        // Pillars must be usable from the valuation date.
+        let stale = VolatilityTermStructure::new(&[(date!(2023 - 06 - 05), 0.2)]).unwrap();
+        assert!(stale.volatility(valuation_date, t1).is_err());
+
+        assert!(VolatilityTermStructure::new(&[]).is_err());
+        assert!(VolatilityTermStructure::new(&[(t1, 0.0)]).is_err());
+    }
+
+    #[test]
+    fn test_volatility_smile_surface() {
+        use crate::data::{VolatilitySlice, VolatilitySmile};
+        use crate::models::SVI;
+
+        let valuation_date = date!(2024 - 01 - 05);
+        let svi = SVI::new(0.03, 0.1, -0.4, 0.0, 0.2);
+        let surface = VolatilitySurface::new(vec![VolatilitySlice::new(
+            1.0,
+            100.0,
+            VolatilitySmile::Svi(svi),
+        )
+        .unwrap()])
+        .unwrap();
+
+        let mut market = MarketData::new(valuation_date);
+        market.set_volatility(MarketVolatility::Smile(surface));
+
+        // One year (Act/365F) after the valuation date is on the slice.
+        let expiry = date!(2025 - 01 - 04);
+
+        assert_approx_equal!(
+            market.volatility(expiry, 90.0).unwrap(),
+            svi.implied_volatility((0.9_f64).ln(), 1.0),
+            1e-12
+        );
+        assert!(market.volatility(valuation_date, 90.0).is_err());
+    }
+}
@@ -125,3 +125,7 @@ pub use curves::*;
 /// For example, a volatility surface is a function of time and strike/moneyness.
 pub mod surfaces;
 pub use surfaces::*;
+
+/// Market data snapshots.
+pub mod market_data;
+pub use market_data::*;
@@ -7,13 +7,22 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+/// Two-dimensional grid surfaces.
+pub mod surface_2d;
+pub use surface_2d::*;
+
+/// Swaption volatility cubes.
+pub mod volatility_cube;
+pub use volatility_cube::*;
+
+pub mod volatility_surface;
+pub use volatility_surface::*;
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // STRUCTS, ENUMS, AND TRAITS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use super::Curve;
 use num::Float;
-use std::collections::BTreeMap;
 use time::Date;
 
 /// Surface trait.
@@ -22,17 +31,6 @@ pub trait Surface {
     fn value<F: Float>(&self, time: Date, space: F) -> f64;
 }
 
-/// Volatility surface.
-/// A volatility surface is a surface of points (volatilities) over a
-/// space dimension (e.g. strike or moneyness) and a time dimension (e.g. dates).
-///
-/// We represent this as a map from time to a curve of volatilities.
-#[allow(clippy::module_name_repetitions)]
-pub struct VolatilitySurface<C: Curve> {
-    /// The volatilities of the surface.
-    pub volatilities: BTreeMap<f64, C>,
-}
-
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // IMPLEMENTATIONS, TRAITS, AND FUNCTIONS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
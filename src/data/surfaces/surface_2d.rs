@@ -0,0 +1,219 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Two-dimensional grid surfaces, such as a volatility surface indexed by
+//! time to expiry and strike.
+
+use crate::math::interpolation::{
+    fitted_interpolator, InterpolationError, Interpolator, LinearInterpolator,
+};
+use std::sync::{Mutex, PoisonError};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// A surface of values on a rectangular grid.
+///
+/// The surface is interpolated first along the `ys` axis (within each row),
+/// then along the `xs` axis, using the interpolation scheme `I` in both
+/// directions. The interpolator along `xs` depends on `y`, so the last one
+/// fitted is cached for further queries at the same `y`.
+#[derive(Debug)]
+pub struct Surface2D<I: Interpolator<f64, f64> = LinearInterpolator<f64, f64>> {
+    /// Grid points of the first axis (e.g. time to expiry).
+    pub xs: Vec<f64>,
+
+    /// Grid points of the second axis (e.g. strike).
+    pub ys: Vec<f64>,
+
+    /// Values of the surface, where `values[i][j]` is the value at `(xs[i], ys[j])`.
+    pub values: Vec<Vec<f64>>,
+
+    /// One fitted interpolator per row of the grid.
+    rows: Vec<I>,
+
+    /// The interpolator along `xs` for the last queried `y`.
+    column: Mutex<Option<(f64, I)>>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<I: Interpolator<f64, f64> + Clone> Clone for Surface2D<I> {
+    fn clone(&self) -> Self {
+        let column = self
+            .column
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+
+        Self {
+            xs: self.xs.clone(),
+            ys: self.ys.clone(),
+            values: self.values.clone(),
+            rows: self.rows.clone(),
+            column: Mutex::new(column),
+        }
+    }
+}
+
+impl<I: Interpolator<f64, f64> + Default> Surface2D<I> {
+    /// Create a new surface from its grid and values.
+    ///
+    /// # Errors
+    /// - `InterpolationError::UnequalLength` if the shape of `values` does
+    ///   not match `xs.len()` by `ys.len()`.
+    /// - `InterpolationError::InsufficientData` if either axis has fewer
+    ///   than two points.
+    /// - `InterpolationError::NotStrictlyIncreasing` if either axis is not
+    ///   strictly increasing.
+    pub fn new(
+        xs: Vec<f64>,
+        ys: Vec<f64>,
+        values: Vec<Vec<f64>>,
+    ) -> Result<Self, InterpolationError> {
+        if values.len() != xs.len() || values.iter().any(|row| row.len() != ys.len()) {
+            return Err(InterpolationError::UnequalLength);
+        }
+        if xs.len() < 2 {
+            return Err(InterpolationError::InsufficientData);
+        }
+
+        let rows = values
+            .iter()
+            .map(|row| fitted_interpolator(&ys, row))
+            .collect::<Result<Vec<I>, InterpolationError>>()?;
+
+        // Fit one column to check the `xs` axis up front.
+        fitted_interpolator::<I>(&xs, &values.iter().map(|row| row[0]).collect::<Vec<f64>>())?;
+
+        Ok(Self {
+            xs,
+            ys,
+            values,
+            rows,
+            column: Mutex::new(None),
+        })
+    }
+
+    /// Value of the surface at `(x, y)`.
+    ///
+    /// # Errors
+    /// - `InterpolationError::OutsideOfRange` if `(x, y)` is outside the grid.
+    pub fn value(&self, x: f64, y: f64) -> Result<f64, InterpolationError> {
+        let mut cache = self.column.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let Some((cached_y, column)) = cache.as_ref() {
+            if *cached_y == y {
+                return column.interpolate(x);
+            }
+        }
+
+        let column = self
+            .rows
+            .iter()
+            .map(|row| row.interpolate(y))
+            .collect::<Result<Vec<f64>, InterpolationError>>()?;
+
+        let interpolator: I = fitted_interpolator(&self.xs, &column)?;
+        let value = interpolator.interpolate(x);
+        *cache = Some((y, interpolator));
+
+        value
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_surface_2d {
+    use super::*;
+    use crate::math::interpolation::{
+        CubicSpline, ExponentialInterpolator, HermiteMonotone, StepInterpolator,
+    };
+    use crate::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    fn grid() -> (Vec<f64>, Vec<f64>, Vec<Vec<f64>>) {
+        let xs = vec![0.25, 0.5, 1.0, 2.0];
+        let ys = vec![80.0, 90.0, 100.0, 110.0, 120.0];
+        let values = xs
+            .iter()
+            .map(|x| {
+                ys.iter()
+                    .map(|y| 0.2 + 0.1 * ((y - 100.0) / 100.0_f64).powi(2) + 0.01 * x)
+                    .collect()
+            })
+            .collect();
+
+        (xs, ys, values)
+    }
+
+    fn check_nodes<I: Interpolator<f64, f64> + Default>() {
+        let (xs, ys, values) = grid();
+        let surface = Surface2D::<I>::new(xs.clone(), ys.clone(), values.clone()).unwrap();
+
+        for (i, x) in xs.iter().enumerate() {
+            for (j, y) in ys.iter().enumerate() {
+                assert_approx_equal!(
+                    surface.value(*x, *y).unwrap(),
+                    values[i][j],
+                    RUSTQUANT_EPSILON
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_surface_reproduces_nodes() {
+        check_nodes::<LinearInterpolator<f64, f64>>();
+        check_nodes::<CubicSpline>();
+        check_nodes::<HermiteMonotone>();
+        check_nodes::<StepInterpolator>();
+        check_nodes::<ExponentialInterpolator<f64, f64>>();
+    }
+
+    #[test]
+    fn test_surface_bilinear() {
+        let surface = Surface2D::<LinearInterpolator<f64, f64>>::new(
+            vec![0.0, 1.0],
+            vec![0.0, 1.0],
+            vec![vec![0.0, 1.0], vec![2.0, 3.0]],
+        )
+        .unwrap();
+
+        assert_approx_equal!(surface.value(0.5, 0.5).unwrap(), 1.5, RUSTQUANT_EPSILON);
+        assert!(surface.value(1.5, 0.5).is_err());
+
+        // Queries alternating between cached and new columns.
+        for (x, y) in [
+            (0.25, 0.5),
+            (0.25, 0.75),
+            (0.75, 0.75),
+            (0.0, 0.0),
+            (1.0, 1.0),
+        ] {
+            assert_approx_equal!(surface.value(x, y).unwrap(), 2.0 * x + y, RUSTQUANT_EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_surface_shape_mismatch() {
+        let result = Surface2D::<LinearInterpolator<f64, f64>>::new(
+            vec![0.0, 1.0],
+            vec![0.0, 1.0],
+            vec![vec![0.0, 1.0]],
+        );
+
+        assert_eq!(result.err(), Some(InterpolationError::UnequalLength));
+    }
+}
@@ -0,0 +1,226 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Swaption volatility cubes, indexed by option expiry, underlying swap
+//! tenor, and strike (or moneyness).
+
+use crate::math::interpolation::{check_knots, InterpolationError};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// A cube of swaption volatilities on a rectangular grid.
+///
+/// Volatilities between grid nodes are found by trilinear interpolation.
+/// Each axis must be strictly increasing.
+#[derive(Debug, Clone)]
+pub struct VolatilityCube {
+    /// Option expiries (in years).
+    pub expiries: Vec<f64>,
+
+    /// Tenors (in years) of the underlying swaps.
+    pub tenors: Vec<f64>,
+
+    /// Strikes (or moneyness, e.g. strike minus the forward swap rate).
+    pub strikes: Vec<f64>,
+
+    /// Volatilities, where `volatilities[i][j][k]` is the volatility at
+    /// `(expiries[i], tenors[j], strikes[k])`.
+    pub volatilities: Vec<Vec<Vec<f64>>>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl VolatilityCube {
+    /// Create a new volatility cube from its grid and volatilities.
+    ///
+    /// # Errors
+    /// - `InterpolationError::UnequalLength` if the shape of `volatilities`
+    ///   does not match the lengths of the three axes.
+    /// - `InterpolationError::InsufficientData` if any axis has fewer than
+    ///   two points.
+    /// - `InterpolationError::NotStrictlyIncreasing` if any axis is not
+    ///   strictly increasing.
+    pub fn new(
+        expiries: Vec<f64>,
+        tenors: Vec<f64>,
+        strikes: Vec<f64>,
+        volatilities: Vec<Vec<Vec<f64>>>,
+    ) -> Result<Self, InterpolationError> {
+        let shape_matches = volatilities.len() == expiries.len()
+            && volatilities.iter().all(|matrix| {
+                matrix.len() == tenors.len() && matrix.iter().all(|row| row.len() == strikes.len())
+            });
+
+        if !shape_matches {
+            return Err(InterpolationError::UnequalLength);
+        }
+        for axis in [&expiries, &tenors, &strikes] {
+            check_knots(axis)?;
+        }
+
+        Ok(Self {
+            expiries,
+            tenors,
+            strikes,
+            volatilities,
+        })
+    }
+
+    /// Volatility at the given expiry, tenor, and strike.
+    ///
+    /// # Errors
+    /// - `InterpolationError::OutsideOfRange` if the point is outside the grid.
+    pub fn volatility(
+        &self,
+        expiry: f64,
+        tenor: f64,
+        strike: f64,
+    ) -> Result<f64, InterpolationError> {
+        let (i, wi) = Self::bracket(&self.expiries, expiry)?;
+        let (j, wj) = Self::bracket(&self.tenors, tenor)?;
+        let (k, wk) = Self::bracket(&self.strikes, strike)?;
+
+        let mut volatility = 0.0;
+
+        for (di, ui) in [(0, 1.0 - wi), (1, wi)] {
+            for (dj, uj) in [(0, 1.0 - wj), (1, wj)] {
+                for (dk, uk) in [(0, 1.0 - wk), (1, wk)] {
+                    volatility += ui * uj * uk * self.volatilities[i + di][j + dj][k + dk];
+                }
+            }
+        }
+
+        Ok(volatility)
+    }
+
+    /// Index of the lower node of the grid cell containing `x`,
+    /// and the linear weight of the upper node.
+    fn bracket(axis: &[f64], x: f64) -> Result<(usize, f64), InterpolationError> {
+        let (first, last) = (axis[0], axis[axis.len() - 1]);
+
+        if !(first..=last).contains(&x) {
+            return Err(InterpolationError::OutsideOfRange);
+        }
+
+        let i = axis
+            .partition_point(|node| *node <= x)
+            .clamp(1, axis.len() - 1)
+            - 1;
+
+        Ok((i, (x - axis[i]) / (axis[i + 1] - axis[i])))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_volatility_cube {
+    use super::*;
+    use crate::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    fn cube() -> VolatilityCube {
+        let expiries = vec![0.5, 1.0, 5.0];
+        let tenors = vec![1.0, 2.0, 10.0];
+        let strikes = vec![-0.01, 0.0, 0.01, 0.02];
+
+        let volatilities = expiries
+            .iter()
+            .map(|e| {
+                tenors
+                    .iter()
+                    .map(|t| {
+                        strikes
+                            .iter()
+                            .map(|k| 0.3 - 0.01 * e - 0.005 * t + 50.0 * k * k)
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        VolatilityCube::new(expiries, tenors, strikes, volatilities).unwrap()
+    }
+
+    #[test]
+    fn test_volatility_cube_reproduces_nodes() {
+        let cube = cube();
+
+        for (i, expiry) in cube.expiries.iter().enumerate() {
+            for (j, tenor) in cube.tenors.iter().enumerate() {
+                for (k, strike) in cube.strikes.iter().enumerate() {
+                    assert_approx_equal!(
+                        cube.volatility(*expiry, *tenor, *strike).unwrap(),
+                        cube.volatilities[i][j][k],
+                        RUSTQUANT_EPSILON
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_volatility_cube_cell_center() {
+        let cube = cube();
+
+        // The center of a cell is the average of its eight corners.
+        let average = [0, 1]
+            .iter()
+            .flat_map(|di| [0, 1].into_iter().map(move |dj| (di, dj)))
+            .flat_map(|(di, dj)| [0, 1].into_iter().map(move |dk| (di, dj, dk)))
+            .map(|(di, dj, dk)| cube.volatilities[1 + di][dj][2 + dk])
+            .sum::<f64>()
+            / 8.0;
+
+        assert_approx_equal!(
+            cube.volatility(3.0, 1.5, 0.015).unwrap(),
+            average,
+            RUSTQUANT_EPSILON
+        );
+    }
+
+    #[test]
+    fn test_volatility_cube_errors() {
+        let cube = cube();
+
+        assert_eq!(
+            cube.volatility(6.0, 1.5, 0.0),
+            Err(InterpolationError::OutsideOfRange)
+        );
+
+        let result = VolatilityCube::new(
+            vec![0.5, 1.0],
+            vec![1.0, 2.0],
+            vec![0.0, 0.01],
+            vec![vec![vec![0.2, 0.2], vec![0.2, 0.2]]],
+        );
+
+        assert_eq!(result.err(), Some(InterpolationError::UnequalLength));
+
+        // Unsorted, or repeated, tenors.
+        for tenors in [vec![2.0, 1.0], vec![1.0, 1.0]] {
+            let result = VolatilityCube::new(
+                vec![0.5, 1.0],
+                tenors,
+                vec![0.0, 0.01],
+                vec![vec![vec![0.2, 0.2], vec![0.2, 0.2]]; 2],
+            );
+
+            assert_eq!(
+                result.err(),
+                Some(InterpolationError::NotStrictlyIncreasing)
+            );
+        }
+    }
+}
@@ -0,0 +1,592 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Implied volatility surfaces, made of one smile per expiry.
+//!
+//! Each slice holds the forward and the smile at its expiry, either quoted
+//! volatilities by strike (or delta), or an SVI or SABR parameterization.
+//! Smiles are functions of the log-moneyness $k = \ln(K / F)$, and the total
+//! implied variance $w = \sigma^2 t$ is interpolated linearly in time between
+//! the slices at constant log-moneyness. Before the first and after the last
+//! slice the volatility is extrapolated flat in time.
+//!
+//! The surface is free of static arbitrage if the call prices of every slice
+//! are convex in strike (no butterfly arbitrage), and the total variance is
+//! non-decreasing in time at constant log-moneyness (no calendar arbitrage).
+
+use crate::error::RustQuantError;
+use crate::math::distributions::{Distribution, Gaussian};
+use crate::math::interpolation::{CubicSpline, Interpolator};
+use crate::models::{SabrSmile, SVI};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// The smile of a single expiry.
+#[derive(Debug, Clone)]
+pub enum VolatilitySmile {
+    /// Quoted volatilities, interpolated by a natural cubic spline in
+    /// log-moneyness, and extrapolated flat outside the quoted strikes.
+    Quoted {
+        /// Strictly increasing strikes.
+        strikes: Vec<f64>,
+
+        /// Implied volatilities at the strikes.
+        volatilities: Vec<f64>,
+    },
+
+    /// SVI parameterization of the total variance in log-moneyness.
+    Svi(SVI),
+
+    /// SABR parameterization, with Hagan's lognormal volatility.
+    Sabr(SabrSmile),
+}
+
+/// A slice of a [`VolatilitySurface`]: the smile at one expiry.
+#[derive(Debug, Clone)]
+pub struct VolatilitySlice {
+    /// Time to expiry (in years).
+    pub expiry: f64,
+
+    /// Forward price of the underlying at expiry.
+    pub forward: f64,
+
+    /// The smile of the slice.
+    pub smile: VolatilitySmile,
+
+    /// Spline through the quoted volatilities, by log-moneyness.
+    spline: Option<CubicSpline>,
+}
+
+/// Implied volatility surface, by time to expiry and strike.
+#[derive(Debug, Clone)]
+pub struct VolatilitySurface {
+    /// The slices, by strictly increasing expiry.
+    slices: Vec<VolatilitySlice>,
+}
+
+/// A static arbitrage found by [`VolatilitySurface::arbitrage_violations`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArbitrageViolation {
+    /// The call prices of the slice are not convex in strike at the log-moneyness.
+    Butterfly {
+        /// Expiry of the slice.
+        expiry: f64,
+
+        /// Log-moneyness of the violation.
+        log_moneyness: f64,
+    },
+
+    /// The total variance of the slice is lower than that of the previous
+    /// slice at the log-moneyness.
+    Calendar {
+        /// Expiry of the (later) slice.
+        expiry: f64,
+
+        /// Log-moneyness of the violation.
+        log_moneyness: f64,
+    },
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl VolatilitySlice {
+    /// Create a new slice from its expiry, forward and smile.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the expiry or forward is not
+    ///   positive, or quoted volatilities have fewer than two strikes, strikes
+    ///   that are not positive and strictly increasing, volatilities that are
+    ///   not positive, or a different number of strikes and volatilities.
+    pub fn new(expiry: f64, forward: f64, smile: VolatilitySmile) -> Result<Self, RustQuantError> {
+        if !(expiry > 0.0 && forward > 0.0) {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the expiry ({expiry}) and forward ({forward}) of a slice must be positive"
+            )));
+        }
+
+        let spline = match &smile {
+            VolatilitySmile::Quoted {
+                strikes,
+                volatilities,
+            } => {
+                if strikes.iter().any(|k| *k <= 0.0)
+                    || volatilities.iter().any(|v| v.is_nan() || *v <= 0.0)
+                {
+                    return Err(RustQuantError::InvalidArgument(
+                        "quoted strikes and volatilities must be positive".to_string(),
+                    ));
+                }
+
+                let log_strikes = strikes.iter().map(|k| (k / forward).ln()).collect();
+                let mut spline = CubicSpline::new(log_strikes, volatilities.clone())
+                    .map_err(|e| RustQuantError::InvalidArgument(format!("{e:?}")))?;
+
+                spline.fit().map_err(|e| {
+                    RustQuantError::InvalidArgument(format!("invalid quoted strikes: {e:?}"))
+                })?;
+
+                Some(spline)
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            expiry,
+            forward,
+            smile,
+            spline,
+        })
+    }
+
+    /// Create a new slice from volatilities quoted by forward call delta
+    /// $\Delta = N(d_1)$, e.g. 0.25 for the 25-delta call and 0.75 for the
+    /// 25-delta put. The strikes follow from
+    ///
+    /// $$
+    /// K = F \exp\left(\frac{1}{2} \sigma^2 t - \sigma \sqrt{t} N^{-1}(\Delta)\right)
+    /// $$
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if a delta is outside $(0, 1)$,
+    ///   the inputs have different lengths, or as for [`VolatilitySlice::new`].
+    pub fn from_deltas(
+        expiry: f64,
+        forward: f64,
+        deltas: &[f64],
+        volatilities: &[f64],
+    ) -> Result<Self, RustQuantError> {
+        if deltas.len() != volatilities.len() {
+            return Err(RustQuantError::InvalidArgument(
+                "deltas and volatilities must have the same length".to_string(),
+            ));
+        }
+        if let Some(delta) = deltas.iter().find(|d| !(**d > 0.0 && **d < 1.0)) {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "delta {delta} is outside (0, 1)"
+            )));
+        }
+
+        let normal = Gaussian::default();
+
+        let mut quotes: Vec<(f64, f64)> = deltas
+            .iter()
+            .zip(volatilities)
+            .map(|(delta, volatility)| {
+                let sqrt_variance = volatility * expiry.max(0.0).sqrt();
+                let k =
+                    0.5 * sqrt_variance * sqrt_variance - sqrt_variance * normal.inv_cdf(*delta);
+
+                (forward * k.exp(), *volatility)
+            })
+            .collect();
+
+        quotes.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let (strikes, volatilities) = quotes.into_iter().unzip();
+
+        Self::new(
+            expiry,
+            forward,
+            VolatilitySmile::Quoted {
+                strikes,
+                volatilities,
+            },
+        )
+    }
+
+    /// Implied volatility at the log-moneyness `k`.
+    #[must_use]
+    pub fn volatility_at(&self, k: f64) -> f64 {
+        match &self.smile {
+            VolatilitySmile::Quoted { .. } => {
+                let spline = self.spline.as_ref().unwrap();
+                let (start, end) = spline.range();
+
+                spline
+                    .interpolate(k.clamp(start, end))
+                    .expect("Log-moneyness clamped to the quotes can be interpolated.")
+            }
+            VolatilitySmile::Svi(svi) => svi.implied_volatility(k, self.expiry),
+            VolatilitySmile::Sabr(sabr) => {
+                sabr.implied_volatility(self.forward, self.forward * k.exp(), self.expiry)
+            }
+        }
+    }
+
+    /// Implied volatility at the strike.
+    #[must_use]
+    pub fn volatility(&self, strike: f64) -> f64 {
+        self.volatility_at((strike / self.forward).ln())
+    }
+
+    /// Total implied variance $\sigma^2 t$ at the log-moneyness `k`.
+    #[must_use]
+    pub fn total_variance(&self, k: f64) -> f64 {
+        self.volatility_at(k).powi(2) * self.expiry
+    }
+
+    /// First and second derivatives of the total implied variance in
+    /// log-moneyness at `k`. They are exact for SVI and quoted smiles (zero
+    /// where quoted smiles are extrapolated flat), and central differences
+    /// for SABR smiles.
+    #[must_use]
+    pub fn total_variance_derivatives(&self, k: f64) -> (f64, f64) {
+        match &self.smile {
+            VolatilitySmile::Quoted { .. } => {
+                let spline = self.spline.as_ref().unwrap();
+                let (start, end) = spline.range();
+
+                if k < start || k > end {
+                    return (0.0, 0.0);
+                }
+
+                let sigma = self.volatility_at(k);
+                let d_sigma = spline.derivative(k).unwrap_or(0.0);
+                let d2_sigma = spline.second_derivative(k).unwrap_or(0.0);
+
+                (
+                    2.0 * sigma * d_sigma * self.expiry,
+                    2.0 * (d_sigma * d_sigma + sigma * d2_sigma) * self.expiry,
+                )
+            }
+            VolatilitySmile::Svi(svi) => {
+                let x = k - svi.m;
+                let r = (x * x + svi.sigma * svi.sigma).sqrt();
+
+                (
+                    svi.b * (svi.rho + x / r),
+                    svi.b * svi.sigma * svi.sigma / r.powi(3),
+                )
+            }
+            VolatilitySmile::Sabr(_) => {
+                let h = 1e-4;
+                let (down, mid, up) = (
+                    self.total_variance(k - h),
+                    self.total_variance(k),
+                    self.total_variance(k + h),
+                );
+
+                ((up - down) / (2.0 * h), (up - 2.0 * mid + down) / (h * h))
+            }
+        }
+    }
+
+    /// Log-moneyness values at which the undiscounted call prices, per unit
+    /// forward, are not convex in strike.
+    fn butterfly_violations(&self, log_moneyness: &[f64]) -> Vec<f64> {
+        let normal = Gaussian::default();
+
+        let mut ks = log_moneyness.to_vec();
+        ks.sort_by(f64::total_cmp);
+        ks.dedup();
+
+        let calls: Vec<(f64, f64)> = ks
+            .iter()
+            .map(|k| {
+                let sqrt_variance = self.total_variance(*k).sqrt();
+                let d_1 = -k / sqrt_variance + 0.5 * sqrt_variance;
+                let d_2 = d_1 - sqrt_variance;
+
+                (k.exp(), normal.cdf(d_1) - k.exp() * normal.cdf(d_2))
+            })
+            .collect();
+
+        calls
+            .windows(3)
+            .zip(&ks[1..])
+            .filter(|(w, _)| {
+                let left = (w[1].1 - w[0].1) / (w[1].0 - w[0].0);
+                let right = (w[2].1 - w[1].1) / (w[2].0 - w[1].0);
+
+                right < left - 1e-12
+            })
+            .map(|(_, k)| *k)
+            .collect()
+    }
+}
+
+impl VolatilitySurface {
+    /// Create a new surface from its slices.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there is no slice, or two
+    ///   slices share an expiry.
+    pub fn new(mut slices: Vec<VolatilitySlice>) -> Result<Self, RustQuantError> {
+        if slices.is_empty() {
+            return Err(RustQuantError::InvalidArgument(
+                "a volatility surface needs at least one slice".to_string(),
+            ));
+        }
+
+        slices.sort_by(|a, b| a.expiry.total_cmp(&b.expiry));
+
+        if slices.windows(2).any(|w| w[0].expiry == w[1].expiry) {
+            return Err(RustQuantError::InvalidArgument(
+                "two slices of a volatility surface share an expiry".to_string(),
+            ));
+        }
+
+        Ok(Self { slices })
+    }
+
+    /// The slices, by increasing expiry.
+    #[must_use]
+    pub fn slices(&self) -> &[VolatilitySlice] {
+        &self.slices
+    }
+
+    /// Forward at time `t`, interpolated linearly between the slices and
+    /// extrapolated flat outside them.
+    #[must_use]
+    pub fn forward(&self, t: f64) -> f64 {
+        let (left, right) = self.neighbours(t);
+
+        if left.expiry == right.expiry {
+            return left.forward;
+        }
+
+        let weight = (t - left.expiry) / (right.expiry - left.expiry);
+
+        left.forward + weight * (right.forward - left.forward)
+    }
+
+    /// Total implied variance at time `t` and log-moneyness `k` (relative to
+    /// the forward at `t`).
+    #[must_use]
+    pub fn total_variance(&self, t: f64, k: f64) -> f64 {
+        let (left, right) = self.neighbours(t);
+
+        if left.expiry == right.expiry {
+            // Flat volatility in time outside the slices.
+            return left.total_variance(k) * t / left.expiry;
+        }
+
+        let weight = (t - left.expiry) / (right.expiry - left.expiry);
+
+        (1.0 - weight) * left.total_variance(k) + weight * right.total_variance(k)
+    }
+
+    /// Implied volatility at time to expiry `t` (in years) and strike.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the time or strike is not positive.
+    pub fn volatility(&self, t: f64, strike: f64) -> Result<f64, RustQuantError> {
+        if !(t > 0.0 && strike > 0.0) {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the time ({t}) and strike ({strike}) must be positive"
+            )));
+        }
+
+        let k = (strike / self.forward(t)).ln();
+
+        Ok((self.total_variance(t, k) / t).sqrt())
+    }
+
+    /// Static arbitrages of the surface at the given log-moneyness values:
+    /// butterfly arbitrage within each slice, and calendar arbitrage between
+    /// consecutive slices.
+    #[must_use]
+    pub fn arbitrage_violations(&self, log_moneyness: &[f64]) -> Vec<ArbitrageViolation> {
+        let mut violations = Vec::new();
+
+        for slice in &self.slices {
+            violations.extend(
+                slice
+                    .butterfly_violations(log_moneyness)
+                    .into_iter()
+                    .map(|k| ArbitrageViolation::Butterfly {
+                        expiry: slice.expiry,
+                        log_moneyness: k,
+                    }),
+            );
+        }
+
+        for pair in self.slices.windows(2) {
+            violations.extend(
+                log_moneyness
+                    .iter()
+                    .filter(|k| pair[1].total_variance(**k) < pair[0].total_variance(**k) - 1e-12)
+                    .map(|k| ArbitrageViolation::Calendar {
+                        expiry: pair[1].expiry,
+                        log_moneyness: *k,
+                    }),
+            );
+        }
+
+        violations
+    }
+
+    /// Checks that the surface has no static arbitrage at the given
+    /// log-moneyness values (see [`VolatilitySurface::arbitrage_violations`]).
+    #[must_use]
+    pub fn is_arbitrage_free(&self, log_moneyness: &[f64]) -> bool {
+        self.arbitrage_violations(log_moneyness).is_empty()
+    }
+
+    /// The slices either side of `t`, or the nearest slice twice outside them.
+    fn neighbours(&self, t: f64) -> (&VolatilitySlice, &VolatilitySlice) {
+        let i = self.slices.partition_point(|slice| slice.expiry < t);
+
+        match i {
+            0 => (&self.slices[0], &self.slices[0]),
+            _ if i == self.slices.len() => (&self.slices[i - 1], &self.slices[i - 1]),
+            _ if self.slices[i].expiry == t => (&self.slices[i], &self.slices[i]),
+            _ => (&self.slices[i - 1], &self.slices[i]),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_volatility_surface {
+    use super::*;
+    use crate::assert_approx_equal;
+
+    fn log_moneyness() -> Vec<f64> {
+        (-20..=20).map(|i| f64::from(i) * 0.05).collect()
+    }
+
+    fn surface() -> VolatilitySurface {
+        VolatilitySurface::new(vec![
+            VolatilitySlice::new(
+                1.0,
+                102.0,
+                VolatilitySmile::Svi(SVI::new(0.03, 0.1, -0.4, 0.0, 0.2)),
+            )
+            .unwrap(),
+            VolatilitySlice::new(
+                0.5,
+                101.0,
+                VolatilitySmile::Quoted {
+                    strikes: vec![80.0, 90.0, 100.0, 110.0, 120.0],
+                    volatilities: vec![0.28, 0.24, 0.21, 0.20, 0.205],
+                },
+            )
+            .unwrap(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_surface_reproduces_slices() {
+        let surface = surface();
+
+        // Quoted volatilities, and flat extrapolation in strike.
+        assert_approx_equal!(surface.volatility(0.5, 90.0).unwrap(), 0.24, 1e-12);
+        assert_approx_equal!(surface.volatility(0.5, 60.0).unwrap(), 0.28, 1e-12);
+
+        let svi = SVI::new(0.03, 0.1, -0.4, 0.0, 0.2);
+        assert_approx_equal!(
+            surface.volatility(1.0, 95.0).unwrap(),
+            svi.implied_volatility((95.0_f64 / 102.0).ln(), 1.0),
+            1e-12
+        );
+
+        // Flat volatility in time outside the slices.
+        assert_approx_equal!(
+            surface.volatility(2.0, 95.0).unwrap(),
+            surface.volatility(1.0, 95.0).unwrap(),
+            1e-12
+        );
+        assert!(surface.volatility(0.0, 100.0).is_err());
+    }
+
+    #[test]
+    fn test_surface_time_interpolation() {
+        let surface = surface();
+
+        // Total variance is linear in time at constant log-moneyness.
+        let t = 0.75;
+        let k = 0.05;
+        let slices = surface.slices();
+        let expected = 0.5 * slices[0].total_variance(k) + 0.5 * slices[1].total_variance(k);
+
+        assert_approx_equal!(surface.total_variance(t, k), expected, 1e-14);
+        assert_approx_equal!(surface.forward(t), 101.5, 1e-12);
+        assert_approx_equal!(
+            surface.volatility(t, 101.5 * k.exp()).unwrap(),
+            (expected / t).sqrt(),
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_sabr_and_delta_slices() {
+        // Lognormal SABR without volatility of volatility is flat at alpha.
+        let sabr = VolatilitySlice::new(
+            1.0,
+            100.0,
+            VolatilitySmile::Sabr(SabrSmile::new(0.2, 1.0, 0.0, 0.0)),
+        )
+        .unwrap();
+
+        assert_approx_equal!(sabr.volatility(80.0), 0.2, 1e-12);
+        assert_approx_equal!(sabr.volatility(125.0), 0.2, 1e-12);
+
+        // The 50-delta strike is above the forward by half the variance.
+        let deltas =
+            VolatilitySlice::from_deltas(1.0, 100.0, &[0.75, 0.5, 0.25], &[0.22, 0.2, 0.21])
+                .unwrap();
+
+        assert_approx_equal!(deltas.volatility(100.0 * f64::exp(0.02)), 0.2, 1e-12);
+        assert!(VolatilitySlice::from_deltas(1.0, 100.0, &[1.5], &[0.2]).is_err());
+    }
+
+    #[test]
+    fn test_arbitrage_checks() {
+        assert!(surface().is_arbitrage_free(&log_moneyness()));
+
+        // A later slice with less variance.
+        let calendar = VolatilitySurface::new(vec![
+            VolatilitySlice::new(
+                0.5,
+                100.0,
+                VolatilitySmile::Svi(SVI::new(0.04, 0.1, -0.4, 0.0, 0.2)),
+            )
+            .unwrap(),
+            VolatilitySlice::new(
+                1.0,
+                100.0,
+                VolatilitySmile::Svi(SVI::new(0.01, 0.05, -0.4, 0.0, 0.2)),
+            )
+            .unwrap(),
+        ])
+        .unwrap();
+
+        assert!(calendar
+            .arbitrage_violations(&log_moneyness())
+            .iter()
+            .all(|v| matches!(v, ArbitrageViolation::Calendar { expiry, .. } if *expiry == 1.0)));
+        assert!(!calendar.is_arbitrage_free(&log_moneyness()));
+
+        // A spike in the smile makes the call prices concave around it.
+        let butterfly = VolatilitySurface::new(vec![VolatilitySlice::new(
+            1.0,
+            100.0,
+            VolatilitySmile::Quoted {
+                strikes: vec![80.0, 95.0, 100.0, 105.0, 120.0],
+                volatilities: vec![0.2, 0.2, 0.6, 0.2, 0.2],
+            },
+        )
+        .unwrap()])
+        .unwrap();
+
+        assert!(butterfly
+            .arbitrage_violations(&log_moneyness())
+            .iter()
+            .any(|v| matches!(v, ArbitrageViolation::Butterfly { .. })));
+    }
+}
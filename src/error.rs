@@ -36,6 +36,11 @@ pub enum RustQuantError {
     #[error{"An input was missing: {0}"}]
     MissingInput(String),
 
+    /// This error indicates that an iterative search or algorithm
+    /// did not converge within its iteration limit.
+    #[error("Not converged: {0}")]
+    NotConverged(String),
+
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Data related errors
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -0,0 +1,270 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Bond analytics shared by all scheduled bonds.
+//!
+//! A [`Bond`] only has to give its [`Schedule`], coupon frequency, face
+//! value and coupon amounts, and gets accrued interest, clean and dirty
+//! prices, yield to maturity, durations, convexity and the z-spread.
+//!
+//! Yields are compounded at the coupon frequency $f$, with the street
+//! convention for the time to each cash flow: the $k$-th remaining cash flow
+//! ($k = 0, 1, \dots$) is $t_k = (k + w) / f$ years away, where $w$ is the
+//! fraction of the current coupon period left at settlement (in days).
+//!
+//! $$
+//! P_{dirty} = \sum_k \frac{CF_k}{(1 + y / f)^{f t_k}}
+//! $$
+
+use crate::data::Curve;
+use crate::error::RustQuantError;
+use crate::math::rootfinding::{BrentSolver, Solver};
+use crate::time::{days_between, DayCountConvention, Frequency, Schedule};
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// A bond paying coupons on a [`Schedule`] and its face value at maturity.
+///
+/// Prices are per bond (not per 100 of face value), for settlement on the
+/// given date. Cash flows paid on or before settlement are excluded.
+pub trait Bond {
+    /// Coupon (and payment) schedule of the bond.
+    fn schedule(&self) -> &Schedule;
+
+    /// Coupon frequency of the bond, which is also the yield compounding
+    /// frequency.
+    fn frequency(&self) -> Frequency;
+
+    /// Face value, redeemed at maturity.
+    fn face_value(&self) -> f64;
+
+    /// Coupon amount of each period of the schedule
+    /// (see [`Schedule::coupon_periods`]).
+    fn coupon_amounts(&self) -> Vec<f64>;
+
+    /// Remaining cash flows after settlement, as (payment date, amount),
+    /// with the face value redeemed with the last coupon.
+    fn cashflows(&self, settlement: Date) -> Vec<(Date, f64)> {
+        let periods = self.schedule().coupon_periods();
+        let coupons = self.coupon_amounts();
+        let n = periods.len();
+
+        periods
+            .iter()
+            .zip(coupons)
+            .enumerate()
+            .filter(|(_, (period, _))| period.payment > settlement)
+            .map(|(i, (period, coupon))| {
+                let redemption = if i + 1 == n { self.face_value() } else { 0.0 };
+
+                (period.payment, coupon + redemption)
+            })
+            .collect()
+    }
+
+    /// Interest accrued in the current coupon period at settlement, linear
+    /// in the day count of the schedule.
+    fn accrued_interest(&self, settlement: Date) -> f64 {
+        let day_count = self.schedule().day_counting_convention;
+
+        self.schedule()
+            .coupon_periods()
+            .iter()
+            .zip(self.coupon_amounts())
+            .find(|(period, _)| period.start <= settlement && settlement < period.end)
+            .map_or(0.0, |(period, coupon)| {
+                // Actual/Actual ICMA accrues against the whole coupon period.
+                let factor = |end: Date| match day_count {
+                    DayCountConvention::Actual_Actual_ICMA => {
+                        DayCountConvention::day_count_factor_actual_actual_icma_with_reference(
+                            period.start,
+                            end,
+                            period.unadjusted_start,
+                            period.unadjusted_end,
+                        )
+                    }
+                    _ => day_count.day_count_factor(period.start, end),
+                };
+
+                coupon * factor(settlement) / factor(period.end)
+            })
+    }
+
+    /// Dirty (full) price at the yield `ytm`.
+    fn dirty_price(&self, settlement: Date, ytm: f64) -> f64 {
+        let f = self.frequency().times_in_year() as f64;
+
+        yield_times(self, settlement)
+            .iter()
+            .map(|(t, cf)| cf * (1.0 + ytm / f).powf(-f * t))
+            .sum()
+    }
+
+    /// Clean price at the yield `ytm`, i.e. the dirty price less the
+    /// accrued interest.
+    fn clean_price(&self, settlement: Date, ytm: f64) -> f64 {
+        self.dirty_price(settlement, ytm) - self.accrued_interest(settlement)
+    }
+
+    /// Yield to maturity implied by a clean price.
+    ///
+    /// # Errors
+    ///
+    /// - `RustQuantError::InvalidArgument` if the bond has no cash flows
+    ///   after settlement, or the price is not positive.
+    /// - `RustQuantError::NotConverged` if no yield up to 100% reprices
+    ///   the bond.
+    fn yield_to_maturity(&self, settlement: Date, clean_price: f64) -> Result<f64, RustQuantError> {
+        if self.cashflows(settlement).is_empty() {
+            return Err(RustQuantError::InvalidArgument(
+                "the bond has no cash flows after settlement".to_string(),
+            ));
+        }
+        if clean_price.is_nan() || clean_price <= 0.0 {
+            return Err(RustQuantError::InvalidArgument(
+                "the clean price must be positive".to_string(),
+            ));
+        }
+
+        let f = self.frequency().times_in_year() as f64;
+        let objective = |y: f64| self.clean_price(settlement, y) - clean_price;
+
+        BrentSolver::default()
+            .solve(objective, -0.99 * f, 1.0)
+            .map_err(|_| {
+                RustQuantError::NotConverged(format!(
+                    "no yield to maturity reprices the bond at {clean_price}"
+                ))
+            })
+    }
+
+    /// Macaulay duration (in years) at the yield `ytm`: the present value
+    /// weighted average time to the cash flows.
+    fn macaulay_duration(&self, settlement: Date, ytm: f64) -> f64 {
+        let f = self.frequency().times_in_year() as f64;
+
+        let weighted: f64 = yield_times(self, settlement)
+            .iter()
+            .map(|(t, cf)| t * cf * (1.0 + ytm / f).powf(-f * t))
+            .sum();
+
+        weighted / self.dirty_price(settlement, ytm)
+    }
+
+    /// Modified duration at the yield `ytm`, i.e. minus the relative
+    /// sensitivity of the dirty price to the yield.
+    fn modified_duration(&self, settlement: Date, ytm: f64) -> f64 {
+        let f = self.frequency().times_in_year() as f64;
+
+        self.macaulay_duration(settlement, ytm) / (1.0 + ytm / f)
+    }
+
+    /// Convexity at the yield `ytm`, i.e. the second derivative of the
+    /// dirty price with respect to the yield, relative to the dirty price.
+    fn convexity(&self, settlement: Date, ytm: f64) -> f64 {
+        let f = self.frequency().times_in_year() as f64;
+
+        let second: f64 = yield_times(self, settlement)
+            .iter()
+            .map(|(t, cf)| cf * t * (t + 1.0 / f) * (1.0 + ytm / f).powf(-f * t - 2.0))
+            .sum();
+
+        second / self.dirty_price(settlement, ytm)
+    }
+
+    /// Dirty price discounted off `curve` (as of settlement), with each
+    /// discount factor shifted by a continuously compounded spread:
+    /// $\sum_k CF_k \frac{P(T_k)}{P(T_s)} e^{-z t_k}$.
+    fn dirty_price_with_spread<C: Curve>(&self, settlement: Date, curve: &C, spread: f64) -> f64
+    where
+        Self: Sized,
+    {
+        let settlement_df = curve.discount_factor(settlement);
+
+        self.cashflows(settlement)
+            .iter()
+            .zip(yield_times(self, settlement))
+            .map(|((date, cf), (t, _))| {
+                cf * curve.discount_factor(*date) / settlement_df * f64::exp(-spread * t)
+            })
+            .sum()
+    }
+
+    /// Z-spread over `curve`: the constant spread to the curve's zero rates
+    /// that reprices the bond at the given clean price.
+    ///
+    /// # Errors
+    ///
+    /// - `RustQuantError::InvalidArgument` as for [`Bond::yield_to_maturity`].
+    /// - `RustQuantError::NotConverged` if no spread in (-100%, 100%)
+    ///   reprices the bond.
+    fn z_spread<C: Curve>(
+        &self,
+        settlement: Date,
+        clean_price: f64,
+        curve: &C,
+    ) -> Result<f64, RustQuantError>
+    where
+        Self: Sized,
+    {
+        if self.cashflows(settlement).is_empty() {
+            return Err(RustQuantError::InvalidArgument(
+                "the bond has no cash flows after settlement".to_string(),
+            ));
+        }
+        if clean_price.is_nan() || clean_price <= 0.0 {
+            return Err(RustQuantError::InvalidArgument(
+                "the clean price must be positive".to_string(),
+            ));
+        }
+
+        let dirty_price = clean_price + self.accrued_interest(settlement);
+        let objective = |z: f64| self.dirty_price_with_spread(settlement, curve, z) - dirty_price;
+
+        BrentSolver::default()
+            .solve(objective, -1.0, 1.0)
+            .map_err(|_| {
+                RustQuantError::NotConverged(format!(
+                    "no z-spread reprices the bond at {clean_price}"
+                ))
+            })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Remaining cash flows as (time in years, amount), with the street
+/// convention for the times (see the module documentation).
+fn yield_times<B: Bond + ?Sized>(bond: &B, settlement: Date) -> Vec<(f64, f64)> {
+    let f = bond.frequency().times_in_year() as f64;
+
+    let Some(current) = bond
+        .schedule()
+        .coupon_periods()
+        .into_iter()
+        .find(|period| period.payment > settlement)
+    else {
+        return Vec::new();
+    };
+
+    // Fraction of the current period left, which exceeds one before issue.
+    let w = days_between(settlement, current.end) as f64
+        / days_between(current.start, current.end) as f64;
+
+    bond.cashflows(settlement)
+        .into_iter()
+        .enumerate()
+        .map(|(k, (_, cf))| ((k as f64 + w) / f, cf))
+        .collect()
+}
@@ -0,0 +1,237 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Bond futures: conversion factors and the cheapest-to-deliver bond.
+//!
+//! A bond future is settled by delivering any bond from a basket of
+//! deliverable bonds. The invoice amount of a bond with conversion factor
+//! $CF$ is $F \cdot CF + AI$, where $F$ is the futures price and $AI$ the
+//! accrued interest at delivery. The short chooses the bond that is
+//! cheapest to deliver, i.e. the one with the smallest net basis
+//! (equivalently, roughly, the highest implied repo rate).
+
+use crate::time::Frequency;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// A bond in the deliverable basket of a bond future.
+///
+/// Prices and accrued interest are per unit of face value.
+#[derive(Debug, Clone, Copy)]
+pub struct DeliverableBond {
+    /// Annual coupon rate of the bond.
+    pub coupon_rate: f64,
+
+    /// Coupon frequency of the bond.
+    pub coupon_frequency: Frequency,
+
+    /// Time (in years) from the delivery date to the maturity of the bond.
+    pub years_to_maturity: f64,
+
+    /// Clean price of the bond today.
+    pub clean_price: f64,
+
+    /// Accrued interest today.
+    pub accrued_interest: f64,
+
+    /// Accrued interest at the delivery date.
+    pub accrued_at_delivery: f64,
+
+    /// Coupons paid between today and the delivery date
+    /// (reinvestment is ignored).
+    pub interim_coupons: f64,
+}
+
+/// Delivery analytics of one bond in the basket.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeliveryAnalytics {
+    /// Conversion factor of the bond.
+    pub conversion_factor: f64,
+
+    /// Gross basis: clean price minus the converted futures price.
+    pub gross_basis: f64,
+
+    /// Net basis: gross basis minus the carry to delivery.
+    pub net_basis: f64,
+
+    /// Implied repo rate of buying the bond and delivering it into the future.
+    pub implied_repo_rate: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl DeliverableBond {
+    /// Conversion factor of the bond against a notional coupon
+    /// (e.g. 6% for US Treasury futures).
+    ///
+    /// This is the clean price, per unit of face value, of the bond at a
+    /// yield equal to the notional coupon, with the time to maturity rounded
+    /// down to a whole number of coupon periods.
+    #[must_use]
+    pub fn conversion_factor(&self, notional_coupon: f64) -> f64 {
+        let frequency = self.coupon_frequency.times_in_year() as f64;
+        let n_periods = (self.years_to_maturity * frequency + 1e-9).floor() as i32;
+
+        let coupon = self.coupon_rate / frequency;
+        let discount = 1.0 / (1.0 + notional_coupon / frequency);
+
+        let coupons: f64 = (1..=n_periods).map(|i| coupon * discount.powi(i)).sum();
+
+        coupons + discount.powi(n_periods)
+    }
+
+    /// Delivery analytics of the bond for a futures price, a term repo rate
+    /// (simple interest), and the time (in years) to delivery.
+    #[must_use]
+    pub fn delivery_analytics(
+        &self,
+        futures_price: f64,
+        repo_rate: f64,
+        time_to_delivery: f64,
+        notional_coupon: f64,
+    ) -> DeliveryAnalytics {
+        let conversion_factor = self.conversion_factor(notional_coupon);
+        let dirty_price = self.clean_price + self.accrued_interest;
+
+        let gross_basis = self.clean_price - futures_price * conversion_factor;
+
+        // Carry: coupon income less the cost of financing the bond.
+        let coupon_income = self.accrued_at_delivery + self.interim_coupons - self.accrued_interest;
+        let financing = dirty_price * repo_rate * time_to_delivery;
+        let net_basis = gross_basis - (coupon_income - financing);
+
+        let invoice = futures_price * conversion_factor + self.accrued_at_delivery;
+        let implied_repo_rate =
+            (invoice + self.interim_coupons - dirty_price) / (dirty_price * time_to_delivery);
+
+        DeliveryAnalytics {
+            conversion_factor,
+            gross_basis,
+            net_basis,
+            implied_repo_rate,
+        }
+    }
+}
+
+/// Cheapest-to-deliver bond of a basket: the bond with the smallest net basis.
+///
+/// Returns the index of the bond in the basket with its delivery analytics,
+/// or `None` if the basket is empty.
+#[must_use]
+pub fn cheapest_to_deliver(
+    bonds: &[DeliverableBond],
+    futures_price: f64,
+    repo_rate: f64,
+    time_to_delivery: f64,
+    notional_coupon: f64,
+) -> Option<(usize, DeliveryAnalytics)> {
+    bonds
+        .iter()
+        .map(|bond| {
+            bond.delivery_analytics(futures_price, repo_rate, time_to_delivery, notional_coupon)
+        })
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.net_basis.total_cmp(&b.net_basis))
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_bond_future {
+    use super::*;
+    use crate::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    fn bond(coupon_rate: f64, years_to_maturity: f64, clean_price: f64) -> DeliverableBond {
+        DeliverableBond {
+            coupon_rate,
+            coupon_frequency: Frequency::SemiAnnually,
+            years_to_maturity,
+            clean_price,
+            accrued_interest: 0.25 * coupon_rate / 2.0,
+            accrued_at_delivery: 0.75 * coupon_rate / 2.0,
+            interim_coupons: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_conversion_factor_at_notional_coupon() {
+        for years in [5.0, 10.25, 20.9] {
+            assert_approx_equal!(
+                bond(0.06, years, 1.0).conversion_factor(0.06),
+                1.0,
+                RUSTQUANT_EPSILON
+            );
+        }
+
+        // Higher coupons convert at a premium, lower coupons at a discount.
+        assert!(bond(0.08, 10.0, 1.0).conversion_factor(0.06) > 1.0);
+        assert!(bond(0.04, 10.0, 1.0).conversion_factor(0.06) < 1.0);
+    }
+
+    #[test]
+    fn test_cheapest_to_deliver() {
+        let (futures_price, repo_rate, time_to_delivery) = (1.12, 0.03, 0.25);
+
+        let basket = [
+            bond(0.04, 8.0, 0.982),
+            bond(0.0625, 15.0, 1.149),
+            bond(0.075, 22.0, 1.326),
+        ];
+
+        // Conversion factors at 6%: (c / 2) a_n + v^n, with v = 1 / 1.03
+        // and n = 16, 30, 44 half-years.
+        // Net basis: clean - 1.12 CF - (c / 4 - dirty * 0.03 * 0.25), where
+        // the coupon income to delivery is half a coupon, c / 4.
+        // Implied repo: (1.12 CF + 0.75 c / 2 - dirty) / (dirty * 0.25).
+        let expected = [
+            (
+                0.874_388_979_740_038,
+                0.000_086_842_691_157,
+                0.029_648_053_936_547,
+            ),
+            (
+                1.024_500_551_686_838,
+                -0.005_389_524_139_258,
+                0.048_635_774_213_222,
+            ),
+            (
+                1.181_907_054_380_63,
+                -0.006_470_588_406_306,
+                0.049_382_086_399_12,
+            ),
+        ];
+
+        for (bond, (conversion_factor, net_basis, implied_repo_rate)) in basket.iter().zip(expected)
+        {
+            let analytics =
+                bond.delivery_analytics(futures_price, repo_rate, time_to_delivery, 0.06);
+
+            assert_approx_equal!(analytics.conversion_factor, conversion_factor, 1e-12);
+            assert_approx_equal!(analytics.net_basis, net_basis, 1e-12);
+            assert_approx_equal!(analytics.implied_repo_rate, implied_repo_rate, 1e-12);
+        }
+
+        // The 7.5% bond has the smallest net basis (and the highest implied repo).
+        let (index, ctd) =
+            cheapest_to_deliver(&basket, futures_price, repo_rate, time_to_delivery, 0.06).unwrap();
+
+        assert_eq!(index, 2);
+        assert_approx_equal!(ctd.net_basis, -0.006_470_588_406_306, 1e-12);
+
+        assert!(
+            cheapest_to_deliver(&[], futures_price, repo_rate, time_to_delivery, 0.06).is_none()
+        );
+    }
+}
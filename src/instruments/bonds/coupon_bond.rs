@@ -8,7 +8,8 @@
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 use super::zero_coupon_bond::ZeroCouponBond;
-use crate::data::{Curve, YieldCurve};
+use crate::data::{Curve, MarketData, YieldCurve};
+use crate::error::RustQuantError;
 use crate::instruments::fx::currency::Currency;
 use crate::instruments::Instrument;
 use crate::time::{DateRollingConvention, Frequency};
@@ -77,53 +78,79 @@ pub struct CouponBond2 {
 impl CouponBond {
     /// Constructs the coupons of the bond.
     pub fn construct_coupons(&mut self) {
-        let mut coupons: BTreeMap<Date, f64> = BTreeMap::new();
+        let notionals = vec![self.face_value; self.coupon_dates().len()];
 
-        // Create the coupon dates
-        let years = (self.expiration_date - self.evaluation_date).whole_days() / 365;
-        let n_coupons = years * self.coupon_frequency as i64;
+        self.construct_amortizing_coupons(&notionals);
+    }
 
-        let mut coupon_dates: Vec<Date> = Vec::with_capacity(n_coupons as usize);
+    /// Constructs the coupons of an amortizing (or sinking-fund) bond.
+    ///
+    /// `notionals[i]` is the notional outstanding over the `i`-th coupon
+    /// period, on which that period's coupon is computed. The difference
+    /// between consecutive notionals is repaid with each coupon, and the
+    /// remaining notional is repaid at maturity. A flat notional equal to
+    /// the face value gives the bullet bond of [`CouponBond::construct_coupons`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not one notional per coupon period.
+    pub fn construct_amortizing_coupons(&mut self, notionals: &[f64]) {
+        let coupon_dates = self.coupon_dates();
+
+        assert_eq!(
+            notionals.len(),
+            coupon_dates.len(),
+            "There must be one notional per coupon period."
+        );
 
-        for i in 1..=n_coupons {
-            let coupon_date =
-                self.evaluation_date + Duration::days(365 * i) / self.coupon_frequency as i32;
+        let coupon_rate = self.coupon_rate / self.coupon_frequency as isize as f64;
 
-            coupon_dates.push(coupon_date);
-        }
+        self.coupons = coupon_dates
+            .iter()
+            .enumerate()
+            .map(|(i, date)| {
+                let repayment = notionals[i] - notionals.get(i + 1).copied().unwrap_or(0.0);
 
-        // Create the coupon amounts
-        let mut coupon_amounts: Vec<f64> = Vec::with_capacity(n_coupons as usize);
-
-        for _ in 1..n_coupons {
-            let coupon_amount =
-                self.face_value * self.coupon_rate / self.coupon_frequency as isize as f64;
+                (*date, notionals[i] * coupon_rate + repayment)
+            })
+            .collect();
+    }
 
-            coupon_amounts.push(coupon_amount);
-        }
+    /// The coupon dates of the bond, the last of which is the expiration date.
+    fn coupon_dates(&self) -> Vec<Date> {
+        let years = (self.expiration_date - self.evaluation_date).whole_days() / 365;
+        let n_coupons = years * self.coupon_frequency as i64;
 
-        // Create the coupons
-        for (date, amount) in coupon_dates.iter().zip(coupon_amounts.iter()) {
-            coupons.insert(*date, *amount);
-        }
+        let mut coupon_dates: Vec<Date> = (1..n_coupons)
+            .map(|i| self.evaluation_date + Duration::days(365 * i) / self.coupon_frequency as i32)
+            .collect();
 
-        // Add the final coupon
-        coupons.insert(
-            self.expiration_date,
-            self.face_value * (1.0 + self.coupon_rate / self.coupon_frequency as isize as f64),
-        );
+        coupon_dates.push(self.expiration_date);
 
-        self.coupons = coupons;
+        coupon_dates
     }
 }
 
-impl Instrument for CouponBond {
-    /// Returns the price (net present value) of the instrument.
-    fn price(&self) -> f64 {
+impl CouponBond {
+    /// Returns the net present value of the bond as of the snapshot's
+    /// valuation date, discounting off the named curve in the given market
+    /// data snapshot instead of the bond's own yield curve.
+    /// Coupons paid before the valuation date are excluded.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if the snapshot has no such curve.
+    pub fn npv(&self, market_data: &MarketData, curve_name: &str) -> Result<f64, RustQuantError> {
+        self.coupons
+            .range(market_data.valuation_date..)
+            .map(|(date, coupon)| Ok(coupon * market_data.discount_factor(curve_name, *date)?))
+            .sum()
+    }
+
+    /// Present value of the coupons, discounted off the given curve.
+    fn present_value(&self, curve: &YieldCurve) -> f64 {
         // Compute the discount factors for the coupons.
-        let discount_factors = self
-            .yield_curve
-            .discount_factors(&self.coupons.keys().copied().collect::<Vec<Date>>());
+        let discount_factors =
+            curve.discount_factors(&self.coupons.keys().copied().collect::<Vec<Date>>());
         // .iter()
         // .enumerate()
         // .map(|(i, df)| (1. + df / self.coupon_frequency as i32 as f64).powi((i + 1) as i32))
@@ -136,6 +163,13 @@ impl Instrument for CouponBond {
             .map(|(coupon, df)| coupon * df)
             .sum::<f64>()
     }
+}
+
+impl Instrument for CouponBond {
+    /// Returns the price (net present value) of the instrument.
+    fn price(&self) -> f64 {
+        self.present_value(&self.yield_curve)
+    }
 
     /// Returns the error on the NPV in case the pricing engine can
     /// provide it (e.g. Monte Carlo pricing engine).
@@ -181,7 +215,7 @@ impl CouponBond2 {
 #[cfg(test)]
 mod tests_bond {
     use super::*;
-    use crate::{data::Curve, iso::USD, time::today};
+    use crate::{assert_approx_equal, iso::USD, time::today};
 
     #[allow(clippy::similar_names)]
     fn create_test_yield_curve(t0: Date) -> YieldCurve {
@@ -225,4 +259,98 @@ mod tests_bond {
         // and the calculator I used. Possibly continuous compounding vs discrete.
         println!("Price: {}", bond.price());
     }
+
+    #[test]
+    fn test_npv_from_market_data() {
+        let today = today();
+
+        let mut bond = CouponBond {
+            evaluation_date: today,
+            expiration_date: today + Duration::days(365 * 2),
+            currency: Some(USD),
+            coupon_rate: 0.05,
+            coupon_frequency: Frequency::SemiAnnually,
+            settlement_convention: DateRollingConvention::Actual,
+            yield_curve: create_test_yield_curve(today),
+            face_value: 1000.0,
+            coupons: BTreeMap::new(),
+        };
+        bond.construct_coupons();
+
+        let mut market_data = MarketData::new(today);
+        market_data.add_curve("UST", create_test_yield_curve(today));
+
+        assert_approx_equal!(bond.npv(&market_data, "UST").unwrap(), bond.price(), 1e-12);
+        assert!(matches!(
+            bond.npv(&market_data, "SOFR"),
+            Err(RustQuantError::MissingInput(_))
+        ));
+
+        // Later snapshots drop the paid coupons and discount from their own date.
+        let first_coupon = *bond.coupons.keys().next().unwrap();
+        let mut later = MarketData::new(first_coupon + Duration::days(1));
+        later.add_curve("UST", create_test_yield_curve(today));
+
+        let curve = create_test_yield_curve(today);
+        let expected: f64 = bond
+            .coupons
+            .iter()
+            .skip(1)
+            .map(|(date, coupon)| {
+                coupon * curve.discount_factor(*date) / curve.discount_factor(later.valuation_date)
+            })
+            .sum();
+
+        assert_approx_equal!(bond.npv(&later, "UST").unwrap(), expected, 1e-12);
+    }
+
+    #[test]
+    fn test_amortizing_coupons() {
+        let today = today();
+
+        let bond = || CouponBond {
+            evaluation_date: today,
+            expiration_date: today + Duration::days(365 * 2),
+            currency: Some(USD),
+            coupon_rate: 0.15,
+            coupon_frequency: Frequency::SemiAnnually,
+            settlement_convention: DateRollingConvention::Actual,
+            yield_curve: create_test_yield_curve(today),
+            face_value: 1000.0,
+            coupons: BTreeMap::new(),
+        };
+
+        let mut bullet = bond();
+        bullet.construct_coupons();
+
+        // A flat notional is the bullet bond.
+        let mut flat = bond();
+        flat.construct_amortizing_coupons(&[1000.0; 4]);
+
+        assert_eq!(flat.coupons, bullet.coupons);
+        assert_approx_equal!(flat.price(), bullet.price(), 1e-12);
+
+        // Linear amortization: 250 is repaid with each coupon.
+        let notionals = [1000.0, 750.0, 500.0, 250.0];
+        let mut amortizing = bond();
+        amortizing.construct_amortizing_coupons(&notionals);
+
+        let bullet_interest = 1000.0 * 0.15 / 2.0;
+
+        for (amount, notional) in amortizing.coupons.values().zip(notionals) {
+            // Interest is paid on the outstanding notional only.
+            assert_approx_equal!(amount - 250.0, notional / 1000.0 * bullet_interest, 1e-10);
+        }
+
+        // The total principal repaid is the face value.
+        let interest: f64 = notionals.iter().map(|n| n * 0.15 / 2.0).sum();
+        assert_approx_equal!(
+            amortizing.coupons.values().sum::<f64>() - interest,
+            1000.0,
+            1e-10
+        );
+
+        // Above-market coupons on a shrinking notional are worth less.
+        assert!(amortizing.price() < bullet.price());
+    }
 }
@@ -0,0 +1,215 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use super::bond::Bond;
+use crate::time::{Frequency, Schedule};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Fixed-rate bond.
+///
+/// Each coupon is `face_value * coupon_rate * tau_i`, where `tau_i` is the
+/// accrual factor of the `i`-th period of the schedule under the schedule's
+/// day count convention (see [`Schedule::accrual_schedule`]), so that under
+/// Actual/Actual ICMA each regular coupon is exactly `coupon_rate / f`.
+/// The analytics are provided by the [`Bond`] trait.
+#[allow(clippy::module_name_repetitions)]
+pub struct FixedRateBond {
+    /// Coupon schedule, from the issue date to maturity.
+    pub schedule: Schedule,
+
+    /// Coupon frequency.
+    pub frequency: Frequency,
+
+    /// Annual coupon rate.
+    pub coupon_rate: f64,
+
+    /// Face value, redeemed at maturity.
+    pub face_value: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl FixedRateBond {
+    /// New fixed-rate bond.
+    #[must_use]
+    pub fn new(
+        schedule: Schedule,
+        frequency: Frequency,
+        coupon_rate: f64,
+        face_value: f64,
+    ) -> Self {
+        Self {
+            schedule,
+            frequency,
+            coupon_rate,
+            face_value,
+        }
+    }
+}
+
+impl Bond for FixedRateBond {
+    fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    fn face_value(&self) -> f64 {
+        self.face_value
+    }
+
+    fn coupon_amounts(&self) -> Vec<f64> {
+        self.schedule
+            .accrual_schedule(self.schedule.day_counting_convention)
+            .iter()
+            .map(|tau| self.face_value * self.coupon_rate * tau)
+            .collect()
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_fixed_rate_bond {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use crate::error::RustQuantError;
+    use crate::time::{
+        countries::north_america::united_states::UnitedStatesCalendar, DateRollingConvention,
+        DayCountConvention,
+    };
+    use time::macros::date;
+
+    /// 5% semi-annual bond, from 15 January 2024 to 15 January 2029.
+    fn bond() -> FixedRateBond {
+        let schedule = Schedule::regular(
+            date!(2024 - 01 - 15),
+            date!(2029 - 01 - 15),
+            Frequency::SemiAnnually,
+            &UnitedStatesCalendar,
+            DateRollingConvention::Actual,
+            DayCountConvention::Actual_Actual_ICMA,
+        )
+        .unwrap();
+
+        FixedRateBond::new(schedule, Frequency::SemiAnnually, 0.05, 100.0)
+    }
+
+    #[test]
+    fn test_par_bond() {
+        let bond = bond();
+        let issue = date!(2024 - 01 - 15);
+
+        assert_eq!(bond.cashflows(issue).len(), 10);
+        assert!(bond
+            .coupon_amounts()
+            .iter()
+            .all(|c| (c - 2.5).abs() < 1e-12));
+
+        // On a coupon date, a bond yielding its coupon is priced at par.
+        assert_approx_equal!(bond.clean_price(issue, 0.05), 100.0, 1e-10);
+        assert_approx_equal!(bond.accrued_interest(issue), 0.0, 1e-12);
+        assert_approx_equal!(bond.yield_to_maturity(issue, 100.0).unwrap(), 0.05, 1e-10);
+
+        // Premium and discount bonds.
+        assert!(bond.yield_to_maturity(issue, 102.0).unwrap() < 0.05);
+        assert!(bond.yield_to_maturity(issue, 98.0).unwrap() > 0.05);
+    }
+
+    #[test]
+    fn test_accrued_interest_and_yield() {
+        let bond = bond();
+
+        // 91 of the 182 days from 15 January to 15 July 2024.
+        let settlement = date!(2024 - 04 - 15);
+        assert_approx_equal!(bond.accrued_interest(settlement), 2.5 * 91.0 / 182.0, 1e-12);
+
+        assert_approx_equal!(
+            bond.dirty_price(settlement, 0.06) - bond.clean_price(settlement, 0.06),
+            bond.accrued_interest(settlement),
+            1e-12
+        );
+
+        let clean = bond.clean_price(settlement, 0.0437);
+        assert_approx_equal!(
+            bond.yield_to_maturity(settlement, clean).unwrap(),
+            0.0437,
+            1e-10
+        );
+
+        // Nothing is left after maturity.
+        assert!(matches!(
+            bond.yield_to_maturity(date!(2029 - 01 - 15), 100.0),
+            Err(RustQuantError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_duration_and_convexity() {
+        let bond = bond();
+        let settlement = date!(2025 - 03 - 01);
+        let (y, h) = (0.045, 1e-5);
+
+        let price = |y: f64| bond.dirty_price(settlement, y);
+        let p = price(y);
+
+        let modified = -(price(y + h) - price(y - h)) / (2.0 * h * p);
+        let convexity = (price(y + h) - 2.0 * p + price(y - h)) / (h * h * p);
+
+        assert_approx_equal!(bond.modified_duration(settlement, y), modified, 1e-6);
+        assert_approx_equal!(bond.convexity(settlement, y), convexity, 1e-3);
+        assert_approx_equal!(
+            bond.macaulay_duration(settlement, y),
+            bond.modified_duration(settlement, y) * (1.0 + y / 2.0),
+            1e-12
+        );
+
+        // With one cash flow left, the Macaulay duration is its time.
+        let last = date!(2028 - 10 - 15);
+        let w = 92.0 / 184.0;
+        assert_approx_equal!(bond.macaulay_duration(last, y), w / 2.0, 1e-12);
+    }
+
+    #[test]
+    fn test_z_spread() {
+        let bond = bond();
+        let settlement = date!(2024 - 06 - 03);
+
+        let curve = YieldCurve::from_dates_and_rates(
+            &[
+                date!(2024 - 01 - 01),
+                date!(2026 - 01 - 01),
+                date!(2030 - 01 - 01),
+            ],
+            &[0.03, 0.035, 0.04],
+        );
+
+        let dirty = bond.dirty_price_with_spread(settlement, &curve, 0.0125);
+        let clean = dirty - bond.accrued_interest(settlement);
+
+        assert_approx_equal!(
+            bond.z_spread(settlement, clean, &curve).unwrap(),
+            0.0125,
+            1e-10
+        );
+
+        // A wider spread means a cheaper bond.
+        assert!(bond.dirty_price_with_spread(settlement, &curve, 0.02) < dirty);
+    }
+}
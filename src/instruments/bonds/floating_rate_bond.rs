@@ -0,0 +1,224 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use super::bond::Bond;
+use crate::data::{Curve, YieldCurve};
+use crate::time::{Frequency, Schedule};
+use std::collections::BTreeMap;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Floating-rate bond (floater), paying an index rate plus a quoted spread.
+///
+/// Each coupon is `face_value * (L_i + spread) * tau_i`, where `tau_i` is
+/// the accrual factor of the `i`-th period of the schedule. The index rate
+/// `L_i` is the fixing of the period if it is known, or otherwise the simple
+/// forward rate over the period projected off the forecast curve:
+///
+/// $$
+/// L_i = \frac{1}{\tau_i} \left( \frac{P(t_{i-1})}{P(t_i)} - 1 \right)
+/// $$
+///
+/// The analytics are provided by the [`Bond`] trait.
+#[allow(clippy::module_name_repetitions)]
+pub struct FloatingRateBond {
+    /// Coupon schedule, from the issue date to maturity.
+    pub schedule: Schedule,
+
+    /// Coupon frequency.
+    pub frequency: Frequency,
+
+    /// Quoted spread over the index rate.
+    pub spread: f64,
+
+    /// Face value, redeemed at maturity.
+    pub face_value: f64,
+
+    /// Curve used to project the index rate of the unfixed periods.
+    pub forecast_curve: YieldCurve,
+
+    /// Known index fixings, keyed by the (adjusted) start date of their period.
+    pub fixings: BTreeMap<Date, f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl FloatingRateBond {
+    /// New floating-rate bond, with no known fixings.
+    #[must_use]
+    pub fn new(
+        schedule: Schedule,
+        frequency: Frequency,
+        spread: f64,
+        face_value: f64,
+        forecast_curve: YieldCurve,
+    ) -> Self {
+        Self {
+            schedule,
+            frequency,
+            spread,
+            face_value,
+            forecast_curve,
+            fixings: BTreeMap::new(),
+        }
+    }
+
+    /// Set the known index fixings, keyed by the start date of their period.
+    #[must_use]
+    pub fn with_fixings(mut self, fixings: BTreeMap<Date, f64>) -> Self {
+        self.fixings = fixings;
+        self
+    }
+
+    /// Index rate of each period of the schedule: the fixing if known,
+    /// otherwise the forward rate projected off the forecast curve.
+    #[must_use]
+    pub fn index_rates(&self) -> Vec<f64> {
+        let accruals = self
+            .schedule
+            .accrual_schedule(self.schedule.day_counting_convention);
+
+        self.schedule
+            .coupon_periods()
+            .iter()
+            .zip(accruals)
+            .map(|(period, tau)| {
+                self.fixings.get(&period.start).copied().unwrap_or_else(|| {
+                    let df_start = self.forecast_curve.discount_factor(period.start);
+                    let df_end = self.forecast_curve.discount_factor(period.end);
+
+                    (df_start / df_end - 1.0) / tau
+                })
+            })
+            .collect()
+    }
+}
+
+impl Bond for FloatingRateBond {
+    fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    fn face_value(&self) -> f64 {
+        self.face_value
+    }
+
+    fn coupon_amounts(&self) -> Vec<f64> {
+        self.schedule
+            .accrual_schedule(self.schedule.day_counting_convention)
+            .iter()
+            .zip(self.index_rates())
+            .map(|(tau, rate)| self.face_value * (rate + self.spread) * tau)
+            .collect()
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_floating_rate_bond {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::time::{
+        countries::north_america::united_states::UnitedStatesCalendar, DateRollingConvention,
+        DayCountConvention,
+    };
+    use time::macros::date;
+
+    fn curve() -> YieldCurve {
+        YieldCurve::from_dates_and_rates(
+            &[
+                date!(2024 - 01 - 15),
+                date!(2026 - 01 - 15),
+                date!(2030 - 01 - 15),
+            ],
+            &[0.04, 0.042, 0.045],
+        )
+    }
+
+    /// Quarterly floater from 15 January 2024 to 15 January 2027.
+    fn floater(spread: f64) -> FloatingRateBond {
+        let schedule = Schedule::regular(
+            date!(2024 - 01 - 15),
+            date!(2027 - 01 - 15),
+            Frequency::Quarterly,
+            &UnitedStatesCalendar,
+            DateRollingConvention::Actual,
+            DayCountConvention::Actual_Actual_ISDA,
+        )
+        .unwrap();
+
+        FloatingRateBond::new(schedule, Frequency::Quarterly, spread, 100.0, curve())
+    }
+
+    #[test]
+    fn test_floater_prices_at_par() {
+        // Discounting off the forecast curve, a floater without a spread is
+        // worth par on its coupon dates.
+        let bond = floater(0.0);
+        let curve = curve();
+
+        for settlement in [date!(2024 - 01 - 15), date!(2025 - 04 - 15)] {
+            assert_approx_equal!(
+                bond.dirty_price_with_spread(settlement, &curve, 0.0),
+                100.0,
+                1e-10
+            );
+        }
+
+        // A positive quoted spread is worth more, and its z-spread at par
+        // is close to the quoted spread.
+        let bond = floater(0.01);
+        let settlement = date!(2024 - 01 - 15);
+
+        assert!(bond.dirty_price_with_spread(settlement, &curve, 0.0) > 100.0);
+        assert!((bond.z_spread(settlement, 100.0, &curve).unwrap() - 0.01).abs() < 5e-4);
+    }
+
+    #[test]
+    fn test_floater_fixings_and_analytics() {
+        let settlement = date!(2024 - 02 - 15);
+        let bond = floater(0.005).with_fixings(BTreeMap::from([(date!(2024 - 01 - 15), 0.0525)]));
+
+        // The current coupon is fixed, and accrues 31 of its 91 days.
+        let rates = bond.index_rates();
+        assert_approx_equal!(rates[0], 0.0525, 1e-12);
+        assert!((rates[1] - 0.04).abs() < 0.005);
+
+        let coupon = bond.coupon_amounts()[0];
+        assert_approx_equal!(coupon, 100.0 * 0.0575 * 91.0 / 366.0, 1e-12);
+        assert_approx_equal!(
+            bond.accrued_interest(settlement),
+            coupon * 31.0 / 91.0,
+            1e-12
+        );
+
+        // Yield round trip, and a short duration for a quarterly floater
+        // with its coupons projected to maturity.
+        let clean = bond.clean_price(settlement, 0.05);
+        assert_approx_equal!(
+            bond.yield_to_maturity(settlement, clean).unwrap(),
+            0.05,
+            1e-10
+        );
+        assert!(bond.modified_duration(settlement, 0.05) > 2.5);
+        assert!(bond.convexity(settlement, 0.05) > 0.0);
+    }
+}
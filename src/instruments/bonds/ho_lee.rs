@@ -0,0 +1,305 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Ho-Lee model for zero-coupon bond prices.
+//!
+//! The risk-neutral short rate follows the process:
+//!
+//! $$
+//! dr_t = \theta(t) dt + \sigma dW_t
+//! $$
+//!
+//! where the drift $\theta(t)$ is chosen so that the model reprices an
+//! initial discount curve $P(0, T)$. Zero-coupon bonds then have the
+//! closed form:
+//!
+//! $$
+//! P(t, T) = \frac{P(0, T)}{P(0, t)}
+//!     \exp\left( (T - t) f(0, t) - \frac{\sigma^2}{2} t (T - t)^2 - (T - t) r_t \right)
+//! $$
+//!
+//! where $f(0, t)$ is the instantaneous forward rate of the initial curve.
+//!
+//! Times are in years from the initial date of the discount curve, and are
+//! mapped to curve dates by rounding to the nearest day.
+
+use crate::data::Curve;
+use time::{Date, Duration};
+
+/// Days per year used to map model times to curve dates.
+const DAYS_PER_YEAR: f64 = 365.25;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Ho-Lee short-rate model calibrated to an initial discount curve.
+pub struct HoLeeModel<'a, C>
+where
+    C: Curve + ?Sized,
+{
+    /// `sigma` - Volatility of the short rate.
+    pub sigma: f64,
+
+    /// Initial discount curve.
+    pub discount_curve: &'a C,
+}
+
+/// A recombining binomial tree of Ho-Lee short rates.
+///
+/// At step `i` node `j` (for `j = 0..=i`) the short rate, applied over one
+/// step, is $r_{i,j} = \alpha_i + \sigma \sqrt{\Delta t} (2j - i)$, and each
+/// branch has probability one half. The $\alpha_i$ are fitted so that the
+/// tree reprices the initial discount curve at every step.
+#[derive(Debug, Clone)]
+pub struct HoLeeTree {
+    /// Length (in years) of each step.
+    pub dt: f64,
+
+    /// Short rates, where `rates[i][j]` is the rate at step `i`, node `j`.
+    pub rates: Vec<Vec<f64>>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<'a, C> HoLeeModel<'a, C>
+where
+    C: Curve + ?Sized,
+{
+    /// Create a new Ho-Lee model.
+    pub fn new(sigma: f64, discount_curve: &'a C) -> Self {
+        Self {
+            sigma,
+            discount_curve,
+        }
+    }
+
+    /// Curve date a number of days after the curve's initial date.
+    fn curve_date(&self, days: i64) -> Date {
+        self.discount_curve.initial_date() + Duration::days(days)
+    }
+
+    /// Discount factor $P(0, t)$ of the initial curve at time `t` (in years).
+    #[must_use]
+    pub fn discount_factor(&self, t: f64) -> f64 {
+        let days = (t * DAYS_PER_YEAR).round() as i64;
+
+        self.discount_curve.discount_factor(self.curve_date(days))
+    }
+
+    /// Instantaneous forward rate $f(0, t)$ of the initial curve,
+    /// by central differences of the log discount factors one day
+    /// either side of `t`.
+    #[must_use]
+    pub fn instantaneous_forward(&self, t: f64) -> f64 {
+        let days = (t * DAYS_PER_YEAR).round() as i64;
+        let lo = i64::max(days - 1, 0);
+        let hi = days + 1;
+
+        let ln_discount = |days: i64| {
+            self.discount_curve
+                .discount_factor(self.curve_date(days))
+                .ln()
+        };
+
+        -(ln_discount(hi) - ln_discount(lo)) / ((hi - lo) as f64 / DAYS_PER_YEAR)
+    }
+
+    /// Price at time `t`, given the short rate `r_t`, of a zero-coupon bond
+    /// paying one unit at time `maturity`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `t` is negative or after the maturity.
+    #[must_use]
+    pub fn zero_coupon_bond(&self, t: f64, maturity: f64, r_t: f64) -> f64 {
+        assert!(
+            (0.0..=maturity).contains(&t),
+            "The time must be between zero and the maturity."
+        );
+
+        let B = maturity - t;
+        let forward_discount = self.discount_factor(maturity) / self.discount_factor(t);
+
+        forward_discount
+            * f64::exp(
+                B * self.instantaneous_forward(t)
+                    - 0.5 * self.sigma.powi(2) * t * B.powi(2)
+                    - B * r_t,
+            )
+    }
+
+    /// Binomial tree of short rates up to `maturity`, with `n_steps` steps,
+    /// calibrated to the initial discount curve.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `maturity` is not positive or `n_steps` is zero.
+    #[must_use]
+    pub fn tree(&self, maturity: f64, n_steps: usize) -> HoLeeTree {
+        assert!(
+            maturity > 0.0 && n_steps > 0,
+            "The maturity and the number of steps must be positive."
+        );
+
+        let dt = maturity / n_steps as f64;
+        let dr = self.sigma * dt.sqrt();
+
+        // Arrow-Debreu prices of the nodes at the current step.
+        let mut arrow_debreu = vec![1.0];
+        let mut rates = Vec::with_capacity(n_steps);
+
+        for i in 0..n_steps {
+            let offset = |j: usize| dr * (2.0 * j as f64 - i as f64);
+
+            // Choose alpha_i so that the bond maturing at step i + 1 is repriced.
+            let target = self.discount_factor((i + 1) as f64 * dt);
+            let unshifted: f64 = arrow_debreu
+                .iter()
+                .enumerate()
+                .map(|(j, q)| q * f64::exp(-offset(j) * dt))
+                .sum();
+            let alpha = (unshifted / target).ln() / dt;
+
+            let step_rates: Vec<f64> = (0..=i).map(|j| alpha + offset(j)).collect();
+
+            let mut next = vec![0.0; i + 2];
+            for (j, (q, r)) in arrow_debreu.iter().zip(&step_rates).enumerate() {
+                let value = 0.5 * q * f64::exp(-r * dt);
+                next[j] += value;
+                next[j + 1] += value;
+            }
+
+            arrow_debreu = next;
+            rates.push(step_rates);
+        }
+
+        HoLeeTree { dt, rates }
+    }
+}
+
+impl HoLeeTree {
+    /// Number of steps of the tree.
+    #[must_use]
+    pub fn n_steps(&self) -> usize {
+        self.rates.len()
+    }
+
+    /// Price today of a zero-coupon bond paying one unit after `n` steps,
+    /// by backward induction through the tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is larger than the number of steps of the tree.
+    #[must_use]
+    pub fn zero_coupon_bond(&self, n: usize) -> f64 {
+        assert!(
+            n <= self.n_steps(),
+            "The bond cannot mature after the last step of the tree."
+        );
+
+        let mut values = vec![1.0; n + 1];
+
+        for i in (0..n).rev() {
+            values = (0..=i)
+                .map(|j| 0.5 * (values[j] + values[j + 1]) * f64::exp(-self.rates[i][j] * self.dt))
+                .collect();
+        }
+
+        values[0]
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_ho_lee {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use std::collections::BTreeMap;
+    use time::macros::date;
+
+    /// An upward-sloping curve with zero rates from 3% to 5%.
+    fn discount_curve() -> YieldCurve {
+        let initial_date = date!(2024 - 01 - 01);
+
+        YieldCurve::new(
+            [0, 1, 2, 3, 5, 7, 10, 20, 30]
+                .into_iter()
+                .map(|years: i32| {
+                    let date = initial_date.replace_year(2024 + years).unwrap();
+
+                    (date, 0.05 - 0.02 * f64::exp(-0.5 * f64::from(years)))
+                })
+                .collect::<BTreeMap<Date, f64>>(),
+        )
+    }
+
+    #[test]
+    fn test_tree_reprices_discount_curve() {
+        let curve = discount_curve();
+        let model = HoLeeModel::new(0.01, &curve);
+        let tree = model.tree(10.0, 40);
+
+        for n in 0..=tree.n_steps() {
+            assert_approx_equal!(
+                tree.zero_coupon_bond(n),
+                model.discount_factor(n as f64 * tree.dt),
+                1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_analytic_bond_matches_discount_curve() {
+        let curve = discount_curve();
+        let model = HoLeeModel::new(0.01, &curve);
+        let r_0 = model.instantaneous_forward(0.0);
+
+        // One year maps to 365 days after the initial date.
+        assert_approx_equal!(
+            model.discount_factor(1.0),
+            curve.discount_factor(date!(2024 - 12 - 31)),
+            1e-15
+        );
+
+        for maturity in [0.5, 1.0, 5.0, 30.0] {
+            assert_approx_equal!(
+                model.zero_coupon_bond(0.0, maturity, r_0),
+                model.discount_factor(maturity),
+                1e-9
+            );
+        }
+
+        // Without volatility, the future bond price is the forward discount
+        // factor when the short rate is on the forward curve.
+        let model = HoLeeModel::new(0.0, &curve);
+        let r_t = model.instantaneous_forward(2.0);
+
+        assert_approx_equal!(
+            model.zero_coupon_bond(2.0, 7.0, r_t),
+            model.discount_factor(7.0) / model.discount_factor(2.0),
+            1e-9
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "The bond cannot mature after the last step of the tree.")]
+    fn test_bond_after_last_step_panics() {
+        let curve = discount_curve();
+        let tree = HoLeeModel::new(0.01, &curve).tree(1.0, 4);
+
+        let _ = tree.zero_coupon_bond(5);
+    }
+}
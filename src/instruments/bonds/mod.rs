@@ -13,6 +13,24 @@ pub mod zero_coupon_bond;
 /// Coupon bond struct.
 pub mod coupon_bond;
 
+/// Bond analytics: accrued interest, prices, yield, duration and spreads.
+pub mod bond;
+pub use bond::*;
+
+/// Fixed-rate bond.
+pub mod fixed_rate_bond;
+pub use fixed_rate_bond::*;
+
+/// Floating-rate bond.
+pub mod floating_rate_bond;
+pub use floating_rate_bond::*;
+
+/// Bond futures: conversion factors and the cheapest-to-deliver bond.
+pub mod bond_future;
+
+/// Ho-Lee short-rate model, with analytic and tree bond pricing.
+pub mod ho_lee;
+
 // /// Cox-Ingersoll-Ross bond pricing model.
 // pub mod cox_ingersoll_ross;
 
@@ -0,0 +1,306 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Commodity forward curves.
+//!
+//! Under the cost-of-carry model, the forward price for delivery at $T$ is
+//!
+//! $$
+//! F(T) = \frac{S}{P(T)} e^{(u - y) T}
+//! $$
+//!
+//! where $P$ is the discount factor, $u$ the storage cost and $y$ the
+//! convenience yield (both continuously compounded), with $T$ in years under
+//! Actual/365 Fixed. Market forward curves are built from futures
+//! settlement prices instead, and can be read back as implied convenience
+//! yields.
+
+use super::commodity_future::CommodityFuture;
+use crate::data::Curve;
+use crate::error::RustQuantError;
+use crate::instruments::credit::hazard_rate_curve::year_fraction;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Cost-of-carry model of a commodity's forward prices.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostOfCarry {
+    /// Spot price.
+    pub spot: f64,
+
+    /// Continuously compounded storage cost.
+    pub storage_cost: f64,
+
+    /// Continuously compounded convenience yield.
+    pub convenience_yield: f64,
+}
+
+/// Forward curve of a commodity, from the prices of futures (or forwards)
+/// by expiry date.
+///
+/// Prices are linear between the nodes and flat outside them, which keeps
+/// the curve well defined when prices are negative (e.g. power or WTI in
+/// April 2020).
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommodityForwardCurve {
+    /// Valuation date of the curve.
+    pub valuation_date: Date,
+
+    /// Node dates, strictly increasing and not before the valuation date.
+    pub dates: Vec<Date>,
+
+    /// Forward price at each node.
+    pub prices: Vec<f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl CostOfCarry {
+    /// New cost-of-carry model.
+    #[must_use]
+    pub fn new(spot: f64, storage_cost: f64, convenience_yield: f64) -> Self {
+        Self {
+            spot,
+            storage_cost,
+            convenience_yield,
+        }
+    }
+
+    /// Forward price for delivery on `delivery_date`.
+    pub fn forward_price<C: Curve>(
+        &self,
+        valuation_date: Date,
+        delivery_date: Date,
+        discount: &C,
+    ) -> f64 {
+        let df = discount.discount_factor(delivery_date) / discount.discount_factor(valuation_date);
+        let t = year_fraction(valuation_date, delivery_date);
+
+        self.spot / df * ((self.storage_cost - self.convenience_yield) * t).exp()
+    }
+
+    /// Forward curve of the model, with nodes at the given dates.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` as for [`CommodityForwardCurve::new`].
+    pub fn forward_curve<C: Curve>(
+        &self,
+        valuation_date: Date,
+        dates: &[Date],
+        discount: &C,
+    ) -> Result<CommodityForwardCurve, RustQuantError> {
+        let prices = dates
+            .iter()
+            .map(|date| self.forward_price(valuation_date, *date, discount))
+            .collect();
+
+        CommodityForwardCurve::new(valuation_date, dates.to_vec(), prices)
+    }
+}
+
+impl CommodityForwardCurve {
+    /// New forward curve from its nodes.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there are no nodes, a different
+    ///   number of dates and prices, or dates that are not strictly increasing
+    ///   from the valuation date.
+    pub fn new(
+        valuation_date: Date,
+        dates: Vec<Date>,
+        prices: Vec<f64>,
+    ) -> Result<Self, RustQuantError> {
+        if dates.is_empty() || dates.len() != prices.len() {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "a forward curve needs one price per node date, got {} dates and {} prices",
+                dates.len(),
+                prices.len()
+            )));
+        }
+        if dates[0] < valuation_date || dates.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(RustQuantError::InvalidArgument(
+                "the node dates must be strictly increasing from the valuation date".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            valuation_date,
+            dates,
+            prices,
+        })
+    }
+
+    /// Forward curve from the settlement prices of futures contracts, with
+    /// a node at the expiry of each contract still trading.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there is not one price per
+    ///   contract, or as for [`CommodityForwardCurve::new`] (e.g. no contract
+    ///   is still trading, or two contracts expire on the same date).
+    pub fn from_futures(
+        valuation_date: Date,
+        futures: &[CommodityFuture],
+        prices: &[f64],
+    ) -> Result<Self, RustQuantError> {
+        if futures.len() != prices.len() {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "got {} futures and {} prices",
+                futures.len(),
+                prices.len()
+            )));
+        }
+
+        let mut nodes: Vec<(Date, f64)> = futures
+            .iter()
+            .zip(prices)
+            .filter(|(future, _)| !future.is_expired(valuation_date))
+            .map(|(future, price)| (future.expiry_date, *price))
+            .collect();
+        nodes.sort_by_key(|(date, _)| *date);
+
+        let (dates, prices) = nodes.into_iter().unzip();
+
+        Self::new(valuation_date, dates, prices)
+    }
+
+    /// Forward price for the given date.
+    #[must_use]
+    pub fn forward_price(&self, date: Date) -> f64 {
+        let i = self.dates.partition_point(|node| *node < date);
+
+        if i == 0 {
+            return self.prices[0];
+        }
+        if i == self.dates.len() {
+            return self.prices[i - 1];
+        }
+
+        let t = year_fraction(self.dates[i - 1], date);
+        let dt = year_fraction(self.dates[i - 1], self.dates[i]);
+
+        self.prices[i - 1] + t / dt * (self.prices[i] - self.prices[i - 1])
+    }
+
+    /// Convenience yield implied by the forward price for the given date,
+    /// under the cost-of-carry model with the given spot price and storage
+    /// cost.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the date is not after the
+    ///   valuation date, or the spot or forward price is not positive.
+    pub fn implied_convenience_yield<C: Curve>(
+        &self,
+        spot: f64,
+        date: Date,
+        discount: &C,
+        storage_cost: f64,
+    ) -> Result<f64, RustQuantError> {
+        let forward = self.forward_price(date);
+        let t = year_fraction(self.valuation_date, date);
+
+        if t <= 0.0 {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the date ({date}) must be after the valuation date ({})",
+                self.valuation_date
+            )));
+        }
+        if !(spot > 0.0 && forward > 0.0) {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the spot ({spot}) and forward ({forward}) prices must be positive"
+            )));
+        }
+
+        let df = discount.discount_factor(date) / discount.discount_factor(self.valuation_date);
+
+        Ok(storage_cost - (forward * df / spot).ln() / t)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_commodity_forward_curve {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use time::macros::date;
+
+    const TODAY: Date = date!(2024 - 01 - 01);
+
+    #[test]
+    fn test_cost_of_carry() {
+        let (today, delivery) = (date!(2025 - 01 - 01), date!(2026 - 01 - 01));
+        let discount = YieldCurve::from_dates_and_rates(&[today, delivery], &[0.05, 0.05]);
+        let model = CostOfCarry::new(80.0, 0.01, 0.04);
+
+        // With continuous discounting, F = S exp((r + u - y) T).
+        assert_approx_equal!(
+            model.forward_price(today, delivery, &discount),
+            80.0 * f64::exp(0.02),
+            1e-10
+        );
+
+        // The convenience yield is recovered from the forward curve.
+        let dates = [date!(2025 - 03 - 15), date!(2025 - 06 - 15), delivery];
+        let curve = model.forward_curve(today, &dates, &discount).unwrap();
+        for date in dates {
+            assert_approx_equal!(
+                curve
+                    .implied_convenience_yield(80.0, date, &discount, 0.01)
+                    .unwrap(),
+                0.04,
+                1e-10
+            );
+        }
+    }
+
+    #[test]
+    fn test_forward_curve_from_futures() {
+        let futures: Vec<CommodityFuture> = [
+            (date!(2024 - 02 - 01), date!(2024 - 01 - 22)),
+            (date!(2024 - 03 - 01), date!(2024 - 02 - 20)),
+            (date!(2024 - 04 - 01), date!(2024 - 03 - 19)),
+        ]
+        .into_iter()
+        .map(|(month, expiry)| CommodityFuture::new("CL", month, expiry, 1_000.0))
+        .collect();
+
+        // Negative prices, as in April 2020, are allowed.
+        let curve =
+            CommodityForwardCurve::from_futures(TODAY, &futures, &[-10.0, 20.0, 25.0]).unwrap();
+
+        assert_approx_equal!(curve.forward_price(TODAY), -10.0, 1e-12);
+        assert_approx_equal!(curve.forward_price(date!(2024 - 02 - 20)), 20.0, 1e-12);
+        assert_approx_equal!(curve.forward_price(date!(2024 - 12 - 31)), 25.0, 1e-12);
+        assert_approx_equal!(
+            curve.forward_price(date!(2024 - 02 - 05)),
+            -10.0 + 30.0 * 14.0 / 29.0,
+            1e-12
+        );
+
+        // Expired contracts are left out.
+        let later = CommodityForwardCurve::from_futures(
+            date!(2024 - 02 - 01),
+            &futures,
+            &[-10.0, 20.0, 25.0],
+        )
+        .unwrap();
+        assert_eq!(later.dates.len(), 2);
+
+        assert!(CommodityForwardCurve::from_futures(TODAY, &futures, &[1.0]).is_err());
+    }
+}
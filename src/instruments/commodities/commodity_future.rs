@@ -0,0 +1,290 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Commodity futures: contract months, exchange expiry rules and daily
+//! variation margin.
+//!
+//! A futures position is marked to market every day: the change in the
+//! settlement price, times the contract size and the number of contracts,
+//! is paid into or out of the margin account, so that an open position is
+//! worth nothing after each settlement.
+
+use crate::error::RustQuantError;
+use crate::time::{
+    add_months, last_business_day_of_month, previous_business_day,
+    utilities::try_advance_business_days, Calendar,
+};
+use time::{Date, Month};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Last trading day rules of exchange-traded commodity futures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuturesExpiryRule {
+    /// NYMEX WTI crude oil (CL): three business days before the 25th
+    /// calendar day of the month before the contract month, or before the
+    /// business day preceding the 25th if it is not a business day.
+    WtiCrudeOil,
+
+    /// NYMEX Henry Hub natural gas (NG): three business days before the
+    /// first calendar day of the contract month.
+    HenryHubNaturalGas,
+
+    /// ICE Brent crude oil (B): the last business day of the second month
+    /// before the contract month.
+    BrentCrudeOil,
+
+    /// CBOT grains (ZC, ZW, ZS): the business day before the 15th calendar
+    /// day of the contract month.
+    CbotGrains,
+
+    /// COMEX metals (GC, SI, HG): the third last business day of the
+    /// contract month.
+    ComexMetals,
+}
+
+/// Commodity futures contract.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommodityFuture {
+    /// Root symbol of the contract, e.g. `CL`.
+    pub symbol: String,
+
+    /// First day of the contract (delivery) month.
+    pub contract_month: Date,
+
+    /// Last trading day.
+    pub expiry_date: Date,
+
+    /// Units of the commodity per contract, e.g. 1,000 barrels.
+    pub contract_size: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl FuturesExpiryRule {
+    /// Last trading day of the contract for the given contract month, on
+    /// the exchange's calendar.
+    ///
+    /// # Errors
+    /// - `RustQuantError::NotConverged` if the calendar has no business day
+    ///   near the expiry.
+    pub fn expiry_date<C: Calendar + ?Sized>(
+        &self,
+        year: i32,
+        month: Month,
+        calendar: &C,
+    ) -> Result<Date, RustQuantError> {
+        let contract_month = Date::from_calendar_date(year, month, 1).unwrap();
+
+        match self {
+            Self::WtiCrudeOil => {
+                let the_25th = add_months(contract_month, -1).replace_day(25).unwrap();
+
+                try_advance_business_days(previous_business_day(the_25th, calendar)?, -3, calendar)
+            }
+            Self::HenryHubNaturalGas => try_advance_business_days(contract_month, -3, calendar),
+            Self::BrentCrudeOil => {
+                last_business_day_of_month(add_months(contract_month, -2), calendar)
+            }
+            Self::CbotGrains => {
+                try_advance_business_days(contract_month.replace_day(15).unwrap(), -1, calendar)
+            }
+            Self::ComexMetals => try_advance_business_days(
+                last_business_day_of_month(contract_month, calendar)?,
+                -2,
+                calendar,
+            ),
+        }
+    }
+}
+
+impl CommodityFuture {
+    /// New futures contract.
+    #[must_use]
+    pub fn new(symbol: &str, contract_month: Date, expiry_date: Date, contract_size: f64) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            contract_month: contract_month.replace_day(1).unwrap(),
+            expiry_date,
+            contract_size,
+        }
+    }
+
+    /// New futures contract, expiring by the exchange's rule.
+    ///
+    /// # Errors
+    /// - `RustQuantError::NotConverged` as for [`FuturesExpiryRule::expiry_date`].
+    pub fn with_expiry_rule<C: Calendar + ?Sized>(
+        symbol: &str,
+        year: i32,
+        month: Month,
+        contract_size: f64,
+        rule: FuturesExpiryRule,
+        calendar: &C,
+    ) -> Result<Self, RustQuantError> {
+        Ok(Self::new(
+            symbol,
+            Date::from_calendar_date(year, month, 1).unwrap(),
+            rule.expiry_date(year, month, calendar)?,
+            contract_size,
+        ))
+    }
+
+    /// Exchange code of the contract: root symbol, month code and the last
+    /// digit of the year, e.g. `CLZ4` for December 2024 crude oil.
+    #[must_use]
+    pub fn code(&self) -> String {
+        format!(
+            "{}{}{}",
+            self.symbol,
+            futures_month_code(self.contract_month.month()),
+            self.contract_month.year().rem_euclid(10)
+        )
+    }
+
+    /// Whether the contract has stopped trading at the given date.
+    #[must_use]
+    pub fn is_expired(&self, date: Date) -> bool {
+        date > self.expiry_date
+    }
+
+    /// Variation margin received by a position of `contracts` (negative when
+    /// short) when the settlement price moves from `previous_settlement` to
+    /// `settlement`.
+    #[must_use]
+    pub fn variation_margin(
+        &self,
+        contracts: f64,
+        previous_settlement: f64,
+        settlement: f64,
+    ) -> f64 {
+        contracts * self.contract_size * (settlement - previous_settlement)
+    }
+
+    /// Daily variation margins of a position opened at `trade_price` and
+    /// held over the given daily settlement prices, one per settlement.
+    #[must_use]
+    pub fn variation_margins(
+        &self,
+        contracts: f64,
+        trade_price: f64,
+        settlements: &[f64],
+    ) -> Vec<f64> {
+        std::iter::once(trade_price)
+            .chain(settlements.iter().copied())
+            .collect::<Vec<f64>>()
+            .windows(2)
+            .map(|pair| self.variation_margin(contracts, pair[0], pair[1]))
+            .collect()
+    }
+}
+
+/// Futures month code of a contract month (F for January to Z for
+/// December).
+#[must_use]
+pub fn futures_month_code(month: Month) -> char {
+    match month {
+        Month::January => 'F',
+        Month::February => 'G',
+        Month::March => 'H',
+        Month::April => 'J',
+        Month::May => 'K',
+        Month::June => 'M',
+        Month::July => 'N',
+        Month::August => 'Q',
+        Month::September => 'U',
+        Month::October => 'V',
+        Month::November => 'X',
+        Month::December => 'Z',
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_commodity_future {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::time::countries::north_america::united_states::UnitedStatesCalendar;
+    use time::macros::date;
+
+    #[test]
+    fn test_expiry_rules() {
+        let expiry = |rule: FuturesExpiryRule, year, month| {
+            rule.expiry_date(year, month, &UnitedStatesCalendar)
+                .unwrap()
+        };
+
+        // The 25th of December is a holiday, so January 2025 crude oil
+        // expires three business days before 24 December 2024.
+        assert_eq!(
+            expiry(FuturesExpiryRule::WtiCrudeOil, 2025, Month::January),
+            date!(2024 - 12 - 19)
+        );
+        assert_eq!(
+            expiry(FuturesExpiryRule::WtiCrudeOil, 2024, Month::July),
+            date!(2024 - 06 - 20)
+        );
+        assert_eq!(
+            expiry(FuturesExpiryRule::HenryHubNaturalGas, 2025, Month::January),
+            date!(2024 - 12 - 27)
+        );
+        assert_eq!(
+            expiry(FuturesExpiryRule::BrentCrudeOil, 2025, Month::March),
+            date!(2025 - 01 - 31)
+        );
+        // 15 March 2025 is a Saturday.
+        assert_eq!(
+            expiry(FuturesExpiryRule::CbotGrains, 2025, Month::March),
+            date!(2025 - 03 - 14)
+        );
+        assert_eq!(
+            expiry(FuturesExpiryRule::ComexMetals, 2024, Month::December),
+            date!(2024 - 12 - 27)
+        );
+    }
+
+    #[test]
+    fn test_code_and_variation_margin() {
+        let future = CommodityFuture::with_expiry_rule(
+            "CL",
+            2024,
+            Month::December,
+            1_000.0,
+            FuturesExpiryRule::WtiCrudeOil,
+            &UnitedStatesCalendar,
+        )
+        .unwrap();
+
+        assert_eq!(future.code(), "CLZ4");
+        assert_eq!(future.expiry_date, date!(2024 - 11 - 20));
+        assert!(!future.is_expired(date!(2024 - 11 - 20)));
+        assert!(future.is_expired(date!(2024 - 11 - 21)));
+
+        // Five short contracts sold at 71.50.
+        let margins = future.variation_margins(-5.0, 71.50, &[72.10, 71.25, 70.00]);
+        assert_eq!(margins.len(), 3);
+        assert_approx_equal!(margins[0], -3_000.0, 1e-9);
+        assert_approx_equal!(margins[1], 4_250.0, 1e-9);
+
+        // The margins add up to the profit from the trade price.
+        assert_approx_equal!(
+            margins.iter().sum::<f64>(),
+            future.variation_margin(-5.0, 71.50, 70.00),
+            1e-9
+        );
+    }
+}
@@ -0,0 +1,223 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! European options on commodity futures.
+//!
+//! The futures price is a martingale under the risk-neutral measure, so an
+//! option on it is priced with the Black-76 (lognormal) or Bachelier
+//! (normal) formula on the futures price (see [`RateOptionModel`]). The
+//! Bachelier model also prices options when the futures or strike price is
+//! negative, as in power and crude oil markets.
+//!
+//! Options with an upfront premium are discounted from expiry. Options with
+//! futures-style margining (e.g. ICE Brent options) are marked to market
+//! daily like the futures, so no premium is paid upfront and their value is
+//! not discounted.
+
+use super::commodity_future::CommodityFuture;
+use crate::data::Curve;
+use crate::error::RustQuantError;
+use crate::instruments::options::TypeFlag;
+use crate::instruments::swaps::cap_floor::{time_to_expiry, OptionVolatility, RateOptionModel};
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// How the premium of an option on futures is settled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PremiumSettlement {
+    /// The premium is paid in full when the option is bought.
+    Upfront,
+
+    /// The option is margined daily like the futures, and the premium is
+    /// only settled at exercise or expiry.
+    FuturesStyle,
+}
+
+/// European option on a commodity futures contract.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub struct FuturesOption {
+    /// Underlying futures contract.
+    pub future: CommodityFuture,
+
+    /// Call or put on the futures.
+    pub option_type: TypeFlag,
+
+    /// Strike price.
+    pub strike: f64,
+
+    /// Expiry date of the option, on or before the expiry of the futures.
+    pub expiry_date: Date,
+
+    /// Settlement of the premium.
+    pub premium_settlement: PremiumSettlement,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl FuturesOption {
+    /// New option on futures, with an upfront premium.
+    #[must_use]
+    pub fn new(
+        future: CommodityFuture,
+        option_type: TypeFlag,
+        strike: f64,
+        expiry_date: Date,
+    ) -> Self {
+        Self {
+            future,
+            option_type,
+            strike,
+            expiry_date,
+            premium_settlement: PremiumSettlement::Upfront,
+        }
+    }
+
+    /// Set the settlement of the premium.
+    #[must_use]
+    pub fn with_premium_settlement(mut self, premium_settlement: PremiumSettlement) -> Self {
+        self.premium_settlement = premium_settlement;
+        self
+    }
+
+    /// Price of one option contract (on one futures contract), or nothing
+    /// once expired.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` as for
+    ///   [`RateOptionModel::undiscounted_price`] and the volatility.
+    pub fn price<C: Curve, V: OptionVolatility + ?Sized>(
+        &self,
+        valuation_date: Date,
+        futures_price: f64,
+        discount: &C,
+        volatility: &V,
+        model: RateOptionModel,
+    ) -> Result<f64, RustQuantError> {
+        if self.expiry_date < valuation_date {
+            return Ok(0.0);
+        }
+
+        let expiry = time_to_expiry(valuation_date, self.expiry_date);
+        let sigma = volatility.option_volatility(expiry, self.strike)?;
+        let undiscounted = model.undiscounted_price(
+            self.option_type,
+            futures_price,
+            self.strike,
+            sigma,
+            expiry,
+        )?;
+
+        let df = match self.premium_settlement {
+            PremiumSettlement::Upfront => {
+                discount.discount_factor(self.expiry_date)
+                    / discount.discount_factor(valuation_date)
+            }
+            PremiumSettlement::FuturesStyle => 1.0,
+        };
+
+        Ok(self.future.contract_size * undiscounted * df)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_futures_option {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use time::macros::date;
+
+    const TODAY: Date = date!(2024 - 01 - 02);
+    const EXPIRY: Date = date!(2024 - 05 - 16);
+
+    fn option(option_type: TypeFlag, strike: f64) -> FuturesOption {
+        let future =
+            CommodityFuture::new("CL", date!(2024 - 06 - 01), date!(2024 - 05 - 21), 1_000.0);
+
+        FuturesOption::new(future, option_type, strike, EXPIRY)
+    }
+
+    #[test]
+    fn test_put_call_parity_and_margining() {
+        let discount =
+            YieldCurve::from_dates_and_rates(&[TODAY, date!(2025 - 01 - 02)], &[0.05, 0.05]);
+        let df = discount.discount_factor(EXPIRY);
+
+        for (model, vol) in [
+            (RateOptionModel::Black, 0.35),
+            (RateOptionModel::Bachelier, 25.0),
+        ] {
+            let call = option(TypeFlag::Call, 75.0)
+                .price(TODAY, 72.0, &discount, &vol, model)
+                .unwrap();
+            let put = option(TypeFlag::Put, 75.0)
+                .price(TODAY, 72.0, &discount, &vol, model)
+                .unwrap();
+
+            assert_approx_equal!(call - put, 1_000.0 * (72.0 - 75.0) * df, 1e-8);
+        }
+
+        // Futures-style options are not discounted.
+        let upfront = option(TypeFlag::Call, 75.0);
+        let futures_style = upfront
+            .clone()
+            .with_premium_settlement(PremiumSettlement::FuturesStyle);
+        assert_approx_equal!(
+            futures_style
+                .price(TODAY, 72.0, &discount, &0.35, RateOptionModel::Black)
+                .unwrap()
+                * df,
+            upfront
+                .price(TODAY, 72.0, &discount, &0.35, RateOptionModel::Black)
+                .unwrap(),
+            1e-8
+        );
+    }
+
+    #[test]
+    fn test_negative_prices() {
+        let discount =
+            YieldCurve::from_dates_and_rates(&[TODAY, date!(2025 - 01 - 02)], &[0.0, 0.0]);
+
+        // Black-76 cannot price a put on a negative futures price...
+        let put = option(TypeFlag::Put, 5.0);
+        assert!(put
+            .price(TODAY, -10.0, &discount, &0.35, RateOptionModel::Black)
+            .is_err());
+
+        // ...but Bachelier can, and is worth more than its intrinsic value.
+        let price = put
+            .price(TODAY, -10.0, &discount, &20.0, RateOptionModel::Bachelier)
+            .unwrap();
+        assert!(price > 15_000.0);
+
+        // Expired options are worth nothing.
+        assert_approx_equal!(
+            put.price(
+                date!(2024 - 05 - 17),
+                -10.0,
+                &discount,
+                &20.0,
+                RateOptionModel::Bachelier
+            )
+            .unwrap(),
+            0.0,
+            1e-15
+        );
+    }
+}
@@ -6,3 +6,19 @@
 //      - LICENSE-APACHE.md
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Commodity futures, exchange expiry rules and variation margin.
+pub mod commodity_future;
+pub use commodity_future::*;
+
+/// Cost-of-carry models and commodity forward curves.
+pub mod commodity_forward_curve;
+pub use commodity_forward_curve::*;
+
+/// Black-76 and Bachelier options on commodity futures.
+pub mod futures_option;
+pub use futures_option::*;
+
+/// Swing options (power and gas).
+pub mod swing_option;
+pub use swing_option::*;
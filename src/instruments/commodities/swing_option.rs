@@ -0,0 +1,308 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Swing options, as traded in power and gas markets.
+//!
+//! The holder has a number of exercise rights over a set of dates. At each
+//! date at most one right can be exercised, delivering a fixed volume at the
+//! strike. The total number of exercises must lie between a minimum and a
+//! maximum (the volume constraints), so the holder may be forced to exercise
+//! out of the money near the end of the contract.
+//!
+//! The option is priced by least-squares Monte Carlo (Longstaff-Schwartz),
+//! with one continuation value per number of rights already used
+//! (Meinshausen and Hambly, 2004).
+
+use crate::instruments::{
+    options::{longstaff_schwartz::least_squares_fit, LongstaffSchwartz, TypeFlag},
+    PricingResult,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use rand_distr::{Distribution, StandardNormal};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Swing option on an underlying following geometric Brownian motion.
+#[derive(Debug, Clone)]
+pub struct SwingOption {
+    /// `S` - Initial price of the underlying.
+    pub initial_price: f64,
+
+    /// `K` - Strike price of each exercise.
+    pub strike_price: f64,
+
+    /// `r` - Risk-free rate parameter.
+    pub risk_free_rate: f64,
+
+    /// `q` - Convenience yield of the underlying.
+    pub convenience_yield: f64,
+
+    /// `v` - Volatility parameter.
+    pub volatility: f64,
+
+    /// Exercise times (in years), in ascending order.
+    pub exercise_times: Vec<f64>,
+
+    /// Volume delivered by each exercise.
+    pub volume: f64,
+
+    /// Minimum total number of exercises.
+    pub min_exercises: usize,
+
+    /// Maximum total number of exercises (the number of rights).
+    pub max_exercises: usize,
+
+    /// Call (buy at the strike) or put (sell at the strike).
+    pub option_type: TypeFlag,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl SwingOption {
+    /// Least-squares Monte Carlo price of the swing option, with its standard
+    /// error and the number of paths.
+    ///
+    /// The continuation values are regressed on the polynomials
+    /// $1, x, x^2, x^3$ in the moneyness $x = S / K$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no exercise dates, if the exercise times are not
+    /// positive and increasing, or if the volume constraints cannot be met
+    /// (`min_exercises > max_exercises` or more exercises than dates).
+    #[must_use]
+    pub fn price(&self, n_paths: usize, seed: u64) -> PricingResult {
+        let times = &self.exercise_times;
+        let n_dates = times.len();
+
+        assert!(n_dates > 0, "There must be at least one exercise date.");
+        assert!(
+            times[0] > 0.0 && times.windows(2).all(|t| t[0] < t[1]),
+            "Exercise times must be positive and increasing."
+        );
+        assert!(
+            self.min_exercises <= self.max_exercises && self.min_exercises <= n_dates,
+            "The volume constraints cannot be met."
+        );
+
+        let paths = self.simulate(n_paths, seed);
+        let max_rights = self.max_exercises.min(n_dates);
+
+        // A state (number of rights used) is feasible at date `i` if the
+        // minimum can still be reached with the `n_dates - i` dates left.
+        let feasible = |i: usize, used: usize| {
+            used <= max_rights && self.min_exercises <= used + (n_dates - i)
+        };
+
+        // Pathwise values of the remaining cash flows, indexed by rights used,
+        // valued at the current exercise date.
+        let mut values = vec![vec![0.0; n_paths]; max_rights + 1];
+
+        for i in (0..n_dates).rev() {
+            let spots: Vec<f64> = paths.iter().map(|path| path[i]).collect();
+
+            // Discount the cash flows from the next exercise date to this one,
+            // and estimate the continuation value in each state.
+            let continuation: Vec<Option<Vec<f64>>> = if i + 1 < n_dates {
+                let discount = f64::exp(-self.risk_free_rate * (times[i + 1] - times[i]));
+
+                for state in &mut values {
+                    state.iter_mut().for_each(|v| *v *= discount);
+                }
+
+                (0..=max_rights)
+                    .map(|used| feasible(i + 1, used).then(|| self.regress(&spots, &values[used])))
+                    .collect()
+            } else {
+                (0..=max_rights)
+                    .map(|used| feasible(i + 1, used).then(|| vec![0.0; n_paths]))
+                    .collect()
+            };
+
+            let mut next_values = values.clone();
+
+            for used in 0..=max_rights {
+                if !feasible(i, used) {
+                    continue;
+                }
+
+                let can_hold = continuation[used].is_some();
+                let can_exercise = used < max_rights && continuation[used + 1].is_some();
+
+                for (p, spot) in spots.iter().enumerate() {
+                    let payoff = self.payoff(*spot);
+
+                    let exercise = match (can_hold, can_exercise) {
+                        (true, true) => {
+                            let hold = continuation[used].as_ref().unwrap()[p];
+                            let exercise = continuation[used + 1].as_ref().unwrap()[p];
+
+                            payoff + exercise > hold
+                        }
+                        (false, true) => true,
+                        _ => false,
+                    };
+
+                    next_values[used][p] = if exercise {
+                        payoff + values[used + 1][p]
+                    } else {
+                        values[used][p]
+                    };
+                }
+            }
+
+            values = next_values;
+        }
+
+        let discount = f64::exp(-self.risk_free_rate * times[0]);
+        let samples: Vec<f64> = values[0].iter().map(|v| discount * v).collect();
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+
+        PricingResult::new(mean)
+            .with_standard_error((variance / n).sqrt())
+            .with_iterations(n_paths)
+    }
+
+    /// Payoff of a single exercise at spot price `s`.
+    fn payoff(&self, s: f64) -> f64 {
+        let intrinsic = match self.option_type {
+            TypeFlag::Call => s - self.strike_price,
+            TypeFlag::Put => self.strike_price - s,
+        };
+
+        self.volume * intrinsic
+    }
+
+    /// Simulate the underlying at the exercise times (exactly, under GBM).
+    fn simulate(&self, n_paths: usize, seed: u64) -> Vec<Vec<f64>> {
+        let (r, q, v) = (self.risk_free_rate, self.convenience_yield, self.volatility);
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        (0..n_paths)
+            .map(|_| {
+                let mut s = self.initial_price;
+                let mut t = 0.0;
+
+                self.exercise_times
+                    .iter()
+                    .map(|t_next| {
+                        let dt = t_next - t;
+                        let z: f64 = StandardNormal.sample(&mut rng);
+
+                        s *= f64::exp((r - q - 0.5 * v * v) * dt + v * dt.sqrt() * z);
+                        t = *t_next;
+
+                        s
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Fitted values of the least-squares regression of `y` on the
+    /// polynomial basis in the moneyness.
+    fn regress(&self, spots: &[f64], y: &[f64]) -> Vec<f64> {
+        let moneyness: Vec<f64> = spots.iter().map(|s| s / self.strike_price).collect();
+
+        least_squares_fit(&LongstaffSchwartz::monomial_basis(3), &moneyness, y)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_swing_option {
+    use super::*;
+
+    const S: f64 = 100.0;
+    const K: f64 = 100.0;
+    const R: f64 = 0.05;
+    const V: f64 = 0.3;
+
+    fn swing(n_dates: usize, min_exercises: usize, max_exercises: usize) -> SwingOption {
+        SwingOption {
+            initial_price: S,
+            strike_price: K,
+            risk_free_rate: R,
+            convenience_yield: 0.0,
+            volatility: V,
+            exercise_times: (1..=n_dates).map(|i| i as f64 / n_dates as f64).collect(),
+            volume: 1.0,
+            min_exercises,
+            max_exercises,
+            option_type: TypeFlag::Put,
+        }
+    }
+
+    /// Bermudan put on a CRR tree, exercisable every `steps / n_dates` steps.
+    fn bermudan_put_tree(n_dates: usize, steps: usize) -> f64 {
+        let dt = 1.0 / steps as f64;
+        let u = f64::exp(V * dt.sqrt());
+        let d = 1.0 / u;
+        let p = (f64::exp(R * dt) - d) / (u - d);
+        let discount = f64::exp(-R * dt);
+
+        let spot = |j: usize, i: usize| S * u.powi(i as i32) * d.powi((j - i) as i32);
+
+        let mut values: Vec<f64> = (0..=steps).map(|i| (K - spot(steps, i)).max(0.0)).collect();
+
+        for j in (0..steps).rev() {
+            for i in 0..=j {
+                values[i] = discount * (p * values[i + 1] + (1.0 - p) * values[i]);
+
+                if j > 0 && j % (steps / n_dates) == 0 {
+                    values[i] = values[i].max(K - spot(j, i));
+                }
+            }
+        }
+
+        values[0]
+    }
+
+    #[test]
+    fn test_single_right_is_bermudan() {
+        let result = swing(4, 0, 1).price(20_000, 1);
+        let bermudan = bermudan_put_tree(4, 2_000);
+
+        assert!((result.value - bermudan).abs() < 3.0 * result.standard_error.unwrap() + 0.05);
+    }
+
+    #[test]
+    fn test_more_rights_increase_value() {
+        let prices: Vec<f64> = (1..=6)
+            .map(|max_exercises| swing(6, 0, max_exercises).price(20_000, 7).value)
+            .collect();
+
+        assert!(prices.windows(2).all(|p| p[1] > p[0]));
+    }
+
+    #[test]
+    fn test_fixed_volume_is_strip_of_forwards() {
+        // With every date forced to exercise, the swing is a strip of
+        // forward contracts: sum of K e^{-rt} - S.
+        let result = swing(4, 4, 4).price(20_000, 3);
+        let strip: f64 = (1..=4)
+            .map(|i| K * f64::exp(-R * f64::from(i) / 4.0) - S)
+            .sum();
+
+        assert!((result.value - strip).abs() < 3.0 * result.standard_error.unwrap());
+
+        // A minimum volume can only reduce the value.
+        assert!(swing(4, 2, 4).price(20_000, 3).value <= swing(4, 0, 4).price(20_000, 3).value);
+    }
+}
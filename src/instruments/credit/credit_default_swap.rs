@@ -0,0 +1,505 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Credit default swaps, valued in the ISDA standard model.
+//!
+//! The protection buyer pays a running coupon on the premium leg, quarterly
+//! on the CDS dates (the 20th of March, June, September and December) under
+//! Actual/360, until maturity or default. On default, the buyer pays the
+//! premium accrued since the last payment, and the seller pays the loss
+//! given default $(1 - R)$ on the notional.
+//!
+//! Following the ISDA standard model, protection starts on the step-in date
+//! (the day after the trade date), the last accrual period includes the
+//! maturity date, and both legs are integrated exactly over intervals on
+//! which the hazard rate and the forward rate of the discount curve are
+//! taken constant. The protection leg is worth
+//!
+//! $$
+//! (1 - R) \sum_j \frac{h_j}{h_j + f_j}
+//! \left( Q(t_j) P(t_j) - Q(t_{j+1}) P(t_{j+1}) \right)
+//! $$
+//!
+//! over those intervals.
+
+use super::hazard_rate_curve::{year_fraction, HazardRateCurve};
+use crate::data::Curve;
+use crate::error::RustQuantError;
+use crate::instruments::swaps::interest_rate_swap::BASIS_POINT;
+use crate::time::{
+    cds_roll_dates, previous_cds_date, Calendar, CouponPeriod, DateRoller, DateRollingConvention,
+    DayCountConvention,
+};
+use time::Date;
+
+/// Standard recovery rate of senior unsecured debt.
+pub const STANDARD_RECOVERY_RATE: f64 = 0.4;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Side of a credit default swap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtectionSide {
+    /// Buy protection: pay the premium, receive the loss on default.
+    Buyer,
+
+    /// Sell protection: receive the premium, pay the loss on default.
+    Seller,
+}
+
+/// Credit default swap.
+#[derive(Debug, Clone)]
+pub struct CreditDefaultSwap {
+    /// Buyer or seller of protection.
+    pub side: ProtectionSide,
+
+    /// Notional.
+    pub notional: f64,
+
+    /// Running coupon (spread) of the premium leg.
+    pub coupon: f64,
+
+    /// Recovery rate on default.
+    pub recovery_rate: f64,
+
+    /// Maturity (protection end) date.
+    pub maturity_date: Date,
+
+    /// Accrual periods of the premium leg.
+    pub periods: Vec<CouponPeriod>,
+}
+
+/// Present values of the two legs, per unit of notional.
+struct LegValues {
+    /// Protection leg, for a zero recovery rate.
+    protection: f64,
+
+    /// Premium leg per unit of coupon, including the accrual on default.
+    annuity: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl ProtectionSide {
+    /// Sign of the protection leg for the holder.
+    fn sign(self) -> f64 {
+        match self {
+            Self::Buyer => 1.0,
+            Self::Seller => -1.0,
+        }
+    }
+}
+
+impl CreditDefaultSwap {
+    /// New credit default swap from its premium accrual periods, with the
+    /// standard recovery rate. The maturity is the unadjusted end of the
+    /// last period.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no periods.
+    #[must_use]
+    pub fn new(
+        side: ProtectionSide,
+        notional: f64,
+        coupon: f64,
+        periods: Vec<CouponPeriod>,
+    ) -> Self {
+        let maturity_date = periods.last().expect("a CDS needs a period").unadjusted_end;
+
+        Self {
+            side,
+            notional,
+            coupon,
+            recovery_rate: STANDARD_RECOVERY_RATE,
+            maturity_date,
+            periods,
+        }
+    }
+
+    /// Standard (ISDA) credit default swap traded on `trade_date`: accruing
+    /// from the last CDS date on or before the trade date, with quarterly
+    /// periods on the CDS dates rolled to the following business day, and
+    /// a last period ending the day after maturity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the maturity is not after the trade date.
+    #[must_use]
+    pub fn standard<K: Calendar>(
+        side: ProtectionSide,
+        notional: f64,
+        coupon: f64,
+        trade_date: Date,
+        maturity_date: Date,
+        calendar: &K,
+    ) -> Self {
+        assert!(
+            maturity_date > trade_date,
+            "the CDS matures before it is traded"
+        );
+
+        let start = previous_cds_date(trade_date.next_day().unwrap());
+
+        let mut dates = cds_roll_dates(start, maturity_date);
+        if dates.last() != Some(&maturity_date) {
+            dates.push(maturity_date);
+        }
+
+        let roll = |date: Date| calendar.roll_date(date, &DateRollingConvention::Following);
+        let n = dates.len() - 1;
+
+        let periods = dates
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| CouponPeriod {
+                start: roll(pair[0]),
+                end: if i + 1 == n {
+                    pair[1].next_day().unwrap()
+                } else {
+                    roll(pair[1])
+                },
+                unadjusted_start: pair[0],
+                unadjusted_end: pair[1],
+                payment: roll(pair[1]),
+            })
+            .collect();
+
+        Self::new(side, notional, coupon, periods)
+    }
+
+    /// Set the recovery rate on default.
+    #[must_use]
+    pub fn with_recovery_rate(mut self, recovery_rate: f64) -> Self {
+        self.recovery_rate = recovery_rate;
+        self
+    }
+
+    /// End of protection: the end of the last accrual period, which is the
+    /// day after maturity for a standard trade.
+    #[must_use]
+    pub fn protection_end_date(&self) -> Date {
+        self.periods
+            .last()
+            .map_or(self.maturity_date, |period| period.end)
+    }
+
+    /// Present value of the protection leg.
+    pub fn protection_leg_npv<C: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        hazard: &HazardRateCurve,
+    ) -> f64 {
+        let legs = self.leg_values(valuation_date, discount, hazard);
+
+        self.notional * (1.0 - self.recovery_rate) * legs.protection
+    }
+
+    /// Risky annuity (RPV01): the present value of the premium leg per unit
+    /// of coupon, including the full current period and the premium accrued
+    /// on default.
+    pub fn risky_annuity<C: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        hazard: &HazardRateCurve,
+    ) -> f64 {
+        self.notional * self.leg_values(valuation_date, discount, hazard).annuity
+    }
+
+    /// Risky annuity less the premium accrued at the step-in date, per unit
+    /// of coupon: the annuity of a trade that pays back the accrued premium.
+    pub fn clean_risky_annuity<C: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        hazard: &HazardRateCurve,
+    ) -> f64 {
+        self.risky_annuity(valuation_date, discount, hazard)
+            - self.notional * self.accrual_at_step_in(valuation_date)
+    }
+
+    /// Present value of the premium leg.
+    pub fn premium_leg_npv<C: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        hazard: &HazardRateCurve,
+    ) -> f64 {
+        self.coupon * self.risky_annuity(valuation_date, discount, hazard)
+    }
+
+    /// Premium accrued from the start of the current period to the step-in
+    /// date, paid by the buyer at settlement.
+    #[must_use]
+    pub fn accrued_premium(&self, valuation_date: Date) -> f64 {
+        self.notional * self.coupon * self.accrual_at_step_in(valuation_date)
+    }
+
+    /// Net present value to the holder: the protection leg less the premium
+    /// leg for a buyer, and the reverse for a seller.
+    pub fn npv<C: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        hazard: &HazardRateCurve,
+    ) -> f64 {
+        let legs = self.leg_values(valuation_date, discount, hazard);
+
+        self.side.sign()
+            * self.notional
+            * ((1.0 - self.recovery_rate) * legs.protection - self.coupon * legs.annuity)
+    }
+
+    /// Par spread: the coupon at which a trade paying back the accrued
+    /// premium has zero value.
+    pub fn par_spread<C: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        hazard: &HazardRateCurve,
+    ) -> f64 {
+        self.protection_leg_npv(valuation_date, discount, hazard)
+            / self.clean_risky_annuity(valuation_date, discount, hazard)
+    }
+
+    /// Upfront paid by the protection buyer, as a fraction of the notional
+    /// (points upfront), as of the valuation date: the protection leg less
+    /// the coupon on the clean risky annuity.
+    pub fn upfront<C: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        hazard: &HazardRateCurve,
+    ) -> f64 {
+        let protection = self.protection_leg_npv(valuation_date, discount, hazard);
+        let annuity = self.clean_risky_annuity(valuation_date, discount, hazard);
+
+        (protection - self.coupon * annuity) / self.notional
+    }
+
+    /// CS01: the change in value to the holder when the par spreads of the
+    /// quotes the hazard rate curve is bootstrapped from rise by one basis
+    /// point.
+    ///
+    /// # Errors
+    /// - As for [`HazardRateCurve::bootstrap`].
+    pub fn cs01<C: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        quotes: &[CreditDefaultSwap],
+    ) -> Result<f64, RustQuantError> {
+        let bumped: Vec<CreditDefaultSwap> = quotes
+            .iter()
+            .map(|quote| {
+                let mut quote = quote.clone();
+                quote.coupon += BASIS_POINT;
+                quote
+            })
+            .collect();
+
+        let base = HazardRateCurve::bootstrap(valuation_date, quotes, discount)?;
+        let bumped = HazardRateCurve::bootstrap(valuation_date, &bumped, discount)?;
+
+        Ok(self.npv(valuation_date, discount, &bumped) - self.npv(valuation_date, discount, &base))
+    }
+
+    /// Accrual (Actual/360) of the current period at the step-in date.
+    fn accrual_at_step_in(&self, valuation_date: Date) -> f64 {
+        let step_in = valuation_date.next_day().unwrap();
+
+        self.periods
+            .iter()
+            .find(|period| period.start <= step_in && step_in < period.end)
+            .map_or(0.0, |period| {
+                DayCountConvention::Actual_360.day_count_factor(period.start, step_in)
+            })
+    }
+
+    /// Both legs per unit of notional, integrated exactly over intervals
+    /// bounded by the period dates and the nodes of the hazard rate curve.
+    fn leg_values<C: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        hazard: &HazardRateCurve,
+    ) -> LegValues {
+        let step_in = valuation_date.next_day().unwrap();
+        let df_0 = discount.discount_factor(valuation_date);
+        let value = |date: Date| hazard.survival_probability(date) * discount.discount_factor(date);
+
+        let mut protection = 0.0;
+        let mut annuity = 0.0;
+
+        for period in self.periods.iter().filter(|period| period.end > step_in) {
+            let tau = DayCountConvention::Actual_360.day_count_factor(period.start, period.end);
+
+            if period.payment > valuation_date {
+                annuity += tau
+                    * hazard.survival_probability(period.end)
+                    * discount.discount_factor(period.payment);
+            }
+
+            let start = period.start.max(step_in);
+            let mut grid = vec![start];
+            grid.extend(
+                hazard
+                    .dates
+                    .iter()
+                    .filter(|node| start < **node && **node < period.end),
+            );
+            grid.push(period.end);
+
+            for pair in grid.windows(2) {
+                let (a, b) = (pair[0], pair[1]);
+                let dt = year_fraction(a, b);
+                let (value_a, value_b) = (value(a), value(b));
+
+                let h = (hazard.survival_probability(a) / hazard.survival_probability(b)).ln() / dt;
+                let k = (value_a / value_b).ln() / dt;
+                let kt = k * dt;
+
+                // Integrals of exp(-k s) and s exp(-k s) over [0, dt].
+                let (i0, i1) = if kt.abs() < 1e-8 {
+                    (dt * (1.0 - 0.5 * kt), dt * dt * (0.5 - kt / 3.0))
+                } else {
+                    let e = (-kt).exp();
+                    ((1.0 - e) / k, (1.0 - e * (1.0 + kt)) / (k * k))
+                };
+
+                // Premium accrued (Actual/360) since the start of the period.
+                let accrued = DayCountConvention::Actual_360.day_count_factor(period.start, a);
+
+                protection += h * value_a * i0;
+                annuity += h * value_a * (accrued * i0 + 365.0 / 360.0 * i1);
+            }
+        }
+
+        LegValues {
+            protection: protection / df_0,
+            annuity: annuity / df_0,
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_credit_default_swap {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use crate::time::{
+        cds_maturity_date, countries::north_america::united_states::UnitedStatesCalendar,
+    };
+    use time::macros::date;
+
+    const TODAY: Date = date!(2024 - 05 - 15);
+
+    fn discount() -> YieldCurve {
+        YieldCurve::from_dates_and_rates(
+            &[TODAY, date!(2026 - 05 - 15), date!(2034 - 05 - 15)],
+            &[0.05, 0.045, 0.042],
+        )
+    }
+
+    fn cds(side: ProtectionSide, coupon: f64, tenor: i32) -> CreditDefaultSwap {
+        CreditDefaultSwap::standard(
+            side,
+            10_000_000.0,
+            coupon,
+            TODAY,
+            cds_maturity_date(TODAY, tenor),
+            &UnitedStatesCalendar,
+        )
+    }
+
+    #[test]
+    fn test_standard_schedule_and_accrued() {
+        let cds = cds(ProtectionSide::Buyer, 0.01, 5);
+
+        // Accrues from 20 March 2024 to 20 June 2029, including maturity.
+        assert_eq!(cds.maturity_date, date!(2029 - 06 - 20));
+        assert_eq!(cds.periods.len(), 21);
+        assert_eq!(cds.periods[0].start, date!(2024 - 03 - 20));
+        assert_eq!(cds.periods[0].payment, date!(2024 - 06 - 20));
+        assert_eq!(cds.periods[20].end, date!(2029 - 06 - 21));
+
+        // 57 days from 20 March to the step-in date on 16 May.
+        assert_approx_equal!(
+            cds.accrued_premium(TODAY),
+            10_000_000.0 * 0.01 * 57.0 / 360.0,
+            1e-8
+        );
+    }
+
+    #[test]
+    fn test_credit_triangle() {
+        let discount = discount();
+        let hazard = HazardRateCurve::new(TODAY, vec![date!(2034 - 05 - 15)], vec![0.02]).unwrap();
+
+        // With a flat hazard rate, the par spread is close to h (1 - R).
+        let cds = cds(ProtectionSide::Buyer, 0.01, 5);
+        let par = cds.par_spread(TODAY, &discount, &hazard);
+        assert!((par - 0.02 * 0.6).abs() < 2e-4);
+
+        // A lower recovery rate means a wider spread.
+        let low_recovery = cds.clone().with_recovery_rate(0.25);
+        assert!(low_recovery.par_spread(TODAY, &discount, &hazard) > par);
+
+        // The upfront prices the coupon against the par spread.
+        assert_approx_equal!(
+            cds.upfront(TODAY, &discount, &hazard) * cds.notional,
+            (par - cds.coupon) * cds.clean_risky_annuity(TODAY, &discount, &hazard),
+            1e-6
+        );
+    }
+
+    #[test]
+    fn test_upfront_npv_and_cs01() {
+        let discount = discount();
+        let quotes: Vec<CreditDefaultSwap> = [(1, 0.006), (3, 0.009), (5, 0.012)]
+            .into_iter()
+            .map(|(tenor, spread)| cds(ProtectionSide::Buyer, spread, tenor))
+            .collect();
+        let hazard = HazardRateCurve::bootstrap(TODAY, &quotes, &discount).unwrap();
+
+        // A 100bp coupon under a 120bp par spread: the buyer pays upfront.
+        let buyer = cds(ProtectionSide::Buyer, 0.01, 5);
+        let seller = cds(ProtectionSide::Seller, 0.01, 5);
+
+        assert_approx_equal!(buyer.par_spread(TODAY, &discount, &hazard), 0.012, 1e-10);
+        assert!(buyer.upfront(TODAY, &discount, &hazard) > 0.0);
+
+        // The dirty value is the upfront plus the accrued premium.
+        let npv = buyer.npv(TODAY, &discount, &hazard);
+        assert_approx_equal!(
+            npv,
+            buyer.upfront(TODAY, &discount, &hazard) * buyer.notional
+                - buyer.accrued_premium(TODAY),
+            1e-6
+        );
+        assert_approx_equal!(seller.npv(TODAY, &discount, &hazard), -npv, 1e-8);
+
+        // Wider spreads gain the buyer about the clean annuity per bp.
+        let cs01 = buyer.cs01(TODAY, &discount, &quotes).unwrap();
+        let annuity = buyer.clean_risky_annuity(TODAY, &discount, &hazard) * BASIS_POINT;
+        assert!(cs01 > 0.0);
+        assert!((cs01 / annuity - 1.0).abs() < 0.05);
+        assert_approx_equal!(seller.cs01(TODAY, &discount, &quotes).unwrap(), -cs01, 1e-8);
+    }
+}
@@ -0,0 +1,275 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Piecewise-constant hazard rate curves, and their bootstrapping from the
+//! par spreads of credit default swaps.
+//!
+//! With hazard rate $h_i$ on $(T_{i-1}, T_i]$, the survival probability is
+//!
+//! $$
+//! Q(t) = \exp \left( -\int_0^t h(s) \, ds \right)
+//! $$
+//!
+//! with times in years under Actual/365 Fixed from the initial date, and the
+//! last hazard rate extrapolated flat after the last node.
+
+use super::credit_default_swap::CreditDefaultSwap;
+use crate::data::Curve;
+use crate::error::RustQuantError;
+use crate::math::rootfinding::{BrentSolver, Solver};
+use crate::time::DayCountConvention;
+use time::Date;
+
+/// Largest hazard rate searched when bootstrapping.
+const MAX_HAZARD_RATE: f64 = 10.0;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Piecewise-constant hazard rate (default intensity) curve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HazardRateCurve {
+    /// Initial date of the curve, from which survival is certain.
+    pub initial_date: Date,
+
+    /// Node dates, strictly increasing and after the initial date.
+    pub dates: Vec<Date>,
+
+    /// Hazard rate up to each node date, from the previous node.
+    pub hazard_rates: Vec<f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl HazardRateCurve {
+    /// New hazard rate curve from its nodes.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there are no nodes, a different
+    ///   number of dates and hazard rates, dates that are not strictly
+    ///   increasing after the initial date, or negative hazard rates.
+    pub fn new(
+        initial_date: Date,
+        dates: Vec<Date>,
+        hazard_rates: Vec<f64>,
+    ) -> Result<Self, RustQuantError> {
+        if dates.is_empty() || dates.len() != hazard_rates.len() {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "a hazard rate curve needs one hazard rate per node date, got {} dates and {} rates",
+                dates.len(),
+                hazard_rates.len()
+            )));
+        }
+        if dates[0] <= initial_date || dates.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(RustQuantError::InvalidArgument(
+                "the node dates must be strictly increasing after the initial date".to_string(),
+            ));
+        }
+        if hazard_rates.iter().any(|h| h.is_nan() || *h < 0.0) {
+            return Err(RustQuantError::InvalidArgument(
+                "the hazard rates must not be negative".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            initial_date,
+            dates,
+            hazard_rates,
+        })
+    }
+
+    /// Hazard rate at the given date.
+    #[must_use]
+    pub fn hazard_rate(&self, date: Date) -> f64 {
+        let i = self.dates.partition_point(|node| *node < date);
+
+        self.hazard_rates[i.min(self.hazard_rates.len() - 1)]
+    }
+
+    /// Probability of surviving (not defaulting) up to the given date.
+    #[must_use]
+    pub fn survival_probability(&self, date: Date) -> f64 {
+        let mut integral = 0.0;
+        let mut previous = self.initial_date;
+
+        for (node, hazard_rate) in self.dates.iter().zip(&self.hazard_rates) {
+            if date <= previous {
+                break;
+            }
+
+            integral += hazard_rate * year_fraction(previous, date.min(*node));
+            previous = *node;
+        }
+
+        if let (Some(last), Some(hazard_rate)) = (self.dates.last(), self.hazard_rates.last()) {
+            if date > *last {
+                integral += hazard_rate * year_fraction(*last, date);
+            }
+        }
+
+        (-integral).exp()
+    }
+
+    /// Probability of defaulting by the given date.
+    #[must_use]
+    pub fn default_probability(&self, date: Date) -> f64 {
+        1.0 - self.survival_probability(date)
+    }
+
+    /// Bootstrap a hazard rate curve from credit default swaps quoted at par
+    /// (each with its quoted par spread as its coupon), with one node at the
+    /// protection end date of each quote.
+    ///
+    /// The hazard rates are solved one node at a time, in order of maturity,
+    /// so that each quote has zero value on the curve up to its maturity.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there are no quotes, or two
+    ///   quotes share a maturity.
+    /// - `RustQuantError::NotConverged` if no hazard rate up to 1000%
+    ///   reprices a quote at par.
+    pub fn bootstrap<C: Curve>(
+        valuation_date: Date,
+        quotes: &[CreditDefaultSwap],
+        discount: &C,
+    ) -> Result<Self, RustQuantError> {
+        let mut quotes: Vec<&CreditDefaultSwap> = quotes.iter().collect();
+        quotes.sort_by_key(|quote| quote.protection_end_date());
+
+        let mut dates = Vec::with_capacity(quotes.len());
+        let mut hazard_rates = Vec::with_capacity(quotes.len());
+
+        for quote in quotes {
+            dates.push(quote.protection_end_date());
+            hazard_rates.push(0.0);
+            Self::new(valuation_date, dates.clone(), hazard_rates.clone())?;
+
+            let objective = |hazard_rate: f64| {
+                let mut rates = hazard_rates.clone();
+                *rates.last_mut().unwrap() = hazard_rate;
+
+                let curve = Self {
+                    initial_date: valuation_date,
+                    dates: dates.clone(),
+                    hazard_rates: rates,
+                };
+                let par_spread = quote.par_spread(valuation_date, discount, &curve);
+
+                par_spread - quote.coupon
+            };
+
+            let root = BrentSolver::default()
+                .solve(objective, 0.0, MAX_HAZARD_RATE)
+                .map_err(|_| {
+                    RustQuantError::NotConverged(format!(
+                        "no hazard rate reprices the CDS maturing on {} at par",
+                        quote.maturity_date
+                    ))
+                })?;
+
+            *hazard_rates.last_mut().unwrap() = root;
+        }
+
+        Self::new(valuation_date, dates, hazard_rates)
+    }
+}
+
+/// Year fraction between two dates, under Actual/365 Fixed.
+pub(crate) fn year_fraction(start: Date, end: Date) -> f64 {
+    DayCountConvention::Actual_365_Fixed.day_count_factor(start, end)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_hazard_rate_curve {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use crate::instruments::credit::ProtectionSide;
+    use crate::time::{
+        cds_maturity_date, countries::north_america::united_states::UnitedStatesCalendar,
+    };
+    use time::macros::date;
+
+    const TODAY: Date = date!(2024 - 05 - 15);
+
+    #[test]
+    fn test_survival_probability() {
+        let curve = HazardRateCurve::new(
+            TODAY,
+            vec![date!(2025 - 05 - 15), date!(2027 - 05 - 15)],
+            vec![0.01, 0.03],
+        )
+        .unwrap();
+
+        let t1 = year_fraction(TODAY, date!(2025 - 05 - 15));
+        let t2 = year_fraction(TODAY, date!(2029 - 05 - 15));
+
+        assert_approx_equal!(curve.survival_probability(TODAY), 1.0, 1e-15);
+        assert_approx_equal!(
+            curve.survival_probability(date!(2025 - 05 - 15)),
+            (-0.01 * t1).exp(),
+            1e-15
+        );
+        // Flat after the last node.
+        assert_approx_equal!(
+            curve.default_probability(date!(2029 - 05 - 15)),
+            1.0 - (-0.01 * t1 - 0.03 * (t2 - t1)).exp(),
+            1e-14
+        );
+        assert_approx_equal!(curve.hazard_rate(date!(2030 - 01 - 01)), 0.03, 1e-15);
+
+        assert!(HazardRateCurve::new(TODAY, vec![TODAY], vec![0.01]).is_err());
+        assert!(HazardRateCurve::new(TODAY, vec![date!(2025 - 05 - 15)], vec![-0.01]).is_err());
+    }
+
+    #[test]
+    fn test_bootstrap_reprices_quotes() {
+        let discount = YieldCurve::from_dates_and_rates(
+            &[TODAY, date!(2026 - 05 - 15), date!(2034 - 05 - 15)],
+            &[0.05, 0.045, 0.042],
+        );
+
+        let quotes: Vec<CreditDefaultSwap> = [(1, 0.006), (3, 0.009), (5, 0.012), (7, 0.015)]
+            .into_iter()
+            .map(|(tenor, spread)| {
+                CreditDefaultSwap::standard(
+                    ProtectionSide::Buyer,
+                    10_000_000.0,
+                    spread,
+                    TODAY,
+                    cds_maturity_date(TODAY, tenor),
+                    &UnitedStatesCalendar,
+                )
+            })
+            .collect();
+
+        let curve = HazardRateCurve::bootstrap(TODAY, &quotes, &discount).unwrap();
+
+        for quote in &quotes {
+            assert_approx_equal!(
+                quote.par_spread(TODAY, &discount, &curve),
+                quote.coupon,
+                1e-10
+            );
+            assert!(quote.upfront(TODAY, &discount, &curve).abs() < 1e-10);
+        }
+
+        // Upward sloping spreads need increasing hazard rates, which are
+        // close to the credit triangle s / (1 - R) at the short end.
+        assert!(curve.hazard_rates.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!((curve.hazard_rates[0] - 0.006 / 0.6).abs() < 5e-4);
+    }
+}
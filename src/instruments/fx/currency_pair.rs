@@ -0,0 +1,133 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Currency pairs, quoted as units of the quote (domestic) currency per unit
+//! of the base (foreign) currency, e.g. EUR/USD = 1.08.
+//!
+//! The outright forward follows from covered interest parity:
+//!
+//! $$
+//! F(T) = S \frac{P_f(T)}{P_d(T)}
+//! $$
+//!
+//! where $P_f$ and $P_d$ are the discount factors of the base (foreign) and
+//! quote (domestic) currencies from the valuation date.
+
+use crate::data::Curve;
+use crate::instruments::fx::currency::Currency;
+use std::fmt;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// A currency pair BASE/QUOTE.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrencyPair {
+    /// Base (foreign) currency, of which one unit is priced.
+    pub base: Currency,
+
+    /// Quote (domestic) currency, in which the rate is expressed.
+    pub quote: Currency,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl CurrencyPair {
+    /// New currency pair BASE/QUOTE.
+    #[must_use]
+    pub fn new(base: Currency, quote: Currency) -> Self {
+        Self { base, quote }
+    }
+
+    /// Market code of the pair, e.g. `EURUSD`.
+    #[must_use]
+    pub fn code(&self) -> String {
+        format!(
+            "{}{}",
+            self.base.code.alphabetic, self.quote.code.alphabetic
+        )
+    }
+
+    /// The inverse pair QUOTE/BASE.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        Self::new(self.quote, self.base)
+    }
+
+    /// Outright forward rate for delivery on `delivery_date`, from the spot
+    /// rate at the valuation date and the discount curves of the quote
+    /// (domestic) and base (foreign) currencies.
+    pub fn forward_rate<D: Curve, F: Curve>(
+        &self,
+        spot: f64,
+        valuation_date: Date,
+        delivery_date: Date,
+        domestic: &D,
+        foreign: &F,
+    ) -> f64 {
+        let foreign_df =
+            foreign.discount_factor(delivery_date) / foreign.discount_factor(valuation_date);
+        let domestic_df =
+            domestic.discount_factor(delivery_date) / domestic.discount_factor(valuation_date);
+
+        spot * foreign_df / domestic_df
+    }
+}
+
+impl fmt::Display for CurrencyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}",
+            self.base.code.alphabetic, self.quote.code.alphabetic
+        )
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_currency_pair {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use crate::iso::{EUR, USD};
+    use time::macros::date;
+
+    #[test]
+    fn test_currency_pair_forward() {
+        let pair = CurrencyPair::new(EUR, USD);
+
+        assert_eq!(pair.code(), "EURUSD");
+        assert_eq!(pair.to_string(), "EUR/USD");
+        assert_eq!(pair.inverse().inverse(), pair);
+
+        let today = date!(2024 - 01 - 01);
+        let delivery = date!(2025 - 01 - 01);
+        let usd = YieldCurve::from_dates_and_rates(&[today, delivery], &[0.05, 0.05]);
+        let eur = YieldCurve::from_dates_and_rates(&[today, delivery], &[0.035, 0.035]);
+
+        // The higher yielding currency trades at a forward discount.
+        let forward = pair.forward_rate(1.08, today, delivery, &usd, &eur);
+        assert_approx_equal!(forward, 1.08 * f64::exp(0.015), 1e-12);
+        assert_approx_equal!(
+            pair.inverse()
+                .forward_rate(1.0 / 1.08, today, delivery, &eur, &usd),
+            1.0 / forward,
+            1e-12
+        );
+    }
+}
@@ -0,0 +1,264 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! FX forwards and FX swaps, valued off the discount curves of both
+//! currencies of the pair.
+//!
+//! Notionals are in the base currency, positive to buy the base currency,
+//! and values are in the quote currency.
+
+use crate::data::Curve;
+use crate::instruments::fx::currency_pair::CurrencyPair;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// FX forward (outright): exchange `notional` units of the base currency
+/// for `notional * contract_rate` units of the quote currency on the
+/// delivery date.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy)]
+pub struct FxForward {
+    /// Currency pair.
+    pub pair: CurrencyPair,
+
+    /// Base currency notional, positive to buy the base currency.
+    pub notional: f64,
+
+    /// Agreed exchange rate, in units of quote currency per base currency.
+    pub contract_rate: f64,
+
+    /// Delivery date.
+    pub delivery_date: Date,
+}
+
+/// FX swap: buy `notional` units of the base currency on the near date at
+/// the near rate, and sell them back on the far date at the far rate
+/// (the reverse for a negative notional).
+#[derive(Debug, Clone, Copy)]
+pub struct FxSwap {
+    /// Currency pair.
+    pub pair: CurrencyPair,
+
+    /// Base currency notional, positive to buy the base currency on the
+    /// near date.
+    pub notional: f64,
+
+    /// Near (first) exchange date.
+    pub near_date: Date,
+
+    /// Exchange rate of the near leg.
+    pub near_rate: f64,
+
+    /// Far (second) exchange date.
+    pub far_date: Date,
+
+    /// Exchange rate of the far leg.
+    pub far_rate: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl FxForward {
+    /// New FX forward.
+    #[must_use]
+    pub fn new(pair: CurrencyPair, notional: f64, contract_rate: f64, delivery_date: Date) -> Self {
+        Self {
+            pair,
+            notional,
+            contract_rate,
+            delivery_date,
+        }
+    }
+
+    /// Outright forward rate to the delivery date.
+    pub fn forward_rate<D: Curve, F: Curve>(
+        &self,
+        spot: f64,
+        valuation_date: Date,
+        domestic: &D,
+        foreign: &F,
+    ) -> f64 {
+        self.pair
+            .forward_rate(spot, valuation_date, self.delivery_date, domestic, foreign)
+    }
+
+    /// Forward points: the outright forward rate less the spot rate.
+    pub fn forward_points<D: Curve, F: Curve>(
+        &self,
+        spot: f64,
+        valuation_date: Date,
+        domestic: &D,
+        foreign: &F,
+    ) -> f64 {
+        self.forward_rate(spot, valuation_date, domestic, foreign) - spot
+    }
+
+    /// Present value in the quote currency:
+    /// $N (F(T) - K) P_d(T)$, or nothing once delivered.
+    pub fn npv<D: Curve, F: Curve>(
+        &self,
+        spot: f64,
+        valuation_date: Date,
+        domestic: &D,
+        foreign: &F,
+    ) -> f64 {
+        if self.delivery_date < valuation_date {
+            return 0.0;
+        }
+
+        let forward = self.forward_rate(spot, valuation_date, domestic, foreign);
+        let df =
+            domestic.discount_factor(self.delivery_date) / domestic.discount_factor(valuation_date);
+
+        self.notional * (forward - self.contract_rate) * df
+    }
+}
+
+impl FxSwap {
+    /// New FX swap.
+    #[must_use]
+    pub fn new(
+        pair: CurrencyPair,
+        notional: f64,
+        near_date: Date,
+        near_rate: f64,
+        far_date: Date,
+        far_rate: f64,
+    ) -> Self {
+        Self {
+            pair,
+            notional,
+            near_date,
+            near_rate,
+            far_date,
+            far_rate,
+        }
+    }
+
+    /// The near leg, as an FX forward.
+    #[must_use]
+    pub fn near_leg(&self) -> FxForward {
+        FxForward::new(self.pair, self.notional, self.near_rate, self.near_date)
+    }
+
+    /// The far leg, as an FX forward in the opposite direction.
+    #[must_use]
+    pub fn far_leg(&self) -> FxForward {
+        FxForward::new(self.pair, -self.notional, self.far_rate, self.far_date)
+    }
+
+    /// Swap points: the outright forward to the far date less the outright
+    /// forward to the near date.
+    pub fn swap_points<D: Curve, F: Curve>(
+        &self,
+        spot: f64,
+        valuation_date: Date,
+        domestic: &D,
+        foreign: &F,
+    ) -> f64 {
+        self.far_leg()
+            .forward_rate(spot, valuation_date, domestic, foreign)
+            - self
+                .near_leg()
+                .forward_rate(spot, valuation_date, domestic, foreign)
+    }
+
+    /// Present value of both legs in the quote currency.
+    pub fn npv<D: Curve, F: Curve>(
+        &self,
+        spot: f64,
+        valuation_date: Date,
+        domestic: &D,
+        foreign: &F,
+    ) -> f64 {
+        self.near_leg().npv(spot, valuation_date, domestic, foreign)
+            + self.far_leg().npv(spot, valuation_date, domestic, foreign)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_fx_forward {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use crate::iso::{EUR, USD};
+    use time::macros::date;
+
+    const TODAY: Date = date!(2024 - 01 - 02);
+
+    fn curves() -> (YieldCurve, YieldCurve) {
+        let dates = [TODAY, date!(2025 - 01 - 02), date!(2026 - 01 - 02)];
+
+        (
+            YieldCurve::from_dates_and_rates(&dates, &[0.052, 0.048, 0.045]),
+            YieldCurve::from_dates_and_rates(&dates, &[0.039, 0.035, 0.031]),
+        )
+    }
+
+    #[test]
+    fn test_fx_forward() {
+        let (usd, eur) = curves();
+        let pair = CurrencyPair::new(EUR, USD);
+        let delivery = date!(2024 - 07 - 02);
+
+        // At the outright forward rate, the forward is worth nothing.
+        let forward = pair.forward_rate(1.10, TODAY, delivery, &usd, &eur);
+        let at_market = FxForward::new(pair, 1_000_000.0, forward, delivery);
+        assert_approx_equal!(at_market.npv(1.10, TODAY, &usd, &eur), 0.0, 1e-8);
+        assert!(at_market.forward_points(1.10, TODAY, &usd, &eur) > 0.0);
+
+        // Buying EUR below the forward gains the discounted difference.
+        let long = FxForward::new(pair, 1_000_000.0, forward - 0.01, delivery);
+        let df = usd.discount_factor(delivery);
+        assert_approx_equal!(long.npv(1.10, TODAY, &usd, &eur), 10_000.0 * df, 1e-6);
+
+        // Delivered forwards are worth nothing.
+        assert_approx_equal!(
+            long.npv(1.10, date!(2024 - 08 - 01), &usd, &eur),
+            0.0,
+            1e-15
+        );
+    }
+
+    #[test]
+    fn test_fx_swap() {
+        let (usd, eur) = curves();
+        let pair = CurrencyPair::new(EUR, USD);
+        let (near, far) = (date!(2024 - 01 - 04), date!(2025 - 01 - 06));
+
+        let near_rate = pair.forward_rate(1.10, TODAY, near, &usd, &eur);
+        let far_rate = pair.forward_rate(1.10, TODAY, far, &usd, &eur);
+
+        // At market swap points the swap is worth nothing.
+        let swap = FxSwap::new(pair, 5_000_000.0, near, near_rate, far, far_rate);
+        assert_approx_equal!(swap.npv(1.10, TODAY, &usd, &eur), 0.0, 1e-7);
+        assert_approx_equal!(
+            swap.swap_points(1.10, TODAY, &usd, &eur),
+            far_rate - near_rate,
+            1e-14
+        );
+
+        // Selling EUR forward at a higher far rate gains on the far leg.
+        let rich = FxSwap::new(pair, 5_000_000.0, near, near_rate, far, far_rate + 0.001);
+        assert_approx_equal!(
+            rich.npv(1.10, TODAY, &usd, &eur),
+            5_000.0 * usd.discount_factor(far),
+            1e-6
+        );
+    }
+}
@@ -0,0 +1,582 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Vanilla FX options in the Garman-Kohlhagen model, with the FX market's
+//! delta conventions.
+//!
+//! In terms of the outright forward $F$ to expiry, a call ($\phi = 1$) or
+//! put ($\phi = -1$) on one unit of the base currency is worth
+//!
+//! $$
+//! P_d(T) \phi \left[ F \Phi(\phi d_1) - K \Phi(\phi d_2) \right],
+//! \quad d_{1,2} = \frac{\ln(F / K) \pm \sigma^2 T / 2}{\sigma \sqrt{T}}
+//! $$
+//!
+//! in the quote currency. FX volatilities are quoted by delta, in one of
+//! four conventions (see [`DeltaConvention`]); [`strike_from_delta`] turns
+//! a quoted delta back into a strike, e.g. to build a surface by strike.
+
+use crate::data::Curve;
+use crate::error::RustQuantError;
+use crate::instruments::fx::currency_pair::CurrencyPair;
+use crate::instruments::options::TypeFlag;
+use crate::math::distributions::{Distribution, Gaussian};
+use crate::math::rootfinding::{BrentSolver, Solver};
+use crate::time::DayCountConvention;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Delta conventions of FX options.
+///
+/// Premium-adjusted deltas are used when the premium is paid in the base
+/// currency (e.g. USD/JPY options with a USD premium), and deduct the
+/// premium from the hedge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeltaConvention {
+    /// Spot delta: $\phi P_f(T) \Phi(\phi d_1)$.
+    Spot,
+
+    /// Forward delta: $\phi \Phi(\phi d_1)$.
+    Forward,
+
+    /// Premium-adjusted spot delta: $\phi P_f(T) \frac{K}{F} \Phi(\phi d_2)$.
+    SpotPremiumAdjusted,
+
+    /// Premium-adjusted forward delta: $\phi \frac{K}{F} \Phi(\phi d_2)$.
+    ForwardPremiumAdjusted,
+}
+
+/// European FX option on the base currency of a pair, settled on expiry.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy)]
+pub struct FxOption {
+    /// Currency pair.
+    pub pair: CurrencyPair,
+
+    /// Base currency notional.
+    pub notional: f64,
+
+    /// Strike, in units of quote currency per base currency.
+    pub strike: f64,
+
+    /// Expiry date.
+    pub expiry_date: Date,
+
+    /// Call or put on the base currency.
+    pub option_type: TypeFlag,
+}
+
+/// Forward, discount factors and time to expiry of an option at a
+/// valuation date.
+struct ForwardInputs {
+    forward: f64,
+    domestic_df: f64,
+    foreign_df: f64,
+    expiry: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl FxOption {
+    /// New FX option.
+    #[must_use]
+    pub fn new(
+        pair: CurrencyPair,
+        notional: f64,
+        strike: f64,
+        expiry_date: Date,
+        option_type: TypeFlag,
+    ) -> Self {
+        Self {
+            pair,
+            notional,
+            strike,
+            expiry_date,
+            option_type,
+        }
+    }
+
+    /// Garman-Kohlhagen price in the quote currency.
+    pub fn price<D: Curve, F: Curve>(
+        &self,
+        spot: f64,
+        volatility: f64,
+        valuation_date: Date,
+        domestic: &D,
+        foreign: &F,
+    ) -> f64 {
+        let inputs = self.inputs(spot, valuation_date, domestic, foreign);
+        let phi = sign(self.option_type);
+        let std_dev = volatility * inputs.expiry.max(0.0).sqrt();
+
+        let undiscounted = if std_dev > 0.0 {
+            let (d1, d2) = d1_d2(inputs.forward, self.strike, std_dev);
+            let n = Gaussian::default();
+
+            phi * (inputs.forward * n.cdf(phi * d1) - self.strike * n.cdf(phi * d2))
+        } else {
+            (phi * (inputs.forward - self.strike)).max(0.0)
+        };
+
+        self.notional * inputs.domestic_df * undiscounted
+    }
+
+    /// Delta per unit of base currency notional, in the given convention.
+    pub fn delta<D: Curve, F: Curve>(
+        &self,
+        convention: DeltaConvention,
+        spot: f64,
+        volatility: f64,
+        valuation_date: Date,
+        domestic: &D,
+        foreign: &F,
+    ) -> f64 {
+        let inputs = self.inputs(spot, valuation_date, domestic, foreign);
+
+        fx_delta(
+            convention,
+            self.option_type,
+            inputs.forward,
+            self.strike,
+            volatility,
+            inputs.expiry,
+            inputs.foreign_df,
+        )
+    }
+
+    /// Forward, discount factors and time to expiry (Actual/365 Fixed).
+    fn inputs<D: Curve, F: Curve>(
+        &self,
+        spot: f64,
+        valuation_date: Date,
+        domestic: &D,
+        foreign: &F,
+    ) -> ForwardInputs {
+        ForwardInputs {
+            forward: self.pair.forward_rate(
+                spot,
+                valuation_date,
+                self.expiry_date,
+                domestic,
+                foreign,
+            ),
+            domestic_df: domestic.discount_factor(self.expiry_date)
+                / domestic.discount_factor(valuation_date),
+            foreign_df: foreign.discount_factor(self.expiry_date)
+                / foreign.discount_factor(valuation_date),
+            expiry: DayCountConvention::Actual_365_Fixed
+                .day_count_factor(valuation_date, self.expiry_date),
+        }
+    }
+}
+
+/// Delta of an FX option in the given convention, from the forward to
+/// expiry, the time to expiry (in years) and the discount factor of the
+/// base (foreign) currency to expiry.
+///
+/// At expiry (or with zero volatility) the delta is intrinsic: the
+/// (premium-adjusted) delta of an in-the-money option, and zero for an
+/// option at or out of the money.
+#[must_use]
+pub fn fx_delta(
+    convention: DeltaConvention,
+    option_type: TypeFlag,
+    forward: f64,
+    strike: f64,
+    volatility: f64,
+    expiry: f64,
+    foreign_df: f64,
+) -> f64 {
+    let phi = sign(option_type);
+    let std_dev = volatility * expiry.max(0.0).sqrt();
+
+    // Exercise probabilities under the foreign and domestic measures.
+    let (p1, p2) = if std_dev > 0.0 {
+        let (d1, d2) = d1_d2(forward, strike, std_dev);
+        let n = Gaussian::default();
+
+        (n.cdf(phi * d1), n.cdf(phi * d2))
+    } else if phi * (forward - strike) > 0.0 {
+        (1.0, 1.0)
+    } else {
+        (0.0, 0.0)
+    };
+
+    let forward_delta = match convention {
+        DeltaConvention::Spot | DeltaConvention::Forward => phi * p1,
+        DeltaConvention::SpotPremiumAdjusted | DeltaConvention::ForwardPremiumAdjusted => {
+            phi * strike / forward * p2
+        }
+    };
+
+    match convention {
+        DeltaConvention::Spot | DeltaConvention::SpotPremiumAdjusted => foreign_df * forward_delta,
+        DeltaConvention::Forward | DeltaConvention::ForwardPremiumAdjusted => forward_delta,
+    }
+}
+
+/// Strike of an FX option with the given delta, inverting [`fx_delta`].
+///
+/// Unadjusted deltas invert in closed form. Premium-adjusted deltas are
+/// solved numerically: the strike lies below the unadjusted strike of the
+/// same delta, and for calls above the strike of the largest
+/// premium-adjusted delta, so that the root is unique. Premium-adjusted put
+/// deltas below -1, which have no unadjusted strike, are also supported.
+///
+/// # Errors
+/// - `RustQuantError::InvalidArgument` if the forward, volatility or time to
+///   expiry is not positive, or no strike has the given delta (e.g. the
+///   delta has the wrong sign for the option type, or a premium-adjusted call
+///   delta is above its maximum).
+/// - `RustQuantError::NotConverged` if the numerical inversion fails.
+pub fn strike_from_delta(
+    delta: f64,
+    convention: DeltaConvention,
+    option_type: TypeFlag,
+    forward: f64,
+    volatility: f64,
+    expiry: f64,
+    foreign_df: f64,
+) -> Result<f64, RustQuantError> {
+    if !(forward > 0.0 && volatility > 0.0 && expiry > 0.0 && foreign_df > 0.0) {
+        return Err(RustQuantError::InvalidArgument(format!(
+            "the forward ({forward}), volatility ({volatility}), expiry ({expiry}) and \
+             foreign discount factor ({foreign_df}) must be positive"
+        )));
+    }
+
+    let phi = sign(option_type);
+    let std_dev = volatility * expiry.sqrt();
+    let n = Gaussian::default();
+
+    let forward_delta = match convention {
+        DeltaConvention::Spot | DeltaConvention::SpotPremiumAdjusted => delta / foreign_df,
+        DeltaConvention::Forward | DeltaConvention::ForwardPremiumAdjusted => delta,
+    };
+
+    // Only unadjusted deltas are bounded by one: premium-adjusted put deltas
+    // fall below -1 for strikes well above the forward.
+    let is_unadjusted = matches!(convention, DeltaConvention::Spot | DeltaConvention::Forward);
+
+    if !(phi * forward_delta > 0.0 && (phi * forward_delta < 1.0 || !is_unadjusted)) {
+        return Err(RustQuantError::InvalidArgument(format!(
+            "no {option_type:?} has a delta of {delta}"
+        )));
+    }
+
+    // Strike of the unadjusted forward delta, from d1 = phi N^-1(phi delta).
+    let unadjusted_strike = |forward_delta: f64| {
+        let d1 = phi * n.inv_cdf(phi * forward_delta);
+        forward * (-d1 * std_dev + 0.5 * std_dev * std_dev).exp()
+    };
+
+    match convention {
+        DeltaConvention::Spot | DeltaConvention::Forward => Ok(unadjusted_strike(forward_delta)),
+        DeltaConvention::SpotPremiumAdjusted | DeltaConvention::ForwardPremiumAdjusted => {
+            let objective = |strike: f64| {
+                fx_delta(
+                    DeltaConvention::ForwardPremiumAdjusted,
+                    option_type,
+                    forward,
+                    strike,
+                    volatility,
+                    expiry,
+                    1.0,
+                ) - forward_delta
+            };
+
+            let lower = match option_type {
+                // The largest call delta solves std_dev N(d2) = n(d2).
+                TypeFlag::Call => {
+                    let d2 = BrentSolver::default()
+                        .solve(|d2| std_dev * n.cdf(d2) - n.pdf(d2), -10.0, 10.0)
+                        .map_err(|_| {
+                            RustQuantError::NotConverged(
+                                "no strike has the largest premium-adjusted delta".to_string(),
+                            )
+                        })?;
+
+                    forward * (-d2 * std_dev - 0.5 * std_dev * std_dev).exp()
+                }
+                TypeFlag::Put => forward * (-10.0 * std_dev - 0.5 * std_dev * std_dev).exp(),
+            };
+
+            let upper = if phi * forward_delta < 1.0 {
+                unadjusted_strike(forward_delta)
+            } else {
+                // Past d2 = -10 a put delta is -K / F to machine precision,
+                // so this strike has a delta below the target.
+                forward
+                    * (10.0 * std_dev - 0.5 * std_dev * std_dev)
+                        .exp()
+                        .max(-2.0 * forward_delta)
+            };
+
+            if objective(lower) * objective(upper) > 0.0 {
+                return Err(RustQuantError::InvalidArgument(format!(
+                    "no {option_type:?} has a premium-adjusted delta of {delta}"
+                )));
+            }
+
+            BrentSolver::default()
+                .solve(objective, lower, upper)
+                .map_err(|_| {
+                    RustQuantError::NotConverged(format!(
+                        "no strike found for a premium-adjusted delta of {delta}"
+                    ))
+                })
+        }
+    }
+}
+
+/// +1 for a call, -1 for a put.
+fn sign(option_type: TypeFlag) -> f64 {
+    match option_type {
+        TypeFlag::Call => 1.0,
+        TypeFlag::Put => -1.0,
+    }
+}
+
+/// Black-76 `d1` and `d2` for a standard deviation of the log forward.
+fn d1_d2(forward: f64, strike: f64, std_dev: f64) -> (f64, f64) {
+    let d1 = ((forward / strike).ln() + 0.5 * std_dev * std_dev) / std_dev;
+
+    (d1, d1 - std_dev)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_fx_option {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use crate::iso::{EUR, USD};
+    use time::macros::date;
+
+    const TODAY: Date = date!(2024 - 01 - 02);
+    const EXPIRY: Date = date!(2024 - 07 - 02);
+
+    fn curves() -> (YieldCurve, YieldCurve) {
+        let dates = [TODAY, date!(2025 - 01 - 02)];
+
+        (
+            YieldCurve::from_dates_and_rates(&dates, &[0.052, 0.048]),
+            YieldCurve::from_dates_and_rates(&dates, &[0.039, 0.035]),
+        )
+    }
+
+    fn option(strike: f64, option_type: TypeFlag) -> FxOption {
+        FxOption::new(
+            CurrencyPair::new(EUR, USD),
+            1_000_000.0,
+            strike,
+            EXPIRY,
+            option_type,
+        )
+    }
+
+    #[test]
+    fn test_garman_kohlhagen_parity_and_delta() {
+        let (usd, eur) = curves();
+        let (spot, vol) = (1.10, 0.08);
+
+        let call = option(1.12, TypeFlag::Call);
+        let put = option(1.12, TypeFlag::Put);
+
+        // Put-call parity: C - P = N (F - K) P_d(T).
+        let forward = call.pair.forward_rate(spot, TODAY, EXPIRY, &usd, &eur);
+        let df = usd.discount_factor(EXPIRY);
+        assert_approx_equal!(
+            call.price(spot, vol, TODAY, &usd, &eur) - put.price(spot, vol, TODAY, &usd, &eur),
+            1_000_000.0 * (forward - 1.12) * df,
+            1e-6
+        );
+
+        // The spot delta is the sensitivity of the price to the spot.
+        let h = 1e-5;
+        let bumped = (call.price(spot + h, vol, TODAY, &usd, &eur)
+            - call.price(spot - h, vol, TODAY, &usd, &eur))
+            / (2.0 * h * call.notional);
+        assert_approx_equal!(
+            call.delta(DeltaConvention::Spot, spot, vol, TODAY, &usd, &eur),
+            bumped,
+            1e-6
+        );
+
+        // The premium-adjusted spot delta deducts the premium in EUR.
+        let premium = call.price(spot, vol, TODAY, &usd, &eur) / (call.notional * spot);
+        assert_approx_equal!(
+            call.delta(
+                DeltaConvention::SpotPremiumAdjusted,
+                spot,
+                vol,
+                TODAY,
+                &usd,
+                &eur
+            ),
+            call.delta(DeltaConvention::Spot, spot, vol, TODAY, &usd, &eur) - premium,
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_fx_delta_at_expiry() {
+        let (forward, foreign_df) = (1.10, 0.98);
+
+        for expiry in [0.0, -0.1] {
+            let delta = |convention, option_type, strike| {
+                fx_delta(
+                    convention,
+                    option_type,
+                    forward,
+                    strike,
+                    0.1,
+                    expiry,
+                    foreign_df,
+                )
+            };
+
+            assert_eq!(
+                delta(DeltaConvention::Spot, TypeFlag::Call, 1.0),
+                foreign_df
+            );
+            assert_eq!(
+                delta(DeltaConvention::Spot, TypeFlag::Put, 1.2),
+                -foreign_df
+            );
+            assert_eq!(delta(DeltaConvention::Forward, TypeFlag::Call, 1.0), 1.0);
+            assert_eq!(delta(DeltaConvention::Forward, TypeFlag::Call, 1.2), 0.0);
+            assert_eq!(delta(DeltaConvention::Spot, TypeFlag::Put, 1.0), 0.0);
+            assert_eq!(delta(DeltaConvention::Spot, TypeFlag::Call, forward), 0.0);
+            assert_approx_equal!(
+                delta(DeltaConvention::ForwardPremiumAdjusted, TypeFlag::Call, 1.0),
+                1.0 / forward,
+                1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_strike_from_delta() {
+        let (forward, vol, expiry, foreign_df) = (1.1063, 0.09, 0.5, 0.981);
+
+        for convention in [
+            DeltaConvention::Spot,
+            DeltaConvention::Forward,
+            DeltaConvention::SpotPremiumAdjusted,
+            DeltaConvention::ForwardPremiumAdjusted,
+        ] {
+            for (option_type, delta) in [(TypeFlag::Call, 0.25), (TypeFlag::Put, -0.25)] {
+                let strike = strike_from_delta(
+                    delta,
+                    convention,
+                    option_type,
+                    forward,
+                    vol,
+                    expiry,
+                    foreign_df,
+                )
+                .unwrap();
+
+                assert_approx_equal!(
+                    fx_delta(
+                        convention,
+                        option_type,
+                        forward,
+                        strike,
+                        vol,
+                        expiry,
+                        foreign_df
+                    ),
+                    delta,
+                    1e-10
+                );
+            }
+        }
+
+        // 25 delta calls are out of the money, more so when unadjusted.
+        let strike = |convention| {
+            strike_from_delta(0.25, convention, TypeFlag::Call, forward, vol, expiry, 1.0).unwrap()
+        };
+        assert!(strike(DeltaConvention::ForwardPremiumAdjusted) > forward);
+        assert!(strike(DeltaConvention::Forward) > strike(DeltaConvention::ForwardPremiumAdjusted));
+
+        // Premium-adjusted put deltas extend below -1, past the forward.
+        for convention in [
+            DeltaConvention::SpotPremiumAdjusted,
+            DeltaConvention::ForwardPremiumAdjusted,
+        ] {
+            let strike = strike_from_delta(
+                -1.2,
+                convention,
+                TypeFlag::Put,
+                forward,
+                vol,
+                expiry,
+                foreign_df,
+            )
+            .unwrap();
+
+            assert!(strike > forward);
+            assert_approx_equal!(
+                fx_delta(
+                    convention,
+                    TypeFlag::Put,
+                    forward,
+                    strike,
+                    vol,
+                    expiry,
+                    foreign_df
+                ),
+                -1.2,
+                1e-10
+            );
+        }
+
+        // Premium-adjusted call deltas are bounded below one.
+        assert!(strike_from_delta(
+            0.99,
+            DeltaConvention::ForwardPremiumAdjusted,
+            TypeFlag::Call,
+            forward,
+            vol,
+            expiry,
+            1.0
+        )
+        .is_err());
+        assert!(strike_from_delta(
+            -1.2,
+            DeltaConvention::Forward,
+            TypeFlag::Put,
+            forward,
+            vol,
+            expiry,
+            1.0
+        )
+        .is_err());
+        assert!(strike_from_delta(
+            -0.25,
+            DeltaConvention::Forward,
+            TypeFlag::Call,
+            forward,
+            vol,
+            expiry,
+            1.0
+        )
+        .is_err());
+    }
+}
@@ -10,3 +10,15 @@
 pub mod currency;
 pub mod exchange;
 pub mod money;
+
+/// Currency pairs and outright forward rates.
+pub mod currency_pair;
+pub use currency_pair::*;
+
+/// FX forwards and FX swaps.
+pub mod fx_forward;
+pub use fx_forward::*;
+
+/// Garman-Kohlhagen FX options and delta conventions.
+pub mod fx_option;
+pub use fx_option::*;
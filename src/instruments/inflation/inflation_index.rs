@@ -0,0 +1,217 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Consumer price indices and their reference (lagged) values.
+//!
+//! A price index is published once a month, for the month as a whole. The
+//! reference index of a date is observed with a lag of a few months: with
+//! a lag of $L$ months and linear interpolation (TIPS, most linkers), the
+//! reference index on day $d$ of a month with $D$ days is
+//!
+//! $$
+//! I_{ref} = I_{m-L} + \frac{d - 1}{D} \left( I_{m-L+1} - I_{m-L} \right)
+//! $$
+//!
+//! and with flat interpolation (most zero-coupon inflation swaps) it is
+//! $I_{m-L}$ for every day of the month.
+
+use crate::error::RustQuantError;
+use crate::time::add_months;
+use std::collections::BTreeMap;
+use time::{util::days_in_year_month, Date};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Interpolation of monthly index fixings within a month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexInterpolation {
+    /// The fixing of the lagged month, for every day of the month.
+    Flat,
+
+    /// Linear in the day of the month, between the fixings of the lagged
+    /// month and the month after.
+    Linear,
+}
+
+/// A monthly price index (e.g. US CPI-U, UK RPI, Eurozone HICPxT) with its
+/// observation lag and interpolation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InflationIndex {
+    /// Name of the index.
+    pub name: String,
+
+    /// Observation lag, in months (usually 2 or 3).
+    pub lag_months: i32,
+
+    /// Interpolation within a month.
+    pub interpolation: IndexInterpolation,
+
+    /// Published fixings, keyed by the first day of their month.
+    pub fixings: BTreeMap<Date, f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl InflationIndex {
+    /// New index, with no fixings.
+    #[must_use]
+    pub fn new(name: &str, lag_months: i32, interpolation: IndexInterpolation) -> Self {
+        Self {
+            name: name.to_string(),
+            lag_months,
+            interpolation,
+            fixings: BTreeMap::new(),
+        }
+    }
+
+    /// Add the fixing of the month of `month`.
+    #[must_use]
+    pub fn with_fixing(mut self, month: Date, value: f64) -> Self {
+        self.fixings.insert(first_day_of_month(month), value);
+        self
+    }
+
+    /// Add the fixings of consecutive months, from the month of `first_month`.
+    #[must_use]
+    pub fn with_fixings(mut self, first_month: Date, values: &[f64]) -> Self {
+        let first_month = first_day_of_month(first_month);
+
+        for (i, value) in values.iter().enumerate() {
+            self.fixings
+                .insert(add_months(first_month, i as i32), *value);
+        }
+        self
+    }
+
+    /// Published fixing of the month of `month`, if any.
+    #[must_use]
+    pub fn fixing(&self, month: Date) -> Option<f64> {
+        self.fixings.get(&first_day_of_month(month)).copied()
+    }
+
+    /// The date whose index level is the reference index of `date`: `date`
+    /// moved back by the lag, and to the start of its month for a flat
+    /// interpolation.
+    #[must_use]
+    pub fn observation_date(&self, date: Date) -> Date {
+        let lagged = add_months(date, -self.lag_months);
+
+        match self.interpolation {
+            IndexInterpolation::Flat => first_day_of_month(lagged),
+            IndexInterpolation::Linear => lagged,
+        }
+    }
+
+    /// Reference index of `date` from the published fixings.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a fixing it needs has not been
+    ///   published.
+    pub fn reference_index(&self, date: Date) -> Result<f64, RustQuantError> {
+        let month = first_day_of_month(add_months(date, -self.lag_months));
+        let fixing = |month: Date| {
+            self.fixing(month).ok_or_else(|| {
+                RustQuantError::MissingInput(format!(
+                    "no {} fixing for {} {}",
+                    self.name,
+                    month.month(),
+                    month.year()
+                ))
+            })
+        };
+
+        match self.interpolation {
+            IndexInterpolation::Flat => fixing(month),
+            IndexInterpolation::Linear => {
+                let start = fixing(month)?;
+
+                if date.day() == 1 {
+                    return Ok(start);
+                }
+
+                let end = fixing(add_months(month, 1))?;
+                let weight = f64::from(date.day() - 1)
+                    / f64::from(days_in_year_month(date.year(), date.month()));
+
+                Ok(start + weight * (end - start))
+            }
+        }
+    }
+}
+
+/// First day of the month of a date.
+pub(crate) fn first_day_of_month(date: Date) -> Date {
+    date.replace_day(1).unwrap()
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_inflation_index {
+    use super::*;
+    use crate::assert_approx_equal;
+    use time::macros::date;
+
+    /// CPI-U, non seasonally adjusted, from September to December 2023.
+    fn cpi(interpolation: IndexInterpolation) -> InflationIndex {
+        InflationIndex::new("CPI-U", 3, interpolation)
+            .with_fixings(date!(2023 - 09 - 01), &[307.789, 307.671, 307.051, 306.746])
+    }
+
+    #[test]
+    fn test_linear_reference_index() {
+        let cpi = cpi(IndexInterpolation::Linear);
+
+        // The first of the month uses the fixing three months before.
+        assert_approx_equal!(
+            cpi.reference_index(date!(2024 - 01 - 01)).unwrap(),
+            307.671,
+            1e-12
+        );
+
+        // 15 January 2024 is 14/31 of the way from October to November.
+        assert_approx_equal!(
+            cpi.reference_index(date!(2024 - 01 - 15)).unwrap(),
+            307.671 + 14.0 / 31.0 * (307.051 - 307.671),
+            1e-12
+        );
+        assert_eq!(
+            cpi.observation_date(date!(2024 - 01 - 15)),
+            date!(2023 - 10 - 15)
+        );
+
+        // The January 2024 fixing is not known.
+        assert!(matches!(
+            cpi.reference_index(date!(2024 - 03 - 31)),
+            Err(RustQuantError::MissingInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_flat_reference_index() {
+        let cpi = cpi(IndexInterpolation::Flat);
+
+        assert_approx_equal!(
+            cpi.reference_index(date!(2024 - 02 - 29)).unwrap(),
+            307.051,
+            1e-12
+        );
+        assert_eq!(
+            cpi.observation_date(date!(2024 - 02 - 29)),
+            date!(2023 - 11 - 01)
+        );
+        assert_eq!(cpi.fixing(date!(2023 - 12 - 25)), Some(306.746));
+    }
+}
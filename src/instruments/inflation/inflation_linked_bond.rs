@@ -0,0 +1,316 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Inflation-linked bonds with TIPS-style indexation.
+//!
+//! The principal is scaled by the index ratio $I_{ref}(t) / I_{base}$, where
+//! $I_{base}$ is the reference index of the dated date, and each real coupon
+//! is paid on the indexed principal of its payment date. With a deflation
+//! floor (as for TIPS), the principal repaid at maturity is never below the
+//! face value.
+//!
+//! The [`Bond`] analytics of an inflation-linked bond are in real terms:
+//! real prices, accrued interest and real yields. The invoice (nominal)
+//! price of a real clean price is the real dirty price times the index
+//! ratio of the settlement date.
+
+use super::zero_inflation_curve::ZeroInflationCurve;
+use crate::data::Curve;
+use crate::error::RustQuantError;
+use crate::instruments::bonds::Bond;
+use crate::time::{Frequency, Schedule};
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Inflation-linked (capital-indexed) bond.
+#[allow(clippy::module_name_repetitions)]
+pub struct InflationLinkedBond {
+    /// Coupon schedule, from the dated date to maturity.
+    pub schedule: Schedule,
+
+    /// Coupon frequency.
+    pub frequency: Frequency,
+
+    /// Annual real coupon rate.
+    pub real_coupon_rate: f64,
+
+    /// Face value, before indexation.
+    pub face_value: f64,
+
+    /// Reference index of the dated date.
+    pub base_index: f64,
+
+    /// Whether the principal repaid at maturity is floored at the face value.
+    pub deflation_floor: bool,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl InflationLinkedBond {
+    /// New inflation-linked bond, with a deflation floor.
+    #[must_use]
+    pub fn new(
+        schedule: Schedule,
+        frequency: Frequency,
+        real_coupon_rate: f64,
+        face_value: f64,
+        base_index: f64,
+    ) -> Self {
+        Self {
+            schedule,
+            frequency,
+            real_coupon_rate,
+            face_value,
+            base_index,
+            deflation_floor: true,
+        }
+    }
+
+    /// Set whether the principal is floored at the face value.
+    #[must_use]
+    pub fn with_deflation_floor(mut self, deflation_floor: bool) -> Self {
+        self.deflation_floor = deflation_floor;
+        self
+    }
+
+    /// Index ratio of a date: its reference index over the base index.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a past fixing it needs has not
+    ///   been published.
+    pub fn index_ratio(
+        &self,
+        date: Date,
+        curve: &ZeroInflationCurve,
+    ) -> Result<f64, RustQuantError> {
+        Ok(curve.reference_index(date)? / self.base_index)
+    }
+
+    /// Remaining nominal cash flows after settlement, as (payment date,
+    /// amount), with the index projected by the curve.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a past fixing it needs has not
+    ///   been published.
+    pub fn nominal_cashflows(
+        &self,
+        settlement: Date,
+        curve: &ZeroInflationCurve,
+    ) -> Result<Vec<(Date, f64)>, RustQuantError> {
+        let periods = self.schedule.coupon_periods();
+        let n = periods.len();
+
+        periods
+            .iter()
+            .zip(self.coupon_amounts())
+            .enumerate()
+            .filter(|(_, (period, _))| period.payment > settlement)
+            .map(|(i, (period, coupon))| {
+                let ratio = self.index_ratio(period.payment, curve)?;
+                let mut amount = coupon * ratio;
+
+                if i + 1 == n {
+                    let principal_ratio = if self.deflation_floor {
+                        ratio.max(1.0)
+                    } else {
+                        ratio
+                    };
+                    amount += self.face_value * principal_ratio;
+                }
+
+                Ok((period.payment, amount))
+            })
+            .collect()
+    }
+
+    /// Nominal dirty price, discounting the projected nominal cash flows off
+    /// `discount` (as of settlement).
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a past fixing it needs has not
+    ///   been published.
+    pub fn nominal_dirty_price<C: Curve>(
+        &self,
+        settlement: Date,
+        inflation: &ZeroInflationCurve,
+        discount: &C,
+    ) -> Result<f64, RustQuantError> {
+        let settlement_df = discount.discount_factor(settlement);
+
+        Ok(self
+            .nominal_cashflows(settlement, inflation)?
+            .iter()
+            .map(|(date, cf)| cf * discount.discount_factor(*date) / settlement_df)
+            .sum())
+    }
+
+    /// Invoice price paid at settlement for a real clean price: the real
+    /// dirty price times the index ratio of the settlement date.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a fixing it needs has not been
+    ///   published.
+    pub fn invoice_price(
+        &self,
+        settlement: Date,
+        real_clean_price: f64,
+        curve: &ZeroInflationCurve,
+    ) -> Result<f64, RustQuantError> {
+        let real_dirty_price = real_clean_price + self.accrued_interest(settlement);
+
+        Ok(real_dirty_price * self.index_ratio(settlement, curve)?)
+    }
+}
+
+impl Bond for InflationLinkedBond {
+    fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    fn face_value(&self) -> f64 {
+        self.face_value
+    }
+
+    fn coupon_amounts(&self) -> Vec<f64> {
+        self.schedule
+            .accrual_schedule(self.schedule.day_counting_convention)
+            .iter()
+            .map(|tau| self.face_value * self.real_coupon_rate * tau)
+            .collect()
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_inflation_linked_bond {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use crate::instruments::inflation::{IndexInterpolation, InflationIndex};
+    use crate::time::{
+        countries::north_america::united_states::UnitedStatesCalendar, DateRollingConvention,
+        DayCountConvention,
+    };
+    use time::macros::date;
+
+    const SETTLEMENT: Date = date!(2024 - 04 - 15);
+
+    /// 2.125% TIPS from 15 January 2024 to 15 January 2029, with a base
+    /// reference CPI of 307.5.
+    fn tips() -> InflationLinkedBond {
+        let schedule = Schedule::regular(
+            date!(2024 - 01 - 15),
+            date!(2029 - 01 - 15),
+            Frequency::SemiAnnually,
+            &UnitedStatesCalendar,
+            DateRollingConvention::Actual,
+            DayCountConvention::Actual_Actual_ICMA,
+        )
+        .unwrap();
+
+        InflationLinkedBond::new(schedule, Frequency::SemiAnnually, 0.02125, 100.0, 307.5)
+    }
+
+    /// Curve with the CPI at `level` from December 2023 to February 2024,
+    /// and a flat zero inflation rate.
+    fn curve(level: f64, zero_rate: f64) -> ZeroInflationCurve {
+        let cpi = InflationIndex::new("CPI-U", 3, IndexInterpolation::Linear)
+            .with_fixings(date!(2023 - 12 - 01), &[level, level, level]);
+
+        ZeroInflationCurve::new(
+            cpi,
+            SETTLEMENT,
+            vec![date!(2034 - 01 - 15)],
+            vec![zero_rate],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_real_yield_and_invoice_price() {
+        let bond = tips();
+        let curve = curve(313.65, 0.025);
+
+        // Real analytics come from the real coupons.
+        assert_approx_equal!(
+            bond.clean_price(date!(2024 - 01 - 15), 0.02125),
+            100.0,
+            1e-10
+        );
+        let clean = bond.clean_price(SETTLEMENT, 0.018);
+        assert_approx_equal!(
+            bond.yield_to_maturity(SETTLEMENT, clean).unwrap(),
+            0.018,
+            1e-10
+        );
+
+        // The invoice price is the real dirty price times the index ratio.
+        assert_approx_equal!(bond.index_ratio(SETTLEMENT, &curve).unwrap(), 1.02, 1e-12);
+        assert_approx_equal!(
+            bond.invoice_price(SETTLEMENT, clean, &curve).unwrap(),
+            (clean + bond.accrued_interest(SETTLEMENT)) * 1.02,
+            1e-10
+        );
+    }
+
+    #[test]
+    fn test_nominal_cashflows_and_deflation_floor() {
+        let bond = tips();
+        let discount = YieldCurve::from_dates_and_rates(
+            &[date!(2024 - 01 - 01), date!(2030 - 01 - 01)],
+            &[0.042, 0.04],
+        );
+
+        // With a constant index the nominal and real cash flows agree.
+        let flat = curve(307.5, 0.0);
+        assert_approx_equal!(
+            bond.nominal_dirty_price(SETTLEMENT, &flat, &discount)
+                .unwrap(),
+            bond.dirty_price_with_spread(SETTLEMENT, &discount, 0.0),
+            1e-10
+        );
+
+        // With inflation the indexed cash flows grow.
+        let inflation = curve(307.5, 0.025);
+        let flows = bond.nominal_cashflows(SETTLEMENT, &inflation).unwrap();
+        assert_eq!(flows.len(), 10);
+        assert!(flows[0].1 > 1.0625 && flows[1].1 > flows[0].1);
+
+        // With deflation the principal is floored at par, but not the coupons.
+        let deflation = curve(307.5, -0.02);
+        let (_, last) = *bond
+            .nominal_cashflows(SETTLEMENT, &deflation)
+            .unwrap()
+            .last()
+            .unwrap();
+        let ratio = bond.index_ratio(date!(2029 - 01 - 15), &deflation).unwrap();
+        assert!(ratio < 1.0);
+        assert_approx_equal!(last, 100.0 + 1.0625 * ratio, 1e-10);
+
+        let unfloored = tips().with_deflation_floor(false);
+        let (_, last) = *unfloored
+            .nominal_cashflows(SETTLEMENT, &deflation)
+            .unwrap()
+            .last()
+            .unwrap();
+        assert_approx_equal!(last, 101.0625 * ratio, 1e-10);
+    }
+}
@@ -0,0 +1,24 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Price indices, with their observation lag and interpolation.
+pub mod inflation_index;
+pub use inflation_index::*;
+
+/// Inflation-linked bonds with TIPS-style indexation.
+pub mod inflation_linked_bond;
+pub use inflation_linked_bond::*;
+
+/// Zero-coupon inflation swaps.
+pub mod zero_coupon_inflation_swap;
+pub use zero_coupon_inflation_swap::*;
+
+/// Zero inflation curves, bootstrapped from zero-coupon inflation swaps.
+pub mod zero_inflation_curve;
+pub use zero_inflation_curve::*;
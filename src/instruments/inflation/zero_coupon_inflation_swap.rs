@@ -0,0 +1,209 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Zero-coupon inflation swaps.
+//!
+//! Both legs are paid once, at maturity: the inflation leg pays
+//! $N \left( I(T) / I(T_0) - 1 \right)$ and the fixed leg pays
+//! $N \left( (1 + K)^\tau - 1 \right)$, where $I$ is the reference index
+//! (with the index's lag and interpolation) and $\tau$ is the year fraction
+//! from start to maturity, a whole number of years under 30/360 by default.
+
+use super::zero_inflation_curve::ZeroInflationCurve;
+use crate::data::Curve;
+use crate::error::RustQuantError;
+use crate::instruments::swaps::SwapDirection;
+use crate::time::DayCountConvention;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Zero-coupon inflation swap.
+#[derive(Debug, Clone, Copy)]
+pub struct ZeroCouponInflationSwap {
+    /// Payer (receives inflation) or receiver of the fixed leg.
+    pub direction: SwapDirection,
+
+    /// Notional of both legs.
+    pub notional: f64,
+
+    /// Annually compounded fixed rate.
+    pub fixed_rate: f64,
+
+    /// Start date, whose reference index is the base of the inflation leg.
+    pub start_date: Date,
+
+    /// Maturity and payment date.
+    pub maturity_date: Date,
+
+    /// Day count convention of the fixed leg's compounding period.
+    pub day_count_convention: DayCountConvention,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl ZeroCouponInflationSwap {
+    /// New zero-coupon inflation swap, compounding the fixed rate under
+    /// 30/360.
+    #[must_use]
+    pub fn new(
+        direction: SwapDirection,
+        notional: f64,
+        fixed_rate: f64,
+        start_date: Date,
+        maturity_date: Date,
+    ) -> Self {
+        Self {
+            direction,
+            notional,
+            fixed_rate,
+            start_date,
+            maturity_date,
+            day_count_convention: DayCountConvention::Thirty_360_ISDA,
+        }
+    }
+
+    /// Set the day count convention of the fixed leg.
+    #[must_use]
+    pub fn with_day_count_convention(mut self, day_count_convention: DayCountConvention) -> Self {
+        self.day_count_convention = day_count_convention;
+        self
+    }
+
+    /// Year fraction from start to maturity.
+    #[must_use]
+    pub fn year_fraction(&self) -> f64 {
+        self.day_count_convention
+            .day_count_factor(self.start_date, self.maturity_date)
+    }
+
+    /// Amount paid by the fixed leg at maturity.
+    #[must_use]
+    pub fn fixed_leg_amount(&self) -> f64 {
+        self.notional * ((1.0 + self.fixed_rate).powf(self.year_fraction()) - 1.0)
+    }
+
+    /// Amount paid by the inflation leg at maturity, projected by the curve
+    /// where the index has not been published.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a past fixing it needs has not
+    ///   been published.
+    pub fn inflation_leg_amount(&self, curve: &ZeroInflationCurve) -> Result<f64, RustQuantError> {
+        let start = curve.reference_index(self.start_date)?;
+        let end = curve.reference_index(self.maturity_date)?;
+
+        Ok(self.notional * (end / start - 1.0))
+    }
+
+    /// Net present value for the holder, or nothing once paid.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a past fixing it needs has not
+    ///   been published.
+    pub fn npv<C: Curve>(
+        &self,
+        valuation_date: Date,
+        inflation: &ZeroInflationCurve,
+        discount: &C,
+    ) -> Result<f64, RustQuantError> {
+        if self.maturity_date <= valuation_date {
+            return Ok(0.0);
+        }
+
+        let df =
+            discount.discount_factor(self.maturity_date) / discount.discount_factor(valuation_date);
+        let net = self.fixed_leg_amount() - self.inflation_leg_amount(inflation)?;
+
+        Ok(self.direction.fixed_sign() * net * df)
+    }
+
+    /// Fixed rate at which the swap is worth nothing, i.e. the zero-coupon
+    /// breakeven inflation rate.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a past fixing it needs has not
+    ///   been published.
+    pub fn fair_rate(&self, curve: &ZeroInflationCurve) -> Result<f64, RustQuantError> {
+        let ratio =
+            curve.reference_index(self.maturity_date)? / curve.reference_index(self.start_date)?;
+
+        Ok(ratio.powf(1.0 / self.year_fraction()) - 1.0)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_zero_coupon_inflation_swap {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use crate::instruments::inflation::{IndexInterpolation, InflationIndex};
+    use time::macros::date;
+
+    const TODAY: Date = date!(2024 - 03 - 01);
+
+    #[test]
+    fn test_zero_coupon_inflation_swap() {
+        let hicp = InflationIndex::new("HICPxT", 3, IndexInterpolation::Flat)
+            .with_fixing(date!(2023 - 12 - 01), 123.32);
+        let curve = ZeroInflationCurve::new(
+            hicp,
+            TODAY,
+            vec![date!(2026 - 12 - 01), date!(2033 - 12 - 01)],
+            vec![0.022, 0.021],
+        )
+        .unwrap();
+        let discount = YieldCurve::from_dates_and_rates(
+            &[TODAY, date!(2029 - 03 - 01), date!(2034 - 03 - 01)],
+            &[0.03, 0.028, 0.027],
+        );
+
+        let swap = ZeroCouponInflationSwap::new(
+            SwapDirection::Payer,
+            10_000_000.0,
+            0.021,
+            TODAY,
+            date!(2029 - 03 - 01),
+        );
+        assert_approx_equal!(swap.year_fraction(), 5.0, 1e-15);
+        assert_approx_equal!(
+            swap.fixed_leg_amount(),
+            10_000_000.0 * (1.021_f64.powi(5) - 1.0),
+            1e-6
+        );
+
+        // At the fair rate both legs pay the same.
+        let fair_rate = swap.fair_rate(&curve).unwrap();
+        let at_market = ZeroCouponInflationSwap {
+            fixed_rate: fair_rate,
+            ..swap
+        };
+        assert_approx_equal!(at_market.npv(TODAY, &curve, &discount).unwrap(), 0.0, 1e-6);
+
+        // Paying fixed below the breakeven gains, and the receiver loses as much.
+        assert!(fair_rate > 0.021);
+        let payer = swap.npv(TODAY, &curve, &discount).unwrap();
+        let receiver = ZeroCouponInflationSwap {
+            direction: SwapDirection::Receiver,
+            ..swap
+        }
+        .npv(TODAY, &curve, &discount)
+        .unwrap();
+        assert!(payer > 0.0);
+        assert_approx_equal!(payer + receiver, 0.0, 1e-8);
+    }
+}
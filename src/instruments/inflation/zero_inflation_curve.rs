@@ -0,0 +1,276 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Zero inflation curves: projected levels of a price index, from the
+//! zero-coupon inflation rates to each observation date.
+//!
+//! The curve starts from the reference index $I_0$ of the valuation date,
+//! observed on the base date $t_0$ (the valuation date less the lag), and
+//! projects the index level observed on a later date $t$ as
+//!
+//! $$
+//! I(t) = I_0 \left( 1 + z(t) \right)^{\tau(t_0, t)}
+//! $$
+//!
+//! with $\tau$ in years under Actual/365 Fixed, and the zero inflation rate
+//! $z$ linear between the nodes and flat outside them.
+
+use super::inflation_index::InflationIndex;
+use super::zero_coupon_inflation_swap::ZeroCouponInflationSwap;
+use crate::error::RustQuantError;
+use crate::instruments::credit::hazard_rate_curve::year_fraction;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Zero inflation curve of a price index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZeroInflationCurve {
+    /// The index, with its published fixings.
+    pub index: InflationIndex,
+
+    /// Observation date of the valuation date.
+    pub base_date: Date,
+
+    /// Reference index of the valuation date.
+    pub base_index: f64,
+
+    /// Observation dates of the nodes, strictly increasing after the base
+    /// date.
+    pub dates: Vec<Date>,
+
+    /// Zero inflation rate to each node.
+    pub zero_rates: Vec<f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl ZeroInflationCurve {
+    /// New zero inflation curve at the valuation date, from the zero
+    /// inflation rates to the given observation dates.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if the reference index of the
+    ///   valuation date has not been published.
+    /// - `RustQuantError::InvalidArgument` if there are no nodes, a different
+    ///   number of dates and rates, or dates that are not strictly increasing
+    ///   after the base date.
+    pub fn new(
+        index: InflationIndex,
+        valuation_date: Date,
+        dates: Vec<Date>,
+        zero_rates: Vec<f64>,
+    ) -> Result<Self, RustQuantError> {
+        if dates.is_empty() || dates.len() != zero_rates.len() {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "a zero inflation curve needs one rate per node date, got {} dates and {} rates",
+                dates.len(),
+                zero_rates.len()
+            )));
+        }
+
+        let base_date = index.observation_date(valuation_date);
+
+        if dates[0] <= base_date || dates.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(RustQuantError::InvalidArgument(
+                "the node dates must be strictly increasing after the base date".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            base_index: index.reference_index(valuation_date)?,
+            index,
+            base_date,
+            dates,
+            zero_rates,
+        })
+    }
+
+    /// Bootstrap a zero inflation curve from zero-coupon inflation swaps
+    /// quoted at par (each with its quoted rate as its fixed rate), with one
+    /// node at the observation date of each maturity.
+    ///
+    /// The index level at each node is the one that makes its swap worth
+    /// nothing, $I(T) = I(T_0) (1 + K)^\tau$, so the quotes are repriced
+    /// exactly.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there are no quotes, a quote
+    ///   starts after the valuation date, or two quotes share an observation
+    ///   date.
+    /// - `RustQuantError::MissingInput` if a fixing needed for the base or
+    ///   start index has not been published.
+    pub fn bootstrap(
+        index: InflationIndex,
+        valuation_date: Date,
+        quotes: &[ZeroCouponInflationSwap],
+    ) -> Result<Self, RustQuantError> {
+        let mut quotes: Vec<&ZeroCouponInflationSwap> = quotes.iter().collect();
+        quotes.sort_by_key(|quote| quote.maturity_date);
+
+        let base_date = index.observation_date(valuation_date);
+        let base_index = index.reference_index(valuation_date)?;
+
+        let mut dates = Vec::with_capacity(quotes.len());
+        let mut zero_rates = Vec::with_capacity(quotes.len());
+
+        for quote in quotes {
+            if quote.start_date > valuation_date {
+                return Err(RustQuantError::InvalidArgument(format!(
+                    "the zero-coupon inflation swap starting on {} is forward starting",
+                    quote.start_date
+                )));
+            }
+
+            let date = index.observation_date(quote.maturity_date);
+            let level = index.reference_index(quote.start_date)?
+                * (1.0 + quote.fixed_rate).powf(quote.year_fraction());
+
+            dates.push(date);
+            zero_rates.push((level / base_index).powf(1.0 / year_fraction(base_date, date)) - 1.0);
+        }
+
+        Self::new(index, valuation_date, dates, zero_rates)
+    }
+
+    /// Zero inflation rate to an observation date.
+    #[must_use]
+    pub fn zero_rate(&self, date: Date) -> f64 {
+        let i = self.dates.partition_point(|node| *node < date);
+
+        if i == 0 {
+            return self.zero_rates[0];
+        }
+        if i == self.dates.len() {
+            return self.zero_rates[i - 1];
+        }
+
+        let t = year_fraction(self.dates[i - 1], date);
+        let dt = year_fraction(self.dates[i - 1], self.dates[i]);
+
+        self.zero_rates[i - 1] + t / dt * (self.zero_rates[i] - self.zero_rates[i - 1])
+    }
+
+    /// Projected index level observed on `date`.
+    #[must_use]
+    pub fn index_level(&self, date: Date) -> f64 {
+        self.base_index * (1.0 + self.zero_rate(date)).powf(year_fraction(self.base_date, date))
+    }
+
+    /// Reference index of a date: from the published fixings when known,
+    /// projected by the curve otherwise.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a fixing observed on or before
+    ///   the base date has not been published.
+    pub fn reference_index(&self, date: Date) -> Result<f64, RustQuantError> {
+        match self.index.reference_index(date) {
+            Ok(fixing) => Ok(fixing),
+            Err(error) => {
+                let observation_date = self.index.observation_date(date);
+
+                if observation_date > self.base_date {
+                    Ok(self.index_level(observation_date))
+                } else {
+                    Err(error)
+                }
+            }
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_zero_inflation_curve {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::instruments::inflation::IndexInterpolation;
+    use crate::instruments::swaps::SwapDirection;
+    use crate::time::add_months;
+    use time::macros::date;
+
+    const TODAY: Date = date!(2024 - 03 - 15);
+
+    fn cpi() -> InflationIndex {
+        InflationIndex::new("CPI-U", 3, IndexInterpolation::Linear)
+            .with_fixings(date!(2023 - 11 - 01), &[307.051, 306.746, 308.417])
+    }
+
+    #[test]
+    fn test_bootstrap_reprices_quotes() {
+        let quotes: Vec<ZeroCouponInflationSwap> = [(1, 0.0245), (2, 0.0240), (5, 0.0236)]
+            .into_iter()
+            .map(|(years, rate)| {
+                ZeroCouponInflationSwap::new(
+                    SwapDirection::Payer,
+                    10_000_000.0,
+                    rate,
+                    TODAY,
+                    add_months(TODAY, 12 * years),
+                )
+            })
+            .collect();
+
+        let curve = ZeroInflationCurve::bootstrap(cpi(), TODAY, &quotes).unwrap();
+
+        // 15 March 2024 observes 15 December 2023, 14/31 of the way from
+        // December 2023 to January 2024.
+        assert_eq!(curve.base_date, date!(2023 - 12 - 15));
+        assert_approx_equal!(
+            curve.base_index,
+            306.746 + 14.0 / 31.0 * (308.417 - 306.746),
+            1e-12
+        );
+
+        for quote in &quotes {
+            assert_approx_equal!(quote.fair_rate(&curve).unwrap(), quote.fixed_rate, 1e-12);
+        }
+
+        // Published fixings take precedence over the curve.
+        assert_approx_equal!(
+            curve.reference_index(date!(2024 - 02 - 01)).unwrap(),
+            307.051,
+            1e-12
+        );
+        assert!(curve.reference_index(date!(2027 - 06 - 01)).unwrap() > curve.base_index);
+    }
+
+    #[test]
+    fn test_zero_rate_interpolation() {
+        let curve = ZeroInflationCurve::new(
+            cpi(),
+            TODAY,
+            vec![date!(2024 - 12 - 15), date!(2026 - 12 - 15)],
+            vec![0.03, 0.02],
+        )
+        .unwrap();
+
+        assert_approx_equal!(curve.zero_rate(date!(2024 - 06 - 15)), 0.03, 1e-15);
+        assert_approx_equal!(curve.zero_rate(date!(2030 - 01 - 01)), 0.02, 1e-15);
+
+        let t = year_fraction(date!(2024 - 12 - 15), date!(2025 - 12 - 15));
+        let dt = year_fraction(date!(2024 - 12 - 15), date!(2026 - 12 - 15));
+        assert_approx_equal!(
+            curve.zero_rate(date!(2025 - 12 - 15)),
+            0.03 - 0.01 * t / dt,
+            1e-15
+        );
+
+        assert!(
+            ZeroInflationCurve::new(cpi(), TODAY, vec![date!(2023 - 12 - 01)], vec![0.02]).is_err()
+        );
+    }
+}
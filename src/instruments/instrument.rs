@@ -7,6 +7,7 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use crate::time::DayCountConvention;
 use time::Date;
 
 /// Instrument trait
@@ -31,6 +32,65 @@ pub trait Instrument {
     fn instrument_type(&self) -> &'static str;
 }
 
+/// Instruments with a maturity (or expiration) date.
+///
+/// The time to maturity is measured from the evaluation date with a
+/// chosen day count convention, rather than as a naive `days / 365`.
+pub trait Maturity {
+    /// Returns the date from which the time to maturity is measured.
+    fn evaluation_date(&self) -> Date;
+
+    /// Returns the maturity (or expiration) date.
+    fn maturity_date(&self) -> Date;
+
+    /// Returns the instrument's own day count convention.
+    fn day_count_convention(&self) -> DayCountConvention;
+
+    /// Returns the time to maturity (in years) under the given day count convention.
+    fn time_to_maturity(&self, day_counter: DayCountConvention) -> f64 {
+        day_counter.day_count_factor(self.evaluation_date(), self.maturity_date())
+    }
+
+    /// Returns the time to maturity (in years) under the instrument's own
+    /// day count convention.
+    fn year_fraction(&self) -> f64 {
+        self.time_to_maturity(self.day_count_convention())
+    }
+}
+
+/// Implements [`Maturity`] for option types with an optional
+/// `evaluation_date` (defaulting to today), an `expiration_date`,
+/// and a `day_count_convention`.
+macro_rules! impl_maturity {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl Maturity for $t {
+                fn evaluation_date(&self) -> Date {
+                    self.evaluation_date.unwrap_or(crate::time::today())
+                }
+
+                fn maturity_date(&self) -> Date {
+                    self.expiration_date
+                }
+
+                fn day_count_convention(&self) -> DayCountConvention {
+                    self.day_count_convention
+                }
+            }
+        )+
+    };
+}
+
+impl_maturity!(
+    crate::instruments::options::AsianOption,
+    crate::instruments::options::Bachelier,
+    crate::instruments::options::BlackScholesMerton,
+    crate::instruments::options::finite_difference_pricer::FiniteDifferencePricer,
+    crate::instruments::options::Merton1976,
+    crate::instruments::options::ModifiedBachelier,
+    crate::instruments::options::PowerOption,
+);
+
 /// Price structure.
 pub struct Price {
     /// Price of the instrument.
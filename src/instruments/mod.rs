@@ -73,6 +73,10 @@
 pub mod instrument;
 pub use instrument::*;
 
+/// Output of numerical pricers.
+pub mod pricing_result;
+pub use pricing_result::*;
+
 /// Bond pricing models.
 pub mod bonds;
 pub use bonds::*;
@@ -81,10 +85,26 @@ pub use bonds::*;
 pub mod options;
 pub use options::*;
 
+/// Interest rate swaps, caps, floors and swaptions.
+pub mod swaps;
+pub use swaps::*;
+
+/// Credit default swaps and hazard rate curves.
+pub mod credit;
+pub use credit::*;
+
+/// Inflation indices, curves, swaps and inflation-linked bonds.
+pub mod inflation;
+pub use inflation::*;
+
 /// FX instruments.
 pub mod fx;
 pub use fx::*;
 
+/// Commodity instruments.
+pub mod commodities;
+pub use commodities::*;
+
 /// Equity instruments.
 pub mod equities;
 pub use equities::*;
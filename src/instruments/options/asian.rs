@@ -8,8 +8,9 @@
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 use crate::{
+    instruments::Maturity,
     math::distributions::{gaussian::Gaussian, Distribution},
-    time::{today, DayCountConvention},
+    time::DayCountConvention,
 };
 use time::Date;
 
@@ -67,6 +68,11 @@ pub struct AsianOption {
 
     /// `expiry_date` - Expiry date.
     pub expiration_date: Date,
+
+    /// Day count convention of the time to expiry
+    /// (optional, defaults to `Actual/Actual ISDA`).
+    #[builder(default)]
+    pub day_count_convention: DayCountConvention,
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -93,6 +99,7 @@ impl AsianOption {
             dividend_rate,
             evaluation_date,
             expiration_date,
+            day_count_convention: DayCountConvention::Actual_Actual_ISDA,
         }
     }
 
@@ -107,10 +114,7 @@ impl AsianOption {
         let q = self.dividend_rate;
 
         // Compute time to maturity.
-        let T = DayCountConvention::default().day_count_factor(
-            self.evaluation_date.unwrap_or(today()),
-            self.expiration_date,
-        );
+        let T = self.year_fraction();
 
         let v_a = v / 3_f64.sqrt();
         let b = r - q;
@@ -126,6 +130,45 @@ impl AsianOption {
 
         (c, p)
     }
+
+    /// Geometric Discrete Average-Rate Price, averaging over `n_fixings`
+    /// equally spaced fixings, the last of which is at expiry.
+    ///
+    /// The geometric average is log-normal, with
+    /// $\mu = \ln S + (b - \sigma^2 / 2) T (n + 1) / (2n)$ and
+    /// $\sigma_G^2 = \sigma^2 T (n + 1)(2n + 1) / (6 n^2)$,
+    /// which tend to the continuous average as $n \to \infty$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_fixings` is zero.
+    #[must_use]
+    pub fn price_geometric_discrete(&self, n_fixings: usize) -> (f64, f64) {
+        assert!(n_fixings > 0, "There must be a fixing.");
+
+        let S = self.initial_price;
+        let K = self.strike_price;
+        let r = self.risk_free_rate;
+        let v = self.volatility;
+        let q = self.dividend_rate;
+
+        let T = self.year_fraction();
+        let n = n_fixings as f64;
+
+        let mu = S.ln() + (r - q - 0.5 * v * v) * T * (n + 1.0) / (2.0 * n);
+        let v_g = v * (T * (n + 1.0) * (2.0 * n + 1.0) / (6.0 * n * n)).sqrt();
+
+        let d1 = (mu - K.ln() + v_g * v_g) / v_g;
+        let d2 = d1 - v_g;
+
+        let N = Gaussian::default();
+        let F = (mu + 0.5 * v_g * v_g).exp();
+
+        let c = (-r * T).exp() * (F * N.cdf(d1) - K * N.cdf(d2));
+        let p = (-r * T).exp() * (K * N.cdf(-d2) - F * N.cdf(-d1));
+
+        (c, p)
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -134,6 +177,7 @@ impl AsianOption {
 
 #[cfg(test)]
 mod tests {
+    use crate::time::today;
     use time::Duration;
 
     use super::*;
@@ -150,6 +194,7 @@ mod tests {
             volatility: 0.2,
             evaluation_date: None,
             expiration_date: expiry_date,
+            day_count_convention: DayCountConvention::default(),
             dividend_rate: -0.03,
         };
 
@@ -158,4 +203,36 @@ mod tests {
         // Value from Haug's book.
         assert_approx_equal!(prices.1, 4.6922, 0.0001);
     }
+
+    #[test]
+    fn test_asian_geometric_discrete() {
+        let asian = AsianOption::new(
+            80.0,
+            85.0,
+            0.05,
+            0.2,
+            -0.03,
+            Some(time::macros::date!(2023 - 01 - 01)),
+            time::macros::date!(2024 - 01 - 01),
+        );
+
+        let continuous = asian.price_geometric_average();
+        let daily = asian.price_geometric_discrete(10_000);
+        let monthly = asian.price_geometric_discrete(12);
+
+        assert_approx_equal!(daily.0, continuous.0, 1e-3);
+        assert_approx_equal!(daily.1, continuous.1, 1e-3);
+
+        // Fewer fixings put more weight on the later, more volatile prices.
+        assert!(monthly.0 > continuous.0);
+
+        // Put-call parity on the forward of the geometric average.
+        let forward =
+            80.0 * f64::exp((0.08 - 0.02) * 13.0 / 24.0 + 0.5 * 0.04 * 13.0 * 25.0 / (6.0 * 144.0));
+        assert_approx_equal!(
+            monthly.0 - monthly.1,
+            f64::exp(-0.05) * (forward - 85.0),
+            1e-10
+        );
+    }
 }
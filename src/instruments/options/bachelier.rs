@@ -12,8 +12,9 @@
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 use crate::instruments::options::TypeFlag;
+use crate::instruments::Maturity;
 use crate::math::distributions::{Distribution, Gaussian};
-use crate::time::{today, DayCountConvention};
+use crate::time::DayCountConvention;
 use time::Date;
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -34,6 +35,9 @@ pub struct Bachelier {
     /// The options expiration date.
     pub expiration_date: Date,
 
+    /// Day count convention of the time to expiry.
+    pub day_count_convention: DayCountConvention,
+
     /// Call or put flag.
     pub option_type: TypeFlag,
 }
@@ -59,6 +63,11 @@ pub struct ModifiedBachelier {
     /// The options expiration date.
     pub expiration_date: Date,
 
+    /// Day count convention of the time to expiry
+    /// (optional, defaults to `Actual/Actual ISDA`).
+    #[builder(default)]
+    pub day_count_convention: DayCountConvention,
+
     /// Call or put flag.
     pub option_type: TypeFlag,
 }
@@ -84,6 +93,7 @@ impl Bachelier {
             volatility,
             evaluation_date,
             expiration_date,
+            day_count_convention: DayCountConvention::default(),
             option_type,
         }
     }
@@ -96,10 +106,7 @@ impl Bachelier {
         let v = self.volatility;
 
         // Compute time to maturity.
-        let T = DayCountConvention::default().day_count_factor(
-            self.evaluation_date.unwrap_or(today()),
-            self.expiration_date,
-        );
+        let T = self.year_fraction();
 
         let d1 = (S - K) / (v * T.sqrt());
 
@@ -134,6 +141,7 @@ impl ModifiedBachelier {
             dividend_yield,
             evaluation_date,
             expiration_date,
+            day_count_convention: DayCountConvention::Actual_Actual_ISDA,
             option_type,
         }
     }
@@ -147,10 +155,7 @@ impl ModifiedBachelier {
         let r = self.risk_free_rate;
 
         // Compute time to maturity.
-        let T = DayCountConvention::default().day_count_factor(
-            self.evaluation_date.unwrap_or(today()),
-            self.expiration_date,
-        );
+        let T = self.year_fraction();
 
         let d1 = (S - K) / (v * T.sqrt());
 
@@ -170,7 +175,7 @@ impl ModifiedBachelier {
 #[cfg(test)]
 mod tests_bachelier {
     use super::*;
-    use crate::{assert_approx_equal, RUSTQUANT_EPSILON};
+    use crate::{assert_approx_equal, time::today};
     use time::Duration;
 
     #[test]
@@ -7,6 +7,9 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use crate::error::RustQuantError;
+use crate::instruments::options::{ExerciseFlag, FiniteDifferenceEngine, TypeFlag};
+use crate::instruments::PricingResult;
 use crate::math::distributions::{gaussian::Gaussian, Distribution};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -196,6 +199,208 @@ impl BarrierOption {
             }
         }
     }
+
+    /// Cox-Ross-Rubinstein binomial tree price of the barrier option,
+    /// with the barrier monitored at each step of the tree.
+    ///
+    /// A CRR tree whose barrier falls between two node layers misprices the
+    /// option (and converges erratically in the number of steps), so the
+    /// number of steps is increased as in Boyle and Lau (1994): the smallest
+    /// $n \geq$ `n_steps` for which a layer of nodes lies on, or just beyond,
+    /// the barrier, $n = \lfloor m^2 \sigma^2 T / \ln(S / H)^2 \rfloor$ for an
+    /// integer $m$. The number of steps used is returned as the iterations.
+    ///
+    /// Knock-out values are set to zero at the nodes beyond the barrier, and
+    /// knock-in options follow from in-out parity with the European option on
+    /// the same tree. The rebate is not included.
+    ///
+    /// Warnings are raised if the rebate is non-zero, or if the spot is so
+    /// close to the barrier that aligning a layer of nodes with it would need
+    /// more than four times `n_steps` steps. In that case the tree is built
+    /// with `n_steps` steps and the price is not reliable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_steps` is zero.
+    #[must_use]
+    pub fn price_binomial(&self, type_flag: BarrierType, n_steps: usize) -> PricingResult {
+        assert!(n_steps > 0, "The tree must have at least one step.");
+
+        let S = self.initial_price;
+        let X = self.strike_price;
+        let H = self.barrier;
+        let v = self.volatility;
+        let r = self.risk_free_rate;
+        let T = self.time_to_expiry;
+
+        // Boyle-Lau: put the m-th node layer from the spot on the barrier.
+        let distance = (S / H).ln().abs();
+        let layers = (distance / (v * (T / n_steps as f64).sqrt())).ceil();
+        let aligned = (layers * layers * v * v * T / (distance * distance)).floor();
+
+        let near_barrier = !(aligned.is_finite() && aligned <= 4.0 * n_steps as f64);
+        let n = if near_barrier {
+            n_steps
+        } else {
+            (aligned as usize).max(n_steps)
+        };
+
+        let dt = T / n as f64;
+        let u = (v * dt.sqrt()).exp();
+        let d = 1.0 / u;
+        let p = (((r - self.dividend_yield) * dt).exp() - d) / (u - d);
+        let Df = (-r * dt).exp();
+
+        let (is_call, is_up, is_in) = match type_flag {
+            BarrierType::CUI => (true, true, true),
+            BarrierType::CDI => (true, false, true),
+            BarrierType::CUO => (true, true, false),
+            BarrierType::CDO => (true, false, false),
+            BarrierType::PUI => (false, true, true),
+            BarrierType::PDI => (false, false, true),
+            BarrierType::PUO => (false, true, false),
+            BarrierType::PDO => (false, false, false),
+        };
+
+        let spot = |j: usize, i: usize| S * u.powi(i as i32) * d.powi((j - i) as i32);
+        let breached = |s: f64| if is_up { s >= H } else { s <= H };
+        let payoff = |s: f64| {
+            if is_call {
+                (s - X).max(0.0)
+            } else {
+                (X - s).max(0.0)
+            }
+        };
+
+        // Backward induction of the knock-out and the European option together.
+        let mut knock_out: Vec<f64> = (0..=n)
+            .map(|i| spot(n, i))
+            .map(|s| if breached(s) { 0.0 } else { payoff(s) })
+            .collect();
+        let mut european: Vec<f64> = (0..=n).map(|i| payoff(spot(n, i))).collect();
+
+        for j in (0..n).rev() {
+            for i in 0..=j {
+                european[i] = Df * (p * european[i + 1] + (1.0 - p) * european[i]);
+                knock_out[i] = if breached(spot(j, i)) {
+                    0.0
+                } else {
+                    Df * (p * knock_out[i + 1] + (1.0 - p) * knock_out[i])
+                };
+            }
+        }
+
+        let value = if is_in {
+            european[0] - knock_out[0]
+        } else {
+            knock_out[0]
+        };
+
+        let mut result = PricingResult::new(value).with_iterations(n);
+
+        if near_barrier {
+            result.warn(format!(
+                "near-barrier instability: the spot {S} is too close to the barrier {H} \
+                to align the tree with it in {n_steps} steps, so the price is not reliable"
+            ));
+        }
+        if self.rebate != 0.0 {
+            result.warn("the rebate is not included in the binomial barrier price");
+        }
+
+        result
+    }
+
+    /// Closed-form price of the option with the barrier monitored at
+    /// `n_monitoring` equally spaced dates, by the continuity correction of
+    /// Broadie, Glasserman and Kou (1997): the continuous price with the
+    /// barrier moved away from the spot by a factor $e^{\beta \sigma \sqrt{T / m}}$,
+    /// where $\beta = -\zeta(1/2) / \sqrt{2 \pi} \approx 0.5826$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_monitoring` is zero.
+    #[must_use]
+    pub fn price_discrete(&self, type_flag: BarrierType, n_monitoring: usize) -> f64 {
+        assert!(n_monitoring > 0, "There must be a monitoring date.");
+
+        const BETA: f64 = 0.582_597_157_939_010_6;
+
+        let shift = BETA * self.volatility * (self.time_to_expiry / n_monitoring as f64).sqrt();
+        let is_up = matches!(
+            type_flag,
+            BarrierType::CUI | BarrierType::CUO | BarrierType::PUI | BarrierType::PUO
+        );
+
+        let barrier = if is_up {
+            self.barrier * shift.exp()
+        } else {
+            self.barrier * (-shift).exp()
+        };
+
+        Self { barrier, ..*self }.price(type_flag)
+    }
+
+    /// Finite difference (Crank-Nicolson) price on a grid of `price_steps`
+    /// spot intervals and `time_steps` time steps.
+    ///
+    /// A knock-out option is priced with the edge of the grid on the
+    /// barrier, and a knock-in option as the vanilla less the knock-out.
+    /// A warning is raised if the rebate is non-zero.
+    ///
+    /// # Errors
+    ///
+    /// As for [`FiniteDifferenceEngine::price`], e.g. if the spot is already
+    /// beyond the barrier.
+    pub fn price_finite_difference(
+        &self,
+        type_flag: BarrierType,
+        price_steps: usize,
+        time_steps: usize,
+    ) -> Result<PricingResult, RustQuantError> {
+        let (option_type, is_up, is_in) = match type_flag {
+            BarrierType::CUI => (TypeFlag::Call, true, true),
+            BarrierType::CDI => (TypeFlag::Call, false, true),
+            BarrierType::CUO => (TypeFlag::Call, true, false),
+            BarrierType::CDO => (TypeFlag::Call, false, false),
+            BarrierType::PUI => (TypeFlag::Put, true, true),
+            BarrierType::PDI => (TypeFlag::Put, false, true),
+            BarrierType::PUO => (TypeFlag::Put, true, false),
+            BarrierType::PDO => (TypeFlag::Put, false, false),
+        };
+
+        let vanilla = FiniteDifferenceEngine::new(
+            self.initial_price,
+            self.strike_price,
+            self.risk_free_rate,
+            self.dividend_yield,
+            self.volatility,
+            self.time_to_expiry,
+            option_type,
+            ExerciseFlag::European,
+        )
+        .with_grid(price_steps, time_steps);
+
+        let knock_out = if is_up {
+            vanilla.clone().with_barriers(None, Some(self.barrier))
+        } else {
+            vanilla.clone().with_barriers(Some(self.barrier), None)
+        };
+
+        let mut result = knock_out.price()?;
+
+        if is_in {
+            let vanilla = vanilla.price()?;
+
+            result.value = vanilla.value - result.value;
+            result.warnings.extend(vanilla.warnings);
+        }
+        if self.rebate != 0.0 {
+            result.warn("the rebate is not included in the finite difference barrier price");
+        }
+
+        Ok(result)
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -264,6 +469,67 @@ mod tests {
         let _ = S_ABOVE_H.price(BarrierType::PUO);
     }
 
+    #[test]
+    fn test_binomial_barrier_tree() {
+        let cdo = S_ABOVE_H.price_binomial(BarrierType::CDO, 500);
+        let cdi = S_ABOVE_H.price_binomial(BarrierType::CDI, 500);
+
+        // The barrier is put on a node layer, so more steps may be used.
+        assert!(cdo.iterations.unwrap() >= 500);
+        assert!(cdo.standard_error.is_none());
+        assert!(!cdo.has_warnings());
+
+        assert_approx_equal!(cdo.value, S_ABOVE_H.price(BarrierType::CDO), 0.02);
+        assert_approx_equal!(cdi.value, S_ABOVE_H.price(BarrierType::CDI), 0.02);
+
+        // Up-and-out from below as well.
+        let puo = S_BELOW_H.price_binomial(BarrierType::PUO, 500);
+        assert_approx_equal!(puo.value, S_BELOW_H.price(BarrierType::PUO), 0.02);
+    }
+
+    #[test]
+    fn test_binomial_barrier_near_barrier_warning() {
+        let near = BarrierOption {
+            initial_price: 105.5,
+            ..S_ABOVE_H
+        };
+
+        // Aligning a node layer with a barrier 0.5% away needs about 1,800
+        // steps, far more than the 100 requested.
+        let result = near.price_binomial(BarrierType::CDO, 100);
+
+        assert_eq!(result.iterations, Some(100));
+        assert!(result.has_warnings());
+        assert!(result.warnings[0].contains("near-barrier instability"));
+
+        // With 1,000 steps requested the tree can be aligned with the barrier.
+        let result = near.price_binomial(BarrierType::CDO, 1_000);
+
+        assert!(!result.has_warnings());
+        assert_approx_equal!(result.value, near.price(BarrierType::CDO), 0.02);
+    }
+
+    #[test]
+    fn test_finite_difference_barrier() {
+        for type_flag in [BarrierType::CDO, BarrierType::CDI, BarrierType::PDO] {
+            let result = S_ABOVE_H
+                .price_finite_difference(type_flag, 400, 400)
+                .unwrap();
+
+            assert!(!result.has_warnings());
+            assert_approx_equal!(result.value, S_ABOVE_H.price(type_flag), 0.02);
+        }
+
+        // The spot is already below an up-and-out barrier.
+        let below = BarrierOption {
+            barrier: 100.0,
+            ..S_ABOVE_H
+        };
+        assert!(below
+            .price_finite_difference(BarrierType::CUO, 400, 400)
+            .is_err());
+    }
+
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Initial underlying price BELOW the barrier.
     //
@@ -9,6 +9,7 @@
 
 //! This module contains various 'binary', or 'digital', option types.
 
+use crate::instruments::options::{black_scholes_merton::generalised_black_scholes, TypeFlag};
 use crate::math::distributions::{gaussian::Gaussian, Distribution};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -110,6 +111,54 @@ impl CashOrNothingOption {
 
         (c, p)
     }
+
+    /// Cash-or-Nothing option pricer that accounts for the volatility smile.
+    ///
+    /// The digital call is replicated by a tight call spread centred on the
+    /// strike, with each call priced at its own volatility from `smile`
+    /// (a function from strike to volatility):
+    ///
+    /// $$
+    /// D \approx K \frac{C(X - h/2, \sigma(X - h/2)) - C(X + h/2, \sigma(X + h/2))}{h}
+    /// $$
+    ///
+    /// As the `spread_width` $h \to 0$ this converges to the flat-volatility
+    /// price less the skew correction $K \cdot \mathcal{V}(X) \cdot \sigma'(X)$.
+    /// The put follows from parity. The `volatility` field is not used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `spread_width` is not positive, or so wide that the lower
+    /// strike of the spread is not positive.
+    #[must_use]
+    pub fn digital_with_smile<F: Fn(f64) -> f64>(&self, smile: F, spread_width: f64) -> (f64, f64) {
+        let X = self.strike_price;
+        let K = self.payout_value;
+        let h = spread_width;
+
+        assert!(h > 0.0, "The spread width must be positive.");
+        assert!(
+            X - 0.5 * h > 0.0,
+            "The spread width must be less than twice the strike."
+        );
+
+        let call = |strike: f64| {
+            generalised_black_scholes(
+                self.initial_price,
+                strike,
+                self.risk_free_rate,
+                self.cost_of_carry,
+                smile(strike),
+                self.time_to_maturity,
+                TypeFlag::Call,
+            )
+        };
+
+        let c = K * (call(X - 0.5 * h) - call(X + 0.5 * h)) / h;
+        let p = K * (-self.risk_free_rate * self.time_to_maturity).exp() - c;
+
+        (c, p)
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -157,4 +206,58 @@ mod tests {
         // Value from Haug's book.
         assert_approx_equal!(prices.1, 2.671_045_684_461_347, RUSTQUANT_EPSILON);
     }
+
+    #[test]
+    fn test_digital_with_smile() {
+        let (S, X, r, b, T) = (100.0, 105.0, 0.05, 0.05, 0.5);
+
+        // Downward sloping skew.
+        let smile = |strike: f64| 0.25 - 0.002 * (strike - 100.0);
+        let skew = -0.002;
+
+        let option = CashOrNothingOption {
+            initial_price: S,
+            payout_value: 1.0,
+            strike_price: X,
+            risk_free_rate: r,
+            volatility: smile(X),
+            time_to_maturity: T,
+            cost_of_carry: b,
+        };
+
+        // Flat-volatility digital, plus the skew correction from the vega.
+        let v = smile(X);
+        let d1 = ((S / X).ln() + (b + 0.5 * v * v) * T) / (v * T.sqrt());
+        let vega = S * ((b - r) * T).exp() * Gaussian::default().pdf(d1) * T.sqrt();
+        let limit = option.price().0 - vega * skew;
+
+        let wide = option.digital_with_smile(smile, 1.0).0;
+        let tight = option.digital_with_smile(smile, 0.01).0;
+
+        assert!((tight - limit).abs() < (wide - limit).abs());
+        assert_approx_equal!(tight, limit, 1e-5);
+
+        // A negative skew makes the digital call more valuable.
+        assert!(tight > option.price().0);
+
+        // Parity.
+        let (c, p) = option.digital_with_smile(smile, 0.01);
+        assert_approx_equal!(c + p, (-r * T).exp(), 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "The spread width must be positive.")]
+    fn test_digital_with_smile_rejects_non_positive_spread() {
+        let option = CashOrNothingOption {
+            initial_price: 100.0,
+            payout_value: 1.0,
+            strike_price: 105.0,
+            risk_free_rate: 0.05,
+            volatility: 0.25,
+            time_to_maturity: 0.5,
+            cost_of_carry: 0.05,
+        };
+
+        let _ = option.digital_with_smile(|_| 0.25, 0.0);
+    }
 }
@@ -12,6 +12,7 @@
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 use super::{ExerciseFlag, TypeFlag};
+use crate::instruments::PricingResult;
 
 /// Struct containing the parameters to price an option via binomial tree method.
 #[allow(clippy::module_name_repetitions)]
@@ -41,6 +42,10 @@ impl BinomialOption {
     /// * `call_put_flag` - `TypeFlag`: either `Call` or `Put`.
     /// * `n` - Height of the binomial tree.
     ///
+    /// The result carries the number of tree steps, and a warning if the
+    /// risk-neutral probability is outside $(0, 1)$, i.e. the step is too
+    /// large for the carry and volatility and the tree admits arbitrage.
+    ///
     /// # Note:
     ///
     /// * `b = r - q` - The cost of carry.
@@ -51,7 +56,7 @@ impl BinomialOption {
         ame_eur_flag: ExerciseFlag,
         call_put_flag: TypeFlag,
         n: usize,
-    ) -> f64 {
+    ) -> PricingResult {
         let S = self.initial_price;
         let K = self.strike_price;
         let T = self.time_to_expiry;
@@ -115,7 +120,7 @@ impl BinomialOption {
         return_value[3] = (option_value[3] - option_value[0]) / (2.0 * dt) / 365.0;
         return_value[0] = option_value[0];
 
-        match output_flag {
+        let value = match output_flag {
             // Return the option value.
             "p" => return_value[0],
             // Return the Delta.
@@ -126,7 +131,18 @@ impl BinomialOption {
             "t" => return_value[3],
             // Capture edge cases.
             _ => panic!("Check OutputFlag. Should be one of: 'p', 'd', 'g', 't'."),
+        };
+
+        let mut result = PricingResult::new(value).with_iterations(n);
+
+        if !(0.0..=1.0).contains(&p) {
+            result.warn(format!(
+                "the risk-neutral probability {p} is outside [0, 1]: \
+                use more than {n} steps"
+            ));
         }
+
+        result
     }
 }
 
@@ -152,8 +168,13 @@ mod tests_binomial {
             volatility: 0.3,
         };
 
-        let c = BinOpt.price_CoxRossRubinstein("p", ExerciseFlag::American, TypeFlag::Call, 100);
-        let p = BinOpt.price_CoxRossRubinstein("p", ExerciseFlag::American, TypeFlag::Put, 100);
+        let call = BinOpt.price_CoxRossRubinstein("p", ExerciseFlag::American, TypeFlag::Call, 100);
+        let put = BinOpt.price_CoxRossRubinstein("p", ExerciseFlag::American, TypeFlag::Put, 100);
+
+        assert_eq!(call.iterations, Some(100));
+        assert!(!call.has_warnings());
+
+        let (c, p) = (call.value, put.value);
 
         let c_intrinsic = (100_f64 - 95_f64).max(0.0);
         let p_intrinsic = (95_f64 - 100_f64).max(0.0);
@@ -169,4 +190,25 @@ mod tests_binomial {
         // Very weak parity due to discrete time steps.
         assert_approx_equal!(parity, 0.0, 0.5);
     }
+
+    #[test]
+    fn test_CRRBinomial_arbitrage_warning() {
+        // Three steps over a year with a 30% carry and 10% volatility have
+        // an up-move smaller than the growth of the forward.
+        let BinOpt = BinomialOption {
+            initial_price: 100.0,
+            strike_price: 100.0,
+            time_to_expiry: 1.0,
+            risk_free_rate: 0.3,
+            dividend_yield: 0.0,
+            volatility: 0.1,
+        };
+
+        let result = BinOpt.price_CoxRossRubinstein("p", ExerciseFlag::European, TypeFlag::Call, 3);
+
+        assert!(result.has_warnings());
+        assert!(!BinOpt
+            .price_CoxRossRubinstein("p", ExerciseFlag::European, TypeFlag::Call, 1_000)
+            .has_warnings());
+    }
 }
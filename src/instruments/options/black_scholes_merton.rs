@@ -25,8 +25,10 @@
 // IMPORTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use crate::instruments::options::TypeFlag;
-use crate::instruments::Instrument;
+use crate::data::MarketData;
+use crate::error::RustQuantError;
+use crate::instruments::options::{DividendSchedule, TypeFlag};
+use crate::instruments::{Instrument, Maturity};
 use crate::math::distributions::{Distribution, Gaussian};
 use crate::time::{today, DayCountConvention};
 
@@ -69,6 +71,11 @@ pub struct BlackScholesMerton {
 
     /// Call or put flag.
     pub option_type: TypeFlag,
+
+    /// Day count convention of the time to expiry
+    /// (optional, defaults to `Actual/Actual ISDA`).
+    #[builder(default)]
+    pub day_count_convention: DayCountConvention,
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -121,9 +128,78 @@ impl BlackScholesMerton {
             evaluation_date,
             expiration_date,
             option_type,
+            day_count_convention: DayCountConvention::default(),
         }
     }
 
+    /// European option priced off a market data snapshot: the evaluation
+    /// date is the snapshot's valuation date, the underlying and volatility
+    /// are looked up at the strike and expiry, and the risk-free rate is the
+    /// continuously-compounded zero rate implied by the named curve's
+    /// discount factor from the valuation date to expiry.
+    ///
+    /// The cost of carry is set to the risk-free rate (no dividends);
+    /// override `cost_of_carry` for the other variants of the model.
+    /// All prices and Greeks of the result are then consistent with the
+    /// snapshot.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if the curve, spot, or volatility
+    ///   is missing from the snapshot.
+    /// - `RustQuantError::InvalidArgument` if the expiry is not after the
+    ///   valuation date.
+    pub fn from_market_data(
+        market_data: &MarketData,
+        curve_name: &str,
+        underlying_name: &str,
+        strike_price: f64,
+        expiration_date: Date,
+        option_type: TypeFlag,
+    ) -> Result<Self, RustQuantError> {
+        let evaluation_date = market_data.valuation_date;
+
+        if expiration_date <= evaluation_date {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "expiry {expiration_date} is not after the valuation date {evaluation_date}"
+            )));
+        }
+
+        let mut option = Self::new(
+            0.0,
+            market_data.spot(underlying_name)?,
+            strike_price,
+            market_data.volatility(expiration_date, strike_price)?,
+            0.0,
+            Some(evaluation_date),
+            expiration_date,
+            option_type,
+        );
+
+        let discount_factor = market_data.discount_factor(curve_name, expiration_date)?;
+        option.risk_free_rate = -discount_factor.ln() / option.year_fraction();
+        option.cost_of_carry = option.risk_free_rate;
+
+        Ok(option)
+    }
+
+    /// Stock option with the given dividends, under the escrowed dividend
+    /// model: the underlying price becomes the spot adjusted for the
+    /// discrete dividends paid before expiry, and the cost of carry becomes
+    /// `r - q` for the schedule's dividend yield `q`.
+    ///
+    /// The dividend times are year fractions from the evaluation date, and
+    /// the spot-based Greeks of the result are with respect to the adjusted
+    /// spot.
+    #[must_use]
+    pub fn with_dividend_schedule(mut self, schedule: &DividendSchedule) -> Self {
+        let T = self.year_fraction();
+
+        self.underlying_price =
+            schedule.adjusted_spot(self.underlying_price, self.risk_free_rate, T);
+        self.cost_of_carry = self.risk_free_rate - schedule.dividend_yield;
+        self
+    }
+
     /// Generalised Black-Scholes European Option Price.
     #[must_use]
     pub fn price(&self) -> f64 {
@@ -152,15 +228,6 @@ impl BlackScholesMerton {
         )
     }
 
-    /// Compute the year fraction between two dates.
-    #[must_use]
-    pub fn year_fraction(&self) -> f64 {
-        DayCountConvention::default().day_count_factor(
-            self.evaluation_date.unwrap_or(today()),
-            self.expiration_date,
-        )
-    }
-
     // Compute d1 and d2.
     #[must_use]
     fn d1_d2(&self) -> (f64, f64) {
@@ -438,6 +505,32 @@ impl BlackScholesMerton {
     }
 }
 
+/// Generalised Black-Scholes-Merton price of a European option, from the
+/// spot `S`, strike `X`, rate `r`, cost of carry `b`, volatility `v`, and
+/// time to expiry `T` (in years), for pricers that work with year fractions
+/// rather than dates. See the module documentation for the choices of `b`
+/// (e.g. `b = 0` with `S` the forward gives Black (1976)).
+#[must_use]
+pub(crate) fn generalised_black_scholes(
+    S: f64,
+    X: f64,
+    r: f64,
+    b: f64,
+    v: f64,
+    T: f64,
+    option_type: TypeFlag,
+) -> f64 {
+    let d1 = ((S / X).ln() + (b + 0.5 * v * v) * T) / (v * T.sqrt());
+    let d2 = d1 - v * T.sqrt();
+
+    let N = Gaussian::default();
+
+    match option_type {
+        TypeFlag::Call => S * ((b - r) * T).exp() * N.cdf(d1) - X * (-r * T).exp() * N.cdf(d2),
+        TypeFlag::Put => X * (-r * T).exp() * N.cdf(-d2) - S * ((b - r) * T).exp() * N.cdf(-d1),
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // UNIT TESTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -480,4 +573,176 @@ mod tests_black_scholes_merton {
         );
         assert_approx_equal!(bsm.price(), 2.456571166461579, RUSTQUANT_EPSILON);
     }
+
+    #[test]
+    fn test_dividend_schedule_put_call_parity() {
+        use time::macros::date;
+
+        let schedule = DividendSchedule::new()
+            .with_dividend_yield(0.01)
+            .with_cash_dividend(0.25, 1.0)
+            .with_cash_dividend(0.75, 1.0)
+            .with_proportional_dividend(0.5, 0.02);
+        let option = |option_type| {
+            BlackScholesMerton::new(
+                0.05,
+                100.0,
+                100.0,
+                0.25,
+                0.05,
+                Some(date!(2025 - 01 - 01)),
+                date!(2026 - 01 - 01),
+                option_type,
+            )
+            .with_dividend_schedule(&schedule)
+        };
+
+        let call = option(TypeFlag::Call);
+        let put = option(TypeFlag::Put);
+        assert_approx_equal!(call.year_fraction(), 1.0, RUSTQUANT_EPSILON);
+
+        // C - P = e^{-rT} (F - K), with the forward of the dividend schedule.
+        let forward = schedule.forward(100.0, 0.05, 1.0);
+        assert_approx_equal!(
+            call.price() - put.price(),
+            f64::exp(-0.05) * (forward - 100.0),
+            1e-10
+        );
+
+        // Without discrete dividends, this is Merton's model.
+        let merton = BlackScholesMerton::new(
+            0.04,
+            100.0,
+            100.0,
+            0.25,
+            0.05,
+            Some(date!(2025 - 01 - 01)),
+            date!(2026 - 01 - 01),
+            TypeFlag::Call,
+        );
+        assert_approx_equal!(
+            BlackScholesMerton::new(
+                0.05,
+                100.0,
+                100.0,
+                0.25,
+                0.05,
+                Some(date!(2025 - 01 - 01)),
+                date!(2026 - 01 - 01),
+                TypeFlag::Call,
+            )
+            .with_dividend_schedule(&DividendSchedule::new().with_dividend_yield(0.01))
+            .price(),
+            merton.price(),
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_time_to_maturity_day_count() {
+        // One calendar year spanning the 2024 leap day.
+        let mut bsm = BlackScholesMerton::new(
+            0.05,
+            100.0,
+            100.0,
+            0.2,
+            0.05,
+            Some(time::macros::date!(2024 - 01 - 01)),
+            time::macros::date!(2025 - 01 - 01),
+            TypeFlag::Call,
+        );
+
+        let act_act = bsm.time_to_maturity(DayCountConvention::Actual_Actual_ISDA);
+        let act_365 = bsm.time_to_maturity(DayCountConvention::Actual_365_Fixed);
+
+        assert_approx_equal!(act_act, 1.0, RUSTQUANT_EPSILON);
+        assert_approx_equal!(act_365, 366.0 / 365.0, RUSTQUANT_EPSILON);
+
+        // The option's own convention is used for pricing.
+        bsm.day_count_convention = DayCountConvention::Actual_Actual_ISDA;
+        let (price_act_act, theta) = (bsm.price(), bsm.theta());
+        assert_approx_equal!(bsm.year_fraction(), act_act, RUSTQUANT_EPSILON);
+
+        bsm.day_count_convention = DayCountConvention::Actual_365_Fixed;
+        let price_act_365 = bsm.price();
+        assert_approx_equal!(bsm.year_fraction(), act_365, RUSTQUANT_EPSILON);
+
+        // The extra day of time value is worth about -theta / 365.
+        assert!(price_act_365 > price_act_act);
+        assert_approx_equal!(price_act_365 - price_act_act, -theta / 365.0, 1e-5);
+    }
+
+    #[test]
+    fn test_from_market_data_matches_loose_arguments() {
+        use crate::data::{MarketVolatility, YieldCurve};
+        use time::macros::date;
+
+        let valuation_date = date!(2024 - 01 - 05);
+        let expiry = date!(2024 - 07 - 05);
+
+        let mut market = MarketData::new(valuation_date);
+        market.add_curve(
+            "USD",
+            YieldCurve::from_dates_and_rates(&[valuation_date], &[0.05]),
+        );
+        market.add_spot("SPX", 100.0);
+        market.set_volatility(MarketVolatility::Constant(0.2));
+
+        let snapshot = BlackScholesMerton::from_market_data(
+            &market,
+            "USD",
+            "SPX",
+            95.0,
+            expiry,
+            TypeFlag::Call,
+        )
+        .unwrap();
+        let loose = BlackScholesMerton::new(
+            0.05,
+            100.0,
+            95.0,
+            0.2,
+            0.05,
+            Some(valuation_date),
+            expiry,
+            TypeFlag::Call,
+        );
+
+        assert_approx_equal!(snapshot.price(), loose.price(), 1e-12);
+        assert_approx_equal!(snapshot.delta(), loose.delta(), 1e-12);
+        assert_approx_equal!(snapshot.vega(), loose.vega(), 1e-12);
+        assert_approx_equal!(snapshot.theta(), loose.theta(), 1e-12);
+
+        // Missing data is reported, not defaulted.
+        market.volatility = None;
+        let error = BlackScholesMerton::from_market_data(
+            &market,
+            "USD",
+            "SPX",
+            95.0,
+            expiry,
+            TypeFlag::Call,
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(error, RustQuantError::MissingInput(_)));
+        assert!(BlackScholesMerton::from_market_data(
+            &market,
+            "USD",
+            "NDX",
+            95.0,
+            expiry,
+            TypeFlag::Call
+        )
+        .is_err());
+        assert!(BlackScholesMerton::from_market_data(
+            &market,
+            "USD",
+            "SPX",
+            95.0,
+            valuation_date,
+            TypeFlag::Call
+        )
+        .is_err());
+    }
 }
@@ -0,0 +1,236 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Dividend schedules of equity underlyings: a continuous dividend yield,
+//! discrete cash dividends and discrete proportional dividends.
+//!
+//! Discrete dividends use the escrowed dividend model: the spot is split
+//! into the present value of the cash dividends paid up to expiry, which is
+//! certain, and a risky part $S^*$ which follows geometric Brownian motion
+//! with the continuous yield $q$, and drops by the fraction $\rho_i$ of each
+//! proportional dividend on its ex-dividend date. A European option then has
+//! the Black-Scholes price of an underlying with the adjusted spot
+//!
+//! $$
+//! \tilde{S} = \left( S - \sum_{t_j \le T} D_j e^{-r t_j} \right)
+//!     \prod_{t_i \le T} (1 - \rho_i)
+//! $$
+//!
+//! and dividend yield $q$, and its forward is $\tilde{S} e^{(r - q) T}$.
+//! Times are in years from the valuation date, and a dividend is paid on its
+//! ex-dividend date.
+
+use crate::error::RustQuantError;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// A cash dividend paid by the underlying.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CashDividend {
+    /// Time (in years) of the ex-dividend date.
+    pub time: f64,
+
+    /// Amount of the dividend.
+    pub amount: f64,
+}
+
+/// A dividend paid as a fraction of the (risky part of the) spot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProportionalDividend {
+    /// Time (in years) of the ex-dividend date.
+    pub time: f64,
+
+    /// Fraction of the spot paid, in $[0, 1)$.
+    pub rate: f64,
+}
+
+/// Dividends of an equity underlying.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DividendSchedule {
+    /// `q` - Continuous dividend yield.
+    pub dividend_yield: f64,
+
+    /// Discrete cash dividends.
+    pub cash: Vec<CashDividend>,
+
+    /// Discrete proportional dividends.
+    pub proportional: Vec<ProportionalDividend>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl DividendSchedule {
+    /// New schedule, without any dividends.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the continuous dividend yield.
+    #[must_use]
+    pub fn with_dividend_yield(mut self, dividend_yield: f64) -> Self {
+        self.dividend_yield = dividend_yield;
+        self
+    }
+
+    /// Add a cash dividend.
+    #[must_use]
+    pub fn with_cash_dividend(mut self, time: f64, amount: f64) -> Self {
+        self.cash.push(CashDividend { time, amount });
+        self
+    }
+
+    /// Add a proportional dividend.
+    #[must_use]
+    pub fn with_proportional_dividend(mut self, time: f64, rate: f64) -> Self {
+        self.proportional.push(ProportionalDividend { time, rate });
+        self
+    }
+
+    /// Present value at time `from` of the cash dividends paid in
+    /// $(from, to]$, discounted at the rate `r`.
+    #[must_use]
+    pub fn cash_present_value(&self, r: f64, from: f64, to: f64) -> f64 {
+        self.cash
+            .iter()
+            .filter(|dividend| dividend.time > from && dividend.time <= to)
+            .map(|dividend| dividend.amount * f64::exp(-r * (dividend.time - from)))
+            .sum()
+    }
+
+    /// Fraction of the risky part of the spot left after the proportional
+    /// dividends paid in $(from, to]$.
+    #[must_use]
+    pub fn proportional_factor(&self, from: f64, to: f64) -> f64 {
+        self.proportional
+            .iter()
+            .filter(|dividend| dividend.time > from && dividend.time <= to)
+            .map(|dividend| 1.0 - dividend.rate)
+            .product()
+    }
+
+    /// Adjusted spot of the escrowed dividend model: the spot less the
+    /// present value of the cash dividends up to `expiry`, reduced by the
+    /// proportional dividends up to `expiry`.
+    #[must_use]
+    pub fn adjusted_spot(&self, spot: f64, r: f64, expiry: f64) -> f64 {
+        (spot - self.cash_present_value(r, 0.0, expiry)) * self.proportional_factor(0.0, expiry)
+    }
+
+    /// Forward price of the underlying for delivery at `expiry`.
+    #[must_use]
+    pub fn forward(&self, spot: f64, r: f64, expiry: f64) -> f64 {
+        self.adjusted_spot(spot, r, expiry) * f64::exp((r - self.dividend_yield) * expiry)
+    }
+
+    /// Check that the dividends can be paid out of the spot.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if a cash dividend is negative, a
+    ///   proportional rate is outside $[0, 1)$, or the cash dividends up to
+    ///   `expiry` are worth at least the spot.
+    pub fn validate(&self, spot: f64, r: f64, expiry: f64) -> Result<(), RustQuantError> {
+        if self
+            .cash
+            .iter()
+            .any(|d| d.amount.is_nan() || d.amount < 0.0)
+        {
+            return Err(RustQuantError::InvalidArgument(
+                "the cash dividends must not be negative".to_string(),
+            ));
+        }
+        if self
+            .proportional
+            .iter()
+            .any(|d| !(0.0..1.0).contains(&d.rate))
+        {
+            return Err(RustQuantError::InvalidArgument(
+                "the proportional dividend rates must be in [0, 1)".to_string(),
+            ));
+        }
+        if self.cash_present_value(r, 0.0, expiry) >= spot {
+            return Err(RustQuantError::InvalidArgument(
+                "the dividends are worth more than the spot".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_dividends {
+    use super::*;
+    use crate::assert_approx_equal;
+
+    #[test]
+    fn test_adjusted_spot_and_forward() {
+        let dividends = DividendSchedule::new()
+            .with_dividend_yield(0.01)
+            .with_cash_dividend(0.25, 1.5)
+            .with_cash_dividend(0.75, 1.5)
+            .with_proportional_dividend(0.5, 0.02)
+            .with_cash_dividend(1.25, 1.5);
+
+        let pv = 1.5 * f64::exp(-0.05 * 0.25) + 1.5 * f64::exp(-0.05 * 0.75);
+        assert_approx_equal!(dividends.cash_present_value(0.05, 0.0, 1.0), pv, 1e-12);
+        assert_approx_equal!(
+            dividends.adjusted_spot(100.0, 0.05, 1.0),
+            (100.0 - pv) * 0.98,
+            1e-12
+        );
+        assert_approx_equal!(
+            dividends.forward(100.0, 0.05, 1.0),
+            (100.0 - pv) * 0.98 * f64::exp(0.04),
+            1e-12
+        );
+
+        // Before the first ex-dividend date only the yield matters.
+        assert_approx_equal!(
+            dividends.forward(100.0, 0.05, 0.2),
+            100.0 * f64::exp(0.04 * 0.2),
+            1e-12
+        );
+
+        // Without dividends, the forward is the usual one.
+        assert_approx_equal!(
+            DividendSchedule::new().forward(100.0, 0.05, 2.0),
+            100.0 * f64::exp(0.1),
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(DividendSchedule::new()
+            .with_cash_dividend(0.5, 5.0)
+            .validate(100.0, 0.05, 1.0)
+            .is_ok());
+        assert!(DividendSchedule::new()
+            .with_cash_dividend(0.5, 120.0)
+            .validate(100.0, 0.05, 1.0)
+            .is_err());
+        assert!(DividendSchedule::new()
+            .with_proportional_dividend(0.5, 1.0)
+            .validate(100.0, 0.05, 1.0)
+            .is_err());
+        assert!(DividendSchedule::new()
+            .with_cash_dividend(0.5, -1.0)
+            .validate(100.0, 0.05, 1.0)
+            .is_err());
+    }
+}
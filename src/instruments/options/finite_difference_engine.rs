@@ -0,0 +1,663 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Finite difference engine for the one-dimensional Black-Scholes PDE
+//!
+//! $$
+//! \frac{\partial V}{\partial \tau} = \frac{1}{2} \sigma^2 S^2 \frac{\partial^2 V}{\partial S^2}
+//!     + (r - q) S \frac{\partial V}{\partial S} - r V
+//! $$
+//!
+//! in the time to expiry $\tau$, on a uniform grid in the spot.
+//!
+//! The time stepping is the $\theta$-scheme: explicit ($\theta = 0$), fully
+//! implicit ($\theta = 1$), or Crank-Nicolson ($\theta = 1/2$). Crank-Nicolson
+//! is second order, but it does not damp the high frequency errors of a
+//! non-smooth payoff (the kink at the strike, or the jump at a barrier), so
+//! its first steps are replaced by pairs of implicit half-steps (Rannacher
+//! smoothing).
+//!
+//! American exercise is handled by projected successive over-relaxation
+//! (PSOR) of the linear system of each step, and knock-out barriers by
+//! putting the edge of the grid on the barrier, where the value is zero.
+
+use crate::error::RustQuantError;
+use crate::instruments::{
+    options::{ExerciseFlag, TypeFlag},
+    PricingResult,
+};
+use crate::math::interpolation::cubic_spline::solve_tridiagonal;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Time stepping of a [`FiniteDifferenceEngine`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FiniteDifferenceScheme {
+    /// Explicit (forward Euler) scheme: only stable for small time steps.
+    Explicit,
+
+    /// Fully implicit (backward Euler) scheme.
+    Implicit,
+
+    /// Crank-Nicolson scheme, with Rannacher smoothing.
+    #[default]
+    CrankNicolson,
+}
+
+/// Finite difference pricer of (knock-out) European and American vanillas.
+#[derive(Debug, Clone)]
+pub struct FiniteDifferenceEngine {
+    /// `S` - Initial price of the underlying.
+    pub initial_price: f64,
+
+    /// `K` - Strike price.
+    pub strike_price: f64,
+
+    /// `r` - Risk-free rate parameter.
+    pub risk_free_rate: f64,
+
+    /// `q` - Dividend yield.
+    pub dividend_yield: f64,
+
+    /// `v` - Volatility parameter.
+    pub volatility: f64,
+
+    /// `T` - Time to expiry/maturity.
+    pub time_to_expiry: f64,
+
+    /// Call or put.
+    pub option_type: TypeFlag,
+
+    /// European or American exercise.
+    pub exercise_flag: ExerciseFlag,
+
+    /// Time stepping scheme.
+    pub scheme: FiniteDifferenceScheme,
+
+    /// Number of intervals of the spot grid.
+    pub price_steps: usize,
+
+    /// Number of time steps.
+    pub time_steps: usize,
+
+    /// Number of initial Crank-Nicolson steps replaced by two implicit half-steps.
+    pub rannacher_steps: usize,
+
+    /// Knock-out barrier below the spot, if any.
+    pub lower_barrier: Option<f64>,
+
+    /// Knock-out barrier above the spot, if any.
+    pub upper_barrier: Option<f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Relaxation parameter of PSOR.
+const PSOR_RELAXATION: f64 = 1.2;
+
+/// PSOR stops once no value moves by more than this in an iteration.
+const PSOR_TOLERANCE: f64 = 1e-10;
+
+/// Maximum number of PSOR iterations per time step.
+const PSOR_MAX_ITERATIONS: usize = 10_000;
+
+impl FiniteDifferenceEngine {
+    /// Create a new engine: Crank-Nicolson with two Rannacher steps, on a
+    /// grid of 400 spot intervals and 400 time steps, without barriers.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        initial_price: f64,
+        strike_price: f64,
+        risk_free_rate: f64,
+        dividend_yield: f64,
+        volatility: f64,
+        time_to_expiry: f64,
+        option_type: TypeFlag,
+        exercise_flag: ExerciseFlag,
+    ) -> Self {
+        Self {
+            initial_price,
+            strike_price,
+            risk_free_rate,
+            dividend_yield,
+            volatility,
+            time_to_expiry,
+            option_type,
+            exercise_flag,
+            scheme: FiniteDifferenceScheme::default(),
+            price_steps: 400,
+            time_steps: 400,
+            rannacher_steps: 2,
+            lower_barrier: None,
+            upper_barrier: None,
+        }
+    }
+
+    /// Use the given time stepping scheme.
+    #[must_use]
+    pub fn with_scheme(mut self, scheme: FiniteDifferenceScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Use a grid of `price_steps` spot intervals and `time_steps` time steps.
+    #[must_use]
+    pub fn with_grid(mut self, price_steps: usize, time_steps: usize) -> Self {
+        self.price_steps = price_steps;
+        self.time_steps = time_steps;
+        self
+    }
+
+    /// Replace the first `rannacher_steps` Crank-Nicolson steps by two
+    /// implicit half-steps each (zero for plain Crank-Nicolson).
+    #[must_use]
+    pub fn with_rannacher_steps(mut self, rannacher_steps: usize) -> Self {
+        self.rannacher_steps = rannacher_steps;
+        self
+    }
+
+    /// Knock the option out (without rebate) if the spot reaches the given
+    /// lower and/or upper barrier.
+    #[must_use]
+    pub fn with_barriers(mut self, lower_barrier: Option<f64>, upper_barrier: Option<f64>) -> Self {
+        self.lower_barrier = lower_barrier;
+        self.upper_barrier = upper_barrier;
+        self
+    }
+
+    /// Price of the option, interpolated quadratically between grid nodes.
+    ///
+    /// The result carries the number of time steps and, for American
+    /// options, the `"psor_iterations"` diagnostic: the total number of PSOR
+    /// iterations. Warnings are raised if the explicit scheme is unstable on
+    /// the grid, or if PSOR does not converge at some step.
+    ///
+    /// # Errors
+    ///
+    /// - `RustQuantError::InvalidArgument` if the exercise is Bermudan, if
+    ///   the grid has fewer than three spot intervals or no time steps, if the
+    ///   spot, strike, volatility or time to expiry is not positive, or if the
+    ///   spot is not strictly between the barriers.
+    pub fn price(&self) -> Result<PricingResult, RustQuantError> {
+        self.validate()?;
+
+        let m = self.price_steps;
+        let (s_min, s_max) = self.domain();
+        let ds = (s_max - s_min) / m as f64;
+        let spots: Vec<f64> = (0..=m).map(|i| s_min + i as f64 * ds).collect();
+
+        // Coefficients of the spatial operator at the interior nodes:
+        // (LV)_i = a_i V_{i-1} + d_i V_i + c_i V_{i+1}.
+        let (r, b, v) = (
+            self.risk_free_rate,
+            self.risk_free_rate - self.dividend_yield,
+            self.volatility,
+        );
+        let diffusion = |s: f64| 0.5 * v * v * s * s / (ds * ds);
+        let convection = |s: f64| 0.5 * b * s / ds;
+
+        let a: Vec<f64> = spots[1..m]
+            .iter()
+            .map(|s| diffusion(*s) - convection(*s))
+            .collect();
+        let c: Vec<f64> = spots[1..m]
+            .iter()
+            .map(|s| diffusion(*s) + convection(*s))
+            .collect();
+        let d: Vec<f64> = spots[1..m]
+            .iter()
+            .map(|s| -2.0 * diffusion(*s) - r)
+            .collect();
+
+        let obstacle: Vec<f64> = spots.iter().map(|s| self.payoff(*s)).collect();
+        let american = matches!(self.exercise_flag, ExerciseFlag::American);
+
+        let mut values = obstacle.clone();
+        values[0] = self.boundary(s_min, self.lower_barrier, 0.0);
+        values[m] = self.boundary(s_max, self.upper_barrier, 0.0);
+
+        let dt = self.time_to_expiry / self.time_steps as f64;
+        let mut warnings = Vec::new();
+
+        if self.scheme == FiniteDifferenceScheme::Explicit && d.iter().any(|d| 1.0 + dt * d < 0.0) {
+            warnings.push(format!(
+                "the explicit scheme is unstable with {} time steps on {m} spot intervals: \
+                use more time steps",
+                self.time_steps
+            ));
+        }
+
+        let theta = match self.scheme {
+            FiniteDifferenceScheme::Explicit => 0.0,
+            FiniteDifferenceScheme::Implicit => 1.0,
+            FiniteDifferenceScheme::CrankNicolson => 0.5,
+        };
+
+        let (mut tau, mut psor_iterations, mut psor_failures) = (0.0, 0, 0);
+
+        for step in 0..self.time_steps {
+            let sub_steps = if theta == 0.5 && step < self.rannacher_steps {
+                vec![(1.0, 0.5 * dt); 2]
+            } else {
+                vec![(theta, dt)]
+            };
+
+            for (theta, h) in sub_steps {
+                tau += h;
+
+                // Right-hand side: (I + (1 - theta) h L) V, with the new
+                // boundary values moved over from the left-hand side.
+                let lower = self.boundary(s_min, self.lower_barrier, tau);
+                let upper = self.boundary(s_max, self.upper_barrier, tau);
+
+                let mut rhs: Vec<f64> = (1..m)
+                    .map(|i| {
+                        let j = i - 1;
+                        values[i]
+                            + (1.0 - theta)
+                                * h
+                                * (a[j] * values[i - 1] + d[j] * values[i] + c[j] * values[i + 1])
+                    })
+                    .collect();
+                rhs[0] += theta * h * a[0] * lower;
+                rhs[m - 2] += theta * h * c[m - 2] * upper;
+
+                // Left-hand side: I - theta h L.
+                let lhs_lower: Vec<f64> = a.iter().map(|a| -theta * h * a).collect();
+                let lhs_diagonal: Vec<f64> = d.iter().map(|d| 1.0 - theta * h * d).collect();
+                let lhs_upper: Vec<f64> = c.iter().map(|c| -theta * h * c).collect();
+
+                let interior = if theta == 0.0 {
+                    rhs
+                } else if american {
+                    let start: Vec<f64> = values[1..m].to_vec();
+                    let (solution, iterations) = psor(
+                        &lhs_lower,
+                        &lhs_diagonal,
+                        &lhs_upper,
+                        &rhs,
+                        &obstacle[1..m],
+                        start,
+                    );
+
+                    psor_iterations += iterations;
+                    if iterations == PSOR_MAX_ITERATIONS {
+                        psor_failures += 1;
+                    }
+
+                    solution
+                } else {
+                    solve_tridiagonal(&lhs_lower, &lhs_diagonal, &lhs_upper, &rhs)
+                };
+
+                values[0] = lower;
+                values[m] = upper;
+                values[1..m].copy_from_slice(&interior);
+
+                if american {
+                    for (value, exercise) in values[1..m].iter_mut().zip(&obstacle[1..m]) {
+                        *value = value.max(*exercise);
+                    }
+                }
+            }
+        }
+
+        if psor_failures > 0 {
+            warnings.push(format!(
+                "PSOR did not converge within {PSOR_MAX_ITERATIONS} iterations \
+                at {psor_failures} time steps"
+            ));
+        }
+
+        let mut result = PricingResult::new(interpolate(&spots, &values, self.initial_price))
+            .with_iterations(self.time_steps);
+        if american {
+            result = result.with_diagnostic("psor_iterations", psor_iterations as f64);
+        }
+        result.warnings = warnings;
+
+        Ok(result)
+    }
+
+    /// Spot range of the grid: from the lower barrier (or zero) to the upper
+    /// barrier (or far enough above the spot and strike).
+    fn domain(&self) -> (f64, f64) {
+        let far = self.initial_price.max(self.strike_price)
+            * f64::exp(5.0 * self.volatility * self.time_to_expiry.sqrt());
+
+        (
+            self.lower_barrier.unwrap_or(0.0),
+            self.upper_barrier.unwrap_or(far),
+        )
+    }
+
+    /// Payoff of exercise at spot `s`.
+    fn payoff(&self, s: f64) -> f64 {
+        match self.option_type {
+            TypeFlag::Call => (s - self.strike_price).max(0.0),
+            TypeFlag::Put => (self.strike_price - s).max(0.0),
+        }
+    }
+
+    /// Value at the edge `s` of the grid at time to expiry `tau`: zero on a
+    /// knock-out barrier, and otherwise the discounted intrinsic value of the
+    /// forward (or the payoff, if larger, for American options).
+    fn boundary(&self, s: f64, barrier: Option<f64>, tau: f64) -> f64 {
+        if barrier.is_some() {
+            return 0.0;
+        }
+
+        let forward = s * f64::exp(-self.dividend_yield * tau)
+            - self.strike_price * f64::exp(-self.risk_free_rate * tau);
+        let european = match self.option_type {
+            TypeFlag::Call => forward.max(0.0),
+            TypeFlag::Put => (-forward).max(0.0),
+        };
+
+        match self.exercise_flag {
+            ExerciseFlag::American => european.max(self.payoff(s)),
+            _ => european,
+        }
+    }
+
+    /// Check that the option can be priced on the grid.
+    fn validate(&self) -> Result<(), RustQuantError> {
+        if matches!(self.exercise_flag, ExerciseFlag::Bermudan) {
+            return Err(RustQuantError::InvalidArgument(
+                "finite difference pricing of Bermudan options is not supported".to_string(),
+            ));
+        }
+        if self.price_steps < 3 || self.time_steps == 0 {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the grid needs at least 3 spot intervals and a time step, not {} and {}",
+                self.price_steps, self.time_steps
+            )));
+        }
+
+        let positive = [
+            ("spot", self.initial_price),
+            ("strike", self.strike_price),
+            ("volatility", self.volatility),
+            ("time to expiry", self.time_to_expiry),
+        ];
+
+        for (name, value) in positive {
+            if value.is_nan() || value <= 0.0 {
+                return Err(RustQuantError::InvalidArgument(format!(
+                    "the {name} must be positive, not {value}"
+                )));
+            }
+        }
+
+        let (s_min, s_max) = self.domain();
+
+        if !(s_min < self.initial_price && self.initial_price < s_max) {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the spot {} must be strictly between the barriers {s_min} and {s_max}",
+                self.initial_price
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Solve the linear complementarity problem $A x \geq y$, $x \geq g$,
+/// $(A x - y) \cdot (x - g) = 0$ for tridiagonal $A$ by projected successive
+/// over-relaxation, from the initial guess `x`.
+///
+/// Returns the solution and the number of iterations.
+fn psor(
+    lower: &[f64],
+    diagonal: &[f64],
+    upper: &[f64],
+    rhs: &[f64],
+    obstacle: &[f64],
+    mut x: Vec<f64>,
+) -> (Vec<f64>, usize) {
+    let n = x.len();
+
+    for iteration in 1..=PSOR_MAX_ITERATIONS {
+        let mut change: f64 = 0.0;
+
+        for i in 0..n {
+            let mut residual = rhs[i] - diagonal[i] * x[i];
+            if i > 0 {
+                residual -= lower[i] * x[i - 1];
+            }
+            if i + 1 < n {
+                residual -= upper[i] * x[i + 1];
+            }
+
+            let updated = (x[i] + PSOR_RELAXATION * residual / diagonal[i]).max(obstacle[i]);
+
+            change = change.max((updated - x[i]).abs());
+            x[i] = updated;
+        }
+
+        if change < PSOR_TOLERANCE {
+            return (x, iteration);
+        }
+    }
+
+    (x, PSOR_MAX_ITERATIONS)
+}
+
+/// Quadratic interpolation of the grid values at `s`, through the three
+/// nodes nearest to it.
+fn interpolate(spots: &[f64], values: &[f64], s: f64) -> f64 {
+    let ds = spots[1] - spots[0];
+    let nearest = ((s - spots[0]) / ds).round() as usize;
+    let i = nearest.clamp(1, spots.len() - 2);
+
+    let x = (s - spots[i]) / ds;
+    let (left, middle, right) = (values[i - 1], values[i], values[i + 1]);
+
+    middle + 0.5 * x * (right - left) + 0.5 * x * x * (right - 2.0 * middle + left)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_finite_difference_engine {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::instruments::options::{BarrierOption, BarrierType};
+    use crate::math::distributions::{Distribution, Gaussian};
+
+    const SCHEMES: [FiniteDifferenceScheme; 3] = [
+        FiniteDifferenceScheme::Explicit,
+        FiniteDifferenceScheme::Implicit,
+        FiniteDifferenceScheme::CrankNicolson,
+    ];
+
+    /// Black-Scholes price with dividend yield `q`.
+    fn black_scholes(s: f64, k: f64, r: f64, q: f64, v: f64, t: f64, call: bool) -> f64 {
+        let n = Gaussian::default();
+        let d1 = ((s / k).ln() + (r - q + 0.5 * v * v) * t) / (v * t.sqrt());
+        let d2 = d1 - v * t.sqrt();
+
+        if call {
+            s * f64::exp(-q * t) * n.cdf(d1) - k * f64::exp(-r * t) * n.cdf(d2)
+        } else {
+            k * f64::exp(-r * t) * n.cdf(-d2) - s * f64::exp(-q * t) * n.cdf(-d1)
+        }
+    }
+
+    #[test]
+    fn test_european_schemes_match_black_scholes() {
+        for scheme in SCHEMES {
+            for (option_type, call) in [(TypeFlag::Call, true), (TypeFlag::Put, false)] {
+                let result = FiniteDifferenceEngine::new(
+                    100.0,
+                    95.0,
+                    0.05,
+                    0.02,
+                    0.25,
+                    0.75,
+                    option_type,
+                    ExerciseFlag::European,
+                )
+                .with_scheme(scheme)
+                .with_grid(200, 3_000)
+                .price()
+                .unwrap();
+
+                let exact = black_scholes(100.0, 95.0, 0.05, 0.02, 0.25, 0.75, call);
+
+                assert_approx_equal!(result.value, exact, 1e-2);
+                assert!(!result.has_warnings());
+            }
+        }
+    }
+
+    #[test]
+    fn test_explicit_instability_warning() {
+        let result = FiniteDifferenceEngine::new(
+            100.0,
+            100.0,
+            0.05,
+            0.0,
+            0.25,
+            1.0,
+            TypeFlag::Call,
+            ExerciseFlag::European,
+        )
+        .with_scheme(FiniteDifferenceScheme::Explicit)
+        .with_grid(400, 50)
+        .price()
+        .unwrap();
+
+        assert!(result.has_warnings());
+    }
+
+    #[test]
+    fn test_american_put_psor() {
+        // S = 36, K = 40, r = 0.06, v = 0.2, T = 1: the American price is 4.4866.
+        for scheme in [
+            FiniteDifferenceScheme::Implicit,
+            FiniteDifferenceScheme::CrankNicolson,
+        ] {
+            let result = FiniteDifferenceEngine::new(
+                36.0,
+                40.0,
+                0.06,
+                0.0,
+                0.2,
+                1.0,
+                TypeFlag::Put,
+                ExerciseFlag::American,
+            )
+            .with_scheme(scheme)
+            .with_grid(400, 1_000)
+            .price()
+            .unwrap();
+
+            assert_approx_equal!(result.value, 4.4866, 5e-3);
+            assert!(result.diagnostic("psor_iterations").unwrap() > 0.0);
+            assert!(!result.has_warnings());
+        }
+    }
+
+    #[test]
+    fn test_knock_out_barriers() {
+        let barrier = |h: f64| BarrierOption {
+            initial_price: 100.0,
+            strike_price: 100.0,
+            barrier: h,
+            time_to_expiry: 0.5,
+            risk_free_rate: 0.08,
+            volatility: 0.25,
+            rebate: 0.0,
+            dividend_yield: 0.04,
+        };
+        let engine = |option_type| {
+            FiniteDifferenceEngine::new(
+                100.0,
+                100.0,
+                0.08,
+                0.04,
+                0.25,
+                0.5,
+                option_type,
+                ExerciseFlag::European,
+            )
+            .with_grid(400, 400)
+        };
+
+        let down_and_out_call = engine(TypeFlag::Call)
+            .with_barriers(Some(90.0), None)
+            .price()
+            .unwrap();
+        let up_and_out_put = engine(TypeFlag::Put)
+            .with_barriers(None, Some(110.0))
+            .price()
+            .unwrap();
+
+        assert_approx_equal!(
+            down_and_out_call.value,
+            barrier(90.0).price(BarrierType::CDO),
+            1e-2
+        );
+        assert_approx_equal!(
+            up_and_out_put.value,
+            barrier(110.0).price(BarrierType::PUO),
+            1e-2
+        );
+
+        // The jump of the payoff at the barrier upsets plain Crank-Nicolson.
+        let up_and_out_call = |rannacher_steps| {
+            engine(TypeFlag::Call)
+                .with_barriers(None, Some(110.0))
+                .with_grid(400, 50)
+                .with_rannacher_steps(rannacher_steps)
+                .price()
+                .unwrap()
+                .value
+        };
+        let exact = barrier(110.0).price(BarrierType::CUO);
+
+        assert!((up_and_out_call(2) - exact).abs() < (up_and_out_call(0) - exact).abs());
+    }
+
+    #[test]
+    fn test_invalid_grids() {
+        let engine = FiniteDifferenceEngine::new(
+            100.0,
+            100.0,
+            0.05,
+            0.0,
+            0.2,
+            1.0,
+            TypeFlag::Put,
+            ExerciseFlag::European,
+        );
+
+        assert!(engine.price().is_ok());
+        assert!(engine.clone().with_grid(2, 100).price().is_err());
+        assert!(engine
+            .clone()
+            .with_barriers(Some(110.0), None)
+            .price()
+            .is_err());
+        assert!(FiniteDifferenceEngine {
+            exercise_flag: ExerciseFlag::Bermudan,
+            ..engine
+        }
+        .price()
+        .is_err());
+    }
+}
@@ -8,7 +8,8 @@
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 use crate::instruments::options::option::{ExerciseFlag, TypeFlag};
-use crate::time::{today, DayCountConvention};
+use crate::instruments::Maturity;
+use crate::time::DayCountConvention;
 use std::cmp::Ordering;
 use time::Date;
 
@@ -32,6 +33,9 @@ pub struct FiniteDifferencePricer {
     /// Maturity date
     pub expiration_date: Date,
 
+    /// Day count convention of the time to expiry.
+    pub day_count_convention: DayCountConvention,
+
     /// Time steps
     pub time_steps: u32,
     /// Price steps
@@ -76,6 +80,7 @@ impl FiniteDifferencePricer {
             volatility,
             evaluation_date,
             expiration_date,
+            day_count_convention: DayCountConvention::default(),
             time_steps,
             price_steps,
             type_flag,
@@ -270,13 +275,6 @@ impl FiniteDifferencePricer {
         self.strike_price * f64::exp(-(self.risk_free_rate * T) - (t as f64 * delta_t))
     }
 
-    fn year_fraction(&self) -> f64 {
-        DayCountConvention::default().day_count_factor(
-            self.evaluation_date.unwrap_or(today()),
-            self.expiration_date,
-        )
-    }
-
     fn time_structure(&self) -> (f64, f64) {
         let T: f64 = self.year_fraction();
         (T, T / (self.time_steps as f64))
@@ -430,6 +428,7 @@ mod tests_finite_difference_pricer_at_the_money {
         volatility: 0.5,
         evaluation_date: Some(date!(2024 - 01 - 01)),
         expiration_date: date!(2025 - 01 - 01),
+        day_count_convention: DayCountConvention::Actual_Actual_ISDA,
         time_steps: 1000,
         price_steps: 100,
         type_flag: TypeFlag::Call,
@@ -443,6 +442,7 @@ mod tests_finite_difference_pricer_at_the_money {
         volatility: 0.5,
         evaluation_date: Some(date!(2024 - 01 - 01)),
         expiration_date: date!(2025 - 01 - 01),
+        day_count_convention: DayCountConvention::Actual_Actual_ISDA,
         time_steps: 1000,
         price_steps: 100,
         type_flag: TypeFlag::Put,
@@ -456,6 +456,7 @@ mod tests_finite_difference_pricer_at_the_money {
         volatility: 0.5,
         evaluation_date: Some(date!(2024 - 01 - 01)),
         expiration_date: date!(2025 - 01 - 01),
+        day_count_convention: DayCountConvention::Actual_Actual_ISDA,
         time_steps: 1000,
         price_steps: 100,
         type_flag: TypeFlag::Call,
@@ -469,6 +470,7 @@ mod tests_finite_difference_pricer_at_the_money {
         volatility: 0.5,
         evaluation_date: Some(date!(2024 - 01 - 01)),
         expiration_date: date!(2025 - 01 - 01),
+        day_count_convention: DayCountConvention::Actual_Actual_ISDA,
         time_steps: 1000,
         price_steps: 100,
         type_flag: TypeFlag::Put,
@@ -559,6 +561,7 @@ mod tests_finite_difference_pricer_in_the_money {
         volatility: 0.5,
         evaluation_date: Some(date!(2024 - 01 - 01)),
         expiration_date: date!(2025 - 01 - 01),
+        day_count_convention: DayCountConvention::Actual_Actual_ISDA,
         time_steps: 1000,
         price_steps: 100,
         type_flag: TypeFlag::Call,
@@ -572,6 +575,7 @@ mod tests_finite_difference_pricer_in_the_money {
         volatility: 0.5,
         evaluation_date: Some(date!(2024 - 01 - 01)),
         expiration_date: date!(2025 - 01 - 01),
+        day_count_convention: DayCountConvention::Actual_Actual_ISDA,
         time_steps: 1000,
         price_steps: 100,
         type_flag: TypeFlag::Put,
@@ -585,6 +589,7 @@ mod tests_finite_difference_pricer_in_the_money {
         volatility: 0.5,
         evaluation_date: Some(date!(2024 - 01 - 01)),
         expiration_date: date!(2025 - 01 - 01),
+        day_count_convention: DayCountConvention::Actual_Actual_ISDA,
         time_steps: 1000,
         price_steps: 100,
         type_flag: TypeFlag::Call,
@@ -598,6 +603,7 @@ mod tests_finite_difference_pricer_in_the_money {
         volatility: 0.5,
         evaluation_date: Some(date!(2024 - 01 - 01)),
         expiration_date: date!(2025 - 01 - 01),
+        day_count_convention: DayCountConvention::Actual_Actual_ISDA,
         time_steps: 1000,
         price_steps: 100,
         type_flag: TypeFlag::Put,
@@ -688,6 +694,7 @@ mod tests_finite_difference_pricer_out_of_the_money {
         volatility: 0.5,
         evaluation_date: Some(date!(2024 - 01 - 01)),
         expiration_date: date!(2025 - 01 - 01),
+        day_count_convention: DayCountConvention::Actual_Actual_ISDA,
         time_steps: 1000,
         price_steps: 100,
         type_flag: TypeFlag::Call,
@@ -701,6 +708,7 @@ mod tests_finite_difference_pricer_out_of_the_money {
         volatility: 0.5,
         evaluation_date: Some(date!(2024 - 01 - 01)),
         expiration_date: date!(2025 - 01 - 01),
+        day_count_convention: DayCountConvention::Actual_Actual_ISDA,
         time_steps: 1000,
         price_steps: 100,
         type_flag: TypeFlag::Put,
@@ -714,6 +722,7 @@ mod tests_finite_difference_pricer_out_of_the_money {
         volatility: 0.5,
         evaluation_date: Some(date!(2024 - 01 - 01)),
         expiration_date: date!(2025 - 01 - 01),
+        day_count_convention: DayCountConvention::Actual_Actual_ISDA,
         time_steps: 1000,
         price_steps: 100,
         type_flag: TypeFlag::Call,
@@ -727,6 +736,7 @@ mod tests_finite_difference_pricer_out_of_the_money {
         volatility: 0.5,
         evaluation_date: Some(date!(2024 - 01 - 01)),
         expiration_date: date!(2025 - 01 - 01),
+        day_count_convention: DayCountConvention::Actual_Actual_ISDA,
         time_steps: 1000,
         price_steps: 100,
         type_flag: TypeFlag::Put,
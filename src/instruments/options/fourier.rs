@@ -0,0 +1,561 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Fourier pricing of European options.
+//!
+//! A model only needs to expose the characteristic function of its log-price
+//! at expiry ([`CharacteristicFunction`]) to be priced by any of the Fourier
+//! methods ([`FourierPricer`]):
+//!
+//! - [`CosMethod`]: the COS method of Fang and Oosterlee (2008), which expands
+//!   the density of the log-price in a cosine series on a truncated range.
+//! - [`CarrMadan`]: the FFT method of Carr and Madan (1999), which prices a
+//!   grid of log-strikes at once from the transform of damped call prices.
+//!
+//! Implemented models are [`BlackScholesDiffusion`], [`CGMY`],
+//! [`VarianceGamma`](crate::models::VarianceGamma) and
+//! [`HestonParameters`](crate::instruments::HestonParameters).
+
+use crate::instruments::options::TypeFlag;
+use crate::math::fft_complex;
+use num::Complex;
+use statrs::function::gamma::gamma;
+use std::f64::consts::PI;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Models with a known characteristic function of the log-price.
+pub trait CharacteristicFunction {
+    /// Characteristic function $\phi(u) = E[e^{i u X_t}]$ of the log-price
+    /// relative to the forward, $X_t = \ln(S_t / F_{0,t})$, so that
+    /// $\phi(-i) = 1$. It must accept complex arguments in the strip of
+    /// finite exponential moments.
+    fn characteristic_function(&self, u: Complex<f64>, t: f64) -> Complex<f64>;
+
+    /// First, second and fourth cumulants $(c_1, c_2, c_4)$ of $X_t$, which
+    /// set the truncation range of the COS method. By default they are found
+    /// by finite differences of $\ln \phi$ at zero, as
+    /// $c_n = (-i)^n \frac{d^n}{du^n} \ln \phi(0)$.
+    fn cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let log_phi = |u: f64| self.characteristic_function(Complex::new(u, 0.0), t).ln();
+
+        let h = 1e-4;
+        let (up, down) = (log_phi(h), log_phi(-h));
+
+        // The fourth derivative needs a wider step, against round-off.
+        let h4 = 1e-2;
+        let fourth =
+            log_phi(2.0 * h4) - 4.0 * log_phi(h4) - 4.0 * log_phi(-h4) + log_phi(-2.0 * h4);
+
+        (
+            (up - down).im / (2.0 * h),
+            -(up + down).re / (h * h),
+            fourth.re / h4.powi(4),
+        )
+    }
+}
+
+/// Fourier methods pricing European options from a characteristic function.
+pub trait FourierPricer {
+    /// Undiscounted prices (i.e. paid at expiry) of European options on an
+    /// underlying with the given forward, for the strikes of a single expiry.
+    fn undiscounted_prices<M: CharacteristicFunction + ?Sized>(
+        &self,
+        model: &M,
+        forward: f64,
+        strikes: &[f64],
+        t: f64,
+        option_type: TypeFlag,
+    ) -> Vec<f64>;
+
+    /// Prices of European options of a single expiry `t` (in years), with
+    /// continuously compounded rates.
+    #[allow(clippy::too_many_arguments)]
+    fn prices<M: CharacteristicFunction + ?Sized>(
+        &self,
+        model: &M,
+        spot: f64,
+        strikes: &[f64],
+        risk_free_rate: f64,
+        dividend_yield: f64,
+        t: f64,
+        option_type: TypeFlag,
+    ) -> Vec<f64> {
+        let discount_factor = (-risk_free_rate * t).exp();
+        let forward = spot * ((risk_free_rate - dividend_yield) * t).exp();
+
+        self.undiscounted_prices(model, forward, strikes, t, option_type)
+            .into_iter()
+            .map(|price| discount_factor * price)
+            .collect()
+    }
+
+    /// Price of a European option with expiry `t` (in years), with
+    /// continuously compounded rates.
+    #[allow(clippy::too_many_arguments)]
+    fn price<M: CharacteristicFunction + ?Sized>(
+        &self,
+        model: &M,
+        spot: f64,
+        strike: f64,
+        risk_free_rate: f64,
+        dividend_yield: f64,
+        t: f64,
+        option_type: TypeFlag,
+    ) -> f64 {
+        self.prices(
+            model,
+            spot,
+            &[strike],
+            risk_free_rate,
+            dividend_yield,
+            t,
+            option_type,
+        )[0]
+    }
+}
+
+/// The COS method of Fang and Oosterlee (2008).
+#[derive(Debug, Clone, Copy)]
+pub struct CosMethod {
+    /// Number of terms of the cosine expansion.
+    pub terms: usize,
+
+    /// Half-width $L$ of the truncation range of the log-price, which is
+    /// $c_1 \pm L \sqrt{c_2 + \sqrt{c_4}}$ in terms of its cumulants.
+    pub truncation: f64,
+}
+
+/// The FFT method of Carr and Madan (1999).
+#[derive(Debug, Clone, Copy)]
+pub struct CarrMadan {
+    /// Number of integration points, and of log-strikes (a power of two).
+    pub points: usize,
+
+    /// Spacing of the integration grid ($\eta$). The log-strikes are spaced
+    /// by $2 \pi / (N \eta)$ around the forward.
+    pub spacing: f64,
+
+    /// Damping exponent of the call prices ($\alpha > 0$), for which
+    /// $E[S_t^{1 + \alpha}]$ must be finite.
+    pub damping: f64,
+}
+
+/// The Black-Scholes model: a lognormal diffusion with constant volatility.
+#[derive(Debug, Clone, Copy)]
+pub struct BlackScholesDiffusion {
+    /// Volatility ($\sigma > 0$).
+    pub volatility: f64,
+}
+
+/// The CGMY model of Carr, Geman, Madan and Yor (2002): a pure jump process
+/// with Lévy density $C e^{-G|x|} / |x|^{1+Y}$ for $x < 0$, and
+/// $C e^{-M x} / x^{1+Y}$ for $x > 0$.
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub struct CGMY {
+    /// Overall activity of the jumps ($C > 0$).
+    pub c: f64,
+
+    /// Exponential decay of the negative jumps ($G > 0$).
+    pub g: f64,
+
+    /// Exponential decay of the positive jumps ($M > 1$).
+    pub m: f64,
+
+    /// Fine structure of the jumps ($Y < 2$, and $Y \neq 0, 1$).
+    pub y: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Default for CosMethod {
+    fn default() -> Self {
+        Self {
+            terms: 256,
+            truncation: 10.0,
+        }
+    }
+}
+
+impl Default for CarrMadan {
+    fn default() -> Self {
+        Self {
+            points: 4096,
+            spacing: 0.25,
+            damping: 1.5,
+        }
+    }
+}
+
+impl CosMethod {
+    /// Create a new COS method with the given number of terms and truncation.
+    #[must_use]
+    pub const fn new(terms: usize, truncation: f64) -> Self {
+        Self { terms, truncation }
+    }
+}
+
+impl CarrMadan {
+    /// Create a new Carr-Madan method with the given grid and damping.
+    #[must_use]
+    pub const fn new(points: usize, spacing: f64, damping: f64) -> Self {
+        Self {
+            points,
+            spacing,
+            damping,
+        }
+    }
+}
+
+impl FourierPricer for CosMethod {
+    /// Puts are priced from the cosine expansion, and calls by put-call
+    /// parity. The log-moneyness at expiry $y = \ln(S_t / K) = \ln(F / K) + X_t$
+    /// is truncated to $[a, b]$, centred on its mean, and
+    ///
+    /// $$
+    /// P = K \sum_{k=0}^{N-1}{}' \text{Re}\left[\phi(u_k) e^{i u_k (\ln(F/K) - a)}\right] V_k,
+    /// \qquad u_k = \frac{k \pi}{b - a}
+    /// $$
+    ///
+    /// where $V_k$ are the cosine coefficients of the payoff $(1 - e^y)^+$.
+    fn undiscounted_prices<M: CharacteristicFunction + ?Sized>(
+        &self,
+        model: &M,
+        forward: f64,
+        strikes: &[f64],
+        t: f64,
+        option_type: TypeFlag,
+    ) -> Vec<f64> {
+        let (mean, variance, fourth) = model.cumulants(t);
+        let half_width = self.truncation * (variance.abs() + fourth.abs().sqrt()).sqrt();
+        let width = 2.0 * half_width;
+
+        // ln(F / K) - a = half_width - mean does not depend on the strike,
+        // so the characteristic function is shared by all the strikes.
+        let terms: Vec<(f64, f64)> = (0..self.terms)
+            .map(|k| {
+                let u = k as f64 * PI / width;
+                let term = model.characteristic_function(Complex::new(u, 0.0), t)
+                    * Complex::new(0.0, u * (half_width - mean)).exp();
+                let weight = if k == 0 { 0.5 } else { 1.0 };
+
+                (u, weight * term.re)
+            })
+            .collect();
+
+        strikes
+            .iter()
+            .map(|strike| {
+                let a = (forward / strike).ln() + mean - half_width;
+                let upper = (a + width).min(0.0);
+
+                let put = if upper <= a {
+                    0.0
+                } else {
+                    let sum: f64 = terms
+                        .iter()
+                        .map(|(u, term)| {
+                            let (sin_upper, cos_upper) = (u * (upper - a)).sin_cos();
+
+                            // Cosine coefficients of 1 and e^y over [a, upper].
+                            let psi = if *u == 0.0 { upper - a } else { sin_upper / u };
+                            let chi = (upper.exp() * (cos_upper + u * sin_upper) - a.exp())
+                                / (1.0 + u * u);
+
+                            term * (psi - chi)
+                        })
+                        .sum();
+
+                    (strike * 2.0 / width * sum).max(0.0)
+                };
+
+                match option_type {
+                    TypeFlag::Put => put,
+                    TypeFlag::Call => put + forward - strike,
+                }
+            })
+            .collect()
+    }
+}
+
+impl FourierPricer for CarrMadan {
+    /// Calls are priced on the log-strike grid and interpolated (cubic) to
+    /// the strikes, and puts by put-call parity. With $k = \ln(K / F)$,
+    ///
+    /// $$
+    /// C(k) = F \frac{e^{-\alpha k}}{\pi} \int_0^\infty \text{Re}\left[e^{-i v k}
+    ///     \frac{\phi(v - (\alpha + 1) i)}{\alpha^2 + \alpha - v^2 + i (2 \alpha + 1) v}\right] dv
+    /// $$
+    ///
+    /// which is evaluated for all the log-strikes by an FFT with Simpson weights.
+    ///
+    /// # Panics
+    /// If the number of points is not a power of two.
+    fn undiscounted_prices<M: CharacteristicFunction + ?Sized>(
+        &self,
+        model: &M,
+        forward: f64,
+        strikes: &[f64],
+        t: f64,
+        option_type: TypeFlag,
+    ) -> Vec<f64> {
+        let n = self.points;
+        let alpha = self.damping;
+        let eta = self.spacing;
+        let lambda = 2.0 * PI / (n as f64 * eta);
+        let b = 0.5 * n as f64 * lambda;
+
+        let i: Complex<f64> = Complex::i();
+
+        let integrand: Vec<Complex<f64>> = (0..n)
+            .map(|j| {
+                let v = j as f64 * eta;
+                let psi = model.characteristic_function(v - (alpha + 1.0) * i, t)
+                    / (alpha * alpha + alpha - v * v + i * (2.0 * alpha + 1.0) * v);
+                let simpson = match j {
+                    0 => 1.0 / 3.0,
+                    _ if j % 2 == 1 => 4.0 / 3.0,
+                    _ => 2.0 / 3.0,
+                };
+
+                (i * v * b).exp() * psi * eta * simpson
+            })
+            .collect();
+
+        // Calls per unit forward on the log-strikes -b + lambda * u.
+        let calls: Vec<f64> = fft_complex(&integrand)
+            .into_iter()
+            .enumerate()
+            .map(|(u, x)| (-alpha * (-b + lambda * u as f64)).exp() / PI * x.re)
+            .collect();
+
+        strikes
+            .iter()
+            .map(|strike| {
+                let position = ((strike / forward).ln() + b) / lambda;
+                let call = forward * lagrange_cubic(&calls, position).max(0.0);
+
+                match option_type {
+                    TypeFlag::Call => call,
+                    TypeFlag::Put => call - forward + strike,
+                }
+            })
+            .collect()
+    }
+}
+
+impl BlackScholesDiffusion {
+    /// Create a new Black-Scholes model.
+    #[must_use]
+    pub const fn new(volatility: f64) -> Self {
+        Self { volatility }
+    }
+}
+
+impl CGMY {
+    /// Create a new CGMY model.
+    #[must_use]
+    pub const fn new(c: f64, g: f64, m: f64, y: f64) -> Self {
+        Self { c, g, m, y }
+    }
+
+    /// Characteristic exponent of the jumps (per unit time), without the
+    /// drift correction.
+    fn exponent(&self, u: Complex<f64>) -> Complex<f64> {
+        let Self { c, g, m, y } = *self;
+        let i: Complex<f64> = Complex::i();
+
+        c * gamma(-y) * ((m - i * u).powf(y) - m.powf(y) + (g + i * u).powf(y) - g.powf(y))
+    }
+}
+
+impl CharacteristicFunction for BlackScholesDiffusion {
+    fn characteristic_function(&self, u: Complex<f64>, t: f64) -> Complex<f64> {
+        let i: Complex<f64> = Complex::i();
+
+        (-0.5 * self.volatility * self.volatility * t * (i * u + u * u)).exp()
+    }
+
+    fn cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let variance = self.volatility * self.volatility * t;
+
+        (-0.5 * variance, variance, 0.0)
+    }
+}
+
+impl CharacteristicFunction for CGMY {
+    fn characteristic_function(&self, u: Complex<f64>, t: f64) -> Complex<f64> {
+        let i: Complex<f64> = Complex::i();
+        let drift_correction = -self.exponent(-i).re;
+
+        (t * (i * u * drift_correction + self.exponent(u))).exp()
+    }
+
+    fn cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let Self { c, g, m, y } = *self;
+        let drift_correction = -self.exponent(-Complex::i()).re;
+
+        (
+            (drift_correction + c * gamma(1.0 - y) * (m.powf(y - 1.0) - g.powf(y - 1.0))) * t,
+            c * gamma(2.0 - y) * (m.powf(y - 2.0) + g.powf(y - 2.0)) * t,
+            c * gamma(4.0 - y) * (m.powf(y - 4.0) + g.powf(y - 4.0)) * t,
+        )
+    }
+}
+
+/// Cubic Lagrange interpolation of equally spaced values at a (fractional) index.
+fn lagrange_cubic(values: &[f64], position: f64) -> f64 {
+    let i = (position.floor() as usize).clamp(1, values.len() - 3);
+    let x = position - i as f64;
+
+    let weights = [
+        -x * (x - 1.0) * (x - 2.0) / 6.0,
+        (x + 1.0) * (x - 1.0) * (x - 2.0) / 2.0,
+        -(x + 1.0) * x * (x - 2.0) / 2.0,
+        (x + 1.0) * x * (x - 1.0) / 6.0,
+    ];
+
+    weights
+        .iter()
+        .zip(&values[i - 1..=i + 2])
+        .map(|(w, v)| w * v)
+        .sum()
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_fourier {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::instruments::HestonParameters;
+    use crate::math::distributions::{Distribution, Gaussian};
+    use crate::models::VarianceGamma;
+
+    /// A model without its closed-form cumulants.
+    struct Numerical<'a, M>(&'a M);
+
+    impl<M: CharacteristicFunction> CharacteristicFunction for Numerical<'_, M> {
+        fn characteristic_function(&self, u: Complex<f64>, t: f64) -> Complex<f64> {
+            self.0.characteristic_function(u, t)
+        }
+    }
+
+    fn numerical_cumulants<M: CharacteristicFunction>(model: &M, t: f64) -> (f64, f64, f64) {
+        Numerical(model).cumulants(t)
+    }
+
+    #[test]
+    fn test_black_scholes_prices() {
+        let model = BlackScholesDiffusion::new(0.25);
+        let (spot, r, q, t) = (100.0, 0.05, 0.02, 0.75);
+        let forward = spot * f64::exp((r - q) * t);
+        let normal = Gaussian::default();
+
+        let strikes = [70.0, 90.0, 100.0, 110.0, 140.0];
+        let cos = CosMethod::default().prices(&model, spot, &strikes, r, q, t, TypeFlag::Call);
+        let fft = CarrMadan::default().prices(&model, spot, &strikes, r, q, t, TypeFlag::Put);
+
+        for (i, strike) in strikes.iter().enumerate() {
+            let d1 = ((forward / strike).ln() + 0.5 * 0.0625 * t) / (0.25 * t.sqrt());
+            let d2 = d1 - 0.25 * t.sqrt();
+            let call = f64::exp(-r * t) * (forward * normal.cdf(d1) - strike * normal.cdf(d2));
+            let put = call - f64::exp(-r * t) * (forward - strike);
+
+            assert_approx_equal!(cos[i], call, 1e-10);
+            assert_approx_equal!(fft[i], put, 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_levy_model_references() {
+        // Reference values from Fang and Oosterlee (2008).
+        let variance_gamma = VarianceGamma::new(0.12, 0.2, -0.14);
+        let cgmy = CGMY::new(1.0, 5.0, 5.0, 0.5);
+
+        // The variance gamma density has a cusp at short expiries, so its
+        // characteristic function decays slowly and needs many more terms.
+        assert_approx_equal!(
+            CosMethod::new(8192, 10.0).price(
+                &variance_gamma,
+                100.0,
+                90.0,
+                0.1,
+                0.0,
+                0.1,
+                TypeFlag::Call
+            ),
+            10.993_703_187,
+            1e-6
+        );
+        assert_approx_equal!(
+            CosMethod::default().price(&cgmy, 100.0, 100.0, 0.1, 0.0, 1.0, TypeFlag::Call),
+            19.812_948_843,
+            1e-8
+        );
+        assert_approx_equal!(
+            CosMethod::default().price(
+                &CGMY::new(1.0, 5.0, 5.0, 1.5),
+                100.0,
+                100.0,
+                0.1,
+                0.0,
+                1.0,
+                TypeFlag::Call
+            ),
+            49.790_905_469,
+            1e-8
+        );
+        assert_approx_equal!(
+            CarrMadan::default().price(&cgmy, 100.0, 100.0, 0.1, 0.0, 1.0, TypeFlag::Call),
+            19.812_948_843,
+            1e-5
+        );
+    }
+
+    #[test]
+    fn test_methods_agree_for_heston() {
+        let heston = HestonParameters::new(0.04, 0.06, 1.5, -0.7, 0.5);
+        let strikes = [60.0, 80.0, 100.0, 120.0, 150.0];
+
+        let cos =
+            CosMethod::default().prices(&heston, 100.0, &strikes, 0.03, 0.01, 1.0, TypeFlag::Call);
+        let fft =
+            CarrMadan::default().prices(&heston, 100.0, &strikes, 0.03, 0.01, 1.0, TypeFlag::Call);
+
+        for (cos, fft) in cos.iter().zip(&fft) {
+            assert_approx_equal!(*cos, *fft, 1e-5);
+        }
+
+        // Numerical cumulants agree with the closed forms.
+        let (mean, variance, fourth) = heston.cumulants(1.0);
+        let decay = f64::exp(-1.5);
+        assert_approx_equal!(mean, (1.0 - decay) * 0.02 / 3.0 - 0.03, 1e-8);
+        assert!(variance > 0.0 && fourth > 0.0);
+
+        for model in [
+            VarianceGamma::new(0.12, 0.2, -0.14),
+            VarianceGamma::new(0.3, 0.5, 0.1),
+        ] {
+            let closed = model.cumulants(0.5);
+            let numerical = numerical_cumulants(&model, 0.5);
+
+            assert_approx_equal!(numerical.0, closed.0, 1e-8);
+            assert_approx_equal!(numerical.1, closed.1, 1e-8);
+            assert_approx_equal!(numerical.2, closed.2, 1e-5);
+        }
+    }
+}
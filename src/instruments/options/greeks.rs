@@ -0,0 +1,512 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Greeks of any pricer, by bump-and-reprice or automatic differentiation.
+//!
+//! Closed-form Greeks are only available for a few models. The
+//! [`GreeksEngine`] computes delta, gamma, vega, theta and rho of any
+//! [`GreeksPricer`], which maps the market inputs (spot, volatility, rate
+//! and time to expiry) to a price. Any `Fn(&GreeksInputs) -> f64` closure is
+//! a pricer, so Monte Carlo, lattice and PDE prices can all be risked.
+//!
+//! Two methods are available:
+//!
+//! - [`GreeksMethod::FiniteDifference`]: central differences of repriced
+//!   values. Monte Carlo pricers should use a fixed seed, so that the bumped
+//!   prices share their random numbers.
+//! - [`GreeksMethod::AutoDiff`]: the pricer is evaluated once on the
+//!   [`autodiff`](crate::autodiff) graph, and the first order Greeks are
+//!   read off the adjoints. The graph only gives gradients, so gamma is the
+//!   central difference of the adjoint deltas.
+
+use crate::autodiff::{Accumulate, Gradient, Graph, Variable};
+use crate::error::RustQuantError;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Market inputs that the Greeks are taken with respect to.
+#[derive(Debug, Clone, Copy)]
+pub struct GreeksInputs {
+    /// `S` - Spot price of the underlying.
+    pub spot: f64,
+
+    /// `v` - Volatility parameter.
+    pub volatility: f64,
+
+    /// `r` - Risk-free rate parameter.
+    pub risk_free_rate: f64,
+
+    /// `T` - Time to expiry/maturity.
+    pub time_to_expiry: f64,
+}
+
+/// Market inputs as variables on an autodiff [`Graph`].
+#[derive(Debug, Clone, Copy)]
+pub struct GreeksVariables<'v> {
+    /// `S` - Spot price of the underlying.
+    pub spot: Variable<'v>,
+
+    /// `v` - Volatility parameter.
+    pub volatility: Variable<'v>,
+
+    /// `r` - Risk-free rate parameter.
+    pub risk_free_rate: Variable<'v>,
+
+    /// `T` - Time to expiry/maturity.
+    pub time_to_expiry: Variable<'v>,
+}
+
+/// A price as a function of the market inputs.
+pub trait GreeksPricer {
+    /// Price for the given market inputs.
+    fn price(&self, inputs: &GreeksInputs) -> f64;
+
+    /// Price recorded on the autodiff graph of `inputs`.
+    /// Pricers that are not written in terms of [`Variable`]s return `None`
+    /// (the default), and only support finite difference Greeks.
+    fn price_autodiff<'v>(&self, _inputs: &GreeksVariables<'v>) -> Option<Variable<'v>> {
+        None
+    }
+}
+
+/// How the Greeks are computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GreeksMethod {
+    /// Central differences of repriced values.
+    #[default]
+    FiniteDifference,
+
+    /// Reverse mode automatic differentiation.
+    AutoDiff,
+}
+
+/// First and second order Greeks.
+#[derive(Debug, Clone, Copy)]
+pub struct Greeks {
+    /// Price of the instrument.
+    pub price: f64,
+
+    /// First derivative with respect to the spot.
+    pub delta: f64,
+
+    /// Second derivative with respect to the spot.
+    pub gamma: f64,
+
+    /// First derivative with respect to the volatility.
+    pub vega: f64,
+
+    /// Decay with the passage of time, i.e. minus the derivative with
+    /// respect to the time to expiry.
+    pub theta: f64,
+
+    /// First derivative with respect to the risk-free rate.
+    pub rho: f64,
+}
+
+/// Greeks engine.
+#[derive(Debug, Clone, Copy)]
+pub struct GreeksEngine {
+    /// Finite differences or automatic differentiation.
+    pub method: GreeksMethod,
+
+    /// Spot bump, relative to the spot (default 1%).
+    pub spot_bump: f64,
+
+    /// Absolute volatility bump (default 0.001).
+    pub volatility_bump: f64,
+
+    /// Absolute rate bump (default 0.0001).
+    pub rate_bump: f64,
+
+    /// Time bump in years (default one day).
+    pub time_bump: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<F> GreeksPricer for F
+where
+    F: Fn(&GreeksInputs) -> f64,
+{
+    fn price(&self, inputs: &GreeksInputs) -> f64 {
+        self(inputs)
+    }
+}
+
+impl GreeksInputs {
+    /// New set of market inputs.
+    #[must_use]
+    pub fn new(spot: f64, volatility: f64, risk_free_rate: f64, time_to_expiry: f64) -> Self {
+        Self {
+            spot,
+            volatility,
+            risk_free_rate,
+            time_to_expiry,
+        }
+    }
+}
+
+impl Default for GreeksEngine {
+    fn default() -> Self {
+        Self::new(GreeksMethod::default())
+    }
+}
+
+impl GreeksEngine {
+    /// New Greeks engine with the default bump sizes.
+    #[must_use]
+    pub fn new(method: GreeksMethod) -> Self {
+        Self {
+            method,
+            spot_bump: 0.01,
+            volatility_bump: 0.001,
+            rate_bump: 0.0001,
+            time_bump: 1.0 / 365.0,
+        }
+    }
+
+    /// Set the bump sizes: the relative spot bump and the absolute
+    /// volatility, rate and time bumps.
+    #[must_use]
+    pub fn with_bumps(mut self, spot: f64, volatility: f64, rate: f64, time: f64) -> Self {
+        self.spot_bump = spot;
+        self.volatility_bump = volatility;
+        self.rate_bump = rate;
+        self.time_bump = time;
+        self
+    }
+
+    /// Compute the Greeks of `pricer` at `inputs`.
+    ///
+    /// # Errors
+    ///
+    /// - `RustQuantError::InvalidArgument` if the inputs or bumps are not
+    ///   positive, or if the automatic differentiation method is chosen for
+    ///   a pricer without an autodiff implementation.
+    /// - `RustQuantError::ComputationError` if the pricer returns a
+    ///   non-finite value.
+    pub fn compute<P>(&self, pricer: &P, inputs: &GreeksInputs) -> Result<Greeks, RustQuantError>
+    where
+        P: GreeksPricer + ?Sized,
+    {
+        self.validate(inputs)?;
+
+        let greeks = match self.method {
+            GreeksMethod::FiniteDifference => self.finite_difference(pricer, inputs),
+            GreeksMethod::AutoDiff => self.autodiff(pricer, inputs)?,
+        };
+
+        let values = [
+            greeks.price,
+            greeks.delta,
+            greeks.gamma,
+            greeks.vega,
+            greeks.theta,
+            greeks.rho,
+        ];
+        if values.iter().any(|x| !x.is_finite()) {
+            return Err(RustQuantError::ComputationError(
+                "the pricer returned a non-finite value".to_string(),
+            ));
+        }
+
+        Ok(greeks)
+    }
+
+    fn validate(&self, inputs: &GreeksInputs) -> Result<(), RustQuantError> {
+        if inputs.spot.is_nan() || inputs.spot <= 0.0 {
+            return Err(RustQuantError::InvalidArgument(
+                "the spot must be positive".to_string(),
+            ));
+        }
+        if inputs.volatility.is_nan() || inputs.volatility < 0.0 {
+            return Err(RustQuantError::InvalidArgument(
+                "the volatility must be non-negative".to_string(),
+            ));
+        }
+        if inputs.time_to_expiry.is_nan() || inputs.time_to_expiry <= 0.0 {
+            return Err(RustQuantError::InvalidArgument(
+                "the time to expiry must be positive".to_string(),
+            ));
+        }
+
+        let bumps = [
+            self.spot_bump,
+            self.volatility_bump,
+            self.rate_bump,
+            self.time_bump,
+        ];
+        if bumps.iter().any(|h| h.is_nan() || *h <= 0.0) {
+            return Err(RustQuantError::InvalidArgument(
+                "the bump sizes must be positive".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn finite_difference<P>(&self, pricer: &P, inputs: &GreeksInputs) -> Greeks
+    where
+        P: GreeksPricer + ?Sized,
+    {
+        let price = pricer.price(inputs);
+        let reprice = |f: &dyn Fn(&mut GreeksInputs)| {
+            let mut bumped = *inputs;
+            f(&mut bumped);
+            pricer.price(&bumped)
+        };
+
+        let dS = self.spot_bump * inputs.spot;
+        let up = reprice(&|x| x.spot += dS);
+        let down = reprice(&|x| x.spot -= dS);
+
+        // Keep the volatility non-negative, with a one-sided difference if needed.
+        let dv = self.volatility_bump;
+        let vega = if inputs.volatility > dv {
+            (reprice(&|x| x.volatility += dv) - reprice(&|x| x.volatility -= dv)) / (2.0 * dv)
+        } else {
+            (reprice(&|x| x.volatility += dv) - price) / dv
+        };
+
+        let dr = self.rate_bump;
+        let rho = (reprice(&|x| x.risk_free_rate += dr) - reprice(&|x| x.risk_free_rate -= dr))
+            / (2.0 * dr);
+
+        Greeks {
+            price,
+            delta: (up - down) / (2.0 * dS),
+            gamma: (up - 2.0 * price + down) / (dS * dS),
+            vega,
+            theta: self.theta(pricer, inputs, price),
+            rho,
+        }
+    }
+
+    /// Theta by repricing, one-sided when expiry is within one time bump.
+    fn theta<P>(&self, pricer: &P, inputs: &GreeksInputs, price: f64) -> f64
+    where
+        P: GreeksPricer + ?Sized,
+    {
+        let dt = self.time_bump;
+        let at = |T: f64| {
+            pricer.price(&GreeksInputs {
+                time_to_expiry: T,
+                ..*inputs
+            })
+        };
+
+        let T = inputs.time_to_expiry;
+        if T > dt {
+            (at(T - dt) - at(T + dt)) / (2.0 * dt)
+        } else {
+            (price - at(T + dt)) / dt
+        }
+    }
+
+    fn autodiff<P>(&self, pricer: &P, inputs: &GreeksInputs) -> Result<Greeks, RustQuantError>
+    where
+        P: GreeksPricer + ?Sized,
+    {
+        // Price and gradient [delta, vega, rho, dV/dT] at the given spot.
+        let gradient = |spot: f64| -> Option<(f64, Vec<f64>)> {
+            let graph = Graph::new();
+            let variables = GreeksVariables {
+                spot: graph.var(spot),
+                volatility: graph.var(inputs.volatility),
+                risk_free_rate: graph.var(inputs.risk_free_rate),
+                time_to_expiry: graph.var(inputs.time_to_expiry),
+            };
+
+            let price = pricer.price_autodiff(&variables)?;
+            let adjoints = price.accumulate();
+
+            Some((
+                price.value,
+                adjoints.wrt(&[
+                    variables.spot,
+                    variables.volatility,
+                    variables.risk_free_rate,
+                    variables.time_to_expiry,
+                ]),
+            ))
+        };
+
+        let missing = || {
+            RustQuantError::InvalidArgument(
+                "the pricer has no automatic differentiation implementation".to_string(),
+            )
+        };
+
+        let (price, adjoints) = gradient(inputs.spot).ok_or_else(missing)?;
+
+        let dS = self.spot_bump * inputs.spot;
+        let (_, up) = gradient(inputs.spot + dS).ok_or_else(missing)?;
+        let (_, down) = gradient(inputs.spot - dS).ok_or_else(missing)?;
+
+        Ok(Greeks {
+            price,
+            delta: adjoints[0],
+            gamma: (up[0] - down[0]) / (2.0 * dS),
+            vega: adjoints[1],
+            theta: -adjoints[3],
+            rho: adjoints[2],
+        })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_greeks {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::instruments::options::{PathMonteCarloEngine, PathPayoff};
+    use crate::math::{distributions::Distribution, Gaussian};
+    use std::f64::consts::SQRT_2;
+
+    const K: f64 = 100.0;
+
+    /// Black-Scholes call with strike `K`, on `f64`s and on the autodiff graph.
+    struct BlackScholesCall;
+
+    impl GreeksPricer for BlackScholesCall {
+        fn price(&self, x: &GreeksInputs) -> f64 {
+            let (S, v, r, T) = (x.spot, x.volatility, x.risk_free_rate, x.time_to_expiry);
+            let d1 = ((S / K).ln() + (r + 0.5 * v * v) * T) / (v * T.sqrt());
+            let d2 = d1 - v * T.sqrt();
+            let n = Gaussian::default();
+
+            S * n.cdf(d1) - K * (-r * T).exp() * n.cdf(d2)
+        }
+
+        fn price_autodiff<'v>(&self, x: &GreeksVariables<'v>) -> Option<Variable<'v>> {
+            let (S, v, r, T) = (x.spot, x.volatility, x.risk_free_rate, x.time_to_expiry);
+            let N = |d: Variable<'v>| 0.5 * (-d / SQRT_2).erfc();
+
+            let d1 = ((S / K).ln() + (r + 0.5 * v * v) * T) / (v * T.sqrt());
+            let d2 = d1 - v * T.sqrt();
+
+            Some(S * N(d1) - K * (-r * T).exp() * N(d2))
+        }
+    }
+
+    /// Closed-form Black-Scholes call Greeks.
+    fn closed_form(x: &GreeksInputs) -> Greeks {
+        let (S, v, r, T) = (x.spot, x.volatility, x.risk_free_rate, x.time_to_expiry);
+        let d1 = ((S / K).ln() + (r + 0.5 * v * v) * T) / (v * T.sqrt());
+        let d2 = d1 - v * T.sqrt();
+        let n = Gaussian::default();
+
+        Greeks {
+            price: BlackScholesCall.price(x),
+            delta: n.cdf(d1),
+            gamma: n.pdf(d1) / (S * v * T.sqrt()),
+            vega: S * n.pdf(d1) * T.sqrt(),
+            theta: -S * n.pdf(d1) * v / (2.0 * T.sqrt()) - r * K * (-r * T).exp() * n.cdf(d2),
+            rho: K * T * (-r * T).exp() * n.cdf(d2),
+        }
+    }
+
+    fn assert_greeks(actual: &Greeks, expected: &Greeks, tolerance: f64) {
+        assert_approx_equal!(actual.price, expected.price, tolerance);
+        assert_approx_equal!(actual.delta, expected.delta, tolerance);
+        assert_approx_equal!(actual.gamma, expected.gamma, tolerance);
+        assert_approx_equal!(actual.vega, expected.vega, tolerance);
+        assert_approx_equal!(actual.theta, expected.theta, tolerance);
+        assert_approx_equal!(actual.rho, expected.rho, tolerance);
+    }
+
+    #[test]
+    fn test_greeks_methods_match_closed_form() {
+        let inputs = GreeksInputs::new(105.0, 0.25, 0.05, 0.75);
+        let expected = closed_form(&inputs);
+
+        let bumped = GreeksEngine::new(GreeksMethod::FiniteDifference)
+            .compute(&BlackScholesCall, &inputs)
+            .unwrap();
+        let adjoint = GreeksEngine::new(GreeksMethod::AutoDiff)
+            .compute(&BlackScholesCall, &inputs)
+            .unwrap();
+
+        assert_greeks(&bumped, &expected, 1e-2);
+        assert_greeks(&adjoint, &expected, 1e-3);
+
+        // The first order adjoint Greeks are exact.
+        assert_approx_equal!(adjoint.delta, expected.delta, 1e-10);
+        assert_approx_equal!(adjoint.vega, expected.vega, 1e-8);
+        assert_approx_equal!(adjoint.rho, expected.rho, 1e-8);
+    }
+
+    #[test]
+    fn test_greeks_monte_carlo_pricer() {
+        struct Call;
+
+        impl PathPayoff for Call {
+            fn payoff(&self, path: &[f64]) -> f64 {
+                (path[path.len() - 1] - K).max(0.0)
+            }
+        }
+
+        // Fixed seed, so the bumped prices share their random numbers.
+        let monte_carlo = |x: &GreeksInputs| {
+            PathMonteCarloEngine::new(
+                x.spot,
+                x.risk_free_rate,
+                0.0,
+                x.volatility,
+                x.time_to_expiry,
+                1,
+                200_000,
+                42,
+            )
+            .price(&Call)
+            .value
+        };
+
+        let inputs = GreeksInputs::new(100.0, 0.2, 0.05, 1.0);
+        let greeks = GreeksEngine::default()
+            .with_bumps(0.05, 0.01, 0.01, 0.05)
+            .compute(&monte_carlo, &inputs)
+            .unwrap();
+        let expected = closed_form(&inputs);
+
+        assert!((greeks.delta - expected.delta).abs() < 0.01);
+        assert!((greeks.gamma - expected.gamma).abs() < 0.002);
+        assert!((greeks.vega / expected.vega - 1.0).abs() < 0.02);
+        assert!((greeks.theta / expected.theta - 1.0).abs() < 0.05);
+        assert!((greeks.rho / expected.rho - 1.0).abs() < 0.02);
+
+        // Closures have no autodiff implementation.
+        assert!(GreeksEngine::new(GreeksMethod::AutoDiff)
+            .compute(&monte_carlo, &inputs)
+            .is_err());
+    }
+
+    #[test]
+    fn test_greeks_invalid_inputs() {
+        let engine = GreeksEngine::default();
+
+        assert!(engine
+            .compute(&BlackScholesCall, &GreeksInputs::new(-1.0, 0.2, 0.05, 1.0))
+            .is_err());
+        assert!(engine
+            .compute(&BlackScholesCall, &GreeksInputs::new(100.0, 0.2, 0.05, 0.0))
+            .is_err());
+        assert!(engine
+            .with_bumps(0.0, 0.001, 0.0001, 0.01)
+            .compute(&BlackScholesCall, &GreeksInputs::new(100.0, 0.2, 0.05, 1.0))
+            .is_err());
+    }
+}
@@ -1054,7 +1054,11 @@ pub(crate) fn implied_volatility_from_a_transformed_rational_guess_with_limited_
 #[cfg(test)]
 mod test_lets_be_rational {
     use super::*;
-    use crate::{assert_approx_equal, instruments::BlackScholesMerton, time::today};
+    use crate::{
+        assert_approx_equal,
+        instruments::{BlackScholesMerton, Maturity},
+        time::today,
+    };
     use std::panic;
     use time::Duration;
 
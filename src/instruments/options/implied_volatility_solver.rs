@@ -0,0 +1,473 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Implied volatilities of European option quotes under the Black-Scholes,
+//! Black-76 and Bachelier models.
+//!
+//! All three models price an option off the forward of its underlying:
+//! Black-Scholes quotes are converted to Black-76 ones with the forward
+//! $F = S e^{(r - q) T}$ (see [`OptionQuote::from_spot`]).
+//!
+//! Lognormal volatilities start from Jäckel's "Let's Be Rational" inversion
+//! (see [`implied_volatility`](super::implied_volatility())), and normal
+//! volatilities from the at-the-money approximation
+//! $\sigma \approx \sqrt{2 \pi / T} \, c$ of the time value $c$. Both are then
+//! polished by Newton's method safeguarded by a bracket (see
+//! [`NewtonRaphsonSolver`]): a step that leaves the bracket, or is taken
+//! where the vega vanishes (far in the wings), is replaced by bisection, so
+//! the solver converges for every quote with an implied volatility.
+
+use super::{
+    implied_volatility::implied_volatility_from_a_transformed_rational_guess_with_limited_iterations,
+    TypeFlag,
+};
+use crate::error::RustQuantError;
+use crate::math::distributions::{Distribution, Gaussian};
+use crate::math::rootfinding::{NewtonRaphsonSolver, SolverSettings};
+use rayon::prelude::*;
+
+/// Maximum number of iterations of the Newton-Raphson solver.
+const MAX_ITERATIONS: usize = 200;
+
+/// Relative tolerance on the undiscounted price.
+const PRICE_TOLERANCE: f64 = 1e-12;
+
+/// Relative width of the volatility bracket at which the solver stops.
+const BRACKET_TOLERANCE: f64 = 1e-15;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Model of the forward under which an implied volatility is quoted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImpliedVolatilityModel {
+    /// Black-76 (or Black-Scholes): a lognormal forward, with lognormal
+    /// volatilities.
+    Black,
+
+    /// Bachelier: a normal forward, with normal (absolute) volatilities.
+    Bachelier,
+}
+
+/// Market price of a European option on a forward.
+#[derive(Debug, Clone, Copy)]
+pub struct OptionQuote {
+    /// Discounted price of the option.
+    pub price: f64,
+
+    /// `F` - Forward price of the underlying for delivery at expiry.
+    pub forward: f64,
+
+    /// `K` - Strike price.
+    pub strike: f64,
+
+    /// `T` - Time to expiry (in years).
+    pub expiry: f64,
+
+    /// Discount factor from the payment date of the option.
+    pub discount_factor: f64,
+
+    /// Call or put.
+    pub option_type: TypeFlag,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl ImpliedVolatilityModel {
+    /// Undiscounted price of a European call or put on a forward, for a
+    /// non-negative volatility (and under the Black model a positive forward
+    /// and strike).
+    fn undiscounted_price(
+        self,
+        option_type: TypeFlag,
+        forward: f64,
+        strike: f64,
+        volatility: f64,
+        expiry: f64,
+    ) -> f64 {
+        let phi = match option_type {
+            TypeFlag::Call => 1.0,
+            TypeFlag::Put => -1.0,
+        };
+        let std_dev = volatility * expiry.sqrt();
+
+        if std_dev == 0.0 {
+            return (phi * (forward - strike)).max(0.0);
+        }
+
+        let n = Gaussian::default();
+
+        match self {
+            Self::Black => {
+                let d1 = ((forward / strike).ln() + 0.5 * std_dev * std_dev) / std_dev;
+                let d2 = d1 - std_dev;
+
+                phi * (forward * n.cdf(phi * d1) - strike * n.cdf(phi * d2))
+            }
+            Self::Bachelier => {
+                let d = (forward - strike) / std_dev;
+
+                phi * (forward - strike) * n.cdf(phi * d) + std_dev * n.pdf(d)
+            }
+        }
+    }
+}
+
+impl OptionQuote {
+    /// New quote of an option on a forward (Black-76 or Bachelier).
+    #[must_use]
+    pub fn new(
+        price: f64,
+        forward: f64,
+        strike: f64,
+        expiry: f64,
+        discount_factor: f64,
+        option_type: TypeFlag,
+    ) -> Self {
+        Self {
+            price,
+            forward,
+            strike,
+            expiry,
+            discount_factor,
+            option_type,
+        }
+    }
+
+    /// New quote of an option on a spot with a continuous dividend yield
+    /// (Black-Scholes), as a quote on its forward.
+    #[must_use]
+    pub fn from_spot(
+        price: f64,
+        spot: f64,
+        strike: f64,
+        expiry: f64,
+        risk_free_rate: f64,
+        dividend_yield: f64,
+        option_type: TypeFlag,
+    ) -> Self {
+        Self::new(
+            price,
+            spot * f64::exp((risk_free_rate - dividend_yield) * expiry),
+            strike,
+            expiry,
+            f64::exp(-risk_free_rate * expiry),
+            option_type,
+        )
+    }
+
+    /// Implied volatility of the quote under the given model: lognormal for
+    /// [`ImpliedVolatilityModel::Black`], normal for [`ImpliedVolatilityModel::Bachelier`].
+    /// A price at intrinsic value has an implied volatility of zero.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the expiry or discount factor
+    ///   is not positive, under the Black model the forward or strike is not
+    ///   positive, or the price is below the intrinsic value or (under the
+    ///   Black model) not below its upper bound.
+    /// - `RustQuantError::NotConverged` if the solver does not converge.
+    pub fn implied_volatility(&self, model: ImpliedVolatilityModel) -> Result<f64, RustQuantError> {
+        self.validate(model)?;
+
+        let (forward, strike, expiry) = (self.forward, self.strike, self.expiry);
+        let phi = match self.option_type {
+            TypeFlag::Call => 1.0,
+            TypeFlag::Put => -1.0,
+        };
+        let target = self.price / self.discount_factor;
+        let intrinsic = (phi * (forward - strike)).max(0.0);
+        let scale = target.abs().max(f64::MIN_POSITIVE);
+
+        if target < intrinsic - PRICE_TOLERANCE * scale {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the price ({target}) is below the intrinsic value ({intrinsic})"
+            )));
+        }
+        if target <= intrinsic {
+            return Ok(0.0);
+        }
+
+        let guess = match model {
+            ImpliedVolatilityModel::Black => {
+                let upper_bound = if phi > 0.0 { forward } else { strike };
+
+                if target >= upper_bound {
+                    return Err(RustQuantError::InvalidArgument(format!(
+                        "the price ({target}) is not below its upper bound ({upper_bound})"
+                    )));
+                }
+
+                implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
+                    target, forward, strike, expiry, phi,
+                )
+            }
+            ImpliedVolatilityModel::Bachelier => {
+                (target - intrinsic) * (2.0 * std::f64::consts::PI / expiry).sqrt()
+            }
+        };
+
+        let price =
+            |sigma: f64| model.undiscounted_price(self.option_type, forward, strike, sigma, expiry);
+        let vega = |sigma: f64| {
+            let std_dev = sigma * expiry.sqrt();
+            let n = Gaussian::default();
+
+            match model {
+                ImpliedVolatilityModel::Black => {
+                    forward
+                        * n.pdf((forward / strike).ln() / std_dev + 0.5 * std_dev)
+                        * expiry.sqrt()
+                }
+                ImpliedVolatilityModel::Bachelier => {
+                    n.pdf((forward - strike) / std_dev) * expiry.sqrt()
+                }
+            }
+        };
+
+        solve_volatility(target, price, vega, guess)
+    }
+
+    /// Check the inputs of the quote.
+    fn validate(&self, model: ImpliedVolatilityModel) -> Result<(), RustQuantError> {
+        let positive = [
+            ("time to expiry", self.expiry),
+            ("discount factor", self.discount_factor),
+        ];
+
+        for (name, value) in positive {
+            if value.is_nan() || value <= 0.0 {
+                return Err(RustQuantError::InvalidArgument(format!(
+                    "the {name} must be positive, not {value}"
+                )));
+            }
+        }
+
+        if model == ImpliedVolatilityModel::Black && !(self.forward > 0.0 && self.strike > 0.0) {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the Black model needs a positive forward ({}) and strike ({})",
+                self.forward, self.strike
+            )));
+        }
+        if !self.price.is_finite() {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the price must be finite, not {}",
+                self.price
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Implied volatilities of a slice of quotes under the given model,
+/// solved in parallel. Each quote gets its own result, so a bad quote does
+/// not fail the others.
+#[must_use]
+pub fn implied_volatilities(
+    quotes: &[OptionQuote],
+    model: ImpliedVolatilityModel,
+) -> Vec<Result<f64, RustQuantError>> {
+    quotes
+        .par_iter()
+        .map(|quote| quote.implied_volatility(model))
+        .collect()
+}
+
+/// Volatility at which the increasing function `price` reaches `target`,
+/// by Newton's method from `guess`, safeguarded by bisection.
+fn solve_volatility<P, V>(target: f64, price: P, vega: V, guess: f64) -> Result<f64, RustQuantError>
+where
+    P: Fn(f64) -> f64,
+    V: Fn(f64) -> f64,
+{
+    // Grow the bracket [0, upper] until it contains the root.
+    let mut upper = if guess.is_finite() && guess > 0.0 {
+        2.0 * guess
+    } else {
+        1.0
+    };
+
+    while price(upper) < target {
+        upper *= 2.0;
+
+        if upper > 1e12 {
+            return Err(RustQuantError::NotConverged(format!(
+                "no volatility reaches the price {target}"
+            )));
+        }
+    }
+
+    // Solve for a relative pricing error, so deep out-of-the-money quotes
+    // are matched as closely as at-the-money ones.
+    let settings = SolverSettings::new(BRACKET_TOLERANCE * upper, PRICE_TOLERANCE, MAX_ITERATIONS);
+
+    NewtonRaphsonSolver::new(settings).solve_with_derivative(
+        |sigma| price(sigma) / target - 1.0,
+        |sigma| vega(sigma) / target,
+        guess,
+        0.0,
+        upper,
+    )
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_implied_volatility_solver {
+    use super::*;
+    use crate::assert_approx_equal;
+
+    fn quote(
+        model: ImpliedVolatilityModel,
+        forward: f64,
+        strike: f64,
+        sigma: f64,
+        expiry: f64,
+        option_type: TypeFlag,
+    ) -> OptionQuote {
+        let df = f64::exp(-0.03 * expiry);
+        let price = df * model.undiscounted_price(option_type, forward, strike, sigma, expiry);
+
+        OptionQuote::new(price, forward, strike, expiry, df, option_type)
+    }
+
+    #[test]
+    fn test_round_trip_including_the_wings() {
+        for (expiry, sigma) in [(0.02, 0.15), (1.0, 0.3), (10.0, 0.8), (30.0, 1.5)] {
+            for moneyness in [-4.0, -2.0, -0.5, 0.0, 0.5, 2.0, 4.0] {
+                // Out-of-the-money options, several standard deviations into
+                // the wings (in the money, the time value is lost in rounding).
+                let strike = 100.0 * f64::exp(moneyness * sigma * f64::sqrt(expiry));
+                let option_type = if strike >= 100.0 {
+                    TypeFlag::Call
+                } else {
+                    TypeFlag::Put
+                };
+
+                let black = quote(
+                    ImpliedVolatilityModel::Black,
+                    100.0,
+                    strike,
+                    sigma,
+                    expiry,
+                    option_type,
+                );
+                assert_approx_equal!(
+                    black
+                        .implied_volatility(ImpliedVolatilityModel::Black)
+                        .unwrap(),
+                    sigma,
+                    1e-8 * sigma
+                );
+            }
+
+            // Normal volatilities, around a negative forward.
+            let normal = 0.01 * sigma;
+            for moneyness in [-4.0, -1.0, 0.0, 1.0, 4.0] {
+                let strike = -0.01 + moneyness * normal * f64::sqrt(expiry);
+
+                for option_type in [TypeFlag::Call, TypeFlag::Put] {
+                    let bachelier = quote(
+                        ImpliedVolatilityModel::Bachelier,
+                        -0.01,
+                        strike,
+                        normal,
+                        expiry,
+                        option_type,
+                    );
+                    assert_approx_equal!(
+                        bachelier
+                            .implied_volatility(ImpliedVolatilityModel::Bachelier)
+                            .unwrap(),
+                        normal,
+                        1e-8 * normal
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_black_scholes_quotes() {
+        // The quote of the Let's Be Rational example.
+        let quote = OptionQuote::from_spot(12.3, 100.0, 110.0, 0.89, 0.03, 0.0, TypeFlag::Call);
+
+        assert_approx_equal!(
+            quote
+                .implied_volatility(ImpliedVolatilityModel::Black)
+                .unwrap(),
+            super::super::implied_volatility(12.3, 100.0, 110.0, 0.89, 0.03, TypeFlag::Call),
+            1e-12
+        );
+
+        // A dividend yield lowers the forward, so the same price implies a
+        // higher volatility.
+        let with_yield =
+            OptionQuote::from_spot(12.3, 100.0, 110.0, 0.89, 0.03, 0.02, TypeFlag::Call);
+        assert!(
+            with_yield
+                .implied_volatility(ImpliedVolatilityModel::Black)
+                .unwrap()
+                > quote
+                    .implied_volatility(ImpliedVolatilityModel::Black)
+                    .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_implied_volatilities_of_a_slice() {
+        let mut quotes: Vec<OptionQuote> = (50..=150)
+            .map(|strike| {
+                quote(
+                    ImpliedVolatilityModel::Black,
+                    100.0,
+                    f64::from(strike),
+                    0.2 + 0.001 * (f64::from(strike) - 100.0).abs(),
+                    0.5,
+                    TypeFlag::Put,
+                )
+            })
+            .collect();
+
+        // A price below intrinsic value, and one above the upper bound.
+        quotes.push(OptionQuote::new(5.0, 100.0, 110.0, 0.5, 1.0, TypeFlag::Put));
+        quotes.push(OptionQuote::new(
+            101.0,
+            100.0,
+            110.0,
+            0.5,
+            1.0,
+            TypeFlag::Call,
+        ));
+
+        let vols = implied_volatilities(&quotes, ImpliedVolatilityModel::Black);
+        assert_eq!(vols.len(), quotes.len());
+
+        for (strike, vol) in (50..=150).zip(&vols) {
+            let sigma = 0.2 + 0.001 * (f64::from(strike) - 100.0).abs();
+            assert_approx_equal!(*vol.as_ref().unwrap(), sigma, 1e-10);
+        }
+        assert!(vols[vols.len() - 2].is_err());
+        assert!(vols[vols.len() - 1].is_err());
+
+        // At intrinsic value, the implied volatility is zero.
+        assert_approx_equal!(
+            OptionQuote::new(10.0, 100.0, 110.0, 0.5, 1.0, TypeFlag::Put)
+                .implied_volatility(ImpliedVolatilityModel::Bachelier)
+                .unwrap(),
+            0.0,
+            1e-15
+        );
+    }
+}
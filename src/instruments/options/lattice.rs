@@ -0,0 +1,752 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Binomial and trinomial lattice pricers for European and American vanilla
+//! options, with discrete cash and proportional dividends.
+//!
+//! The lattices are:
+//! - Cox-Ross-Rubinstein: $u = e^{\sigma \sqrt{\Delta t}}$, $d = 1/u$.
+//! - Jarrow-Rudd (equal probabilities): $u, d = e^{(b - \sigma^2/2) \Delta t \pm \sigma \sqrt{\Delta t}}$, $p = 1/2$.
+//! - Tian (moment matching), which also matches the third moment of the
+//!   log-normal step.
+//! - Boyle's trinomial lattice: $u = e^{\sigma \sqrt{2 \Delta t}}$, $d = 1/u$,
+//!   and a middle branch that keeps the price unchanged.
+//!
+//! Discrete dividends use the escrowed dividend model: the lattice is built
+//! on the spot less the present value of the dividends paid before expiry,
+//! and the present value of the dividends still to be paid is added back at
+//! each node. The volatility is that of this "dividend-free" part, which
+//! also drops by the rate of each proportional dividend on its ex-date (see
+//! [`DividendSchedule`]).
+
+use crate::error::RustQuantError;
+use crate::instruments::{
+    options::{CashDividend, DividendSchedule, ExerciseFlag, ProportionalDividend, TypeFlag},
+    PricingResult,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// (Spot, value) pairs of the nodes of a time step, from the lowest node up.
+type Nodes = Vec<(f64, f64)>;
+
+/// Construction of the lattice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LatticeMethod {
+    /// Cox-Ross-Rubinstein (1979) binomial lattice.
+    #[default]
+    CoxRossRubinstein,
+
+    /// Jarrow-Rudd (1983) equal probability binomial lattice.
+    JarrowRudd,
+
+    /// Tian (1993) moment matching binomial lattice.
+    Tian,
+
+    /// Boyle (1986) trinomial lattice.
+    Trinomial,
+}
+
+/// Greeks of a [`LatticeOption`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatticeGreeks {
+    /// First derivative of the price with respect to the spot.
+    pub delta: f64,
+
+    /// Second derivative of the price with respect to the spot.
+    pub gamma: f64,
+
+    /// Derivative of the price with respect to the passage of time (per year).
+    pub theta: f64,
+
+    /// Derivative of the price with respect to the volatility.
+    pub vega: f64,
+
+    /// Derivative of the price with respect to the risk-free rate.
+    pub rho: f64,
+}
+
+/// European or American vanilla option priced on a lattice.
+#[derive(Debug, Clone)]
+pub struct LatticeOption {
+    /// `S` - Initial price of the underlying.
+    pub initial_price: f64,
+
+    /// `K` - Strike price.
+    pub strike_price: f64,
+
+    /// `r` - Risk-free rate parameter.
+    pub risk_free_rate: f64,
+
+    /// `q` - Continuous dividend yield.
+    pub dividend_yield: f64,
+
+    /// `v` - Volatility parameter.
+    pub volatility: f64,
+
+    /// `T` - Time to expiry/maturity.
+    pub time_to_expiry: f64,
+
+    /// Call or put.
+    pub option_type: TypeFlag,
+
+    /// European or American exercise.
+    pub exercise_flag: ExerciseFlag,
+
+    /// Construction of the lattice.
+    pub method: LatticeMethod,
+
+    /// Number of time steps of the lattice.
+    pub steps: usize,
+
+    /// Discrete cash dividends; those outside $(0, T]$ are ignored.
+    pub dividends: Vec<CashDividend>,
+
+    /// Discrete proportional dividends; those outside $(0, T]$ are ignored.
+    pub proportional_dividends: Vec<ProportionalDividend>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl LatticeOption {
+    /// Create a new option, on a 500 step Cox-Ross-Rubinstein lattice and
+    /// without discrete dividends.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        initial_price: f64,
+        strike_price: f64,
+        risk_free_rate: f64,
+        dividend_yield: f64,
+        volatility: f64,
+        time_to_expiry: f64,
+        option_type: TypeFlag,
+        exercise_flag: ExerciseFlag,
+    ) -> Self {
+        Self {
+            initial_price,
+            strike_price,
+            risk_free_rate,
+            dividend_yield,
+            volatility,
+            time_to_expiry,
+            option_type,
+            exercise_flag,
+            method: LatticeMethod::default(),
+            steps: 500,
+            dividends: Vec::new(),
+            proportional_dividends: Vec::new(),
+        }
+    }
+
+    /// Use the given lattice construction.
+    #[must_use]
+    pub fn with_method(mut self, method: LatticeMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Use the given number of time steps.
+    #[must_use]
+    pub fn with_steps(mut self, steps: usize) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    /// Add discrete cash dividends.
+    #[must_use]
+    pub fn with_dividends(mut self, dividends: Vec<CashDividend>) -> Self {
+        self.dividends = dividends;
+        self
+    }
+
+    /// Use the dividend yield, cash dividends and proportional dividends of
+    /// the schedule.
+    #[must_use]
+    pub fn with_dividend_schedule(mut self, schedule: &DividendSchedule) -> Self {
+        self.dividend_yield = schedule.dividend_yield;
+        self.dividends = schedule.cash.clone();
+        self.proportional_dividends = schedule.proportional.clone();
+        self
+    }
+
+    /// Price of the option.
+    ///
+    /// The result carries the number of steps, and a warning if a branch
+    /// probability is outside $[0, 1]$, i.e. the step is too large for the
+    /// carry and volatility and the lattice admits arbitrage.
+    ///
+    /// # Errors
+    ///
+    /// - `RustQuantError::InvalidArgument` if the exercise is Bermudan, if
+    ///   there are fewer than two steps, if the spot, strike, volatility or
+    ///   time to expiry is not positive, or if the dividends are worth more
+    ///   than the spot.
+    pub fn price(&self) -> Result<PricingResult, RustQuantError> {
+        let (levels, warnings) = self.roll_back()?;
+
+        let mut result = PricingResult::new(levels[0][0].1).with_iterations(self.steps);
+        result.warnings = warnings;
+
+        Ok(result)
+    }
+
+    /// Greeks of the option.
+    ///
+    /// Delta and gamma are read from the nodes of the first steps of the
+    /// lattice. Theta, vega and rho are central differences of the prices of
+    /// perturbed lattices: by 2% of the time to expiry (moving the dividend
+    /// dates with it), by a volatility point, and by a percentage point of
+    /// the rate. The bumps are wide so as to average out the oscillation of
+    /// the lattice prices with the position of the strike between nodes.
+    ///
+    /// # Errors
+    ///
+    /// As for [`LatticeOption::price`].
+    pub fn greeks(&self) -> Result<LatticeGreeks, RustQuantError> {
+        let (levels, _) = self.roll_back()?;
+
+        // Three nodes around the spot: at the first step of a trinomial
+        // lattice, and at the second step of a binomial one.
+        let (first, near) = match self.method {
+            LatticeMethod::Trinomial => ([levels[1][0], levels[1][2]], &levels[1]),
+            _ => ([levels[1][0], levels[1][1]], &levels[2]),
+        };
+
+        let slope = |a: (f64, f64), b: (f64, f64)| (b.1 - a.1) / (b.0 - a.0);
+
+        let delta = slope(first[0], first[1]);
+        let gamma =
+            (slope(near[1], near[2]) - slope(near[0], near[1])) / (0.5 * (near[2].0 - near[0].0));
+
+        let price =
+            |option: &Self| -> Result<f64, RustQuantError> { Ok(option.roll_back()?.0[0][0].1) };
+
+        let h = 0.02 * self.time_to_expiry;
+        let theta = (price(&self.aged(h))? - price(&self.aged(-h))?) / (2.0 * h);
+
+        let h = f64::min(0.01, 0.5 * self.volatility);
+        let vega = (price(&Self {
+            volatility: self.volatility + h,
+            ..self.clone()
+        })? - price(&Self {
+            volatility: self.volatility - h,
+            ..self.clone()
+        })?) / (2.0 * h);
+
+        let h = 0.01;
+        let rho = (price(&Self {
+            risk_free_rate: self.risk_free_rate + h,
+            ..self.clone()
+        })? - price(&Self {
+            risk_free_rate: self.risk_free_rate - h,
+            ..self.clone()
+        })?) / (2.0 * h);
+
+        Ok(LatticeGreeks {
+            delta,
+            gamma,
+            theta,
+            vega,
+            rho,
+        })
+    }
+
+    /// The same option, `h` years later.
+    fn aged(&self, h: f64) -> Self {
+        Self {
+            time_to_expiry: self.time_to_expiry - h,
+            dividends: self
+                .dividends
+                .iter()
+                .map(|dividend| CashDividend {
+                    time: dividend.time - h,
+                    amount: dividend.amount,
+                })
+                .collect(),
+            proportional_dividends: self
+                .proportional_dividends
+                .iter()
+                .map(|dividend| ProportionalDividend {
+                    time: dividend.time - h,
+                    rate: dividend.rate,
+                })
+                .collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Present value at time `t` of the dividends paid after `t` and up to expiry.
+    fn dividends_after(&self, t: f64) -> f64 {
+        self.dividends
+            .iter()
+            .filter(|dividend| dividend.time > t && dividend.time <= self.time_to_expiry)
+            .map(|dividend| dividend.amount * f64::exp(-self.risk_free_rate * (dividend.time - t)))
+            .sum()
+    }
+
+    /// Fraction of the dividend-free part of the spot left at time `t`
+    /// after the proportional dividends paid up to `t`.
+    fn proportional_factor(&self, t: f64) -> f64 {
+        self.proportional_dividends
+            .iter()
+            .filter(|dividend| dividend.time > 0.0 && dividend.time <= t)
+            .map(|dividend| 1.0 - dividend.rate)
+            .product()
+    }
+
+    /// Up and down factors and the branch probabilities (from the lowest
+    /// branch up) of a step of length `dt`.
+    fn branches(&self, dt: f64) -> (f64, f64, Vec<f64>) {
+        let b = self.risk_free_rate - self.dividend_yield;
+        let v = self.volatility;
+
+        match self.method {
+            LatticeMethod::CoxRossRubinstein => {
+                let u = f64::exp(v * dt.sqrt());
+                let d = 1.0 / u;
+                let p = (f64::exp(b * dt) - d) / (u - d);
+
+                (u, d, vec![1.0 - p, p])
+            }
+            LatticeMethod::JarrowRudd => {
+                let drift = (b - 0.5 * v * v) * dt;
+                let u = f64::exp(drift + v * dt.sqrt());
+                let d = f64::exp(drift - v * dt.sqrt());
+
+                (u, d, vec![0.5, 0.5])
+            }
+            LatticeMethod::Tian => {
+                let m = f64::exp(b * dt);
+                let w = f64::exp(v * v * dt);
+                let root = (w * w + 2.0 * w - 3.0).sqrt();
+                let u = 0.5 * m * w * (w + 1.0 + root);
+                let d = 0.5 * m * w * (w + 1.0 - root);
+                let p = (m - d) / (u - d);
+
+                (u, d, vec![1.0 - p, p])
+            }
+            LatticeMethod::Trinomial => {
+                let u = f64::exp(v * (2.0 * dt).sqrt());
+                let half_up = f64::exp(v * (0.5 * dt).sqrt());
+                let half_carry = f64::exp(0.5 * b * dt);
+                let p_up = ((half_carry - 1.0 / half_up) / (half_up - 1.0 / half_up)).powi(2);
+                let p_down = ((half_up - half_carry) / (half_up - 1.0 / half_up)).powi(2);
+
+                (u, 1.0 / u, vec![p_down, 1.0 - p_up - p_down, p_up])
+            }
+        }
+    }
+
+    /// Backward induction through the lattice, returning the (spot, value)
+    /// pairs of the nodes of the first three time steps, and any warnings.
+    fn roll_back(&self) -> Result<(Vec<Nodes>, Vec<String>), RustQuantError> {
+        self.validate()?;
+
+        let n = self.steps;
+        let dt = self.time_to_expiry / n as f64;
+        let discount = f64::exp(-self.risk_free_rate * dt);
+        let (u, d, probabilities) = self.branches(dt);
+
+        let mut warnings = Vec::new();
+
+        if probabilities.iter().any(|p| !(0.0..=1.0).contains(p)) {
+            warnings.push(format!(
+                "the branch probabilities {probabilities:?} are not all in [0, 1]: \
+                use more than {n} steps"
+            ));
+        }
+
+        let escrowed = self.initial_price - self.dividends_after(0.0);
+        let trinomial = self.method == LatticeMethod::Trinomial;
+
+        // Spot at node `j` of step `i`, counting from the lowest node.
+        let spot = |i: usize, j: usize| -> f64 {
+            let (ups, downs) = if trinomial {
+                (j.saturating_sub(i), i.saturating_sub(j))
+            } else {
+                (j, i - j)
+            };
+
+            escrowed
+                * self.proportional_factor(i as f64 * dt)
+                * u.powi(ups as i32)
+                * d.powi(downs as i32)
+                + self.dividends_after(i as f64 * dt)
+        };
+        let n_nodes = |i: usize| if trinomial { 2 * i + 1 } else { i + 1 };
+
+        let sign = match self.option_type {
+            TypeFlag::Call => 1.0,
+            TypeFlag::Put => -1.0,
+        };
+        let payoff = |s: f64| (sign * (s - self.strike_price)).max(0.0);
+        let american = matches!(self.exercise_flag, ExerciseFlag::American);
+
+        let mut values: Vec<f64> = (0..n_nodes(n)).map(|j| payoff(spot(n, j))).collect();
+        let mut levels = vec![Vec::new(); 3];
+
+        for i in (0..n).rev() {
+            values = (0..n_nodes(i))
+                .map(|j| {
+                    let continuation = discount
+                        * probabilities
+                            .iter()
+                            .zip(&values[j..])
+                            .map(|(p, v)| p * v)
+                            .sum::<f64>();
+
+                    if american {
+                        continuation.max(payoff(spot(i, j)))
+                    } else {
+                        continuation
+                    }
+                })
+                .collect();
+
+            if i < 3 {
+                levels[i] = (0..n_nodes(i)).map(|j| (spot(i, j), values[j])).collect();
+            }
+        }
+
+        Ok((levels, warnings))
+    }
+
+    /// Check that the option can be priced on a lattice.
+    fn validate(&self) -> Result<(), RustQuantError> {
+        if matches!(self.exercise_flag, ExerciseFlag::Bermudan) {
+            return Err(RustQuantError::InvalidArgument(
+                "lattice pricing of Bermudan options is not supported".to_string(),
+            ));
+        }
+        if self.steps < 2 {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the lattice needs at least 2 steps, not {}",
+                self.steps
+            )));
+        }
+
+        let positive = [
+            ("spot", self.initial_price),
+            ("strike", self.strike_price),
+            ("volatility", self.volatility),
+            ("time to expiry", self.time_to_expiry),
+        ];
+
+        for (name, value) in positive {
+            if value.is_nan() || value <= 0.0 {
+                return Err(RustQuantError::InvalidArgument(format!(
+                    "the {name} must be positive, not {value}"
+                )));
+            }
+        }
+
+        if self.dividends_after(0.0) >= self.initial_price {
+            return Err(RustQuantError::InvalidArgument(
+                "the dividends are worth more than the spot".to_string(),
+            ));
+        }
+        if self
+            .proportional_dividends
+            .iter()
+            .any(|dividend| !(0.0..1.0).contains(&dividend.rate))
+        {
+            return Err(RustQuantError::InvalidArgument(
+                "the proportional dividend rates must be in [0, 1)".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_lattice {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::math::distributions::{Distribution, Gaussian};
+
+    const METHODS: [LatticeMethod; 4] = [
+        LatticeMethod::CoxRossRubinstein,
+        LatticeMethod::JarrowRudd,
+        LatticeMethod::Tian,
+        LatticeMethod::Trinomial,
+    ];
+
+    /// Black-Scholes price with dividend yield `q`.
+    fn black_scholes(s: f64, k: f64, r: f64, q: f64, v: f64, t: f64, call: bool) -> f64 {
+        let n = Gaussian::default();
+        let d1 = ((s / k).ln() + (r - q + 0.5 * v * v) * t) / (v * t.sqrt());
+        let d2 = d1 - v * t.sqrt();
+
+        if call {
+            s * f64::exp(-q * t) * n.cdf(d1) - k * f64::exp(-r * t) * n.cdf(d2)
+        } else {
+            k * f64::exp(-r * t) * n.cdf(-d2) - s * f64::exp(-q * t) * n.cdf(-d1)
+        }
+    }
+
+    #[test]
+    fn test_european_lattices_converge_to_black_scholes() {
+        let call = black_scholes(100.0, 95.0, 0.05, 0.02, 0.25, 0.75, true);
+        let put = black_scholes(100.0, 95.0, 0.05, 0.02, 0.25, 0.75, false);
+
+        for method in METHODS {
+            let option = |option_type| {
+                LatticeOption::new(
+                    100.0,
+                    95.0,
+                    0.05,
+                    0.02,
+                    0.25,
+                    0.75,
+                    option_type,
+                    ExerciseFlag::European,
+                )
+                .with_method(method)
+                .with_steps(1_000)
+                .price()
+                .unwrap()
+            };
+
+            let (lattice_call, lattice_put) = (option(TypeFlag::Call), option(TypeFlag::Put));
+
+            assert_approx_equal!(lattice_call.value, call, 1e-2);
+            assert_approx_equal!(lattice_put.value, put, 1e-2);
+            assert_eq!(lattice_call.iterations, Some(1_000));
+            assert!(!lattice_call.has_warnings());
+        }
+    }
+
+    #[test]
+    fn test_american_put() {
+        // S = 36, K = 40, r = 0.06, v = 0.2, T = 1: the American price is
+        // 4.4866 (Longstaff and Schwartz (2001) find 4.478 with 50 exercise
+        // dates per year).
+        for method in METHODS {
+            let american = LatticeOption::new(
+                36.0,
+                40.0,
+                0.06,
+                0.0,
+                0.2,
+                1.0,
+                TypeFlag::Put,
+                ExerciseFlag::American,
+            )
+            .with_method(method)
+            .with_steps(2_000)
+            .price()
+            .unwrap();
+
+            assert_approx_equal!(american.value, 4.4866, 2e-3);
+        }
+
+        // Without dividends, an American call is never exercised early.
+        let call = |exercise_flag| {
+            LatticeOption::new(
+                36.0,
+                40.0,
+                0.06,
+                0.0,
+                0.2,
+                1.0,
+                TypeFlag::Call,
+                exercise_flag,
+            )
+            .price()
+            .unwrap()
+            .value
+        };
+
+        assert_approx_equal!(
+            call(ExerciseFlag::American),
+            call(ExerciseFlag::European),
+            1e-10
+        );
+    }
+
+    #[test]
+    fn test_discrete_dividends() {
+        let dividends = vec![
+            CashDividend {
+                time: 0.25,
+                amount: 2.0,
+            },
+            CashDividend {
+                time: 0.75,
+                amount: 2.0,
+            },
+            // After expiry: ignored.
+            CashDividend {
+                time: 1.5,
+                amount: 50.0,
+            },
+        ];
+        let option = |exercise_flag| {
+            LatticeOption::new(
+                100.0,
+                100.0,
+                0.05,
+                0.0,
+                0.3,
+                1.0,
+                TypeFlag::Call,
+                exercise_flag,
+            )
+            .with_steps(1_000)
+            .with_dividends(dividends.clone())
+            .price()
+            .unwrap()
+            .value
+        };
+
+        // Escrowed model: a European option on the spot less the dividends.
+        let escrowed = 100.0 - 2.0 * f64::exp(-0.05 * 0.25) - 2.0 * f64::exp(-0.05 * 0.75);
+        let european = black_scholes(escrowed, 100.0, 0.05, 0.0, 0.3, 1.0, true);
+
+        assert_approx_equal!(option(ExerciseFlag::European), european, 2e-2);
+
+        // Exercise just before a large dividend.
+        let large = LatticeOption::new(
+            100.0,
+            90.0,
+            0.05,
+            0.0,
+            0.2,
+            1.0,
+            TypeFlag::Call,
+            ExerciseFlag::American,
+        )
+        .with_dividends(vec![CashDividend {
+            time: 0.5,
+            amount: 10.0,
+        }]);
+        let european = LatticeOption {
+            exercise_flag: ExerciseFlag::European,
+            ..large.clone()
+        };
+
+        assert!(large.price().unwrap().value > european.price().unwrap().value + 0.5);
+    }
+
+    #[test]
+    fn test_dividend_schedule() {
+        let schedule = DividendSchedule::new()
+            .with_dividend_yield(0.01)
+            .with_cash_dividend(0.25, 2.0)
+            .with_proportional_dividend(0.5, 0.03)
+            .with_cash_dividend(0.75, 2.0);
+        let option = |exercise_flag| {
+            LatticeOption::new(
+                100.0,
+                100.0,
+                0.05,
+                0.0,
+                0.3,
+                1.0,
+                TypeFlag::Put,
+                exercise_flag,
+            )
+            .with_steps(1_000)
+            .with_dividend_schedule(&schedule)
+        };
+
+        // Escrowed model: a European option on the adjusted spot.
+        let spot = schedule.adjusted_spot(100.0, 0.05, 1.0);
+        let european = black_scholes(spot, 100.0, 0.05, 0.01, 0.3, 1.0, false);
+
+        assert_approx_equal!(
+            option(ExerciseFlag::European).price().unwrap().value,
+            european,
+            2e-2
+        );
+        assert!(
+            option(ExerciseFlag::American).price().unwrap().value
+                > option(ExerciseFlag::European).price().unwrap().value
+        );
+
+        let mut invalid = option(ExerciseFlag::European);
+        invalid.proportional_dividends[0].rate = 1.0;
+        assert!(invalid.price().is_err());
+    }
+
+    #[test]
+    fn test_greeks_match_black_scholes() {
+        let (s, k, r, q, v, t) = (100.0, 105.0, 0.04, 0.01, 0.2, 0.5);
+        let bs = |s: f64, r: f64, v: f64, t: f64| black_scholes(s, k, r, q, v, t, true);
+
+        let h = 1e-4;
+        let delta = (bs(s + h, r, v, t) - bs(s - h, r, v, t)) / (2.0 * h);
+        let gamma = (bs(s + h, r, v, t) - 2.0 * bs(s, r, v, t) + bs(s - h, r, v, t)) / (h * h);
+        let theta = -(bs(s, r, v, t + h) - bs(s, r, v, t - h)) / (2.0 * h);
+        let vega = (bs(s, r, v + h, t) - bs(s, r, v - h, t)) / (2.0 * h);
+        let rho = (bs(s, r + h, v, t) - bs(s, r - h, v, t)) / (2.0 * h);
+
+        for method in METHODS {
+            let greeks =
+                LatticeOption::new(s, k, r, q, v, t, TypeFlag::Call, ExerciseFlag::European)
+                    .with_method(method)
+                    .with_steps(2_000)
+                    .greeks()
+                    .unwrap();
+
+            assert_approx_equal!(greeks.delta, delta, 1e-3);
+            assert_approx_equal!(greeks.gamma, gamma, 1e-3);
+            // The bumped prices carry the oscillating discretisation error.
+            assert_approx_equal!(greeks.theta, theta, 1e-2 * theta.abs());
+            assert_approx_equal!(greeks.vega, vega, 1e-2 * vega);
+            assert_approx_equal!(greeks.rho, rho, 1e-2 * rho);
+        }
+    }
+
+    #[test]
+    fn test_invalid_lattices() {
+        let option = LatticeOption::new(
+            100.0,
+            100.0,
+            0.05,
+            0.0,
+            0.2,
+            1.0,
+            TypeFlag::Put,
+            ExerciseFlag::American,
+        );
+
+        assert!(option.price().is_ok());
+        assert!(option.clone().with_steps(1).price().is_err());
+        assert!(LatticeOption {
+            exercise_flag: ExerciseFlag::Bermudan,
+            ..option.clone()
+        }
+        .price()
+        .is_err());
+        assert!(LatticeOption {
+            volatility: 0.0,
+            ..option.clone()
+        }
+        .price()
+        .is_err());
+        assert!(option
+            .with_dividends(vec![CashDividend {
+                time: 0.5,
+                amount: 120.0
+            }])
+            .price()
+            .is_err());
+    }
+}
@@ -0,0 +1,396 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Least-squares Monte Carlo engine for American and Bermudan exercise on
+//! simulated paths.
+//!
+//! Unlike [`LongstaffSchwartz`](crate::instruments::options::LongstaffSchwartz),
+//! which simulates its own Black-Scholes paths, the engine takes paths that
+//! were simulated elsewhere (e.g. by a [`StochasticProcess`]), one set of
+//! [`Trajectories`] per factor, so that any (multi-factor) model can be used.
+//! The continuation value at each exercise date is estimated by regressing the
+//! discounted future cash flows of the in-the-money paths on a basis of
+//! functions of the state, i.e. of the values of all factors at that date.
+//!
+//! [`StochasticProcess`]: crate::stochastics::StochasticProcess
+
+use crate::error::RustQuantError;
+use crate::instruments::{options::longstaff_schwartz::fitted_values, PricingResult};
+use crate::stochastics::Trajectories;
+use nalgebra::DMatrix;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// A regression basis function of the state (the value of each factor).
+pub type StateBasisFunction = Box<dyn Fn(&[f64]) -> f64>;
+
+/// Least-squares Monte Carlo engine for early exercise on simulated paths.
+pub struct LeastSquaresMonteCarlo {
+    /// Times at which the holder may exercise, in increasing order.
+    /// Each must be a time point of the simulated paths, after the first.
+    pub exercise_times: Vec<f64>,
+
+    /// `r` - Risk-free rate parameter, used to discount the cash flows.
+    pub risk_free_rate: f64,
+
+    /// Regression basis functions of the state.
+    basis: Vec<StateBasisFunction>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl LeastSquaresMonteCarlo {
+    /// Create a new engine for the given exercise schedule and regression basis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the basis is empty.
+    #[must_use]
+    pub fn new(
+        exercise_times: Vec<f64>,
+        risk_free_rate: f64,
+        basis: Vec<StateBasisFunction>,
+    ) -> Self {
+        assert!(!basis.is_empty(), "The basis must not be empty.");
+
+        Self {
+            exercise_times,
+            risk_free_rate,
+            basis,
+        }
+    }
+
+    /// All monomials $x_1^{a_1} \cdots x_n^{a_n}$ of the `n_factors` state
+    /// variables with total degree $a_1 + \dots + a_n$ at most `degree`,
+    /// starting with the constant.
+    #[must_use]
+    pub fn polynomial_basis(n_factors: usize, degree: usize) -> Vec<StateBasisFunction> {
+        let mut exponents: Vec<Vec<i32>> = vec![vec![]];
+
+        for _ in 0..n_factors {
+            exponents = exponents
+                .into_iter()
+                .flat_map(|powers| {
+                    let used = powers.iter().sum::<i32>();
+
+                    (0..=degree as i32 - used).map(move |a| {
+                        let mut powers = powers.clone();
+                        powers.push(a);
+                        powers
+                    })
+                })
+                .collect();
+        }
+
+        exponents.sort_by_key(|powers| powers.iter().sum::<i32>());
+
+        exponents
+            .into_iter()
+            .map(|powers| {
+                Box::new(move |x: &[f64]| {
+                    x.iter()
+                        .zip(&powers)
+                        .map(|(x, a)| x.powi(*a))
+                        .product::<f64>()
+                }) as StateBasisFunction
+            })
+            .collect()
+    }
+
+    /// Least-squares Monte Carlo valuation of the option paying `payoff(state)`
+    /// on exercise, on the paths of the given `factors`.
+    ///
+    /// All factors must share the same time points and number of paths; the
+    /// state of path `p` at time point `j` is `[f.paths[p][j] for f in factors]`.
+    /// The holder must exercise at the last exercise time if not before, and
+    /// the value is discounted to the first time point.
+    ///
+    /// The result carries the standard error, the number of paths, and the
+    /// `"regression_residual"` diagnostic: the mean squared residual of the
+    /// continuation value regressions, over all exercise dates and
+    /// in-the-money paths. A warning is raised for each exercise date with
+    /// fewer in-the-money paths than basis functions.
+    ///
+    /// # Errors
+    ///
+    /// - `RustQuantError::MissingInput` if there are no factors, paths, or
+    ///   exercise times.
+    /// - `RustQuantError::InvalidArgument` if the factors do not share their
+    ///   time points and number of paths, or if the exercise times are not
+    ///   increasing time points of the paths after the first.
+    pub fn price<F>(
+        &self,
+        factors: &[Trajectories],
+        payoff: F,
+    ) -> Result<PricingResult, RustQuantError>
+    where
+        F: Fn(&[f64]) -> f64,
+    {
+        let dates = self.exercise_indices(factors)?;
+
+        let times = &factors[0].times;
+        let n_paths = factors[0].paths.len();
+
+        let state = |p: usize, j: usize| -> Vec<f64> {
+            factors.iter().map(|factor| factor.paths[p][j]).collect()
+        };
+
+        // Cash flows of each path, valued at the current exercise date.
+        let last = dates[dates.len() - 1];
+        let mut cash_flows: Vec<f64> = (0..n_paths).map(|p| payoff(&state(p, last))).collect();
+
+        let (mut squared_residuals, mut n_residuals) = (0.0, 0);
+        let mut warnings = Vec::new();
+
+        for k in (0..dates.len() - 1).rev() {
+            let j = dates[k];
+            let discount = f64::exp(-self.risk_free_rate * (times[dates[k + 1]] - times[j]));
+            cash_flows.iter_mut().for_each(|c| *c *= discount);
+
+            let states: Vec<Vec<f64>> = (0..n_paths).map(|p| state(p, j)).collect();
+            let exercise: Vec<f64> = states.iter().map(|x| payoff(x)).collect();
+
+            let in_the_money: Vec<usize> = (0..n_paths).filter(|&p| exercise[p] > 0.0).collect();
+
+            if in_the_money.is_empty() {
+                continue;
+            }
+            if in_the_money.len() < self.basis.len() {
+                warnings.push(format!(
+                    "exercise time {} has {} in-the-money paths for {} basis functions: \
+                    the continuation value regression is underdetermined",
+                    times[j],
+                    in_the_money.len(),
+                    self.basis.len()
+                ));
+            }
+
+            let design = DMatrix::from_fn(in_the_money.len(), self.basis.len(), |i, b| {
+                self.basis[b](&states[in_the_money[i]])
+            });
+            let targets: Vec<f64> = in_the_money.iter().map(|&p| cash_flows[p]).collect();
+
+            let continuation = fitted_values(design, &targets);
+
+            for ((p, fitted), target) in in_the_money.iter().zip(&continuation).zip(&targets) {
+                squared_residuals += (target - fitted).powi(2);
+                n_residuals += 1;
+
+                if exercise[*p] > *fitted {
+                    cash_flows[*p] = exercise[*p];
+                }
+            }
+        }
+
+        let discount = f64::exp(-self.risk_free_rate * (times[dates[0]] - times[0]));
+        let samples: Vec<f64> = cash_flows.iter().map(|c| discount * c).collect();
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = if n_paths > 1 {
+            samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        let regression_residual = if n_residuals > 0 {
+            squared_residuals / f64::from(n_residuals)
+        } else {
+            0.0
+        };
+
+        let mut result = PricingResult::new(mean)
+            .with_standard_error((variance / n).sqrt())
+            .with_iterations(n_paths)
+            .with_diagnostic("regression_residual", regression_residual);
+        result.warnings = warnings;
+
+        Ok(result)
+    }
+
+    /// Indices of the exercise times among the time points of the paths,
+    /// after checking that the factors and the schedule are consistent.
+    fn exercise_indices(&self, factors: &[Trajectories]) -> Result<Vec<usize>, RustQuantError> {
+        let Some(first) = factors.first() else {
+            return Err(RustQuantError::MissingInput(
+                "at least one factor is required".to_string(),
+            ));
+        };
+        if first.paths.is_empty() {
+            return Err(RustQuantError::MissingInput(
+                "at least one path is required".to_string(),
+            ));
+        }
+        if self.exercise_times.is_empty() {
+            return Err(RustQuantError::MissingInput(
+                "at least one exercise time is required".to_string(),
+            ));
+        }
+
+        let consistent = factors.iter().all(|factor| {
+            factor.times == first.times
+                && factor.paths.len() == first.paths.len()
+                && factor
+                    .paths
+                    .iter()
+                    .all(|path| path.len() == first.times.len())
+        });
+        if !consistent {
+            return Err(RustQuantError::InvalidArgument(
+                "all factors must share their time points and number of paths".to_string(),
+            ));
+        }
+
+        let mut indices = Vec::with_capacity(self.exercise_times.len());
+
+        for t in &self.exercise_times {
+            let index = first
+                .times
+                .iter()
+                .position(|s| (s - t).abs() <= 1e-9 * t.abs().max(1.0))
+                .filter(|&j| j > 0 && indices.last().is_none_or(|&i| j > i))
+                .ok_or_else(|| {
+                    RustQuantError::InvalidArgument(format!(
+                        "exercise time {t} is not an increasing time point of the paths \
+                        after the first"
+                    ))
+                })?;
+
+            indices.push(index);
+        }
+
+        Ok(indices)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_least_squares_monte_carlo {
+    use super::*;
+    use crate::models::geometric_brownian_motion::GeometricBrownianMotion;
+    use crate::stochastics::StochasticProcess;
+
+    // Table 1 of Longstaff and Schwartz (2001): S = 36, K = 40, r = 0.06,
+    // v = 0.2, T = 1, with 50 exercise dates per year.
+    // The finite difference American price is 4.478, and the European
+    // (Black-Scholes) price is 3.844.
+    fn gbm_paths(n_paths: usize, seed: u64) -> Trajectories {
+        GeometricBrownianMotion::new(0.06, 0.2)
+            .seedable_euler_maruyama(36.0, 0.0, 1.0, 50, n_paths, false, seed)
+    }
+
+    fn put(state: &[f64]) -> f64 {
+        (40.0 - state[0]).max(0.0)
+    }
+
+    fn moneyness_basis() -> Vec<StateBasisFunction> {
+        (0..=3)
+            .map(|j| Box::new(move |x: &[f64]| (x[0] / 40.0).powi(j)) as StateBasisFunction)
+            .collect()
+    }
+
+    #[test]
+    fn test_american_put_on_gbm_paths() {
+        let paths = gbm_paths(20_000, 1);
+        let schedule: Vec<f64> = paths.times[1..].to_vec();
+
+        let result = LeastSquaresMonteCarlo::new(schedule, 0.06, moneyness_basis())
+            .price(&[paths], put)
+            .unwrap();
+
+        assert!((result.value - 4.478).abs() < 0.08);
+        assert!(result.standard_error.unwrap() > 0.0);
+        assert_eq!(result.iterations, Some(20_000));
+        assert!(!result.has_warnings());
+    }
+
+    #[test]
+    fn test_bermudan_between_european_and_american() {
+        let paths = gbm_paths(20_000, 2);
+        let engine = |schedule: Vec<f64>| {
+            LeastSquaresMonteCarlo::new(schedule, 0.06, moneyness_basis())
+                .price(std::slice::from_ref(&paths), put)
+                .unwrap()
+                .value
+        };
+
+        let european = engine(vec![1.0]);
+        let bermudan = engine(vec![0.2, 0.4, 0.6, 0.8, 1.0]);
+        let american = engine(paths.times[1..].to_vec());
+
+        assert!((european - 3.844).abs() < 0.08);
+        assert!(european < bermudan && bermudan < american);
+    }
+
+    #[test]
+    fn test_two_factor_basket_put() {
+        let first = gbm_paths(10_000, 3);
+        let second = gbm_paths(10_000, 4);
+        let schedule: Vec<f64> = first.times[1..].to_vec();
+        let factors = [first, second];
+
+        let basket_put = |state: &[f64]| (40.0 - 0.5 * (state[0] + state[1])).max(0.0);
+
+        let american = LeastSquaresMonteCarlo::new(
+            schedule,
+            0.06,
+            LeastSquaresMonteCarlo::polynomial_basis(2, 2),
+        )
+        .price(&factors, basket_put)
+        .unwrap();
+        let european = LeastSquaresMonteCarlo::new(
+            vec![1.0],
+            0.06,
+            LeastSquaresMonteCarlo::polynomial_basis(2, 2),
+        )
+        .price(&factors, basket_put)
+        .unwrap();
+
+        assert!(american.value > european.value);
+        assert!(american.value >= 4.0 - 1e-12);
+    }
+
+    #[test]
+    fn test_polynomial_basis_and_invalid_inputs() {
+        // 1, x, y, x^2, xy, y^2.
+        let basis = LeastSquaresMonteCarlo::polynomial_basis(2, 2);
+        let values: Vec<f64> = basis.iter().map(|f| f(&[2.0, 3.0])).collect();
+        let mut sorted = values.clone();
+        sorted.sort_by(f64::total_cmp);
+
+        assert_eq!(values.len(), 6);
+        assert_eq!(values[0], 1.0);
+        assert_eq!(sorted, vec![1.0, 2.0, 3.0, 4.0, 6.0, 9.0]);
+
+        let paths = gbm_paths(10, 5);
+        let engine = |schedule: Vec<f64>| {
+            LeastSquaresMonteCarlo::new(schedule, 0.06, moneyness_basis())
+                .price(std::slice::from_ref(&paths), put)
+        };
+
+        assert!(engine(vec![0.5, 0.25]).is_err());
+        assert!(engine(vec![0.0, 1.0]).is_err());
+        assert!(engine(vec![0.51]).is_err());
+        assert!(engine(vec![]).is_err());
+
+        let mismatched = gbm_paths(11, 6);
+        assert!(
+            LeastSquaresMonteCarlo::new(vec![1.0], 0.06, moneyness_basis())
+                .price(&[paths, mismatched], put)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,343 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Longstaff-Schwartz (2001) least-squares Monte Carlo pricer for American
+//! options under Black-Scholes dynamics.
+//!
+//! The American option is approximated by a Bermudan option with equally
+//! spaced exercise dates. Going backwards from expiry, the continuation value
+//! at each date is estimated by regressing the discounted future cash flows of
+//! the in-the-money paths on a set of basis functions of the moneyness
+//! $x = S / K$. By default the basis is the monomials $1, x, x^2, x^3$, but any
+//! set of functions can be supplied, e.g. [`LongstaffSchwartz::laguerre_basis`].
+
+use crate::instruments::{options::TypeFlag, PricingResult};
+use nalgebra::{DMatrix, DVector};
+use rand::{rngs::StdRng, SeedableRng};
+use rand_distr::{Distribution, StandardNormal};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// A regression basis function of the moneyness $S / K$.
+pub type BasisFunction = Box<dyn Fn(f64) -> f64>;
+
+/// Least-squares Monte Carlo pricer for American options.
+pub struct LongstaffSchwartz {
+    /// `S` - Initial price of the underlying.
+    pub initial_price: f64,
+
+    /// `K` - Strike price.
+    pub strike_price: f64,
+
+    /// `r` - Risk-free rate parameter.
+    pub risk_free_rate: f64,
+
+    /// `q` - Dividend yield.
+    pub dividend_yield: f64,
+
+    /// `v` - Volatility parameter.
+    pub volatility: f64,
+
+    /// `T` - Time to expiry/maturity.
+    pub time_to_maturity: f64,
+
+    /// Number of (equally spaced) exercise dates, including expiry.
+    pub n_exercise_dates: usize,
+
+    /// Call or put.
+    pub option_type: TypeFlag,
+
+    /// Regression basis functions of the moneyness.
+    basis: Vec<BasisFunction>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl LongstaffSchwartz {
+    /// Create a new pricer, with the cubic monomial basis.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        initial_price: f64,
+        strike_price: f64,
+        risk_free_rate: f64,
+        dividend_yield: f64,
+        volatility: f64,
+        time_to_maturity: f64,
+        n_exercise_dates: usize,
+        option_type: TypeFlag,
+    ) -> Self {
+        Self {
+            initial_price,
+            strike_price,
+            risk_free_rate,
+            dividend_yield,
+            volatility,
+            time_to_maturity,
+            n_exercise_dates,
+            option_type,
+            basis: Self::monomial_basis(3),
+        }
+    }
+
+    /// Use the given regression basis functions (of the moneyness $S / K$).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the basis is empty.
+    #[must_use]
+    pub fn with_basis(mut self, basis: Vec<BasisFunction>) -> Self {
+        assert!(!basis.is_empty(), "The basis must not be empty.");
+
+        self.basis = basis;
+        self
+    }
+
+    /// The monomials $1, x, \dots, x^n$ for `degree` $n$.
+    #[must_use]
+    pub fn monomial_basis(degree: usize) -> Vec<BasisFunction> {
+        (0..=degree as i32)
+            .map(|j| Box::new(move |x: f64| x.powi(j)) as BasisFunction)
+            .collect()
+    }
+
+    /// The constant and the weighted Laguerre polynomials
+    /// $e^{-x/2} L_1(x), \dots, e^{-x/2} L_n(x)$ for `degree` $n$,
+    /// as used by Longstaff and Schwartz (2001).
+    #[must_use]
+    pub fn laguerre_basis(degree: usize) -> Vec<BasisFunction> {
+        let mut basis: Vec<BasisFunction> = vec![Box::new(|_| 1.0)];
+
+        for n in 1..=degree {
+            basis.push(Box::new(move |x: f64| {
+                // Three-term recurrence:
+                // (k + 1) L_{k+1} = (2k + 1 - x) L_k - k L_{k-1}.
+                let (mut previous, mut current) = (1.0, 1.0 - x);
+
+                for k in 1..n {
+                    let k = k as f64;
+                    let next = ((2.0 * k + 1.0 - x) * current - k * previous) / (k + 1.0);
+
+                    previous = current;
+                    current = next;
+                }
+
+                f64::exp(-0.5 * x) * current
+            }));
+        }
+
+        basis
+    }
+
+    /// Least-squares Monte Carlo valuation with `n_paths` simulated paths.
+    ///
+    /// The result carries the standard error, the number of paths, and the
+    /// `"regression_residual"` diagnostic: the mean squared residual of the
+    /// continuation value regressions, over all exercise dates and
+    /// in-the-money paths. A warning is raised for each exercise date with
+    /// fewer in-the-money paths than basis functions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no exercise dates.
+    #[must_use]
+    pub fn price(&self, n_paths: usize, seed: u64) -> PricingResult {
+        assert!(self.n_exercise_dates > 0, "There must be an exercise date.");
+
+        let n_dates = self.n_exercise_dates;
+        let dt = self.time_to_maturity / n_dates as f64;
+        let discount = f64::exp(-self.risk_free_rate * dt);
+
+        let paths = self.simulate(n_paths, dt, seed);
+
+        // Cash flows of each path, valued at the current exercise date.
+        let mut cash_flows: Vec<f64> = paths.iter().map(|p| self.payoff(p[n_dates - 1])).collect();
+
+        let (mut squared_residuals, mut n_residuals) = (0.0, 0);
+        let mut warnings = Vec::new();
+
+        for i in (0..n_dates - 1).rev() {
+            cash_flows.iter_mut().for_each(|c| *c *= discount);
+
+            let in_the_money: Vec<usize> = (0..n_paths)
+                .filter(|&p| self.payoff(paths[p][i]) > 0.0)
+                .collect();
+
+            if in_the_money.is_empty() {
+                continue;
+            }
+            if in_the_money.len() < self.basis.len() {
+                warnings.push(format!(
+                    "exercise date {i} has {} in-the-money paths for {} basis functions: \
+                    the continuation value regression is underdetermined",
+                    in_the_money.len(),
+                    self.basis.len()
+                ));
+            }
+
+            let moneyness: Vec<f64> = in_the_money
+                .iter()
+                .map(|&p| paths[p][i] / self.strike_price)
+                .collect();
+            let targets: Vec<f64> = in_the_money.iter().map(|&p| cash_flows[p]).collect();
+
+            let continuation = least_squares_fit(&self.basis, &moneyness, &targets);
+
+            for ((p, fitted), target) in in_the_money.iter().zip(&continuation).zip(&targets) {
+                squared_residuals += (target - fitted).powi(2);
+                n_residuals += 1;
+
+                let exercise = self.payoff(paths[*p][i]);
+
+                if exercise > *fitted {
+                    cash_flows[*p] = exercise;
+                }
+            }
+        }
+
+        let samples: Vec<f64> = cash_flows.iter().map(|c| discount * c).collect();
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+
+        let regression_residual = if n_residuals > 0 {
+            squared_residuals / f64::from(n_residuals)
+        } else {
+            0.0
+        };
+
+        let mut result = PricingResult::new(mean)
+            .with_standard_error((variance / n).sqrt())
+            .with_iterations(n_paths)
+            .with_diagnostic("regression_residual", regression_residual);
+        result.warnings = warnings;
+
+        result
+    }
+
+    /// Payoff of immediate exercise at spot price `s`.
+    fn payoff(&self, s: f64) -> f64 {
+        match self.option_type {
+            TypeFlag::Call => (s - self.strike_price).max(0.0),
+            TypeFlag::Put => (self.strike_price - s).max(0.0),
+        }
+    }
+
+    /// Simulate the underlying at the exercise dates (exactly, under GBM).
+    fn simulate(&self, n_paths: usize, dt: f64, seed: u64) -> Vec<Vec<f64>> {
+        let (r, q, v) = (self.risk_free_rate, self.dividend_yield, self.volatility);
+        let drift = (r - q - 0.5 * v * v) * dt;
+        let diffusion = v * dt.sqrt();
+
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        (0..n_paths)
+            .map(|_| {
+                let mut s = self.initial_price;
+
+                (0..self.n_exercise_dates)
+                    .map(|_| {
+                        let z: f64 = StandardNormal.sample(&mut rng);
+                        s *= f64::exp(drift + diffusion * z);
+
+                        s
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Fitted values of the least-squares regression of `y` on the basis
+/// functions evaluated at `x`.
+///
+/// The regression is solved by SVD, so a rank-deficient design
+/// (e.g. all `x` equal) is handled gracefully.
+pub(crate) fn least_squares_fit(basis: &[BasisFunction], x: &[f64], y: &[f64]) -> Vec<f64> {
+    let design = DMatrix::from_fn(x.len(), basis.len(), |i, j| basis[j](x[i]));
+
+    fitted_values(design, y)
+}
+
+/// Fitted values of the least-squares regression of `y` on the columns of
+/// the design matrix, solved by SVD.
+pub(crate) fn fitted_values(design: DMatrix<f64>, y: &[f64]) -> Vec<f64> {
+    let coefficients = design
+        .clone()
+        .svd(true, true)
+        .solve(&DVector::from_column_slice(y), 1e-12)
+        .expect("the SVD was computed with U and V");
+
+    (design * coefficients).iter().copied().collect()
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_longstaff_schwartz {
+    use super::*;
+
+    // Table 1 of Longstaff and Schwartz (2001): S = 36, K = 40, r = 0.06,
+    // v = 0.2, T = 1, with 50 exercise dates per year.
+    // The finite difference American price is 4.478.
+    fn american_put() -> LongstaffSchwartz {
+        LongstaffSchwartz::new(36.0, 40.0, 0.06, 0.0, 0.2, 1.0, 50, TypeFlag::Put)
+    }
+
+    #[test]
+    fn test_laguerre_and_monomial_bases_agree() {
+        let monomial = american_put().price(20_000, 1);
+        let laguerre = american_put()
+            .with_basis(LongstaffSchwartz::laguerre_basis(3))
+            .price(20_000, 1);
+
+        assert!((monomial.value - 4.478).abs() < 0.05);
+        assert!((laguerre.value - 4.478).abs() < 0.05);
+        assert!((monomial.value - laguerre.value).abs() < 0.02);
+
+        assert_eq!(monomial.iterations, Some(20_000));
+        assert!(monomial.standard_error.unwrap() > 0.0);
+        assert!(!monomial.has_warnings());
+    }
+
+    #[test]
+    fn test_richer_basis_reduces_residual() {
+        let linear = american_put()
+            .with_basis(LongstaffSchwartz::monomial_basis(1))
+            .price(10_000, 2);
+        let quartic = american_put()
+            .with_basis(LongstaffSchwartz::monomial_basis(4))
+            .price(10_000, 2);
+
+        assert!(
+            quartic.diagnostic("regression_residual").unwrap()
+                < linear.diagnostic("regression_residual").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_laguerre_polynomials() {
+        let basis = LongstaffSchwartz::laguerre_basis(3);
+        let x: f64 = 0.7;
+
+        // L_2(x) = (x^2 - 4x + 2) / 2 and L_3(x) = (-x^3 + 9x^2 - 18x + 6) / 6.
+        let l2 = 0.5 * (x * x - 4.0 * x + 2.0);
+        let l3 = (-x.powi(3) + 9.0 * x * x - 18.0 * x + 6.0) / 6.0;
+
+        assert!((basis[2](x) - f64::exp(-0.5 * x) * l2).abs() < 1e-12);
+        assert!((basis[3](x) - f64::exp(-0.5 * x) * l3).abs() < 1e-12);
+    }
+}
@@ -12,8 +12,8 @@
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 use super::TypeFlag;
-use crate::instruments::BlackScholesMerton;
-use crate::time::{today, DayCountConvention};
+use crate::instruments::{BlackScholesMerton, Maturity};
+use crate::time::DayCountConvention;
 use time::Date;
 
 /// Merton (1976) jump diffusion model parameters.
@@ -47,6 +47,11 @@ pub struct Merton1976 {
 
     /// `expiration_date` - Valuation date.
     pub expiration_date: Date,
+
+    /// Day count convention of the time to expiry
+    /// (optional, defaults to `Actual/Actual ISDA`).
+    #[builder(default)]
+    pub day_count_convention: DayCountConvention,
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -68,10 +73,7 @@ impl Merton1976 {
             self.type_flag,
         );
 
-        let tau = DayCountConvention::default().day_count_factor(
-            self.evaluation_date.unwrap_or(today()),
-            self.expiration_date,
-        );
+        let tau = self.year_fraction();
 
         let mut price = 0_f64;
 
@@ -106,7 +108,7 @@ impl Merton1976 {
 
 #[cfg(test)]
 mod tests_merton_1976 {
-    use crate::{assert_approx_equal, instruments::TypeFlag};
+    use crate::{assert_approx_equal, instruments::TypeFlag, time::today};
 
     use super::*;
 
@@ -122,6 +124,7 @@ mod tests_merton_1976 {
             type_flag: TypeFlag::Call,
             evaluation_date: None,
             expiration_date: today() + time::Duration::days(36),
+            day_count_convention: DayCountConvention::default(),
         };
 
         // Price example from Haug's book.
@@ -9,8 +9,10 @@
 
 pub use crate::instruments::options::{
     asian::*, bachelier::*, barrier::*, binary::*, binomial::*, black_scholes_merton::*,
-    forward_start::*, heston::*, implied_volatility::*, lookback::*, merton_jump_diffusion::*,
-    option::*, power::*,
+    dividends::*, finite_difference_engine::*, forward_start::*, fourier::*, greeks::*, heston::*,
+    implied_volatility::*, implied_volatility_solver::*, lattice::*, least_squares_monte_carlo::*,
+    longstaff_schwartz::*, lookback::*, merton_jump_diffusion::*, monte_carlo::*, option::*,
+    path_dependent::*, power::*, variance_swap::*,
 };
 
 /// Asian option pricers.
@@ -31,26 +33,59 @@ pub mod binomial;
 /// Generalised Black-Scholes-Merton option pricer.
 pub mod black_scholes_merton;
 
+/// Discrete and continuous dividend schedules.
+pub mod dividends;
+
+/// Finite difference engine for the Black-Scholes PDE.
+pub mod finite_difference_engine;
+
 /// Forward start options pricers.
 pub mod forward_start;
 
+/// Fourier (COS and Carr-Madan) option pricers.
+pub mod fourier;
+
+/// Greeks of any pricer, by bump-and-reprice or automatic differentiation.
+pub mod greeks;
+
 /// Heston model option pricer.
 pub mod heston;
 
 /// Implied volatility functions.
 pub mod implied_volatility;
 
+/// Implied volatility solver for Black-Scholes, Black-76 and Bachelier quotes.
+pub mod implied_volatility_solver;
+
+/// Binomial and trinomial lattice pricers.
+pub mod lattice;
+
+/// Least-squares Monte Carlo engine for early exercise on simulated paths.
+pub mod least_squares_monte_carlo;
+
+/// Longstaff-Schwartz least-squares Monte Carlo American option pricer.
+pub mod longstaff_schwartz;
+
 /// Lookback option pricers.
 pub mod lookback;
 
 /// Merton (1976) jump diffusion model.
 pub mod merton_jump_diffusion;
 
+/// Monte Carlo pricer with pathwise and likelihood ratio Greeks.
+pub mod monte_carlo;
+
 /// Base option traits.
 pub mod option;
 
+/// Monte Carlo pricing of discretely monitored path-dependent payoffs.
+pub mod path_dependent;
+
 /// Power option pricers.
 pub mod power;
 
+/// Variance swap fair strike.
+pub mod variance_swap;
+
 /// Finite Difference Pricer
 pub mod finite_difference_pricer;
@@ -0,0 +1,783 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Monte Carlo pricing of European payoffs under Black-Scholes dynamics,
+//! with Greeks computed without bumping.
+//!
+//! The terminal price is simulated exactly as
+//!
+//! $$
+//! S_T = S_0 \exp\left( (r - q - \frac{1}{2} \sigma^2) T + \sigma \sqrt{T} Z \right)
+//! $$
+//!
+//! where $q$ is the continuous dividend yield. Discrete dividends follow the
+//! escrowed dividend model of [`DividendSchedule`]: $S_0$ is replaced by the
+//! spot adjusted for the cash and proportional dividends paid before expiry.
+//!
+//! Two unbiased Greek estimators are provided:
+//!
+//! - **Pathwise**: differentiates the discounted payoff along each path,
+//!   $\Delta = e^{-rT} \mathbb{E}[f'(S_T) \partial S_T / \partial S_0]$. This requires the
+//!   payoff to be Lipschitz continuous (e.g. calls, puts, spreads), so it
+//!   cannot be used for digitals or barriers.
+//! - **Likelihood ratio**: differentiates the density of $S_T$ instead of
+//!   the payoff, $\mathcal{V} = e^{-rT} \mathbb{E}[f(S_T) ((Z^2 - 1)/\sigma - Z \sqrt{T})]$.
+//!   This works for any integrable payoff, including discontinuous ones,
+//!   at the cost of a higher variance.
+//!
+//! For deep out-of-the-money payoffs, most paths pay nothing. Importance
+//! sampling draws $Z \sim N(\theta, 1)$ instead, shifting the drift of the
+//! sampling measure towards the payoff region, and reweights each path by the
+//! likelihood ratio $e^{-\theta Z + \theta^2 / 2}$ so the estimator stays unbiased.
+//! Alternatively, $Z - \theta$ can be drawn from a Student's t distribution,
+//! whose power-law tails reach further into the payoff region, reweighting
+//! each path by the ratio of the normal and Student's t densities.
+//! See [`ImportanceSampler`].
+//!
+//! The normals can also be drawn from a randomized low-discrepancy sequence
+//! (quasi-Monte Carlo), see [`SampleSequence`], which fills the sample
+//! space evenly and reduces the error of smooth payoffs for the same
+//! number of paths.
+//!
+//! Two more variance reduction techniques are available:
+//!
+//! - **Antithetic variates**: each draw $Z$ is paired with $-Z$ and the
+//!   sample is the average of the two payoffs, which cancels the odd part of
+//!   the payoff (e.g. most of the variance of a near linear payoff).
+//! - **Control variates**: a second payoff $C$ with a known price, computed
+//!   on the same paths, corrects the estimate to $Y - \beta (C - \mathbb{E}[C])$.
+//!   The coefficient $\beta = \mathrm{Cov}(Y, C) / \mathrm{Var}(C)$ is
+//!   estimated by regression on the paths, which reduces the variance by the
+//!   factor $1 - \rho^2$, $\rho$ being the correlation of $Y$ and $C$.
+
+use crate::instruments::{options::DividendSchedule, PricingResult};
+use crate::math::{
+    distributions::{Distribution as _, Gaussian, StudentsT},
+    HaltonSequence, SobolSequence,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use rand_distr::{Distribution, StandardNormal};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Fraction of paths with a non-zero payoff below which a price is flagged
+/// as a rare-event estimate, whose standard error is unreliable.
+const RARE_EVENT_FRACTION: f64 = 0.01;
+
+/// Number of independently randomized replications of a quasi-random
+/// sequence, from whose spread the standard error is estimated.
+const QMC_REPLICATIONS: usize = 16;
+
+/// Sequence from which [`MonteCarloEngine`] draws its standard normals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SampleSequence {
+    /// Pseudo-random numbers from a seeded generator.
+    #[default]
+    PseudoRandom,
+
+    /// Digitally shifted Sobol' sequence.
+    Sobol,
+
+    /// Randomly rotated Halton sequence.
+    Halton,
+}
+
+/// Sampling measure of the standard normal $Z$ driving $S_T$, for
+/// importance sampling.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImportanceSampler {
+    /// Exponential tilting: $Z \sim N(\theta, 1)$, with likelihood ratio
+    /// $e^{-\theta Z + \theta^2 / 2}$.
+    ExponentialTilt {
+        /// Drift shift $\theta$ of the sampled normal.
+        shift: f64,
+    },
+
+    /// Power-law tails: $Z = \theta + X$ with $X$ Student's t distributed,
+    /// with likelihood ratio $\phi(Z) / f_\nu(X)$. The heavier tails keep the
+    /// likelihood ratio bounded, so the estimator has finite variance even
+    /// when the shift overshoots.
+    PowerLaw {
+        /// Location shift $\theta$ of the sampled variable.
+        shift: f64,
+
+        /// Degrees of freedom $\nu$ of the Student's t distribution: the
+        /// density tails decay like $|x|^{-(\nu + 1)}$.
+        tail_index: f64,
+    },
+}
+
+/// Monte Carlo engine for European payoffs under Black-Scholes dynamics.
+#[derive(Debug, Clone, Copy)]
+pub struct MonteCarloEngine {
+    /// `S` - Initial price of the underlying.
+    pub initial_price: f64,
+
+    /// `r` - Risk-free rate parameter.
+    pub risk_free_rate: f64,
+
+    /// `v` - Volatility parameter.
+    pub volatility: f64,
+
+    /// `T` - Time to expiry/maturity.
+    pub time_to_maturity: f64,
+
+    /// Number of simulated paths.
+    pub n_paths: usize,
+
+    /// Seed of the random number generator, so that prices and Greeks
+    /// are computed with common random numbers.
+    pub seed: u64,
+
+    /// Sequence of the normal draws (pseudo-random by default).
+    pub sequence: SampleSequence,
+
+    /// Whether each draw $Z$ is paired with its antithetic $-Z$.
+    pub antithetic: bool,
+
+    /// `q` - Continuous dividend yield.
+    pub dividend_yield: f64,
+
+    /// Present value of the cash dividends paid before expiry.
+    pub dividends_present_value: f64,
+
+    /// Fraction of the spot left after the proportional dividends paid
+    /// before expiry.
+    pub proportional_factor: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl MonteCarloEngine {
+    /// Create a new Monte Carlo engine.
+    #[must_use]
+    pub const fn new(
+        initial_price: f64,
+        risk_free_rate: f64,
+        volatility: f64,
+        time_to_maturity: f64,
+        n_paths: usize,
+        seed: u64,
+    ) -> Self {
+        Self {
+            initial_price,
+            risk_free_rate,
+            volatility,
+            time_to_maturity,
+            n_paths,
+            seed,
+            sequence: SampleSequence::PseudoRandom,
+            antithetic: false,
+            dividend_yield: 0.0,
+            dividends_present_value: 0.0,
+            proportional_factor: 1.0,
+        }
+    }
+
+    /// Simulate the underlying with the dividends of the schedule, paid
+    /// before expiry.
+    #[must_use]
+    pub fn with_dividend_schedule(mut self, schedule: &DividendSchedule) -> Self {
+        let (r, t) = (self.risk_free_rate, self.time_to_maturity);
+
+        self.dividend_yield = schedule.dividend_yield;
+        self.dividends_present_value = schedule.cash_present_value(r, 0.0, t);
+        self.proportional_factor = schedule.proportional_factor(0.0, t);
+        self
+    }
+
+    /// Draw the normals from the given sequence.
+    ///
+    /// With a quasi-random sequence the paths are split into
+    /// 16 replications, each randomized from the seed, and the standard
+    /// error is that of the mean of the replications. Sobol' points are best
+    /// balanced when each replication has a power of two of paths.
+    #[must_use]
+    pub const fn with_sequence(mut self, sequence: SampleSequence) -> Self {
+        self.sequence = sequence;
+        self
+    }
+
+    /// Pair each draw $Z$ with its antithetic $-Z$: every sample is the
+    /// average of the two paths, so twice as many paths are simulated.
+    #[must_use]
+    pub const fn with_antithetic_variates(mut self) -> Self {
+        self.antithetic = true;
+        self
+    }
+
+    /// Price of the payoff `f(S_T)`, with its standard error and the number
+    /// of paths.
+    ///
+    /// A warning is raised if fewer than 1% of the paths have a non-zero
+    /// payoff, in which case importance sampling should be considered.
+    pub fn price<F: Fn(f64) -> f64>(&self, payoff: F) -> PricingResult {
+        self.estimate_sampled(None, |_, s_t| payoff(s_t))
+    }
+
+    /// Importance-sampled price of the payoff `f(S_T)`, with its standard
+    /// error and the number of paths.
+    ///
+    /// The standard normal driving `S_T` is sampled with mean `shift`, and each
+    /// path is reweighted by the likelihood ratio. A shift of zero is plain
+    /// Monte Carlo; see [`MonteCarloEngine::importance_shift`] for a good
+    /// choice for out-of-the-money options.
+    pub fn price_importance_sampled<F: Fn(f64) -> f64>(
+        &self,
+        payoff: F,
+        shift: f64,
+    ) -> PricingResult {
+        self.price_with_sampler(payoff, ImportanceSampler::ExponentialTilt { shift })
+    }
+
+    /// Price of the payoff `f(S_T)` under the given importance sampler,
+    /// with its standard error and the number of paths.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tail index of a power-law sampler is not positive.
+    pub fn price_with_sampler<F: Fn(f64) -> f64>(
+        &self,
+        payoff: F,
+        sampler: ImportanceSampler,
+    ) -> PricingResult {
+        self.estimate_sampled(Some(sampler), |_, s_t| payoff(s_t))
+    }
+
+    /// Price of the payoff `f(S_T)` corrected by the control variate `c(S_T)`,
+    /// whose price `control_price` is known, with its standard error and the
+    /// number of paths.
+    ///
+    /// The control coefficient is regressed on the paths, and is reported
+    /// with the correlation of the payoff and the control as the
+    /// `control_coefficient` and `control_correlation` diagnostics.
+    pub fn price_with_control_variate<F, C>(
+        &self,
+        payoff: F,
+        control: C,
+        control_price: f64,
+    ) -> PricingResult
+    where
+        F: Fn(f64) -> f64,
+        C: Fn(f64) -> f64,
+    {
+        let payoffs = self.discounted_samples(None, |_, s_t| payoff(s_t));
+        let controls = self.discounted_samples(None, |_, s_t| control(s_t));
+
+        let y: Vec<f64> = payoffs.iter().flatten().copied().collect();
+        let c: Vec<f64> = controls.iter().flatten().copied().collect();
+        let (y_mean, c_mean) = (mean(&y), mean(&c));
+
+        let covariance: f64 = y
+            .iter()
+            .zip(&c)
+            .map(|(y, c)| (y - y_mean) * (c - c_mean))
+            .sum();
+        let (y_variance, c_variance): (f64, f64) = (
+            y.iter().map(|y| (y - y_mean).powi(2)).sum(),
+            c.iter().map(|c| (c - c_mean).powi(2)).sum(),
+        );
+
+        let (beta, correlation) = if c_variance > 0.0 && y_variance > 0.0 {
+            (
+                covariance / c_variance,
+                covariance / (c_variance * y_variance).sqrt(),
+            )
+        } else {
+            (0.0, 0.0)
+        };
+
+        let corrected: Vec<Vec<f64>> = payoffs
+            .iter()
+            .zip(&controls)
+            .map(|(y, c)| {
+                y.iter()
+                    .zip(c)
+                    .map(|(y, c)| y - beta * (c - control_price))
+                    .collect()
+            })
+            .collect();
+
+        self.summarize(&corrected, &payoffs)
+            .with_diagnostic("control_coefficient", beta)
+            .with_diagnostic("control_correlation", correlation)
+    }
+
+    /// Price of the payoff `f(S_T)` with the underlying `S_T` as the control
+    /// variate, whose discounted expectation is the spot net of dividends.
+    pub fn price_with_underlying_control<F: Fn(f64) -> f64>(&self, payoff: F) -> PricingResult {
+        let forward_price =
+            self.adjusted_spot() * f64::exp(-self.dividend_yield * self.time_to_maturity);
+
+        self.price_with_control_variate(payoff, |s_t| s_t, forward_price)
+    }
+
+    /// Drift shift that centres the sampled terminal price on `target`
+    /// (e.g. the strike of an out-of-the-money option).
+    #[must_use]
+    pub fn importance_shift(&self, target: f64) -> f64 {
+        let (s, r, v, t) = (
+            self.adjusted_spot(),
+            self.risk_free_rate,
+            self.volatility,
+            self.time_to_maturity,
+        );
+
+        ((target / s).ln() - (r - self.dividend_yield - 0.5 * v * v) * t) / (v * t.sqrt())
+    }
+
+    /// Pathwise delta estimate, and its standard error.
+    /// Takes the derivative `f'(S_T)` of a Lipschitz payoff, e.g.
+    /// `|s| if s > k { 1.0 } else { 0.0 }` for a call struck at `k`.
+    pub fn mc_delta_pathwise<F: Fn(f64) -> f64>(&self, payoff_derivative: F) -> (f64, f64) {
+        // Only the part of the spot net of the cash dividends is random.
+        let escrowed = self.initial_price - self.dividends_present_value;
+
+        self.estimate(|_, s_t| payoff_derivative(s_t) * s_t / escrowed)
+    }
+
+    /// Likelihood ratio vega estimate, and its standard error.
+    /// Takes the payoff `f(S_T)` itself, which need not be continuous.
+    pub fn mc_vega_likelihood_ratio<F: Fn(f64) -> f64>(&self, payoff: F) -> (f64, f64) {
+        let (v, sqrt_t) = (self.volatility, self.time_to_maturity.sqrt());
+
+        self.estimate(|z, s_t| payoff(s_t) * ((z * z - 1.0) / v - z * sqrt_t))
+    }
+
+    /// Spot adjusted for the discrete dividends paid before expiry.
+    fn adjusted_spot(&self) -> f64 {
+        (self.initial_price - self.dividends_present_value) * self.proportional_factor
+    }
+
+    /// Discounted sample mean and standard error of `g(Z, S_T)`.
+    fn estimate<G: Fn(f64, f64) -> f64>(&self, g: G) -> (f64, f64) {
+        let result = self.estimate_sampled(None, g);
+
+        (result.value, result.standard_error.unwrap_or(0.0))
+    }
+
+    /// Discounted sample mean and standard error of `g(Z, S_T)`, with `Z`
+    /// drawn from the sampler (standard normal if none) and reweighted by
+    /// the likelihood ratio.
+    fn estimate_sampled<G: Fn(f64, f64) -> f64>(
+        &self,
+        sampler: Option<ImportanceSampler>,
+        g: G,
+    ) -> PricingResult {
+        let samples = self.discounted_samples(sampler, g);
+
+        self.summarize(&samples, &samples)
+    }
+
+    /// Discounted samples of `g(Z, S_T)` in each replication, with `Z`
+    /// drawn from the sampler (standard normal if none) and reweighted by
+    /// the likelihood ratio. With antithetic variates, each sample is the
+    /// average over the draw and its antithetic.
+    fn discounted_samples<G: Fn(f64, f64) -> f64>(
+        &self,
+        sampler: Option<ImportanceSampler>,
+        g: G,
+    ) -> Vec<Vec<f64>> {
+        let (s, r, v, t) = (
+            self.adjusted_spot(),
+            self.risk_free_rate,
+            self.volatility,
+            self.time_to_maturity,
+        );
+        let drift = (r - self.dividend_yield - 0.5 * v * v) * t;
+        let discount = f64::exp(-r * t);
+
+        // Maps a standard normal draw to the sampled `Z` and its likelihood ratio.
+        let sample: Box<dyn Fn(f64) -> (f64, f64)> = match sampler {
+            None => Box::new(|epsilon| (epsilon, 1.0)),
+            Some(ImportanceSampler::ExponentialTilt { shift }) => Box::new(move |epsilon| {
+                let z = shift + epsilon;
+
+                (z, f64::exp(-shift * z + 0.5 * shift * shift))
+            }),
+            Some(ImportanceSampler::PowerLaw { shift, tail_index }) => {
+                let students_t = StudentsT::new(tail_index);
+                let normal = Gaussian::default();
+
+                Box::new(move |epsilon| {
+                    // Map the normal draw through its own quantile, so
+                    // quasi-random sequences keep their even spread.
+                    let u = normal.cdf(epsilon).clamp(f64::EPSILON, 1.0 - f64::EPSILON);
+                    let x = students_t.inv_cdf(u);
+                    let z = shift + x;
+
+                    (z, normal.pdf(z) / students_t.pdf(x))
+                })
+            }
+        };
+
+        let path = |epsilon: f64| {
+            let (z, likelihood_ratio) = sample(epsilon);
+            let s_t = s * f64::exp(drift + v * t.sqrt() * z);
+
+            discount * likelihood_ratio * g(z, s_t)
+        };
+
+        self.normal_draws()
+            .iter()
+            .map(|draws| {
+                draws
+                    .iter()
+                    .map(|epsilon| {
+                        if self.antithetic {
+                            0.5 * (path(*epsilon) + path(-epsilon))
+                        } else {
+                            path(*epsilon)
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Mean and standard error of the samples, with a warning if few of the
+    /// `payoffs` on the same paths are non-zero.
+    fn summarize(&self, samples: &[Vec<f64>], payoffs: &[Vec<f64>]) -> PricingResult {
+        // Pseudo-random draws are one sample of independent paths; the
+        // replications of a quasi-random sequence are the independent samples.
+        let (mean, standard_error) = match samples {
+            [paths] => mean_and_standard_error(paths),
+            _ => mean_and_standard_error(
+                &samples
+                    .iter()
+                    .map(|paths| mean(paths))
+                    .collect::<Vec<f64>>(),
+            ),
+        };
+
+        let n = payoffs.iter().map(Vec::len).sum::<usize>() as f64;
+        let paths = if self.antithetic {
+            2 * n as usize
+        } else {
+            n as usize
+        };
+
+        let mut result = PricingResult::new(mean)
+            .with_standard_error(standard_error)
+            .with_iterations(paths);
+
+        let hits = payoffs.iter().flatten().filter(|x| **x != 0.0).count() as f64;
+
+        if hits < RARE_EVENT_FRACTION * n {
+            result.warn(format!(
+                "only {hits} of {n} paths have a non-zero payoff: \
+                the standard error is unreliable, consider importance sampling"
+            ));
+        }
+
+        result
+    }
+
+    /// Standard normal draws of the paths, in independent replications.
+    fn normal_draws(&self) -> Vec<Vec<f64>> {
+        let replications = QMC_REPLICATIONS.min(self.n_paths).max(1);
+        let paths = self.n_paths / replications;
+        let normal = Gaussian::default();
+
+        match self.sequence {
+            SampleSequence::PseudoRandom => {
+                let mut rng = StdRng::seed_from_u64(self.seed);
+
+                vec![(0..self.n_paths)
+                    .map(|_| StandardNormal.sample(&mut rng))
+                    .collect()]
+            }
+            SampleSequence::Sobol => (0..replications as u64)
+                .map(|i| {
+                    let mut sobol = SobolSequence::new(1)
+                        .expect("one dimension is supported")
+                        .with_digital_shift(self.seed.wrapping_add(i));
+
+                    (0..paths)
+                        .map(|_| normal.inv_cdf(sobol.next_point()[0]))
+                        .collect()
+                })
+                .collect(),
+            SampleSequence::Halton => (0..replications as u64)
+                .map(|i| {
+                    let mut halton = HaltonSequence::new(1)
+                        .expect("one dimension is supported")
+                        .with_random_shift(self.seed.wrapping_add(i));
+
+                    (0..paths)
+                        .map(|_| normal.inv_cdf(halton.next_point()[0]))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Sample mean.
+fn mean(samples: &[f64]) -> f64 {
+    samples.iter().sum::<f64>() / samples.len() as f64
+}
+
+/// Sample mean and standard error of the mean.
+fn mean_and_standard_error(samples: &[f64]) -> (f64, f64) {
+    let n = samples.len() as f64;
+    let mean = mean(samples);
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+
+    (mean, (variance / n).sqrt())
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_monte_carlo {
+    use super::*;
+
+    const S: f64 = 100.0;
+    const K: f64 = 105.0;
+    const R: f64 = 0.05;
+    const V: f64 = 0.2;
+    const T: f64 = 1.0;
+
+    fn d1() -> f64 {
+        ((S / K).ln() + (R + 0.5 * V * V) * T) / (V * T.sqrt())
+    }
+
+    #[test]
+    fn test_pathwise_delta_matches_analytic() {
+        let engine = MonteCarloEngine::new(S, R, V, T, 100_000, 42);
+        let (delta, error) = engine.mc_delta_pathwise(|s| if s > K { 1.0 } else { 0.0 });
+
+        let analytic = Gaussian::default().cdf(d1());
+
+        assert!((delta - analytic).abs() < 4.0 * error);
+    }
+
+    #[test]
+    fn test_likelihood_ratio_vega_is_unbiased() {
+        let analytic = S * Gaussian::default().pdf(d1()) * T.sqrt();
+
+        // Average over independent seeds to check the estimator, not one draw.
+        let estimates: Vec<(f64, f64)> = (0..5)
+            .map(|seed| {
+                MonteCarloEngine::new(S, R, V, T, 100_000, seed)
+                    .mc_vega_likelihood_ratio(|s| (s - K).max(0.0))
+            })
+            .collect();
+
+        let mean = estimates.iter().map(|(v, _)| v).sum::<f64>() / 5.0;
+        let error = estimates.iter().map(|(_, e)| e * e).sum::<f64>().sqrt() / 5.0;
+
+        assert!((mean - analytic).abs() < 4.0 * error);
+
+        // The likelihood ratio also handles discontinuous payoffs:
+        // vega of a cash-or-nothing call is -e^{-rT} phi(d2) d1 / v.
+        let d2 = d1() - V * T.sqrt();
+        let digital = -f64::exp(-R * T) * Gaussian::default().pdf(d2) * d1() / V;
+        let (vega, error) = MonteCarloEngine::new(S, R, V, T, 200_000, 7)
+            .mc_vega_likelihood_ratio(|s| if s > K { 1.0 } else { 0.0 });
+
+        assert!((vega - digital).abs() < 4.0 * error);
+    }
+
+    #[test]
+    fn test_monte_carlo_price() {
+        let engine = MonteCarloEngine::new(S, R, V, T, 100_000, 1);
+        let result = engine.price(|s| (s - K).max(0.0));
+        let (price, error) = (result.value, result.standard_error.unwrap());
+
+        let n = Gaussian::default();
+        let d2 = d1() - V * T.sqrt();
+        let analytic = S * n.cdf(d1()) - K * f64::exp(-R * T) * n.cdf(d2);
+
+        assert!((price - analytic).abs() < 4.0 * error);
+        assert_eq!(result.iterations, Some(100_000));
+        assert!(!result.has_warnings());
+    }
+
+    #[test]
+    fn test_importance_sampling_far_otm_call() {
+        let strike = 200.0;
+
+        let n = Gaussian::default();
+        let d1 = ((S / strike).ln() + (R + 0.5 * V * V) * T) / (V * T.sqrt());
+        let d2 = d1 - V * T.sqrt();
+        let analytic = S * n.cdf(d1) - strike * f64::exp(-R * T) * n.cdf(d2);
+
+        let engine = MonteCarloEngine::new(S, R, V, T, 20_000, 3);
+        let payoff = |s: f64| (s - strike).max(0.0);
+
+        let plain = engine.price(payoff);
+        let sampled = engine.price_importance_sampled(payoff, engine.importance_shift(strike));
+        let sampled_error = sampled.standard_error.unwrap();
+
+        assert!((sampled.value - analytic).abs() < 4.0 * sampled_error);
+        assert!(sampled_error < 0.2 * plain.standard_error.unwrap());
+
+        // Plain Monte Carlo rarely hits the payoff region, and says so.
+        assert!(plain.has_warnings());
+        assert!(!sampled.has_warnings());
+
+        // A zero shift is plain Monte Carlo.
+        assert_eq!(engine.price_importance_sampled(payoff, 0.0), plain);
+    }
+
+    #[test]
+    fn test_power_law_importance_sampling_far_otm_call() {
+        let strike = 200.0;
+
+        let n = Gaussian::default();
+        let d1 = ((S / strike).ln() + (R + 0.5 * V * V) * T) / (V * T.sqrt());
+        let d2 = d1 - V * T.sqrt();
+        let analytic = S * n.cdf(d1) - strike * f64::exp(-R * T) * n.cdf(d2);
+
+        let engine = MonteCarloEngine::new(S, R, V, T, 20_000, 3);
+        let payoff = |s: f64| (s - strike).max(0.0);
+
+        let plain = engine.price(payoff);
+        let sampled = engine.price_with_sampler(
+            payoff,
+            ImportanceSampler::PowerLaw {
+                shift: engine.importance_shift(strike),
+                tail_index: 4.0,
+            },
+        );
+        let sampled_error = sampled.standard_error.unwrap();
+
+        assert!((sampled.value - analytic).abs() < 4.0 * sampled_error);
+        assert!(sampled_error < 0.2 * plain.standard_error.unwrap());
+        assert!(!sampled.has_warnings());
+
+        // Also unbiased with a quasi-random sequence.
+        let sobol = engine
+            .with_sequence(SampleSequence::Sobol)
+            .price_with_sampler(
+                payoff,
+                ImportanceSampler::PowerLaw {
+                    shift: engine.importance_shift(strike),
+                    tail_index: 4.0,
+                },
+            );
+
+        assert!((sobol.value - analytic).abs() < 4.0 * sobol.standard_error.unwrap());
+    }
+
+    #[test]
+    fn test_quasi_random_sequences_reduce_error() {
+        let n = Gaussian::default();
+        let d2 = d1() - V * T.sqrt();
+        let analytic = S * n.cdf(d1()) - K * f64::exp(-R * T) * n.cdf(d2);
+        let payoff = |s: f64| (s - K).max(0.0);
+
+        // 16 replications of 2^12 points.
+        let engine = MonteCarloEngine::new(S, R, V, T, 1 << 16, 5);
+        let plain = engine.price(payoff);
+
+        for sequence in [SampleSequence::Sobol, SampleSequence::Halton] {
+            let quasi = engine.with_sequence(sequence).price(payoff);
+            let error = quasi.standard_error.unwrap();
+
+            assert_eq!(quasi.iterations, Some(1 << 16));
+            assert!((quasi.value - analytic).abs() < 4.0 * error);
+            assert!(error < 0.1 * plain.standard_error.unwrap());
+        }
+
+        // The Greeks use the same draws.
+        let (delta, error) = engine
+            .with_sequence(SampleSequence::Sobol)
+            .mc_delta_pathwise(|s| if s > K { 1.0 } else { 0.0 });
+        let (_, plain_error) = engine.mc_delta_pathwise(|s| if s > K { 1.0 } else { 0.0 });
+
+        assert!((delta - n.cdf(d1())).abs() < 4.0 * error);
+        assert!(error < plain_error);
+    }
+
+    #[test]
+    fn test_antithetic_variates() {
+        let n = Gaussian::default();
+        let d2 = d1() - V * T.sqrt();
+        let analytic = S * n.cdf(d1()) - K * f64::exp(-R * T) * n.cdf(d2);
+
+        let engine = MonteCarloEngine::new(S, R, V, T, 50_000, 11);
+        let plain = engine.price(|s| (s - K).max(0.0));
+        let antithetic = engine
+            .with_antithetic_variates()
+            .price(|s| (s - K).max(0.0));
+        let error = antithetic.standard_error.unwrap();
+
+        assert_eq!(antithetic.iterations, Some(100_000));
+        assert!((antithetic.value - analytic).abs() < 4.0 * error);
+        assert!(error < 0.75 * plain.standard_error.unwrap());
+
+        // A linear payoff is priced exactly: the forward.
+        let forward = engine.with_antithetic_variates().price(|s| s - K);
+        let exact = S - K * f64::exp(-R * T);
+        assert!((forward.value - exact).abs() < 0.05 * plain.standard_error.unwrap());
+    }
+
+    #[test]
+    fn test_control_variates() {
+        let n = Gaussian::default();
+        let d2 = d1() - V * T.sqrt();
+        let call = S * n.cdf(d1()) - K * f64::exp(-R * T) * n.cdf(d2);
+
+        // An in-the-money call, controlled by the underlying.
+        let engine = MonteCarloEngine::new(S, R, V, T, 50_000, 13);
+        let payoff = |s: f64| (s - 90.0).max(0.0);
+        let plain = engine.price(payoff);
+        let controlled = engine.price_with_underlying_control(payoff);
+
+        let d1_itm = ((S / 90.0).ln() + (R + 0.5 * V * V) * T) / (V * T.sqrt());
+        let analytic = S * n.cdf(d1_itm) - 90.0 * f64::exp(-R * T) * n.cdf(d1_itm - V * T.sqrt());
+        let error = controlled.standard_error.unwrap();
+
+        assert!((controlled.value - analytic).abs() < 4.0 * error);
+        assert!(error < 0.4 * plain.standard_error.unwrap());
+        assert!(controlled.diagnostic("control_correlation").unwrap() > 0.9);
+
+        // A call struck at 110, controlled by the call struck at 105: the
+        // regressed coefficient is close to one.
+        let otm = |s: f64| (s - 110.0).max(0.0);
+        let result = engine.price_with_control_variate(otm, |s| (s - K).max(0.0), call);
+        assert!(result.diagnostic("control_coefficient").unwrap() > 0.8);
+        assert!(result.standard_error.unwrap() < 0.3 * engine.price(otm).standard_error.unwrap());
+    }
+
+    #[test]
+    fn test_dividend_schedule() {
+        let schedule = DividendSchedule::new()
+            .with_dividend_yield(0.01)
+            .with_cash_dividend(0.25, 2.0)
+            .with_proportional_dividend(0.5, 0.03)
+            .with_cash_dividend(0.75, 2.0);
+        let engine =
+            MonteCarloEngine::new(S, R, V, T, 100_000, 5).with_dividend_schedule(&schedule);
+
+        // Black-Scholes on the adjusted spot, with dividend yield q.
+        let (s, q) = (schedule.adjusted_spot(S, R, T), 0.01);
+        let d1 = ((s / K).ln() + (R - q + 0.5 * V * V) * T) / (V * T.sqrt());
+        let d2 = d1 - V * T.sqrt();
+        let n = Gaussian::default();
+        let analytic = s * f64::exp(-q * T) * n.cdf(d1) - K * f64::exp(-R * T) * n.cdf(d2);
+
+        let result = engine.price(|s| (s - K).max(0.0));
+        assert!((result.value - analytic).abs() < 4.0 * result.standard_error.unwrap());
+
+        // The delta with respect to the spot carries the proportional dividends.
+        let (delta, error) = engine.mc_delta_pathwise(|s| if s > K { 1.0 } else { 0.0 });
+        let analytic = f64::exp(-q * T) * 0.97 * n.cdf(d1);
+        assert!((delta - analytic).abs() < 4.0 * error);
+    }
+}
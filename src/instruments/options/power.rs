@@ -12,7 +12,8 @@
 //! Power contracts are options with the payoff: (S/K)^i
 //! where i is the (fixed) power of the contract.
 
-use crate::time::{today, DayCountConvention};
+use crate::instruments::Maturity;
+use crate::time::DayCountConvention;
 use time::Date;
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -41,6 +42,9 @@ pub struct PowerOption {
     pub evaluation_date: Option<Date>,
     /// `expiry_date` - Expiry date.
     pub expiration_date: Date,
+
+    /// Day count convention of the time to expiry.
+    pub day_count_convention: DayCountConvention,
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -70,6 +74,7 @@ impl PowerOption {
             volatility,
             evaluation_date,
             expiration_date,
+            day_count_convention: DayCountConvention::default(),
         }
     }
 
@@ -84,10 +89,7 @@ impl PowerOption {
         let i = self.power;
 
         // Compute time to maturity.
-        let T = DayCountConvention::default().day_count_factor(
-            self.evaluation_date.unwrap_or(today()),
-            self.expiration_date,
-        );
+        let T = self.year_fraction();
 
         (S / K).powf(i) * (((b - 0.5 * v.powi(2)) * i - r + 0.5 * (i * v).powi(2)) * T).exp()
     }
@@ -100,7 +102,7 @@ impl PowerOption {
 #[cfg(test)]
 mod tests_power_contract {
     use super::*;
-    use crate::{assert_approx_equal, RUSTQUANT_EPSILON};
+    use crate::{assert_approx_equal, time::today, RUSTQUANT_EPSILON};
     use time::Duration;
 
     #[test]
@@ -114,8 +116,41 @@ mod tests_power_contract {
             volatility: 0.25,
             evaluation_date: None,
             expiration_date: today() + Duration::days(182),
+            day_count_convention: DayCountConvention::default(),
         };
 
         assert_approx_equal!(power_option.price(), 0.83144001309052, RUSTQUANT_EPSILON);
     }
+
+    #[test]
+    fn test_power_day_count_convention() {
+        let evaluation_date = time::macros::date!(2024 - 01 - 05);
+        let mut power_option = PowerOption {
+            initial_price: 400.,
+            strike_price: 450.,
+            power: 2.,
+            risk_free_rate: 0.08,
+            cost_of_carry: 0.06,
+            volatility: 0.25,
+            evaluation_date: Some(evaluation_date),
+            expiration_date: evaluation_date + Duration::days(182),
+            day_count_convention: DayCountConvention::Actual_360,
+        };
+
+        assert_approx_equal!(
+            power_option.year_fraction(),
+            182.0 / 360.0,
+            RUSTQUANT_EPSILON
+        );
+        let act_360 = power_option.price();
+
+        power_option.day_count_convention = DayCountConvention::Actual_365_Fixed;
+
+        assert_approx_equal!(
+            power_option.year_fraction(),
+            182.0 / 365.0,
+            RUSTQUANT_EPSILON
+        );
+        assert!(power_option.price() != act_360);
+    }
 }
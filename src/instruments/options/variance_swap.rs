@@ -0,0 +1,112 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Variance swaps.
+//!
+//! The fair variance strike is replicated by a static portfolio of
+//! out-of-the-money options (the log contract), weighted by $1/K^2$:
+//!
+//! $$
+//! K_{var} = \frac{2 e^{r \tau}}{\tau} \left(
+//!     \int_0^F \frac{P(K)}{K^2} dK + \int_F^\infty \frac{C(K)}{K^2} dK
+//! \right)
+//! $$
+//!
+//! where $F$ is the forward and $P(K)$, $C(K)$ are the put and call prices
+//! at strike $K$, each priced at its own volatility from the smile.
+
+use crate::instruments::options::{black_scholes_merton::generalised_black_scholes, TypeFlag};
+use crate::math::integrate;
+
+/// Number of standard deviations (at the forward) of log-moneyness
+/// covered by the replication integrals on each side of the forward.
+const STRIKE_RANGE_STD_DEVS: f64 = 12.0;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Fair variance strike (annualised variance, i.e. a volatility squared)
+/// of a variance swap.
+///
+/// - `smile`: implied volatility as a function of strike.
+/// - `f`: forward price of the underlying to the swap maturity.
+/// - `tau`: time to maturity (in years).
+/// - `rate`: continuously-compounded risk-free rate.
+///
+/// The integrals are computed in log-moneyness $k = \ln(K / F)$, truncated
+/// at a number of standard deviations of the at-the-forward volatility.
+///
+/// # Panics
+///
+/// Panics if `f` or `tau` is not positive.
+#[must_use]
+pub fn variance_swap_fair_strike<F>(smile: F, f: f64, tau: f64, rate: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    assert!(f > 0.0, "The forward must be positive.");
+    assert!(tau > 0.0, "The time to maturity must be positive.");
+
+    let discount = f64::exp(-rate * tau);
+    let range = STRIKE_RANGE_STD_DEVS * smile(f) * tau.sqrt();
+
+    // With K = F e^k, dK / K^2 = e^{-k} dk / F.
+    // Options on the forward are priced with Black (1976), i.e. no carry.
+    let otm_price = |k: f64| {
+        let strike = f * k.exp();
+        let option_type = if k > 0.0 {
+            TypeFlag::Call
+        } else {
+            TypeFlag::Put
+        };
+        let price =
+            generalised_black_scholes(f, strike, rate, 0.0, smile(strike), tau, option_type);
+
+        price * (-k).exp() / f
+    };
+
+    let puts = integrate(otm_price, -range, 0.0);
+    let calls = integrate(otm_price, 0.0, range);
+
+    2.0 / (tau * discount) * (puts + calls)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_variance_swap {
+    use super::*;
+    use crate::assert_approx_equal;
+
+    #[test]
+    fn test_flat_smile_fair_strike() {
+        for (v, tau, rate) in [(0.2, 1.0, 0.05), (0.35, 0.25, 0.0), (0.1, 3.0, 0.02)] {
+            let strike = variance_swap_fair_strike(|_| v, 100.0, tau, rate);
+
+            assert_approx_equal!(strike, v * v, 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_skewed_smile_raises_fair_strike() {
+        let (f, tau, atm) = (100.0, 1.0, 0.2);
+
+        // Volatility rising for low strikes, as in equity markets.
+        let skew = |strike: f64| f64::max(atm - 0.1 * (strike / f).ln(), 0.05);
+
+        let flat = variance_swap_fair_strike(|_| atm, f, tau, 0.03);
+        let skewed = variance_swap_fair_strike(skew, f, tau, 0.03);
+
+        assert!(skewed > atm * atm);
+        assert!(skewed > flat);
+    }
+}
@@ -0,0 +1,110 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Output of numerical pricers.
+//!
+//! Monte Carlo and tree pricers return a [`PricingResult`] rather than a bare
+//! price, so that the error estimate, the amount of work done, and any
+//! non-fatal problems found along the way travel with the value.
+
+use std::fmt;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Result of a numerical pricer.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PricingResult {
+    /// The computed value.
+    pub value: f64,
+
+    /// Standard error of the value, for simulation-based pricers.
+    pub standard_error: Option<f64>,
+
+    /// Number of iterations used: simulated paths, or tree steps.
+    pub iterations: Option<usize>,
+
+    /// Non-fatal warnings, e.g. about instability or poor convergence.
+    pub warnings: Vec<String>,
+
+    /// Named method-specific diagnostics (e.g. a regression residual).
+    pub diagnostics: Vec<(String, f64)>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl PricingResult {
+    /// Create a new result with the given value and nothing else.
+    #[must_use]
+    pub fn new(value: f64) -> Self {
+        Self {
+            value,
+            ..Self::default()
+        }
+    }
+
+    /// Set the standard error.
+    #[must_use]
+    pub fn with_standard_error(mut self, standard_error: f64) -> Self {
+        self.standard_error = Some(standard_error);
+        self
+    }
+
+    /// Set the number of iterations.
+    #[must_use]
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = Some(iterations);
+        self
+    }
+
+    /// Add a named diagnostic.
+    #[must_use]
+    pub fn with_diagnostic(mut self, name: &str, value: f64) -> Self {
+        self.diagnostics.push((name.to_string(), value));
+        self
+    }
+
+    /// Add a warning.
+    pub fn warn(&mut self, warning: impl Into<String>) {
+        self.warnings.push(warning.into());
+    }
+
+    /// Whether any warnings were raised.
+    #[must_use]
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+
+    /// Value of the diagnostic with the given name, if any.
+    #[must_use]
+    pub fn diagnostic(&self, name: &str) -> Option<f64> {
+        self.diagnostics
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+impl fmt::Display for PricingResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)?;
+
+        if let Some(standard_error) = self.standard_error {
+            write!(f, " (± {standard_error})")?;
+        }
+        for warning in &self.warnings {
+            write!(f, "\nWarning: {warning}")?;
+        }
+
+        Ok(())
+    }
+}
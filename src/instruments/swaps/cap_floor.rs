@@ -0,0 +1,484 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Caps and floors, as strips of caplets and floorlets on a forward rate.
+//!
+//! A caplet on the simple forward rate $F$ of the period $[T_s, T_e]$, fixed
+//! at $T_s$ and paid at $T_p$, is worth
+//!
+//! $$
+//! N \tau P(T_p) \left[ F \Phi(d_1) - K \Phi(d_2) \right],
+//! \quad d_{1,2} = \frac{\ln(F / K) \pm \sigma^2 T_s / 2}{\sigma \sqrt{T_s}}
+//! $$
+//!
+//! under the Black-76 (lognormal) model, and
+//!
+//! $$
+//! N \tau P(T_p) \left[ (F - K) \Phi(d) + \sigma \sqrt{T_s} \phi(d) \right],
+//! \quad d = \frac{F - K}{\sigma \sqrt{T_s}}
+//! $$
+//!
+//! under the Bachelier (normal) model, which also holds for negative
+//! forwards and strikes. Floorlets are the corresponding puts.
+
+use crate::data::{Curve, Surface2D, VolatilitySurface};
+use crate::error::RustQuantError;
+use crate::instruments::options::TypeFlag;
+use crate::math::distributions::{Distribution, Gaussian};
+use crate::math::interpolation::Interpolator;
+use crate::time::{DayCountConvention, Schedule};
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Model of the forward rate for pricing interest rate options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateOptionModel {
+    /// Black-76: a lognormal forward, with lognormal volatilities.
+    Black,
+
+    /// Bachelier: a normal forward, with normal (absolute) volatilities.
+    Bachelier,
+}
+
+/// Cap or floor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapFloorType {
+    /// A cap, made of caplets (calls on the forward rate).
+    Cap,
+
+    /// A floor, made of floorlets (puts on the forward rate).
+    Floor,
+}
+
+/// Volatility of an interest rate option by time to expiry (in years) and
+/// strike, in the units of the model it is used with.
+pub trait OptionVolatility {
+    /// Volatility at the given time to expiry and strike.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the volatility is not defined
+    ///   at the given point.
+    fn option_volatility(&self, expiry: f64, strike: f64) -> Result<f64, RustQuantError>;
+}
+
+/// Caplet or floorlet on the simple forward rate of one accrual period,
+/// fixed at the start of the period.
+#[derive(Debug, Clone, Copy)]
+pub struct Caplet {
+    /// Caplet or floorlet.
+    pub cap_floor_type: CapFloorType,
+
+    /// Notional.
+    pub notional: f64,
+
+    /// Strike rate.
+    pub strike: f64,
+
+    /// Start of the accrual period, when the rate fixes.
+    pub accrual_start: Date,
+
+    /// End of the accrual period.
+    pub accrual_end: Date,
+
+    /// Payment date.
+    pub payment_date: Date,
+
+    /// Year fraction of the accrual period.
+    pub accrual_factor: f64,
+}
+
+/// Cap or floor: a strip of caplets or floorlets over the periods of a
+/// schedule.
+#[allow(clippy::module_name_repetitions)]
+pub struct CapFloor {
+    /// Cap or floor.
+    pub cap_floor_type: CapFloorType,
+
+    /// Notional.
+    pub notional: f64,
+
+    /// Strike rate.
+    pub strike: f64,
+
+    /// Accrual schedule, whose day count convention accrues the forward rate.
+    pub schedule: Schedule,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl RateOptionModel {
+    /// Undiscounted price of a European call or put on a forward, per unit
+    /// of notional and accrual.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the volatility is negative, or
+    ///   under the Black model the forward or strike is not positive.
+    pub fn undiscounted_price(
+        self,
+        option_type: TypeFlag,
+        forward: f64,
+        strike: f64,
+        volatility: f64,
+        expiry: f64,
+    ) -> Result<f64, RustQuantError> {
+        if volatility.is_nan() || volatility < 0.0 {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the volatility ({volatility}) must not be negative"
+            )));
+        }
+        if self == Self::Black && !(forward > 0.0 && strike > 0.0) {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the Black model needs a positive forward ({forward}) and strike ({strike}), \
+                 use the Bachelier model instead"
+            )));
+        }
+
+        let phi = match option_type {
+            TypeFlag::Call => 1.0,
+            TypeFlag::Put => -1.0,
+        };
+        let std_dev = volatility * expiry.max(0.0).sqrt();
+
+        if std_dev == 0.0 {
+            return Ok((phi * (forward - strike)).max(0.0));
+        }
+
+        let n = Gaussian::default();
+
+        Ok(match self {
+            Self::Black => {
+                let d1 = ((forward / strike).ln() + 0.5 * std_dev * std_dev) / std_dev;
+                let d2 = d1 - std_dev;
+
+                phi * (forward * n.cdf(phi * d1) - strike * n.cdf(phi * d2))
+            }
+            Self::Bachelier => {
+                let d = (forward - strike) / std_dev;
+
+                phi * (forward - strike) * n.cdf(phi * d) + std_dev * n.pdf(d)
+            }
+        })
+    }
+}
+
+impl CapFloorType {
+    /// The option on the forward rate: a call for a cap, a put for a floor.
+    #[must_use]
+    pub fn option_type(self) -> TypeFlag {
+        match self {
+            Self::Cap => TypeFlag::Call,
+            Self::Floor => TypeFlag::Put,
+        }
+    }
+}
+
+impl OptionVolatility for f64 {
+    /// A flat volatility.
+    fn option_volatility(&self, _expiry: f64, _strike: f64) -> Result<f64, RustQuantError> {
+        Ok(*self)
+    }
+}
+
+impl<I: Interpolator<f64, f64> + Default> OptionVolatility for Surface2D<I> {
+    /// Volatility from a grid by time to expiry and strike, which may hold
+    /// normal volatilities at negative strikes.
+    fn option_volatility(&self, expiry: f64, strike: f64) -> Result<f64, RustQuantError> {
+        self.value(expiry, strike).map_err(|error| {
+            RustQuantError::InvalidArgument(format!(
+                "no volatility at expiry {expiry} and strike {strike}: {error:?}"
+            ))
+        })
+    }
+}
+
+impl OptionVolatility for VolatilitySurface {
+    /// Lognormal volatility from the smiles of the surface.
+    fn option_volatility(&self, expiry: f64, strike: f64) -> Result<f64, RustQuantError> {
+        self.volatility(expiry, strike)
+    }
+}
+
+/// Time to expiry (in years) of a rate option, under Actual/365 Fixed.
+pub(crate) fn time_to_expiry(valuation_date: Date, expiry_date: Date) -> f64 {
+    DayCountConvention::Actual_365_Fixed.day_count_factor(valuation_date, expiry_date)
+}
+
+impl Caplet {
+    /// New caplet or floorlet over `[accrual_start, accrual_end]`, accrued
+    /// under the given day count convention.
+    #[must_use]
+    pub fn new(
+        cap_floor_type: CapFloorType,
+        notional: f64,
+        strike: f64,
+        accrual_start: Date,
+        accrual_end: Date,
+        payment_date: Date,
+        day_count_convention: DayCountConvention,
+    ) -> Self {
+        Self {
+            cap_floor_type,
+            notional,
+            strike,
+            accrual_start,
+            accrual_end,
+            payment_date,
+            accrual_factor: day_count_convention.day_count_factor(accrual_start, accrual_end),
+        }
+    }
+
+    /// Simple forward rate over the accrual period implied by `forecast`.
+    pub fn forward_rate<F: Curve>(&self, forecast: &F) -> f64 {
+        (forecast.discount_factor(self.accrual_start) / forecast.discount_factor(self.accrual_end)
+            - 1.0)
+            / self.accrual_factor
+    }
+
+    /// Price of the caplet at the valuation date, with the volatility at its
+    /// fixing and strike.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the rate has already fixed, or
+    ///   as for [`RateOptionModel::undiscounted_price`] and the volatility.
+    pub fn price<C: Curve, F: Curve, V: OptionVolatility + ?Sized>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        forecast: &F,
+        volatility: &V,
+        model: RateOptionModel,
+    ) -> Result<f64, RustQuantError> {
+        if self.accrual_start <= valuation_date {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the caplet fixed on {}, on or before the valuation date",
+                self.accrual_start
+            )));
+        }
+
+        let expiry = time_to_expiry(valuation_date, self.accrual_start);
+        let sigma = volatility.option_volatility(expiry, self.strike)?;
+
+        let undiscounted = model.undiscounted_price(
+            self.cap_floor_type.option_type(),
+            self.forward_rate(forecast),
+            self.strike,
+            sigma,
+            expiry,
+        )?;
+        let df =
+            discount.discount_factor(self.payment_date) / discount.discount_factor(valuation_date);
+
+        Ok(self.notional * self.accrual_factor * df * undiscounted)
+    }
+}
+
+impl CapFloor {
+    /// New cap or floor over the periods of a schedule.
+    #[must_use]
+    pub fn new(
+        cap_floor_type: CapFloorType,
+        notional: f64,
+        strike: f64,
+        schedule: Schedule,
+    ) -> Self {
+        Self {
+            cap_floor_type,
+            notional,
+            strike,
+            schedule,
+        }
+    }
+
+    /// The caplets (or floorlets) of each period of the schedule.
+    #[must_use]
+    pub fn caplets(&self) -> Vec<Caplet> {
+        let accruals = self
+            .schedule
+            .accrual_schedule(self.schedule.day_counting_convention);
+
+        self.schedule
+            .coupon_periods()
+            .iter()
+            .zip(accruals)
+            .map(|(period, tau)| Caplet {
+                cap_floor_type: self.cap_floor_type,
+                notional: self.notional,
+                strike: self.strike,
+                accrual_start: period.start,
+                accrual_end: period.end,
+                payment_date: period.payment,
+                accrual_factor: tau,
+            })
+            .collect()
+    }
+
+    /// Price of the cap or floor at the valuation date: the sum of its
+    /// caplets still to fix. Caplets fixed on or before the valuation date
+    /// are known cash flows, and are left out (as is market practice for
+    /// the first caplet of a spot starting cap).
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` as for [`Caplet::price`].
+    pub fn price<C: Curve, F: Curve, V: OptionVolatility + ?Sized>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        forecast: &F,
+        volatility: &V,
+        model: RateOptionModel,
+    ) -> Result<f64, RustQuantError> {
+        self.caplets()
+            .iter()
+            .filter(|caplet| caplet.accrual_start > valuation_date)
+            .map(|caplet| caplet.price(valuation_date, discount, forecast, volatility, model))
+            .sum()
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_cap_floor {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use crate::math::interpolation::LinearInterpolator;
+    use crate::time::{
+        countries::north_america::united_states::UnitedStatesCalendar, DateRollingConvention,
+        Frequency,
+    };
+    use time::macros::date;
+
+    const TODAY: Date = date!(2024 - 03 - 20);
+
+    /// 3 year quarterly cap or floor, starting today.
+    fn cap_floor(cap_floor_type: CapFloorType, strike: f64) -> CapFloor {
+        let schedule = Schedule::regular(
+            TODAY,
+            date!(2027 - 03 - 20),
+            Frequency::Quarterly,
+            &UnitedStatesCalendar,
+            DateRollingConvention::ModifiedFollowing,
+            DayCountConvention::Actual_360,
+        )
+        .unwrap();
+
+        CapFloor::new(cap_floor_type, 1_000_000.0, strike, schedule)
+    }
+
+    fn curve(rates: &[f64]) -> YieldCurve {
+        YieldCurve::from_dates_and_rates(
+            &[TODAY, date!(2025 - 03 - 20), date!(2030 - 03 - 20)],
+            rates,
+        )
+    }
+
+    #[test]
+    fn test_black_and_bachelier_formulas() {
+        // At the money, Black is F (2 N(s / 2) - 1) and Bachelier is
+        // s / sqrt(2 pi), for a standard deviation s.
+        let black = RateOptionModel::Black
+            .undiscounted_price(TypeFlag::Call, 0.05, 0.05, 0.2, 1.0)
+            .unwrap();
+        assert_approx_equal!(black, 0.003_982_784, 1e-9);
+
+        let bachelier = RateOptionModel::Bachelier
+            .undiscounted_price(TypeFlag::Put, -0.005, -0.005, 0.01, 1.0)
+            .unwrap();
+        assert_approx_equal!(bachelier, 0.003_989_423, 1e-9);
+
+        // Expired options are worth their intrinsic value.
+        let expired = RateOptionModel::Black
+            .undiscounted_price(TypeFlag::Put, 0.03, 0.05, 0.2, 0.0)
+            .unwrap();
+        assert_approx_equal!(expired, 0.02, 1e-15);
+
+        assert!(RateOptionModel::Black
+            .undiscounted_price(TypeFlag::Call, -0.001, 0.01, 0.2, 1.0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_cap_floor_parity() {
+        let curve = curve(&[0.04, 0.042, 0.045]);
+        let strike = 0.043;
+
+        let cap = cap_floor(CapFloorType::Cap, strike);
+        let floor = cap_floor(CapFloorType::Floor, strike);
+
+        // Cap less floor is a swap over the caplets still to fix.
+        let swap: f64 = cap
+            .caplets()
+            .iter()
+            .skip(1)
+            .map(|c| {
+                c.notional
+                    * c.accrual_factor
+                    * curve.discount_factor(c.payment_date)
+                    * (c.forward_rate(&curve) - strike)
+            })
+            .sum();
+
+        for (model, volatility) in [
+            (RateOptionModel::Black, 0.25),
+            (RateOptionModel::Bachelier, 0.01),
+        ] {
+            let cap = cap
+                .price(TODAY, &curve, &curve, &volatility, model)
+                .unwrap();
+            let floor = floor
+                .price(TODAY, &curve, &curve, &volatility, model)
+                .unwrap();
+
+            assert!(cap > 0.0 && floor > 0.0);
+            assert_approx_equal!(cap - floor, swap, 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_negative_rates_and_surface() {
+        let curve = curve(&[-0.006, -0.004, -0.002]);
+        let floor = cap_floor(CapFloorType::Floor, 0.0);
+
+        // Normal volatilities on a grid by expiry and strike.
+        let surface: Surface2D<LinearInterpolator<f64, f64>> = Surface2D::new(
+            vec![0.0, 1.0, 5.0],
+            vec![-0.02, 0.0, 0.02],
+            vec![vec![0.008; 3], vec![0.009; 3], vec![0.01; 3]],
+        )
+        .unwrap();
+
+        let price = floor
+            .price(TODAY, &curve, &curve, &surface, RateOptionModel::Bachelier)
+            .unwrap();
+        let low = floor
+            .price(TODAY, &curve, &curve, &0.008, RateOptionModel::Bachelier)
+            .unwrap();
+        let high = floor
+            .price(TODAY, &curve, &curve, &0.01, RateOptionModel::Bachelier)
+            .unwrap();
+
+        assert!(low < price && price < high);
+
+        // A floor at zero is in the money with negative forwards, and cannot
+        // be priced with the Black model.
+        assert!(price > 0.0);
+        assert!(matches!(
+            floor.price(TODAY, &curve, &curve, &0.2, RateOptionModel::Black),
+            Err(RustQuantError::InvalidArgument(_))
+        ));
+    }
+}
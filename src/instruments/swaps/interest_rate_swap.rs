@@ -0,0 +1,458 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Vanilla fixed-for-floating interest rate swap.
+//!
+//! The two legs have their own [`Schedule`]s, so their calendars, payment
+//! frequencies, business day and day count conventions are independent.
+//! The swap is valued with separate discount and forecast curves: the
+//! floating rate of each unfixed period is the simple forward rate over the
+//! period implied by the forecast curve, and all cash flows are discounted
+//! off the discount curve, as of the valuation date.
+
+use crate::data::{Curve, YieldCurve};
+use crate::math::interpolation::Interpolator;
+use crate::time::{CouponPeriod, DayCountConvention, Schedule};
+use std::collections::BTreeMap;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Direction of a swap, by its fixed leg.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwapDirection {
+    /// Pay fixed, receive floating.
+    Payer,
+
+    /// Receive fixed, pay floating.
+    Receiver,
+}
+
+/// Vanilla fixed-for-floating interest rate swap.
+#[allow(clippy::module_name_repetitions)]
+pub struct InterestRateSwap {
+    /// Payer or receiver of the fixed leg.
+    pub direction: SwapDirection,
+
+    /// Notional of both legs.
+    pub notional: f64,
+
+    /// Annual rate of the fixed leg.
+    pub fixed_rate: f64,
+
+    /// Schedule of the fixed leg.
+    pub fixed_schedule: Schedule,
+
+    /// Schedule of the floating leg.
+    pub floating_schedule: Schedule,
+
+    /// Spread over the index rate of the floating leg.
+    pub floating_spread: f64,
+
+    /// Known index fixings, keyed by the (adjusted) start date of their
+    /// floating period.
+    pub fixings: BTreeMap<Date, f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// One basis point.
+pub(crate) const BASIS_POINT: f64 = 1e-4;
+
+impl SwapDirection {
+    /// Sign of the fixed leg for the holder of the swap.
+    pub(crate) fn fixed_sign(self) -> f64 {
+        match self {
+            Self::Payer => -1.0,
+            Self::Receiver => 1.0,
+        }
+    }
+}
+
+impl InterestRateSwap {
+    /// New swap, with no floating spread and no known fixings.
+    #[must_use]
+    pub fn new(
+        direction: SwapDirection,
+        notional: f64,
+        fixed_rate: f64,
+        fixed_schedule: Schedule,
+        floating_schedule: Schedule,
+    ) -> Self {
+        Self {
+            direction,
+            notional,
+            fixed_rate,
+            fixed_schedule,
+            floating_schedule,
+            floating_spread: 0.0,
+            fixings: BTreeMap::new(),
+        }
+    }
+
+    /// Set the spread over the index rate of the floating leg.
+    #[must_use]
+    pub fn with_floating_spread(mut self, spread: f64) -> Self {
+        self.floating_spread = spread;
+        self
+    }
+
+    /// Set the known index fixings, keyed by the start date of their period.
+    #[must_use]
+    pub fn with_fixings(mut self, fixings: BTreeMap<Date, f64>) -> Self {
+        self.fixings = fixings;
+        self
+    }
+
+    /// Index rate of each floating period: the fixing if known, otherwise
+    /// the simple forward rate over the period implied by `forecast`.
+    pub fn floating_rates<C: Curve>(&self, forecast: &C) -> Vec<f64> {
+        let accruals = self
+            .floating_schedule
+            .accrual_schedule(self.floating_schedule.day_counting_convention);
+
+        self.floating_schedule
+            .coupon_periods()
+            .iter()
+            .zip(accruals)
+            .map(|(period, tau)| {
+                self.fixings.get(&period.start).copied().unwrap_or_else(|| {
+                    (forecast.discount_factor(period.start) / forecast.discount_factor(period.end)
+                        - 1.0)
+                        / tau
+                })
+            })
+            .collect()
+    }
+
+    /// Annuity of the fixed leg: its present value per unit of fixed rate,
+    /// $N \sum_i \tau_i P(t_i)$.
+    pub fn annuity<C: Curve>(&self, valuation_date: Date, discount: &C) -> f64 {
+        let n = self.fixed_schedule.coupon_periods().len();
+
+        leg_npv(
+            &self.fixed_schedule,
+            self.notional,
+            &vec![1.0; n],
+            valuation_date,
+            discount,
+        )
+    }
+
+    /// Present value of the fixed leg (as received).
+    pub fn fixed_leg_npv<C: Curve>(&self, valuation_date: Date, discount: &C) -> f64 {
+        self.fixed_rate * self.annuity(valuation_date, discount)
+    }
+
+    /// Present value of the floating leg (as received), including the spread.
+    pub fn floating_leg_npv<C: Curve, F: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        forecast: &F,
+    ) -> f64 {
+        let rates: Vec<f64> = self
+            .floating_rates(forecast)
+            .iter()
+            .map(|rate| rate + self.floating_spread)
+            .collect();
+
+        leg_npv(
+            &self.floating_schedule,
+            self.notional,
+            &rates,
+            valuation_date,
+            discount,
+        )
+    }
+
+    /// Net present value of the swap to its holder.
+    pub fn npv<C: Curve, F: Curve>(&self, valuation_date: Date, discount: &C, forecast: &F) -> f64 {
+        self.direction.fixed_sign()
+            * (self.fixed_leg_npv(valuation_date, discount)
+                - self.floating_leg_npv(valuation_date, discount, forecast))
+    }
+
+    /// Par rate: the fixed rate at which the swap has zero value,
+    /// i.e. the floating leg value divided by the annuity.
+    pub fn par_rate<C: Curve, F: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        forecast: &F,
+    ) -> f64 {
+        self.floating_leg_npv(valuation_date, discount, forecast)
+            / self.annuity(valuation_date, discount)
+    }
+
+    /// PV01: the change in value of the swap for a one basis point rise in
+    /// the fixed rate.
+    pub fn pv01<C: Curve>(&self, valuation_date: Date, discount: &C) -> f64 {
+        self.direction.fixed_sign() * self.annuity(valuation_date, discount) * BASIS_POINT
+    }
+
+    /// DV01: the change in value of the swap for a one basis point parallel
+    /// rise in the zero rates of both curves, by central differences.
+    pub fn dv01<I, J>(
+        &self,
+        valuation_date: Date,
+        discount: &YieldCurve<I>,
+        forecast: &YieldCurve<J>,
+    ) -> f64
+    where
+        I: Interpolator<f64, f64> + Default,
+        J: Interpolator<f64, f64> + Default,
+    {
+        let npv = |shift: f64| {
+            self.npv(
+                valuation_date,
+                &shifted(discount, shift),
+                &shifted(forecast, shift),
+            )
+        };
+
+        (npv(BASIS_POINT) - npv(-BASIS_POINT)) / 2.0
+    }
+
+    /// Interest accrued on the fixed leg at the given date (as received).
+    #[must_use]
+    pub fn fixed_accrued(&self, date: Date) -> f64 {
+        accrued(&self.fixed_schedule, self.notional * self.fixed_rate, date)
+    }
+
+    /// Interest accrued on the floating leg at the given date (as received).
+    /// The current period's rate is its fixing, or otherwise the forward rate
+    /// implied by `forecast`.
+    pub fn floating_accrued<F: Curve>(&self, date: Date, forecast: &F) -> f64 {
+        let periods = self.floating_schedule.coupon_periods();
+
+        periods
+            .iter()
+            .zip(self.floating_rates(forecast))
+            .find(|(period, _)| period.start <= date && date < period.end)
+            .map_or(0.0, |(period, rate)| {
+                accrual(period, self.floating_schedule.day_counting_convention, date)
+                    * self.notional
+                    * (rate + self.floating_spread)
+            })
+    }
+
+    /// Net interest accrued on the swap at the given date, to its holder.
+    pub fn accrued_interest<F: Curve>(&self, date: Date, forecast: &F) -> f64 {
+        self.direction.fixed_sign()
+            * (self.fixed_accrued(date) - self.floating_accrued(date, forecast))
+    }
+}
+
+/// Present value of a leg paying `notional * rates[i] * tau_i` on each
+/// period of `schedule`, over the periods paid after the valuation date.
+pub(crate) fn leg_npv<C: Curve>(
+    schedule: &Schedule,
+    notional: f64,
+    rates: &[f64],
+    valuation_date: Date,
+    discount: &C,
+) -> f64 {
+    let df_0 = discount.discount_factor(valuation_date);
+
+    schedule
+        .coupon_periods()
+        .iter()
+        .zip(schedule.accrual_schedule(schedule.day_counting_convention))
+        .zip(rates)
+        .filter(|((period, _), _)| period.payment > valuation_date)
+        .map(|((period, tau), rate)| {
+            notional * rate * tau * discount.discount_factor(period.payment) / df_0
+        })
+        .sum()
+}
+
+/// Interest accrued at `date` on a leg paying a constant `amount` per year.
+pub(crate) fn accrued(schedule: &Schedule, amount: f64, date: Date) -> f64 {
+    schedule
+        .coupon_periods()
+        .iter()
+        .find(|period| period.start <= date && date < period.end)
+        .map_or(0.0, |period| {
+            amount * accrual(period, schedule.day_counting_convention, date)
+        })
+}
+
+/// Year fraction accrued from the start of a period to `date`.
+fn accrual(period: &CouponPeriod, day_count: DayCountConvention, date: Date) -> f64 {
+    match day_count {
+        DayCountConvention::Actual_Actual_ICMA => {
+            DayCountConvention::day_count_factor_actual_actual_icma_with_reference(
+                period.start,
+                date,
+                period.unadjusted_start,
+                period.unadjusted_end,
+            )
+        }
+        _ => day_count.day_count_factor(period.start, date),
+    }
+}
+
+/// Curve with its zero rates shifted in parallel.
+fn shifted<I>(curve: &YieldCurve<I>, shift: f64) -> YieldCurve<I>
+where
+    I: Interpolator<f64, f64> + Default,
+{
+    YieldCurve::interpolated(
+        curve
+            .rates
+            .iter()
+            .map(|(date, rate)| (*date, rate + shift))
+            .collect(),
+    )
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_interest_rate_swap {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::time::{
+        countries::north_america::united_states::UnitedStatesCalendar, DateRollingConvention,
+        Frequency,
+    };
+    use time::macros::date;
+
+    const START: Date = date!(2024 - 03 - 20);
+    const END: Date = date!(2029 - 03 - 20);
+
+    fn curve(rates: [f64; 3]) -> YieldCurve {
+        YieldCurve::from_dates_and_rates(
+            &[
+                date!(2024 - 03 - 20),
+                date!(2026 - 03 - 20),
+                date!(2034 - 03 - 20),
+            ],
+            &rates,
+        )
+    }
+
+    /// 5 year swap: semi-annual 30/360 fixed leg against a quarterly
+    /// Actual/360 floating leg, both modified following.
+    fn swap(direction: SwapDirection, fixed_rate: f64) -> InterestRateSwap {
+        let schedule = |frequency, day_count| {
+            Schedule::regular(
+                START,
+                END,
+                frequency,
+                &UnitedStatesCalendar,
+                DateRollingConvention::ModifiedFollowing,
+                day_count,
+            )
+            .unwrap()
+        };
+
+        InterestRateSwap::new(
+            direction,
+            1_000_000.0,
+            fixed_rate,
+            schedule(Frequency::SemiAnnually, DayCountConvention::Thirty_360_ISDA),
+            schedule(Frequency::Quarterly, DayCountConvention::Actual_360),
+        )
+    }
+
+    #[test]
+    fn test_par_rate() {
+        let discount = curve([0.035, 0.037, 0.04]);
+        let forecast = curve([0.04, 0.041, 0.043]);
+
+        let par = swap(SwapDirection::Payer, 0.0).par_rate(START, &discount, &forecast);
+        assert!(par > 0.04 && par < 0.045);
+
+        // Both sides of a par swap are worth nothing.
+        for direction in [SwapDirection::Payer, SwapDirection::Receiver] {
+            assert_approx_equal!(
+                swap(direction, par).npv(START, &discount, &forecast),
+                0.0,
+                1e-6
+            );
+        }
+
+        // A payer gains from paying less than par.
+        let payer = swap(SwapDirection::Payer, par - 0.001);
+        let receiver = swap(SwapDirection::Receiver, par - 0.001);
+        let npv = payer.npv(START, &discount, &forecast);
+
+        assert!(npv > 0.0);
+        assert_approx_equal!(receiver.npv(START, &discount, &forecast), -npv, 1e-8);
+        assert_approx_equal!(npv, -10.0 * payer.pv01(START, &discount), 1e-6);
+
+        // With a single curve, the floating leg is worth 1 - P(T) at the start
+        // (up to the modified following payment dates).
+        let floating = payer.floating_leg_npv(START, &discount, &discount);
+        let redemption = 1_000_000.0 * (1.0 - discount.discount_factor(END));
+        assert!((floating / redemption - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_pv01_and_dv01() {
+        let discount = curve([0.035, 0.037, 0.04]);
+        let forecast = curve([0.04, 0.041, 0.043]);
+
+        let payer = swap(SwapDirection::Payer, 0.042);
+        let receiver = swap(SwapDirection::Receiver, 0.042);
+
+        // About 4.6 years of annuity on 1m notional.
+        let pv01 = receiver.pv01(START, &discount);
+        assert!(pv01 > 400.0 && pv01 < 500.0);
+        assert_approx_equal!(payer.pv01(START, &discount), -pv01, 1e-10);
+
+        // Payers gain when rates rise, by about the PV01.
+        let dv01 = payer.dv01(START, &discount, &forecast);
+        assert!(dv01 > 0.0);
+        assert!((dv01 / pv01 - 1.0).abs() < 0.1);
+        assert_approx_equal!(receiver.dv01(START, &discount, &forecast), -dv01, 1e-8);
+    }
+
+    #[test]
+    fn test_accrued_and_fixings() {
+        let forecast = curve([0.04, 0.041, 0.043]);
+        let fixing = 0.0531;
+
+        let payer = swap(SwapDirection::Payer, 0.042)
+            .with_fixings(BTreeMap::from([(START, fixing)]))
+            .with_floating_spread(0.001);
+
+        assert_approx_equal!(payer.floating_rates(&forecast)[0], fixing, 1e-12);
+
+        // 30 days into both first periods, or 29 under 30/360.
+        let date = date!(2024 - 04 - 19);
+        let fixed = 1_000_000.0 * 0.042 * 29.0 / 360.0;
+        let floating = 1_000_000.0 * (fixing + 0.001) * 30.0 / 360.0;
+
+        assert_approx_equal!(payer.fixed_accrued(date), fixed, 1e-8);
+        assert_approx_equal!(payer.floating_accrued(date, &forecast), floating, 1e-8);
+        assert_approx_equal!(
+            payer.accrued_interest(date, &forecast),
+            floating - fixed,
+            1e-8
+        );
+
+        // Nothing accrues after maturity, and nothing is left to value.
+        let discount = curve([0.035, 0.037, 0.04]);
+        assert_approx_equal!(payer.accrued_interest(END, &forecast), 0.0, 1e-12);
+        assert_approx_equal!(
+            payer.npv(date!(2029 - 06 - 01), &discount, &forecast),
+            0.0,
+            1e-12
+        );
+    }
+}
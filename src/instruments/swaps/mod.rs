@@ -0,0 +1,24 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Caps and floors, priced with the Black or Bachelier model.
+pub mod cap_floor;
+pub use cap_floor::*;
+
+/// Vanilla fixed-for-floating interest rate swap.
+pub mod interest_rate_swap;
+pub use interest_rate_swap::*;
+
+/// Overnight indexed swap, compounding overnight rates in arrears.
+pub mod overnight_indexed_swap;
+pub use overnight_indexed_swap::*;
+
+/// European swaptions, priced with the Black or Bachelier model.
+pub mod swaption;
+pub use swaption::*;
@@ -0,0 +1,427 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Overnight indexed swap (OIS): a fixed leg against a leg of overnight rates
+//! (e.g. SOFR, ESTR or SONIA) compounded daily in arrears.
+//!
+//! Each period of the overnight leg pays an [`OvernightIndexedCashflow`],
+//! compounded with the swap's [`OvernightConventions`] (lookback, lockout,
+//! observation shift and payment delay). Rates observed before the forecast
+//! curve's initial date are taken from the historical fixings, so a seasoned
+//! swap needs the fixings of its current period.
+
+use super::interest_rate_swap::{accrued, leg_npv, SwapDirection, BASIS_POINT};
+use crate::cashflows::{
+    compounded_overnight_rate_with_conventions, Cashflow, OvernightConventions,
+    OvernightIndexedCashflow,
+};
+use crate::data::Curve;
+use crate::error::RustQuantError;
+use crate::time::{advance_business_days, Calendar, Schedule};
+use std::collections::BTreeMap;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Overnight indexed swap.
+///
+/// The fixed leg pays on the payment dates of its schedule, so a payment
+/// delay on the fixed leg is set on its schedule (see
+/// [`ScheduleBuilder::payment_lag`](crate::time::ScheduleBuilder::payment_lag)),
+/// while the overnight leg is delayed by its conventions.
+#[allow(clippy::module_name_repetitions)]
+pub struct OvernightIndexedSwap<K: Calendar> {
+    /// Payer or receiver of the fixed leg.
+    pub direction: SwapDirection,
+
+    /// Notional of both legs.
+    pub notional: f64,
+
+    /// Annual rate of the fixed leg.
+    pub fixed_rate: f64,
+
+    /// Schedule of the fixed leg.
+    pub fixed_schedule: Schedule,
+
+    /// Accrual schedule of the overnight leg.
+    pub overnight_schedule: Schedule,
+
+    /// Business day calendar of the overnight index.
+    pub calendar: K,
+
+    /// Compounding conventions of the overnight leg.
+    pub conventions: OvernightConventions,
+
+    /// Spread over the compounded overnight rate.
+    pub spread: f64,
+
+    /// Historical overnight fixings, keyed by observation date.
+    pub fixings: BTreeMap<Date, f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<K: Calendar> OvernightIndexedSwap<K> {
+    /// New overnight indexed swap, with the default conventions (plain
+    /// daily compounding with Actual/360 accrual), no spread and no fixings.
+    #[must_use]
+    pub fn new(
+        direction: SwapDirection,
+        notional: f64,
+        fixed_rate: f64,
+        fixed_schedule: Schedule,
+        overnight_schedule: Schedule,
+        calendar: K,
+    ) -> Self {
+        Self {
+            direction,
+            notional,
+            fixed_rate,
+            fixed_schedule,
+            overnight_schedule,
+            calendar,
+            conventions: OvernightConventions::default(),
+            spread: 0.0,
+            fixings: BTreeMap::new(),
+        }
+    }
+
+    /// Set the compounding conventions of the overnight leg.
+    #[must_use]
+    pub fn with_conventions(mut self, conventions: OvernightConventions) -> Self {
+        self.conventions = conventions;
+        self
+    }
+
+    /// Set the spread over the compounded overnight rate.
+    #[must_use]
+    pub fn with_spread(mut self, spread: f64) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Set the historical overnight fixings, keyed by observation date.
+    #[must_use]
+    pub fn with_fixings(mut self, fixings: BTreeMap<Date, f64>) -> Self {
+        self.fixings = fixings;
+        self
+    }
+
+    /// Cash flows of the overnight leg paid after the valuation date.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a rate is observed before the
+    ///   forecast curve's initial date and there is no fixing for it.
+    pub fn overnight_cashflows<F: Curve>(
+        &self,
+        valuation_date: Date,
+        forecast: &F,
+    ) -> Result<Vec<OvernightIndexedCashflow>, RustQuantError> {
+        self.overnight_schedule
+            .coupon_periods()
+            .iter()
+            .filter(|period| {
+                advance_business_days(
+                    period.end,
+                    self.conventions.payment_delay_days,
+                    &self.calendar,
+                ) > valuation_date
+            })
+            .map(|period| {
+                OvernightIndexedCashflow::new(
+                    self.notional,
+                    self.spread,
+                    period.start,
+                    period.end,
+                    forecast,
+                    &self.calendar,
+                    &self.conventions,
+                    &self.fixings,
+                )
+            })
+            .collect()
+    }
+
+    /// Annuity of the fixed leg: its present value per unit of fixed rate.
+    pub fn annuity<C: Curve>(&self, valuation_date: Date, discount: &C) -> f64 {
+        let n = self.fixed_schedule.coupon_periods().len();
+
+        leg_npv(
+            &self.fixed_schedule,
+            self.notional,
+            &vec![1.0; n],
+            valuation_date,
+            discount,
+        )
+    }
+
+    /// Present value of the fixed leg (as received).
+    pub fn fixed_leg_npv<C: Curve>(&self, valuation_date: Date, discount: &C) -> f64 {
+        self.fixed_rate * self.annuity(valuation_date, discount)
+    }
+
+    /// Present value of the overnight leg (as received), including the spread.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` as for
+    ///   [`OvernightIndexedSwap::overnight_cashflows`].
+    pub fn overnight_leg_npv<C: Curve, F: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        forecast: &F,
+    ) -> Result<f64, RustQuantError> {
+        let df_0 = discount.discount_factor(valuation_date);
+
+        Ok(self
+            .overnight_cashflows(valuation_date, forecast)?
+            .iter()
+            .map(|cashflow| {
+                cashflow.amount() * discount.discount_factor(cashflow.payment_date) / df_0
+            })
+            .sum())
+    }
+
+    /// Net present value of the swap to its holder.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` as for
+    ///   [`OvernightIndexedSwap::overnight_cashflows`].
+    pub fn npv<C: Curve, F: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        forecast: &F,
+    ) -> Result<f64, RustQuantError> {
+        Ok(self.direction.fixed_sign()
+            * (self.fixed_leg_npv(valuation_date, discount)
+                - self.overnight_leg_npv(valuation_date, discount, forecast)?))
+    }
+
+    /// Par rate: the fixed rate at which the swap has zero value.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` as for
+    ///   [`OvernightIndexedSwap::overnight_cashflows`].
+    pub fn par_rate<C: Curve, F: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        forecast: &F,
+    ) -> Result<f64, RustQuantError> {
+        Ok(self.overnight_leg_npv(valuation_date, discount, forecast)?
+            / self.annuity(valuation_date, discount))
+    }
+
+    /// PV01: the change in value of the swap for a one basis point rise in
+    /// the fixed rate.
+    pub fn pv01<C: Curve>(&self, valuation_date: Date, discount: &C) -> f64 {
+        self.direction.fixed_sign() * self.annuity(valuation_date, discount) * BASIS_POINT
+    }
+
+    /// Interest accrued on the fixed leg at the given date (as received).
+    #[must_use]
+    pub fn fixed_accrued(&self, date: Date) -> f64 {
+        accrued(&self.fixed_schedule, self.notional * self.fixed_rate, date)
+    }
+
+    /// Interest accrued on the overnight leg at the given date (as received),
+    /// compounding the overnight rates from the start of the current period.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a rate is observed before the
+    ///   forecast curve's initial date and there is no fixing for it.
+    pub fn overnight_accrued<F: Curve>(
+        &self,
+        date: Date,
+        forecast: &F,
+    ) -> Result<f64, RustQuantError> {
+        let periods = self.overnight_schedule.coupon_periods();
+
+        let Some(period) = periods
+            .iter()
+            .find(|period| period.start < date && date < period.end)
+        else {
+            return Ok(0.0);
+        };
+
+        let rate = compounded_overnight_rate_with_conventions(
+            period.start,
+            date,
+            forecast,
+            &self.calendar,
+            &self.conventions,
+            &self.fixings,
+        )?;
+        let tau = self
+            .conventions
+            .day_count_convention
+            .day_count_factor(period.start, date);
+
+        Ok(self.notional * (rate + self.spread) * tau)
+    }
+
+    /// Net interest accrued on the swap at the given date, to its holder.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` as for
+    ///   [`OvernightIndexedSwap::overnight_accrued`].
+    pub fn accrued_interest<F: Curve>(
+        &self,
+        date: Date,
+        forecast: &F,
+    ) -> Result<f64, RustQuantError> {
+        Ok(self.direction.fixed_sign()
+            * (self.fixed_accrued(date) - self.overnight_accrued(date, forecast)?))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_overnight_indexed_swap {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use crate::time::{
+        countries::north_america::united_states::UnitedStatesCalendar, DateRollingConvention,
+        DayCountConvention, Frequency,
+    };
+    use time::macros::date;
+
+    const START: Date = date!(2024 - 03 - 20);
+    const END: Date = date!(2026 - 03 - 20);
+
+    fn curve() -> YieldCurve {
+        YieldCurve::from_dates_and_rates(
+            &[
+                date!(2024 - 03 - 20),
+                date!(2025 - 03 - 20),
+                date!(2027 - 03 - 20),
+            ],
+            &[0.053, 0.049, 0.045],
+        )
+    }
+
+    /// 2 year SOFR swap, with annual Actual/360 payments on both legs.
+    fn ois(
+        direction: SwapDirection,
+        fixed_rate: f64,
+    ) -> OvernightIndexedSwap<UnitedStatesCalendar> {
+        let schedule = || {
+            Schedule::regular(
+                START,
+                END,
+                Frequency::Annually,
+                &UnitedStatesCalendar,
+                DateRollingConvention::ModifiedFollowing,
+                DayCountConvention::Actual_360,
+            )
+            .unwrap()
+        };
+
+        OvernightIndexedSwap::new(
+            direction,
+            1_000_000.0,
+            fixed_rate,
+            schedule(),
+            schedule(),
+            UnitedStatesCalendar,
+        )
+    }
+
+    #[test]
+    fn test_ois_par_rate() {
+        let curve = curve();
+        let payer = ois(SwapDirection::Payer, 0.05);
+
+        // Compounding the curve's own overnight forwards, the overnight leg
+        // is worth 1 - P(T) at the start.
+        assert_approx_equal!(
+            payer.overnight_leg_npv(START, &curve, &curve).unwrap(),
+            1_000_000.0 * (1.0 - curve.discount_factor(END) / curve.discount_factor(START)),
+            1e-6
+        );
+
+        let par = payer.par_rate(START, &curve, &curve).unwrap();
+        assert!(par > 0.045 && par < 0.053);
+
+        for direction in [SwapDirection::Payer, SwapDirection::Receiver] {
+            let npv = ois(direction, par).npv(START, &curve, &curve).unwrap();
+            assert_approx_equal!(npv, 0.0, 1e-6);
+        }
+
+        // A receiver gains from receiving more than par, by its PV01 per bp.
+        let receiver = ois(SwapDirection::Receiver, par + 0.0025);
+        assert_approx_equal!(
+            receiver.npv(START, &curve, &curve).unwrap(),
+            25.0 * receiver.pv01(START, &curve),
+            1e-6
+        );
+    }
+
+    #[test]
+    fn test_seasoned_ois_needs_fixings() {
+        // Valued on 1 April 2024 off a curve starting that day.
+        let today = date!(2024 - 04 - 01);
+        let curve =
+            YieldCurve::from_dates_and_rates(&[today, date!(2027 - 04 - 01)], &[0.053, 0.045]);
+
+        let conventions = OvernightConventions {
+            lookback_days: 2,
+            observation_shift: true,
+            ..OvernightConventions::default()
+        };
+        let swap = ois(SwapDirection::Payer, 0.05).with_conventions(conventions);
+
+        assert!(matches!(
+            swap.npv(today, &curve, &curve),
+            Err(RustQuantError::MissingInput(_))
+        ));
+
+        // SOFR fixings from 18 to 29 March 2024: the observations of the
+        // current period before the curve starts, shifted back two days.
+        let fixings: BTreeMap<Date, f64> = [
+            date!(2024 - 03 - 18),
+            date!(2024 - 03 - 19),
+            date!(2024 - 03 - 20),
+            date!(2024 - 03 - 21),
+            date!(2024 - 03 - 22),
+            date!(2024 - 03 - 25),
+            date!(2024 - 03 - 26),
+            date!(2024 - 03 - 27),
+            date!(2024 - 03 - 28),
+            date!(2024 - 03 - 29),
+        ]
+        .into_iter()
+        .map(|date| (date, 0.0531))
+        .collect();
+
+        let swap = swap.with_fixings(fixings);
+        assert!(swap.npv(today, &curve, &curve).is_ok());
+
+        // The twelve days accrued so far compound the fixings.
+        let accrued = swap.overnight_accrued(today, &curve).unwrap();
+        assert!((accrued / (1_000_000.0 * 0.0531 * 12.0 / 360.0) - 1.0).abs() < 1e-3);
+
+        let fixed = 1_000_000.0 * 0.05 * 12.0 / 360.0;
+        assert_approx_equal!(swap.fixed_accrued(today), fixed, 1e-8);
+        assert_approx_equal!(
+            swap.accrued_interest(today, &curve).unwrap(),
+            accrued - fixed,
+            1e-8
+        );
+    }
+}
@@ -0,0 +1,286 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! European swaptions: options to enter an interest rate swap.
+//!
+//! Under the annuity measure the forward swap rate $S$ is a martingale, so a
+//! payer swaption (a call on $S$) struck at the fixed rate $K$ of the swap is
+//! worth
+//!
+//! $$
+//! A(t) \cdot \mathbb{E}^A \left[ (S - K)^+ \right]
+//! $$
+//!
+//! where $A(t)$ is the annuity of the fixed leg, and the expectation is
+//! given by the Black-76 or Bachelier formula (see [`RateOptionModel`]).
+//! A receiver swaption is the corresponding put.
+
+use super::cap_floor::{time_to_expiry, OptionVolatility, RateOptionModel};
+use super::interest_rate_swap::{InterestRateSwap, SwapDirection};
+use crate::data::{Curve, VolatilityCube};
+use crate::error::RustQuantError;
+use crate::instruments::options::TypeFlag;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// European swaption, with physical settlement into the underlying swap.
+///
+/// The direction of the underlying swap makes the swaption a payer
+/// ([`SwapDirection::Payer`]) or receiver ([`SwapDirection::Receiver`])
+/// swaption, and its fixed rate is the strike.
+pub struct Swaption {
+    /// Expiry date of the option.
+    pub expiry_date: Date,
+
+    /// The swap entered into on exercise.
+    pub swap: InterestRateSwap,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Swaption {
+    /// New European swaption on the given swap.
+    #[must_use]
+    pub fn new(expiry_date: Date, swap: InterestRateSwap) -> Self {
+        Self { expiry_date, swap }
+    }
+
+    /// Strike of the swaption: the fixed rate of the swap.
+    #[must_use]
+    pub fn strike(&self) -> f64 {
+        self.swap.fixed_rate
+    }
+
+    /// Tenor of the underlying swap (in years, Actual/365 Fixed), from the
+    /// start to the end of its fixed leg.
+    #[must_use]
+    pub fn tenor(&self) -> f64 {
+        let periods = self.swap.fixed_schedule.coupon_periods();
+
+        match (periods.first(), periods.last()) {
+            (Some(first), Some(last)) => time_to_expiry(first.start, last.end),
+            _ => 0.0,
+        }
+    }
+
+    /// Forward swap rate of the underlying swap.
+    pub fn forward_swap_rate<C: Curve, F: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        forecast: &F,
+    ) -> f64 {
+        self.swap.par_rate(valuation_date, discount, forecast)
+    }
+
+    /// Price of the swaption at the valuation date, with the volatility at
+    /// its expiry and strike.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the swaption has expired, or as
+    ///   for [`RateOptionModel::undiscounted_price`] and the volatility.
+    pub fn price<C: Curve, F: Curve, V: OptionVolatility + ?Sized>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        forecast: &F,
+        volatility: &V,
+        model: RateOptionModel,
+    ) -> Result<f64, RustQuantError> {
+        let expiry = self.time_to_expiry(valuation_date)?;
+        let sigma = volatility.option_volatility(expiry, self.strike())?;
+
+        self.price_with_volatility(valuation_date, discount, forecast, sigma, model)
+    }
+
+    /// Price of the swaption at the valuation date, with the volatility of
+    /// a swaption cube at its expiry, tenor and (absolute) strike.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the swaption has expired, is
+    ///   outside the cube, or as for [`RateOptionModel::undiscounted_price`].
+    pub fn price_with_cube<C: Curve, F: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        forecast: &F,
+        cube: &VolatilityCube,
+        model: RateOptionModel,
+    ) -> Result<f64, RustQuantError> {
+        let expiry = self.time_to_expiry(valuation_date)?;
+        let sigma = cube
+            .volatility(expiry, self.tenor(), self.strike())
+            .map_err(|error| {
+                RustQuantError::InvalidArgument(format!(
+                    "no swaption volatility at expiry {expiry} and tenor {}: {error:?}",
+                    self.tenor()
+                ))
+            })?;
+
+        self.price_with_volatility(valuation_date, discount, forecast, sigma, model)
+    }
+
+    /// Time to expiry (in years) at the valuation date.
+    fn time_to_expiry(&self, valuation_date: Date) -> Result<f64, RustQuantError> {
+        if self.expiry_date <= valuation_date {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the swaption expired on {}",
+                self.expiry_date
+            )));
+        }
+
+        Ok(time_to_expiry(valuation_date, self.expiry_date))
+    }
+
+    /// Annuity times the model's undiscounted price of the forward swap rate.
+    fn price_with_volatility<C: Curve, F: Curve>(
+        &self,
+        valuation_date: Date,
+        discount: &C,
+        forecast: &F,
+        volatility: f64,
+        model: RateOptionModel,
+    ) -> Result<f64, RustQuantError> {
+        let option_type = match self.swap.direction {
+            SwapDirection::Payer => TypeFlag::Call,
+            SwapDirection::Receiver => TypeFlag::Put,
+        };
+
+        let undiscounted = model.undiscounted_price(
+            option_type,
+            self.forward_swap_rate(valuation_date, discount, forecast),
+            self.strike(),
+            volatility,
+            self.time_to_expiry(valuation_date)?,
+        )?;
+
+        Ok(self.swap.annuity(valuation_date, discount) * undiscounted)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_swaption {
+    use super::*;
+    use crate::assert_approx_equal;
+    use crate::data::YieldCurve;
+    use crate::time::{
+        countries::north_america::united_states::UnitedStatesCalendar, DateRollingConvention,
+        DayCountConvention, Frequency, Schedule,
+    };
+    use time::macros::date;
+
+    const TODAY: Date = date!(2024 - 03 - 20);
+    const EXPIRY: Date = date!(2025 - 03 - 20);
+
+    fn curve(rates: &[f64]) -> YieldCurve {
+        YieldCurve::from_dates_and_rates(
+            &[TODAY, date!(2026 - 03 - 20), date!(2034 - 03 - 20)],
+            rates,
+        )
+    }
+
+    /// 1 year into 5 year swaption, on an annual 30/360 against quarterly
+    /// Actual/360 swap.
+    fn swaption(direction: SwapDirection, strike: f64) -> Swaption {
+        let schedule = |frequency, day_count| {
+            Schedule::regular(
+                EXPIRY,
+                date!(2030 - 03 - 20),
+                frequency,
+                &UnitedStatesCalendar,
+                DateRollingConvention::ModifiedFollowing,
+                day_count,
+            )
+            .unwrap()
+        };
+
+        let swap = InterestRateSwap::new(
+            direction,
+            1_000_000.0,
+            strike,
+            schedule(Frequency::Annually, DayCountConvention::Thirty_360_ISDA),
+            schedule(Frequency::Quarterly, DayCountConvention::Actual_360),
+        );
+
+        Swaption::new(EXPIRY, swap)
+    }
+
+    #[test]
+    fn test_payer_receiver_parity() {
+        let curve = curve(&[0.04, 0.042, 0.045]);
+        let payer = swaption(SwapDirection::Payer, 0.045);
+        let receiver = swaption(SwapDirection::Receiver, 0.045);
+
+        let forward = payer.forward_swap_rate(TODAY, &curve, &curve);
+        assert!(forward > 0.04 && forward < 0.05);
+
+        // Payer less receiver is the forward starting payer swap.
+        let swap = payer.swap.npv(TODAY, &curve, &curve);
+
+        for (model, volatility) in [
+            (RateOptionModel::Black, 0.2),
+            (RateOptionModel::Bachelier, 0.009),
+        ] {
+            let payer = payer
+                .price(TODAY, &curve, &curve, &volatility, model)
+                .unwrap();
+            let receiver = receiver
+                .price(TODAY, &curve, &curve, &volatility, model)
+                .unwrap();
+
+            assert!(payer > 0.0 && receiver > 0.0);
+            assert_approx_equal!(payer - receiver, swap, 1e-8);
+        }
+
+        assert!(payer
+            .price(EXPIRY, &curve, &curve, &0.2, RateOptionModel::Black)
+            .is_err());
+    }
+
+    #[test]
+    fn test_cube_and_negative_rates() {
+        let curve = curve(&[-0.004, -0.002, 0.001]);
+        let receiver = swaption(SwapDirection::Receiver, 0.0);
+
+        // A flat cube of normal volatilities prices as its flat volatility.
+        let cube = VolatilityCube::new(
+            vec![0.5, 2.0],
+            vec![1.0, 10.0],
+            vec![-0.01, 0.01],
+            vec![vec![vec![0.007; 2]; 2]; 2],
+        )
+        .unwrap();
+
+        let price = receiver
+            .price_with_cube(TODAY, &curve, &curve, &cube, RateOptionModel::Bachelier)
+            .unwrap();
+        let flat = receiver
+            .price(TODAY, &curve, &curve, &0.007, RateOptionModel::Bachelier)
+            .unwrap();
+
+        assert_approx_equal!(receiver.tenor(), 5.0, 1e-2);
+        assert_approx_equal!(price, flat, 1e-10);
+        assert!(price > 0.0);
+
+        // The forward swap rate is negative, so only the normal model applies.
+        assert!(receiver.forward_swap_rate(TODAY, &curve, &curve) < 0.0);
+        assert!(receiver
+            .price(TODAY, &curve, &curve, &0.2, RateOptionModel::Black)
+            .is_err());
+    }
+}
@@ -0,0 +1,803 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Archimedean copulas
+//!
+//! $$
+//! C(u) = \psi\left(\sum_i \psi^{-1}(u_i)\right)
+//! $$
+//!
+//! where the generator $\psi$ is the Laplace transform of a positive
+//! "frailty" $V$. They are sampled by the Marshall-Olkin algorithm,
+//! $U_i = \psi(E_i / V)$ for independent standard exponentials $E_i$, and
+//! their density is
+//!
+//! $$
+//! c(u) = \psi^{(d)}\left(\sum_i \psi^{-1}(u_i)\right) \prod_i \left(\psi^{-1}\right)'(u_i).
+//! $$
+
+use crate::{
+    error::RustQuantError,
+    math::{
+        copulas::{kendalls_tau_matrix, validate_observations, Copula, CopulaFitMethod},
+        distributions::gamma::marsaglia_tsang,
+        integration::integrate,
+        optimization::NelderMead,
+        rootfinding::{BrentSolver, Solver},
+    },
+};
+use rand::{Rng, RngCore};
+use rand_distr::{Exp1, Open01};
+use std::f64::consts::PI;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Clayton copula, with generator $\psi(t) = (1 + t)^{-1/\theta}$ for
+/// $\theta > 0$ and a gamma frailty.
+///
+/// Its Kendall's tau is $\theta / (\theta + 2)$ and it has lower tail
+/// dependence $2^{-1/\theta}$: joint crashes, but not joint rallies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClaytonCopula {
+    /// Number of dimensions.
+    dimension: usize,
+
+    /// Theta: the dependence parameter.
+    theta: f64,
+}
+
+/// Gumbel copula, with generator $\psi(t) = \exp(-t^{1/\theta})$ for
+/// $\theta \geq 1$ and a positive stable frailty.
+///
+/// Its Kendall's tau is $1 - 1 / \theta$ and it has upper tail dependence
+/// $2 - 2^{1/\theta}$. It is the only Archimedean extreme value copula.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GumbelCopula {
+    /// Number of dimensions.
+    dimension: usize,
+
+    /// Theta: the dependence parameter.
+    theta: f64,
+}
+
+/// Frank copula, with generator
+/// $\psi(t) = -\ln\left(1 - (1 - e^{-\theta}) e^{-t}\right) / \theta$
+/// and a logarithmic frailty.
+///
+/// It has no tail dependence. Two dimensional Frank copulas also model
+/// negative dependence, with $\theta < 0$; in more dimensions $\theta > 0$.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrankCopula {
+    /// Number of dimensions.
+    dimension: usize,
+
+    /// Theta: the dependence parameter.
+    theta: f64,
+}
+
+/// Generator of an Archimedean copula, and its one parameter family.
+trait Archimedean: Copula + Sized {
+    /// Member of the family in `dimension` dimensions with Kendall's tau `tau`.
+    fn from_kendalls_tau(dimension: usize, tau: f64) -> Result<Self, RustQuantError>;
+
+    /// Member of the family from an unconstrained parameter, for likelihood
+    /// maximisation.
+    fn from_unconstrained(dimension: usize, x: f64) -> Result<Self, RustQuantError>;
+
+    /// Unconstrained parameter of the copula.
+    fn unconstrained(&self) -> f64;
+
+    /// Generator $\psi(t)$.
+    fn generator(&self, t: f64) -> f64;
+
+    /// Inverse generator $\psi^{-1}(u)$.
+    fn inverse_generator(&self, u: f64) -> f64;
+
+    /// $\ln |\psi^{(n)}(t)|$, the logarithm of the absolute value of the
+    /// n-th derivative of the generator.
+    fn log_derivative(&self, n: usize, t: f64) -> f64;
+
+    /// Draws a frailty: a positive variable with Laplace transform $\psi$.
+    fn frailty(&self, rng: &mut dyn RngCore) -> f64;
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Checks that an Archimedean copula has at least one dimension.
+fn check_dimension(dimension: usize) -> Result<(), RustQuantError> {
+    if dimension == 0 {
+        return Err(RustQuantError::InvalidArgument(
+            "a copula needs at least one dimension".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that Kendall's tau is in `(-1, 1)`.
+fn check_kendalls_tau(tau: f64) -> Result<(), RustQuantError> {
+    if tau.is_nan() || tau.abs() >= 1.0 {
+        return Err(RustQuantError::InvalidArgument(format!(
+            "Kendall's tau ({tau}) must be in (-1, 1)"
+        )));
+    }
+
+    Ok(())
+}
+
+impl ClaytonCopula {
+    /// New instance of a Clayton copula.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the dimension is zero or theta
+    ///   is not positive.
+    pub fn new(dimension: usize, theta: f64) -> Result<Self, RustQuantError> {
+        check_dimension(dimension)?;
+
+        if theta.is_nan() || theta <= 0.0 || theta.is_infinite() {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the Clayton parameter ({theta}) must be positive"
+            )));
+        }
+
+        Ok(Self { dimension, theta })
+    }
+
+    /// Fits the copula to observations in the open unit cube, one per row
+    /// (see `pseudo_observations`). Kendall's tau inversion matches the
+    /// average empirical Kendall's tau of the pairs of variables.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there are fewer than two
+    ///   observations or two variables, the observations are not in the open
+    ///   unit cube, or their Kendall's tau is not positive.
+    /// - `RustQuantError::NotConverged` if the likelihood maximisation does not
+    ///   converge.
+    pub fn fit(observations: &[Vec<f64>], method: CopulaFitMethod) -> Result<Self, RustQuantError> {
+        fit_archimedean(observations, method)
+    }
+
+    /// Theta: the dependence parameter.
+    #[must_use]
+    pub fn theta(&self) -> f64 {
+        self.theta
+    }
+
+    /// Kendall's tau of each pair of variables.
+    #[must_use]
+    pub fn kendalls_tau(&self) -> f64 {
+        self.theta / (self.theta + 2.0)
+    }
+
+    /// Coefficient of lower tail dependence of each pair of variables.
+    #[must_use]
+    pub fn tail_dependence(&self) -> f64 {
+        2_f64.powf(-1.0 / self.theta)
+    }
+
+    /// Copula (distribution function) at a point of the unit cube.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u` does not have one entry per dimension.
+    #[must_use]
+    pub fn cdf(&self, u: &[f64]) -> f64 {
+        archimedean_cdf(self, u)
+    }
+}
+
+impl Archimedean for ClaytonCopula {
+    fn from_kendalls_tau(dimension: usize, tau: f64) -> Result<Self, RustQuantError> {
+        check_kendalls_tau(tau)?;
+
+        Self::new(dimension, 2.0 * tau / (1.0 - tau))
+    }
+
+    fn from_unconstrained(dimension: usize, x: f64) -> Result<Self, RustQuantError> {
+        Self::new(dimension, x.exp())
+    }
+
+    fn unconstrained(&self) -> f64 {
+        self.theta.ln()
+    }
+
+    fn generator(&self, t: f64) -> f64 {
+        (1.0 + t).powf(-1.0 / self.theta)
+    }
+
+    fn inverse_generator(&self, u: f64) -> f64 {
+        u.powf(-self.theta) - 1.0
+    }
+
+    fn log_derivative(&self, n: usize, t: f64) -> f64 {
+        let alpha = 1.0 / self.theta;
+
+        (0..n).map(|k| (alpha + k as f64).ln()).sum::<f64>() - (alpha + n as f64) * t.ln_1p()
+    }
+
+    fn frailty(&self, rng: &mut dyn RngCore) -> f64 {
+        marsaglia_tsang(1.0 / self.theta, rng)
+    }
+}
+
+impl GumbelCopula {
+    /// New instance of a Gumbel copula.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the dimension is zero or theta
+    ///   is less than one.
+    pub fn new(dimension: usize, theta: f64) -> Result<Self, RustQuantError> {
+        check_dimension(dimension)?;
+
+        if theta.is_nan() || theta < 1.0 || theta.is_infinite() {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the Gumbel parameter ({theta}) must be at least one"
+            )));
+        }
+
+        Ok(Self { dimension, theta })
+    }
+
+    /// Fits the copula to observations in the open unit cube, one per row
+    /// (see `pseudo_observations`). Kendall's tau inversion matches the
+    /// average empirical Kendall's tau of the pairs of variables.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there are fewer than two
+    ///   observations or two variables, the observations are not in the open
+    ///   unit cube, or their Kendall's tau is negative.
+    /// - `RustQuantError::NotConverged` if the likelihood maximisation does not
+    ///   converge.
+    pub fn fit(observations: &[Vec<f64>], method: CopulaFitMethod) -> Result<Self, RustQuantError> {
+        fit_archimedean(observations, method)
+    }
+
+    /// Theta: the dependence parameter.
+    #[must_use]
+    pub fn theta(&self) -> f64 {
+        self.theta
+    }
+
+    /// Kendall's tau of each pair of variables.
+    #[must_use]
+    pub fn kendalls_tau(&self) -> f64 {
+        1.0 - 1.0 / self.theta
+    }
+
+    /// Coefficient of upper tail dependence of each pair of variables.
+    #[must_use]
+    pub fn tail_dependence(&self) -> f64 {
+        2.0 - 2_f64.powf(1.0 / self.theta)
+    }
+
+    /// Copula (distribution function) at a point of the unit cube.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u` does not have one entry per dimension.
+    #[must_use]
+    pub fn cdf(&self, u: &[f64]) -> f64 {
+        archimedean_cdf(self, u)
+    }
+}
+
+impl Archimedean for GumbelCopula {
+    fn from_kendalls_tau(dimension: usize, tau: f64) -> Result<Self, RustQuantError> {
+        check_kendalls_tau(tau)?;
+
+        Self::new(dimension, 1.0 / (1.0 - tau))
+    }
+
+    fn from_unconstrained(dimension: usize, x: f64) -> Result<Self, RustQuantError> {
+        Self::new(dimension, 1.0 + x.exp())
+    }
+
+    fn unconstrained(&self) -> f64 {
+        (self.theta - 1.0).max(1e-6).ln()
+    }
+
+    fn generator(&self, t: f64) -> f64 {
+        (-t.powf(1.0 / self.theta)).exp()
+    }
+
+    fn inverse_generator(&self, u: f64) -> f64 {
+        (-u.ln()).powf(self.theta)
+    }
+
+    fn log_derivative(&self, n: usize, t: f64) -> f64 {
+        // The n-th derivative is psi(t) t^(-n) sum_k b_k t^(alpha k), with the
+        // coefficients from differentiating the previous derivative.
+        let alpha = 1.0 / self.theta;
+        let mut b = vec![1.0];
+
+        for j in 0..n {
+            b = (0..=j + 1)
+                .map(|k| {
+                    let same = b.get(k).map_or(0.0, |b| (alpha * k as f64 - j as f64) * b);
+                    let lower = if k > 0 { alpha * b[k - 1] } else { 0.0 };
+                    same - lower
+                })
+                .collect();
+        }
+
+        let sum: f64 = b
+            .iter()
+            .enumerate()
+            .map(|(k, b)| b * t.powf(alpha * k as f64))
+            .sum();
+
+        -t.powf(alpha) + sum.abs().ln() - n as f64 * t.ln()
+    }
+
+    fn frailty(&self, rng: &mut dyn RngCore) -> f64 {
+        // Kanter's representation of the positive stable distribution with
+        // Laplace transform exp(-t^alpha).
+        let alpha = 1.0 / self.theta;
+        if alpha == 1.0 {
+            return 1.0;
+        }
+
+        let u = PI * rng.sample::<f64, _>(Open01);
+        let w: f64 = rng.sample(Exp1);
+
+        (alpha * u).sin() / u.sin().powf(1.0 / alpha)
+            * (((1.0 - alpha) * u).sin() / w).powf((1.0 - alpha) / alpha)
+    }
+}
+
+impl FrankCopula {
+    /// New instance of a Frank copula.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the dimension is zero, theta is
+    ///   zero, or theta is negative in more than two dimensions.
+    pub fn new(dimension: usize, theta: f64) -> Result<Self, RustQuantError> {
+        check_dimension(dimension)?;
+
+        if theta.is_nan() || theta == 0.0 || theta.is_infinite() {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the Frank parameter ({theta}) must be non-zero"
+            )));
+        }
+        if dimension > 2 && theta < 0.0 {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the Frank parameter ({theta}) must be positive in more than two dimensions"
+            )));
+        }
+
+        Ok(Self { dimension, theta })
+    }
+
+    /// Fits the copula to observations in the open unit cube, one per row
+    /// (see `pseudo_observations`). Kendall's tau inversion matches the
+    /// average empirical Kendall's tau of the pairs of variables.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there are fewer than two
+    ///   observations or two variables, the observations are not in the open
+    ///   unit cube, or their Kendall's tau is zero (or negative, in more than
+    ///   two dimensions).
+    /// - `RustQuantError::NotConverged` if the likelihood maximisation does not
+    ///   converge.
+    pub fn fit(observations: &[Vec<f64>], method: CopulaFitMethod) -> Result<Self, RustQuantError> {
+        fit_archimedean(observations, method)
+    }
+
+    /// Theta: the dependence parameter.
+    #[must_use]
+    pub fn theta(&self) -> f64 {
+        self.theta
+    }
+
+    /// Kendall's tau of each pair of variables,
+    /// $1 - 4 (1 - D_1(\theta)) / \theta$ with the Debye function
+    /// $D_1(\theta) = \frac{1}{\theta} \int_0^\theta \frac{t}{e^t - 1} dt$.
+    #[must_use]
+    pub fn kendalls_tau(&self) -> f64 {
+        frank_kendalls_tau(self.theta)
+    }
+
+    /// Copula (distribution function) at a point of the unit cube.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u` does not have one entry per dimension.
+    #[must_use]
+    pub fn cdf(&self, u: &[f64]) -> f64 {
+        archimedean_cdf(self, u)
+    }
+
+    /// $1 - (1 - e^{-\theta}) e^{-t}$.
+    fn one_minus_x(&self, t: f64) -> f64 {
+        (-t - self.theta).exp() - (-t).exp_m1()
+    }
+}
+
+impl Archimedean for FrankCopula {
+    fn from_kendalls_tau(dimension: usize, tau: f64) -> Result<Self, RustQuantError> {
+        check_kendalls_tau(tau)?;
+
+        if tau == 0.0 {
+            return Err(RustQuantError::InvalidArgument(
+                "Frank copulas have a non-zero Kendall's tau".to_string(),
+            ));
+        }
+
+        // Kendall's tau is odd and increasing in theta.
+        let mut upper = 1.0;
+        while frank_kendalls_tau(upper) < tau.abs() {
+            upper *= 2.0;
+        }
+        let theta = BrentSolver::default().solve(
+            |theta| frank_kendalls_tau(theta) - tau.abs(),
+            0.0,
+            upper,
+        )?;
+
+        Self::new(dimension, theta.copysign(tau))
+    }
+
+    fn from_unconstrained(dimension: usize, x: f64) -> Result<Self, RustQuantError> {
+        if dimension > 2 {
+            Self::new(dimension, x.exp())
+        } else {
+            Self::new(dimension, x)
+        }
+    }
+
+    fn unconstrained(&self) -> f64 {
+        if self.dimension > 2 {
+            self.theta.ln()
+        } else {
+            self.theta
+        }
+    }
+
+    fn generator(&self, t: f64) -> f64 {
+        -self.one_minus_x(t).ln() / self.theta
+    }
+
+    fn inverse_generator(&self, u: f64) -> f64 {
+        -((-self.theta * u).exp_m1() / (-self.theta).exp_m1()).ln()
+    }
+
+    fn log_derivative(&self, n: usize, t: f64) -> f64 {
+        // The n-th derivative is (-1)^n Li_{1 - n}(x) / theta, with
+        // x = (1 - e^{-theta}) e^{-t} and the polylogarithm
+        // Li_{-m}(x) = sum_k A(m, k) x^(k + 1) / (1 - x)^(m + 1)
+        // in terms of the Eulerian numbers A(m, k).
+        let m = n - 1;
+        let mut eulerian = vec![1.0];
+        for row in 1..=m {
+            eulerian = (0..row)
+                .map(|k| {
+                    let same = eulerian.get(k).map_or(0.0, |a| (k + 1) as f64 * a);
+                    let lower = if k > 0 {
+                        (row - k) as f64 * eulerian[k - 1]
+                    } else {
+                        0.0
+                    };
+                    same + lower
+                })
+                .collect();
+        }
+
+        let x = -(-self.theta).exp_m1() * (-t).exp();
+        let polynomial: f64 = eulerian
+            .iter()
+            .enumerate()
+            .map(|(k, a)| a * x.powi(k as i32 + 1))
+            .sum();
+
+        polynomial.abs().ln() - (m + 1) as f64 * self.one_minus_x(t).ln() - self.theta.abs().ln()
+    }
+
+    fn frailty(&self, rng: &mut dyn RngCore) -> f64 {
+        // Kemp's (1981) algorithm for the logarithmic distribution with
+        // P(V = k) proportional to p^k / k.
+        let p = -(-self.theta).exp_m1();
+        let u: f64 = rng.sample(Open01);
+        if u >= p {
+            return 1.0;
+        }
+
+        let v: f64 = rng.sample(Open01);
+        let log_q = (-(-self.theta * v).exp()).ln_1p();
+        let q = log_q.exp();
+
+        if u < q * q {
+            (1.0 + u.ln() / log_q).floor()
+        } else if u > q {
+            1.0
+        } else {
+            2.0
+        }
+    }
+}
+
+macro_rules! impl_copula_for_archimedean {
+    ($($copula:ty),*) => {
+        $(
+            impl Copula for $copula {
+                fn dimension(&self) -> usize {
+                    self.dimension
+                }
+
+                fn log_pdf(&self, u: &[f64]) -> f64 {
+                    archimedean_log_pdf(self, u)
+                }
+
+                fn sample_with_rng(&self, rng: &mut dyn RngCore) -> Vec<f64> {
+                    archimedean_sample(self, rng)
+                }
+            }
+        )*
+    };
+}
+
+impl_copula_for_archimedean!(ClaytonCopula, GumbelCopula);
+
+impl Copula for FrankCopula {
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn log_pdf(&self, u: &[f64]) -> f64 {
+        archimedean_log_pdf(self, u)
+    }
+
+    /// Negative dependence has no frailty, so the second variable is drawn
+    /// by inverting its distribution conditional on the first.
+    fn sample_with_rng(&self, rng: &mut dyn RngCore) -> Vec<f64> {
+        if self.theta > 0.0 {
+            return archimedean_sample(self, rng);
+        }
+
+        let u: f64 = rng.sample(Open01);
+        let w: f64 = rng.sample(Open01);
+        let decay = (-self.theta * u).exp();
+        let v = -(w * (-self.theta).exp_m1() / (w + (1.0 - w) * decay)).ln_1p() / self.theta;
+
+        vec![u, v.clamp(f64::MIN_POSITIVE, 1.0 - f64::EPSILON)]
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Kendall's tau of the Frank copula with parameter `theta`.
+fn frank_kendalls_tau(theta: f64) -> f64 {
+    // tau = theta / 9 + O(theta^3), without the cancellation of the formula.
+    if theta.abs() < 1e-4 {
+        return theta / 9.0;
+    }
+
+    let a = theta.abs();
+    let debye = integrate(|t| if t == 0.0 { 1.0 } else { t / t.exp_m1() }, 0.0, a) / a;
+
+    (1.0 - 4.0 * (1.0 - debye) / a).copysign(theta)
+}
+
+/// Archimedean copula at a point of the unit cube.
+fn archimedean_cdf<C: Archimedean>(copula: &C, u: &[f64]) -> f64 {
+    assert_eq!(u.len(), copula.dimension(), "one entry per dimension");
+
+    if u.iter().any(|u| *u <= 0.0) {
+        return 0.0;
+    }
+
+    copula.generator(u.iter().map(|u| copula.inverse_generator(u.min(1.0))).sum())
+}
+
+/// Logarithm of the density of an Archimedean copula.
+fn archimedean_log_pdf<C: Archimedean>(copula: &C, u: &[f64]) -> f64 {
+    let d = copula.dimension();
+    assert_eq!(u.len(), d, "one entry per dimension");
+
+    if u.iter().any(|u| *u <= 0.0 || *u >= 1.0) {
+        return f64::NEG_INFINITY;
+    }
+
+    // The derivative of the inverse generator is 1 / psi'(psi^{-1}(u)).
+    let t: Vec<f64> = u.iter().map(|u| copula.inverse_generator(*u)).collect();
+
+    copula.log_derivative(d, t.iter().sum())
+        - t.iter().map(|t| copula.log_derivative(1, *t)).sum::<f64>()
+}
+
+/// Marshall-Olkin sample of an Archimedean copula.
+fn archimedean_sample<C: Archimedean>(copula: &C, rng: &mut dyn RngCore) -> Vec<f64> {
+    let v = copula.frailty(rng);
+
+    (0..copula.dimension())
+        .map(|_| {
+            let e: f64 = rng.sample(Exp1);
+            copula.generator(e / v)
+        })
+        .collect()
+}
+
+/// Fits an Archimedean copula by inversion of the average Kendall's tau of
+/// the pairs of variables, or by maximum likelihood from there.
+fn fit_archimedean<C: Archimedean>(
+    observations: &[Vec<f64>],
+    method: CopulaFitMethod,
+) -> Result<C, RustQuantError> {
+    let d = validate_observations(observations)?;
+
+    let tau = kendalls_tau_matrix(observations)?;
+    let pairs = d * (d - 1) / 2;
+    let average = (0..d)
+        .flat_map(|i| (i + 1..d).map(move |j| (i, j)))
+        .map(|(i, j)| tau[(i, j)])
+        .sum::<f64>()
+        / pairs as f64;
+
+    let start = C::from_kendalls_tau(d, average)?;
+
+    match method {
+        CopulaFitMethod::KendallsTau => Ok(start),
+        CopulaFitMethod::MaximumLikelihood => {
+            let x = NelderMead::new(1e-10, 5_000)
+                .minimize(
+                    |x| {
+                        C::from_unconstrained(d, x[0])
+                            .map_or(f64::INFINITY, |c| -c.log_likelihood(observations))
+                    },
+                    &[start.unconstrained()],
+                )?
+                .minimizer[0];
+
+            C::from_unconstrained(d, x)
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_archimedean {
+    use super::*;
+    use crate::math::copulas::kendalls_tau;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// Mixed partial derivative of the copula by central differences.
+    fn mixed_derivative<C: Archimedean>(copula: &C, u: &[f64]) -> f64 {
+        let d = u.len();
+        let h = 1e-3;
+
+        (0..1 << d)
+            .map(|corner: usize| {
+                let point: Vec<f64> = (0..d)
+                    .map(|i| u[i] + if corner >> i & 1 == 1 { h } else { -h })
+                    .collect();
+                let sign = if (d - corner.count_ones() as usize).is_multiple_of(2) {
+                    1.0
+                } else {
+                    -1.0
+                };
+                sign * archimedean_cdf(copula, &point)
+            })
+            .sum::<f64>()
+            / (2.0 * h).powi(d as i32)
+    }
+
+    #[test]
+    fn test_archimedean_density() {
+        let u = [0.3, 0.55, 0.8];
+
+        let clayton = ClaytonCopula::new(3, 2.0).unwrap();
+        let gumbel = GumbelCopula::new(3, 1.7).unwrap();
+        let frank = FrankCopula::new(3, 5.0).unwrap();
+        let negative = FrankCopula::new(2, -4.0).unwrap();
+
+        let relative = |density: f64, derivative: f64| (density / derivative - 1.0).abs();
+        assert!(relative(clayton.pdf(&u), mixed_derivative(&clayton, &u)) < 1e-4);
+        assert!(relative(gumbel.pdf(&u), mixed_derivative(&gumbel, &u)) < 1e-4);
+        assert!(relative(frank.pdf(&u), mixed_derivative(&frank, &u)) < 1e-4);
+        assert!(relative(negative.pdf(&u[..2]), mixed_derivative(&negative, &u[..2])) < 1e-4);
+
+        // Uniform marginals, and zero density outside the unit cube.
+        assert_approx_equal!(gumbel.cdf(&[0.4, 1.0, 1.0]), 0.4, 1e-12);
+        assert_approx_equal!(negative.cdf(&[1.0, 0.7]), 0.7, 1e-12);
+        assert_eq!(clayton.pdf(&[0.3, 0.0, 0.5]), 0.0);
+
+        assert!(ClaytonCopula::new(2, 0.0).is_err());
+        assert!(GumbelCopula::new(2, 0.5).is_err());
+        assert!(FrankCopula::new(3, -1.0).is_err());
+        assert!(FrankCopula::new(0, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_archimedean_sampling() {
+        let tau = 0.5;
+        let n = 2_000;
+        let mut rng = StdRng::seed_from_u64(17);
+
+        let clayton = ClaytonCopula::from_kendalls_tau(3, tau).unwrap();
+        let gumbel = GumbelCopula::from_kendalls_tau(3, tau).unwrap();
+        let frank = FrankCopula::from_kendalls_tau(3, tau).unwrap();
+        assert_approx_equal!(frank.kendalls_tau(), tau, 1e-10);
+
+        let samples: [Vec<Vec<f64>>; 3] = [
+            (0..n).map(|_| clayton.sample_with_rng(&mut rng)).collect(),
+            (0..n).map(|_| gumbel.sample_with_rng(&mut rng)).collect(),
+            (0..n).map(|_| frank.sample_with_rng(&mut rng)).collect(),
+        ];
+
+        for sample in &samples {
+            let mean = sample.iter().map(|u| u[2]).sum::<f64>() / n as f64;
+            assert!((mean - 0.5).abs() < 0.02);
+
+            let tau_matrix = kendalls_tau_matrix(sample).unwrap();
+            assert!((tau_matrix[(0, 1)] - tau).abs() < 0.03);
+            assert!((tau_matrix[(1, 2)] - tau).abs() < 0.03);
+        }
+
+        // Clayton has lower and Gumbel upper tail dependence.
+        let q = 0.05;
+        let lower = |s: &Vec<Vec<f64>>| s.iter().filter(|u| u[0] < q && u[1] < q).count();
+        let upper = |s: &Vec<Vec<f64>>| {
+            s.iter()
+                .filter(|u| u[0] > 1.0 - q && u[1] > 1.0 - q)
+                .count()
+        };
+        assert!(lower(&samples[0]) > 2 * upper(&samples[0]));
+        assert!(upper(&samples[1]) > 2 * lower(&samples[1]));
+        assert_approx_equal!(clayton.tail_dependence(), 0.5_f64.sqrt(), 1e-12);
+        assert_approx_equal!(gumbel.tail_dependence(), 2.0 - 2_f64.sqrt(), 1e-12);
+
+        // Negative dependence in two dimensions.
+        let negative = FrankCopula::from_kendalls_tau(2, -0.3).unwrap();
+        let pairs: Vec<Vec<f64>> = (0..n).map(|_| negative.sample_with_rng(&mut rng)).collect();
+        let x: Vec<f64> = pairs.iter().map(|u| u[0]).collect();
+        let y: Vec<f64> = pairs.iter().map(|u| u[1]).collect();
+        assert!((kendalls_tau(&x, &y).unwrap() + 0.3).abs() < 0.03);
+    }
+
+    #[test]
+    fn test_archimedean_fit() {
+        let mut rng = StdRng::seed_from_u64(23);
+
+        let clayton = ClaytonCopula::new(2, 2.0).unwrap();
+        let observations: Vec<Vec<f64>> = (0..1_500)
+            .map(|_| clayton.sample_with_rng(&mut rng))
+            .collect();
+
+        let tau = ClaytonCopula::fit(&observations, CopulaFitMethod::KendallsTau).unwrap();
+        let mle = ClaytonCopula::fit(&observations, CopulaFitMethod::MaximumLikelihood).unwrap();
+        assert!((tau.theta() - 2.0).abs() < 0.25);
+        assert!((mle.theta() - 2.0).abs() < 0.2);
+        assert!(mle.log_likelihood(&observations) >= tau.log_likelihood(&observations));
+
+        let gumbel = GumbelCopula::new(3, 2.5).unwrap();
+        let observations: Vec<Vec<f64>> = (0..1_500)
+            .map(|_| gumbel.sample_with_rng(&mut rng))
+            .collect();
+        let mle = GumbelCopula::fit(&observations, CopulaFitMethod::MaximumLikelihood).unwrap();
+        assert!((mle.theta() - 2.5).abs() < 0.15);
+
+        let frank = FrankCopula::new(2, -6.0).unwrap();
+        let observations: Vec<Vec<f64>> = (0..1_500)
+            .map(|_| frank.sample_with_rng(&mut rng))
+            .collect();
+        let mle = FrankCopula::fit(&observations, CopulaFitMethod::MaximumLikelihood).unwrap();
+        assert!((mle.theta() + 6.0).abs() < 0.6);
+
+        // Clayton copulas only model positive dependence.
+        assert!(ClaytonCopula::fit(&observations, CopulaFitMethod::KendallsTau).is_err());
+    }
+}
@@ -0,0 +1,345 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::error::RustQuantError;
+use nalgebra::DMatrix;
+use rand::RngCore;
+use std::f64::consts::PI;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// A copula: the joint distribution of a random vector with uniform
+/// marginals on $(0, 1)$.
+/// <https://en.wikipedia.org/wiki/Copula_(probability_theory)>
+///
+/// By Sklar's theorem, any joint distribution is its marginals coupled by a
+/// copula, so copulas model the dependence between variables separately from
+/// their individual distributions: a sample of the copula is mapped to the
+/// variables through the inverse CDFs of the marginals.
+pub trait Copula {
+    /// Number of dimensions.
+    fn dimension(&self) -> usize;
+
+    /// Logarithm of the copula density at a point of the unit cube, and
+    /// negative infinity outside its interior.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u` does not have one entry per dimension.
+    fn log_pdf(&self, u: &[f64]) -> f64;
+
+    /// Copula density at a point of the unit cube.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u` does not have one entry per dimension.
+    fn pdf(&self, u: &[f64]) -> f64 {
+        self.log_pdf(u).exp()
+    }
+
+    /// Draws a point of the unit cube from the copula.
+    fn sample_with_rng(&self, rng: &mut dyn RngCore) -> Vec<f64>;
+
+    /// Draws `n` points of the unit cube from the copula.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if `n` is zero.
+    fn sample(&self, n: usize) -> Result<Vec<Vec<f64>>, RustQuantError> {
+        use rand::thread_rng;
+
+        if n == 0 {
+            return Err(RustQuantError::InvalidArgument(
+                "the number of samples must be positive".to_string(),
+            ));
+        }
+
+        let mut rng = thread_rng();
+
+        Ok((0..n).map(|_| self.sample_with_rng(&mut rng)).collect())
+    }
+
+    /// Log-likelihood of observations in the unit cube, one per row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an observation does not have one entry per dimension.
+    fn log_likelihood(&self, observations: &[Vec<f64>]) -> f64 {
+        observations.iter().map(|u| self.log_pdf(u)).sum()
+    }
+}
+
+/// How copula parameters are estimated from observations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CopulaFitMethod {
+    /// Match the empirical Kendall's tau of each pair of variables, which
+    /// only depends on the ranks of the observations.
+    KendallsTau,
+
+    /// Maximise the log-likelihood of the observations, starting from the
+    /// Kendall's tau estimate.
+    #[default]
+    MaximumLikelihood,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Pseudo-observations of a sample, one observation per row: each value is
+/// replaced by its rank within its column divided by `n + 1`.
+///
+/// The result lies in the open unit cube, and is the usual input of copula
+/// fitting when the marginal distributions are unknown.
+///
+/// # Errors
+/// - `RustQuantError::InvalidArgument` if the sample is empty, or the rows do
+///   not all have the same length.
+pub fn pseudo_observations(data: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, RustQuantError> {
+    let d = data.first().map_or(0, Vec::len);
+
+    if d == 0 || data.iter().any(|row| row.len() != d) {
+        return Err(RustQuantError::InvalidArgument(
+            "the sample must be non-empty, with rows of the same length".to_string(),
+        ));
+    }
+
+    let n = data.len();
+    let mut pseudo = vec![vec![0.0; d]; n];
+
+    for j in 0..d {
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|a, b| data[*a][j].total_cmp(&data[*b][j]));
+
+        for (rank, i) in order.into_iter().enumerate() {
+            pseudo[i][j] = (rank + 1) as f64 / (n + 1) as f64;
+        }
+    }
+
+    Ok(pseudo)
+}
+
+/// Empirical Kendall's tau of two samples: the number of concordant pairs
+/// minus the number of discordant pairs, over the number of pairs.
+///
+/// The computation compares all pairs, in $O(n^2)$ operations.
+///
+/// # Errors
+/// - `RustQuantError::InvalidArgument` if the samples have different lengths,
+///   or fewer than two values.
+pub fn kendalls_tau(x: &[f64], y: &[f64]) -> Result<f64, RustQuantError> {
+    let n = x.len();
+
+    if n < 2 || y.len() != n {
+        return Err(RustQuantError::InvalidArgument(
+            "Kendall's tau needs two samples of the same length, with at least two values"
+                .to_string(),
+        ));
+    }
+
+    let mut concordance = 0.0;
+    for i in 0..n {
+        for j in i + 1..n {
+            concordance += ((x[i] - x[j]) * (y[i] - y[j])).signum();
+        }
+    }
+
+    Ok(concordance / (n * (n - 1) / 2) as f64)
+}
+
+/// Matrix of the empirical Kendall's tau of each pair of columns of a
+/// sample, one observation per row.
+///
+/// # Errors
+/// - `RustQuantError::InvalidArgument` if the sample has fewer than two
+///   rows, or the rows do not all have the same length.
+pub fn kendalls_tau_matrix(data: &[Vec<f64>]) -> Result<DMatrix<f64>, RustQuantError> {
+    let d = data.first().map_or(0, Vec::len);
+
+    if d == 0 || data.iter().any(|row| row.len() != d) {
+        return Err(RustQuantError::InvalidArgument(
+            "the sample must be non-empty, with rows of the same length".to_string(),
+        ));
+    }
+
+    let columns: Vec<Vec<f64>> = (0..d)
+        .map(|j| data.iter().map(|row| row[j]).collect())
+        .collect();
+
+    let mut tau = DMatrix::identity(d, d);
+    for i in 0..d {
+        for j in i + 1..d {
+            tau[(i, j)] = kendalls_tau(&columns[i], &columns[j])?;
+            tau[(j, i)] = tau[(i, j)];
+        }
+    }
+
+    Ok(tau)
+}
+
+/// Checks that observations to fit a copula to have at least two dimensions
+/// and lie in the open unit cube, and returns their dimension.
+pub(crate) fn validate_observations(observations: &[Vec<f64>]) -> Result<usize, RustQuantError> {
+    let d = observations.first().map_or(0, Vec::len);
+
+    if observations.len() < 2 || d < 2 || observations.iter().any(|u| u.len() != d) {
+        return Err(RustQuantError::InvalidArgument(
+            "copulas are fitted to at least two observations of at least two variables".to_string(),
+        ));
+    }
+    if observations
+        .iter()
+        .flatten()
+        .any(|u| *u <= 0.0 || *u >= 1.0)
+    {
+        return Err(RustQuantError::InvalidArgument(
+            "the observations must lie in the open unit cube, see `pseudo_observations`"
+                .to_string(),
+        ));
+    }
+
+    Ok(d)
+}
+
+/// Lower Cholesky factor of a correlation matrix.
+///
+/// # Errors
+/// - `RustQuantError::InvalidArgument` if the matrix is not square,
+///   symmetric and positive definite with a unit diagonal.
+pub(crate) fn correlation_cholesky(
+    correlation: &DMatrix<f64>,
+) -> Result<DMatrix<f64>, RustQuantError> {
+    let n = correlation.nrows();
+
+    if n == 0
+        || correlation.ncols() != n
+        || (0..n).any(|i| (correlation[(i, i)] - 1.0).abs() > 1e-12)
+        || (correlation - correlation.transpose()).amax() > 1e-12
+    {
+        return Err(RustQuantError::InvalidArgument(
+            "the correlation matrix must be square and symmetric, with a unit diagonal".to_string(),
+        ));
+    }
+
+    Ok(correlation
+        .clone()
+        .cholesky()
+        .ok_or_else(|| {
+            RustQuantError::InvalidArgument(
+                "the correlation matrix must be positive definite".to_string(),
+            )
+        })?
+        .l())
+}
+
+/// Correlation matrix of an elliptical copula with the empirical Kendall's
+/// tau of the observations, from $\rho = \sin(\pi \tau / 2)$.
+pub(crate) fn elliptical_correlation(
+    observations: &[Vec<f64>],
+) -> Result<DMatrix<f64>, RustQuantError> {
+    Ok(kendalls_tau_matrix(observations)?.map(|tau| (0.5 * PI * tau).sin()))
+}
+
+/// Correlation matrix `L L^T` with the rows of `L` on the unit sphere, in
+/// spherical coordinates: row `i` has the `i` angles that follow those of
+/// the previous rows.
+///
+/// Any angles give a valid correlation matrix, so that likelihoods can be
+/// maximised over correlation matrices without constraints.
+pub(crate) fn correlation_from_angles(dimension: usize, angles: &[f64]) -> DMatrix<f64> {
+    let mut l = DMatrix::zeros(dimension, dimension);
+    let mut angles = angles.iter();
+
+    for i in 0..dimension {
+        let mut sines = 1.0;
+        for j in 0..i {
+            let angle = angles.next().expect("one angle per pair of variables");
+            l[(i, j)] = angle.cos() * sines;
+            sines *= angle.sin();
+        }
+        l[(i, i)] = sines;
+    }
+
+    let correlation = &l * l.transpose();
+
+    // Symmetric, with a unit diagonal up to rounding.
+    DMatrix::from_fn(dimension, dimension, |i, j| match i.cmp(&j) {
+        std::cmp::Ordering::Equal => 1.0,
+        std::cmp::Ordering::Less => correlation[(j, i)],
+        std::cmp::Ordering::Greater => correlation[(i, j)],
+    })
+}
+
+/// Spherical coordinates of the lower Cholesky factor of a correlation
+/// matrix: the inverse of `correlation_from_angles`.
+pub(crate) fn angles_from_cholesky(cholesky: &DMatrix<f64>) -> Vec<f64> {
+    let mut angles = Vec::new();
+
+    for i in 0..cholesky.nrows() {
+        let mut sines = 1.0;
+        for j in 0..i {
+            let angle = (cholesky[(i, j)] / sines).clamp(-1.0, 1.0).acos();
+            angles.push(angle);
+            sines *= angle.sin();
+        }
+    }
+
+    angles
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_copula {
+    use super::*;
+
+    #[test]
+    fn test_kendalls_tau() {
+        // Of the 10 pairs, 7 are concordant and 3 discordant.
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y = [3.0, 1.0, 4.0, 2.0, 9.0];
+        assert_approx_equal!(kendalls_tau(&x, &y).unwrap(), 0.4, 1e-12);
+
+        // Ranks are invariant under increasing transformations.
+        let data: Vec<Vec<f64>> = x.iter().zip(y).map(|(x, y)| vec![*x, y]).collect();
+        let pseudo = pseudo_observations(&data).unwrap();
+        assert_eq!(pseudo[0], vec![1.0 / 6.0, 3.0 / 6.0]);
+        assert_eq!(pseudo[1], vec![2.0 / 6.0, 1.0 / 6.0]);
+
+        let tau = kendalls_tau_matrix(&pseudo).unwrap();
+        assert_approx_equal!(tau[(0, 1)], 0.4, 1e-12);
+        assert_approx_equal!(tau[(1, 0)], 0.4, 1e-12);
+        assert_approx_equal!(tau[(1, 1)], 1.0, 1e-12);
+
+        assert!(kendalls_tau(&x, &y[..4]).is_err());
+        assert!(pseudo_observations(&[vec![1.0], vec![1.0, 2.0]]).is_err());
+        assert!(validate_observations(&[vec![0.5, 1.0], vec![0.5, 0.5]]).is_err());
+    }
+
+    #[test]
+    fn test_correlation_angles() {
+        let correlation =
+            DMatrix::from_row_slice(3, 3, &[1.0, 0.6, -0.3, 0.6, 1.0, 0.2, -0.3, 0.2, 1.0]);
+        let cholesky = correlation_cholesky(&correlation).unwrap();
+        let angles = angles_from_cholesky(&cholesky);
+        assert_eq!(angles.len(), 3);
+
+        let round_trip = correlation_from_angles(3, &angles);
+        assert!((round_trip - &correlation).amax() < 1e-12);
+
+        // Arbitrary angles give correlation matrices.
+        let arbitrary = correlation_from_angles(3, &[4.0, -1.0, 2.5]);
+        assert!(correlation_cholesky(&arbitrary).is_ok());
+
+        assert!(correlation_cholesky(&DMatrix::from_element(2, 2, 1.0)).is_err());
+    }
+}
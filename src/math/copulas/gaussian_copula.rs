@@ -0,0 +1,215 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::{
+    error::RustQuantError,
+    math::{
+        copulas::{
+            angles_from_cholesky, correlation_cholesky, correlation_from_angles,
+            elliptical_correlation, validate_observations, Copula, CopulaFitMethod,
+        },
+        distributions::{Distribution, Gaussian},
+        optimization::NelderMead,
+    },
+};
+use nalgebra::{DMatrix, DVector};
+use rand::{Rng, RngCore};
+use rand_distr::StandardNormal;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Gaussian copula with correlation matrix `R`: the joint distribution of
+/// $U_i = \Phi(Z_i)$ with $Z \sim N(0, R)$.
+///
+/// The copula of the one-factor default model of Li (2000), with density
+///
+/// $$
+/// c(u) = |R|^{-1/2} \exp\left(-\frac{1}{2} z^T (R^{-1} - I) z\right),
+/// \quad z_i = \Phi^{-1}(u_i).
+/// $$
+///
+/// It has no tail dependence: joint extremes become independent as they
+/// become more extreme.
+#[derive(Debug, Clone)]
+pub struct GaussianCopula {
+    /// Correlation matrix.
+    correlation: DMatrix<f64>,
+
+    /// Lower Cholesky factor of the correlation matrix.
+    cholesky: DMatrix<f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl GaussianCopula {
+    /// New instance of a Gaussian copula.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the correlation matrix is not
+    ///   square, symmetric and positive definite with a unit diagonal.
+    pub fn new(correlation: DMatrix<f64>) -> Result<Self, RustQuantError> {
+        let cholesky = correlation_cholesky(&correlation)?;
+
+        Ok(Self {
+            correlation,
+            cholesky,
+        })
+    }
+
+    /// Fits the copula to observations in the open unit cube, one per row
+    /// (see `pseudo_observations`).
+    ///
+    /// With `CopulaFitMethod::KendallsTau` each correlation is
+    /// $\sin(\pi \tau / 2)$ for the empirical Kendall's tau $\tau$ of the
+    /// pair of variables.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there are fewer than two
+    ///   observations or two variables, the observations are not in the open
+    ///   unit cube, or (Kendall's tau) the correlation matrix matching Kendall's
+    ///   tau is not positive definite.
+    /// - `RustQuantError::NotConverged` if the likelihood maximisation does not
+    ///   converge.
+    pub fn fit(observations: &[Vec<f64>], method: CopulaFitMethod) -> Result<Self, RustQuantError> {
+        let d = validate_observations(observations)?;
+
+        match method {
+            CopulaFitMethod::KendallsTau => Self::new(elliptical_correlation(observations)?),
+            CopulaFitMethod::MaximumLikelihood => {
+                let start = Self::fit(observations, CopulaFitMethod::KendallsTau)
+                    .or_else(|_| Self::new(DMatrix::identity(d, d)))?;
+
+                // The normal scores do not depend on the correlation.
+                let gaussian = Gaussian::default();
+                let scores: Vec<DVector<f64>> = observations
+                    .iter()
+                    .map(|u| DVector::from_iterator(d, u.iter().map(|u| gaussian.inv_cdf(*u))))
+                    .collect();
+
+                let negative_log_likelihood = |angles: &[f64]| {
+                    Self::new(correlation_from_angles(d, angles)).map_or(f64::INFINITY, |c| {
+                        -scores.iter().map(|z| c.log_pdf_scores(z)).sum::<f64>()
+                    })
+                };
+
+                let angles = NelderMead::new(1e-10, 20_000)
+                    .minimize(
+                        negative_log_likelihood,
+                        &angles_from_cholesky(&start.cholesky),
+                    )?
+                    .minimizer;
+
+                Self::new(correlation_from_angles(d, &angles))
+            }
+        }
+    }
+
+    /// Correlation matrix.
+    #[must_use]
+    pub fn correlation(&self) -> &DMatrix<f64> {
+        &self.correlation
+    }
+
+    /// Logarithm of the density at the point with normal scores `z`.
+    fn log_pdf_scores(&self, z: &DVector<f64>) -> f64 {
+        let y = self
+            .cholesky
+            .solve_lower_triangular(z)
+            .expect("the Cholesky factor has a positive diagonal");
+        let log_determinant: f64 = self.cholesky.diagonal().iter().map(|l| 2.0 * l.ln()).sum();
+
+        -0.5 * log_determinant - 0.5 * (y.norm_squared() - z.norm_squared())
+    }
+}
+
+impl Copula for GaussianCopula {
+    fn dimension(&self) -> usize {
+        self.correlation.nrows()
+    }
+
+    fn log_pdf(&self, u: &[f64]) -> f64 {
+        let n = self.dimension();
+        assert_eq!(u.len(), n, "one entry per dimension");
+
+        if u.iter().any(|u| *u <= 0.0 || *u >= 1.0) {
+            return f64::NEG_INFINITY;
+        }
+
+        let gaussian = Gaussian::default();
+
+        self.log_pdf_scores(&DVector::from_iterator(
+            n,
+            u.iter().map(|u| gaussian.inv_cdf(*u)),
+        ))
+    }
+
+    fn sample_with_rng(&self, rng: &mut dyn RngCore) -> Vec<f64> {
+        let gaussian = Gaussian::default();
+        let z =
+            &self.cholesky * DVector::from_fn(self.dimension(), |_, _| rng.sample(StandardNormal));
+
+        z.iter().map(|z| gaussian.cdf(*z)).collect()
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_gaussian_copula {
+    use super::*;
+    use crate::math::distributions::MultivariateNormal;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_gaussian_copula_density() {
+        let correlation =
+            DMatrix::from_row_slice(3, 3, &[1.0, 0.5, 0.2, 0.5, 1.0, -0.3, 0.2, -0.3, 1.0]);
+        let copula = GaussianCopula::new(correlation.clone()).unwrap();
+        let normal = MultivariateNormal::new(&[0.0; 3], correlation).unwrap();
+        let gaussian = Gaussian::default();
+
+        // The joint density of the normal scores over their marginal densities.
+        let u = [0.2, 0.9, 0.6];
+        let z: Vec<f64> = u.iter().map(|u| gaussian.inv_cdf(*u)).collect();
+        let marginals: f64 = z.iter().map(|z| gaussian.pdf(*z)).product();
+        assert_approx_equal!(copula.pdf(&u), normal.pdf(&z) / marginals, 1e-10);
+
+        // The identity correlation is the independence copula.
+        let independence = GaussianCopula::new(DMatrix::identity(2, 2)).unwrap();
+        assert_approx_equal!(independence.pdf(&[0.1, 0.7]), 1.0, 1e-12);
+        assert_eq!(copula.pdf(&[0.2, 1.0, 0.5]), 0.0);
+
+        assert!(GaussianCopula::new(DMatrix::from_element(2, 2, 2.0)).is_err());
+    }
+
+    #[test]
+    fn test_gaussian_copula_fit() {
+        let rho = 0.6;
+        let copula =
+            GaussianCopula::new(DMatrix::from_row_slice(2, 2, &[1.0, rho, rho, 1.0])).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let observations: Vec<Vec<f64>> = (0..2_000)
+            .map(|_| copula.sample_with_rng(&mut rng))
+            .collect();
+
+        let tau = GaussianCopula::fit(&observations, CopulaFitMethod::KendallsTau).unwrap();
+        let mle = GaussianCopula::fit(&observations, CopulaFitMethod::MaximumLikelihood).unwrap();
+
+        assert!((tau.correlation()[(0, 1)] - rho).abs() < 0.03);
+        assert!((mle.correlation()[(0, 1)] - rho).abs() < 0.03);
+        assert!(mle.log_likelihood(&observations) >= tau.log_likelihood(&observations));
+    }
+}
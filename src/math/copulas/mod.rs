@@ -0,0 +1,24 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Copula trait, Kendall's tau and pseudo-observations.
+pub mod copula;
+pub use copula::*;
+
+/// Archimedean copulas (Clayton, Gumbel and Frank).
+pub mod archimedean;
+pub use archimedean::*;
+
+/// Gaussian copula.
+pub mod gaussian_copula;
+pub use gaussian_copula::*;
+
+/// Student's t copula.
+pub mod students_t_copula;
+pub use students_t_copula::*;
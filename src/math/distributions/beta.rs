@@ -0,0 +1,246 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::{
+    error::RustQuantError,
+    math::distributions::{bisection_quantile, gamma::marsaglia_tsang, Distribution},
+};
+use num::Complex;
+use rand::RngCore;
+use statrs::function::{
+    beta::{beta_reg, ln_beta},
+    gamma::digamma,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Beta distribution: X ~ Beta(alpha, beta), supported on [0, 1].
+/// <https://en.wikipedia.org/wiki/Beta_distribution>
+pub struct Beta {
+    /// Alpha: the first shape parameter.
+    alpha: f64,
+    /// Beta: the second shape parameter.
+    beta: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Beta {
+    /// New instance of a Beta distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if alpha and beta are not positive.
+    #[must_use]
+    pub fn new(alpha: f64, beta: f64) -> Self {
+        assert!(alpha > 0.0 && beta > 0.0);
+
+        Self { alpha, beta }
+    }
+
+    /// Kummer's function $_1F_1(\alpha; \alpha + \beta; z)$, which gives
+    /// both the characteristic and moment generating functions.
+    fn kummer(&self, z: Complex<f64>) -> Complex<f64> {
+        let (a, b) = (self.alpha, self.alpha + self.beta);
+
+        let mut term = Complex::new(1.0, 0.0);
+        let mut sum = term;
+
+        for k in 0..500 {
+            let k = f64::from(k);
+            term *= z * (a + k) / ((b + k) * (k + 1.0));
+            sum += term;
+
+            if term.norm() < 1e-16 * sum.norm() {
+                break;
+            }
+        }
+
+        sum
+    }
+}
+
+impl Distribution for Beta {
+    /// Characteristic function $_1F_1(\alpha; \alpha + \beta; it)$.
+    fn cf(&self, t: f64) -> Complex<f64> {
+        self.kummer(Complex::new(0.0, t))
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        assert!((0.0..=1.0).contains(&x));
+
+        let (alpha, beta) = (self.alpha, self.beta);
+
+        ((alpha - 1.0) * x.ln() + (beta - 1.0) * (1.0 - x).ln() - ln_beta(alpha, beta)).exp()
+    }
+
+    fn pmf(&self, x: f64) -> f64 {
+        self.pdf(x)
+    }
+
+    /// Regularized incomplete beta function $I_x(\alpha, \beta)$.
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x >= 1.0 {
+            return 1.0;
+        }
+
+        beta_reg(self.alpha, self.beta, x)
+    }
+
+    /// Quantile function, found by bisection on the CDF.
+    fn inv_cdf(&self, p: f64) -> f64 {
+        assert!((0.0..=1.0).contains(&p));
+
+        bisection_quantile(|x| self.cdf(x), p, 0.0, Some(1.0))
+    }
+
+    fn mean(&self) -> f64 {
+        self.alpha / (self.alpha + self.beta)
+    }
+
+    fn median(&self) -> f64 {
+        self.inv_cdf(0.5)
+    }
+
+    /// Mode of the distribution, or `NaN` if it is not unique
+    /// (when both shape parameters are at most one).
+    fn mode(&self) -> f64 {
+        let (alpha, beta) = (self.alpha, self.beta);
+
+        match (alpha > 1.0, beta > 1.0) {
+            (true, true) => (alpha - 1.0) / (alpha + beta - 2.0),
+            (false, true) => 0.0,
+            (true, false) => 1.0,
+            (false, false) => f64::NAN,
+        }
+    }
+
+    fn variance(&self) -> f64 {
+        let (alpha, beta) = (self.alpha, self.beta);
+        let sum = alpha + beta;
+
+        alpha * beta / (sum * sum * (sum + 1.0))
+    }
+
+    fn skewness(&self) -> f64 {
+        let (alpha, beta) = (self.alpha, self.beta);
+        let sum = alpha + beta;
+
+        2.0 * (beta - alpha) * (sum + 1.0).sqrt() / ((sum + 2.0) * (alpha * beta).sqrt())
+    }
+
+    /// Excess kurtosis.
+    fn kurtosis(&self) -> f64 {
+        let (alpha, beta) = (self.alpha, self.beta);
+        let sum = alpha + beta;
+
+        6.0 * ((alpha - beta).powi(2) * (sum + 1.0) - alpha * beta * (sum + 2.0))
+            / (alpha * beta * (sum + 2.0) * (sum + 3.0))
+    }
+
+    fn entropy(&self) -> f64 {
+        let (alpha, beta) = (self.alpha, self.beta);
+
+        ln_beta(alpha, beta) - (alpha - 1.0) * digamma(alpha) - (beta - 1.0) * digamma(beta)
+            + (alpha + beta - 2.0) * digamma(alpha + beta)
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        self.kummer(Complex::new(t, 0.0)).re
+    }
+
+    fn sample(&self, n: usize) -> Result<Vec<f64>, RustQuantError> {
+        use rand::thread_rng;
+
+        assert!(n > 0);
+
+        let mut rng = thread_rng();
+
+        let mut variates: Vec<f64> = Vec::with_capacity(n);
+
+        for _ in 0..variates.capacity() {
+            variates.push(self.sample_with_rng(&mut rng));
+        }
+
+        Ok(variates)
+    }
+
+    /// Ratio of Gammas: `X / (X + Y)` for `X ~ Gamma(alpha)`, `Y ~ Gamma(beta)`.
+    fn sample_with_rng(&self, rng: &mut dyn RngCore) -> f64 {
+        let x = marsaglia_tsang(self.alpha, rng);
+        let y = marsaglia_tsang(self.beta, rng);
+
+        x / (x + y)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_beta {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_beta_density_and_distribution_functions() {
+        // Beta(2, 5) density is 30 x (1 - x)^4.
+        let dist = Beta::new(2.0, 5.0);
+
+        assert_approx_equal!(dist.pdf(0.2), 2.4576, 1e-12);
+
+        // Beta(2, 3): I_{0.5}(2, 3) = (6 + 4 + 1) / 16.
+        let dist = Beta::new(2.0, 3.0);
+
+        assert_approx_equal!(dist.cdf(0.5), 0.6875, 1e-12);
+        assert_approx_equal!(dist.cdf(dist.inv_cdf(0.9)), 0.9, 1e-12);
+        assert_approx_equal!(dist.cdf(1.5), 1.0, 1e-12);
+
+        // Beta(1, 1) is U(0, 1).
+        let dist = Beta::new(1.0, 1.0);
+
+        assert_approx_equal!(dist.cdf(0.3), 0.3, 1e-12);
+        assert_approx_equal!(dist.entropy(), 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_beta_characteristic_function() {
+        // E[e^{X}] for Beta(1, 1) is e - 1.
+        let dist = Beta::new(1.0, 1.0);
+
+        assert_approx_equal!(dist.mgf(1.0), std::f64::consts::E - 1.0, 1e-12);
+        assert_approx_equal!(dist.cf(0.0).re, 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_beta_sample_moments() {
+        let n = 50_000;
+        let mut rng = StdRng::seed_from_u64(2024);
+
+        for dist in [Beta::new(2.0, 5.0), Beta::new(0.5, 0.5)] {
+            let samples: Vec<f64> = (0..n).map(|_| dist.sample_with_rng(&mut rng)).collect();
+
+            let mean = samples.iter().sum::<f64>() / n as f64;
+            let variance =
+                samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n as f64 - 1.0);
+
+            assert!(samples.iter().all(|x| (0.0..=1.0).contains(x)));
+            assert!((mean - dist.mean()).abs() < 4.0 * (dist.variance() / n as f64).sqrt());
+            assert!((variance / dist.variance() - 1.0).abs() < 0.05);
+        }
+    }
+}
@@ -132,6 +132,13 @@ impl Distribution for Binomial {
     /// assert_approx_equal!(binomial.cdf(3.0), 0.9129600, 1e-7);
     /// ```
     fn cdf(&self, k: f64) -> f64 {
+        if k < 0.0 {
+            return 0.0;
+        }
+        if k as usize >= self.n {
+            return 1.0;
+        }
+
         statrs::function::beta::beta_reg((self.n - k as usize) as f64, 1_f64 + k, 1_f64 - self.p)
     }
 
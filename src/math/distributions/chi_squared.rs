@@ -301,6 +301,16 @@ impl Distribution for ChiSquared {
 
         Ok(variates)
     }
+
+    fn sample_with_rng(&self, rng: &mut dyn rand::RngCore) -> f64 {
+        // IMPORT HERE TO AVOID CLASH WITH
+        // `RustQuant::distributions::Distribution`
+        use rand_distr::{ChiSquared, Distribution};
+
+        ChiSquared::new(self.k as f64)
+            .expect("parameters are validated on construction")
+            .sample(rng)
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
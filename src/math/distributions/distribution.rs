@@ -8,6 +8,7 @@
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 use num::Complex;
+use rand::{Rng, RngCore};
 
 use crate::error::RustQuantError;
 
@@ -93,4 +94,123 @@ pub trait Distribution {
 
     /// Generates a random sample from the distribution.
     fn sample(&self, n: usize) -> Result<Vec<f64>, RustQuantError>;
+
+    /// Generates a single random variate from the distribution, using the
+    /// given random number generator (so that samples are reproducible).
+    /// Defaults to inverse transform sampling, `inv_cdf(U)` for `U ~ U(0,1)`.
+    fn sample_with_rng(&self, rng: &mut dyn RngCore) -> f64 {
+        self.inv_cdf(rng.gen::<f64>())
+    }
+}
+
+/// Quantile of a continuous distribution, found by bisection on its CDF.
+///
+/// The search starts on `[lower, upper]`, or, for distributions unbounded
+/// above (`upper = None`), on a bracket grown by doubling its width from
+/// `[lower, lower + 1]` until it contains the quantile.
+pub(crate) fn bisection_quantile<F>(cdf: F, p: f64, lower: f64, upper: Option<f64>) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let mut hi = upper.unwrap_or_else(|| {
+        let mut hi = lower + 1.0;
+
+        while cdf(hi) < p {
+            hi = lower + 2.0 * (hi - lower);
+        }
+
+        hi
+    });
+    let mut lo = lower;
+
+    for _ in 0..200 {
+        let mid = 0.5 * (lo + hi);
+
+        // The bracket cannot be split any further.
+        if mid <= lo || mid >= hi {
+            break;
+        }
+
+        if cdf(mid) < p {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    0.5 * (lo + hi)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_distribution {
+    use super::*;
+    use crate::math::distributions::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// Sample mean and standard error, for any distribution.
+    fn sample_mean(distribution: &dyn Distribution, n: usize) -> (f64, f64) {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let samples: Vec<f64> = (0..n)
+            .map(|_| distribution.sample_with_rng(&mut rng))
+            .collect();
+
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n as f64 - 1.0);
+
+        (mean, (variance / n as f64).sqrt())
+    }
+
+    #[test]
+    fn test_sample_mean_converges_to_mean() {
+        let distributions: Vec<Box<dyn Distribution>> = vec![
+            Box::new(Bernoulli::new(0.3)),
+            Box::new(Beta::new(2.0, 3.0)),
+            Box::new(Binomial::new(10, 0.4)),
+            Box::new(ChiSquared::new(3)),
+            Box::new(Exponential::new(2.0)),
+            Box::new(Gamma::new(2.0, 3.0)),
+            Box::new(Gaussian::new(1.0, 4.0)),
+            Box::new(Poisson::new(3.5)),
+            Box::new(StudentsT::new(5.0)),
+            Box::new(Uniform::new(-1.0, 3.0, DistributionClass::Continuous)),
+        ];
+
+        for distribution in &distributions {
+            let (mean, error) = sample_mean(distribution.as_ref(), 20_000);
+
+            assert!((mean - distribution.mean()).abs() < 4.0 * error);
+        }
+    }
+
+    #[test]
+    fn test_bisection_quantile() {
+        // Exponential(2): F(x) = 1 - e^{-2x}, unbounded above.
+        let cdf = |x: f64| 1.0 - (-2.0 * x).exp();
+
+        for p in [1e-6, 0.25, 0.5, 0.999] {
+            let quantile = bisection_quantile(cdf, p, 0.0, None);
+
+            assert!((quantile + (1.0 - p).ln() / 2.0).abs() < 1e-12);
+        }
+
+        // U(0, 1) on its support.
+        assert!((bisection_quantile(|x| x, 0.3, 0.0, Some(1.0)) - 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sample_with_rng_is_reproducible() {
+        let gaussian = Gaussian::default();
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let first = gaussian.sample_with_rng(&mut rng);
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let second = gaussian.sample_with_rng(&mut rng);
+
+        assert_eq!(first, second);
+    }
 }
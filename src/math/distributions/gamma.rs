@@ -7,9 +7,14 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use crate::{error::RustQuantError, math::distributions::Distribution};
+use crate::{
+    error::RustQuantError,
+    math::distributions::{bisection_quantile, Distribution},
+};
 use num::Complex;
-use statrs::function::gamma::{gamma, gamma_li};
+use rand::{Rng, RngCore};
+use rand_distr::StandardNormal;
+use statrs::function::gamma::{digamma, gamma, gamma_lr, ln_gamma};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // STRUCTS
@@ -50,6 +55,39 @@ impl Gamma {
     }
 }
 
+/// Draws a `Gamma(shape, 1)` variate with the Marsaglia-Tsang method.
+///
+/// For `shape < 1`, uses the boosting identity
+/// `Gamma(shape) = Gamma(shape + 1) * U^(1 / shape)`.
+///
+/// Marsaglia, G. and Tsang, W. W. (2000). A simple method for generating
+/// gamma variables. ACM Transactions on Mathematical Software, 26(3).
+pub(crate) fn marsaglia_tsang(shape: f64, rng: &mut dyn RngCore) -> f64 {
+    if shape < 1.0 {
+        let u: f64 = rng.gen();
+
+        return marsaglia_tsang(shape + 1.0, rng) * u.powf(shape.recip());
+    }
+
+    let d = shape - 1.0 / 3.0;
+    let c = (9.0 * d).sqrt().recip();
+
+    loop {
+        let z: f64 = rng.sample(StandardNormal);
+        let v = (1.0 + c * z).powi(3);
+
+        if v <= 0.0 {
+            continue;
+        }
+
+        let u: f64 = rng.gen();
+
+        if u.ln() < 0.5 * z * z + d - d * v + d * v.ln() {
+            return d * v;
+        }
+    }
+}
+
 impl Distribution for Gamma {
     fn cf(&self, t: f64) -> Complex<f64> {
         let i: Complex<f64> = Complex::i();
@@ -72,17 +110,27 @@ impl Distribution for Gamma {
         self.pdf(x)
     }
 
+    /// Regularized lower incomplete gamma function `P(alpha, beta * x)`.
     fn cdf(&self, x: f64) -> f64 {
-        assert!(x > 0.0);
-
-        let alpha = self.alpha;
-        let beta = self.beta;
+        if x <= 0.0 {
+            return 0.0;
+        }
 
-        gamma_li(alpha, beta * x) / gamma(alpha)
+        gamma_lr(self.alpha, self.beta * x)
     }
 
-    fn inv_cdf(&self, _p: f64) -> f64 {
-        unimplemented!()
+    /// Quantile function, found by bisection on the CDF.
+    fn inv_cdf(&self, p: f64) -> f64 {
+        assert!((0.0..=1.0).contains(&p));
+
+        if p == 0.0 {
+            return 0.0;
+        }
+        if p == 1.0 {
+            return f64::INFINITY;
+        }
+
+        bisection_quantile(|x| self.cdf(x), p, 0.0, None)
     }
 
     fn mean(&self) -> f64 {
@@ -90,7 +138,7 @@ impl Distribution for Gamma {
     }
 
     fn median(&self) -> f64 {
-        unimplemented!()
+        self.inv_cdf(0.5)
     }
 
     fn mode(&self) -> f64 {
@@ -114,7 +162,9 @@ impl Distribution for Gamma {
     }
 
     fn entropy(&self) -> f64 {
-        todo!()
+        let alpha = self.alpha;
+
+        alpha - self.beta.ln() + ln_gamma(alpha) + (1.0 - alpha) * digamma(alpha)
     }
 
     fn mgf(&self, t: f64) -> f64 {
@@ -124,25 +174,25 @@ impl Distribution for Gamma {
     }
 
     fn sample(&self, n: usize) -> Result<Vec<f64>, RustQuantError> {
-        // IMPORT HERE TO AVOID CLASH WITH
-        // `RustQuant::distributions::Distribution`
         use rand::thread_rng;
-        use rand_distr::{Distribution, Gamma};
 
         assert!(n > 0);
 
         let mut rng = thread_rng();
 
-        let dist = Gamma::new(self.alpha, self.beta.recip())?;
-
         let mut variates: Vec<f64> = Vec::with_capacity(n);
 
         for _ in 0..variates.capacity() {
-            variates.push(dist.sample(&mut rng) as usize as f64);
+            variates.push(self.sample_with_rng(&mut rng));
         }
 
         Ok(variates)
     }
+
+    /// Marsaglia-Tsang sampling, scaled by the rate.
+    fn sample_with_rng(&self, rng: &mut dyn RngCore) -> f64 {
+        marsaglia_tsang(self.alpha, rng) / self.beta
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -188,5 +238,33 @@ mod tests {
         assert_approx_equal!(dist.cdf(2.0), 0.864_664_716_763_387_2, EPS);
         assert_approx_equal!(dist.cdf(3.0), 0.950_212_931_632_136, EPS);
         assert_approx_equal!(dist.cdf(4.0), 0.981_684_361_111_265_8, EPS);
+
+        // Gamma(2, 3): P(2, 3x) = 1 - (1 + 3x) e^{-3x}.
+        let dist: Gamma = Gamma::new(2.0, 3.0);
+
+        assert_approx_equal!(dist.cdf(1.0), 1.0 - 4.0 * (-3.0_f64).exp(), 1e-12);
+        assert_approx_equal!(dist.cdf(dist.inv_cdf(0.3)), 0.3, 1e-12);
+        assert_approx_equal!(dist.cdf(0.0), 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_gamma_sample_moments() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let n = 50_000;
+        let mut rng = StdRng::seed_from_u64(2024);
+
+        // Both branches of Marsaglia-Tsang (shape above and below one).
+        for dist in [Gamma::new(2.5, 2.0), Gamma::new(0.5, 1.5)] {
+            let samples: Vec<f64> = (0..n).map(|_| dist.sample_with_rng(&mut rng)).collect();
+
+            let mean = samples.iter().sum::<f64>() / n as f64;
+            let variance =
+                samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n as f64 - 1.0);
+
+            assert!(samples.iter().all(|x| *x > 0.0));
+            assert!((mean - dist.mean()).abs() < 4.0 * (dist.variance() / n as f64).sqrt());
+            assert!((variance / dist.variance() - 1.0).abs() < 0.05);
+        }
     }
 }
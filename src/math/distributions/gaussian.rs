@@ -305,6 +305,16 @@ impl Distribution for Gaussian {
 
         Ok(variates)
     }
+
+    fn sample_with_rng(&self, rng: &mut dyn rand::RngCore) -> f64 {
+        // IMPORT HERE TO AVOID CLASH WITH
+        // `RustQuant::distributions::Distribution`
+        use rand_distr::{Distribution, Normal};
+
+        Normal::new(self.mean, self.variance.sqrt())
+            .expect("parameters are validated on construction")
+            .sample(rng)
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
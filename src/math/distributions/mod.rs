@@ -15,6 +15,10 @@ pub use distribution::*;
 pub mod bernoulli;
 pub use bernoulli::*;
 
+/// Beta distribution.
+pub mod beta;
+pub use beta::*;
+
 /// Binomial distribution.
 pub mod binomial;
 pub use binomial::*;
@@ -35,10 +39,25 @@ pub use gamma::*;
 pub mod gaussian;
 pub use gaussian::*;
 
+/// Multivariate normal distribution.
+pub mod multivariate_normal;
+pub use multivariate_normal::*;
+
+/// Noncentral Chi-squared distribution.
+pub mod noncentral_chi_squared;
+pub use noncentral_chi_squared::*;
+
 /// Poisson distribution.
 pub mod poisson;
 pub use poisson::*;
 
+/// Student's t distribution.
+pub mod students_t;
+pub use students_t::*;
+
 /// Uniform distribution.
 pub mod uniform;
 pub use uniform::*;
+
+/// Student's t copula (see crate::math::copulas).
+pub use crate::math::copulas::StudentsTCopula;
@@ -0,0 +1,274 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::{
+    error::RustQuantError,
+    math::{
+        distributions::{bisection_quantile, gamma::marsaglia_tsang, Distribution},
+        integrate,
+    },
+};
+use num::Complex;
+use rand::RngCore;
+use statrs::function::gamma::{gamma_lr, ln_gamma};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Noncentral Chi-Squared distribution: X ~ ChiSq'(k, lambda)
+/// <https://en.wikipedia.org/wiki/Noncentral_chi-squared_distribution>
+///
+/// The degrees of freedom need not be an integer, which is required for
+/// the transition density of the Cox-Ingersoll-Ross process.
+///
+/// The distribution is a Poisson mixture of central Chi-Squared
+/// distributions: if $N \sim Poisson(\lambda / 2)$, then
+/// $X | N \sim \chi^2_{k + 2N}$.
+pub struct NoncentralChiSquared {
+    /// k: degrees of freedom.
+    k: f64,
+    /// lambda: noncentrality parameter.
+    lambda: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl NoncentralChiSquared {
+    /// New instance of a Noncentral Chi-Squared distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not positive or `lambda` is negative.
+    #[must_use]
+    pub fn new(k: f64, lambda: f64) -> Self {
+        assert!(k > 0.0 && lambda >= 0.0);
+
+        Self { k, lambda }
+    }
+
+    /// Poisson mixture weights `P(N = j)` for `N ~ Poisson(lambda / 2)`,
+    /// truncated once the remaining mass is negligible.
+    fn poisson_weights(&self) -> Vec<f64> {
+        let mean = 0.5 * self.lambda;
+        let n_terms = (mean + 12.0 * mean.sqrt() + 30.0) as usize;
+
+        (0..n_terms)
+            .map(|j| {
+                let j = j as f64;
+
+                if mean == 0.0 {
+                    return if j == 0.0 { 1.0 } else { 0.0 };
+                }
+
+                (j * mean.ln() - mean - ln_gamma(j + 1.0)).exp()
+            })
+            .collect()
+    }
+}
+
+impl Distribution for NoncentralChiSquared {
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let i = Complex::new(0.0, 1.0);
+        let denominator = 1.0 - 2.0 * i * t;
+
+        (i * self.lambda * t / denominator).exp() / denominator.powf(0.5 * self.k)
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+
+        self.poisson_weights()
+            .iter()
+            .enumerate()
+            .map(|(j, w)| {
+                let half_dof = 0.5 * self.k + j as f64;
+
+                w * ((half_dof - 1.0) * x.ln()
+                    - 0.5 * x
+                    - half_dof * std::f64::consts::LN_2
+                    - ln_gamma(half_dof))
+                .exp()
+            })
+            .sum()
+    }
+
+    fn pmf(&self, x: f64) -> f64 {
+        self.pdf(x)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+
+        self.poisson_weights()
+            .iter()
+            .enumerate()
+            .map(|(j, w)| w * gamma_lr(0.5 * self.k + j as f64, 0.5 * x))
+            .sum::<f64>()
+            .min(1.0)
+    }
+
+    /// Quantile function, found by bisection on the CDF.
+    fn inv_cdf(&self, p: f64) -> f64 {
+        assert!((0.0..=1.0).contains(&p));
+
+        if p == 1.0 {
+            return f64::INFINITY;
+        }
+
+        bisection_quantile(|x| self.cdf(x), p, 0.0, None)
+    }
+
+    fn mean(&self) -> f64 {
+        self.k + self.lambda
+    }
+
+    fn median(&self) -> f64 {
+        self.inv_cdf(0.5)
+    }
+
+    /// Mode of the distribution, found by golden-section search on the density.
+    /// The density is unbounded at zero for fewer than two degrees of freedom.
+    fn mode(&self) -> f64 {
+        if self.k < 2.0 {
+            return 0.0;
+        }
+
+        let ratio = 0.5 * (5.0_f64.sqrt() - 1.0);
+        let (mut lo, mut hi) = (0.0, self.mean());
+
+        for _ in 0..200 {
+            let a = hi - ratio * (hi - lo);
+            let b = lo + ratio * (hi - lo);
+
+            if self.pdf(a) < self.pdf(b) {
+                lo = a;
+            } else {
+                hi = b;
+            }
+        }
+
+        0.5 * (lo + hi)
+    }
+
+    fn variance(&self) -> f64 {
+        2.0 * (self.k + 2.0 * self.lambda)
+    }
+
+    fn skewness(&self) -> f64 {
+        2.0_f64.powf(1.5) * (self.k + 3.0 * self.lambda) / (self.k + 2.0 * self.lambda).powf(1.5)
+    }
+
+    /// Excess kurtosis.
+    fn kurtosis(&self) -> f64 {
+        12.0 * (self.k + 4.0 * self.lambda) / (self.k + 2.0 * self.lambda).powi(2)
+    }
+
+    /// Differential entropy, by numerical integration of $-f \ln f$.
+    fn entropy(&self) -> f64 {
+        let upper = self.mean() + 20.0 * self.variance().sqrt();
+
+        integrate(
+            |x| {
+                let f = self.pdf(x);
+
+                if f > 0.0 {
+                    -f * f.ln()
+                } else {
+                    0.0
+                }
+            },
+            0.0,
+            upper,
+        )
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        assert!(t < 0.5);
+
+        (self.lambda * t / (1.0 - 2.0 * t)).exp() / (1.0 - 2.0 * t).powf(0.5 * self.k)
+    }
+
+    fn sample(&self, n: usize) -> Result<Vec<f64>, RustQuantError> {
+        use rand::thread_rng;
+
+        assert!(n > 0);
+
+        let mut rng = thread_rng();
+
+        let mut variates: Vec<f64> = Vec::with_capacity(n);
+
+        for _ in 0..variates.capacity() {
+            variates.push(self.sample_with_rng(&mut rng));
+        }
+
+        Ok(variates)
+    }
+
+    /// Poisson mixture sampling: draw `N ~ Poisson(lambda / 2)`,
+    /// then a central Chi-Squared with `k + 2N` degrees of freedom.
+    fn sample_with_rng(&self, rng: &mut dyn RngCore) -> f64 {
+        // IMPORT HERE TO AVOID CLASH WITH
+        // `RustQuant::distributions::Distribution`
+        use rand_distr::{Distribution, Poisson};
+
+        let n = if self.lambda > 0.0 {
+            Poisson::new(0.5 * self.lambda)
+                .expect("parameters are validated on construction")
+                .sample(rng)
+        } else {
+            0.0
+        };
+
+        2.0 * marsaglia_tsang(0.5 * self.k + n, rng)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_noncentral_chi_squared {
+    use super::*;
+    use crate::math::distributions::ChiSquared;
+
+    #[test]
+    fn test_zero_noncentrality_is_central() {
+        let noncentral = NoncentralChiSquared::new(3.0, 0.0);
+        let central = ChiSquared::new(3);
+
+        for x in [0.5, 1.0, 2.5, 7.0] {
+            assert_approx_equal!(noncentral.pdf(x), central.pdf(x), 1e-12);
+            assert_approx_equal!(noncentral.cdf(x), central.cdf(x), 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_noncentral_chi_squared_values() {
+        let dist = NoncentralChiSquared::new(2.0, 3.0);
+
+        // Reference values from the Bessel form of the density,
+        // f(x) = exp(-(x + lambda) / 2) I_0(sqrt(lambda x)) / 2 for k = 2.
+        assert_approx_equal!(dist.cdf(4.0), 0.493_562_416_893_174, 1e-9);
+        assert_approx_equal!(dist.pdf(4.0), 0.108_091_481_670_466, 1e-9);
+        assert_approx_equal!(dist.cdf(dist.inv_cdf(0.7)), 0.7, 1e-10);
+
+        // The density integrates to the first moment.
+        let mean = integrate(|x| x * dist.pdf(x), 0.0, 100.0);
+
+        assert_approx_equal!(mean, dist.mean(), 1e-6);
+    }
+}
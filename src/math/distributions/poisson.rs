@@ -271,6 +271,16 @@ impl Distribution for Poisson {
 
         Ok(variates)
     }
+
+    fn sample_with_rng(&self, rng: &mut dyn rand::RngCore) -> f64 {
+        // IMPORT HERE TO AVOID CLASH WITH
+        // `RustQuant::distributions::Distribution`
+        use rand_distr::{Distribution, Poisson};
+
+        Poisson::new(self.lambda)
+            .expect("parameters are validated on construction")
+            .sample(rng)
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
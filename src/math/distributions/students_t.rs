@@ -0,0 +1,272 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::{
+    error::RustQuantError,
+    math::{distributions::Distribution, integrate},
+};
+use num::Complex;
+use rand::RngCore;
+use statrs::function::{
+    beta::{beta_reg, inv_beta_reg, ln_beta},
+    gamma::{digamma, ln_gamma},
+};
+use std::f64::consts::{LN_2, PI};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Student's t distribution with `nu` degrees of freedom.
+///
+/// The density is
+/// `f(x) = Gamma((nu + 1) / 2) / (sqrt(nu pi) Gamma(nu / 2)) (1 + x^2 / nu)^(-(nu + 1) / 2)`.
+pub struct StudentsT {
+    /// Nu: the degrees of freedom.
+    nu: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl StudentsT {
+    /// New instance of a Student's t distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the degrees of freedom are not positive.
+    #[must_use]
+    pub fn new(nu: f64) -> Self {
+        assert!(nu > 0.0);
+
+        Self { nu }
+    }
+}
+
+impl Distribution for StudentsT {
+    /// Writing `X = Z / sqrt(V / nu)` with `V ~ ChiSquared(nu)`, the
+    /// characteristic function is `E[exp(-t^2 nu / (2 V))]`. This smooth
+    /// integral over the chi-squared density is computed numerically on
+    /// `(0, 1)` after the substitution `v = u / (1 - u)`.
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let k = 0.5 * self.nu;
+        let ln_norm = -k * LN_2 - ln_gamma(k);
+
+        let integrand = |u: f64| {
+            if u <= 0.0 || u >= 1.0 {
+                return 0.0;
+            }
+
+            let v = u / (1.0 - u);
+            let ln_density = ln_norm + (k - 1.0) * v.ln() - 0.5 * v;
+
+            (ln_density - 0.5 * t * t * self.nu / v).exp() / (1.0 - u).powi(2)
+        };
+
+        Complex::new(integrate(integrand, 0.0, 1.0), 0.0)
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        let nu = self.nu;
+
+        let ln_norm = ln_gamma(0.5 * (nu + 1.0)) - ln_gamma(0.5 * nu) - 0.5 * (nu * PI).ln();
+
+        (ln_norm - 0.5 * (nu + 1.0) * (x * x / nu).ln_1p()).exp()
+    }
+
+    fn pmf(&self, x: f64) -> f64 {
+        self.pdf(x)
+    }
+
+    /// `F(x) = 1 - I_{nu / (nu + x^2)}(nu / 2, 1 / 2) / 2` for `x > 0`,
+    /// and symmetric for `x < 0`.
+    fn cdf(&self, x: f64) -> f64 {
+        let nu = self.nu;
+        let tail = 0.5 * beta_reg(0.5 * nu, 0.5, nu / (nu + x * x));
+
+        if x > 0.0 {
+            1.0 - tail
+        } else {
+            tail
+        }
+    }
+
+    /// Quantile function, by inverting the regularized incomplete beta
+    /// function of the [`cdf`](StudentsT::cdf).
+    fn inv_cdf(&self, p: f64) -> f64 {
+        assert!((0.0..=1.0).contains(&p));
+
+        if p == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        if p == 1.0 {
+            return f64::INFINITY;
+        }
+
+        let nu = self.nu;
+        let z = inv_beta_reg(0.5 * nu, 0.5, 2.0 * p.min(1.0 - p));
+        let x = (nu * (1.0 / z - 1.0)).sqrt();
+
+        if p < 0.5 {
+            -x
+        } else {
+            x
+        }
+    }
+
+    /// Zero for `nu > 1`, undefined (NaN) otherwise.
+    fn mean(&self) -> f64 {
+        if self.nu > 1.0 {
+            0.0
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn median(&self) -> f64 {
+        0.0
+    }
+
+    fn mode(&self) -> f64 {
+        0.0
+    }
+
+    /// `nu / (nu - 2)` for `nu > 2`, infinite for `1 < nu <= 2`,
+    /// and undefined (NaN) otherwise.
+    fn variance(&self) -> f64 {
+        match self.nu {
+            nu if nu > 2.0 => nu / (nu - 2.0),
+            nu if nu > 1.0 => f64::INFINITY,
+            _ => f64::NAN,
+        }
+    }
+
+    /// Zero for `nu > 3`, undefined (NaN) otherwise.
+    fn skewness(&self) -> f64 {
+        if self.nu > 3.0 {
+            0.0
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// Excess kurtosis: `6 / (nu - 4)` for `nu > 4`, infinite for
+    /// `2 < nu <= 4`, and undefined (NaN) otherwise.
+    fn kurtosis(&self) -> f64 {
+        match self.nu {
+            nu if nu > 4.0 => 6.0 / (nu - 4.0),
+            nu if nu > 2.0 => f64::INFINITY,
+            _ => f64::NAN,
+        }
+    }
+
+    fn entropy(&self) -> f64 {
+        let nu = self.nu;
+
+        0.5 * (nu + 1.0) * (digamma(0.5 * (nu + 1.0)) - digamma(0.5 * nu))
+            + 0.5 * nu.ln()
+            + ln_beta(0.5 * nu, 0.5)
+    }
+
+    /// The moment generating function only exists at `t = 0`,
+    /// and is infinite elsewhere.
+    fn mgf(&self, t: f64) -> f64 {
+        if t == 0.0 {
+            1.0
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn sample(&self, n: usize) -> Result<Vec<f64>, RustQuantError> {
+        use rand::thread_rng;
+
+        assert!(n > 0);
+
+        let mut rng = thread_rng();
+
+        let mut variates: Vec<f64> = Vec::with_capacity(n);
+
+        for _ in 0..variates.capacity() {
+            variates.push(self.sample_with_rng(&mut rng));
+        }
+
+        Ok(variates)
+    }
+
+    fn sample_with_rng(&self, rng: &mut dyn RngCore) -> f64 {
+        // IMPORT HERE TO AVOID CLASH WITH
+        // `RustQuant::distributions::Distribution`
+        use rand_distr::{Distribution, StudentT};
+
+        StudentT::new(self.nu)
+            .expect("parameters are validated on construction")
+            .sample(rng)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_students_t_density_and_distribution_functions() {
+        // With one degree of freedom, Student's t is the standard Cauchy.
+        let cauchy = StudentsT::new(1.0);
+
+        for x in [-3.0, -0.5, 0.0, 1.0, 4.0] {
+            assert_approx_equal!(cauchy.pdf(x), 1.0 / (PI * (1.0 + x * x)), 1e-12);
+            assert_approx_equal!(cauchy.cdf(x), 0.5 + f64::atan(x) / PI, 1e-12);
+        }
+
+        // Values computed using R: dt(1, 5), pt(2, 5), qt(0.975, 5).
+        let dist = StudentsT::new(5.0);
+
+        assert_approx_equal!(dist.pdf(1.0), 0.219_679_797_350_981_1, 1e-12);
+        assert_approx_equal!(dist.cdf(2.0), 0.949_030_260_585_070_4, 1e-12);
+        assert_approx_equal!(dist.inv_cdf(0.975), 2.570_581_835_636_314, 1e-9);
+        assert_approx_equal!(dist.inv_cdf(0.025), -2.570_581_835_636_314, 1e-9);
+        assert_approx_equal!(dist.inv_cdf(0.5), 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_students_t_characteristic_function() {
+        // With three degrees of freedom, cf(t) = (1 + sqrt(3)|t|) e^{-sqrt(3)|t|}.
+        let dist = StudentsT::new(3.0);
+
+        for t in [0.0, 0.5, 1.0, 2.0] {
+            let s = 3.0_f64.sqrt() * t;
+
+            assert_approx_equal!(dist.cf(t).re, (1.0 + s) * (-s).exp(), 1e-6);
+            assert_approx_equal!(dist.cf(t).im, 0.0, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_students_t_moments() {
+        let dist = StudentsT::new(6.0);
+
+        assert_approx_equal!(dist.mean(), 0.0, 1e-12);
+        assert_approx_equal!(dist.variance(), 1.5, 1e-12);
+        assert_approx_equal!(dist.kurtosis(), 3.0, 1e-12);
+
+        // Moments that do not exist.
+        assert!(StudentsT::new(1.0).mean().is_nan());
+        assert!(StudentsT::new(1.5).variance().is_infinite());
+        assert!(StudentsT::new(3.0).kurtosis().is_infinite());
+
+        // The Cauchy entropy is ln(4 pi).
+        assert_approx_equal!(StudentsT::new(1.0).entropy(), (4.0 * PI).ln(), 1e-12);
+    }
+}
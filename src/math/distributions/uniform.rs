@@ -364,29 +364,33 @@ impl Distribution for Uniform {
         // IMPORT HERE TO AVOID CLASH WITH
         // `RustQuant::distributions::Distribution`
         use rand::thread_rng;
-        use rand_distr::{Distribution, Uniform};
 
         assert!(n > 0);
 
         let mut rng = thread_rng();
 
-        let dist = Uniform::new(self.a, self.b);
-
         let mut variates: Vec<f64> = Vec::with_capacity(n);
+
+        for _ in 0..variates.capacity() {
+            variates.push(self.sample_with_rng(&mut rng));
+        }
+
+        Ok(variates)
+    }
+
+    /// Draws a single variate. Discrete variates are drawn directly from
+    /// the integers `a..=b`, each with probability `1 / (b - a + 1)`.
+    fn sample_with_rng(&self, rng: &mut dyn rand::RngCore) -> f64 {
+        // IMPORT HERE TO AVOID CLASH WITH
+        // `RustQuant::distributions::Distribution`
+        use rand_distr::{Distribution, Uniform};
+
         match self.class {
             DistributionClass::Discrete => {
-                for _ in 0..variates.capacity() {
-                    variates.push(dist.sample(&mut rng) as usize as f64);
-                }
-            }
-            DistributionClass::Continuous => {
-                for _ in 0..variates.capacity() {
-                    variates.push(dist.sample(&mut rng));
-                }
+                Uniform::new_inclusive(self.a as i64, self.b as i64).sample(rng) as f64
             }
+            DistributionClass::Continuous => Uniform::new(self.a, self.b).sample(rng),
         }
-
-        Ok(variates)
     }
 }
 
@@ -435,4 +439,26 @@ mod tests {
         let cdf = dist.cdf(0.5);
         assert_approx_equal!(cdf, 0.5, EPS);
     }
+
+    #[test]
+    fn test_discrete_uniform_sample_covers_each_integer() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let dist = Uniform::new(1.0, 6.0, DistributionClass::Discrete);
+        let mut rng = StdRng::seed_from_u64(11);
+
+        let n = 60_000;
+        let mut counts = [0_usize; 6];
+        for _ in 0..n {
+            let x = dist.sample_with_rng(&mut rng);
+
+            assert_eq!(x.fract(), 0.0);
+            counts[x as usize - 1] += 1;
+        }
+
+        // Both endpoints are drawn, each face with probability 1/6.
+        for count in counts {
+            assert!((count as f64 / n as f64 - 1.0 / 6.0).abs() < 0.01);
+        }
+    }
 }
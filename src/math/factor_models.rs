@@ -0,0 +1,341 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Factor models of asset returns, for factor analysis and attribution.
+//!
+//! The market model (CAPM) regresses the excess returns of an asset on the
+//! excess returns of the market:
+//!
+//! $$
+//! r_a - r_f = \alpha + \beta (r_m - r_f) + \epsilon
+//! $$
+//!
+//! Multi-factor (Fama-French style) models regress the returns on several
+//! factor returns at once:
+//!
+//! $$
+//! r_a = \alpha + \sum_j \beta_j f_j + \epsilon
+//! $$
+
+use crate::error::RustQuantError;
+use nalgebra::{DMatrix, DVector};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Result of a market-model (CAPM) regression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CapmRegression {
+    /// Jensen's alpha: the intercept, per period.
+    pub alpha: f64,
+
+    /// The beta of the asset to the market.
+    pub beta: f64,
+
+    /// Coefficient of determination of the regression.
+    pub r_squared: f64,
+}
+
+/// Estimator of the covariance matrix of the regression coefficients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CovarianceEstimator {
+    /// Classical OLS estimator $s^2 (X^T X)^{-1}$, which assumes
+    /// homoskedastic errors.
+    Ordinary,
+
+    /// White's heteroskedasticity-consistent estimator,
+    /// $(X^T X)^{-1} X^T \text{diag}(e_i^2) X (X^T X)^{-1}$.
+    HC0,
+
+    /// HC0 with the degrees-of-freedom correction $n / (n - p)$.
+    HC1,
+}
+
+/// Result of a multi-factor regression.
+///
+/// The first element of each vector refers to the intercept (alpha),
+/// and the others to the factors, in the order of the factor columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FactorRegression {
+    /// The intercept followed by the factor loadings.
+    pub coefficients: DVector<f64>,
+
+    /// Standard errors of the coefficients.
+    pub standard_errors: DVector<f64>,
+
+    /// t-statistics of the coefficients.
+    pub t_statistics: DVector<f64>,
+
+    /// Coefficient of determination of the regression.
+    pub r_squared: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Market-model regression of `asset_returns` on `market_returns`,
+/// in excess of the per-period risk-free rate `risk_free_rate`.
+///
+/// # Panics
+/// Panics if the return series have different lengths, fewer than two
+/// elements, or the market returns are constant.
+#[must_use]
+pub fn capm(asset_returns: &[f64], market_returns: &[f64], risk_free_rate: f64) -> CapmRegression {
+    let asset: Vec<f64> = asset_returns.iter().map(|r| r - risk_free_rate).collect();
+    let market: Vec<f64> = market_returns.iter().map(|r| r - risk_free_rate).collect();
+
+    let (mean_a, mean_m, var_a, var_m, cov) = moments(&asset, &market);
+
+    let beta = cov / var_m;
+    let r_squared = if var_a > 0.0 {
+        cov * cov / (var_a * var_m)
+    } else {
+        1.0
+    };
+
+    CapmRegression {
+        alpha: mean_a - beta * mean_m,
+        beta,
+        r_squared,
+    }
+}
+
+/// Beta of `asset_returns` to `market_returns` over a rolling window.
+///
+/// Element `i` of the result is the beta over the returns `i..i + window`,
+/// so there are `n - window + 1` betas.
+///
+/// # Panics
+/// Panics if the return series have different lengths, or the window is
+/// shorter than two or longer than the series.
+#[must_use]
+pub fn rolling_beta(asset_returns: &[f64], market_returns: &[f64], window: usize) -> Vec<f64> {
+    assert_eq!(
+        asset_returns.len(),
+        market_returns.len(),
+        "Return series must have the same length."
+    );
+    assert!(
+        (2..=asset_returns.len()).contains(&window),
+        "Window must have at least two elements and fit in the series."
+    );
+
+    asset_returns
+        .windows(window)
+        .zip(market_returns.windows(window))
+        .map(|(asset, market)| {
+            let (_, _, _, var_m, cov) = moments(asset, market);
+
+            cov / var_m
+        })
+        .collect()
+}
+
+/// OLS regression of `asset_returns` on the columns of `factors`
+/// (one row per period, one column per factor), with an intercept.
+///
+/// # Errors
+/// Returns an error if the factors are collinear.
+///
+/// # Panics
+/// Panics if the number of returns and factor rows differ, or there are
+/// not more observations than coefficients.
+pub fn factor_regression(
+    asset_returns: &[f64],
+    factors: &DMatrix<f64>,
+    covariance: CovarianceEstimator,
+) -> Result<FactorRegression, RustQuantError> {
+    assert_eq!(
+        asset_returns.len(),
+        factors.nrows(),
+        "There must be one row of factors per return."
+    );
+
+    let x = factors.clone().insert_column(0, 1.0);
+    let y = DVector::from_column_slice(asset_returns);
+    let (n, p) = x.shape();
+
+    assert!(n > p, "There must be more observations than coefficients.");
+
+    let x_t = x.transpose();
+    let x_t_x_inv = (&x_t * &x)
+        .try_inverse()
+        .ok_or(RustQuantError::MatrixInversionFailed)?;
+
+    let coefficients = &x_t_x_inv * &x_t * &y;
+    let residuals = &y - &x * &coefficients;
+
+    let dof = (n - p) as f64;
+
+    let covariance_matrix = match covariance {
+        CovarianceEstimator::Ordinary => &x_t_x_inv * (residuals.norm_squared() / dof),
+        CovarianceEstimator::HC0 | CovarianceEstimator::HC1 => {
+            let weighted = DMatrix::from_fn(n, p, |i, j| residuals[i].powi(2) * x[(i, j)]);
+            let meat = &x_t * weighted;
+            let hc0 = &x_t_x_inv * meat * &x_t_x_inv;
+
+            match covariance {
+                CovarianceEstimator::HC1 => hc0 * (n as f64 / dof),
+                _ => hc0,
+            }
+        }
+    };
+
+    let standard_errors = covariance_matrix.diagonal().map(f64::sqrt);
+    let t_statistics = coefficients.component_div(&standard_errors);
+
+    let mean = y.mean();
+    let total = y.iter().map(|yi| (yi - mean).powi(2)).sum::<f64>();
+
+    Ok(FactorRegression {
+        coefficients,
+        standard_errors,
+        t_statistics,
+        r_squared: 1.0 - residuals.norm_squared() / total,
+    })
+}
+
+/// Means, variances, and covariance of two series.
+fn moments(x: &[f64], y: &[f64]) -> (f64, f64, f64, f64, f64) {
+    assert_eq!(x.len(), y.len(), "Return series must have the same length.");
+    assert!(
+        x.len() > 1,
+        "Return series must have at least two elements."
+    );
+
+    let n = x.len() as f64;
+    let mean_x = x.iter().sum::<f64>() / n;
+    let mean_y = y.iter().sum::<f64>() / n;
+
+    let (mut var_x, mut var_y, mut cov) = (0.0, 0.0, 0.0);
+
+    for (xi, yi) in x.iter().zip(y) {
+        var_x += (xi - mean_x).powi(2);
+        var_y += (yi - mean_y).powi(2);
+        cov += (xi - mean_x) * (yi - mean_y);
+    }
+
+    assert!(var_y > 0.0, "Market returns must not be constant.");
+
+    (mean_x, mean_y, var_x, var_y, cov)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_factor_models {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rand_distr::{Distribution, Normal, StandardNormal};
+
+    fn market_returns(n: usize) -> Vec<f64> {
+        let mut rng = StdRng::seed_from_u64(42);
+        let normal = Normal::new(0.0005, 0.01).unwrap();
+
+        (0..n).map(|_| normal.sample(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_beta_of_market_is_one() {
+        let market = market_returns(250);
+
+        let result = capm(&market, &market, 0.0001);
+
+        assert_approx_equal!(result.beta, 1.0, 1e-12);
+        assert_approx_equal!(result.alpha, 0.0, 1e-12);
+        assert_approx_equal!(result.r_squared, 1.0, 1e-12);
+
+        assert!(rolling_beta(&market, &market, 60)
+            .iter()
+            .all(|beta| (beta - 1.0).abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_leveraged_series_has_beta_two() {
+        let market = market_returns(250);
+        let mut rng = StdRng::seed_from_u64(7);
+        let noise = Normal::new(0.0, 0.002).unwrap();
+
+        let asset: Vec<f64> = market
+            .iter()
+            .map(|r| 2.0 * r + noise.sample(&mut rng))
+            .collect();
+
+        let result = capm(&asset, &market, 0.0);
+
+        assert!((result.beta - 2.0).abs() < 0.05);
+        assert!(result.r_squared > 0.95 && result.r_squared < 1.0);
+
+        let betas = rolling_beta(&asset, &market, 60);
+
+        assert_eq!(betas.len(), 250 - 60 + 1);
+        assert!(betas.iter().all(|beta| (beta - 2.0).abs() < 0.15));
+    }
+
+    #[test]
+    fn test_factor_regression_recovers_loadings() -> Result<(), RustQuantError> {
+        let n = 2_000;
+        let mut rng = StdRng::seed_from_u64(11);
+
+        let factors = DMatrix::from_fn(n, 3, |_, _| 0.01 * rng.sample::<f64, _>(StandardNormal));
+        let loadings = [1.1, 0.4, -0.3];
+
+        let returns: Vec<f64> = (0..n)
+            .map(|i| {
+                let noise: f64 = 0.001 * rng.sample::<f64, _>(StandardNormal);
+
+                0.0002 + (0..3).map(|j| loadings[j] * factors[(i, j)]).sum::<f64>() + noise
+            })
+            .collect();
+
+        let result = factor_regression(&returns, &factors, CovarianceEstimator::Ordinary)?;
+
+        for (j, loading) in loadings.iter().enumerate() {
+            assert!(
+                (result.coefficients[j + 1] - loading).abs() < 4.0 * result.standard_errors[j + 1]
+            );
+            assert!(result.t_statistics[j + 1].abs() > 10.0);
+        }
+
+        assert!(result.r_squared > 0.95);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_robust_standard_errors_under_heteroskedasticity() -> Result<(), RustQuantError> {
+        let n = 2_000;
+        let mut rng = StdRng::seed_from_u64(13);
+
+        let factors = DMatrix::from_fn(n, 1, |_, _| rng.sample::<f64, _>(StandardNormal));
+
+        // The noise scales with the factor, so the OLS errors are too small.
+        let returns: Vec<f64> = (0..n)
+            .map(|i| {
+                let f = factors[(i, 0)];
+
+                0.5 * f + f.abs() * rng.sample::<f64, _>(StandardNormal)
+            })
+            .collect();
+
+        let ols = factor_regression(&returns, &factors, CovarianceEstimator::Ordinary)?;
+        let hc0 = factor_regression(&returns, &factors, CovarianceEstimator::HC0)?;
+        let hc1 = factor_regression(&returns, &factors, CovarianceEstimator::HC1)?;
+
+        assert_eq!(ols.coefficients, hc0.coefficients);
+        assert!(hc0.standard_errors[1] > 1.3 * ols.standard_errors[1]);
+        assert!(hc1.standard_errors[1] > hc0.standard_errors[1]);
+
+        Ok(())
+    }
+}
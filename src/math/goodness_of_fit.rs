@@ -0,0 +1,123 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Goodness-of-fit tests, for validating simulated samples against a
+//! reference distribution.
+
+use crate::math::distributions::Distribution;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// One-sample Kolmogorov-Smirnov test of `data` against `distribution`.
+///
+/// Returns the KS statistic
+///
+/// $$
+/// D_n = \sup_x | F_n(x) - F(x) |
+/// $$
+///
+/// and an approximate p-value from the asymptotic Kolmogorov distribution,
+/// with Stephens' small-sample correction
+/// $\lambda = (\sqrt{n} + 0.12 + 0.11 / \sqrt{n}) D_n$:
+///
+/// $$
+/// p = 2 \sum_{k=1}^{\infty} (-1)^{k-1} e^{-2 k^2 \lambda^2}
+/// $$
+///
+/// # Panics
+/// Panics if `data` is empty.
+#[must_use]
+pub fn ks_test(data: &[f64], distribution: &dyn Distribution) -> (f64, f64) {
+    assert!(!data.is_empty(), "Data must have at least one element.");
+
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let n = sorted.len() as f64;
+
+    // The supremum is attained just before or at one of the observations.
+    let statistic = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            let cdf = distribution.cdf(*x);
+            f64::max((i + 1) as f64 / n - cdf, cdf - i as f64 / n)
+        })
+        .fold(0.0, f64::max);
+
+    let lambda = (n.sqrt() + 0.12 + 0.11 / n.sqrt()) * statistic;
+
+    (statistic, kolmogorov_survival(lambda))
+}
+
+/// Survival function $P(K > \lambda)$ of the Kolmogorov distribution.
+fn kolmogorov_survival(lambda: f64) -> f64 {
+    // The series converges slowly for small lambda, where p is ~1 anyway.
+    if lambda < 0.2 {
+        return 1.0;
+    }
+
+    let mut sum = 0.0;
+
+    for k in 1..=100 {
+        let k = f64::from(k);
+        let term = f64::exp(-2.0 * k * k * lambda * lambda);
+        sum += if k % 2.0 == 1.0 { term } else { -term };
+
+        if term < 1e-16 {
+            break;
+        }
+    }
+
+    (2.0 * sum).clamp(0.0, 1.0)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_goodness_of_fit {
+    use super::*;
+    use crate::math::distributions::Gaussian;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rand_distr::StandardNormal;
+
+    #[test]
+    fn test_ks_normal_sample_not_rejected() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let data: Vec<f64> = (0..2_000).map(|_| rng.sample(StandardNormal)).collect();
+
+        let (statistic, p_value) = ks_test(&data, &Gaussian::default());
+
+        assert!(statistic < 0.05);
+        assert!(p_value > 0.05);
+    }
+
+    #[test]
+    fn test_ks_uniform_sample_rejected() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let data: Vec<f64> = (0..2_000).map(|_| rng.gen::<f64>()).collect();
+
+        let (statistic, p_value) = ks_test(&data, &Gaussian::default());
+
+        // U(0, 1) puts no mass below zero, so D is at least ~0.5.
+        assert!(statistic > 0.45);
+        assert!(p_value < 1e-6);
+    }
+
+    #[test]
+    fn test_kolmogorov_survival() {
+        // Critical value of the Kolmogorov distribution at the 5% level.
+        assert!((kolmogorov_survival(1.358) - 0.05).abs() < 1e-3);
+        assert_approx_equal!(kolmogorov_survival(0.1), 1.0, 1e-12);
+    }
+}
@@ -0,0 +1,274 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Histograms and empirical CDFs, for diagnostics and plotting of
+//! simulated distributions.
+
+use crate::error::RustQuantError;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Rule for choosing the number of bins of a histogram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinRule {
+    /// A fixed number of bins.
+    Count(usize),
+
+    /// Sturges' rule: $\lceil \log_2 n \rceil + 1$ bins.
+    /// Works well for roughly normal data and small samples.
+    Sturges,
+
+    /// Freedman-Diaconis rule: bin width $2 \, IQR / n^{1/3}$.
+    /// Robust to outliers and heavy tails. Falls back to Sturges' rule if
+    /// the interquartile range is zero, and never uses more bins than
+    /// observations (a tiny IQR would otherwise give a huge number of bins).
+    FreedmanDiaconis,
+}
+
+/// Histogram of a sample.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// Bin edges, in ascending order (one more than the number of bins).
+    pub edges: Vec<f64>,
+
+    /// Number of observations in each bin.
+    /// Bins are half-open `[edge_i, edge_{i+1})`, except the last which is closed.
+    pub counts: Vec<usize>,
+}
+
+/// Empirical (step) CDF of a sample.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmpiricalCdf {
+    /// Distinct observations, in ascending order.
+    pub values: Vec<f64>,
+
+    /// Proportion of observations less than or equal to each value.
+    pub probabilities: Vec<f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl BinRule {
+    /// Number of bins for the given (sorted) sample.
+    fn bins(&self, sorted: &[f64]) -> usize {
+        let n = sorted.len() as f64;
+
+        let bins = match self {
+            Self::Count(bins) => *bins,
+            Self::Sturges => n.log2().ceil() as usize + 1,
+            Self::FreedmanDiaconis => {
+                let q1 = sorted[sorted.len() / 4];
+                let q3 = sorted[sorted.len() * 3 / 4];
+                let width = 2.0 * (q3 - q1) / n.cbrt();
+                let range = sorted[sorted.len() - 1] - sorted[0];
+
+                if width > 0.0 {
+                    (range / width).ceil().min(n) as usize
+                } else {
+                    Self::Sturges.bins(sorted)
+                }
+            }
+        };
+
+        bins.max(1)
+    }
+}
+
+/// Build a histogram of `data`, with the number of bins chosen by `bins`.
+///
+/// The bins span `[min(data), max(data)]` with equal widths.
+///
+/// # Errors
+/// - `RustQuantError::InvalidArgument` if `data` is empty or has a
+///   non-finite value.
+pub fn histogram(data: &[f64], bins: BinRule) -> Result<Histogram, RustQuantError> {
+    let sorted = sorted_sample(data)?;
+
+    let n_bins = bins.bins(&sorted);
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+
+    let width = if max > min {
+        (max - min) / n_bins as f64
+    } else {
+        1.0
+    };
+
+    let edges: Vec<f64> = (0..=n_bins).map(|i| min + width * i as f64).collect();
+    let mut counts = vec![0; n_bins];
+
+    for x in &sorted {
+        let bin = (((x - min) / width) as usize).min(n_bins - 1);
+        counts[bin] += 1;
+    }
+
+    Ok(Histogram { edges, counts })
+}
+
+/// Build the empirical CDF of `data`.
+///
+/// # Errors
+/// - `RustQuantError::InvalidArgument` if `data` is empty or has a
+///   non-finite value.
+pub fn empirical_cdf(data: &[f64]) -> Result<EmpiricalCdf, RustQuantError> {
+    let sorted = sorted_sample(data)?;
+
+    let n = sorted.len() as f64;
+    let mut values = Vec::new();
+    let mut probabilities = Vec::new();
+
+    for (i, x) in sorted.iter().enumerate() {
+        // Ties are collapsed into a single step at the last occurrence.
+        if sorted.get(i + 1) != Some(x) {
+            values.push(*x);
+            probabilities.push((i + 1) as f64 / n);
+        }
+    }
+
+    Ok(EmpiricalCdf {
+        values,
+        probabilities,
+    })
+}
+
+/// Sorted copy of a non-empty sample of finite values.
+fn sorted_sample(data: &[f64]) -> Result<Vec<f64>, RustQuantError> {
+    if data.is_empty() {
+        return Err(RustQuantError::InvalidArgument(
+            "data must have at least one element".to_string(),
+        ));
+    }
+    if let Some(x) = data.iter().find(|x| !x.is_finite()) {
+        return Err(RustQuantError::InvalidArgument(format!(
+            "data must be finite, got {x}"
+        )));
+    }
+
+    let mut sorted = data.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    Ok(sorted)
+}
+
+impl Histogram {
+    /// Density of each bin, normalised so the histogram integrates to one.
+    #[must_use]
+    pub fn densities(&self) -> Vec<f64> {
+        let total = self.counts.iter().sum::<usize>() as f64;
+
+        self.counts
+            .iter()
+            .zip(self.edges.windows(2))
+            .map(|(count, edge)| *count as f64 / (total * (edge[1] - edge[0])))
+            .collect()
+    }
+}
+
+impl EmpiricalCdf {
+    /// Evaluate the empirical CDF at `x`.
+    #[must_use]
+    pub fn evaluate(&self, x: f64) -> f64 {
+        match self.values.partition_point(|v| *v <= x) {
+            0 => 0.0,
+            i => self.probabilities[i - 1],
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_histogram {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use rand_distr::{Distribution, StandardNormal};
+
+    fn normal_sample(n: usize) -> Vec<f64> {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        (0..n).map(|_| StandardNormal.sample(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_histogram_counts_sum_to_sample_size() {
+        let data = normal_sample(1_000);
+
+        for rule in [
+            BinRule::Count(20),
+            BinRule::Sturges,
+            BinRule::FreedmanDiaconis,
+        ] {
+            let hist = histogram(&data, rule).unwrap();
+
+            assert_eq!(hist.counts.iter().sum::<usize>(), data.len());
+            assert_eq!(hist.edges.len(), hist.counts.len() + 1);
+
+            let area: f64 = hist
+                .densities()
+                .iter()
+                .zip(hist.edges.windows(2))
+                .map(|(d, e)| d * (e[1] - e[0]))
+                .sum();
+
+            assert_approx_equal!(area, 1.0, 1e-12);
+        }
+
+        // Sturges: ceil(log2(1000)) + 1 = 11 bins.
+        assert_eq!(histogram(&data, BinRule::Sturges).unwrap().counts.len(), 11);
+    }
+
+    #[test]
+    fn test_empirical_cdf_is_monotonic() {
+        let data = normal_sample(500);
+        let ecdf = empirical_cdf(&data).unwrap();
+
+        assert!(ecdf.probabilities.windows(2).all(|p| p[0] < p[1]));
+        assert!(ecdf.values.windows(2).all(|v| v[0] < v[1]));
+        assert_approx_equal!(*ecdf.probabilities.last().unwrap(), 1.0, 1e-12);
+
+        assert_approx_equal!(ecdf.evaluate(-100.0), 0.0, 1e-12);
+        assert_approx_equal!(ecdf.evaluate(100.0), 1.0, 1e-12);
+
+        // Ties collapse to a single step.
+        let ecdf = empirical_cdf(&[1.0, 2.0, 2.0, 3.0]).unwrap();
+
+        assert_eq!(ecdf.values, vec![1.0, 2.0, 3.0]);
+        assert_approx_equal!(ecdf.evaluate(2.5), 0.75, 1e-12);
+    }
+
+    #[test]
+    fn test_freedman_diaconis_bins_are_capped() {
+        // A tiny interquartile range next to a large outlier would give
+        // billions of bins; the rule never uses more than the sample size.
+        let mut data: Vec<f64> = (0..100).map(|i| 1.0 + 1e-12 * i as f64).collect();
+        data.push(1e6);
+
+        let hist = histogram(&data, BinRule::FreedmanDiaconis).unwrap();
+        assert_eq!(hist.counts.len(), data.len());
+
+        // A zero interquartile range falls back to Sturges' rule.
+        let hist = histogram(&[2.0; 64], BinRule::FreedmanDiaconis).unwrap();
+        assert_eq!(hist.counts.len(), 7);
+        assert_eq!(hist.counts[0], 64);
+    }
+
+    #[test]
+    fn test_invalid_samples_are_rejected() {
+        assert!(histogram(&[], BinRule::Sturges).is_err());
+        assert!(histogram(&[1.0, f64::NAN, 2.0], BinRule::Sturges).is_err());
+        assert!(histogram(&[1.0, f64::INFINITY], BinRule::FreedmanDiaconis).is_err());
+        assert!(empirical_cdf(&[]).is_err());
+        assert!(empirical_cdf(&[f64::NAN]).is_err());
+    }
+}
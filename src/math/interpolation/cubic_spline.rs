@@ -0,0 +1,415 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Cubic spline interpolation.
+//!
+//! On each interval $[x_i, x_{i+1}]$ with $h_i = x_{i+1} - x_i$ the spline is
+//!
+//! $$
+//! S(x) = a y_i + b y_{i+1} + \frac{h_i^2}{6} \left[ (a^3 - a) M_i + (b^3 - b) M_{i+1} \right]
+//! $$
+//!
+//! where $a = (x_{i+1} - x) / h_i$, $b = 1 - a$, and the $M_i$ are the second
+//! derivatives at the knots, found by solving a tridiagonal system closed
+//! by one of the [`SplineBoundary`] conditions:
+//!
+//! - **Natural**: $M_0 = M_n = 0$.
+//! - **Clamped**: the first derivatives at both ends are given.
+//! - **Not-a-knot**: the third derivative is continuous at $x_1$ and
+//!   $x_{n-1}$, so the first two and the last two intervals are each a
+//!   single cubic.
+
+use crate::math::interpolation::{
+    check_knots, insert_knot, locate, sorted_knots, InterpolationError, Interpolator,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Boundary conditions of a [`CubicSpline`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SplineBoundary {
+    /// Zero second derivative at both ends.
+    #[default]
+    Natural,
+
+    /// Given first derivatives at both ends.
+    Clamped {
+        /// First derivative at the first knot.
+        start_slope: f64,
+
+        /// First derivative at the last knot.
+        end_slope: f64,
+    },
+
+    /// Continuous third derivative at the second and the second-to-last knots.
+    /// With three knots this is the parabola through them, and with two the
+    /// straight line.
+    NotAKnot,
+}
+
+/// Cubic spline interpolator (natural unless another boundary is given).
+#[derive(Debug, Clone, Default)]
+pub struct CubicSpline {
+    /// X-axis values for the interpolator.
+    pub xs: Vec<f64>,
+
+    /// Y-axis values for the interpolator.
+    pub ys: Vec<f64>,
+
+    /// Whether the interpolator has been fitted.
+    pub fitted: bool,
+
+    /// Boundary conditions at the first and last knots.
+    pub boundary: SplineBoundary,
+
+    /// Second derivatives at the knots.
+    second_derivatives: Vec<f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl CubicSpline {
+    /// Create a new (unfitted) natural cubic spline through the knots `(xs, ys)`.
+    ///
+    /// # Errors
+    /// - `InterpolationError::UnequalLength` if ```xs.length() != ys.length()```.
+    pub fn new(xs: Vec<f64>, ys: Vec<f64>) -> Result<Self, InterpolationError> {
+        let (xs, ys) = sorted_knots(xs, ys)?;
+
+        Ok(Self {
+            xs,
+            ys,
+            ..Self::default()
+        })
+    }
+
+    /// Use the given boundary conditions (natural by default).
+    #[must_use]
+    pub fn with_boundary(mut self, boundary: SplineBoundary) -> Self {
+        self.boundary = boundary;
+        self.fitted = false;
+        self
+    }
+
+    /// Index of the interval containing `x`, if the spline is fitted.
+    fn interval(&self, x: f64) -> Result<usize, InterpolationError> {
+        if !self.fitted {
+            return Err(InterpolationError::Unfitted);
+        }
+
+        locate(&self.xs, x)
+    }
+
+    /// Returns the second derivative of the spline at `x`.
+    ///
+    /// # Errors
+    /// - `InterpolationError::Unfitted` if the spline has not been fitted.
+    /// - `InterpolationError::OutsideOfRange` if `x` is outside the knots.
+    pub fn second_derivative(&self, x: f64) -> Result<f64, InterpolationError> {
+        let i = self.interval(x)?;
+        let h = self.xs[i + 1] - self.xs[i];
+        let a = (self.xs[i + 1] - x) / h;
+
+        Ok(a * self.second_derivatives[i] + (1.0 - a) * self.second_derivatives[i + 1])
+    }
+}
+
+impl Interpolator<f64, f64> for CubicSpline {
+    fn fit(&mut self) -> Result<(), InterpolationError> {
+        check_knots(&self.xs)?;
+
+        let (xs, ys) = (&self.xs, &self.ys);
+        let n = xs.len();
+
+        let h: Vec<f64> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+        let slope = |i: usize| (ys[i + 1] - ys[i]) / h[i];
+
+        // Tridiagonal system for the second derivatives, one row per knot.
+        // The end rows default to the natural condition M = 0.
+        let mut lower = vec![0.0; n];
+        let mut diagonal = vec![1.0; n];
+        let mut upper = vec![0.0; n];
+        let mut rhs = vec![0.0; n];
+
+        for i in 1..n - 1 {
+            lower[i] = h[i - 1] / 6.0;
+            diagonal[i] = (h[i - 1] + h[i]) / 3.0;
+            upper[i] = h[i] / 6.0;
+            rhs[i] = slope(i) - slope(i - 1);
+        }
+
+        match self.boundary {
+            SplineBoundary::Natural => {}
+            SplineBoundary::Clamped {
+                start_slope,
+                end_slope,
+            } => {
+                diagonal[0] = h[0] / 3.0;
+                upper[0] = h[0] / 6.0;
+                rhs[0] = slope(0) - start_slope;
+
+                lower[n - 1] = h[n - 2] / 6.0;
+                diagonal[n - 1] = h[n - 2] / 3.0;
+                rhs[n - 1] = end_slope - slope(n - 2);
+            }
+            SplineBoundary::NotAKnot if n == 3 => {
+                let m = 2.0 * (slope(1) - slope(0)) / (h[0] + h[1]);
+
+                self.second_derivatives = vec![m; n];
+                self.fitted = true;
+
+                return Ok(());
+            }
+            SplineBoundary::NotAKnot if n > 3 => {
+                // Substitute M_0 = ((h_0 + h_1) M_1 - h_0 M_2) / h_1 into the
+                // second row, and likewise M_{n-1} into the second-to-last row.
+                diagonal[1] += lower[1] * (h[0] + h[1]) / h[1];
+                upper[1] -= lower[1] * h[0] / h[1];
+                lower[1] = 0.0;
+
+                let (k, l) = (n - 3, n - 2);
+                diagonal[l] += upper[l] * (h[k] + h[l]) / h[k];
+                lower[l] -= upper[l] * h[l] / h[k];
+                upper[l] = 0.0;
+            }
+            SplineBoundary::NotAKnot => {}
+        }
+
+        let mut m = solve_tridiagonal(&lower, &diagonal, &upper, &rhs);
+
+        if self.boundary == SplineBoundary::NotAKnot && n > 3 {
+            m[0] = ((h[0] + h[1]) * m[1] - h[0] * m[2]) / h[1];
+            m[n - 1] = ((h[n - 3] + h[n - 2]) * m[n - 2] - h[n - 2] * m[n - 3]) / h[n - 3];
+        }
+
+        self.second_derivatives = m;
+        self.fitted = true;
+
+        Ok(())
+    }
+
+    fn interpolate(&self, point: f64) -> Result<f64, InterpolationError> {
+        let i = self.interval(point)?;
+        let m = &self.second_derivatives;
+
+        let h = self.xs[i + 1] - self.xs[i];
+        let a = (self.xs[i + 1] - point) / h;
+        let b = 1.0 - a;
+
+        Ok(a * self.ys[i]
+            + b * self.ys[i + 1]
+            + ((a.powi(3) - a) * m[i] + (b.powi(3) - b) * m[i + 1]) * h * h / 6.0)
+    }
+
+    fn derivative(&self, point: f64) -> Result<f64, InterpolationError> {
+        let i = self.interval(point)?;
+        let m = &self.second_derivatives;
+
+        let h = self.xs[i + 1] - self.xs[i];
+        let a = (self.xs[i + 1] - point) / h;
+        let b = 1.0 - a;
+
+        Ok(
+            (self.ys[i + 1] - self.ys[i]) / h - (3.0 * a * a - 1.0) * h * m[i] / 6.0
+                + (3.0 * b * b - 1.0) * h * m[i + 1] / 6.0,
+        )
+    }
+
+    fn range(&self) -> (f64, f64) {
+        (*self.xs.first().unwrap(), *self.xs.last().unwrap())
+    }
+
+    fn add_point(&mut self, point: (f64, f64)) {
+        insert_knot(&mut self.xs, &mut self.ys, point);
+        self.fitted = false;
+    }
+}
+
+/// Solve a tridiagonal system by the Thomas algorithm.
+pub(crate) fn solve_tridiagonal(
+    lower: &[f64],
+    diagonal: &[f64],
+    upper: &[f64],
+    rhs: &[f64],
+) -> Vec<f64> {
+    let n = diagonal.len();
+
+    let mut c_prime = vec![0.0; n];
+    let mut d_prime = vec![0.0; n];
+
+    for i in 0..n {
+        let (c, d) = if i == 0 {
+            (0.0, 0.0)
+        } else {
+            (c_prime[i - 1], d_prime[i - 1])
+        };
+        let denominator = diagonal[i] - lower[i] * c;
+
+        c_prime[i] = upper[i] / denominator;
+        d_prime[i] = (rhs[i] - lower[i] * d) / denominator;
+    }
+
+    let mut x = d_prime;
+
+    for i in (0..n - 1).rev() {
+        x[i] -= c_prime[i] * x[i + 1];
+    }
+
+    x
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_cubic_spline {
+    use super::*;
+
+    #[test]
+    fn test_cubic_spline_natural_ends() {
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let ys = xs.map(f64::sin);
+
+        let mut spline = CubicSpline::new(xs.to_vec(), ys.to_vec()).unwrap();
+        spline.fit().unwrap();
+
+        assert_approx_equal!(spline.second_derivative(0.0).unwrap(), 0.0, 1e-12);
+        assert_approx_equal!(spline.second_derivative(4.0).unwrap(), 0.0, 1e-12);
+        assert_approx_equal!(spline.interpolate(1.5).unwrap(), f64::sin(1.5), 1e-2);
+    }
+
+    #[test]
+    fn test_cubic_spline_linear_data() {
+        let xs = [0.0, 1.0, 3.0, 4.0];
+        let ys = xs.map(|x| 2.0 * x + 1.0);
+
+        let mut spline = CubicSpline::new(xs.to_vec(), ys.to_vec()).unwrap();
+        spline.fit().unwrap();
+
+        assert_approx_equal!(spline.interpolate(2.2).unwrap(), 5.4, 1e-12);
+        assert_approx_equal!(spline.derivative(2.2).unwrap(), 2.0, 1e-12);
+    }
+
+    fn fitted(xs: &[f64], ys: &[f64], boundary: SplineBoundary) -> CubicSpline {
+        let mut spline = CubicSpline::new(xs.to_vec(), ys.to_vec())
+            .unwrap()
+            .with_boundary(boundary);
+        spline.fit().unwrap();
+
+        spline
+    }
+
+    #[test]
+    fn test_cubic_spline_boundaries_reproduce_knots() {
+        let xs = [0.0, 0.5, 1.5, 2.0, 3.5, 5.0];
+        let ys = xs.map(|x: f64| x.exp().ln_1p());
+
+        for boundary in [
+            SplineBoundary::Natural,
+            SplineBoundary::Clamped {
+                start_slope: 0.3,
+                end_slope: -1.0,
+            },
+            SplineBoundary::NotAKnot,
+        ] {
+            let spline = fitted(&xs, &ys, boundary);
+
+            for (x, y) in xs.iter().zip(ys) {
+                assert_approx_equal!(spline.interpolate(*x).unwrap(), y, 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cubic_spline_clamped_end_slopes() {
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let ys = xs.map(f64::sin);
+
+        let spline = fitted(
+            &xs,
+            &ys,
+            SplineBoundary::Clamped {
+                start_slope: 1.0,
+                end_slope: f64::cos(4.0),
+            },
+        );
+
+        assert_approx_equal!(spline.derivative(0.0).unwrap(), 1.0, 1e-12);
+        assert_approx_equal!(spline.derivative(4.0).unwrap(), f64::cos(4.0), 1e-12);
+
+        // With the true end slopes, a cubic is reproduced exactly.
+        let cubic = |x: f64| x.powi(3) - 2.0 * x * x + 1.0;
+        let spline = fitted(
+            &xs,
+            &xs.map(cubic),
+            SplineBoundary::Clamped {
+                start_slope: 0.0,
+                end_slope: 32.0,
+            },
+        );
+
+        assert_approx_equal!(spline.interpolate(2.7).unwrap(), cubic(2.7), 1e-12);
+    }
+
+    #[test]
+    fn test_cubic_spline_not_a_knot() {
+        // A cubic is reproduced exactly, without any end derivatives.
+        let xs = [0.0, 0.5, 1.5, 2.0, 3.5];
+        let cubic = |x: f64| x.powi(3) - 2.0 * x * x + 1.0;
+        let spline = fitted(&xs, &xs.map(cubic), SplineBoundary::NotAKnot);
+
+        for x in [0.2, 1.1, 3.0] {
+            assert_approx_equal!(spline.interpolate(x).unwrap(), cubic(x), 1e-12);
+        }
+
+        // The third derivative is continuous at the second knot.
+        let third = |i: usize| {
+            (spline.second_derivatives[i + 1] - spline.second_derivatives[i]) / (xs[i + 1] - xs[i])
+        };
+
+        assert_approx_equal!(third(0), third(1), 1e-12);
+
+        // Three knots give the parabola through them.
+        let parabola = |x: f64| 3.0 * x * x - x + 2.0;
+        let spline = fitted(
+            &[0.0, 1.0, 3.0],
+            &[0.0, 1.0, 3.0].map(parabola),
+            SplineBoundary::NotAKnot,
+        );
+
+        assert_approx_equal!(spline.interpolate(2.2).unwrap(), parabola(2.2), 1e-12);
+    }
+
+    #[test]
+    fn test_cubic_spline_errors() {
+        let mut spline = CubicSpline::new(vec![0.0, 1.0, 1.0], vec![0.0, 1.0, 2.0]).unwrap();
+
+        assert_eq!(
+            spline.interpolate(0.5).err(),
+            Some(InterpolationError::Unfitted)
+        );
+        assert_eq!(
+            spline.fit().err(),
+            Some(InterpolationError::NotStrictlyIncreasing)
+        );
+        assert_eq!(
+            CubicSpline::new(vec![0.0], vec![]).err(),
+            Some(InterpolationError::UnequalLength)
+        );
+        assert_eq!(
+            CubicSpline::new(vec![0.0], vec![1.0]).unwrap().fit().err(),
+            Some(InterpolationError::InsufficientData)
+        );
+    }
+}
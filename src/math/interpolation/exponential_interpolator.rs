@@ -9,13 +9,16 @@
 
 //! Module containing functionality for interpolation.
 
-use crate::math::interpolation::{InterpolationError, InterpolationIndex, InterpolationValue};
+use crate::math::interpolation::{
+    InterpolationError, InterpolationIndex, InterpolationValue, Interpolator,
+};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // STRUCTS & ENUMS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 /// Exponential Interpolator.
+#[derive(Debug, Clone)]
 pub struct ExponentialInterpolator<IndexType, ValueType>
 where
     IndexType: InterpolationIndex,
@@ -31,6 +34,11 @@ where
     pub fitted: bool,
 }
 
+/// Log-linear interpolator, i.e. linear interpolation of $\ln y$
+/// (see [`ExponentialInterpolator`]).
+pub type LogLinearInterpolator<IndexType, ValueType> =
+    ExponentialInterpolator<IndexType, ValueType>;
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // IMPLEMENTATIONS, FUNCTIONS, AND MACROS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -69,88 +77,160 @@ where
     }
 }
 
-// impl<IndexType, ValueType> Interpolator<IndexType, ValueType>
-//     for ExponentialInterpolator<IndexType, ValueType>
-// where
-//     IndexType: InterpolationIndex<DeltaDiv = ValueType>, //+ std::ops::Div<Output = ValueType>,
-//     ValueType: InterpolationValue + num_traits::Float,
-// {
-//     fn fit(&mut self) -> Result<(), InterpolationError> {
-//         self.fitted = true;
-//         Ok(())
-//     }
+impl<IndexType, ValueType> Default for ExponentialInterpolator<IndexType, ValueType>
+where
+    IndexType: InterpolationIndex,
+    ValueType: InterpolationValue,
+{
+    /// An empty interpolator, to be filled with [`Interpolator::add_point`].
+    fn default() -> Self {
+        Self {
+            xs: Vec::new(),
+            ys: Vec::new(),
+            fitted: false,
+        }
+    }
+}
 
-//     fn range(&self) -> (IndexType, IndexType) {
-//         (*self.xs.first().unwrap(), *self.xs.last().unwrap())
-//     }
+impl<IndexType, ValueType> Interpolator<IndexType, ValueType>
+    for ExponentialInterpolator<IndexType, ValueType>
+where
+    IndexType: InterpolationIndex<DeltaDiv = ValueType>,
+    ValueType: InterpolationValue + num::Float,
+{
+    fn fit(&mut self) -> Result<(), InterpolationError> {
+        if self.xs.is_empty() {
+            return Err(InterpolationError::InsufficientData);
+        }
+        if !self.xs.windows(2).all(|w| w[0] < w[1]) {
+            return Err(InterpolationError::NotStrictlyIncreasing);
+        }
 
-//     fn add_point(&mut self, point: (IndexType, ValueType)) {
-//         let idx = self.xs.partition_point(|&x| x < point.0);
+        self.fitted = true;
+        Ok(())
+    }
 
-//         self.xs.insert(idx, point.0);
-//         self.ys.insert(idx, point.1);
-//     }
+    fn range(&self) -> (IndexType, IndexType) {
+        (*self.xs.first().unwrap(), *self.xs.last().unwrap())
+    }
 
-//     fn interpolate(&self, point: IndexType) -> Result<ValueType, InterpolationError> {
-//         let range = self.range();
+    fn add_point(&mut self, point: (IndexType, ValueType)) {
+        let idx = self.xs.partition_point(|&x| x < point.0);
 
-//         if point.partial_cmp(&range.0).unwrap() == std::cmp::Ordering::Less
-//             || point.partial_cmp(&range.1).unwrap() == std::cmp::Ordering::Greater
-//         {
-//             return Err(InterpolationError::OutsideOfRange);
-//         }
+        self.xs.insert(idx, point.0);
+        self.ys.insert(idx, point.1);
+        self.fitted = false;
+    }
 
-//         if let Ok(idx) = self
-//             .xs
-//             .binary_search_by(|p| p.partial_cmp(&point).expect("Cannot compare values."))
-//         {
-//             return Ok(self.ys[idx]);
-//         }
+    /// Interpolates geometrically between the neighbouring points:
+    /// $y = y_l (y_r / y_l)^w$, where $w = (x - x_l) / (x_r - x_l)$.
+    /// This is linear interpolation of $\ln y$, e.g. of log discount factors.
+    fn interpolate(&self, point: IndexType) -> Result<ValueType, InterpolationError> {
+        if !self.fitted {
+            return Err(InterpolationError::Unfitted);
+        }
 
-//         let idx_r = self.xs.partition_point(|&x| x < point);
-//         let idx_l = idx_r - 1;
+        let range = self.range();
 
-//         let lambda = (self.xs[idx_r] - point) / (self.xs[idx_r] - self.xs[idx_l]);
+        if point < range.0 || point > range.1 {
+            return Err(InterpolationError::OutsideOfRange);
+        }
 
-//         let exponent_1 = lambda * (point / self.xs[idx_l]);
-//         let exponent_2 = point / self.xs[idx_r] - lambda * (point / self.xs[idx_r]);
+        let idx_r = self.xs.partition_point(|&x| x < point);
 
-//         let term_1 = self.ys[idx_l].powf(exponent_1);
-//         let term_2 = self.ys[idx_r].powf(exponent_2);
+        if self.xs[idx_r] == point {
+            return Ok(self.ys[idx_r]);
+        }
 
-//         let result = term_1 * term_2;
+        let idx_l = idx_r - 1;
+        let w = (point - self.xs[idx_l]) / (self.xs[idx_r] - self.xs[idx_l]);
 
-//         Ok(result)
-//     }
-// }
+        Ok(self.ys[idx_l] * (self.ys[idx_r] / self.ys[idx_l]).powf(w))
+    }
 
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// Unit tests
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    /// The derivative of the geometric interpolation, $y \ln(y_r / y_l) / (x_r - x_l)$.
+    fn derivative(&self, point: IndexType) -> Result<ValueType, InterpolationError>
+    where
+        ValueType: std::ops::Div<IndexType::Delta, Output = ValueType>,
+    {
+        let value = self.interpolate(point)?;
 
-// #[cfg(test)]
-// mod tests_exponential_interpolation {
-//     use super::*;
-//     use crate::{assert_approx_equal, RUSTQUANT_EPSILON};
-//     use time::macros::date;
+        if self.xs.len() < 2 {
+            return Err(InterpolationError::InsufficientData);
+        }
 
-//     // #[test]
-//     // fn test_exponential_interpolation_dates() {
-//     //     let d_1m = date!(1990 - 06 - 16);
-//     //     let d_2m = date!(1990 - 07 - 17);
+        let idx_r = self
+            .xs
+            .partition_point(|&x| x <= point)
+            .clamp(1, self.xs.len() - 1);
+        let idx_l = idx_r - 1;
 
-//     //     let r_1m = 0.9870;
-//     //     let r_2m = 0.9753;
+        Ok(value * (self.ys[idx_r] / self.ys[idx_l]).ln() / (self.xs[idx_r] - self.xs[idx_l]))
+    }
+}
 
-//     //     let dates = vec![d_1m, d_2m];
-//     //     let rates = vec![r_1m, r_2m];
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Unit tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-//     //     let mut interpolator = ExponentialInterpolator::new(dates, rates).unwrap();
+#[cfg(test)]
+mod tests_exponential_interpolation {
+    use super::*;
+    use crate::RUSTQUANT_EPSILON;
+    use time::macros::date;
+
+    #[test]
+    fn test_exponential_interpolation_dates() {
+        let d_1m = date!(1990 - 06 - 16);
+        let d_2m = date!(1990 - 07 - 17);
+
+        let r_1m = 0.9870;
+        let r_2m = 0.9753;
+
+        let dates = vec![d_1m, d_2m];
+        let rates = vec![r_1m, r_2m];
+
+        let mut interpolator = ExponentialInterpolator::new(dates, rates).unwrap();
+        interpolator.fit().unwrap();
+
+        // 4 of the 31 days between the points.
+        assert_approx_equal!(
+            interpolator.interpolate(date!(1990 - 06 - 20)).unwrap(),
+            r_1m * (r_2m / r_1m).powf(4.0 / 31.0),
+            RUSTQUANT_EPSILON
+        );
+        assert_approx_equal!(
+            interpolator.interpolate(d_2m).unwrap(),
+            r_2m,
+            RUSTQUANT_EPSILON
+        );
+    }
 
-//     //     assert_approx_equal!(
-//     //         0.9854,
-//     //         interpolator.interpolate(date!(1990 - 06 - 20)).unwrap(),
-//     //         RUSTQUANT_EPSILON
-//     //     );
-//     // }
-// }
+    #[test]
+    fn test_exponential_interpolation_geometric_midpoint() {
+        let mut interpolator =
+            ExponentialInterpolator::new(vec![0.0, 1.0, 2.0], vec![1.0, 0.9, 0.8]).unwrap();
+
+        assert_eq!(
+            interpolator.interpolate(0.5).err(),
+            Some(InterpolationError::Unfitted)
+        );
+
+        interpolator.fit().unwrap();
+
+        assert_approx_equal!(
+            interpolator.interpolate(1.5).unwrap(),
+            f64::sqrt(0.9 * 0.8),
+            RUSTQUANT_EPSILON
+        );
+        assert_approx_equal!(
+            interpolator.derivative(1.5).unwrap(),
+            f64::sqrt(0.9 * 0.8) * f64::ln(0.8 / 0.9),
+            RUSTQUANT_EPSILON
+        );
+        assert_eq!(
+            interpolator.interpolate(2.5).err(),
+            Some(InterpolationError::OutsideOfRange)
+        );
+    }
+}
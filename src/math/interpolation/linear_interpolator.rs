@@ -18,6 +18,7 @@ use crate::math::interpolation::{
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 /// Linear Interpolator.
+#[derive(Debug, Clone)]
 pub struct LinearInterpolator<IndexType, ValueType>
 where
     IndexType: InterpolationIndex,
@@ -71,6 +72,21 @@ where
     }
 }
 
+impl<IndexType, ValueType> Default for LinearInterpolator<IndexType, ValueType>
+where
+    IndexType: InterpolationIndex,
+    ValueType: InterpolationValue,
+{
+    /// An empty interpolator, to be filled with [`Interpolator::add_point`].
+    fn default() -> Self {
+        Self {
+            xs: Vec::new(),
+            ys: Vec::new(),
+            fitted: false,
+        }
+    }
+}
+
 impl<IndexType, ValueType> Interpolator<IndexType, ValueType>
     for LinearInterpolator<IndexType, ValueType>
 where
@@ -78,6 +94,13 @@ where
     ValueType: InterpolationValue,
 {
     fn fit(&mut self) -> Result<(), InterpolationError> {
+        if self.xs.is_empty() {
+            return Err(InterpolationError::InsufficientData);
+        }
+        if !self.xs.windows(2).all(|w| w[0] < w[1]) {
+            return Err(InterpolationError::NotStrictlyIncreasing);
+        }
+
         self.fitted = true;
         Ok(())
     }
@@ -90,6 +113,7 @@ where
         let idx = self.xs.partition_point(|&x| x < point.0);
         self.xs.insert(idx, point.0);
         self.ys.insert(idx, point.1);
+        self.fitted = false;
     }
 
     fn interpolate(&self, point: IndexType) -> Result<ValueType, InterpolationError> {
@@ -115,6 +139,30 @@ where
 
         Ok(result)
     }
+
+    /// The slope of the knots on either side of `point`: the interpolant is
+    /// piecewise linear, so its derivative is piecewise constant.
+    fn derivative(&self, point: IndexType) -> Result<ValueType, InterpolationError>
+    where
+        ValueType: std::ops::Div<IndexType::Delta, Output = ValueType>,
+    {
+        if self.xs.len() < 2 {
+            return Err(InterpolationError::InsufficientData);
+        }
+
+        let range = self.range();
+        if point < range.0 || point > range.1 {
+            return Err(InterpolationError::OutsideOfRange);
+        }
+
+        let idx_r = self
+            .xs
+            .partition_point(|&x| x <= point)
+            .clamp(1, self.xs.len() - 1);
+        let idx_l = idx_r - 1;
+
+        Ok((self.ys[idx_r] - self.ys[idx_l]) / (self.xs[idx_r] - self.xs[idx_l]))
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -147,6 +195,34 @@ mod tests_linear_interpolation {
         );
     }
 
+    #[test]
+    fn test_linear_interpolation_derivative() {
+        let mut interpolator =
+            LinearInterpolator::new(vec![0.0, 1.0, 3.0], vec![0.0, 2.0, 3.0]).unwrap();
+        interpolator.fit().unwrap();
+
+        // The slope is constant between the knots, and taken from the right at them.
+        assert_approx_equal!(
+            interpolator.derivative(0.5).unwrap(),
+            2.0,
+            RUSTQUANT_EPSILON
+        );
+        assert_approx_equal!(
+            interpolator.derivative(1.0).unwrap(),
+            0.5,
+            RUSTQUANT_EPSILON
+        );
+        assert_approx_equal!(
+            interpolator.derivative(3.0).unwrap(),
+            0.5,
+            RUSTQUANT_EPSILON
+        );
+        assert_eq!(
+            interpolator.derivative(3.5),
+            Err(InterpolationError::OutsideOfRange)
+        );
+    }
+
     #[test]
     fn test_linear_interpolation_out_of_range() {
         let xs = vec![1., 2., 3., 4., 5.];
@@ -204,4 +280,21 @@ mod tests_linear_interpolation {
             RUSTQUANT_EPSILON
         );
     }
+
+    #[test]
+    fn test_linear_interpolation_duplicate_points() {
+        let mut interpolator = LinearInterpolator::new(vec![1., 2., 2.], vec![1., 2., 3.]).unwrap();
+
+        assert_eq!(
+            interpolator.fit().err(),
+            Some(InterpolationError::NotStrictlyIncreasing)
+        );
+
+        let mut interpolator = LinearInterpolator::<f64, f64>::default();
+        interpolator.add_point((2., 4.));
+        interpolator.add_point((0., 0.));
+        interpolator.fit().unwrap();
+
+        assert_approx_equal!(interpolator.interpolate(1.).unwrap(), 2., RUSTQUANT_EPSILON);
+    }
 }
@@ -13,6 +13,15 @@ pub use linear_interpolator::*;
 pub mod exponential_interpolator;
 pub use exponential_interpolator::*;
 
+pub mod cubic_spline;
+pub use cubic_spline::*;
+
+pub mod hermite_monotone;
+pub use hermite_monotone::*;
+
+pub mod step_interpolator;
+pub use step_interpolator::*;
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
@@ -27,6 +36,12 @@ pub enum InterpolationError {
 
     /// Outside of interpolation range.
     OutsideOfRange,
+
+    /// Not enough points to fit the interpolator.
+    InsufficientData,
+
+    /// The index values are not strictly increasing (e.g. a repeated point).
+    NotStrictlyIncreasing,
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -66,6 +81,19 @@ where
     /// - `InterpolationError::Unfitted` when the interpolator has not been fitted.
     fn interpolate(&self, point: IndexType) -> Result<ValueType, InterpolationError>;
 
+    /// First derivative of the interpolant at value `point`.
+    ///
+    /// Where the interpolant has a kink (e.g. at the knots of a linear
+    /// interpolator), this is the derivative from the right, except at the
+    /// last knot where it is the derivative from the left.
+    ///
+    /// # Errors
+    /// - `InterpolationError::Unfitted` when the interpolator has not been fitted.
+    /// - `InterpolationError::OutsideOfRange` when `point` is outside the knots.
+    fn derivative(&self, point: IndexType) -> Result<ValueType, InterpolationError>
+    where
+        ValueType: std::ops::Div<IndexType::Delta, Output = ValueType>;
+
     /// Return range of interpolation.
     fn range(&self) -> (IndexType, IndexType);
 
@@ -73,6 +101,77 @@ where
     fn add_point(&mut self, point: (IndexType, ValueType));
 }
 
+/// Check that real-valued knots can be fitted: at least two of them,
+/// strictly increasing.
+pub(crate) fn check_knots(xs: &[f64]) -> Result<(), InterpolationError> {
+    if xs.len() < 2 {
+        return Err(InterpolationError::InsufficientData);
+    }
+    if !xs.windows(2).all(|w| w[0] < w[1]) {
+        return Err(InterpolationError::NotStrictlyIncreasing);
+    }
+
+    Ok(())
+}
+
+/// Index `i` of the interval `[xs[i], xs[i + 1]]` containing `x`.
+pub(crate) fn locate(xs: &[f64], x: f64) -> Result<usize, InterpolationError> {
+    if x < xs[0] || x > xs[xs.len() - 1] {
+        return Err(InterpolationError::OutsideOfRange);
+    }
+
+    Ok(xs.partition_point(|&v| v <= x).clamp(1, xs.len() - 1) - 1)
+}
+
+/// An interpolator of type `I` fitted to the knots `(xs, ys)`.
+///
+/// # Errors
+/// - `InterpolationError::UnequalLength` if `xs.len() != ys.len()`.
+/// - Any error from fitting the interpolator to the knots.
+pub(crate) fn fitted_interpolator<I>(xs: &[f64], ys: &[f64]) -> Result<I, InterpolationError>
+where
+    I: Interpolator<f64, f64> + Default,
+{
+    if xs.len() != ys.len() {
+        return Err(InterpolationError::UnequalLength);
+    }
+
+    let mut interpolator = I::default();
+
+    for point in xs.iter().copied().zip(ys.iter().copied()) {
+        interpolator.add_point(point);
+    }
+
+    interpolator.fit()?;
+
+    Ok(interpolator)
+}
+
+/// Insert a point into sorted knots, keeping them sorted.
+pub(crate) fn insert_knot(xs: &mut Vec<f64>, ys: &mut Vec<f64>, point: (f64, f64)) {
+    let idx = xs.partition_point(|&x| x < point.0);
+    xs.insert(idx, point.0);
+    ys.insert(idx, point.1);
+}
+
+/// Sort real-valued knots by `xs`.
+///
+/// # Errors
+/// - `InterpolationError::UnequalLength` if `xs.len() != ys.len()`.
+pub(crate) fn sorted_knots(
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+) -> Result<(Vec<f64>, Vec<f64>), InterpolationError> {
+    if xs.len() != ys.len() {
+        return Err(InterpolationError::UnequalLength);
+    }
+
+    let mut knots: Vec<(f64, f64)> = xs.into_iter().zip(ys).collect();
+    knots.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    Ok(knots.into_iter().unzip())
+}
+
 // macro_rules! impl_Interpolator {
 //     ($t:ty) => {
 //         impl Interpolator for $t {
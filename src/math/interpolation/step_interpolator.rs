@@ -0,0 +1,163 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Piecewise constant (step) interpolation.
+//!
+//! Between two knots the value is held flat, either from the left knot
+//! (forward flat, e.g. instantaneous forward rates that apply until the next
+//! pillar) or from the right knot (backward flat).
+
+use crate::math::interpolation::{
+    check_knots, insert_knot, locate, sorted_knots, InterpolationError, Interpolator,
+};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Which knot a [`StepInterpolator`] holds flat between two knots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StepDirection {
+    /// The value of the left knot, up to (not including) the right knot.
+    #[default]
+    ForwardFlat,
+
+    /// The value of the right knot, from (not including) the left knot.
+    BackwardFlat,
+}
+
+/// Piecewise constant interpolator (forward flat unless another direction is given).
+#[derive(Debug, Clone, Default)]
+pub struct StepInterpolator {
+    /// X-axis values for the interpolator.
+    pub xs: Vec<f64>,
+
+    /// Y-axis values for the interpolator.
+    pub ys: Vec<f64>,
+
+    /// Whether the interpolator has been fitted.
+    pub fitted: bool,
+
+    /// Which knot is held flat between two knots.
+    pub direction: StepDirection,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl StepInterpolator {
+    /// Create a new (unfitted) forward flat interpolator through the knots `(xs, ys)`.
+    ///
+    /// # Errors
+    /// - `InterpolationError::UnequalLength` if ```xs.length() != ys.length()```.
+    pub fn new(xs: Vec<f64>, ys: Vec<f64>) -> Result<Self, InterpolationError> {
+        let (xs, ys) = sorted_knots(xs, ys)?;
+
+        Ok(Self {
+            xs,
+            ys,
+            ..Self::default()
+        })
+    }
+
+    /// Hold the given knot flat between two knots (forward flat by default).
+    #[must_use]
+    pub fn with_direction(mut self, direction: StepDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+}
+
+impl Interpolator<f64, f64> for StepInterpolator {
+    fn fit(&mut self) -> Result<(), InterpolationError> {
+        check_knots(&self.xs)?;
+
+        self.fitted = true;
+
+        Ok(())
+    }
+
+    fn interpolate(&self, point: f64) -> Result<f64, InterpolationError> {
+        if !self.fitted {
+            return Err(InterpolationError::Unfitted);
+        }
+
+        let i = locate(&self.xs, point)?;
+
+        // The knots themselves are always reproduced.
+        if point == self.xs[i + 1] {
+            return Ok(self.ys[i + 1]);
+        }
+
+        Ok(match self.direction {
+            StepDirection::ForwardFlat => self.ys[i],
+            StepDirection::BackwardFlat if point == self.xs[i] => self.ys[i],
+            StepDirection::BackwardFlat => self.ys[i + 1],
+        })
+    }
+
+    /// Zero: the interpolant is piecewise constant.
+    fn derivative(&self, point: f64) -> Result<f64, InterpolationError> {
+        self.interpolate(point).map(|_| 0.0)
+    }
+
+    fn range(&self) -> (f64, f64) {
+        (*self.xs.first().unwrap(), *self.xs.last().unwrap())
+    }
+
+    fn add_point(&mut self, point: (f64, f64)) {
+        insert_knot(&mut self.xs, &mut self.ys, point);
+        self.fitted = false;
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_step_interpolator {
+    use super::*;
+
+    #[test]
+    fn test_step_interpolator() {
+        let xs = vec![0.0, 1.0, 2.0, 4.0];
+        let ys = vec![0.01, 0.02, 0.025, 0.03];
+
+        let mut forward = StepInterpolator::new(xs.clone(), ys.clone()).unwrap();
+        let mut backward = StepInterpolator::new(xs.clone(), ys.clone())
+            .unwrap()
+            .with_direction(StepDirection::BackwardFlat);
+
+        assert_eq!(forward.interpolate(0.5), Err(InterpolationError::Unfitted));
+
+        forward.fit().unwrap();
+        backward.fit().unwrap();
+
+        assert_eq!(forward.derivative(0.5), Ok(0.0));
+        assert_eq!(
+            backward.derivative(4.5),
+            Err(InterpolationError::OutsideOfRange)
+        );
+
+        for (x, y) in xs.iter().zip(ys.iter()) {
+            assert_eq!(forward.interpolate(*x).unwrap(), *y);
+            assert_eq!(backward.interpolate(*x).unwrap(), *y);
+        }
+
+        assert_eq!(forward.interpolate(1.5).unwrap(), 0.02);
+        assert_eq!(backward.interpolate(1.5).unwrap(), 0.025);
+        assert_eq!(forward.interpolate(3.99).unwrap(), 0.025);
+        assert_eq!(
+            forward.interpolate(4.5),
+            Err(InterpolationError::OutsideOfRange)
+        );
+    }
+}
@@ -0,0 +1,475 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Low-discrepancy (quasi-random) sequences on the unit hypercube.
+//!
+//! Quasi-Monte Carlo replaces pseudo-random points with points that fill
+//! the unit hypercube evenly, so integration errors decay close to $O(1/n)$
+//! instead of $O(1/\sqrt{n})$. Each sequence can be randomized by a seeded
+//! shift (or, for Sobol' points, a scrambling), so that independent
+//! replications give an error estimate while keeping the even spread of the
+//! points.
+
+use crate::error::RustQuantError;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Number of bits of the Sobol' integers.
+const SOBOL_BITS: usize = 32;
+
+/// Joe-Kuo (`new-joe-kuo-6.21201`) primitive polynomials and initial
+/// direction numbers of Sobol' dimensions 2 and up, as
+/// `(degree, coefficients, initial direction numbers)`.
+/// Dimension 1 is the van der Corput sequence in base 2. Dimensions above
+/// the table use the next primitive polynomials, with odd initial direction
+/// numbers drawn from a fixed seed.
+const SOBOL_DIRECTIONS: [(u32, u32, &[u32]); 15] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+];
+
+/// Bases of the Halton dimensions: the first primes.
+const HALTON_BASES: [u64; 16] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Sobol' sequence, generated in Gray code order.
+#[derive(Debug, Clone)]
+pub struct SobolSequence {
+    /// Direction numbers, `directions[j][k]` for dimension `j` and bit `k`.
+    directions: Vec<[u32; SOBOL_BITS]>,
+
+    /// Integer coordinates of the current point.
+    state: Vec<u32>,
+
+    /// Digital shift XOR-ed into every point (zero if unrandomized or only
+    /// scrambled).
+    shift: Vec<u32>,
+
+    /// Index of the next point.
+    index: u64,
+}
+
+/// Halton sequence: the radical inverses of the point index in the first
+/// prime bases.
+#[derive(Debug, Clone)]
+pub struct HaltonSequence {
+    /// Number of dimensions.
+    dimension: usize,
+
+    /// Cranley-Patterson rotation added (modulo one) to every point.
+    shift: Vec<f64>,
+
+    /// Index of the next point.
+    index: u64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl SobolSequence {
+    /// Maximum number of dimensions supported.
+    pub const MAX_DIMENSION: usize = 1024;
+
+    /// Create a new (unrandomized) Sobol' sequence in `dimension` dimensions.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the dimension is zero or
+    ///   above [`SobolSequence::MAX_DIMENSION`].
+    pub fn new(dimension: usize) -> Result<Self, RustQuantError> {
+        if dimension == 0 || dimension > Self::MAX_DIMENSION {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "Sobol' sequences support 1 to {} dimensions, got {dimension}",
+                Self::MAX_DIMENSION
+            )));
+        }
+
+        let mut directions = Vec::with_capacity(dimension);
+
+        // Dimension 1: all initial direction numbers are one.
+        directions.push(std::array::from_fn(|k| 1 << (SOBOL_BITS - 1 - k)));
+
+        let polynomials = primitive_polynomials(dimension - 1);
+
+        for (j, &(degree, coefficients)) in polynomials.iter().enumerate() {
+            let s = degree as usize;
+            let initial: Vec<u32> = match SOBOL_DIRECTIONS.get(j) {
+                Some((_, _, initial)) => initial.to_vec(),
+                None => (1..=s)
+                    .map(|k| {
+                        let draw = splitmix64(((j as u64) << 8) | k as u64);
+                        ((draw % (1 << (k - 1))) as u32) << 1 | 1
+                    })
+                    .collect(),
+            };
+            let mut v = [0_u32; SOBOL_BITS];
+
+            for k in 0..SOBOL_BITS {
+                v[k] = if k < s {
+                    initial[k] << (SOBOL_BITS - 1 - k)
+                } else {
+                    let mut value = v[k - s] ^ (v[k - s] >> s);
+
+                    for i in 1..s {
+                        if (coefficients >> (s - 1 - i)) & 1 == 1 {
+                            value ^= v[k - i];
+                        }
+                    }
+
+                    value
+                };
+            }
+
+            directions.push(v);
+        }
+
+        Ok(Self {
+            directions,
+            state: vec![0; dimension],
+            shift: vec![0; dimension],
+            index: 0,
+        })
+    }
+
+    /// Randomize the sequence by a digital shift (a random XOR of every
+    /// coordinate) drawn from the given seed. Each shifted point is still
+    /// uniformly distributed, so replications with different seeds are
+    /// independent unbiased estimates.
+    #[must_use]
+    pub fn with_digital_shift(mut self, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        self.shift = self.shift.iter().map(|_| rng.gen()).collect();
+        self
+    }
+
+    /// Randomize the sequence by a random linear matrix scrambling
+    /// (Matoušek, 1998) followed by a digital shift, both drawn from the
+    /// given seed. Each digit of a coordinate is replaced by itself plus a
+    /// random combination of the more significant digits, which keeps the
+    /// stratification of the points while removing the lattice structure
+    /// that a shift alone preserves.
+    #[must_use]
+    pub fn with_scrambling(mut self, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        for v in &mut self.directions {
+            // Row `d` of a lower unit triangular matrix acting on the digits,
+            // from the most significant one.
+            let rows: [u32; SOBOL_BITS] = std::array::from_fn(|d| {
+                let above = rng.gen::<u32>() & !(u32::MAX >> d);
+                above | 1 << (SOBOL_BITS - 1 - d)
+            });
+
+            for x in v.iter_mut() {
+                *x = rows.iter().enumerate().fold(0, |acc, (d, row)| {
+                    acc | ((row & *x).count_ones() & 1) << (SOBOL_BITS - 1 - d)
+                });
+            }
+        }
+
+        self.state = vec![0; self.directions.len()];
+        self.index = 0;
+        self.shift = self.shift.iter().map(|_| rng.gen()).collect();
+        self
+    }
+
+    /// Number of dimensions of the sequence.
+    #[must_use]
+    pub fn dimension(&self) -> usize {
+        self.directions.len()
+    }
+
+    /// Returns the next point of the sequence. Coordinates are placed at the
+    /// centre of their $2^{-32}$ cell, so they lie strictly inside $(0, 1)$.
+    pub fn next_point(&mut self) -> Vec<f64> {
+        let point = self
+            .state
+            .iter()
+            .zip(&self.shift)
+            .map(|(x, shift)| ((x ^ shift) as f64 + 0.5) / 2_f64.powi(SOBOL_BITS as i32))
+            .collect();
+
+        // Gray code update: flip the direction number of the lowest zero bit.
+        let bit = (!self.index).trailing_zeros() as usize;
+        for (x, v) in self.state.iter_mut().zip(&self.directions) {
+            *x ^= v[bit.min(SOBOL_BITS - 1)];
+        }
+        self.index += 1;
+
+        point
+    }
+}
+
+impl HaltonSequence {
+    /// Maximum number of dimensions supported.
+    pub const MAX_DIMENSION: usize = HALTON_BASES.len();
+
+    /// Create a new (unrandomized) Halton sequence in `dimension` dimensions.
+    /// The sequence starts at index one, skipping the origin.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the dimension is zero or
+    ///   above [`HaltonSequence::MAX_DIMENSION`].
+    pub fn new(dimension: usize) -> Result<Self, RustQuantError> {
+        if dimension == 0 || dimension > Self::MAX_DIMENSION {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "Halton sequences support 1 to {} dimensions, got {dimension}",
+                Self::MAX_DIMENSION
+            )));
+        }
+
+        Ok(Self {
+            dimension,
+            shift: vec![0.0; dimension],
+            index: 1,
+        })
+    }
+
+    /// Randomize the sequence by a Cranley-Patterson rotation: a random
+    /// vector drawn from the given seed, added modulo one to every point.
+    #[must_use]
+    pub fn with_random_shift(mut self, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        self.shift = self.shift.iter().map(|_| rng.gen()).collect();
+        self
+    }
+
+    /// Number of dimensions of the sequence.
+    #[must_use]
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Returns the next point of the sequence.
+    pub fn next_point(&mut self) -> Vec<f64> {
+        let point = HALTON_BASES[..self.dimension]
+            .iter()
+            .zip(&self.shift)
+            .map(|(base, shift)| (radical_inverse(self.index, *base) + shift).fract())
+            .collect();
+
+        self.index += 1;
+
+        point
+    }
+}
+
+/// The first `count` primitive polynomials over GF(2), of degree one and up,
+/// as `(degree, coefficients)`: the polynomial of degree $s$
+/// $x^s + c_1 x^{s-1} + \dots + c_{s-1} x + 1$ has coefficients
+/// $c_1 \dots c_{s-1}$ as the bits of `coefficients`, most significant first.
+fn primitive_polynomials(count: usize) -> Vec<(u32, u32)> {
+    let mut polynomials = Vec::with_capacity(count);
+    let mut degree = 1;
+
+    while polynomials.len() < count {
+        for coefficients in 0..1 << (degree - 1) {
+            if polynomials.len() < count && is_primitive(degree, coefficients) {
+                polynomials.push((degree, coefficients));
+            }
+        }
+        degree += 1;
+    }
+
+    polynomials
+}
+
+/// Whether $x$ has the maximal order $2^s - 1$ modulo the polynomial, which
+/// makes the polynomial primitive.
+fn is_primitive(degree: u32, coefficients: u32) -> bool {
+    let modulus = 1 << degree | u64::from(coefficients) << 1 | 1;
+    let order = (1_u64 << degree) - 1;
+
+    // x^n modulo the polynomial, by square and multiply.
+    let power = |mut n: u64| {
+        let (mut result, mut base) = (1, reduce(2, modulus, degree));
+        while n > 0 {
+            if n & 1 == 1 {
+                result = multiply(result, base, modulus, degree);
+            }
+            base = multiply(base, base, modulus, degree);
+            n >>= 1;
+        }
+        result
+    };
+
+    // The distinct prime factors of the order, by trial division.
+    let mut factors = Vec::new();
+    let (mut n, mut p) = (order, 2);
+    while p * p <= n {
+        if n % p == 0 {
+            factors.push(p);
+            while n % p == 0 {
+                n /= p;
+            }
+        }
+        p += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+
+    power(order) == 1 && factors.iter().all(|q| power(order / q) != 1)
+}
+
+/// Product of two polynomials over GF(2) modulo a polynomial of the given
+/// degree.
+fn multiply(mut a: u64, b: u64, modulus: u64, degree: u32) -> u64 {
+    let mut product = 0;
+
+    for k in 0..degree {
+        if (b >> k) & 1 == 1 {
+            product ^= a;
+        }
+        a = reduce(a << 1, modulus, degree);
+    }
+
+    product
+}
+
+/// Remainder of a polynomial over GF(2) of degree at most `degree` modulo a
+/// polynomial of that degree.
+fn reduce(a: u64, modulus: u64, degree: u32) -> u64 {
+    if (a >> degree) & 1 == 1 {
+        a ^ modulus
+    } else {
+        a
+    }
+}
+
+/// `SplitMix64` hash, a fixed source of initial direction numbers.
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Radical inverse of `n` in the given base: the digits of `n` mirrored
+/// about the radix point.
+fn radical_inverse(mut n: u64, base: u64) -> f64 {
+    let inverse_base = 1.0 / base as f64;
+    let (mut value, mut scale) = (0.0, inverse_base);
+
+    while n > 0 {
+        value += (n % base) as f64 * scale;
+        n /= base;
+        scale *= inverse_base;
+    }
+
+    value
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_low_discrepancy {
+    use super::*;
+
+    #[test]
+    fn test_sobol_first_points() {
+        let mut sobol = SobolSequence::new(2).unwrap();
+        let expected = [
+            [0.0, 0.0],
+            [0.5, 0.5],
+            [0.75, 0.25],
+            [0.25, 0.75],
+            [0.375, 0.375],
+            [0.875, 0.875],
+            [0.625, 0.125],
+            [0.125, 0.625],
+        ];
+
+        for point in expected {
+            for (x, y) in sobol.next_point().iter().zip(point) {
+                assert!((x - y).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sobol_points_are_stratified() {
+        // The first 2^m points of every dimension hit each interval
+        // [k / 2^m, (k + 1) / 2^m) exactly once, with or without a shift or
+        // a scrambling.
+        for randomization in [0, 1, 2] {
+            let sobol = SobolSequence::new(SobolSequence::MAX_DIMENSION).unwrap();
+            let mut sobol = match randomization {
+                1 => sobol.with_digital_shift(7),
+                2 => sobol.with_scrambling(7),
+                _ => sobol,
+            };
+
+            let n = 256;
+            let points: Vec<Vec<f64>> = (0..n).map(|_| sobol.next_point()).collect();
+
+            for j in 0..sobol.dimension() {
+                let mut counts = vec![0; n];
+                for point in &points {
+                    counts[(point[j] * n as f64) as usize] += 1;
+                }
+
+                assert!(counts.iter().all(|count| *count == 1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sobol_primitive_polynomials() {
+        // The enumeration agrees with the Joe-Kuo table, and there are
+        // 2, 6 and 18 primitive polynomials of degree 4, 5 and 7.
+        let polynomials = primitive_polynomials(60);
+
+        for (polynomial, (degree, coefficients, _)) in polynomials.iter().zip(SOBOL_DIRECTIONS) {
+            assert_eq!(*polynomial, (degree, coefficients));
+        }
+        for (degree, count) in [(4, 2), (5, 6), (7, 18)] {
+            assert_eq!(polynomials.iter().filter(|p| p.0 == degree).count(), count);
+        }
+    }
+
+    #[test]
+    fn test_halton_first_points() {
+        let mut halton = HaltonSequence::new(2).unwrap();
+        let expected = [[0.5, 1.0 / 3.0], [0.25, 2.0 / 3.0], [0.75, 1.0 / 9.0]];
+
+        for point in expected {
+            for (x, y) in halton.next_point().iter().zip(point) {
+                assert!((x - y).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_dimensions() {
+        assert!(SobolSequence::new(0).is_err());
+        assert!(SobolSequence::new(SobolSequence::MAX_DIMENSION + 1).is_err());
+        assert!(HaltonSequence::new(0).is_err());
+        assert!(HaltonSequence::new(HaltonSequence::MAX_DIMENSION + 1).is_err());
+    }
+}
@@ -62,6 +62,10 @@
 //!
 //! - [x] Risk-Reward Measures (Sharpe, Treynor, Sortino, etc)
 
+/// Copulas (Gaussian, Student's t and Archimedean) for dependence modelling.
+pub mod copulas;
+pub use copulas::*;
+
 /// Statistical distributions.
 pub mod distributions;
 pub use distributions::*;
@@ -75,14 +79,34 @@ pub use integration::*;
 pub mod optimization;
 pub use optimization::*;
 
+/// Market-model (CAPM) and factor regressions.
+pub mod factor_models;
+pub use factor_models::*;
+
 /// Fast fourier transform.
 pub mod fft;
 pub use fft::*;
 
+/// Goodness-of-fit tests (Kolmogorov-Smirnov).
+pub mod goodness_of_fit;
+pub use goodness_of_fit::*;
+
+/// Histograms and empirical CDFs.
+pub mod histogram;
+pub use histogram::*;
+
 /// Interpolation routines.
 pub mod interpolation;
 pub use interpolation::*;
 
+/// Low-discrepancy (quasi-random) sequences.
+pub mod low_discrepancy;
+pub use low_discrepancy::*;
+
+/// Bootstrap and jackknife resampling.
+pub mod resampling;
+pub use resampling::*;
+
 /// Simple risk/reward measures.
 pub mod risk_reward;
 pub use risk_reward::*;
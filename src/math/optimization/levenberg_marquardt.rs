@@ -0,0 +1,385 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Nonlinear least squares with the Levenberg-Marquardt method.
+//!
+//! Given residuals $r: \mathbb{R}^n \rightarrow \mathbb{R}^m$, we minimise
+//!
+//! $$
+//! F(x) = \frac{1}{2} \sum_{i=1}^m r_i(x)^2
+//! $$
+//!
+//! by solving at each iteration the damped normal equations
+//!
+//! $$
+//! \left( J^\top J + \mu I \right) \delta = -J^\top r
+//! $$
+//!
+//! where $J$ is the Jacobian of the residuals. A small damping $\mu$ gives a
+//! Gauss-Newton step and a large one a short gradient descent step. The
+//! damping is updated from the ratio of the actual to the predicted decrease
+//! of $F$ (Madsen, Nielsen and Tingleff, 2004).
+//!
+//! The Jacobian is either estimated by central differences
+//! ([`LevenbergMarquardt::minimize`]), given
+//! ([`LevenbergMarquardt::minimize_with_jacobian`]), or computed with
+//! reverse mode automatic differentiation on RustQuant's graph
+//! ([`LevenbergMarquardt::minimize_autodiff`]).
+
+use crate::autodiff::{variables::variable::Variable, Accumulate, Gradient, Graph};
+use crate::error::RustQuantError;
+use nalgebra::{DMatrix, DVector};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Levenberg-Marquardt least squares optimizer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevenbergMarquardt {
+    /// Tolerance on the gradient $J^\top r$ and on the relative step.
+    pub tolerance: f64,
+
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+
+    /// Initial damping, relative to the largest diagonal element of
+    /// $J^\top J$.
+    pub initial_damping: f64,
+}
+
+/// Result of the Levenberg-Marquardt optimization.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevenbergMarquardtResult {
+    /// Parameters minimising the sum of squared residuals.
+    pub parameters: Vec<f64>,
+
+    /// Residuals at the parameters.
+    pub residuals: Vec<f64>,
+
+    /// Half the sum of the squared residuals.
+    pub cost: f64,
+
+    /// Number of iterations.
+    pub iterations: usize,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Default for LevenbergMarquardt {
+    fn default() -> Self {
+        Self::new(1e-10, 500)
+    }
+}
+
+impl LevenbergMarquardt {
+    /// New Levenberg-Marquardt optimizer, with an initial damping of 1e-3.
+    #[must_use]
+    pub fn new(tolerance: f64, max_iterations: usize) -> Self {
+        Self {
+            tolerance,
+            max_iterations,
+            initial_damping: 1e-3,
+        }
+    }
+
+    /// Set the initial damping.
+    #[must_use]
+    pub fn with_initial_damping(mut self, initial_damping: f64) -> Self {
+        self.initial_damping = initial_damping;
+        self
+    }
+
+    /// Minimise the sum of squares of `residuals` starting from `x0`, with a
+    /// central difference Jacobian.
+    ///
+    /// # Errors
+    /// As for [`LevenbergMarquardt::minimize_with_jacobian`].
+    pub fn minimize<R>(
+        &self,
+        residuals: R,
+        x0: &[f64],
+    ) -> Result<LevenbergMarquardtResult, RustQuantError>
+    where
+        R: Fn(&[f64]) -> Vec<f64>,
+    {
+        self.minimize_with_jacobian(
+            &residuals,
+            |x| finite_difference_jacobian(&residuals, x),
+            x0,
+        )
+    }
+
+    /// Minimise the sum of squares of `residuals` starting from `x0`, where
+    /// `jacobian` returns the $m \times n$ Jacobian of the residuals.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if `x0` is empty, or the
+    ///   Jacobian does not match the residuals.
+    /// - `RustQuantError::ComputationError` if the residuals are not finite
+    ///   at `x0`.
+    /// - `RustQuantError::NotConverged` if the optimizer does not converge
+    ///   within its maximum number of iterations.
+    pub fn minimize_with_jacobian<R, J>(
+        &self,
+        residuals: R,
+        jacobian: J,
+        x0: &[f64],
+    ) -> Result<LevenbergMarquardtResult, RustQuantError>
+    where
+        R: Fn(&[f64]) -> Vec<f64>,
+        J: Fn(&[f64]) -> DMatrix<f64>,
+    {
+        if x0.is_empty() {
+            return Err(RustQuantError::InvalidArgument(
+                "Levenberg-Marquardt needs at least one parameter".to_string(),
+            ));
+        }
+
+        let n = x0.len();
+        let mut x = DVector::from_column_slice(x0);
+        let mut r = DVector::from_vec(residuals(x.as_slice()));
+        if r.iter().any(|r| !r.is_finite()) {
+            return Err(RustQuantError::ComputationError(
+                "the residuals are not finite at the starting point".to_string(),
+            ));
+        }
+
+        let mut cost = 0.5 * r.norm_squared();
+        let mut mu = f64::NAN;
+        let mut nu = 2.0;
+
+        for iterations in 0..self.max_iterations {
+            let j = jacobian(x.as_slice());
+            if j.shape() != (r.len(), n) {
+                return Err(RustQuantError::InvalidArgument(format!(
+                    "the Jacobian is {:?}, but should be {:?}",
+                    j.shape(),
+                    (r.len(), n)
+                )));
+            }
+
+            let jtj = j.tr_mul(&j);
+            let gradient = j.tr_mul(&r);
+
+            if gradient.amax() <= self.tolerance {
+                return Ok(result(x, r, cost, iterations));
+            }
+            if mu.is_nan() {
+                mu = self.initial_damping * jtj.diagonal().max().max(f64::MIN_POSITIVE);
+            }
+
+            // Increase the damping until the step decreases the cost.
+            loop {
+                let damped = &jtj + DMatrix::identity(n, n) * mu;
+                let Some(delta) = damped.cholesky().map(|c| c.solve(&(-&gradient))) else {
+                    mu *= nu;
+                    nu *= 2.0;
+                    continue;
+                };
+
+                if delta.norm() <= self.tolerance * (x.norm() + self.tolerance) {
+                    return Ok(result(x, r, cost, iterations + 1));
+                }
+
+                let x_new = &x + &delta;
+                let r_new = DVector::from_vec(residuals(x_new.as_slice()));
+                let cost_new = 0.5 * r_new.norm_squared();
+
+                // Decrease predicted by the linear model of the residuals.
+                let predicted = 0.5 * delta.dot(&(&delta * mu - &gradient));
+                let ratio = (cost - cost_new) / predicted;
+
+                if ratio > 0.0 && cost_new.is_finite() {
+                    (x, r, cost) = (x_new, r_new, cost_new);
+                    mu *= f64::max(1.0 / 3.0, 1.0 - (2.0 * ratio - 1.0).powi(3));
+                    nu = 2.0;
+                    break;
+                }
+
+                mu *= nu;
+                nu *= 2.0;
+
+                if !mu.is_finite() {
+                    return Err(RustQuantError::NotConverged(
+                        "Levenberg-Marquardt could not decrease the residuals".to_string(),
+                    ));
+                }
+            }
+        }
+
+        Err(RustQuantError::NotConverged(format!(
+            "Levenberg-Marquardt did not converge in {} iterations",
+            self.max_iterations
+        )))
+    }
+
+    /// Minimise the sum of squares of `residuals` starting from `x0`, with
+    /// the Jacobian computed by reverse mode automatic differentiation (one
+    /// sweep of the graph per residual).
+    ///
+    /// # Errors
+    /// As for [`LevenbergMarquardt::minimize_with_jacobian`].
+    pub fn minimize_autodiff<R>(
+        &self,
+        residuals: R,
+        x0: &[f64],
+    ) -> Result<LevenbergMarquardtResult, RustQuantError>
+    where
+        R: for<'v> Fn(&[Variable<'v>]) -> Vec<Variable<'v>>,
+    {
+        let values = |x: &[f64]| {
+            let graph = Graph::new();
+            let location = graph.vars(x);
+
+            residuals(&location)
+                .iter()
+                .map(|residual| residual.value)
+                .collect::<Vec<f64>>()
+        };
+
+        self.minimize_with_jacobian(values, |x| autodiff_jacobian(&residuals, x), x0)
+    }
+}
+
+/// Result at the parameters `x`, with residuals `r`.
+fn result(
+    x: DVector<f64>,
+    r: DVector<f64>,
+    cost: f64,
+    iterations: usize,
+) -> LevenbergMarquardtResult {
+    LevenbergMarquardtResult {
+        parameters: x.data.into(),
+        residuals: r.data.into(),
+        cost,
+        iterations,
+    }
+}
+
+/// Jacobian of `residuals` at `x` by central differences.
+fn finite_difference_jacobian<R>(residuals: &R, x: &[f64]) -> DMatrix<f64>
+where
+    R: Fn(&[f64]) -> Vec<f64>,
+{
+    let columns: Vec<DVector<f64>> = (0..x.len())
+        .map(|k| {
+            let h = f64::EPSILON.cbrt() * x[k].abs().max(1.0);
+            let mut up = x.to_vec();
+            let mut down = x.to_vec();
+            up[k] += h;
+            down[k] -= h;
+
+            (DVector::from_vec(residuals(&up)) - DVector::from_vec(residuals(&down))) / (2.0 * h)
+        })
+        .collect();
+
+    DMatrix::from_columns(&columns)
+}
+
+/// Jacobian of `residuals` at `x` by reverse mode automatic differentiation.
+fn autodiff_jacobian<R>(residuals: &R, x: &[f64]) -> DMatrix<f64>
+where
+    R: for<'v> Fn(&[Variable<'v>]) -> Vec<Variable<'v>>,
+{
+    let graph = Graph::new();
+    let location = graph.vars(x);
+    let outputs = residuals(&location);
+
+    let rows: Vec<Vec<f64>> = outputs
+        .iter()
+        .map(|residual| residual.accumulate().wrt(&location))
+        .collect();
+
+    DMatrix::from_fn(rows.len(), x.len(), |i, k| rows[i][k])
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_levenberg_marquardt {
+    use super::*;
+
+    // Rosenbrock's function as the residuals (10 (y - x^2), 1 - x).
+    fn rosenbrock(x: &[f64]) -> Vec<f64> {
+        vec![10.0 * (x[1] - x[0] * x[0]), 1.0 - x[0]]
+    }
+
+    #[test]
+    fn test_levenberg_marquardt_rosenbrock() {
+        let result = LevenbergMarquardt::default()
+            .minimize(rosenbrock, &[-1.2, 1.0])
+            .unwrap();
+
+        assert_approx_equal!(result.parameters[0], 1.0, 1e-8);
+        assert_approx_equal!(result.parameters[1], 1.0, 1e-8);
+        assert!(result.cost < 1e-16);
+
+        assert!(LevenbergMarquardt::default()
+            .minimize(rosenbrock, &[])
+            .is_err());
+        assert!(matches!(
+            LevenbergMarquardt::new(1e-14, 2).minimize(rosenbrock, &[-1.2, 1.0]),
+            Err(RustQuantError::NotConverged(_))
+        ));
+    }
+
+    #[test]
+    fn test_levenberg_marquardt_curve_fit() {
+        // Fit y = a exp(-b t) + c to noisy-free data.
+        let times: Vec<f64> = (0..20).map(|i| 0.25 * f64::from(i)).collect();
+        let data: Vec<f64> = times
+            .iter()
+            .map(|t| 2.5 * f64::exp(-1.3 * t) + 0.5)
+            .collect();
+
+        let residuals = |p: &[f64]| -> Vec<f64> {
+            times
+                .iter()
+                .zip(&data)
+                .map(|(t, y)| p[0] * f64::exp(-p[1] * t) + p[2] - y)
+                .collect()
+        };
+
+        let result = LevenbergMarquardt::default()
+            .minimize(residuals, &[1.0, 0.5, 0.0])
+            .unwrap();
+
+        assert_approx_equal!(result.parameters[0], 2.5, 1e-8);
+        assert_approx_equal!(result.parameters[1], 1.3, 1e-8);
+        assert_approx_equal!(result.parameters[2], 0.5, 1e-8);
+    }
+
+    #[test]
+    fn test_levenberg_marquardt_autodiff() {
+        fn residuals<'v>(x: &[Variable<'v>]) -> Vec<Variable<'v>> {
+            vec![(x[1] - x[0] * x[0]) * 10.0, (x[0] * -1.0) + 1.0]
+        }
+
+        // The autodiff Jacobian agrees with central differences.
+        let x = [0.3, -0.7];
+        let exact = autodiff_jacobian(&residuals, &x);
+        let approximate = finite_difference_jacobian(&rosenbrock, &x);
+        for (a, b) in exact.iter().zip(approximate.iter()) {
+            assert_approx_equal!(*a, *b, 1e-8);
+        }
+
+        let result = LevenbergMarquardt::default()
+            .minimize_autodiff(residuals, &[-1.2, 1.0])
+            .unwrap();
+
+        assert_approx_equal!(result.parameters[0], 1.0, 1e-8);
+        assert_approx_equal!(result.parameters[1], 1.0, 1e-8);
+    }
+}
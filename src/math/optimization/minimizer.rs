@@ -0,0 +1,323 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! One-dimensional minimisation over an interval, behind a common
+//! [`Minimizer`] trait.
+//!
+//! - [`GoldenSectionMinimizer`]: shrinks the interval by the golden ratio at
+//!   each step, keeping a minimum of a unimodal function inside it.
+//! - [`BrentMinimizer`]: parabolic interpolation through the three best
+//!   points, falling back to golden section steps (Brent, 1973). Converges
+//!   superlinearly for smooth functions.
+//!
+//! The location of a minimum can only be found to about the square root of
+//! the machine precision, so the tolerances are relative to $\sqrt{\epsilon}$.
+
+use crate::error::RustQuantError;
+
+/// Fraction of the interval of a golden section step, $(3 - \sqrt{5}) / 2$.
+const GOLDEN_SECTION: f64 = 0.381_966_011_250_105_1;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// One-dimensional minimiser.
+pub trait Minimizer {
+    /// Minimum of `f` in `[lower, upper]`, which is local unless `f` is
+    /// unimodal on the interval.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the interval is not finite and
+    ///   increasing.
+    /// - `RustQuantError::NotConverged` if the minimiser does not converge
+    ///   within its maximum number of iterations.
+    fn minimize<F: Fn(f64) -> f64>(
+        &self,
+        f: F,
+        lower: f64,
+        upper: f64,
+    ) -> Result<Minimum, RustQuantError>;
+}
+
+/// Minimum found by a [`Minimizer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Minimum {
+    /// Location of the minimum.
+    pub x: f64,
+
+    /// Value of the function at the minimum.
+    pub value: f64,
+
+    /// Number of iterations taken.
+    pub iterations: usize,
+}
+
+/// Golden section search.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GoldenSectionMinimizer {
+    /// Relative tolerance on the location of the minimum.
+    pub tolerance: f64,
+
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+}
+
+/// Brent's minimiser.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BrentMinimizer {
+    /// Relative tolerance on the location of the minimum.
+    pub tolerance: f64,
+
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Default for GoldenSectionMinimizer {
+    fn default() -> Self {
+        Self::new(f64::EPSILON.sqrt(), 200)
+    }
+}
+
+impl Default for BrentMinimizer {
+    fn default() -> Self {
+        Self::new(f64::EPSILON.sqrt(), 200)
+    }
+}
+
+impl GoldenSectionMinimizer {
+    /// New golden section search.
+    #[must_use]
+    pub fn new(tolerance: f64, max_iterations: usize) -> Self {
+        Self {
+            tolerance,
+            max_iterations,
+        }
+    }
+}
+
+impl BrentMinimizer {
+    /// New Brent's minimiser.
+    #[must_use]
+    pub fn new(tolerance: f64, max_iterations: usize) -> Self {
+        Self {
+            tolerance,
+            max_iterations,
+        }
+    }
+}
+
+impl Minimizer for GoldenSectionMinimizer {
+    fn minimize<F: Fn(f64) -> f64>(
+        &self,
+        f: F,
+        lower: f64,
+        upper: f64,
+    ) -> Result<Minimum, RustQuantError> {
+        check_interval(lower, upper)?;
+
+        let (mut a, mut b) = (lower, upper);
+        let mut x1 = a + GOLDEN_SECTION * (b - a);
+        let mut x2 = b - GOLDEN_SECTION * (b - a);
+        let (mut f1, mut f2) = (f(x1), f(x2));
+
+        for iterations in 0..self.max_iterations {
+            if b - a <= self.tolerance * (x1.abs() + x2.abs()) + f64::EPSILON {
+                let (x, value) = if f1 < f2 { (x1, f1) } else { (x2, f2) };
+
+                return Ok(Minimum {
+                    x,
+                    value,
+                    iterations,
+                });
+            }
+
+            if f1 < f2 {
+                b = x2;
+                (x2, f2) = (x1, f1);
+                x1 = a + GOLDEN_SECTION * (b - a);
+                f1 = f(x1);
+            } else {
+                a = x1;
+                (x1, f1) = (x2, f2);
+                x2 = b - GOLDEN_SECTION * (b - a);
+                f2 = f(x2);
+            }
+        }
+
+        Err(not_converged("golden section search", self.max_iterations))
+    }
+}
+
+impl Minimizer for BrentMinimizer {
+    fn minimize<F: Fn(f64) -> f64>(
+        &self,
+        f: F,
+        lower: f64,
+        upper: f64,
+    ) -> Result<Minimum, RustQuantError> {
+        check_interval(lower, upper)?;
+
+        // `x` is the best point, `w` the second best, `v` the previous `w`.
+        let (mut a, mut b) = (lower, upper);
+        let mut x = a + GOLDEN_SECTION * (b - a);
+        let (mut w, mut v) = (x, x);
+        let mut fx = f(x);
+        let (mut fw, mut fv) = (fx, fx);
+        let (mut d, mut e): (f64, f64) = (0.0, 0.0);
+
+        for iterations in 0..self.max_iterations {
+            let mid = 0.5 * (a + b);
+            let tol1 = self.tolerance * x.abs() + f64::EPSILON;
+            let tol2 = 2.0 * tol1;
+
+            if (x - mid).abs() <= tol2 - 0.5 * (b - a) {
+                return Ok(Minimum {
+                    x,
+                    value: fx,
+                    iterations,
+                });
+            }
+
+            let mut golden = true;
+
+            if e.abs() > tol1 {
+                // Parabola through x, w and v.
+                let r = (x - w) * (fx - fv);
+                let mut q = (x - v) * (fx - fw);
+                let mut p = (x - v) * q - (x - w) * r;
+                q = 2.0 * (q - r);
+                if q > 0.0 {
+                    p = -p;
+                }
+                q = q.abs();
+
+                // Take the parabolic step if it is inside the interval and
+                // shorter than half the step before last.
+                if p.abs() < (0.5 * q * e).abs() && p > q * (a - x) && p < q * (b - x) {
+                    e = d;
+                    d = p / q;
+                    golden = false;
+
+                    let u = x + d;
+                    if u - a < tol2 || b - u < tol2 {
+                        d = tol1.copysign(mid - x);
+                    }
+                }
+            }
+
+            if golden {
+                e = if x >= mid { a - x } else { b - x };
+                d = GOLDEN_SECTION * e;
+            }
+
+            let u = if d.abs() >= tol1 {
+                x + d
+            } else {
+                x + tol1.copysign(d)
+            };
+            let fu = f(u);
+
+            if fu <= fx {
+                if u >= x {
+                    a = x;
+                } else {
+                    b = x;
+                }
+                (v, fv) = (w, fw);
+                (w, fw) = (x, fx);
+                (x, fx) = (u, fu);
+            } else {
+                if u < x {
+                    a = u;
+                } else {
+                    b = u;
+                }
+                if fu <= fw || w == x {
+                    (v, fv) = (w, fw);
+                    (w, fw) = (u, fu);
+                } else if fu <= fv || v == x || v == w {
+                    (v, fv) = (u, fu);
+                }
+            }
+        }
+
+        Err(not_converged("Brent's minimiser", self.max_iterations))
+    }
+}
+
+/// Check that `[lower, upper]` is a finite, increasing interval.
+fn check_interval(lower: f64, upper: f64) -> Result<(), RustQuantError> {
+    if lower.is_finite() && upper.is_finite() && lower < upper {
+        Ok(())
+    } else {
+        Err(RustQuantError::InvalidArgument(format!(
+            "[{lower}, {upper}] is not a finite interval"
+        )))
+    }
+}
+
+/// Error for a minimiser that ran out of iterations.
+fn not_converged(name: &str, max_iterations: usize) -> RustQuantError {
+    RustQuantError::NotConverged(format!(
+        "{name} did not converge in {max_iterations} iterations"
+    ))
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_minimizer {
+    use super::*;
+
+    #[test]
+    fn test_minimizers() {
+        // Minimum at x = 9/4, with a saddle at 0.
+        let f = |x: f64| x.powi(4) - 3.0 * x.powi(3) + 2.0;
+
+        let golden = GoldenSectionMinimizer::default()
+            .minimize(f, 0.5, 5.0)
+            .unwrap();
+        let brent = BrentMinimizer::default().minimize(f, 0.5, 5.0).unwrap();
+
+        for minimum in [golden, brent] {
+            assert_approx_equal!(minimum.x, 2.25, 1e-7);
+            assert_approx_equal!(minimum.value, f(2.25), 1e-12);
+        }
+
+        // Parabolic steps converge much faster on a smooth function.
+        assert!(brent.iterations < golden.iterations);
+
+        // A minimum at the end of the interval.
+        let edge = BrentMinimizer::default()
+            .minimize(|x| x.exp(), -1.0, 1.0)
+            .unwrap();
+        assert_approx_equal!(edge.x, -1.0, 1e-7);
+    }
+
+    #[test]
+    fn test_minimizer_errors() {
+        assert!(BrentMinimizer::default()
+            .minimize(|x| x * x, 1.0, -1.0)
+            .is_err());
+        assert!(GoldenSectionMinimizer::default()
+            .minimize(|x| x * x, f64::NEG_INFINITY, 1.0)
+            .is_err());
+        assert!(matches!(
+            GoldenSectionMinimizer::new(1e-12, 5).minimize(|x| x * x, -1.0, 2.0),
+            Err(RustQuantError::NotConverged(_))
+        ));
+    }
+}
@@ -10,3 +10,15 @@
 /// Gradient descent method.
 pub mod gradient_descent;
 pub use gradient_descent::*;
+
+/// Levenberg-Marquardt nonlinear least squares.
+pub mod levenberg_marquardt;
+pub use levenberg_marquardt::*;
+
+/// Golden section and Brent's one-dimensional minimisers.
+pub mod minimizer;
+pub use minimizer::*;
+
+/// Nelder-Mead simplex method.
+pub mod nelder_mead;
+pub use nelder_mead::*;
@@ -0,0 +1,294 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Derivative-free minimisation with the Nelder-Mead simplex method, used by
+//! the smile calibrations (e.g. SVI and SABR).
+//!
+//! [`NelderMead`] minimises a function of any number of variables given as a
+//! slice, and [`nelder_mead`] is the same search for a fixed number of
+//! variables given as an array.
+
+use crate::error::RustQuantError;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Nelder-Mead simplex minimiser.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NelderMead {
+    /// Tolerance on the spread of the values at the vertices of the simplex.
+    pub tolerance: f64,
+
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+
+    /// Step of each coordinate from the starting point to the other
+    /// vertices of the initial simplex.
+    pub step: f64,
+}
+
+/// Result of the Nelder-Mead minimisation.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct NelderMeadResult {
+    /// Minimizer of the function.
+    pub minimizer: Vec<f64>,
+
+    /// Value of the function at the minimum.
+    pub minimum: f64,
+
+    /// Number of iterations.
+    pub iterations: usize,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Default for NelderMead {
+    fn default() -> Self {
+        Self::new(1e-12, 5000)
+    }
+}
+
+impl NelderMead {
+    /// New Nelder-Mead minimiser, with an initial step of 0.1.
+    #[must_use]
+    pub fn new(tolerance: f64, max_iterations: usize) -> Self {
+        Self {
+            tolerance,
+            max_iterations,
+            step: 0.1,
+        }
+    }
+
+    /// Set the step of the initial simplex.
+    #[must_use]
+    pub fn with_step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Minimise `f` starting from `x0`.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if `x0` is empty or the step is
+    ///   zero.
+    /// - `RustQuantError::NotConverged` if the values at the vertices are
+    ///   not within the tolerance after the maximum number of iterations.
+    pub fn minimize<F>(&self, f: F, x0: &[f64]) -> Result<NelderMeadResult, RustQuantError>
+    where
+        F: Fn(&[f64]) -> f64,
+    {
+        if x0.is_empty() || self.step == 0.0 || !self.step.is_finite() {
+            return Err(RustQuantError::InvalidArgument(
+                "Nelder-Mead needs a starting point and a non-zero step".to_string(),
+            ));
+        }
+
+        let steps = vec![self.step; x0.len()];
+        let result = simplex_search(f, x0, &steps, self.tolerance, self.max_iterations);
+
+        if result.iterations >= self.max_iterations {
+            return Err(RustQuantError::NotConverged(format!(
+                "Nelder-Mead did not converge in {} iterations",
+                self.max_iterations
+            )));
+        }
+
+        Ok(result)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Minimise a function of `N` variables with the Nelder-Mead simplex method.
+///
+/// The initial simplex is `start` and, for each coordinate, `start` moved by
+/// the step of that coordinate. The search stops once the values at the
+/// vertices are within `tolerance`, or after `max_iterations` iterations, and
+/// returns the best vertex.
+pub fn nelder_mead<F, const N: usize>(
+    f: F,
+    start: [f64; N],
+    steps: [f64; N],
+    tolerance: f64,
+    max_iterations: usize,
+) -> [f64; N]
+where
+    F: Fn([f64; N]) -> f64,
+{
+    let result = simplex_search(
+        |x| f(std::array::from_fn(|i| x[i])),
+        &start,
+        &steps,
+        tolerance,
+        max_iterations,
+    );
+
+    std::array::from_fn(|i| result.minimizer[i])
+}
+
+/// The simplex search itself. The number of iterations of the result equals
+/// `max_iterations` only if the search did not converge.
+fn simplex_search<F>(
+    f: F,
+    start: &[f64],
+    steps: &[f64],
+    tolerance: f64,
+    max_iterations: usize,
+) -> NelderMeadResult
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let n = start.len();
+
+    let mut simplex: Vec<Vec<f64>> = std::iter::once(start.to_vec())
+        .chain((0..n).map(|i| {
+            let mut vertex = start.to_vec();
+            vertex[i] += steps[i];
+            vertex
+        }))
+        .collect();
+    let mut values: Vec<f64> = simplex.iter().map(|vertex| f(vertex)).collect();
+    let mut iterations = max_iterations;
+
+    for k in 0..max_iterations {
+        // Order the vertices from best to worst.
+        let mut order: Vec<usize> = (0..=n).collect();
+        order.sort_by(|i, j| values[*i].total_cmp(&values[*j]));
+        simplex = order.iter().map(|i| simplex[*i].clone()).collect();
+        values = order.iter().map(|i| values[*i]).collect();
+
+        if (values[n] - values[0]).abs() < tolerance {
+            iterations = k;
+            break;
+        }
+
+        let mut centroid = vec![0.0; n];
+        for vertex in &simplex[..n] {
+            for (c, x) in centroid.iter_mut().zip(vertex) {
+                *c += x / n as f64;
+            }
+        }
+
+        let worst = &simplex[n];
+        let along = |t: f64| -> Vec<f64> {
+            centroid
+                .iter()
+                .zip(worst)
+                .map(|(c, w)| c + t * (w - c))
+                .collect()
+        };
+
+        let reflected = along(-1.0);
+        let f_reflected = f(&reflected);
+
+        if f_reflected < values[0] {
+            let expanded = along(-2.0);
+            let f_expanded = f(&expanded);
+
+            (simplex[n], values[n]) = if f_expanded < f_reflected {
+                (expanded, f_expanded)
+            } else {
+                (reflected, f_reflected)
+            };
+        } else if f_reflected < values[n - 1] {
+            (simplex[n], values[n]) = (reflected, f_reflected);
+        } else {
+            let contracted = along(0.5);
+            let f_contracted = f(&contracted);
+
+            if f_contracted < values[n] {
+                (simplex[n], values[n]) = (contracted, f_contracted);
+            } else {
+                // Shrink towards the best vertex.
+                let best = simplex[0].clone();
+
+                for i in 1..=n {
+                    simplex[i] = best
+                        .iter()
+                        .zip(&simplex[i])
+                        .map(|(b, x)| (b + x) / 2.0)
+                        .collect();
+                    values[i] = f(&simplex[i]);
+                }
+            }
+        }
+    }
+
+    let best = (0..=n)
+        .min_by(|i, j| values[*i].total_cmp(&values[*j]))
+        .unwrap_or(0);
+
+    NelderMeadResult {
+        minimizer: simplex.swap_remove(best),
+        minimum: values[best],
+        iterations,
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_nelder_mead {
+    use super::*;
+
+    #[test]
+    fn test_nelder_mead_rosenbrock() {
+        let rosenbrock = |x: [f64; 2]| (1.0 - x[0]).powi(2) + 100.0 * (x[1] - x[0] * x[0]).powi(2);
+
+        let minimum = nelder_mead(rosenbrock, [-1.2, 1.0], [0.1, 0.1], 1e-20, 5000);
+
+        assert_approx_equal!(minimum[0], 1.0, 1e-6);
+        assert_approx_equal!(minimum[1], 1.0, 1e-6);
+    }
+
+    #[test]
+    fn test_nelder_mead_quadratic() {
+        let quadratic = |x: [f64; 4]| {
+            x.iter()
+                .enumerate()
+                .map(|(i, x)| (x - i as f64).powi(2))
+                .sum::<f64>()
+        };
+
+        let minimum = nelder_mead(quadratic, [0.0; 4], [0.5; 4], 1e-24, 10000);
+
+        for (i, x) in minimum.iter().enumerate() {
+            assert_approx_equal!(*x, i as f64, 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_nelder_mead_struct() {
+        let himmelblau =
+            |x: &[f64]| (x[0] * x[0] + x[1] - 11.0).powi(2) + (x[0] + x[1] * x[1] - 7.0).powi(2);
+
+        let result = NelderMead::new(1e-20, 2000)
+            .with_step(0.5)
+            .minimize(himmelblau, &[0.0, 0.0])
+            .unwrap();
+
+        assert_approx_equal!(result.minimizer[0], 3.0, 1e-6);
+        assert_approx_equal!(result.minimizer[1], 2.0, 1e-6);
+        assert!(result.minimum < 1e-12);
+
+        assert!(NelderMead::default().minimize(himmelblau, &[]).is_err());
+        assert!(matches!(
+            NelderMead::new(1e-20, 10).minimize(himmelblau, &[0.0, 0.0]),
+            Err(RustQuantError::NotConverged(_))
+        ));
+    }
+}
@@ -0,0 +1,167 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Resampling methods (bootstrap and jackknife) for estimating the sampling
+//! distribution, bias, and variance of a statistic.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Jackknife estimates for a statistic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JackknifeEstimate {
+    /// The statistic computed on the full sample.
+    pub estimate: f64,
+
+    /// Jackknife estimate of the bias of the statistic.
+    pub bias: f64,
+
+    /// Jackknife estimate of the variance of the statistic.
+    pub variance: f64,
+
+    /// The leave-one-out replicates of the statistic.
+    pub replicates: Vec<f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Draw `n_resamples` bootstrap resamples of `data`, each the same length as
+/// `data` and drawn uniformly with replacement.
+///
+/// The same `seed` always gives the same resamples.
+#[must_use]
+pub fn bootstrap_resample<T: Clone>(data: &[T], n_resamples: usize, seed: u64) -> Vec<Vec<T>> {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..n_resamples)
+        .map(|_| {
+            (0..data.len())
+                .map(|_| data[rng.gen_range(0..data.len())].clone())
+                .collect()
+        })
+        .collect()
+}
+
+/// Bootstrap distribution of `statistic`: the statistic evaluated on each of
+/// `n_resamples` bootstrap resamples of `data`.
+#[must_use]
+pub fn bootstrap<F>(data: &[f64], statistic: F, n_resamples: usize, seed: u64) -> Vec<f64>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    bootstrap_resample(data, n_resamples, seed)
+        .iter()
+        .map(|resample| statistic(resample))
+        .collect()
+}
+
+/// Jackknife (leave-one-out) bias and variance estimates of `statistic`.
+///
+/// With leave-one-out replicates $\hat\theta_{(i)}$ and their mean
+/// $\bar\theta$:
+///
+/// $$
+/// \text{bias} = (n - 1) (\bar\theta - \hat\theta), \quad
+/// \text{variance} = \frac{n - 1}{n} \sum_{i=1}^n (\hat\theta_{(i)} - \bar\theta)^2
+/// $$
+///
+/// # Panics
+/// Panics if `data` has fewer than two elements.
+#[must_use]
+pub fn jackknife<F>(data: &[f64], statistic: F) -> JackknifeEstimate
+where
+    F: Fn(&[f64]) -> f64,
+{
+    assert!(data.len() > 1, "Data must have at least two elements.");
+
+    let n = data.len() as f64;
+    let estimate = statistic(data);
+
+    let mut subsample = Vec::with_capacity(data.len() - 1);
+    let replicates: Vec<f64> = (0..data.len())
+        .map(|i| {
+            subsample.clear();
+            subsample.extend_from_slice(&data[..i]);
+            subsample.extend_from_slice(&data[i + 1..]);
+
+            statistic(&subsample)
+        })
+        .collect();
+
+    let mean = replicates.iter().sum::<f64>() / n;
+
+    JackknifeEstimate {
+        estimate,
+        bias: (n - 1.0) * (mean - estimate),
+        variance: (n - 1.0) / n * replicates.iter().map(|r| (r - mean).powi(2)).sum::<f64>(),
+        replicates,
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_resampling {
+    use super::*;
+    use crate::math::Statistic;
+
+    fn mean(data: &[f64]) -> f64 {
+        data.iter().sum::<f64>() / data.len() as f64
+    }
+
+    #[test]
+    fn test_jackknife_variance_of_mean() {
+        let data = vec![2.1, 3.4, 1.9, 5.6, 4.2, 3.3, 2.8, 4.9];
+
+        let result = jackknife(&data, mean);
+
+        // For the mean, the jackknife variance is exactly s^2 / n.
+        let closed_form = data.sample_variance() / data.len() as f64;
+
+        assert_approx_equal!(result.variance, closed_form, 1e-12);
+        assert_approx_equal!(result.bias, 0.0, 1e-12);
+        assert_eq!(result.replicates.len(), data.len());
+    }
+
+    #[test]
+    fn test_jackknife_bias_of_population_variance() {
+        let data = vec![2.1, 3.4, 1.9, 5.6, 4.2, 3.3, 2.8, 4.9];
+
+        // The jackknife corrects the plug-in variance to the sample variance.
+        let result = jackknife(&data, |x| x.to_vec().population_variance());
+
+        assert_approx_equal!(result.estimate - result.bias, data.sample_variance(), 1e-12);
+    }
+
+    #[test]
+    fn test_bootstrap_resamples() {
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0];
+
+        let resamples = bootstrap_resample(&data, 100, 42);
+
+        assert_eq!(resamples.len(), 100);
+        assert!(resamples.iter().all(|r| r.len() == data.len()));
+        assert!(resamples.iter().flatten().all(|x| data.contains(x)));
+
+        // The same seed gives the same resamples.
+        assert_eq!(resamples, bootstrap_resample(&data, 100, 42));
+
+        let means = bootstrap(&data, mean, 2_000, 7);
+
+        assert_eq!(means.len(), 2_000);
+        assert!((mean(&means) - 3.0).abs() < 0.05);
+    }
+}
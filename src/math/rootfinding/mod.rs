@@ -18,3 +18,7 @@ pub mod newton_raphson;
 
 /// Root-finding trait and data.
 pub mod rootfinder;
+
+/// Bracketing root-finders behind a common trait.
+pub mod solver;
+pub use solver::*;
@@ -0,0 +1,521 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Bracketing root-finders behind a common [`Solver`] trait.
+//!
+//! Every solver takes an interval over which the function changes sign and
+//! keeps the root bracketed, so it converges for any continuous function:
+//!
+//! - [`BisectionSolver`]: halves the bracket, gaining one bit per step.
+//! - [`BrentSolver`]: inverse quadratic interpolation and secant steps,
+//!   falling back to bisection (Brent, 1973). The default choice.
+//! - [`RiddersSolver`]: exponential regula falsi, with quadratic convergence
+//!   and two evaluations per step (Ridders, 1979).
+//! - [`NewtonRaphsonSolver`]: Newton steps, replaced by bisection when they
+//!   leave the bracket. Uses the derivative when it is known
+//!   ([`NewtonRaphsonSolver::solve_with_derivative`]), and central finite
+//!   differences otherwise.
+
+use crate::error::RustQuantError;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// One-dimensional root-finder.
+pub trait Solver {
+    /// Root of `f` in `[lower, upper]`.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the interval is not finite and
+    ///   increasing, or `f` has the same sign at both ends of it.
+    /// - `RustQuantError::NotConverged` if the solver does not converge
+    ///   within its maximum number of iterations.
+    fn solve<F: Fn(f64) -> f64>(&self, f: F, lower: f64, upper: f64)
+        -> Result<f64, RustQuantError>;
+}
+
+/// Convergence criteria of a [`Solver`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverSettings {
+    /// Width of the bracket (or step) below which the root is found.
+    pub x_tolerance: f64,
+
+    /// Absolute value of the function below which the root is found.
+    pub f_tolerance: f64,
+
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+}
+
+/// Bisection root-finder.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BisectionSolver {
+    /// Convergence criteria.
+    pub settings: SolverSettings,
+}
+
+/// Brent's root-finder.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BrentSolver {
+    /// Convergence criteria.
+    pub settings: SolverSettings,
+}
+
+/// Ridders' root-finder.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RiddersSolver {
+    /// Convergence criteria.
+    pub settings: SolverSettings,
+}
+
+/// Newton-Raphson root-finder, safeguarded by bisection.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NewtonRaphsonSolver {
+    /// Convergence criteria.
+    pub settings: SolverSettings,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Default for SolverSettings {
+    fn default() -> Self {
+        Self {
+            x_tolerance: 1e-14,
+            f_tolerance: 0.0,
+            max_iterations: 200,
+        }
+    }
+}
+
+impl SolverSettings {
+    /// New convergence criteria.
+    #[must_use]
+    pub fn new(x_tolerance: f64, f_tolerance: f64, max_iterations: usize) -> Self {
+        Self {
+            x_tolerance,
+            f_tolerance,
+            max_iterations,
+        }
+    }
+
+    /// Error for a solver that ran out of iterations.
+    fn not_converged(&self, name: &str) -> RustQuantError {
+        RustQuantError::NotConverged(format!(
+            "{name} did not converge in {} iterations",
+            self.max_iterations
+        ))
+    }
+}
+
+/// Solvers built from their convergence criteria.
+macro_rules! impl_with_settings {
+    ($($solver:ty),*) => {
+        $(
+            impl $solver {
+                /// New solver with the given convergence criteria.
+                #[must_use]
+                pub fn new(settings: SolverSettings) -> Self {
+                    Self { settings }
+                }
+            }
+        )*
+    };
+}
+
+impl_with_settings!(
+    BisectionSolver,
+    BrentSolver,
+    RiddersSolver,
+    NewtonRaphsonSolver
+);
+
+impl Solver for BisectionSolver {
+    fn solve<F: Fn(f64) -> f64>(
+        &self,
+        f: F,
+        lower: f64,
+        upper: f64,
+    ) -> Result<f64, RustQuantError> {
+        let f_lower = match bracket(&f, lower, upper)? {
+            Bracket::Root(root) => return Ok(root),
+            Bracket::Values(f_lower, _) => f_lower,
+        };
+        let settings = &self.settings;
+
+        // Keep `f(a) < 0 < f(b)`.
+        let (mut a, mut b) = if f_lower < 0.0 {
+            (lower, upper)
+        } else {
+            (upper, lower)
+        };
+
+        for _ in 0..settings.max_iterations {
+            let mid = 0.5 * (a + b);
+            let f_mid = f(mid);
+
+            if f_mid.abs() <= settings.f_tolerance || (b - a).abs() <= settings.x_tolerance {
+                return Ok(mid);
+            }
+
+            if f_mid < 0.0 {
+                a = mid;
+            } else {
+                b = mid;
+            }
+        }
+
+        Err(settings.not_converged("bisection"))
+    }
+}
+
+impl Solver for BrentSolver {
+    fn solve<F: Fn(f64) -> f64>(
+        &self,
+        f: F,
+        lower: f64,
+        upper: f64,
+    ) -> Result<f64, RustQuantError> {
+        let (mut fa, mut fb) = match bracket(&f, lower, upper)? {
+            Bracket::Root(root) => return Ok(root),
+            Bracket::Values(f_lower, f_upper) => (f_lower, f_upper),
+        };
+        let settings = &self.settings;
+
+        // `b` is the best estimate, `a` the previous one, and `c` the
+        // other end of the bracket around the root.
+        let (mut a, mut b) = (lower, upper);
+        let (mut c, mut fc) = (b, fb);
+        let (mut d, mut e) = (b - a, b - a);
+
+        for _ in 0..settings.max_iterations {
+            if fb * fc > 0.0 {
+                (c, fc) = (a, fa);
+                d = b - a;
+                e = d;
+            }
+            if fc.abs() < fb.abs() {
+                (a, fa) = (b, fb);
+                (b, fb) = (c, fc);
+                (c, fc) = (a, fa);
+            }
+
+            let tolerance = 2.0 * f64::EPSILON * b.abs() + 0.5 * settings.x_tolerance;
+            let m = 0.5 * (c - b);
+
+            if m.abs() <= tolerance || fb.abs() <= settings.f_tolerance {
+                return Ok(b);
+            }
+
+            if e.abs() >= tolerance && fa.abs() > fb.abs() {
+                // Inverse quadratic interpolation, or the secant step if
+                // only two points are distinct.
+                let s = fb / fa;
+                let (mut p, mut q) = if a == c {
+                    (2.0 * m * s, 1.0 - s)
+                } else {
+                    let (q, r) = (fa / fc, fb / fc);
+
+                    (
+                        s * (2.0 * m * q * (q - r) - (b - a) * (r - 1.0)),
+                        (q - 1.0) * (r - 1.0) * (s - 1.0),
+                    )
+                };
+
+                if p > 0.0 {
+                    q = -q;
+                } else {
+                    p = -p;
+                }
+
+                // Accept the step if it falls well within the bracket.
+                if 2.0 * p < f64::min(3.0 * m * q - (tolerance * q).abs(), (e * q).abs()) {
+                    e = d;
+                    d = p / q;
+                } else {
+                    d = m;
+                    e = m;
+                }
+            } else {
+                d = m;
+                e = m;
+            }
+
+            (a, fa) = (b, fb);
+            b += if d.abs() > tolerance {
+                d
+            } else {
+                tolerance.copysign(m)
+            };
+            fb = f(b);
+        }
+
+        Err(settings.not_converged("Brent's method"))
+    }
+}
+
+impl Solver for RiddersSolver {
+    fn solve<F: Fn(f64) -> f64>(
+        &self,
+        f: F,
+        lower: f64,
+        upper: f64,
+    ) -> Result<f64, RustQuantError> {
+        let (mut fa, mut fb) = match bracket(&f, lower, upper)? {
+            Bracket::Root(root) => return Ok(root),
+            Bracket::Values(f_lower, f_upper) => (f_lower, f_upper),
+        };
+        let settings = &self.settings;
+
+        let (mut a, mut b) = (lower, upper);
+        let mut previous = f64::NAN;
+
+        for _ in 0..settings.max_iterations {
+            let mid = 0.5 * (a + b);
+            let f_mid = f(mid);
+            let s = (f_mid * f_mid - fa * fb).sqrt();
+
+            if s == 0.0 {
+                return Ok(mid);
+            }
+
+            let x = mid + (mid - a) * (fa - fb).signum() * f_mid / s;
+            let fx = f(x);
+
+            if fx.abs() <= settings.f_tolerance || (x - previous).abs() <= settings.x_tolerance {
+                return Ok(x);
+            }
+            previous = x;
+
+            // Keep the tightest bracket around the root.
+            if f_mid * fx < 0.0 {
+                (a, fa) = (mid, f_mid);
+                (b, fb) = (x, fx);
+            } else if fa * fx < 0.0 {
+                (b, fb) = (x, fx);
+            } else {
+                (a, fa) = (x, fx);
+            }
+
+            if (b - a).abs() <= settings.x_tolerance {
+                return Ok(x);
+            }
+        }
+
+        Err(settings.not_converged("Ridders' method"))
+    }
+}
+
+impl Solver for NewtonRaphsonSolver {
+    /// Root of `f` by Newton's method from the middle of the interval, with
+    /// derivatives by central finite differences.
+    fn solve<F: Fn(f64) -> f64>(
+        &self,
+        f: F,
+        lower: f64,
+        upper: f64,
+    ) -> Result<f64, RustQuantError> {
+        let derivative = |x: f64| {
+            let h = 1e-7 * x.abs().max(1.0);
+
+            (f(x + h) - f(x - h)) / (2.0 * h)
+        };
+
+        self.solve_with_derivative(&f, derivative, 0.5 * (lower + upper), lower, upper)
+    }
+}
+
+impl NewtonRaphsonSolver {
+    /// Root of `f` in `[lower, upper]` by Newton's method from `guess`, with
+    /// the derivative `df` of `f`. A step that leaves the bracket around the
+    /// root, e.g. where the derivative vanishes, is replaced by bisection.
+    ///
+    /// # Errors
+    /// As for [`Solver::solve`].
+    pub fn solve_with_derivative<F, D>(
+        &self,
+        f: F,
+        df: D,
+        guess: f64,
+        lower: f64,
+        upper: f64,
+    ) -> Result<f64, RustQuantError>
+    where
+        F: Fn(f64) -> f64,
+        D: Fn(f64) -> f64,
+    {
+        let f_lower = match bracket(&f, lower, upper)? {
+            Bracket::Root(root) => return Ok(root),
+            Bracket::Values(f_lower, _) => f_lower,
+        };
+        let settings = &self.settings;
+
+        // Keep `f(a) < 0 < f(b)`.
+        let (mut a, mut b) = if f_lower < 0.0 {
+            (lower, upper)
+        } else {
+            (upper, lower)
+        };
+        let mut x = if guess > lower && guess < upper {
+            guess
+        } else {
+            0.5 * (lower + upper)
+        };
+
+        for _ in 0..settings.max_iterations {
+            let fx = f(x);
+
+            if fx.abs() <= settings.f_tolerance {
+                return Ok(x);
+            }
+
+            if fx < 0.0 {
+                a = x;
+            } else {
+                b = x;
+            }
+
+            let step = x - fx / df(x);
+            let next = if step.is_finite() && (step - a) * (step - b) < 0.0 {
+                step
+            } else {
+                0.5 * (a + b)
+            };
+
+            if (next - x).abs() <= settings.x_tolerance || (b - a).abs() <= settings.x_tolerance {
+                return Ok(next);
+            }
+
+            x = next;
+        }
+
+        Err(settings.not_converged("Newton-Raphson"))
+    }
+}
+
+/// Values of a function at the ends of a bracket, or a root at one of them.
+enum Bracket {
+    Root(f64),
+    Values(f64, f64),
+}
+
+/// Check that `f` changes sign over `[lower, upper]`.
+fn bracket<F: Fn(f64) -> f64>(f: &F, lower: f64, upper: f64) -> Result<Bracket, RustQuantError> {
+    if !(lower.is_finite() && upper.is_finite() && lower < upper) {
+        return Err(RustQuantError::InvalidArgument(format!(
+            "[{lower}, {upper}] is not a finite interval"
+        )));
+    }
+
+    let (f_lower, f_upper) = (f(lower), f(upper));
+
+    if f_lower == 0.0 {
+        return Ok(Bracket::Root(lower));
+    }
+    if f_upper == 0.0 {
+        return Ok(Bracket::Root(upper));
+    }
+    let product = f_lower * f_upper;
+
+    if product.is_nan() || product > 0.0 {
+        return Err(RustQuantError::InvalidArgument(format!(
+            "the root is not bracketed by [{lower}, {upper}]: f = {f_lower} and {f_upper}"
+        )));
+    }
+
+    Ok(Bracket::Values(f_lower, f_upper))
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_solver {
+    use super::*;
+
+    /// Solve with each of the solvers.
+    fn solve_all<F: Fn(f64) -> f64 + Copy>(f: F, lower: f64, upper: f64) -> Vec<f64> {
+        vec![
+            BisectionSolver::default().solve(f, lower, upper).unwrap(),
+            BrentSolver::default().solve(f, lower, upper).unwrap(),
+            RiddersSolver::default().solve(f, lower, upper).unwrap(),
+            NewtonRaphsonSolver::default()
+                .solve(f, lower, upper)
+                .unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_solvers_find_roots() {
+        for root in solve_all(|x| x * x - 2.0, 0.0, 2.0) {
+            assert_approx_equal!(root, std::f64::consts::SQRT_2, 1e-13);
+        }
+
+        // A flat function with a kink: Newton steps leave the bracket.
+        for root in solve_all(|x| (x - 1.0).powi(3) + 1e-3 * (x - 1.0), -5.0, 20.0) {
+            assert_approx_equal!(root, 1.0, 1e-9);
+        }
+
+        // Yield of a 5-year annual 4% bond priced at 95.
+        let price = |y: f64| {
+            (1..=5).map(|t| 4.0 / (1.0 + y).powi(t)).sum::<f64>() + 100.0 / (1.0 + y).powi(5) - 95.0
+        };
+        let roots = solve_all(price, -0.5, 1.0);
+        for root in &roots {
+            assert_approx_equal!(price(*root), 0.0, 1e-10);
+        }
+
+        // The exact derivative needs fewer evaluations, and gives the same root.
+        let derivative = |y: f64| {
+            (1..=5)
+                .map(|t| -f64::from(t) * 4.0 / (1.0 + y).powi(t + 1))
+                .sum::<f64>()
+                - 500.0 / (1.0 + y).powi(6)
+        };
+        let newton = NewtonRaphsonSolver::default()
+            .solve_with_derivative(price, derivative, 0.04, -0.5, 1.0)
+            .unwrap();
+        assert_approx_equal!(newton, roots[1], 1e-12);
+    }
+
+    #[test]
+    fn test_solver_errors() {
+        let f = |x: f64| x * x + 1.0;
+
+        assert!(BrentSolver::default().solve(f, -1.0, 1.0).is_err());
+        assert!(BrentSolver::default().solve(|x| x, 1.0, -1.0).is_err());
+        assert!(RiddersSolver::default()
+            .solve(|x| x, f64::NEG_INFINITY, 1.0)
+            .is_err());
+
+        // A root at the end of the interval.
+        assert_approx_equal!(
+            BisectionSolver::default().solve(|x| x, 0.0, 1.0).unwrap(),
+            0.0,
+            1e-15
+        );
+
+        // Too few iterations.
+        let settings = SolverSettings::new(1e-14, 0.0, 3);
+        assert!(matches!(
+            BisectionSolver::new(settings).solve(|x| x - 0.3, 0.0, 1.0),
+            Err(RustQuantError::NotConverged(_))
+        ));
+
+        // A loose function tolerance stops early.
+        let settings = SolverSettings::new(0.0, 1e-3, 100);
+        let root = BisectionSolver::new(settings)
+            .solve(|x| x - 0.3, 0.0, 1.0)
+            .unwrap();
+        assert!((root - 0.3).abs() <= 1e-3);
+    }
+}
@@ -0,0 +1,865 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! The Hull-White one-factor short rate model, fitted to a yield curve:
+//!
+//! $$
+//! dr_t = (\theta(t) - a r_t) dt + \sigma dW_t
+//! $$
+//!
+//! The short rate is $r_t = x_t + \alpha(t)$, where $x$ is an
+//! Ornstein-Uhlenbeck process started at zero and the deterministic shift
+//!
+//! $$
+//! \alpha(t) = f(0, t) + \frac{\sigma^2}{2 a^2} \left(1 - e^{-a t}\right)^2
+//! $$
+//!
+//! makes the model reprice the discount factors $P(0, T)$ of the curve
+//! exactly (Brigo and Mercurio, 2006, section 3.3). Zero-coupon bonds and
+//! options on them have closed forms, which price caplets (as bond puts)
+//! and European swaptions (by Jamshidian's decomposition). The mean
+//! reversion and volatility can be calibrated to caplet and swaption prices,
+//! and the model simulated on a trinomial tree fitted to the curve, or by
+//! Monte Carlo (see [`StochasticProcess`](crate::stochastics::StochasticProcess)).
+//!
+//! Times are in years (Actual/365 Fixed) from the initial date of the curve,
+//! which is the valuation date.
+
+use crate::data::YieldCurve;
+use crate::error::RustQuantError;
+use crate::instruments::options::TypeFlag;
+use crate::instruments::swaps::cap_floor::time_to_expiry;
+use crate::instruments::swaps::{CapFloorType, Caplet, SwapDirection, Swaption};
+use crate::math::distributions::{Distribution, Gaussian};
+use crate::math::interpolation::{Interpolator, LinearInterpolator};
+use crate::models::sabr::minimise;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Hull-White one-factor model fitted to a yield curve.
+#[derive(Debug, Clone)]
+pub struct HullWhiteOneFactor<I: Interpolator<f64, f64> = LinearInterpolator<f64, f64>> {
+    /// Mean reversion speed ($a > 0$).
+    pub mean_reversion: f64,
+
+    /// Volatility of the short rate ($\sigma > 0$).
+    pub volatility: f64,
+
+    /// Initial term structure, discounting and forecasting.
+    pub curve: YieldCurve<I>,
+}
+
+/// Market price of an instrument to calibrate the model to.
+#[derive(Clone, Copy)]
+pub enum HullWhiteCalibrationQuote<'a> {
+    /// Price of a caplet or floorlet.
+    Caplet {
+        /// The caplet.
+        caplet: &'a Caplet,
+
+        /// Its market price.
+        price: f64,
+    },
+
+    /// Price of a European swaption.
+    Swaption {
+        /// The swaption.
+        swaption: &'a Swaption,
+
+        /// Its market price.
+        price: f64,
+    },
+}
+
+/// Trinomial tree of the Hull-White short rate (Hull and White, 1994), on a
+/// uniform time grid, fitted to the discount factors of the curve.
+///
+/// The node `k` of step `i` has the rate
+/// $r = \alpha_i + (k - w_i) \Delta x$, where $w_i$ is the number of nodes
+/// above (or below) the middle of the step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HullWhiteTree {
+    /// Time step.
+    dt: f64,
+
+    /// Spacing of the rates.
+    dx: f64,
+
+    /// Highest node, from the middle, where the branching turns down.
+    j_max: i64,
+
+    /// Expected change of $x$ over a time step, per unit of $x$.
+    drift: f64,
+
+    /// Rate of the middle node of each step, fitted to the curve.
+    shifts: Vec<f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<I: Interpolator<f64, f64> + Default> HullWhiteOneFactor<I> {
+    /// Create a new Hull-White model fitted to the curve.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mean reversion or the volatility is not positive.
+    #[must_use]
+    pub fn new(mean_reversion: f64, volatility: f64, curve: YieldCurve<I>) -> Self {
+        assert!(mean_reversion > 0.0, "the mean reversion must be positive");
+        assert!(volatility > 0.0, "the volatility must be positive");
+
+        Self {
+            mean_reversion,
+            volatility,
+            curve,
+        }
+    }
+
+    /// Discount factor $P(0, t)$ of the curve.
+    #[must_use]
+    pub fn discount_factor(&self, t: f64) -> f64 {
+        self.curve.discount_factor_at(t)
+    }
+
+    /// Instantaneous forward rate $f(0, t)$ of the curve.
+    #[must_use]
+    pub fn instantaneous_forward(&self, t: f64) -> f64 {
+        let h = 1e-4;
+        let t1 = (t - h).max(0.0);
+
+        self.curve.forward_rate_at(t1, t1 + 2.0 * h)
+    }
+
+    /// Deterministic shift $\alpha(t)$ of the short rate, $r_t = x_t + \alpha(t)$.
+    #[must_use]
+    pub fn shift(&self, t: f64) -> f64 {
+        let (a, sigma) = (self.mean_reversion, self.volatility);
+
+        self.instantaneous_forward(t) + (sigma / a * (1.0 - (-a * t).exp())).powi(2) / 2.0
+    }
+
+    /// Drift term $\theta(t)$ of the short rate, fitting the model to the
+    /// curve:
+    ///
+    /// $$
+    /// \theta(t) = \frac{\partial f(0, t)}{\partial t} + a f(0, t) +
+    /// \frac{\sigma^2}{2 a} \left(1 - e^{-2 a t}\right)
+    /// $$
+    #[must_use]
+    pub fn theta(&self, t: f64) -> f64 {
+        let (a, sigma) = (self.mean_reversion, self.volatility);
+        let h = 1e-3;
+        let t1 = (t - h).max(0.0);
+        let slope =
+            (self.instantaneous_forward(t1 + 2.0 * h) - self.instantaneous_forward(t1)) / (2.0 * h);
+
+        slope
+            + a * self.instantaneous_forward(t)
+            + sigma * sigma / (2.0 * a) * (1.0 - (-2.0 * a * t).exp())
+    }
+
+    /// Price at time `t` of the zero-coupon bond maturing at `maturity`,
+    /// given the short rate at `t`:
+    ///
+    /// $$
+    /// P(t, T) = \frac{P(0, T)}{P(0, t)} \exp \left( B(t, T) f(0, t) -
+    /// \frac{\sigma^2}{4 a} \left(1 - e^{-2 a t}\right) B(t, T)^2 -
+    /// B(t, T) r_t \right), \quad B(t, T) = \frac{1 - e^{-a (T - t)}}{a}
+    /// $$
+    #[must_use]
+    pub fn zero_coupon_bond(&self, t: f64, maturity: f64, short_rate: f64) -> f64 {
+        self.bond(t, maturity, short_rate - self.shift(t))
+    }
+
+    /// Price of a European option expiring at `expiry` on the zero-coupon
+    /// bond maturing at `maturity`, struck at `strike`:
+    ///
+    /// $$
+    /// ZBC = P(0, S) \Phi(h) - K P(0, T) \Phi(h - \sigma_P), \quad
+    /// h = \frac{1}{\sigma_P} \ln \frac{P(0, S)}{K P(0, T)} + \frac{\sigma_P}{2}
+    /// $$
+    ///
+    /// with $\sigma_P = \sigma \sqrt{(1 - e^{-2 a T}) / (2 a)} B(T, S)$, and
+    /// the put by parity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bond matures before the option expires.
+    #[must_use]
+    pub fn bond_option(
+        &self,
+        option_type: TypeFlag,
+        strike: f64,
+        expiry: f64,
+        maturity: f64,
+    ) -> f64 {
+        assert!(maturity >= expiry, "the bond must outlive the option");
+
+        let (p_t, p_s) = (self.discount_factor(expiry), self.discount_factor(maturity));
+        let sigma_p = self.variance(expiry).sqrt() * self.b(expiry, maturity);
+
+        if sigma_p <= 0.0 {
+            return match option_type {
+                TypeFlag::Call => (p_s - strike * p_t).max(0.0),
+                TypeFlag::Put => (strike * p_t - p_s).max(0.0),
+            };
+        }
+
+        let normal = Gaussian::default();
+        let h = (p_s / (strike * p_t)).ln() / sigma_p + sigma_p / 2.0;
+
+        match option_type {
+            TypeFlag::Call => p_s * normal.cdf(h) - strike * p_t * normal.cdf(h - sigma_p),
+            TypeFlag::Put => strike * p_t * normal.cdf(sigma_p - h) - p_s * normal.cdf(-h),
+        }
+    }
+
+    /// Price of a caplet (a put on the zero-coupon bond over its accrual
+    /// period) or floorlet (a call), paid at the end of its accrual period:
+    ///
+    /// $$
+    /// N (1 + K \tau) \cdot ZBP \left( \frac{1}{1 + K \tau}, T_s, T_e \right)
+    /// $$
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the caplet fixes on or before
+    ///   the initial date of the curve.
+    pub fn caplet_price(&self, caplet: &Caplet) -> Result<f64, RustQuantError> {
+        let start = self.time(caplet.accrual_start, "caplet fixing")?;
+        let end = self.time(caplet.accrual_end, "caplet accrual end")?;
+
+        let scale = 1.0 + caplet.strike * caplet.accrual_factor;
+        let option_type = match caplet.cap_floor_type {
+            CapFloorType::Cap => TypeFlag::Put,
+            CapFloorType::Floor => TypeFlag::Call,
+        };
+
+        Ok(caplet.notional * scale * self.bond_option(option_type, 1.0 / scale, start, end))
+    }
+
+    /// Price of a European swaption, by Jamshidian's decomposition.
+    ///
+    /// At expiry $T_0$ the payer swaption pays the floating leg
+    /// $P(T_0, T_s) - P(T_0, T_n)$ less the fixed coupons
+    /// $\sum_i K \tau_i P(T_0, T_i)$, where all the bond prices are
+    /// decreasing in $x_{T_0}$. The swaption is exercised if and only if
+    /// $x_{T_0}$ is above the critical $x^*$ at which the swap is worth
+    /// nothing, so each bond contributes
+    /// $P(0, T) \mathbb{Q}^T(x_{T_0} > x^*)$, a normal probability under its
+    /// forward measure.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the swaption expires on or
+    ///   before the initial date of the curve, or the swap starts before it
+    ///   expires.
+    pub fn swaption_price(&self, swaption: &Swaption) -> Result<f64, RustQuantError> {
+        let swap = &swaption.swap;
+        let periods = swap.fixed_schedule.coupon_periods();
+        let accruals = swap
+            .fixed_schedule
+            .accrual_schedule(swap.fixed_schedule.day_counting_convention);
+
+        let expiry = self.time(swaption.expiry_date, "swaption expiry")?;
+        let (Some(first), Some(last)) = (periods.first(), periods.last()) else {
+            return Ok(0.0);
+        };
+        let start = time_to_expiry(self.curve_date(), first.start);
+        if start < expiry {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the swap starts on {}, before the swaption expires",
+                first.start
+            )));
+        }
+
+        // The fixed coupons and the notional at the end of the swap, and the
+        // start of the floating leg with a negative weight.
+        let mut cash_flows: Vec<(f64, f64)> = periods
+            .iter()
+            .zip(&accruals)
+            .map(|(period, tau)| {
+                (
+                    time_to_expiry(self.curve_date(), period.payment),
+                    swap.fixed_rate * tau,
+                )
+            })
+            .collect();
+        cash_flows.push((time_to_expiry(self.curve_date(), last.end), 1.0));
+        cash_flows.push((start, -1.0));
+
+        // The swap value per unit of the start bond is decreasing in x.
+        let variance = self.variance(expiry);
+        let x_star = self.critical_state(expiry, &cash_flows);
+        let normal = Gaussian::default();
+
+        let value: f64 = cash_flows
+            .iter()
+            .map(|&(t, c)| {
+                let d = (self.forward_mean(expiry, t) - x_star) / variance.sqrt();
+                let probability = match swap.direction {
+                    SwapDirection::Payer => normal.cdf(d),
+                    SwapDirection::Receiver => normal.cdf(-d),
+                };
+
+                c * self.discount_factor(t) * probability
+            })
+            .sum();
+
+        let sign = match swap.direction {
+            SwapDirection::Payer => -1.0,
+            SwapDirection::Receiver => 1.0,
+        };
+
+        Ok(swap.notional * sign * value)
+    }
+
+    /// Calibrate the mean reversion and volatility to the market prices of
+    /// caplets and swaptions, by least squares on the relative price errors.
+    ///
+    /// If `mean_reversion` is given it is kept, and only the volatility is
+    /// fitted, as is common when calibrating to a single expiry. The search
+    /// is a Nelder-Mead minimisation over $(\ln a, \ln \sigma)$.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there are no quotes, a price
+    ///   or the given mean reversion is not positive, or an instrument cannot
+    ///   be priced (see [`caplet_price`](Self::caplet_price) and
+    ///   [`swaption_price`](Self::swaption_price)).
+    /// - `RustQuantError::ComputationError` if the fitted parameters are not
+    ///   finite.
+    pub fn calibrate(
+        curve: YieldCurve<I>,
+        quotes: &[HullWhiteCalibrationQuote],
+        mean_reversion: Option<f64>,
+    ) -> Result<Self, RustQuantError>
+    where
+        I: Clone,
+    {
+        if quotes.is_empty() {
+            return Err(RustQuantError::InvalidArgument(
+                "at least one quote is needed to calibrate Hull-White".to_string(),
+            ));
+        }
+        if quotes.iter().any(|quote| quote.price() <= 0.0) {
+            return Err(RustQuantError::InvalidArgument(
+                "the quoted prices must be positive".to_string(),
+            ));
+        }
+        if let Some(a) = mean_reversion.filter(|a| *a <= 0.0) {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the mean reversion ({a}) must be positive"
+            )));
+        }
+
+        let mut model = Self::new(0.05, 0.01, curve);
+        for quote in quotes {
+            model.price(quote)?;
+        }
+
+        let error = |a: f64, sigma: f64| -> f64 {
+            let model = Self {
+                mean_reversion: a,
+                volatility: sigma,
+                curve: model.curve.clone(),
+            };
+            let sse: f64 = quotes
+                .iter()
+                .map(|quote| {
+                    model
+                        .price(quote)
+                        .map_or(f64::MAX, |price| (price / quote.price() - 1.0).powi(2))
+                })
+                .sum();
+
+            if sse.is_finite() {
+                sse
+            } else {
+                f64::MAX
+            }
+        };
+
+        let volatilities = [0.002_f64, 0.005, 0.01, 0.02, 0.04];
+        let (a, sigma) = match mean_reversion {
+            Some(a) => {
+                let objective = |x: [f64; 1]| error(a, x[0].exp());
+                let start = volatilities
+                    .iter()
+                    .map(|sigma| [sigma.ln()])
+                    .min_by(|x, y| objective(*x).total_cmp(&objective(*y)))
+                    .unwrap_or_default();
+
+                (a, minimise(objective, start)[0].exp())
+            }
+            None => {
+                let objective = |x: [f64; 2]| error(x[0].exp(), x[1].exp());
+                let start = [0.01_f64, 0.05, 0.2]
+                    .iter()
+                    .flat_map(|a| volatilities.iter().map(move |sigma| [a.ln(), sigma.ln()]))
+                    .min_by(|x, y| objective(*x).total_cmp(&objective(*y)))
+                    .unwrap_or_default();
+                let x = minimise(objective, start);
+
+                (x[0].exp(), x[1].exp())
+            }
+        };
+
+        if !(a.is_finite() && sigma.is_finite() && a > 0.0 && sigma > 0.0) {
+            return Err(RustQuantError::ComputationError(
+                "Hull-White calibration did not produce finite parameters".to_string(),
+            ));
+        }
+
+        model.mean_reversion = a;
+        model.volatility = sigma;
+
+        Ok(model)
+    }
+
+    /// Trinomial tree of the short rate up to `maturity`, with `n_steps`
+    /// time steps, fitted to the discount factors of the curve at each step.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the maturity or the number of
+    ///   steps is not positive.
+    pub fn trinomial_tree(
+        &self,
+        maturity: f64,
+        n_steps: usize,
+    ) -> Result<HullWhiteTree, RustQuantError> {
+        if maturity.is_nan() || maturity <= 0.0 || n_steps == 0 {
+            return Err(RustQuantError::InvalidArgument(
+                "the tree needs a positive maturity and number of steps".to_string(),
+            ));
+        }
+
+        let dt = maturity / n_steps as f64;
+        let drift = (-self.mean_reversion * dt).exp() - 1.0;
+        let mut tree = HullWhiteTree {
+            dt,
+            dx: (3.0 * self.variance(dt)).sqrt(),
+            j_max: (0.184 / -drift).floor() as i64 + 1,
+            drift,
+            shifts: Vec::with_capacity(n_steps),
+        };
+
+        // Forward induction of the Arrow-Debreu prices of the nodes, with
+        // the shift of each step repricing the discount factor of the next.
+        let mut state_prices = vec![1.0];
+        for step in 0..n_steps {
+            let width = tree.width(step);
+            let sum: f64 = state_prices
+                .iter()
+                .enumerate()
+                .map(|(k, q)| q * (-((k as i64 - width) as f64) * tree.dx * dt).exp())
+                .sum();
+            let shift = (sum.ln() - self.discount_factor((step + 1) as f64 * dt).ln()) / dt;
+            tree.shifts.push(shift);
+
+            let next_width = tree.width(step + 1);
+            let mut next = vec![0.0; 2 * next_width as usize + 1];
+            for (k, q) in state_prices.iter().enumerate() {
+                let j = k as i64 - width;
+                let discounted = q * (-(shift + j as f64 * tree.dx) * dt).exp();
+                let (middle, probabilities) = tree.branches(j);
+
+                for (offset, p) in [1, 0, -1].iter().zip(probabilities) {
+                    next[(middle + offset + next_width) as usize] += discounted * p;
+                }
+            }
+            state_prices = next;
+        }
+
+        Ok(tree)
+    }
+
+    /// Price of a calibration instrument.
+    fn price(&self, quote: &HullWhiteCalibrationQuote) -> Result<f64, RustQuantError> {
+        match quote {
+            HullWhiteCalibrationQuote::Caplet { caplet, .. } => self.caplet_price(caplet),
+            HullWhiteCalibrationQuote::Swaption { swaption, .. } => self.swaption_price(swaption),
+        }
+    }
+
+    /// $B(t, T)$.
+    fn b(&self, t: f64, maturity: f64) -> f64 {
+        let a = self.mean_reversion;
+
+        (1.0 - (-a * (maturity - t)).exp()) / a
+    }
+
+    /// Variance of $x_t$.
+    fn variance(&self, t: f64) -> f64 {
+        let (a, sigma) = (self.mean_reversion, self.volatility);
+
+        sigma * sigma * (1.0 - (-2.0 * a * t).exp()) / (2.0 * a)
+    }
+
+    /// Mean of $x_t$ under the forward measure of the bond maturing at
+    /// `maturity`.
+    fn forward_mean(&self, t: f64, maturity: f64) -> f64 {
+        let (a, sigma) = (self.mean_reversion, self.volatility);
+        let scale = sigma * sigma / (a * a);
+
+        -scale * (1.0 - (-a * t).exp())
+            + scale / 2.0 * ((-a * (maturity - t)).exp() - (-a * (maturity + t)).exp())
+    }
+
+    /// Price at `t` of the zero-coupon bond maturing at `maturity`, given $x_t$.
+    fn bond(&self, t: f64, maturity: f64, x: f64) -> f64 {
+        let (a, sigma) = (self.mean_reversion, self.volatility);
+        let b = self.b(t, maturity);
+        let exponent = -b * x
+            - sigma * sigma / (4.0 * a) * (1.0 - (-2.0 * a * t).exp()) * b * b
+            - b * (sigma / a * (1.0 - (-a * t).exp())).powi(2) / 2.0;
+
+        self.discount_factor(maturity) / self.discount_factor(t) * exponent.exp()
+    }
+
+    /// The $x_t$ at which the weighted bonds are worth nothing. Their sum
+    /// divided by the bond with the negative weight (which matures first) is
+    /// decreasing and convex in $x_t$, so Newton's method converges from any
+    /// start.
+    fn critical_state(&self, t: f64, cash_flows: &[(f64, f64)]) -> f64 {
+        let start = cash_flows
+            .iter()
+            .filter(|(_, c)| *c < 0.0)
+            .map(|(t, _)| *t)
+            .fold(t, f64::max);
+
+        let mut x = 0.0;
+        for _ in 0..100 {
+            let (value, slope) = cash_flows.iter().filter(|(_, c)| *c > 0.0).fold(
+                (-1.0, 0.0),
+                |(value, slope), &(maturity, c)| {
+                    let ratio = c * self.bond(t, maturity, x) / self.bond(t, start, x);
+                    let b = self.b(t, maturity) - self.b(t, start);
+                    (value + ratio, slope - b * ratio)
+                },
+            );
+
+            let step = value / slope;
+            x -= step;
+            if step.abs() < 1e-14 {
+                break;
+            }
+        }
+
+        x
+    }
+
+    /// Initial date of the curve.
+    fn curve_date(&self) -> Date {
+        self.curve
+            .rates
+            .keys()
+            .next()
+            .copied()
+            .expect("the curve has no points")
+    }
+
+    /// Time from the initial date of the curve to a later date.
+    fn time(&self, date: Date, what: &str) -> Result<f64, RustQuantError> {
+        if date <= self.curve_date() {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the {what} ({date}) is not after the initial date of the curve"
+            )));
+        }
+
+        Ok(time_to_expiry(self.curve_date(), date))
+    }
+}
+
+impl HullWhiteCalibrationQuote<'_> {
+    /// The market price.
+    #[must_use]
+    pub fn price(&self) -> f64 {
+        match self {
+            Self::Caplet { price, .. } | Self::Swaption { price, .. } => *price,
+        }
+    }
+}
+
+impl HullWhiteTree {
+    /// Number of time steps.
+    #[must_use]
+    pub fn n_steps(&self) -> usize {
+        self.shifts.len()
+    }
+
+    /// Time step.
+    #[must_use]
+    pub fn dt(&self) -> f64 {
+        self.dt
+    }
+
+    /// Number of nodes of a step.
+    #[must_use]
+    pub fn nodes(&self, step: usize) -> usize {
+        2 * self.width(step) as usize + 1
+    }
+
+    /// Short rate at a node of a step, before the last step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step is not before the last step.
+    #[must_use]
+    pub fn short_rate(&self, step: usize, node: usize) -> f64 {
+        self.shifts[step] + (node as i64 - self.width(step)) as f64 * self.dx
+    }
+
+    /// Values at the nodes of step `to` of the values at the nodes of the
+    /// later step `from`, by discounted expectation over the tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `to` is after `from`, `from` is after the last step, or
+    /// there is not one value per node of `from`.
+    #[must_use]
+    pub fn rollback(&self, values: &[f64], from: usize, to: usize) -> Vec<f64> {
+        assert!(to <= from && from <= self.n_steps(), "invalid steps");
+        assert_eq!(values.len(), self.nodes(from), "one value per node");
+
+        let mut values = values.to_vec();
+        for step in (to..from).rev() {
+            let (width, next_width) = (self.width(step), self.width(step + 1));
+
+            values = (0..self.nodes(step))
+                .map(|k| {
+                    let (middle, probabilities) = self.branches(k as i64 - width);
+                    let expectation: f64 = [1, 0, -1]
+                        .iter()
+                        .zip(probabilities)
+                        .map(|(offset, p)| p * values[(middle + offset + next_width) as usize])
+                        .sum();
+
+                    (-self.short_rate(step, k) * self.dt).exp() * expectation
+                })
+                .collect();
+        }
+
+        values
+    }
+
+    /// Nodes above (or below) the middle of a step.
+    fn width(&self, step: usize) -> i64 {
+        (step as i64).min(self.j_max)
+    }
+
+    /// Middle node of the branches from node `j`, and the probabilities of
+    /// moving up, to the middle, and down, which match the mean and variance
+    /// of $x$ over a time step.
+    fn branches(&self, j: i64) -> (i64, [f64; 3]) {
+        let middle = j.clamp(1 - self.j_max, self.j_max - 1);
+        let eta = j as f64 * (1.0 + self.drift) - middle as f64;
+
+        (
+            middle,
+            [
+                (1.0 / 3.0 + eta * eta + eta) / 2.0,
+                2.0 / 3.0 - eta * eta,
+                (1.0 / 3.0 + eta * eta - eta) / 2.0,
+            ],
+        )
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_hull_white_one_factor {
+    use super::*;
+    use crate::instruments::swaps::InterestRateSwap;
+    use crate::time::{
+        countries::north_america::united_states::UnitedStatesCalendar, DateRollingConvention,
+        DayCountConvention, Frequency, Schedule,
+    };
+    use time::macros::date;
+
+    const TODAY: Date = date!(2024 - 03 - 20);
+
+    fn model(a: f64, sigma: f64) -> HullWhiteOneFactor {
+        let curve = YieldCurve::from_dates_and_rates(
+            &[
+                TODAY,
+                date!(2025 - 03 - 20),
+                date!(2027 - 03 - 20),
+                date!(2034 - 03 - 20),
+            ],
+            &[0.03, 0.035, 0.04, 0.042],
+        );
+
+        HullWhiteOneFactor::new(a, sigma, curve)
+    }
+
+    fn swaption(direction: SwapDirection, expiry: Date, end: Date, strike: f64) -> Swaption {
+        let schedule = |frequency, day_count| {
+            Schedule::regular(
+                expiry,
+                end,
+                frequency,
+                &UnitedStatesCalendar,
+                DateRollingConvention::ModifiedFollowing,
+                day_count,
+            )
+            .unwrap()
+        };
+
+        let swap = InterestRateSwap::new(
+            direction,
+            1.0,
+            strike,
+            schedule(Frequency::Annually, DayCountConvention::Thirty_360_ISDA),
+            schedule(Frequency::Quarterly, DayCountConvention::Actual_360),
+        );
+
+        Swaption::new(expiry, swap)
+    }
+
+    #[test]
+    fn test_bonds_and_options_fit_the_curve() {
+        let hw = model(0.1, 0.01);
+
+        // From the initial short rate, the bonds are the curve's.
+        let r_0 = hw.instantaneous_forward(0.0);
+        for t in [0.5, 2.0, 7.0] {
+            assert_approx_equal!(
+                hw.zero_coupon_bond(0.0, t, r_0),
+                hw.discount_factor(t),
+                1e-12
+            );
+        }
+
+        // Put-call parity of bond options.
+        let call = hw.bond_option(TypeFlag::Call, 0.95, 1.0, 3.0);
+        let put = hw.bond_option(TypeFlag::Put, 0.95, 1.0, 3.0);
+        assert_approx_equal!(
+            call - put,
+            hw.discount_factor(3.0) - 0.95 * hw.discount_factor(1.0),
+            1e-14
+        );
+
+        // Payer less receiver swaption is the forward starting payer swap.
+        let (expiry, end) = (date!(2026 - 03 - 20), date!(2031 - 03 - 20));
+        let payer = hw
+            .swaption_price(&swaption(SwapDirection::Payer, expiry, end, 0.04))
+            .unwrap();
+        let receiver = hw
+            .swaption_price(&swaption(SwapDirection::Receiver, expiry, end, 0.04))
+            .unwrap();
+
+        let receiver_swaption = swaption(SwapDirection::Receiver, expiry, end, 0.04);
+        let periods = receiver_swaption.swap.fixed_schedule.coupon_periods();
+        let accruals = receiver_swaption
+            .swap
+            .fixed_schedule
+            .accrual_schedule(DayCountConvention::Thirty_360_ISDA);
+        let time = |date| time_to_expiry(TODAY, date);
+        let fixed: f64 = periods
+            .iter()
+            .zip(&accruals)
+            .map(|(period, tau)| 0.04 * tau * hw.discount_factor(time(period.payment)))
+            .sum();
+        let floating = hw.discount_factor(time(periods[0].start))
+            - hw.discount_factor(time(periods[periods.len() - 1].end));
+
+        assert!(payer > 0.0 && receiver > 0.0);
+        assert_approx_equal!(payer - receiver, floating - fixed, 1e-14);
+    }
+
+    #[test]
+    fn test_calibration_recovers_parameters() {
+        let hw = model(0.08, 0.012);
+
+        let caplets: Vec<Caplet> = [
+            (date!(2025 - 03 - 20), date!(2025 - 06 - 20)),
+            (date!(2027 - 03 - 22), date!(2027 - 06 - 21)),
+        ]
+        .iter()
+        .map(|(start, end)| {
+            Caplet::new(
+                CapFloorType::Cap,
+                1.0,
+                0.04,
+                *start,
+                *end,
+                *end,
+                DayCountConvention::Actual_360,
+            )
+        })
+        .collect();
+        let swaptions = [
+            swaption(
+                SwapDirection::Payer,
+                date!(2025 - 03 - 20),
+                date!(2030 - 03 - 20),
+                0.04,
+            ),
+            swaption(
+                SwapDirection::Receiver,
+                date!(2029 - 03 - 20),
+                date!(2034 - 03 - 20),
+                0.04,
+            ),
+        ];
+
+        let quotes: Vec<HullWhiteCalibrationQuote> = caplets
+            .iter()
+            .map(|caplet| HullWhiteCalibrationQuote::Caplet {
+                caplet,
+                price: hw.caplet_price(caplet).unwrap(),
+            })
+            .chain(
+                swaptions
+                    .iter()
+                    .map(|swaption| HullWhiteCalibrationQuote::Swaption {
+                        swaption,
+                        price: hw.swaption_price(swaption).unwrap(),
+                    }),
+            )
+            .collect();
+
+        let fitted = HullWhiteOneFactor::calibrate(hw.curve.clone(), &quotes, None).unwrap();
+        assert_approx_equal!(fitted.mean_reversion, 0.08, 1e-5);
+        assert_approx_equal!(fitted.volatility, 0.012, 1e-6);
+
+        let fixed = HullWhiteOneFactor::calibrate(hw.curve.clone(), &quotes, Some(0.08)).unwrap();
+        assert_approx_equal!(fixed.volatility, 0.012, 1e-7);
+
+        assert!(HullWhiteOneFactor::calibrate(hw.curve.clone(), &[], None).is_err());
+    }
+
+    #[test]
+    fn test_trinomial_tree() {
+        let hw = model(0.1, 0.01);
+        let tree = hw.trinomial_tree(3.0, 150).unwrap();
+        assert_eq!(tree.n_steps(), 150);
+        assert_eq!(tree.nodes(150), tree.nodes(149));
+
+        // The tree reprices the discount factors of the curve.
+        for step in [1, 50, 150] {
+            let bond = tree.rollback(&vec![1.0; tree.nodes(step)], step, 0);
+            assert_approx_equal!(bond[0], hw.discount_factor(step as f64 * tree.dt()), 1e-12);
+        }
+
+        // A one year call on the three year bond, as in closed form.
+        let bonds = tree.rollback(&vec![1.0; tree.nodes(150)], 150, 50);
+        let payoffs: Vec<f64> = bonds.iter().map(|p| (p - 0.95).max(0.0)).collect();
+        let call = tree.rollback(&payoffs, 50, 0)[0];
+
+        assert_approx_equal!(call, hw.bond_option(TypeFlag::Call, 0.95, 1.0, 3.0), 2e-5);
+        assert!(hw.trinomial_tree(3.0, 0).is_err());
+    }
+}
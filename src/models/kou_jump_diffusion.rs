@@ -0,0 +1,117 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::instruments::CharacteristicFunction;
+use num::Complex;
+
+/// Struct containing the Kou double exponential jump diffusion parameters.
+///
+/// The price is $S_t = S_0 e^{(\mu + \omega) t + \sigma W_t + J_t}$, where
+/// $J_t$ is a compound Poisson process of intensity $\lambda$ whose jumps are
+/// exponential with rate $\eta_1$ upwards (with probability $p$) and rate
+/// $\eta_2$ downwards, and $\omega$ makes $E[S_t] = S_0 e^{\mu t}$
+/// (Kou, 2002).
+#[derive(Debug, Clone, Copy)]
+pub struct KouJumpDiffusion {
+    /// The drift ($\mu$), e.g. $r - q$ under the risk-neutral measure.
+    pub mu: f64,
+
+    /// The volatility of the diffusion ($\sigma$).
+    pub sigma: f64,
+
+    /// The jump intensity ($\lambda$).
+    pub lambda: f64,
+
+    /// The probability of an upward jump ($p$).
+    pub p: f64,
+
+    /// The rate of the upward jumps ($\eta_1$), their mean being $1 / \eta_1$.
+    pub eta_up: f64,
+
+    /// The rate of the downward jumps ($\eta_2$), their mean being $1 / \eta_2$.
+    pub eta_down: f64,
+}
+
+impl KouJumpDiffusion {
+    /// Create a new Kou jump diffusion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if $\sigma$, $\lambda$ or $\eta_2$ is negative, if $p$ is not a
+    /// probability, or if the price has no finite mean ($\eta_1 \leq 1$).
+    #[must_use]
+    pub fn new(mu: f64, sigma: f64, lambda: f64, p: f64, eta_up: f64, eta_down: f64) -> Self {
+        assert!(sigma >= 0.0 && lambda >= 0.0 && eta_down > 0.0);
+        assert!((0.0..=1.0).contains(&p));
+        assert!(eta_up > 1.0);
+
+        Self {
+            mu,
+            sigma,
+            lambda,
+            p,
+            eta_up,
+            eta_down,
+        }
+    }
+
+    /// The mean relative jump size $\zeta = E[e^Y] - 1$.
+    #[must_use]
+    pub fn mean_jump(&self) -> f64 {
+        self.p * self.eta_up / (self.eta_up - 1.0)
+            + (1.0 - self.p) * self.eta_down / (self.eta_down + 1.0)
+            - 1.0
+    }
+
+    /// The martingale correction $\omega = -\sigma^2 / 2 - \lambda \zeta$.
+    #[must_use]
+    pub fn martingale_correction(&self) -> f64 {
+        -0.5 * self.sigma * self.sigma - self.lambda * self.mean_jump()
+    }
+
+    /// Characteristic exponent of $\sigma W_t + J_t$ (per unit time).
+    fn exponent(&self, u: Complex<f64>) -> Complex<f64> {
+        let i: Complex<f64> = Complex::i();
+        let jump = self.p * self.eta_up / (self.eta_up - i * u)
+            + (1.0 - self.p) * self.eta_down / (self.eta_down + i * u)
+            - 1.0;
+
+        -0.5 * self.sigma * self.sigma * u * u + self.lambda * jump
+    }
+}
+
+impl CharacteristicFunction for KouJumpDiffusion {
+    fn characteristic_function(&self, u: Complex<f64>, t: f64) -> Complex<f64> {
+        let i: Complex<f64> = Complex::i();
+
+        (t * (i * u * self.martingale_correction() + self.exponent(u))).exp()
+    }
+
+    fn cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let Self {
+            sigma,
+            lambda,
+            p,
+            eta_up,
+            eta_down,
+            ..
+        } = *self;
+
+        // The n-th moment of the jumps is n! (p / eta_1^n + (-1)^n (1 - p) / eta_2^n).
+        let moment = |n: i32, factorial: f64| {
+            factorial * (p / eta_up.powi(n) + (-1_f64).powi(n) * (1.0 - p) / eta_down.powi(n))
+        };
+
+        (
+            (self.martingale_correction() + lambda * moment(1, 1.0)) * t,
+            (sigma * sigma + lambda * moment(2, 2.0)) * t,
+            lambda * moment(4, 24.0) * t,
+        )
+    }
+}
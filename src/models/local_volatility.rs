@@ -0,0 +1,340 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Dupire's local volatility model, built from an implied volatility surface.
+//!
+//! The spot follows
+//!
+//! $$
+//! \frac{dS_t}{S_t} = \mu(t) dt + \sigma_{loc}(t, S_t) dW_t
+//! $$
+//!
+//! where $\mu(t) = \partial_t \ln F(t)$ keeps the forward $F(t)$, and the
+//! local volatility is the one that reprices every European option of the
+//! surface. With the total implied variance $w(t, y)$ at log-moneyness
+//! $y = \ln(K / F(t))$, Dupire's formula reads (Gatheral, 2006)
+//!
+//! $$
+//! \sigma_{loc}^2(t, y) = \frac{\partial_t w}{1 - \frac{y}{w} \partial_y w +
+//! \frac{1}{4} \left(-\frac{1}{4} - \frac{1}{w} + \frac{y^2}{w^2}\right) (\partial_y w)^2 +
+//! \frac{1}{2} \partial_y^2 w}
+//! $$
+//!
+//! The forward is interpolated log-linearly from the spot through the
+//! forwards of the slices of the surface, and extrapolated at the rate of the
+//! last interval.
+
+use crate::data::VolatilitySurface;
+use crate::error::RustQuantError;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// How the derivatives of the total implied variance are computed.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum VarianceDerivatives {
+    /// Derivatives of the smile of each slice (see
+    /// [`VolatilitySlice::total_variance_derivatives`]), and of the linear
+    /// interpolation of the total variance in time.
+    ///
+    /// [`VolatilitySlice::total_variance_derivatives`]: crate::data::VolatilitySlice::total_variance_derivatives
+    #[default]
+    Analytic,
+
+    /// Central differences of the total implied variance of the surface.
+    FiniteDifference {
+        /// Step in time (in years).
+        time_step: f64,
+
+        /// Step in log-moneyness.
+        log_moneyness_step: f64,
+    },
+}
+
+/// Dupire local volatility model.
+#[derive(Debug, Clone)]
+pub struct LocalVolatility {
+    /// Spot price of the underlying.
+    pub spot: f64,
+
+    /// Implied volatility surface.
+    pub surface: VolatilitySurface,
+
+    /// How the derivatives of the total variance are computed.
+    derivatives: VarianceDerivatives,
+
+    /// Lower and upper bounds of the local volatility.
+    bounds: (f64, f64),
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl LocalVolatility {
+    /// Create a new local volatility model from the spot and an implied
+    /// volatility surface, with analytic derivatives and the local
+    /// volatility bounded to $[0, 5]$.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the spot is not positive.
+    pub fn new(spot: f64, surface: VolatilitySurface) -> Result<Self, RustQuantError> {
+        if spot.is_nan() || spot <= 0.0 {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the spot ({spot}) must be positive"
+            )));
+        }
+
+        Ok(Self {
+            spot,
+            surface,
+            derivatives: VarianceDerivatives::Analytic,
+            bounds: (0.0, 5.0),
+        })
+    }
+
+    /// Compute the derivatives of the total variance as given.
+    #[must_use]
+    pub fn with_derivatives(mut self, derivatives: VarianceDerivatives) -> Self {
+        self.derivatives = derivatives;
+        self
+    }
+
+    /// Bound the local volatility to `[lower, upper]`. The bounds only bind
+    /// where the surface is close to (or has) static arbitrage, and Dupire's
+    /// formula blows up.
+    #[must_use]
+    pub fn with_volatility_bounds(mut self, lower: f64, upper: f64) -> Self {
+        self.bounds = (lower.max(0.0), upper.max(lower));
+        self
+    }
+
+    /// Forward of the underlying at time `t`.
+    #[must_use]
+    pub fn forward(&self, t: f64) -> f64 {
+        let (start, forward, rate) = self.forward_interval(t);
+
+        forward * (rate * (t - start)).exp()
+    }
+
+    /// Drift rate $\mu(t) = \partial_t \ln F(t)$ of the spot at time `t`.
+    #[must_use]
+    pub fn drift_rate(&self, t: f64) -> f64 {
+        self.forward_interval(t).2
+    }
+
+    /// Total implied variance $w$ at time `t` and log-moneyness `y`, and its
+    /// derivatives $(\partial_y w, \partial_y^2 w, \partial_t w)$.
+    #[must_use]
+    pub fn total_variance_derivatives(&self, t: f64, y: f64) -> (f64, f64, f64, f64) {
+        let t = t.max(1e-10);
+
+        match self.derivatives {
+            VarianceDerivatives::Analytic => {
+                let slices = self.surface.slices();
+                let i = slices.partition_point(|slice| slice.expiry <= t);
+
+                // Flat volatility in time outside the slices.
+                if i == 0 || i == slices.len() {
+                    let slice = &slices[i.saturating_sub(1)];
+                    let w = slice.total_variance(y);
+                    let (w_y, w_yy) = slice.total_variance_derivatives(y);
+                    let scale = t / slice.expiry;
+
+                    return (w * scale, w_y * scale, w_yy * scale, w / slice.expiry);
+                }
+
+                let (left, right) = (&slices[i - 1], &slices[i]);
+                let weight = (t - left.expiry) / (right.expiry - left.expiry);
+                let (w_l, w_r) = (left.total_variance(y), right.total_variance(y));
+                let (w_y_l, w_yy_l) = left.total_variance_derivatives(y);
+                let (w_y_r, w_yy_r) = right.total_variance_derivatives(y);
+
+                (
+                    (1.0 - weight) * w_l + weight * w_r,
+                    (1.0 - weight) * w_y_l + weight * w_y_r,
+                    (1.0 - weight) * w_yy_l + weight * w_yy_r,
+                    (w_r - w_l) / (right.expiry - left.expiry),
+                )
+            }
+            VarianceDerivatives::FiniteDifference {
+                time_step,
+                log_moneyness_step,
+            } => {
+                let w = |t: f64, y: f64| self.surface.total_variance(t, y);
+                let (h, dt) = (log_moneyness_step, time_step);
+
+                let (down, mid, up) = (w(t, y - h), w(t, y), w(t, y + h));
+                let w_t = if t > dt {
+                    (w(t + dt, y) - w(t - dt, y)) / (2.0 * dt)
+                } else {
+                    (w(t + dt, y) - mid) / dt
+                };
+
+                (
+                    mid,
+                    (up - down) / (2.0 * h),
+                    (up - 2.0 * mid + down) / (h * h),
+                    w_t,
+                )
+            }
+        }
+    }
+
+    /// Local variance by Dupire's formula at time `t` and log-moneyness `y`,
+    /// without bounds. It is negative or infinite where the surface has
+    /// calendar or butterfly arbitrage.
+    #[must_use]
+    pub fn local_variance(&self, t: f64, y: f64) -> f64 {
+        let (w, w_y, w_yy, w_t) = self.total_variance_derivatives(t, y);
+
+        let denominator =
+            1.0 - y / w * w_y + 0.25 * (-0.25 - 1.0 / w + y * y / (w * w)) * w_y * w_y + 0.5 * w_yy;
+
+        if denominator <= 0.0 {
+            return f64::INFINITY;
+        }
+
+        w_t / denominator
+    }
+
+    /// Local volatility at time `t` and spot level `spot`, within the bounds.
+    #[must_use]
+    pub fn local_volatility(&self, t: f64, spot: f64) -> f64 {
+        let y = (spot / self.forward(t)).ln();
+        let variance = self.local_variance(t, y);
+
+        if variance.is_nan() {
+            return self.bounds.0;
+        }
+
+        variance.max(0.0).sqrt().clamp(self.bounds.0, self.bounds.1)
+    }
+
+    /// Start, forward at the start, and log-forward rate of the interval of
+    /// the forward curve containing `t`.
+    fn forward_interval(&self, t: f64) -> (f64, f64, f64) {
+        let slices = self.surface.slices();
+        let i = slices.partition_point(|slice| slice.expiry <= t);
+
+        let node = |j: usize| match j {
+            0 => (0.0, self.spot),
+            _ => (slices[j - 1].expiry, slices[j - 1].forward),
+        };
+        let rate = |j: usize| {
+            let ((t_0, f_0), (t_1, f_1)) = (node(j - 1), node(j));
+            (f_1 / f_0).ln() / (t_1 - t_0)
+        };
+
+        let (start, forward) = node(i);
+        let rate = if i == slices.len() {
+            rate(i)
+        } else {
+            rate(i + 1)
+        };
+
+        (start, forward, rate)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_local_volatility {
+    use super::*;
+    use crate::data::{VolatilitySlice, VolatilitySmile};
+    use crate::models::SVI;
+
+    fn flat_smiles() -> VolatilitySurface {
+        let flat = |expiry: f64, forward: f64, volatility: f64| {
+            VolatilitySlice::new(
+                expiry,
+                forward,
+                VolatilitySmile::Quoted {
+                    strikes: vec![50.0, 100.0, 200.0],
+                    volatilities: vec![volatility; 3],
+                },
+            )
+            .unwrap()
+        };
+
+        VolatilitySurface::new(vec![flat(0.5, 101.0, 0.2), flat(1.5, 104.0, 0.25)]).unwrap()
+    }
+
+    #[test]
+    fn test_flat_smiles_give_forward_volatilities() {
+        let model = LocalVolatility::new(100.0, flat_smiles()).unwrap();
+
+        // The forward interpolates the spot and the slices.
+        assert_approx_equal!(model.forward(0.0), 100.0, 1e-12);
+        assert_approx_equal!(model.forward(0.5), 101.0, 1e-12);
+        assert_approx_equal!(model.forward(1.0), (101.0_f64 * 104.0).sqrt(), 1e-10);
+        assert_approx_equal!(model.drift_rate(2.0), (104.0_f64 / 101.0).ln(), 1e-12);
+
+        // Without a smile, the local volatility is the forward volatility
+        // between the slices, at any spot.
+        let forward_volatility = ((0.25 * 0.25 * 1.5 - 0.2 * 0.2 * 0.5) / 1.0_f64).sqrt();
+        for spot in [70.0, 100.0, 140.0] {
+            assert_approx_equal!(model.local_volatility(0.2, spot), 0.2, 1e-10);
+            assert_approx_equal!(model.local_volatility(1.0, spot), forward_volatility, 1e-10);
+            assert_approx_equal!(model.local_volatility(3.0, spot), 0.25, 1e-10);
+        }
+
+        assert!(LocalVolatility::new(0.0, flat_smiles()).is_err());
+    }
+
+    #[test]
+    fn test_analytic_and_finite_difference_derivatives_agree() {
+        let surface = VolatilitySurface::new(vec![
+            VolatilitySlice::new(
+                0.5,
+                101.0,
+                VolatilitySmile::Svi(SVI::new(0.01, 0.05, -0.4, 0.0, 0.2)),
+            )
+            .unwrap(),
+            VolatilitySlice::new(
+                1.0,
+                102.0,
+                VolatilitySmile::Svi(SVI::new(0.03, 0.1, -0.4, 0.0, 0.2)),
+            )
+            .unwrap(),
+        ])
+        .unwrap();
+
+        let analytic = LocalVolatility::new(100.0, surface).unwrap();
+        let finite_difference =
+            analytic
+                .clone()
+                .with_derivatives(VarianceDerivatives::FiniteDifference {
+                    time_step: 1e-4,
+                    log_moneyness_step: 1e-4,
+                });
+
+        for t in [0.25, 0.75, 2.0] {
+            for spot in [80.0, 95.0, 100.0, 110.0, 130.0] {
+                let a = analytic.local_volatility(t, spot);
+                let b = finite_difference.local_volatility(t, spot);
+
+                assert!(a > 0.0 && a < 1.0);
+                assert_approx_equal!(a, b, 1e-5);
+            }
+        }
+
+        // Negative skew: the local volatility is higher below the forward.
+        assert!(analytic.local_volatility(0.75, 90.0) > analytic.local_volatility(0.75, 110.0));
+
+        // Bounds only bind where the local volatility is outside them.
+        let bounded = analytic.clone().with_volatility_bounds(0.23, 0.3);
+        assert_approx_equal!(bounded.local_volatility(0.75, 105.0), 0.23, 1e-15);
+        assert_approx_equal!(bounded.local_volatility(0.75, 50.0), 0.3, 1e-15);
+    }
+}
@@ -74,6 +74,18 @@ pub use ho_lee::*;
 pub mod hull_white;
 pub use hull_white::*;
 
+/// Hull-White one-factor model fitted to a yield curve.
+pub mod hull_white_one_factor;
+pub use hull_white_one_factor::*;
+
+/// Kou double exponential jump diffusion.
+pub mod kou_jump_diffusion;
+pub use kou_jump_diffusion::*;
+
+/// Dupire local volatility model.
+pub mod local_volatility;
+pub use local_volatility::*;
+
 /// Merton Jump Diffusion.
 pub mod merton_jump_diffusion;
 pub use merton_jump_diffusion::*;
@@ -90,6 +102,10 @@ pub use nelson_siegel::*;
 pub mod nelson_siegel_svensson;
 pub use nelson_siegel_svensson::*;
 
+/// Normal inverse Gaussian process.
+pub mod normal_inverse_gaussian;
+pub use normal_inverse_gaussian::*;
+
 /// Ornstein-Uhlenbeck.
 pub mod ornstein_uhlenbeck;
 pub use ornstein_uhlenbeck::*;
@@ -97,3 +113,11 @@ pub use ornstein_uhlenbeck::*;
 /// SABR: Stochastic Alpha, Beta, Rho.
 pub mod sabr;
 pub use sabr::*;
+
+/// SVI: Stochastic Volatility Inspired smile parameterization.
+pub mod svi;
+pub use svi::*;
+
+/// Variance gamma process.
+pub mod variance_gamma;
+pub use variance_gamma::*;
@@ -0,0 +1,96 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::instruments::CharacteristicFunction;
+use num::Complex;
+
+/// Struct containing the normal inverse Gaussian process parameters.
+///
+/// The price is $S_t = S_0 e^{(\mu + \omega) t + X_t}$, where
+/// $X_t = \beta I_t + W_{I_t}$ is a Brownian motion with drift evaluated at
+/// an inverse Gaussian time $I_t$ of mean $\delta t / \gamma$ and shape
+/// $\delta^2 t^2$, with $\gamma = \sqrt{\alpha^2 - \beta^2}$, and $\omega$
+/// makes $E[S_t] = S_0 e^{\mu t}$ (Barndorff-Nielsen, 1997).
+#[derive(Debug, Clone, Copy)]
+pub struct NormalInverseGaussian {
+    /// The drift ($\mu$), e.g. $r - q$ under the risk-neutral measure.
+    pub mu: f64,
+
+    /// The tail heaviness ($\alpha$).
+    pub alpha: f64,
+
+    /// The asymmetry ($\beta$).
+    pub beta: f64,
+
+    /// The scale ($\delta$).
+    pub delta: f64,
+}
+
+impl NormalInverseGaussian {
+    /// Create a new normal inverse Gaussian process.
+    ///
+    /// # Panics
+    ///
+    /// Panics if $\delta$ is not positive, or if the price has no finite
+    /// mean ($\alpha \leq |\beta|$ or $\alpha \leq |\beta + 1|$).
+    #[must_use]
+    pub fn new(mu: f64, alpha: f64, beta: f64, delta: f64) -> Self {
+        assert!(delta > 0.0);
+        assert!(alpha > beta.abs() && alpha > (beta + 1.0).abs());
+
+        Self {
+            mu,
+            alpha,
+            beta,
+            delta,
+        }
+    }
+
+    /// $\gamma = \sqrt{\alpha^2 - \beta^2}$.
+    #[must_use]
+    pub fn gamma(&self) -> f64 {
+        (self.alpha * self.alpha - self.beta * self.beta).sqrt()
+    }
+
+    /// The martingale correction
+    /// $\omega = \delta (\sqrt{\alpha^2 - (\beta + 1)^2} - \gamma)$.
+    #[must_use]
+    pub fn martingale_correction(&self) -> f64 {
+        self.delta * ((self.alpha * self.alpha - (self.beta + 1.0).powi(2)).sqrt() - self.gamma())
+    }
+
+    /// Characteristic exponent of $X_t$ (per unit time).
+    fn exponent(&self, u: Complex<f64>) -> Complex<f64> {
+        let i: Complex<f64> = Complex::i();
+
+        self.delta * (self.gamma() - (self.alpha * self.alpha - (self.beta + i * u).powi(2)).sqrt())
+    }
+}
+
+impl CharacteristicFunction for NormalInverseGaussian {
+    fn characteristic_function(&self, u: Complex<f64>, t: f64) -> Complex<f64> {
+        let i: Complex<f64> = Complex::i();
+
+        (t * (i * u * self.martingale_correction() + self.exponent(u))).exp()
+    }
+
+    fn cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let Self {
+            alpha, beta, delta, ..
+        } = *self;
+        let gamma = self.gamma();
+        let alpha2 = alpha * alpha;
+
+        (
+            (self.martingale_correction() + delta * beta / gamma) * t,
+            delta * alpha2 / gamma.powi(3) * t,
+            3.0 * delta * alpha2 * (alpha2 + 4.0 * beta * beta) / gamma.powi(7) * t,
+        )
+    }
+}
@@ -7,9 +7,26 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+//! The SABR stochastic volatility model of Hagan et al. (2002):
+//!
+//! $$
+//! dF_t = \sigma_t F_t^\beta dW_t, \qquad d\sigma_t = \nu \sigma_t dZ_t, \qquad
+//! dW_t dZ_t = \rho dt, \qquad \sigma_0 = \alpha
+//! $$
+//!
+//! Smiles of a single expiry use Hagan's asymptotic approximations of the
+//! lognormal (Black) and normal (Bachelier) implied volatilities, and can be
+//! calibrated to quoted volatilities by least squares.
+
+use crate::error::RustQuantError;
+use crate::math::optimization::nelder_mead;
 use crate::models::model_parameter::ModelParameter;
 
-/// Struct containing the Heston model parameters.
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Struct containing the SABR model parameters.
 pub struct SABR {
     /// The volatility of the volatility ($\alpha$).
     /// Note: $\alpha \in [0, \infty)$.
@@ -24,6 +41,38 @@ pub struct SABR {
     pub rho: ModelParameter,
 }
 
+/// SABR parameters of a single expiry slice of the volatility smile,
+/// for Hagan's implied volatility approximation.
+#[derive(Debug, Clone, Copy)]
+pub struct SabrSmile {
+    /// Initial volatility ($\alpha > 0$).
+    pub alpha: f64,
+
+    /// CEV exponent of the forward ($\beta \in [0, 1]$).
+    pub beta: f64,
+
+    /// Correlation between the forward and its volatility ($\rho \in (-1, 1)$).
+    pub rho: f64,
+
+    /// Volatility of the volatility ($\nu \geq 0$).
+    pub nu: f64,
+}
+
+/// Quoting convention of implied volatilities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SabrVolatilityType {
+    /// Lognormal (Black) volatilities.
+    #[default]
+    Lognormal,
+
+    /// Normal (Bachelier) volatilities, e.g. for swaptions and caps.
+    Normal,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
 impl SABR {
     /// Create a new SABR process.
     pub fn new(
@@ -38,3 +87,395 @@ impl SABR {
         }
     }
 }
+
+impl SabrSmile {
+    /// Create a new SABR smile.
+    #[must_use]
+    pub const fn new(alpha: f64, beta: f64, rho: f64, nu: f64) -> Self {
+        Self {
+            alpha,
+            beta,
+            rho,
+            nu,
+        }
+    }
+
+    /// Hagan et al. (2002) lognormal (Black) implied volatility at the given
+    /// forward, strike, and time to expiry:
+    ///
+    /// $$
+    /// \sigma_B = \frac{\alpha}{(FK)^{(1-\beta)/2} \left[1 + \frac{(1-\beta)^2}{24} \ln^2 \frac{F}{K} + \frac{(1-\beta)^4}{1920} \ln^4 \frac{F}{K}\right]}
+    /// \frac{z}{x(z)} \left[1 + \left(\frac{(1-\beta)^2 \alpha^2}{24 (FK)^{1-\beta}} + \frac{\rho \beta \nu \alpha}{4 (FK)^{(1-\beta)/2}} + \frac{2 - 3\rho^2}{24} \nu^2\right) T\right]
+    /// $$
+    ///
+    /// where $z = \frac{\nu}{\alpha} (FK)^{(1-\beta)/2} \ln \frac{F}{K}$ and
+    /// $x(z) = \ln \frac{\sqrt{1 - 2\rho z + z^2} + z - \rho}{1 - \rho}$.
+    #[must_use]
+    pub fn implied_volatility(&self, forward: f64, strike: f64, t: f64) -> f64 {
+        let Self {
+            alpha,
+            beta,
+            rho,
+            nu,
+        } = *self;
+
+        let one_minus_beta = 1.0 - beta;
+        let log_moneyness = (forward / strike).ln();
+        let fk = (forward * strike).powf(one_minus_beta / 2.0);
+
+        let z = nu / alpha * fk * log_moneyness;
+
+        let denominator = fk
+            * (1.0
+                + one_minus_beta.powi(2) / 24.0 * log_moneyness.powi(2)
+                + one_minus_beta.powi(4) / 1920.0 * log_moneyness.powi(4));
+
+        let correction = 1.0
+            + (one_minus_beta.powi(2) * alpha * alpha / (24.0 * fk * fk)
+                + rho * beta * nu * alpha / (4.0 * fk)
+                + (2.0 - 3.0 * rho * rho) / 24.0 * nu * nu)
+                * t;
+
+        alpha / denominator * z_over_x(z, rho) * correction
+    }
+
+    /// Hagan et al. (2002) normal (Bachelier) implied volatility at the
+    /// given forward, strike, and time to expiry:
+    ///
+    /// $$
+    /// \sigma_N = \alpha \frac{(1-\beta)(F-K)}{F^{1-\beta} - K^{1-\beta}}
+    /// \frac{\zeta}{x(\zeta)} \left[1 + \left(\frac{-\beta(2-\beta) \alpha^2}{24 (FK)^{1-\beta}} + \frac{\rho \beta \nu \alpha}{4 (FK)^{(1-\beta)/2}} + \frac{2 - 3\rho^2}{24} \nu^2\right) T\right]
+    /// $$
+    ///
+    /// where $\zeta = \frac{\nu}{\alpha} \frac{F - K}{(FK)^{\beta/2}}$, and the
+    /// first fraction is $(F-K) / \ln(F/K)$ when $\beta = 1$.
+    #[must_use]
+    pub fn normal_volatility(&self, forward: f64, strike: f64, t: f64) -> f64 {
+        let Self {
+            alpha,
+            beta,
+            rho,
+            nu,
+        } = *self;
+
+        let one_minus_beta = 1.0 - beta;
+        let log_moneyness = (forward / strike).ln();
+        let fk = (forward * strike).powf(one_minus_beta / 2.0);
+
+        let zeta = nu / alpha * (forward - strike) / (forward * strike).powf(beta / 2.0);
+
+        let scale = if log_moneyness.abs() < 1e-8 {
+            forward.powf(beta)
+        } else if one_minus_beta.abs() < 1e-8 {
+            (forward - strike) / log_moneyness
+        } else {
+            one_minus_beta * (forward - strike)
+                / (forward.powf(one_minus_beta) - strike.powf(one_minus_beta))
+        };
+
+        let correction = 1.0
+            + (-beta * (2.0 - beta) * alpha * alpha / (24.0 * fk * fk)
+                + rho * beta * nu * alpha / (4.0 * fk)
+                + (2.0 - 3.0 * rho * rho) / 24.0 * nu * nu)
+                * t;
+
+        alpha * scale * z_over_x(zeta, rho) * correction
+    }
+
+    /// Implied volatility of the given type at the forward, strike, and time
+    /// to expiry.
+    #[must_use]
+    pub fn volatility(
+        &self,
+        forward: f64,
+        strike: f64,
+        t: f64,
+        volatility_type: SabrVolatilityType,
+    ) -> f64 {
+        match volatility_type {
+            SabrVolatilityType::Lognormal => self.implied_volatility(forward, strike, t),
+            SabrVolatilityType::Normal => self.normal_volatility(forward, strike, t),
+        }
+    }
+
+    /// Calibrate a SABR smile to the implied volatilities quoted at the
+    /// strikes of a single expiry, by least squares on the volatilities.
+    ///
+    /// Following market practice, $\beta$ is usually fixed (e.g. 0.5 for
+    /// rates, 1 for equities) and only $(\alpha, \rho, \nu)$ are fitted, as
+    /// $\alpha$ and $\beta$ have much the same effect on a single smile. If
+    /// `beta` is `None` it is fitted as well. The search is a Nelder-Mead
+    /// minimisation over $(\ln \alpha, \tanh^{-1} \rho, \ln \nu)$ (and the
+    /// logit of $\beta$), which keeps the parameters in their domains.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there are fewer quotes than
+    ///   fitted parameters, the inputs have different lengths, the forward,
+    ///   strikes or time to expiry are not positive, or `beta` is outside $[0, 1]$.
+    /// - `RustQuantError::ComputationError` if the fitted smile is not finite.
+    pub fn calibrate(
+        forward: f64,
+        strikes: &[f64],
+        implied_volatilities: &[f64],
+        time_to_expiry: f64,
+        volatility_type: SabrVolatilityType,
+        beta: Option<f64>,
+    ) -> Result<Self, RustQuantError> {
+        if strikes.len() != implied_volatilities.len() {
+            return Err(RustQuantError::InvalidArgument(
+                "strikes and implied volatilities must have the same length".to_string(),
+            ));
+        }
+
+        let parameters = if beta.is_some() { 3 } else { 4 };
+
+        if strikes.len() < parameters {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "at least {parameters} quotes are needed to calibrate SABR"
+            )));
+        }
+        if !(forward > 0.0 && time_to_expiry > 0.0) || strikes.iter().any(|k| *k <= 0.0) {
+            return Err(RustQuantError::InvalidArgument(
+                "the forward, strikes and time to expiry must be positive".to_string(),
+            ));
+        }
+        if let Some(beta) = beta.filter(|beta| !(0.0..=1.0).contains(beta)) {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "beta ({beta}) must be in [0, 1]"
+            )));
+        }
+
+        let sse = |smile: Self| -> f64 {
+            let sse: f64 = strikes
+                .iter()
+                .zip(implied_volatilities)
+                .map(|(k, v)| {
+                    (smile.volatility(forward, *k, time_to_expiry, volatility_type) - v).powi(2)
+                })
+                .sum();
+
+            if sse.is_finite() {
+                sse
+            } else {
+                f64::MAX
+            }
+        };
+        let smile = |x: [f64; 3], beta: f64| Self::new(x[0].exp(), beta, x[1].tanh(), x[2].exp());
+
+        // Start from the alpha matching the quote nearest the money.
+        let atm = strikes
+            .iter()
+            .zip(implied_volatilities)
+            .min_by(|a, b| (a.0 - forward).abs().total_cmp(&(b.0 - forward).abs()))
+            .map_or(0.2, |(_, v)| *v);
+        let initial_alpha = |beta: f64| match volatility_type {
+            SabrVolatilityType::Lognormal => atm * forward.powf(1.0 - beta),
+            SabrVolatilityType::Normal => atm / forward.powf(beta),
+        };
+        let starts = |beta: f64| {
+            [-0.5_f64, 0.0, 0.5].into_iter().flat_map(move |rho| {
+                [0.1_f64, 0.3, 0.6, 1.0]
+                    .into_iter()
+                    .map(move |nu| [initial_alpha(beta).ln(), rho.atanh(), nu.ln()])
+            })
+        };
+
+        let fitted = match beta {
+            Some(beta) => {
+                let objective = |x: [f64; 3]| sse(smile(x, beta));
+                let start = starts(beta)
+                    .min_by(|a, b| objective(*a).total_cmp(&objective(*b)))
+                    .unwrap_or_default();
+
+                smile(minimise(objective, start), beta)
+            }
+            None => {
+                let logistic = |x: f64| 1.0 / (1.0 + (-x).exp());
+                let objective = |x: [f64; 4]| sse(smile([x[0], x[1], x[2]], logistic(x[3])));
+                let start = [0.2_f64, 0.5, 0.8]
+                    .into_iter()
+                    .flat_map(|beta| {
+                        starts(beta).map(move |x| [x[0], x[1], x[2], (beta / (1.0 - beta)).ln()])
+                    })
+                    .min_by(|a, b| objective(*a).total_cmp(&objective(*b)))
+                    .unwrap_or_default();
+
+                let x = minimise(objective, start);
+
+                smile([x[0], x[1], x[2]], logistic(x[3]))
+            }
+        };
+
+        if [fitted.alpha, fitted.beta, fitted.rho, fitted.nu]
+            .iter()
+            .any(|x| !x.is_finite())
+        {
+            return Err(RustQuantError::ComputationError(
+                "SABR calibration did not produce a finite smile".to_string(),
+            ));
+        }
+
+        Ok(fitted)
+    }
+}
+
+/// The ratio $z / x(z)$ of Hagan's formulas, which tends to one at the money.
+fn z_over_x(z: f64, rho: f64) -> f64 {
+    if z.abs() < 1e-8 {
+        return 1.0;
+    }
+
+    let x = (((1.0 - 2.0 * rho * z + z * z).sqrt() + z - rho) / (1.0 - rho)).ln();
+
+    z / x
+}
+
+/// Nelder-Mead minimisation, restarted from the previous minimum so the
+/// simplex does not collapse early.
+pub(crate) fn minimise<F, const N: usize>(objective: F, start: [f64; N]) -> [f64; N]
+where
+    F: Fn([f64; N]) -> f64,
+{
+    (0..3).fold(start, |x, _| {
+        nelder_mead(&objective, x, [0.1; N], 1e-20, 5000)
+    })
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_sabr {
+    use super::*;
+
+    const FORWARD: f64 = 0.03;
+    const EXPIRY: f64 = 2.0;
+
+    fn strikes() -> Vec<f64> {
+        (0..=12).map(|i| 0.01 + 0.0025 * f64::from(i)).collect()
+    }
+
+    #[test]
+    fn test_sabr_volatility_limits() {
+        // Without volatility of volatility, lognormal SABR is Black and
+        // normal SABR with beta = 0 is Bachelier.
+        let lognormal = SabrSmile::new(0.2, 1.0, -0.3, 0.0);
+        let normal = SabrSmile::new(0.008, 0.0, -0.3, 0.0);
+
+        for strike in strikes() {
+            assert_approx_equal!(
+                lognormal.implied_volatility(FORWARD, strike, EXPIRY),
+                0.2,
+                1e-12
+            );
+            assert_approx_equal!(
+                normal.normal_volatility(FORWARD, strike, EXPIRY),
+                0.008,
+                1e-12
+            );
+        }
+
+        // At the money, a Black volatility is a normal volatility of about
+        // sigma F (1 - sigma^2 T / 24).
+        assert_approx_equal!(
+            lognormal.normal_volatility(FORWARD, FORWARD, EXPIRY),
+            0.2 * FORWARD * (1.0 - 0.04 * EXPIRY / 24.0),
+            1e-15
+        );
+
+        // The normal volatility is continuous at the money.
+        let smile = SabrSmile::new(0.04, 0.5, -0.3, 0.4);
+        assert_approx_equal!(
+            smile.normal_volatility(FORWARD, FORWARD * (1.0 + 1e-7), EXPIRY),
+            smile.normal_volatility(FORWARD, FORWARD, EXPIRY),
+            1e-9
+        );
+    }
+
+    #[test]
+    fn test_sabr_calibration_recovers_parameters() {
+        let truth = SabrSmile::new(0.04, 0.5, -0.3, 0.4);
+
+        for volatility_type in [SabrVolatilityType::Lognormal, SabrVolatilityType::Normal] {
+            let quotes: Vec<f64> = strikes()
+                .iter()
+                .map(|k| truth.volatility(FORWARD, *k, EXPIRY, volatility_type))
+                .collect();
+
+            let fitted = SabrSmile::calibrate(
+                FORWARD,
+                &strikes(),
+                &quotes,
+                EXPIRY,
+                volatility_type,
+                Some(0.5),
+            )
+            .unwrap();
+
+            assert_approx_equal!(fitted.alpha, truth.alpha, 1e-6);
+            assert_approx_equal!(fitted.beta, truth.beta, 1e-15);
+            assert_approx_equal!(fitted.rho, truth.rho, 1e-4);
+            assert_approx_equal!(fitted.nu, truth.nu, 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_sabr_calibration_with_free_beta() {
+        let truth = SabrSmile::new(0.2, 0.9, -0.4, 0.6);
+        let quotes: Vec<f64> = strikes()
+            .iter()
+            .map(|k| truth.implied_volatility(FORWARD, *k, EXPIRY))
+            .collect();
+
+        let fitted = SabrSmile::calibrate(
+            FORWARD,
+            &strikes(),
+            &quotes,
+            EXPIRY,
+            SabrVolatilityType::Lognormal,
+            None,
+        )
+        .unwrap();
+
+        for (strike, quote) in strikes().iter().zip(quotes) {
+            assert_approx_equal!(
+                fitted.implied_volatility(FORWARD, *strike, EXPIRY),
+                quote,
+                1e-5
+            );
+        }
+    }
+
+    #[test]
+    fn test_sabr_calibration_invalid_input() {
+        let lognormal = SabrVolatilityType::Lognormal;
+
+        assert!(
+            SabrSmile::calibrate(0.03, &[0.02, 0.03], &[0.2, 0.2], 1.0, lognormal, Some(0.5))
+                .is_err()
+        );
+        assert!(
+            SabrSmile::calibrate(0.03, &[0.02; 4], &[0.2; 3], 1.0, lognormal, Some(0.5)).is_err()
+        );
+        assert!(SabrSmile::calibrate(0.03, &[0.02; 3], &[0.2; 3], 1.0, lognormal, None).is_err());
+        assert!(SabrSmile::calibrate(
+            0.03,
+            &[-0.01, 0.02, 0.03],
+            &[0.2; 3],
+            1.0,
+            lognormal,
+            Some(0.5)
+        )
+        .is_err());
+        assert!(SabrSmile::calibrate(
+            0.03,
+            &[0.02, 0.03, 0.04],
+            &[0.2; 3],
+            1.0,
+            lognormal,
+            Some(1.5)
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,284 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Gatheral's stochastic volatility inspired (SVI) parameterization of a
+//! single maturity slice of the implied volatility smile.
+//!
+//! The total implied variance $w(k) = \sigma_{BS}^2(k) T$ at log-moneyness
+//! $k = \ln(K / F)$ is:
+//!
+//! $$
+//! w(k) = a + b \left( \rho (k - m) + \sqrt{(k - m)^2 + \sigma^2} \right)
+//! $$
+//!
+//! A slice is free of butterfly arbitrage if Durrleman's function
+//!
+//! $$
+//! g(k) = \left(1 - \frac{k w'(k)}{2 w(k)}\right)^2
+//!      - \frac{w'(k)^2}{4} \left(\frac{1}{w(k)} + \frac{1}{4}\right)
+//!      + \frac{w''(k)}{2}
+//! $$
+//!
+//! is non-negative for all $k$.
+
+use crate::error::RustQuantError;
+use crate::math::optimization::nelder_mead;
+use nalgebra::{Matrix3, Vector3};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// SVI parameters for a single maturity slice.
+#[derive(Debug, Clone, Copy)]
+pub struct SVI {
+    /// Overall level of variance ($a$).
+    pub a: f64,
+
+    /// Slope of the wings ($b \geq 0$).
+    pub b: f64,
+
+    /// Correlation, controlling the skew ($\rho \in (-1, 1)$).
+    pub rho: f64,
+
+    /// Horizontal shift of the smile ($m$).
+    pub m: f64,
+
+    /// Curvature at the minimum ($\sigma > 0$).
+    pub sigma: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// SVI total implied variance at log-moneyness `k`.
+#[must_use]
+pub fn svi_total_variance(k: f64, a: f64, b: f64, rho: f64, m: f64, sigma: f64) -> f64 {
+    let x = k - m;
+
+    a + b * (rho * x + (x * x + sigma * sigma).sqrt())
+}
+
+impl SVI {
+    /// Create a new SVI slice.
+    #[must_use]
+    pub const fn new(a: f64, b: f64, rho: f64, m: f64, sigma: f64) -> Self {
+        Self {
+            a,
+            b,
+            rho,
+            m,
+            sigma,
+        }
+    }
+
+    /// Total implied variance at log-moneyness `k`.
+    #[must_use]
+    pub fn total_variance(&self, k: f64) -> f64 {
+        svi_total_variance(k, self.a, self.b, self.rho, self.m, self.sigma)
+    }
+
+    /// Implied (Black-Scholes) volatility at log-moneyness `k`, for a slice
+    /// with time to expiry `t`.
+    #[must_use]
+    pub fn implied_volatility(&self, k: f64, t: f64) -> f64 {
+        (self.total_variance(k) / t).sqrt()
+    }
+
+    /// Durrleman's function $g(k)$. The slice is free of butterfly arbitrage
+    /// where this is non-negative.
+    #[must_use]
+    pub fn durrleman_condition(&self, k: f64) -> f64 {
+        let x = k - self.m;
+        let r = (x * x + self.sigma * self.sigma).sqrt();
+
+        let w = self.total_variance(k);
+        let dw = self.b * (self.rho + x / r);
+        let d2w = self.b * self.sigma * self.sigma / r.powi(3);
+
+        (1.0 - k * dw / (2.0 * w)).powi(2) - dw * dw / 4.0 * (1.0 / w + 0.25) + d2w / 2.0
+    }
+
+    /// Checks the parameter constraints and Durrleman's condition at each
+    /// of the given log-moneyness values.
+    #[must_use]
+    pub fn is_arbitrage_free(&self, log_strikes: &[f64]) -> bool {
+        self.b >= 0.0
+            && self.rho.abs() < 1.0
+            && self.sigma > 0.0
+            && self.a + self.b * self.sigma * (1.0 - self.rho * self.rho).sqrt() >= 0.0
+            && log_strikes
+                .iter()
+                .all(|k| self.durrleman_condition(*k) >= -1e-12)
+    }
+
+    /// Calibrate an SVI slice to a smile of implied volatilities.
+    ///
+    /// Uses the quasi-explicit method: for fixed $(m, \sigma)$ the total
+    /// variance is linear in $(a, b \rho, b)$ and is solved by least squares,
+    /// while $(m, \sigma)$ are found by a Nelder-Mead search. Parameter sets
+    /// violating the no-arbitrage constraints are penalised.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if fewer than five quotes are given,
+    ///   or the inputs have different lengths.
+    /// - `RustQuantError::ComputationError` if the best fit is not free of
+    ///   butterfly arbitrage.
+    pub fn calibrate(
+        log_strikes: &[f64],
+        implied_volatilities: &[f64],
+        time_to_expiry: f64,
+    ) -> Result<Self, RustQuantError> {
+        if log_strikes.len() != implied_volatilities.len() {
+            return Err(RustQuantError::InvalidArgument(
+                "log-strikes and implied volatilities must have the same length".to_string(),
+            ));
+        }
+        if log_strikes.len() < 5 {
+            return Err(RustQuantError::InvalidArgument(
+                "at least five quotes are needed to calibrate SVI".to_string(),
+            ));
+        }
+
+        let variances: Vec<f64> = implied_volatilities
+            .iter()
+            .map(|v| v * v * time_to_expiry)
+            .collect();
+
+        // Check the fitted slice on a grid wider than the quotes.
+        let (k_min, k_max) = log_strikes
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(lo, hi), k| (lo.min(*k), hi.max(*k)));
+        let width = k_max - k_min;
+        let check_grid: Vec<f64> = (0..=100)
+            .map(|i| k_min - width + 3.0 * width * f64::from(i) / 100.0)
+            .collect();
+
+        let objective = |point: [f64; 2]| -> f64 {
+            let slice = Self::fit_linear(log_strikes, &variances, point[0], point[1].exp());
+            let sse: f64 = log_strikes
+                .iter()
+                .zip(variances.iter())
+                .map(|(k, w)| (slice.total_variance(*k) - w).powi(2))
+                .sum();
+
+            if slice.is_arbitrage_free(&check_grid) {
+                sse
+            } else {
+                sse + 1.0
+            }
+        };
+
+        // Coarse grid search for a starting point, then refine.
+        let mut start = [0.0, f64::ln(0.1)];
+        let mut best = f64::MAX;
+
+        for i in 0..=10 {
+            for sigma in [0.01, 0.05, 0.1, 0.2, 0.5, 1.0] {
+                let point = [k_min + width * f64::from(i) / 10.0, f64::ln(sigma)];
+                let value = objective(point);
+
+                if value < best {
+                    best = value;
+                    start = point;
+                }
+            }
+        }
+
+        let point = nelder_mead(objective, start, [0.05, 0.25], 1e-16, 2000);
+        let slice = Self::fit_linear(log_strikes, &variances, point[0], point[1].exp());
+
+        if !slice.is_arbitrage_free(&check_grid) {
+            return Err(RustQuantError::ComputationError(
+                "calibrated SVI slice violates the no-arbitrage constraints".to_string(),
+            ));
+        }
+
+        Ok(slice)
+    }
+
+    /// Least-squares fit of $(a, b \rho, b)$ for fixed $(m, \sigma)$.
+    fn fit_linear(log_strikes: &[f64], variances: &[f64], m: f64, sigma: f64) -> Self {
+        let mut lhs = Matrix3::<f64>::zeros();
+        let mut rhs = Vector3::<f64>::zeros();
+
+        for (k, w) in log_strikes.iter().zip(variances.iter()) {
+            let x = k - m;
+            let row = Vector3::new(1.0, x, (x * x + sigma * sigma).sqrt());
+
+            lhs += row * row.transpose();
+            rhs += row * *w;
+        }
+
+        let solution = lhs
+            .lu()
+            .solve(&rhs)
+            .unwrap_or_else(|| Vector3::new(f64::NAN, f64::NAN, f64::NAN));
+        let (a, c, b) = (solution[0], solution[1], solution[2]);
+
+        Self::new(a, b, c / b, m, sigma)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_svi {
+    use super::*;
+
+    #[test]
+    fn test_svi_calibration_recovers_parameters() {
+        let t = 0.5;
+        let truth = SVI::new(0.02, 0.15, -0.4, 0.05, 0.15);
+
+        let log_strikes: Vec<f64> = (0..=20).map(|i| -0.5 + 0.05 * f64::from(i)).collect();
+        let vols: Vec<f64> = log_strikes
+            .iter()
+            .map(|k| truth.implied_volatility(*k, t))
+            .collect();
+
+        let fitted = SVI::calibrate(&log_strikes, &vols, t).unwrap();
+
+        assert_approx_equal!(fitted.a, truth.a, 1e-5);
+        assert_approx_equal!(fitted.b, truth.b, 1e-5);
+        assert_approx_equal!(fitted.rho, truth.rho, 1e-4);
+        assert_approx_equal!(fitted.m, truth.m, 1e-4);
+        assert_approx_equal!(fitted.sigma, truth.sigma, 1e-4);
+
+        assert!(fitted.is_arbitrage_free(&log_strikes));
+    }
+
+    #[test]
+    fn test_svi_total_variance() {
+        // At k = m the square root term is just sigma.
+        assert_approx_equal!(
+            svi_total_variance(0.1, 0.04, 0.4, -0.4, 0.1, 0.2),
+            0.04 + 0.4 * 0.2,
+            1e-15
+        );
+    }
+
+    #[test]
+    fn test_durrleman_detects_arbitrage() {
+        // Very steep wings with a tiny level admit butterfly arbitrage.
+        let slice = SVI::new(-0.01, 2.0, 0.9, 0.0, 0.05);
+        let grid: Vec<f64> = (0..=40).map(|i| -1.0 + 0.05 * f64::from(i)).collect();
+
+        assert!(!slice.is_arbitrage_free(&grid));
+    }
+
+    #[test]
+    fn test_svi_calibration_invalid_input() {
+        assert!(SVI::calibrate(&[0.0, 0.1], &[0.2, 0.2], 1.0).is_err());
+        assert!(SVI::calibrate(&[0.0; 5], &[0.2; 4], 1.0).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::instruments::CharacteristicFunction;
+use num::Complex;
+
+/// The variance gamma model of Madan, Carr and Chang (1998): Brownian motion
+/// with drift, evaluated at a gamma distributed time.
+///
+/// The price is $S_t = S_0 e^{(\mu + \omega) t + X_t}$, where $X_t$ is the
+/// time-changed Brownian motion and $\omega$ makes $E[S_t] = S_0 e^{\mu t}$.
+#[derive(Debug, Clone, Copy)]
+pub struct VarianceGamma {
+    /// Drift of the price ($\mu$), e.g. $r - q$ under the risk-neutral
+    /// measure. Only used by the simulation, since the characteristic
+    /// function is relative to the forward.
+    pub mu: f64,
+
+    /// Volatility of the Brownian motion ($\sigma > 0$).
+    pub sigma: f64,
+
+    /// Variance rate of the gamma time change ($\nu > 0$).
+    pub nu: f64,
+
+    /// Drift of the Brownian motion, which sets the skew ($\theta$).
+    pub theta: f64,
+}
+
+impl VarianceGamma {
+    /// Create a new variance gamma model, with no drift.
+    #[must_use]
+    pub const fn new(sigma: f64, nu: f64, theta: f64) -> Self {
+        Self {
+            mu: 0.0,
+            sigma,
+            nu,
+            theta,
+        }
+    }
+
+    /// Set the drift of the price ($\mu$).
+    #[must_use]
+    pub fn with_drift(mut self, mu: f64) -> Self {
+        self.mu = mu;
+        self
+    }
+
+    /// Drift correction $\omega = \ln(1 - \theta \nu - \sigma^2 \nu / 2) / \nu$
+    /// making the price a martingale.
+    pub(crate) fn drift_correction(&self) -> f64 {
+        (1.0 - self.theta * self.nu - 0.5 * self.sigma * self.sigma * self.nu).ln() / self.nu
+    }
+}
+
+impl CharacteristicFunction for VarianceGamma {
+    fn characteristic_function(&self, u: Complex<f64>, t: f64) -> Complex<f64> {
+        let Self {
+            sigma, nu, theta, ..
+        } = *self;
+        let i: Complex<f64> = Complex::i();
+
+        let base = 1.0 - i * theta * nu * u + 0.5 * sigma * sigma * nu * u * u;
+
+        (i * u * self.drift_correction() * t - t / nu * base.ln()).exp()
+    }
+
+    fn cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let Self {
+            sigma, nu, theta, ..
+        } = *self;
+        let (sigma2, theta2) = (sigma * sigma, theta * theta);
+
+        (
+            (theta + self.drift_correction()) * t,
+            (sigma2 + nu * theta2) * t,
+            3.0 * (sigma2 * sigma2 * nu
+                + 2.0 * theta2 * theta2 * nu.powi(3)
+                + 4.0 * sigma2 * theta2 * nu * nu)
+                * t,
+        )
+    }
+}
@@ -77,8 +77,14 @@
 // IMPORTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use crate::error::RustQuantError;
+use crate::math::rootfinding::{
+    brent::Brent,
+    rootfinder::{Rootfinder, RootfinderData},
+};
 use crate::{instruments::fx::currency::Currency, instruments::Instrument};
 use std::collections::HashMap;
+use time::Date;
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // STRUCTS, ENUMS, AND TRAITS
@@ -225,6 +231,121 @@ where
     }
 }
 
+/// Time-weighted return: the compounded return over consecutive
+/// sub-periods, $\prod_i (1 + r_i) - 1$.
+///
+/// Each sub-period return should be measured between external flows, so
+/// that the result does not depend on the timing or size of the flows.
+#[must_use]
+pub fn time_weighted_return(subperiod_returns: &[f64]) -> f64 {
+    subperiod_returns.iter().map(|r| 1.0 + r).product::<f64>() - 1.0
+}
+
+/// Money-weighted return (internal rate of return) of a stream of dated
+/// cash flows: every annually compounded rate $r$ solving
+///
+/// $$
+/// \sum_i \frac{CF_i}{(1 + r)^{t_i}} = 0
+/// $$
+///
+/// where $t_i$ is the time (Actual/365) from the first cash flow.
+///
+/// A stream whose sign changes more than once can have several rates of
+/// return, so all the real roots found in $(-99\%, 1000\%)$ are returned, in
+/// ascending order. Use [`money_weighted_return_in`] to pick one by bracketing it.
+///
+/// # Errors
+/// - `RustQuantError::InvalidArgument` if the flows do not change sign.
+/// - `RustQuantError::NotConverged` if no root is found.
+pub fn money_weighted_return(cashflows: &[(Date, f64)]) -> Result<Vec<f64>, RustQuantError> {
+    validate_cashflows(cashflows)?;
+
+    let npv = |r: f64| net_present_value(cashflows, r);
+
+    let grid: Vec<f64> = (0..=2_000)
+        .map(|i| -0.99 + 10.99 * f64::from(i) / 2_000.0)
+        .collect();
+
+    let roots: Vec<f64> = grid
+        .windows(2)
+        .filter(|w| npv(w[0]) * npv(w[1]) <= 0.0 && npv(w[1]) != 0.0)
+        .map(|w| solve_in(cashflows, w[0], w[1]))
+        .collect();
+
+    if roots.is_empty() {
+        return Err(RustQuantError::NotConverged(
+            "no money-weighted return found in (-99%, 1000%)".to_string(),
+        ));
+    }
+
+    Ok(roots)
+}
+
+/// Money-weighted return (internal rate of return) of a stream of dated
+/// cash flows within a bracketing interval `[lower, upper]`.
+/// See [`money_weighted_return`].
+///
+/// # Errors
+/// - `RustQuantError::InvalidArgument` if the flows do not change sign, or
+///   the net present value does not change sign over the interval.
+pub fn money_weighted_return_in(
+    cashflows: &[(Date, f64)],
+    lower: f64,
+    upper: f64,
+) -> Result<f64, RustQuantError> {
+    validate_cashflows(cashflows)?;
+
+    if lower <= -1.0 || upper <= lower {
+        return Err(RustQuantError::InvalidArgument(format!(
+            "invalid bracket [{lower}, {upper}]: need -1 < lower < upper"
+        )));
+    }
+    if net_present_value(cashflows, lower) * net_present_value(cashflows, upper) > 0.0 {
+        return Err(RustQuantError::InvalidArgument(format!(
+            "the interval [{lower}, {upper}] does not bracket a money-weighted return"
+        )));
+    }
+
+    Ok(solve_in(cashflows, lower, upper))
+}
+
+/// Check there are cash flows of both signs.
+fn validate_cashflows(cashflows: &[(Date, f64)]) -> Result<(), RustQuantError> {
+    let has_inflow = cashflows.iter().any(|(_, cf)| *cf > 0.0);
+    let has_outflow = cashflows.iter().any(|(_, cf)| *cf < 0.0);
+
+    if !(has_inflow && has_outflow) {
+        return Err(RustQuantError::InvalidArgument(
+            "the cash flows must change sign to have a rate of return".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Net present value of the cash flows at an annually compounded rate,
+/// discounting to the first cash flow date.
+fn net_present_value(cashflows: &[(Date, f64)], rate: f64) -> f64 {
+    let start = cashflows.iter().map(|(date, _)| *date).min().unwrap();
+
+    cashflows
+        .iter()
+        .map(|(date, cf)| cf * (1.0 + rate).powf(-((*date - start).whole_days() as f64) / 365.0))
+        .sum()
+}
+
+/// Root of the net present value in a bracketing interval, by Brent's method.
+fn solve_in(cashflows: &[(Date, f64)], lower: f64, upper: f64) -> f64 {
+    let data = RootfinderData::new(1e-12, 0.25 * (upper - lower), lower, upper, true);
+    let mut solver = Brent::new(
+        |r| net_present_value(cashflows, r),
+        0.5 * (lower + upper),
+        data,
+    );
+
+    solver.solve()
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // UNIT TESTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -309,4 +430,70 @@ mod tests_portfolio {
         assert_eq!(weights.get("Put Options"), Some(&0.36363637));
         assert_eq!(weights.get("Call Options"), Some(&0.6363636));
     }
+
+    #[test]
+    fn test_time_and_money_weighted_returns_agree_without_flows() {
+        let returns = [0.05, -0.02, 0.08];
+        let twr = time_weighted_return(&returns);
+
+        // Without external flows, the portfolio grows from 100 to 100 (1 + TWR).
+        let cashflows = [
+            (time::macros::date!(2021 - 01 - 01), -100.0),
+            (time::macros::date!(2022 - 01 - 01), 100.0 * (1.0 + twr)),
+        ];
+
+        let mwr = money_weighted_return(&cashflows).unwrap();
+
+        assert_eq!(mwr.len(), 1);
+        assert_approx_equal!(mwr[0], twr, 1e-10);
+    }
+
+    #[test]
+    fn test_money_weighted_return() {
+        let cashflows = [
+            (time::macros::date!(2021 - 01 - 01), -1000.0),
+            (time::macros::date!(2022 - 01 - 01), 300.0),
+            (time::macros::date!(2023 - 01 - 01), 400.0),
+            (time::macros::date!(2024 - 01 - 01), 500.0),
+        ];
+
+        let mwr = money_weighted_return(&cashflows).unwrap();
+
+        assert_eq!(mwr.len(), 1);
+        assert_approx_equal!(mwr[0], 0.088_963_394_693_35, 1e-10);
+        assert_approx_equal!(
+            money_weighted_return_in(&cashflows, 0.0, 1.0).unwrap(),
+            mwr[0],
+            1e-10
+        );
+
+        assert!(matches!(
+            money_weighted_return(&cashflows[1..]),
+            Err(RustQuantError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_money_weighted_return_multiple_roots() {
+        // -100 + 230 / (1 + r) - 132 / (1 + r)^2 = 0 at r = 10% and r = 20%.
+        let cashflows = [
+            (time::macros::date!(2021 - 01 - 01), -100.0),
+            (time::macros::date!(2022 - 01 - 01), 230.0),
+            (time::macros::date!(2023 - 01 - 01), -132.0),
+        ];
+
+        let roots = money_weighted_return(&cashflows).unwrap();
+
+        assert_eq!(roots.len(), 2);
+        assert_approx_equal!(roots[0], 0.1, 1e-10);
+        assert_approx_equal!(roots[1], 0.2, 1e-10);
+
+        // A bracket picks out one of them.
+        assert_approx_equal!(
+            money_weighted_return_in(&cashflows, 0.15, 0.5).unwrap(),
+            0.2,
+            1e-10
+        );
+        assert!(money_weighted_return_in(&cashflows, 0.12, 0.18).is_err());
+    }
 }
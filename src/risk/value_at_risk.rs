@@ -0,0 +1,471 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::{
+    error::RustQuantError,
+    math::distributions::{Distribution, Gaussian, MultivariateNormal},
+};
+use nalgebra::{DMatrix, DVector};
+use rand::{rngs::StdRng, SeedableRng};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// How the distribution of the portfolio profit and loss is obtained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarMethod {
+    /// Historical simulation: the observed returns applied to the current
+    /// positions, with the mean profit and loss scaled to the horizon by the
+    /// time and the deviations from it by the square root of time.
+    Historical,
+
+    /// Variance-covariance: normal returns with the sample mean and
+    /// covariance of the observed returns.
+    Parametric,
+
+    /// Monte Carlo simulation of normal returns with the sample mean and
+    /// covariance of the observed returns.
+    MonteCarlo {
+        /// Number of simulated scenarios.
+        scenarios: usize,
+
+        /// Seed of the random number generator.
+        seed: u64,
+    },
+}
+
+/// Value at risk and expected shortfall, as positive losses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RiskMeasures {
+    /// Value at risk: the loss that is exceeded with probability one minus
+    /// the confidence level.
+    pub value_at_risk: f64,
+
+    /// Expected shortfall: the average loss beyond the value at risk.
+    pub expected_shortfall: f64,
+}
+
+/// Market risk of a portfolio of positions, from a history of the returns
+/// of their assets.
+/// <https://en.wikipedia.org/wiki/Value_at_risk>
+///
+/// The profit and loss of the portfolio over one period is $w^T r$, with
+/// the exposures $w$ (the current values of the positions) and the returns
+/// $r$ of the assets over the period. Over a horizon of $h$ periods, the
+/// parametric mean and covariance of the returns are scaled by $h$. Each
+/// historical scenario $x$ with mean $\bar{x}$ becomes
+/// $h \bar{x} + \sqrt{h} (x - \bar{x})$, with the same mean and variance.
+#[derive(Debug, Clone)]
+pub struct PortfolioRisk {
+    /// Current values of the positions.
+    exposures: DVector<f64>,
+
+    /// Observed returns of the assets, one period per row.
+    returns: DMatrix<f64>,
+
+    /// Confidence level of the risk measures (default 0.99).
+    confidence: f64,
+
+    /// Horizon, in periods of the observed returns (default 1).
+    horizon: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl PortfolioRisk {
+    /// New portfolio risk, with a confidence level of 99% over one period.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if there are no positions, fewer
+    ///   than two observations, or an observation does not have one return
+    ///   per position.
+    pub fn new(exposures: &[f64], returns: &[Vec<f64>]) -> Result<Self, RustQuantError> {
+        let n = exposures.len();
+
+        if n == 0 || returns.len() < 2 || returns.iter().any(|r| r.len() != n) {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "need at least two observations of {n} returns, one per position"
+            )));
+        }
+
+        Ok(Self {
+            exposures: DVector::from_column_slice(exposures),
+            returns: DMatrix::from_fn(returns.len(), n, |t, i| returns[t][i]),
+            confidence: 0.99,
+            horizon: 1.0,
+        })
+    }
+
+    /// Set the confidence level.
+    #[must_use]
+    pub fn with_confidence(mut self, confidence: f64) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
+    /// Set the horizon, in periods of the observed returns.
+    #[must_use]
+    pub fn with_horizon(mut self, horizon: f64) -> Self {
+        self.horizon = horizon;
+        self
+    }
+
+    /// Current values of the positions.
+    #[must_use]
+    pub fn exposures(&self) -> &[f64] {
+        self.exposures.as_slice()
+    }
+
+    /// Value at risk and expected shortfall of the portfolio.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the confidence level is not in
+    ///   `(0, 1)`, the horizon is not positive, there are no Monte Carlo
+    ///   scenarios, or (Monte Carlo) the covariance of the returns is not
+    ///   positive definite.
+    pub fn measures(&self, method: VarMethod) -> Result<RiskMeasures, RustQuantError> {
+        self.measures_of(&self.exposures, method)
+    }
+
+    /// Value at risk of the portfolio.
+    ///
+    /// # Errors
+    /// - As for `measures`.
+    pub fn value_at_risk(&self, method: VarMethod) -> Result<f64, RustQuantError> {
+        Ok(self.measures(method)?.value_at_risk)
+    }
+
+    /// Expected shortfall of the portfolio.
+    ///
+    /// # Errors
+    /// - As for `measures`.
+    pub fn expected_shortfall(&self, method: VarMethod) -> Result<f64, RustQuantError> {
+        Ok(self.measures(method)?.expected_shortfall)
+    }
+
+    /// Marginal value at risk of each position in the variance-covariance
+    /// model: the derivative of the value at risk with respect to the
+    /// exposure,
+    ///
+    /// $$
+    /// \frac{\partial \text{VaR}}{\partial w_i} =
+    /// -h \mu_i + z_c \frac{h (\Sigma w)_i}{\sigma_p}.
+    /// $$
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the confidence level is not in
+    ///   `(0, 1)` or the horizon is not positive.
+    /// - `RustQuantError::ComputationError` if the portfolio has no variance.
+    pub fn marginal_var(&self) -> Result<Vec<f64>, RustQuantError> {
+        self.validate()?;
+
+        let (mean, covariance) = self.moments();
+        let sigma = (self.horizon * self.exposures.dot(&(&covariance * &self.exposures))).sqrt();
+
+        if sigma.is_nan() || sigma <= 0.0 {
+            return Err(RustQuantError::ComputationError(
+                "the portfolio has no variance".to_string(),
+            ));
+        }
+
+        let z = Gaussian::default().inv_cdf(self.confidence);
+        let gradient =
+            -self.horizon * mean + z * self.horizon * (&covariance * &self.exposures) / sigma;
+
+        Ok(gradient.iter().copied().collect())
+    }
+
+    /// Component value at risk of each position in the variance-covariance
+    /// model: the exposure times the marginal value at risk.
+    ///
+    /// The value at risk is homogeneous of degree one in the exposures, so
+    /// by Euler's theorem the components add up to the parametric value at
+    /// risk of the portfolio.
+    ///
+    /// # Errors
+    /// - As for `marginal_var`.
+    pub fn component_var(&self) -> Result<Vec<f64>, RustQuantError> {
+        Ok(self
+            .marginal_var()?
+            .iter()
+            .zip(self.exposures.iter())
+            .map(|(marginal, w)| marginal * w)
+            .collect())
+    }
+
+    /// Incremental value at risk of a trade: the value at risk of the
+    /// portfolio with the trade (changes of the exposures) minus that of
+    /// the current portfolio, both by full revaluation.
+    ///
+    /// The incremental value at risk of closing position `i` is that of the
+    /// trade `-w_i` in position `i`.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the trade does not have one
+    ///   change per position.
+    /// - As for `measures`.
+    pub fn incremental_var(&self, trade: &[f64], method: VarMethod) -> Result<f64, RustQuantError> {
+        if trade.len() != self.exposures.len() {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the trade must have one change per position ({})",
+                self.exposures.len()
+            )));
+        }
+
+        let traded = &self.exposures + DVector::from_column_slice(trade);
+
+        Ok(self.measures_of(&traded, method)?.value_at_risk - self.measures(method)?.value_at_risk)
+    }
+
+    /// Checks the confidence level and the horizon.
+    fn validate(&self) -> Result<(), RustQuantError> {
+        if self.confidence.is_nan() || self.confidence <= 0.0 || self.confidence >= 1.0 {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the confidence level ({}) must be in (0, 1)",
+                self.confidence
+            )));
+        }
+        if self.horizon.is_nan() || self.horizon <= 0.0 {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the horizon ({}) must be positive",
+                self.horizon
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Sample mean and covariance of the returns over one period.
+    fn moments(&self) -> (DVector<f64>, DMatrix<f64>) {
+        let n = self.returns.nrows() as f64;
+        let mean = self.returns.row_mean().transpose();
+        let centred = DMatrix::from_fn(self.returns.nrows(), self.returns.ncols(), |t, i| {
+            self.returns[(t, i)] - mean[i]
+        });
+
+        let covariance = centred.transpose() * centred / (n - 1.0);
+
+        (mean, covariance)
+    }
+
+    /// Risk measures of the portfolio with the given exposures.
+    fn measures_of(
+        &self,
+        exposures: &DVector<f64>,
+        method: VarMethod,
+    ) -> Result<RiskMeasures, RustQuantError> {
+        self.validate()?;
+
+        match method {
+            VarMethod::Historical => {
+                let pnl = &self.returns * exposures;
+                let mean = pnl.mean();
+                let scale = self.horizon.sqrt();
+                let pnl: Vec<f64> = pnl
+                    .iter()
+                    .map(|pnl| self.horizon * mean + scale * (pnl - mean))
+                    .collect();
+
+                risk_measures(&pnl, self.confidence)
+            }
+            VarMethod::Parametric => {
+                let (mean, covariance) = self.moments();
+                let mu = self.horizon * exposures.dot(&mean);
+                let sigma = (self.horizon * exposures.dot(&(&covariance * exposures))).sqrt();
+
+                let gaussian = Gaussian::default();
+                let z = gaussian.inv_cdf(self.confidence);
+
+                Ok(RiskMeasures {
+                    value_at_risk: -mu + z * sigma,
+                    expected_shortfall: -mu + sigma * gaussian.pdf(z) / (1.0 - self.confidence),
+                })
+            }
+            VarMethod::MonteCarlo { scenarios, seed } => {
+                if scenarios == 0 {
+                    return Err(RustQuantError::InvalidArgument(
+                        "the number of scenarios must be positive".to_string(),
+                    ));
+                }
+
+                let (mean, covariance) = self.moments();
+                let normal = MultivariateNormal::new(
+                    (self.horizon * mean).as_slice(),
+                    self.horizon * covariance,
+                )?;
+
+                let mut rng = StdRng::seed_from_u64(seed);
+                let pnl: Vec<f64> = (0..scenarios)
+                    .map(|_| {
+                        let returns = normal.sample_with_rng(&mut rng);
+                        exposures.iter().zip(returns).map(|(w, r)| w * r).sum()
+                    })
+                    .collect();
+
+                risk_measures(&pnl, self.confidence)
+            }
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Value at risk and expected shortfall of a sample of profits and losses,
+/// e.g. of scenarios generated with a copula.
+///
+/// Of $n$ scenarios, the value at risk is the $m$-th largest loss and the
+/// expected shortfall the average of the $m$ largest losses, with
+/// $m = \lceil n (1 - c) \rceil$ for the confidence level $c$.
+///
+/// # Errors
+/// - `RustQuantError::InvalidArgument` if the sample is empty or the
+///   confidence level is not in `(0, 1)`.
+pub fn risk_measures(pnl: &[f64], confidence: f64) -> Result<RiskMeasures, RustQuantError> {
+    if pnl.is_empty() {
+        return Err(RustQuantError::InvalidArgument(
+            "the profit and loss sample must be non-empty".to_string(),
+        ));
+    }
+    if confidence.is_nan() || confidence <= 0.0 || confidence >= 1.0 {
+        return Err(RustQuantError::InvalidArgument(format!(
+            "the confidence level ({confidence}) must be in (0, 1)"
+        )));
+    }
+
+    let mut sorted = pnl.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    // The tail size, up to the rounding of its product.
+    let m = ((pnl.len() as f64 * (1.0 - confidence) - 1e-9).ceil() as usize).clamp(1, pnl.len());
+    let tail = &sorted[..m];
+
+    Ok(RiskMeasures {
+        value_at_risk: -tail[m - 1],
+        expected_shortfall: -tail.iter().sum::<f64>() / m as f64,
+    })
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_value_at_risk {
+    use super::*;
+    use rand::Rng;
+    use rand_distr::StandardNormal;
+
+    /// Daily returns of two correlated assets.
+    fn returns(n: usize, seed: u64) -> Vec<Vec<f64>> {
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        (0..n)
+            .map(|_| {
+                let z1: f64 = rng.sample(StandardNormal);
+                let z2: f64 = rng.sample(StandardNormal);
+                vec![
+                    0.0005 + 0.01 * z1,
+                    0.0002 + 0.02 * (0.4 * z1 + 0.84_f64.sqrt() * z2),
+                ]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_risk_measures() {
+        // Losses of 1 to 100: the 5 largest are 96 to 100.
+        let pnl: Vec<f64> = (1..=100).map(|i| -f64::from(i)).collect();
+        let measures = risk_measures(&pnl, 0.95).unwrap();
+        assert_approx_equal!(measures.value_at_risk, 96.0, 1e-12);
+        assert_approx_equal!(measures.expected_shortfall, 98.0, 1e-12);
+
+        assert!(risk_measures(&pnl, 1.0).is_err());
+        assert!(risk_measures(&[], 0.95).is_err());
+
+        let risk = PortfolioRisk::new(&[1.0, 2.0], &returns(10, 1)).unwrap();
+        assert!(risk
+            .clone()
+            .with_horizon(0.0)
+            .measures(VarMethod::Parametric)
+            .is_err());
+        assert!(risk
+            .measures(VarMethod::MonteCarlo {
+                scenarios: 0,
+                seed: 1
+            })
+            .is_err());
+        assert!(PortfolioRisk::new(&[1.0], &returns(10, 1)).is_err());
+    }
+
+    #[test]
+    fn test_value_at_risk_methods() {
+        let risk = PortfolioRisk::new(&[1e6, 5e5], &returns(50_000, 2))
+            .unwrap()
+            .with_confidence(0.99)
+            .with_horizon(10.0);
+
+        let parametric = risk.measures(VarMethod::Parametric).unwrap();
+        let historical = risk.measures(VarMethod::Historical).unwrap();
+        let monte_carlo = risk
+            .measures(VarMethod::MonteCarlo {
+                scenarios: 50_000,
+                seed: 3,
+            })
+            .unwrap();
+
+        // Normal expected shortfall over value at risk at 99%.
+        let ratio = Gaussian::default().pdf(2.326_347_874) / 0.01 / 2.326_347_874;
+        let (mean, _) = risk.moments();
+        let mu = 10.0 * (1e6 * mean[0] + 5e5 * mean[1]);
+        assert!(
+            ((parametric.expected_shortfall + mu) / (parametric.value_at_risk + mu) - ratio).abs()
+                < 1e-6
+        );
+
+        assert!((historical.value_at_risk / parametric.value_at_risk - 1.0).abs() < 0.03);
+        assert!((monte_carlo.value_at_risk / parametric.value_at_risk - 1.0).abs() < 0.03);
+        assert!(
+            (monte_carlo.expected_shortfall / parametric.expected_shortfall - 1.0).abs() < 0.03
+        );
+    }
+
+    #[test]
+    fn test_var_attribution() {
+        let risk = PortfolioRisk::new(&[1e6, 5e5], &returns(2_000, 4)).unwrap();
+        let var = risk.value_at_risk(VarMethod::Parametric).unwrap();
+
+        let components = risk.component_var().unwrap();
+        assert_approx_equal!(components.iter().sum::<f64>(), var, 1e-6);
+
+        // Marginal value at risk is the sensitivity of the value at risk.
+        let marginal = risk.marginal_var().unwrap();
+        let incremental = risk
+            .incremental_var(&[1_000.0, 0.0], VarMethod::Parametric)
+            .unwrap();
+        assert!((incremental / 1_000.0 - marginal[0]).abs() < 1e-5);
+
+        // Closing a position leaves the risk of the other one.
+        let closed = risk
+            .incremental_var(&[-1e6, 0.0], VarMethod::Historical)
+            .unwrap();
+        let historical = risk.value_at_risk(VarMethod::Historical).unwrap();
+        let remaining = PortfolioRisk::new(&[0.0, 5e5], &returns(2_000, 4))
+            .unwrap()
+            .value_at_risk(VarMethod::Historical)
+            .unwrap();
+        assert_approx_equal!(historical + closed, remaining, 1e-8);
+
+        assert!(risk.incremental_var(&[1.0], VarMethod::Parametric).is_err());
+    }
+}
@@ -0,0 +1,362 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Joint simulation of several processes driven by correlated Brownian
+//! motions, e.g. the assets of a basket, spread or quanto payoff.
+//!
+//! The correlated increments are $dW = B \, dZ$, with $dZ$ independent and
+//! $B B^\top$ the correlation matrix. $B$ is either the Cholesky factor of
+//! the correlation matrix, which must then be positive definite, or is
+//! obtained by clipping its negative eigenvalues to zero and rescaling the
+//! rows to unit length (Rebonato and Jäckel, 1999), which repairs matrices
+//! estimated or stressed into being indefinite.
+
+use crate::error::RustQuantError;
+use crate::stochastics::{StochasticProcess, Trajectories};
+use nalgebra::{DMatrix, DVector};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, StandardNormal};
+use rayon::prelude::*;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Factorization of the correlation matrix into $B B^\top$.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CorrelationFactorization {
+    /// Cholesky factorization, for positive definite matrices.
+    #[default]
+    Cholesky,
+
+    /// Spectral factorization with the negative eigenvalues clipped to zero
+    /// and the rows rescaled to unit length, for any symmetric matrix.
+    EigenvalueClipped,
+}
+
+/// Processes driven by correlated Brownian motions.
+pub struct CorrelatedProcesses {
+    /// The processes, one per Brownian motion.
+    processes: Vec<Box<dyn StochasticProcess>>,
+
+    /// Factor $B$ of the correlation matrix $B B^\top$.
+    factor: DMatrix<f64>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl CorrelatedProcesses {
+    /// Create new correlated processes from the correlation matrix of their
+    /// Brownian motions.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the correlation matrix is not
+    ///   a symmetric matrix with one row per process, a unit diagonal and
+    ///   entries in [-1, 1], or if it is not positive definite for a
+    ///   Cholesky factorization.
+    pub fn new(
+        processes: Vec<Box<dyn StochasticProcess>>,
+        correlation: &DMatrix<f64>,
+        factorization: CorrelationFactorization,
+    ) -> Result<Self, RustQuantError> {
+        let n = processes.len();
+
+        if correlation.shape() != (n, n) {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "expected a {n} x {n} correlation matrix, got {} x {}",
+                correlation.nrows(),
+                correlation.ncols()
+            )));
+        }
+        if (0..n).any(|i| (correlation[(i, i)] - 1.0).abs() > 1e-12)
+            || correlation.iter().any(|rho| !(-1.0..=1.0).contains(rho))
+            || (correlation - correlation.transpose()).amax() > 1e-12
+        {
+            return Err(RustQuantError::InvalidArgument(
+                "a correlation matrix is symmetric, with a unit diagonal and entries in [-1, 1]"
+                    .to_string(),
+            ));
+        }
+
+        let factor = match factorization {
+            CorrelationFactorization::Cholesky => correlation
+                .clone()
+                .cholesky()
+                .map(|cholesky| cholesky.unpack())
+                .ok_or_else(|| {
+                    RustQuantError::InvalidArgument(
+                        "the correlation matrix is not positive definite, \
+                        consider an eigenvalue clipped factorization"
+                            .to_string(),
+                    )
+                })?,
+            CorrelationFactorization::EigenvalueClipped => eigenvalue_clipped_factor(correlation),
+        };
+
+        Ok(Self { processes, factor })
+    }
+
+    /// Number of processes.
+    #[must_use]
+    pub fn dimension(&self) -> usize {
+        self.processes.len()
+    }
+
+    /// Correlation matrix $B B^\top$ of the simulated Brownian motions,
+    /// which differs from the input one if its eigenvalues were clipped.
+    #[must_use]
+    pub fn correlation(&self) -> DMatrix<f64> {
+        &self.factor * self.factor.transpose()
+    }
+
+    /// Euler-Maruyama discretisation scheme, with the paths split across
+    /// threads. Path `i` draws from stream `i` of a `ChaCha8` generator
+    /// seeded with `seed`, so the result is the same for any number of
+    /// threads.
+    ///
+    /// Returns the trajectories of each process, in order.
+    ///
+    /// # Arguments:
+    /// * `x_0` - The processes' initial values at `t_0`.
+    /// * `t_0` - The initial time point.
+    /// * `t_n` - The terminal time point.
+    /// * `n_steps` - The number of time steps between `t_0` and `t_n`.
+    /// * `m_paths` - How many trajectories of each process to simulate.
+    /// * `seed` - The master seed of the random number generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not one initial value per process, or if
+    /// `t_0 >= t_n`.
+    #[must_use]
+    pub fn euler_maruyama(
+        &self,
+        x_0: &[f64],
+        t_0: f64,
+        t_n: f64,
+        n_steps: usize,
+        m_paths: usize,
+        seed: u64,
+    ) -> Vec<Trajectories> {
+        assert_eq!(x_0.len(), self.dimension(), "one initial value per process");
+        assert!(t_0 < t_n);
+
+        let n = self.dimension();
+        let dt: f64 = (t_n - t_0) / (n_steps as f64);
+        let scale = dt.sqrt();
+        let times: Vec<f64> = (0..=n_steps).map(|t| t_0 + dt * (t as f64)).collect();
+
+        // Paths of every process, for each path index.
+        let simulated: Vec<Vec<Vec<f64>>> = (0..m_paths)
+            .into_par_iter()
+            .map(|i| {
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                rng.set_stream(i as u64);
+
+                let mut paths: Vec<Vec<f64>> = x_0
+                    .iter()
+                    .map(|x| {
+                        let mut path = Vec::with_capacity(n_steps + 1);
+                        path.push(*x);
+                        path
+                    })
+                    .collect();
+
+                for t in 0..n_steps {
+                    let z = DVector::from_fn(n, |_, _| StandardNormal.sample(&mut rng));
+                    let dW = &self.factor * z * scale;
+
+                    for (j, (path, process)) in paths.iter_mut().zip(&self.processes).enumerate() {
+                        let x = path[t];
+                        path.push(
+                            x + process.drift(x, times[t]) * dt
+                                + process.diffusion(x, times[t]) * dW[j],
+                        );
+                    }
+                }
+
+                paths
+            })
+            .collect();
+
+        (0..n)
+            .map(|j| Trajectories {
+                times: times.clone(),
+                paths: simulated.iter().map(|paths| paths[j].clone()).collect(),
+            })
+            .collect()
+    }
+}
+
+/// Factor $B$ of the nearest correlation matrix $B B^\top$ obtained by
+/// clipping the negative eigenvalues and rescaling the rows to unit length.
+fn eigenvalue_clipped_factor(correlation: &DMatrix<f64>) -> DMatrix<f64> {
+    principal_factor(correlation, correlation.nrows())
+}
+
+/// Factor $B$, with `n_factors` columns, of the correlation matrix
+/// $B B^\top$ of rank `n_factors` nearest to `correlation`: the principal
+/// components of its largest (non-negative) eigenvalues, with the rows
+/// rescaled to unit length.
+pub(crate) fn principal_factor(correlation: &DMatrix<f64>, n_factors: usize) -> DMatrix<f64> {
+    let eigen = correlation.clone().symmetric_eigen();
+
+    let mut order: Vec<usize> = (0..eigen.eigenvalues.len()).collect();
+    order.sort_by(|&i, &j| eigen.eigenvalues[j].total_cmp(&eigen.eigenvalues[i]));
+    order.truncate(n_factors.max(1));
+
+    let mut factor = DMatrix::from_fn(correlation.nrows(), order.len(), |i, k| {
+        eigen.eigenvectors[(i, order[k])] * eigen.eigenvalues[order[k]].max(0.0).sqrt()
+    });
+    for mut row in factor.row_iter_mut() {
+        let norm = row.norm();
+        if norm > 0.0 {
+            row /= norm;
+        }
+    }
+
+    factor
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_correlated {
+    use super::*;
+    use crate::math::{Distribution as _, Gaussian};
+    use crate::models::{
+        brownian_motion::BrownianMotion, geometric_brownian_motion::GeometricBrownianMotion,
+    };
+
+    #[test]
+    fn test_correlated_brownian_motions() {
+        let correlation =
+            DMatrix::from_row_slice(3, 3, &[1.0, 0.6, -0.3, 0.6, 1.0, 0.2, -0.3, 0.2, 1.0]);
+        let processes: Vec<Box<dyn StochasticProcess>> = vec![
+            Box::new(BrownianMotion::new()),
+            Box::new(BrownianMotion::new()),
+            Box::new(BrownianMotion::new()),
+        ];
+        let correlated =
+            CorrelatedProcesses::new(processes, &correlation, CorrelationFactorization::Cholesky)
+                .unwrap();
+
+        let trajectories = correlated.euler_maruyama(&[0.0; 3], 0.0, 1.0, 4, 50_000, 7);
+        assert_eq!(trajectories.len(), 3);
+
+        // The terminal values have the input correlation.
+        let terminal: Vec<Vec<f64>> = trajectories
+            .iter()
+            .map(|t| t.paths.iter().map(|path| path[4]).collect())
+            .collect();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let covariance = terminal[i]
+                    .iter()
+                    .zip(&terminal[j])
+                    .map(|(x, y)| x * y)
+                    .sum::<f64>()
+                    / 50_000.0;
+                assert!((covariance - correlation[(i, j)]).abs() < 0.02);
+            }
+        }
+
+        // Deterministic for a given seed.
+        let again = correlated.euler_maruyama(&[0.0; 3], 0.0, 1.0, 4, 100, 7);
+        assert_eq!(again[2].paths[..], trajectories[2].paths[..100]);
+    }
+
+    #[test]
+    fn test_eigenvalue_clipping_repairs_indefinite_matrix() {
+        // Pairwise correlations that cannot hold together.
+        let correlation =
+            DMatrix::from_row_slice(3, 3, &[1.0, 0.9, 0.7, 0.9, 1.0, -0.4, 0.7, -0.4, 1.0]);
+        let processes = || -> Vec<Box<dyn StochasticProcess>> {
+            (0..3)
+                .map(|_| Box::new(BrownianMotion::new()) as Box<dyn StochasticProcess>)
+                .collect()
+        };
+
+        assert!(CorrelatedProcesses::new(
+            processes(),
+            &correlation,
+            CorrelationFactorization::Cholesky
+        )
+        .is_err());
+
+        let repaired = CorrelatedProcesses::new(
+            processes(),
+            &correlation,
+            CorrelationFactorization::EigenvalueClipped,
+        )
+        .unwrap()
+        .correlation();
+
+        for i in 0..3 {
+            assert!((repaired[(i, i)] - 1.0).abs() < 1e-12);
+        }
+        assert!(repaired.clone().symmetric_eigen().eigenvalues.min() > -1e-12);
+        assert!((&repaired - &correlation).amax() < 0.35);
+
+        // Invalid matrices are rejected.
+        let asymmetric = DMatrix::from_row_slice(2, 2, &[1.0, 0.5, 0.4, 1.0]);
+        let two: Vec<Box<dyn StochasticProcess>> = processes().into_iter().take(2).collect();
+        assert!(
+            CorrelatedProcesses::new(two, &asymmetric, CorrelationFactorization::default())
+                .is_err()
+        );
+        assert!(CorrelatedProcesses::new(
+            processes(),
+            &DMatrix::identity(2, 2),
+            CorrelationFactorization::default()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_exchange_option_matches_margrabe() {
+        // Exchange option max(S_1 - S_2, 0) under the risk-neutral measure.
+        let (s1, s2, v1, v2, rho, r, t) = (100.0, 95.0, 0.25, 0.2, 0.4, 0.03, 1.0);
+
+        let correlation = DMatrix::from_row_slice(2, 2, &[1.0, rho, rho, 1.0]);
+        let processes: Vec<Box<dyn StochasticProcess>> = vec![
+            Box::new(GeometricBrownianMotion::new(r, v1)),
+            Box::new(GeometricBrownianMotion::new(r, v2)),
+        ];
+        let correlated =
+            CorrelatedProcesses::new(processes, &correlation, CorrelationFactorization::Cholesky)
+                .unwrap();
+
+        let m = 40_000;
+        let trajectories = correlated.euler_maruyama(&[s1, s2], 0.0, t, 50, m, 11);
+        let payoffs: Vec<f64> = trajectories[0]
+            .paths
+            .iter()
+            .zip(&trajectories[1].paths)
+            .map(|(x, y)| (-r * t).exp() * (x[50] - y[50]).max(0.0))
+            .collect();
+
+        let mean = payoffs.iter().sum::<f64>() / m as f64;
+        let variance = payoffs.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / (m - 1) as f64;
+        let standard_error = (variance / m as f64).sqrt();
+
+        let v = (v1 * v1 + v2 * v2 - 2.0 * rho * v1 * v2).sqrt();
+        let d1 = ((s1 / s2).ln() + 0.5 * v * v * t) / (v * t.sqrt());
+        let n = Gaussian::default();
+        let margrabe = s1 * n.cdf(d1) - s2 * n.cdf(d1 - v * t.sqrt());
+
+        assert!((mean - margrabe).abs() < 4.0 * standard_error);
+    }
+}
@@ -7,18 +7,590 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+//! Simulation of the Heston model:
+//!
+//! $$
+//! dS_t = \mu S_t dt + \sqrt{v_t} S_t dW_t, \qquad
+//! dv_t = \kappa (\theta - v_t) dt + \sigma \sqrt{v_t} dZ_t, \qquad
+//! dW_t dZ_t = \rho dt
+//! $$
+//!
+//! The Euler scheme of the variance goes negative whenever the Feller
+//! condition $2 \kappa \theta \geq \sigma^2$ fails, and truncating it at zero
+//! biases the prices unless the time steps are very small. The schemes of
+//! [`HestonScheme`] avoid this bias:
+//!
+//! - Andersen's (2008) quadratic-exponential (QE) scheme matches the first two
+//!   moments of the variance over each step with a quadratic of a normal draw
+//!   when the variance is far from zero, and with a mixture of a mass at zero
+//!   and an exponential tail when it is close to zero. The spot step has a
+//!   martingale correction, so that the discounted spot is an exact martingale.
+//! - The Broadie-Kaya (2006) exact scheme samples the variance from its
+//!   noncentral chi-squared transition law, then the time integral of the
+//!   variance over the step by inverting its characteristic function
+//!   conditional on the end points, and the log-spot is then Gaussian. It has
+//!   no discretisation bias at any step size, but is much slower per step.
+//!
+//! The model parameters are evaluated at the start of each time step.
+
+use crate::math::distributions::{Distribution as _, NoncentralChiSquared};
 use crate::{models::Heston, stochastics::StochasticProcess};
+use num::Complex;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::StandardNormal;
+use rayon::prelude::*;
+use std::f64::consts::PI;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Discretisation scheme of a Heston simulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HestonScheme {
+    /// Log-Euler scheme with full truncation: the variance may go negative,
+    /// and its positive part drives the drift and the diffusion.
+    Euler,
+
+    /// Andersen's quadratic-exponential scheme, with martingale correction.
+    #[default]
+    QuadraticExponential,
+
+    /// Broadie-Kaya exact simulation.
+    BroadieKaya,
+}
+
+/// Settings of a Heston simulation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HestonSimulationConfig {
+    /// Initial spot.
+    pub spot: f64,
+
+    /// Drift of the spot $\mu$ (e.g. $r - q$ under the risk neutral measure).
+    pub drift: f64,
+
+    /// Time horizon (in years).
+    pub maturity: f64,
+
+    /// Number of time steps.
+    pub n_steps: usize,
+
+    /// Number of paths.
+    pub m_paths: usize,
+
+    /// Master seed of the random number generator; path `i` draws from
+    /// stream `i` of a `ChaCha8` generator.
+    pub seed: u64,
+
+    /// Discretisation scheme.
+    pub scheme: HestonScheme,
+}
 
+/// Spot and variance paths of a Heston simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HestonTrajectories {
+    /// Time points.
+    pub times: Vec<f64>,
+
+    /// Spot paths.
+    pub spots: Vec<Vec<f64>>,
+
+    /// Variance paths.
+    pub variances: Vec<Vec<f64>>,
+}
+
+/// Parameters of the Heston model over one time step.
+#[derive(Debug, Clone, Copy)]
+struct StepParameters {
+    kappa: f64,
+    theta: f64,
+    sigma: f64,
+    rho: f64,
+    dt: f64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Switching level of the QE scheme between its quadratic and exponential
+/// approximations, on the squared coefficient of variation of the variance.
+const QE_CRITICAL_PSI: f64 = 1.5;
+
+/// The variance process $dv_t = \kappa (\theta - v_t) dt + \sigma \sqrt{v_t} dZ_t$.
+/// See [`Heston::simulate`] for the joint simulation of the spot and variance.
 impl StochasticProcess for Heston {
-    fn drift(&self, _x: f64, _t: f64) -> f64 {
-        todo!()
+    fn drift(&self, x: f64, t: f64) -> f64 {
+        self.mean_reversion_rate.0(t) * (self.long_run_variance.0(t) - x)
     }
 
-    fn diffusion(&self, _x: f64, _t: f64) -> f64 {
-        todo!()
+    fn diffusion(&self, x: f64, t: f64) -> f64 {
+        self.volatility_of_volatility.0(t) * x.max(0.0).sqrt()
     }
 
     fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
-        todo!()
+        None
+    }
+}
+
+impl HestonSimulationConfig {
+    /// Create a new simulation config with the QE scheme and seed zero.
+    #[must_use]
+    pub fn new(spot: f64, drift: f64, maturity: f64, n_steps: usize, m_paths: usize) -> Self {
+        Self {
+            spot,
+            drift,
+            maturity,
+            n_steps,
+            m_paths,
+            seed: 0,
+            scheme: HestonScheme::default(),
+        }
+    }
+
+    /// Simulate with the given scheme.
+    #[must_use]
+    pub fn with_scheme(mut self, scheme: HestonScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Simulate from the given seed.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+impl Heston {
+    /// Simulate the spot and the variance, with the paths split across
+    /// threads. The result only depends on the config, not on the number of
+    /// threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the maturity or the number of steps is not positive.
+    #[must_use]
+    pub fn simulate(&self, config: &HestonSimulationConfig) -> HestonTrajectories {
+        assert!(config.maturity > 0.0 && config.n_steps > 0);
+
+        let n_steps = config.n_steps;
+        let dt = config.maturity / n_steps as f64;
+        let times: Vec<f64> = (0..=n_steps).map(|t| dt * t as f64).collect();
+
+        let parameters: Vec<StepParameters> = times[..n_steps]
+            .iter()
+            .map(|t| StepParameters {
+                kappa: self.mean_reversion_rate.0(*t),
+                theta: self.long_run_variance.0(*t),
+                sigma: self.volatility_of_volatility.0(*t),
+                rho: self.correlation.0(*t),
+                dt,
+            })
+            .collect();
+        let v_0 = self.initial_variance.0(0.0);
+
+        let (spots, variances) = (0..config.m_paths)
+            .into_par_iter()
+            .map(|i| {
+                let mut rng = ChaCha8Rng::seed_from_u64(config.seed);
+                rng.set_stream(i as u64);
+
+                let mut log_spot = config.spot.ln();
+                let mut v = v_0;
+                let mut spots = Vec::with_capacity(n_steps + 1);
+                let mut variances = Vec::with_capacity(n_steps + 1);
+                spots.push(config.spot);
+                variances.push(v);
+
+                for p in &parameters {
+                    let (log_return, next) = match config.scheme {
+                        HestonScheme::Euler => p.euler_step(v, &mut rng),
+                        HestonScheme::QuadraticExponential => {
+                            p.quadratic_exponential_step(v, &mut rng)
+                        }
+                        HestonScheme::BroadieKaya => p.broadie_kaya_step(v, &mut rng),
+                    };
+
+                    log_spot += config.drift * dt + log_return;
+                    v = next;
+                    spots.push(log_spot.exp());
+                    variances.push(v);
+                }
+
+                (spots, variances)
+            })
+            .unzip();
+
+        HestonTrajectories {
+            times,
+            spots,
+            variances,
+        }
+    }
+}
+
+impl StepParameters {
+    /// Full truncation Euler step: the log-return (without the drift) and
+    /// the next variance.
+    fn euler_step<R: Rng>(&self, v: f64, rng: &mut R) -> (f64, f64) {
+        let positive = v.max(0.0);
+        let z_v: f64 = rng.sample(StandardNormal);
+        let z: f64 = rng.sample(StandardNormal);
+        let z_s = self.rho * z_v + (1.0 - self.rho * self.rho).sqrt() * z;
+
+        let next = v
+            + self.kappa * (self.theta - positive) * self.dt
+            + self.sigma * (positive * self.dt).sqrt() * z_v;
+
+        (
+            -0.5 * positive * self.dt + (positive * self.dt).sqrt() * z_s,
+            next,
+        )
+    }
+
+    /// QE step with martingale correction: the log-return (without the
+    /// drift) and the next variance.
+    fn quadratic_exponential_step<R: Rng>(&self, v: f64, rng: &mut R) -> (f64, f64) {
+        let Self {
+            kappa,
+            theta,
+            sigma,
+            rho,
+            dt,
+        } = *self;
+        let decay = (-kappa * dt).exp();
+
+        // Conditional mean and variance of the next variance.
+        let m = theta + (v - theta) * decay;
+        let s2 = v * sigma * sigma * decay * (1.0 - decay) / kappa
+            + theta * sigma * sigma * (1.0 - decay).powi(2) / (2.0 * kappa);
+        let psi = s2 / (m * m);
+
+        // Central discretisation of the time integral of the variance.
+        let k_1 = 0.5 * dt * (kappa * rho / sigma - 0.5) - rho / sigma;
+        let k_2 = 0.5 * dt * (kappa * rho / sigma - 0.5) + rho / sigma;
+        let k_3 = 0.5 * dt * (1.0 - rho * rho);
+        let a = k_2 + 0.5 * k_3;
+
+        let (next, log_mgf) = if psi <= QE_CRITICAL_PSI {
+            let b2 = 2.0 / psi - 1.0 + (2.0 / psi).sqrt() * (2.0 / psi - 1.0).sqrt();
+            let scale = m / (1.0 + b2);
+            let z: f64 = rng.sample(StandardNormal);
+
+            (
+                scale * (b2.sqrt() + z).powi(2),
+                (1.0 - 2.0 * a * scale > 0.0).then(|| {
+                    a * b2 * scale / (1.0 - 2.0 * a * scale) - 0.5 * (1.0 - 2.0 * a * scale).ln()
+                }),
+            )
+        } else {
+            let p = (psi - 1.0) / (psi + 1.0);
+            let beta = (1.0 - p) / m;
+            let u: f64 = rng.gen();
+            let next = if u <= p {
+                0.0
+            } else {
+                ((1.0 - p) / (1.0 - u)).ln() / beta
+            };
+
+            (
+                next,
+                (a < beta).then(|| (p + beta * (1.0 - p) / (beta - a)).ln()),
+            )
+        };
+
+        // Martingale correction, where the moment generating function exists.
+        let k_0 = log_mgf.map_or(-rho * kappa * theta * dt / sigma, |log_mgf| {
+            -log_mgf - (k_1 + 0.5 * k_3) * v
+        });
+
+        let z: f64 = rng.sample(StandardNormal);
+
+        (
+            k_0 + k_1 * v + k_2 * next + (k_3 * (v + next)).sqrt() * z,
+            next,
+        )
+    }
+
+    /// Broadie-Kaya exact step: the log-return (without the drift) and the
+    /// next variance.
+    fn broadie_kaya_step<R: Rng>(&self, v: f64, rng: &mut R) -> (f64, f64) {
+        let Self {
+            kappa,
+            theta,
+            sigma,
+            rho,
+            dt,
+        } = *self;
+
+        let decay = (-kappa * dt).exp();
+        let c = sigma * sigma * (1.0 - decay) / (4.0 * kappa);
+        let degrees = 4.0 * kappa * theta / (sigma * sigma);
+        let next = c * NoncentralChiSquared::new(degrees, v * decay / c).sample_with_rng(rng);
+
+        let integrated = self.sample_integrated_variance(v, next, rng);
+        let z: f64 = rng.sample(StandardNormal);
+
+        (
+            rho / sigma * (next - v - kappa * theta * dt)
+                + (kappa * rho / sigma - 0.5) * integrated
+                + ((1.0 - rho * rho) * integrated).sqrt() * z,
+            next,
+        )
+    }
+
+    /// Characteristic function of $\int_t^{t + \Delta t} v_s ds$ conditional
+    /// on $v_t$ and $v_{t + \Delta t}$ (Broadie and Kaya, 2006, eq. 13).
+    fn integrated_variance_cf(&self, a: f64, v: f64, next: f64) -> Complex<f64> {
+        let Self {
+            kappa,
+            theta,
+            sigma,
+            dt,
+            ..
+        } = *self;
+        let nu = 2.0 * kappa * theta / (sigma * sigma) - 1.0;
+        let s2 = sigma * sigma;
+
+        let gamma = (Complex::new(kappa * kappa, -2.0 * s2 * a)).sqrt();
+        let k = Complex::new(kappa, 0.0);
+
+        // ln(1 - e^{-g dt}) has a positive real part, so the principal
+        // logarithms are continuous in `a`.
+        let one_minus = |g: Complex<f64>| 1.0 - (-g * dt).exp();
+        let coth = |g: Complex<f64>| (2.0 - one_minus(g)) / one_minus(g);
+        let log_argument = |g: Complex<f64>| (4.0 * g / s2).ln() - g * dt / 2.0 - one_minus(g).ln();
+
+        let first = gamma * (-(gamma - k) * dt / 2.0).exp() * one_minus(k) / (k * one_minus(gamma));
+        let second = ((v + next) / s2 * (k * coth(k) - gamma * coth(gamma))).exp();
+
+        // Ratio of the modified Bessel functions I_nu, by their power series.
+        let (log_gamma, log_kappa) = (log_argument(gamma), log_argument(k));
+        let (sum_gamma, scale_gamma) = bessel_series((2.0 * log_gamma).exp() * v * next / 4.0, nu);
+        let (sum_kappa, scale_kappa) = bessel_series((2.0 * log_kappa).exp() * v * next / 4.0, nu);
+
+        let ratio = (nu * (log_gamma - log_kappa) + (scale_gamma - scale_kappa)).exp() * sum_gamma
+            / sum_kappa;
+
+        first * second * ratio
+    }
+
+    /// Sample $\int_t^{t + \Delta t} v_s ds$ conditional on the end points,
+    /// by inverting its distribution function, which follows from the
+    /// characteristic function by the trapezoidal rule.
+    fn sample_integrated_variance<R: Rng>(&self, v: f64, next: f64, rng: &mut R) -> f64 {
+        let cf = |a: f64| self.integrated_variance_cf(a, v, next);
+
+        // Mean and variance from the log characteristic function near zero.
+        let guess = (0.5 * (v + next)).max(self.theta) * self.dt;
+        let h = 1e-2 / guess;
+        let log_cf = cf(h).ln();
+        let mean = log_cf.im / h;
+        let variance = (-2.0 * log_cf.re / (h * h)).max(0.0);
+
+        let upper = mean + 12.0 * variance.sqrt().max(1e-3 * mean);
+        let step = 2.0 * PI / upper;
+
+        let mut terms = Vec::new();
+        for j in 1..=10_000 {
+            let phi = cf(step * j as f64);
+            terms.push(phi.re / j as f64);
+
+            if phi.norm() / (j as f64) < 1e-6 {
+                break;
+            }
+        }
+
+        let cdf = |x: f64| {
+            step * x / PI
+                + 2.0 / PI
+                    * terms
+                        .iter()
+                        .enumerate()
+                        .map(|(j, term)| (step * (j + 1) as f64 * x).sin() * term)
+                        .sum::<f64>()
+        };
+
+        let u: f64 = rng.gen();
+        let (mut low, mut high) = (0.0, upper);
+        for _ in 0..50 {
+            let middle = 0.5 * (low + high);
+
+            if cdf(middle) < u {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        0.5 * (low + high)
+    }
+}
+
+/// Power series $\sum_k (z^2/4)^k / (k! (\nu + 1)_k)$, so that
+/// $I_\nu(z) = (z/2)^\nu / \Gamma(\nu + 1)$ times the series, as a sum and
+/// the logarithm of a factor it was scaled down by to avoid overflow.
+fn bessel_series(quarter_square: Complex<f64>, nu: f64) -> (Complex<f64>, f64) {
+    let magnitude = quarter_square.norm();
+
+    let mut term = Complex::new(1.0, 0.0);
+    let mut sum = term;
+    let mut log_scale = 0.0;
+
+    for k in 1..100_000 {
+        let k = k as f64;
+        term *= quarter_square / (k * (k + nu));
+        sum += term;
+
+        if sum.norm() > 1e200 {
+            term /= 1e200;
+            sum /= 1e200;
+            log_scale += 200.0 * std::f64::consts::LN_10;
+        }
+        if k * k > magnitude && term.norm() < 1e-16 * sum.norm() {
+            break;
+        }
+    }
+
+    (sum, log_scale)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_heston {
+    use super::*;
+    use crate::instruments::{HestonParameters, TypeFlag};
+
+    // The Feller condition 2 kappa theta >= sigma^2 fails.
+    const PARAMETERS: (f64, f64, f64, f64, f64) = (0.04, 0.04, 1.5, -0.7, 0.6);
+
+    fn heston() -> Heston {
+        let (v_0, theta, kappa, rho, sigma) = PARAMETERS;
+        Heston::new(v_0, theta, kappa, rho, sigma)
+    }
+
+    // Discounted call prices and their standard errors.
+    fn call_prices(paths: &HestonTrajectories, strikes: &[f64], rate: f64) -> Vec<(f64, f64)> {
+        let n = paths.spots.len() as f64;
+        let discount = (-rate * paths.times[paths.times.len() - 1]).exp();
+
+        strikes
+            .iter()
+            .map(|strike| {
+                let payoffs: Vec<f64> = paths
+                    .spots
+                    .iter()
+                    .map(|path| discount * (path[path.len() - 1] - strike).max(0.0))
+                    .collect();
+                let mean = payoffs.iter().sum::<f64>() / n;
+                let variance = payoffs.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / (n - 1.0);
+
+                (mean, (variance / n).sqrt())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_quadratic_exponential_scheme() {
+        let (v_0, theta, kappa, rho, sigma) = PARAMETERS;
+        let cos = HestonParameters::new(v_0, theta, kappa, rho, sigma);
+        let strikes = [80.0, 100.0, 120.0];
+
+        // Coarse monthly steps.
+        let config = HestonSimulationConfig::new(100.0, 0.03, 1.0, 12, 40_000).with_seed(3);
+        let qe = heston().simulate(&config);
+        let euler = heston().simulate(&config.with_scheme(HestonScheme::Euler));
+
+        // The QE variances stay non-negative, unlike the Euler ones.
+        assert!(qe.variances.iter().flatten().all(|v| *v >= 0.0));
+        assert!(euler.variances.iter().flatten().any(|v| *v < 0.0));
+
+        // The discounted spot is a martingale.
+        let (forward, error) = call_prices(&qe, &[0.0], 0.03)[0];
+        assert!((forward - 100.0).abs() < 4.0 * error);
+
+        let mut qe_error = 0.0;
+        let mut euler_error = 0.0;
+        for ((strike, qe), euler) in strikes
+            .iter()
+            .zip(call_prices(&qe, &strikes, 0.03))
+            .zip(call_prices(&euler, &strikes, 0.03))
+        {
+            let price = cos.price(100.0, *strike, 0.03, 0.0, 1.0, TypeFlag::Call);
+
+            assert!((qe.0 - price).abs() < 4.0 * qe.1 + 0.02);
+            qe_error += (qe.0 - price).abs();
+            euler_error += (euler.0 - price).abs();
+        }
+        assert!(qe_error < euler_error);
+
+        // The same seed gives the same paths.
+        assert_eq!(qe, heston().simulate(&config));
+    }
+
+    #[test]
+    fn test_broadie_kaya_scheme() {
+        let (v_0, theta, kappa, rho, sigma) = PARAMETERS;
+        let cos = HestonParameters::new(v_0, theta, kappa, rho, sigma);
+        let strikes = [80.0, 100.0, 120.0];
+
+        // Two steps: exact simulation has no discretisation bias.
+        let config = HestonSimulationConfig::new(100.0, 0.03, 1.0, 2, 5_000)
+            .with_scheme(HestonScheme::BroadieKaya)
+            .with_seed(5);
+        let paths = heston().simulate(&config);
+
+        assert!(paths.variances.iter().flatten().all(|v| *v >= 0.0));
+
+        for (strike, (mean, error)) in strikes.iter().zip(call_prices(&paths, &strikes, 0.03)) {
+            let price = cos.price(100.0, *strike, 0.03, 0.0, 1.0, TypeFlag::Call);
+
+            assert!((mean - price).abs() < 4.0 * error);
+        }
+    }
+
+    #[test]
+    fn test_integrated_variance_distribution() {
+        let (_, theta, kappa, rho, sigma) = PARAMETERS;
+        let step = StepParameters {
+            kappa,
+            theta,
+            sigma,
+            rho,
+            dt: 0.5,
+        };
+
+        // The characteristic function is one at zero, and its derivative is
+        // i times the mean, which lies between the end points times dt.
+        let (v, next) = (0.03, 0.05);
+        let one = step.integrated_variance_cf(0.0, v, next);
+        assert!((one - Complex::new(1.0, 0.0)).norm() < 1e-12);
+
+        let h = 1e-3;
+        let mean = (step.integrated_variance_cf(h, v, next).ln() / h).im;
+        assert!(mean > 0.0 && mean < 0.05 * 0.5);
+
+        // The sampled integrals have that mean.
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let n = 4_000;
+        let samples: Vec<f64> = (0..n)
+            .map(|_| step.sample_integrated_variance(v, next, &mut rng))
+            .collect();
+        let sample_mean = samples.iter().sum::<f64>() / n as f64;
+        let sample_error = (samples
+            .iter()
+            .map(|x| (x - sample_mean).powi(2))
+            .sum::<f64>()
+            / (n * n) as f64)
+            .sqrt();
+
+        assert!(samples.iter().all(|x| *x > 0.0));
+        assert!((sample_mean - mean).abs() < 4.0 * sample_error);
     }
 }
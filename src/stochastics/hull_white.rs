@@ -7,7 +7,11 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use crate::{models::hull_white::HullWhite, stochastics::process::StochasticProcess};
+use crate::math::interpolation::Interpolator;
+use crate::models::{hull_white::HullWhite, hull_white_one_factor::HullWhiteOneFactor};
+use crate::stochastics::process::{StochasticProcess, Trajectories};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand_distr::StandardNormal;
 
 impl StochasticProcess for HullWhite {
     fn drift(&self, x: f64, t: f64) -> f64 {
@@ -23,6 +27,80 @@ impl StochasticProcess for HullWhite {
     }
 }
 
+impl<I: Interpolator<f64, f64> + Default + Sync> StochasticProcess for HullWhiteOneFactor<I> {
+    fn drift(&self, x: f64, t: f64) -> f64 {
+        self.theta(t) - self.mean_reversion * x
+    }
+
+    fn diffusion(&self, _x: f64, _t: f64) -> f64 {
+        self.volatility
+    }
+
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
+    }
+}
+
+impl<I: Interpolator<f64, f64> + Default> HullWhiteOneFactor<I> {
+    /// Exact simulation of the short rate, from the Gaussian transitions of
+    /// $x_t = r_t - \alpha(t)$:
+    ///
+    /// $$
+    /// x_{t + \Delta t} = x_t e^{-a \Delta t} +
+    /// \sigma \sqrt{\frac{1 - e^{-2 a \Delta t}}{2 a}} Z
+    /// $$
+    ///
+    /// Starting from $r_0 = f(0, 0)$, the simulated rates reprice the curve.
+    ///
+    /// # Arguments:
+    /// * `x_0` - The short rate at `t_0`.
+    /// * `t_0` - The initial time point.
+    /// * `t_n` - The terminal time point.
+    /// * `n_steps` - The number of time steps between `t_0` and `t_n`.
+    /// * `m_paths` - How many process trajectories to simulate.
+    /// * `seed` - The seed for the random number generator.
+    #[must_use]
+    pub fn exact_simulation(
+        &self,
+        x_0: f64,
+        t_0: f64,
+        t_n: f64,
+        n_steps: usize,
+        m_paths: usize,
+        seed: u64,
+    ) -> Trajectories {
+        assert!(t_0 < t_n);
+
+        let a = self.mean_reversion;
+        let dt: f64 = (t_n - t_0) / (n_steps as f64);
+        let times: Vec<f64> = (0..=n_steps).map(|t| t_0 + dt * (t as f64)).collect();
+        let shifts: Vec<f64> = times.iter().map(|t| self.shift(*t)).collect();
+
+        let decay = (-a * dt).exp();
+        let std_dev = self.volatility * ((1.0 - decay * decay) / (2.0 * a)).sqrt();
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let paths = (0..m_paths)
+            .map(|_| {
+                let mut x = x_0 - shifts[0];
+
+                shifts
+                    .iter()
+                    .enumerate()
+                    .map(|(t, shift)| {
+                        if t > 0 {
+                            x = x * decay + std_dev * rng.sample::<f64, _>(StandardNormal);
+                        }
+                        x + shift
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Trajectories { times, paths }
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // TESTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -30,7 +108,10 @@ impl StochasticProcess for HullWhite {
 #[cfg(test)]
 mod tests_hull_white {
     use super::*;
+    use crate::data::YieldCurve;
+    use crate::instruments::TypeFlag;
     use crate::{assert_approx_equal, math::*};
+    use time::macros::date;
 
     // fn theta_t(_t: f64) -> f64 {
     //     0.5
@@ -64,4 +145,50 @@ mod tests_hull_white {
         // No closed form solution for variance that I know of...
         // Have to take it on faith that it works
     }
+
+    #[test]
+    fn test_hull_white_one_factor_reprices_the_curve() {
+        let curve = YieldCurve::from_dates_and_rates(
+            &[
+                date!(2024 - 03 - 20),
+                date!(2026 - 03 - 20),
+                date!(2034 - 03 - 20),
+            ],
+            &[0.03, 0.04, 0.042],
+        );
+        let hw = HullWhiteOneFactor::new(0.1, 0.01, curve);
+
+        // Two years of the short rate, from its initial value.
+        let n_paths = 20_000;
+        let r_0 = hw.instantaneous_forward(0.0);
+        let output = hw.exact_simulation(r_0, 0.0, 2.0, 200, n_paths, 42);
+
+        // Discount factors to one and two years, by the trapezoidal rule,
+        // and the one year call on the three year bond.
+        let (mut bonds, mut calls) = (Vec::new(), Vec::new());
+        for path in &output.paths {
+            let integral = |n: usize| {
+                path[..=n]
+                    .windows(2)
+                    .map(|r| (r[0] + r[1]) / 2.0)
+                    .sum::<f64>()
+                    * 0.01
+            };
+            let bond = hw.zero_coupon_bond(1.0, 3.0, path[100]);
+
+            bonds.push((-integral(200)).exp());
+            calls.push((-integral(100)).exp() * (bond - 0.95).max(0.0));
+        }
+
+        let tolerance =
+            |samples: &Vec<f64>| 4.0 * samples.standard_deviation() / (n_paths as f64).sqrt();
+        assert!((bonds.mean() - hw.discount_factor(2.0)).abs() < tolerance(&bonds) + 1e-5);
+        assert!(
+            (calls.mean() - hw.bond_option(TypeFlag::Call, 0.95, 1.0, 3.0)).abs()
+                < tolerance(&calls) + 1e-5
+        );
+
+        // The Euler scheme of the process has the same initial slope.
+        assert_approx_equal!(hw.drift(r_0, 0.0), hw.theta(0.0) - 0.1 * r_0, 1e-15);
+    }
 }
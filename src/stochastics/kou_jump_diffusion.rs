@@ -0,0 +1,150 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::models::kou_jump_diffusion::KouJumpDiffusion;
+use crate::stochastics::process::{ExactLogIncrements, StochasticProcess};
+use rand::{Rng, RngCore};
+use rand_distr::{Exp, Poisson, StandardNormal};
+
+/// Geometric Brownian motion with double exponential jumps, which come from
+/// its exact scheme.
+impl StochasticProcess for KouJumpDiffusion {
+    fn drift(&self, x: f64, _t: f64) -> f64 {
+        self.mu * x
+    }
+
+    fn diffusion(&self, x: f64, _t: f64) -> f64 {
+        self.sigma * x
+    }
+
+    /// The jumps are sampled by the exact scheme, with the simulation's
+    /// random number generator.
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
+    }
+
+    fn exact_scheme(&self) -> Option<&dyn ExactLogIncrements> {
+        Some(self)
+    }
+}
+
+/// Exact simulation of the Kou jump diffusion, by sampling the number of
+/// jumps over each time step and their double exponential sizes:
+///
+/// $$
+/// \ln \frac{S_{t + \Delta t}}{S_t} = (\mu + \omega) \Delta t + \sigma \sqrt{\Delta t} Z +
+/// \sum_{k=1}^{N} Y_k, \quad N \sim \text{Poisson}(\lambda \Delta t)
+/// $$
+impl ExactLogIncrements for KouJumpDiffusion {
+    fn sample_log_increment(&self, rng: &mut dyn RngCore, dt: f64) -> f64 {
+        if dt == 0.0 {
+            return 0.0;
+        }
+
+        let z: f64 = rng.sample(StandardNormal);
+        let jumps = if self.lambda > 0.0 {
+            let poisson = Poisson::new(self.lambda * dt).expect("positive intensity");
+            let n: f64 = rng.sample(poisson);
+            (0..n as usize).map(|_| self.jump_size(rng)).sum()
+        } else {
+            0.0
+        };
+
+        (self.mu + self.martingale_correction()) * dt + self.sigma * dt.sqrt() * z + jumps
+    }
+}
+
+impl KouJumpDiffusion {
+    /// A double exponential jump of the log-price.
+    fn jump_size(&self, rng: &mut dyn RngCore) -> f64 {
+        if rng.gen::<f64>() < self.p {
+            rng.sample(Exp::new(self.eta_up).expect("positive rate"))
+        } else {
+            -rng.sample(Exp::new(self.eta_down).expect("positive rate"))
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_kou_jump_diffusion {
+    use super::*;
+    use crate::instruments::{CharacteristicFunction, CosMethod, FourierPricer, TypeFlag};
+    use num::Complex;
+
+    #[test]
+    fn test_exact_simulation_prices_match_fourier() {
+        let (spot, r, t) = (100.0, 0.05, 1.0);
+        let model = KouJumpDiffusion::new(0.05, 0.15, 1.5, 0.35, 10.0, 5.0);
+
+        let n_paths = 50_000;
+        let output = model.exact_simulation(spot, 0.0, t, 4, n_paths, 42);
+        let terminal: Vec<f64> = output.paths.iter().map(|path| path[4]).collect();
+
+        // The discounted price is a martingale.
+        let mean = terminal.iter().sum::<f64>() / n_paths as f64;
+        let std_dev = (terminal.iter().map(|s| (s - mean).powi(2)).sum::<f64>()
+            / (n_paths - 1) as f64)
+            .sqrt();
+        assert!((mean - spot * (r * t).exp()).abs() < 4.0 * std_dev / (n_paths as f64).sqrt());
+
+        // Calls priced by Monte Carlo and by the COS method agree.
+        for strike in [80.0, 100.0, 120.0] {
+            let payoffs: Vec<f64> = terminal
+                .iter()
+                .map(|s| (-r * t).exp() * (s - strike).max(0.0))
+                .collect();
+            let price = payoffs.iter().sum::<f64>() / n_paths as f64;
+            let error = (payoffs.iter().map(|p| (p - price).powi(2)).sum::<f64>()
+                / (n_paths - 1) as f64
+                / n_paths as f64)
+                .sqrt();
+
+            let cos = CosMethod::default().price(&model, spot, strike, r, 0.0, t, TypeFlag::Call);
+            assert!((price - cos).abs() < 4.0 * error);
+        }
+
+        // The same seed gives the same paths.
+        let again = model.seedable_euler_maruyama(spot, 0.0, t, 4, 10, false, 42);
+        assert_eq!(again.paths[..], output.paths[..10]);
+    }
+
+    #[test]
+    fn test_characteristic_function() {
+        let model = KouJumpDiffusion::new(0.05, 0.15, 1.5, 0.35, 10.0, 5.0);
+        let one = Complex::new(1.0, 0.0);
+
+        // phi(0) = 1, and phi(-i) = E[S_t / F] = 1.
+        assert_approx_equal!(
+            (model.characteristic_function(Complex::new(0.0, 0.0), 0.5) - one).norm(),
+            0.0,
+            1e-14
+        );
+        assert_approx_equal!(
+            (model.characteristic_function(-Complex::i(), 0.5) - one).norm(),
+            0.0,
+            1e-12
+        );
+
+        // The closed-form cumulants agree with those of the characteristic
+        // function.
+        let log_phi = |u: f64| {
+            model
+                .characteristic_function(Complex::new(u, 0.0), 0.5)
+                .ln()
+        };
+        let h = 1e-4;
+        let (c1, c2, _) = model.cumulants(0.5);
+        assert_approx_equal!(c1, (log_phi(h) - log_phi(-h)).im / (2.0 * h), 1e-6);
+        assert_approx_equal!(c2, -(log_phi(h) + log_phi(-h)).re / (h * h), 1e-5);
+    }
+}
@@ -0,0 +1,772 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! The LIBOR (lognormal forward) market model.
+//!
+//! On the tenor dates $0 = T_0 < T_1 < \dots < T_n$, the simple forward rate
+//! $F_i$ of the period $[T_i, T_{i+1}]$, with accrual $\tau_i$, fixes at
+//! $T_i$ and follows
+//!
+//! $$
+//! \frac{dF_i}{F_i} = \mu_i(t) dt + \sigma_i(t) dW_i, \quad
+//! dW_i dW_j = \rho_{ij} dt
+//! $$
+//!
+//! with piecewise-constant volatilities over the periods. The drift depends
+//! on the numeraire. Under the spot LIBOR measure (the bank account rolled
+//! over at each tenor date), with $q(t)$ the first forward still to fix,
+//!
+//! $$
+//! \mu_i = \sigma_i \sum_{j = q(t)}^{i} \frac{\tau_j \rho_{ij} \sigma_j F_j}{1 + \tau_j F_j}
+//! $$
+//!
+//! and under the terminal measure (the bond maturing at $T_n$),
+//!
+//! $$
+//! \mu_i = -\sigma_i \sum_{j = i + 1}^{n - 1} \frac{\tau_j \rho_{ij} \sigma_j F_j}{1 + \tau_j F_j}
+//! $$
+//!
+//! The forwards are simulated by log-Euler steps with a predictor-corrector
+//! drift, driven by the principal factors of their correlation matrix. The
+//! simulated paths carry the deflator $N_0 / N_{T_m}$ of each tenor date, so
+//! that a cash flow $X$ paid at $T_m$ is worth $\mathbb{E}[X N_0 / N_{T_m}]$,
+//! e.g. to price Bermudan swaptions by least squares Monte Carlo.
+
+use crate::error::RustQuantError;
+use crate::instruments::options::longstaff_schwartz::fitted_values;
+use crate::instruments::swaps::SwapDirection;
+use crate::instruments::PricingResult;
+use crate::stochastics::correlated::principal_factor;
+use nalgebra::DMatrix;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, StandardNormal};
+use rayon::prelude::*;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Numeraire of the measure the forward rates are simulated under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LiborMeasure {
+    /// Spot LIBOR measure: the bank account rolled over at each tenor date.
+    #[default]
+    Spot,
+
+    /// Terminal measure: the zero-coupon bond maturing at the last tenor date.
+    Terminal,
+}
+
+/// Piecewise-constant instantaneous volatilities of the forward rates, over
+/// the periods between tenor dates.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForwardVolatility {
+    /// Constant volatility of each forward rate.
+    Constant(Vec<f64>),
+
+    /// Volatilities depending only on the number of periods to the fixing:
+    /// the `k`-th entry applies to a forward rate `k + 1` periods before it
+    /// fixes.
+    TimeHomogeneous(Vec<f64>),
+
+    /// Volatility of forward rate `i` over period `m`, in row `i` and column
+    /// `m`, for each period `m < i` before it fixes.
+    PiecewiseConstant(Vec<Vec<f64>>),
+}
+
+/// Correlation of the forward rates, by their fixing times.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForwardCorrelation {
+    /// $\rho_{ij} = e^{-\beta |T_i - T_j|}$.
+    Exponential {
+        /// Decay $\beta \geq 0$.
+        decay: f64,
+    },
+
+    /// Rebonato's $\rho_{ij} = \rho_\infty + (1 - \rho_\infty) e^{-\beta |T_i - T_j|}$.
+    Rebonato {
+        /// Long term correlation $\rho_\infty \in [0, 1]$.
+        long_term: f64,
+
+        /// Decay $\beta \geq 0$.
+        decay: f64,
+    },
+
+    /// Correlation matrix, with one row per forward rate.
+    Matrix(DMatrix<f64>),
+}
+
+/// LIBOR market model of the forward rates between tenor dates.
+#[derive(Debug, Clone)]
+pub struct LiborMarketModel {
+    /// Tenor dates (in years), starting today.
+    tenors: Vec<f64>,
+
+    /// Forward rates today.
+    initial_forwards: Vec<f64>,
+
+    /// Volatility of each forward rate over each period before it fixes.
+    volatilities: Vec<Vec<f64>>,
+
+    /// Correlation matrix of the forward rates.
+    correlation: DMatrix<f64>,
+
+    /// Number of Brownian factors.
+    n_factors: usize,
+
+    /// Measure to simulate under.
+    measure: LiborMeasure,
+
+    /// Time steps per period.
+    steps_per_period: usize,
+}
+
+/// Forward rates simulated by a [`LiborMarketModel`], at the tenor dates.
+#[derive(Debug, Clone)]
+pub struct LiborMarketModelPaths {
+    /// Tenor dates.
+    pub times: Vec<f64>,
+
+    /// Accrual factors of the periods.
+    pub accruals: Vec<f64>,
+
+    /// Forward rates of each path (first index) at each tenor date (second
+    /// index). A forward rate keeps its fixing after its fixing date.
+    pub forwards: Vec<Vec<Vec<f64>>>,
+
+    /// Deflator $N_0 / N_{T_m}$ of each path at each tenor date.
+    pub deflators: Vec<Vec<f64>>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl ForwardCorrelation {
+    /// Correlation matrix of forward rates fixing at the given times.
+    #[must_use]
+    pub fn matrix(&self, fixing_times: &[f64]) -> DMatrix<f64> {
+        let n = fixing_times.len();
+        let distance = |i: usize, j: usize| (fixing_times[i] - fixing_times[j]).abs();
+
+        match self {
+            Self::Exponential { decay } => {
+                DMatrix::from_fn(n, n, |i, j| (-decay * distance(i, j)).exp())
+            }
+            Self::Rebonato { long_term, decay } => DMatrix::from_fn(n, n, |i, j| {
+                long_term + (1.0 - long_term) * (-decay * distance(i, j)).exp()
+            }),
+            Self::Matrix(matrix) => matrix.clone(),
+        }
+    }
+}
+
+impl LiborMarketModel {
+    /// Create a new LIBOR market model, simulated under the spot measure
+    /// with all the factors of the correlation matrix and one time step per
+    /// period.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the tenor dates do not start
+    ///   at zero and increase, there is not one positive forward rate per
+    ///   period, a volatility is missing or negative, or the correlation
+    ///   matrix is not symmetric with one row per forward rate, a unit
+    ///   diagonal and entries in [-1, 1].
+    pub fn new(
+        tenors: &[f64],
+        initial_forwards: &[f64],
+        volatility: &ForwardVolatility,
+        correlation: &ForwardCorrelation,
+    ) -> Result<Self, RustQuantError> {
+        let n = initial_forwards.len();
+
+        if tenors.len() != n + 1 || n == 0 {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "{} tenor dates for {n} forward rates, expected one more date than rates",
+                tenors.len()
+            )));
+        }
+        if tenors[0] != 0.0 || tenors.windows(2).any(|t| t[0] >= t[1]) {
+            return Err(RustQuantError::InvalidArgument(
+                "the tenor dates must start today (zero) and increase".to_string(),
+            ));
+        }
+        if initial_forwards.iter().any(|f| f.is_nan() || *f <= 0.0) {
+            return Err(RustQuantError::InvalidArgument(
+                "the forward rates of a lognormal model must be positive".to_string(),
+            ));
+        }
+
+        let volatilities: Vec<Vec<f64>> = (0..n)
+            .map(|i| {
+                (0..i)
+                    .map(|m| match volatility {
+                        ForwardVolatility::Constant(v) => v.get(i).copied(),
+                        ForwardVolatility::TimeHomogeneous(v) => v.get(i - m - 1).copied(),
+                        ForwardVolatility::PiecewiseConstant(v) => {
+                            v.get(i).and_then(|row| row.get(m)).copied()
+                        }
+                    })
+                    .collect::<Option<Vec<f64>>>()
+            })
+            .collect::<Option<_>>()
+            .ok_or_else(|| {
+                RustQuantError::InvalidArgument(
+                    "a volatility is missing for a forward rate before its fixing".to_string(),
+                )
+            })?;
+        if volatilities
+            .iter()
+            .flatten()
+            .any(|v| v.is_nan() || *v < 0.0)
+        {
+            return Err(RustQuantError::InvalidArgument(
+                "the volatilities must not be negative".to_string(),
+            ));
+        }
+
+        let correlation = correlation.matrix(&tenors[..n]);
+        if correlation.shape() != (n, n)
+            || (0..n).any(|i| (correlation[(i, i)] - 1.0).abs() > 1e-12)
+            || correlation.iter().any(|rho| !(-1.0..=1.0).contains(rho))
+            || (&correlation - correlation.transpose()).amax() > 1e-12
+        {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "the correlation matrix must be {n} x {n} and symmetric, with a unit \
+                diagonal and entries in [-1, 1]"
+            )));
+        }
+
+        Ok(Self {
+            tenors: tenors.to_vec(),
+            initial_forwards: initial_forwards.to_vec(),
+            volatilities,
+            correlation,
+            n_factors: n,
+            measure: LiborMeasure::Spot,
+            steps_per_period: 1,
+        })
+    }
+
+    /// Simulate under the given measure.
+    #[must_use]
+    pub fn with_measure(mut self, measure: LiborMeasure) -> Self {
+        self.measure = measure;
+        self
+    }
+
+    /// Drive the forward rates by the given number of principal factors of
+    /// their correlation matrix (between one and the number of rates).
+    #[must_use]
+    pub fn with_factors(mut self, n_factors: usize) -> Self {
+        self.n_factors = n_factors.clamp(1, self.initial_forwards.len());
+        self
+    }
+
+    /// Split each period into the given number of time steps.
+    #[must_use]
+    pub fn with_steps_per_period(mut self, steps_per_period: usize) -> Self {
+        self.steps_per_period = steps_per_period.max(1);
+        self
+    }
+
+    /// Number of forward rates.
+    #[must_use]
+    pub fn dimension(&self) -> usize {
+        self.initial_forwards.len()
+    }
+
+    /// Correlation matrix of the simulated forward rates, of the rank of the
+    /// number of factors.
+    #[must_use]
+    pub fn correlation(&self) -> DMatrix<f64> {
+        let loadings = principal_factor(&self.correlation, self.n_factors);
+
+        &loadings * loadings.transpose()
+    }
+
+    /// Volatility of forward rate `i` over period `m`, before it fixes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the forward rate fixes at or before the start of the period.
+    #[must_use]
+    pub fn volatility(&self, i: usize, m: usize) -> f64 {
+        self.volatilities[i][m]
+    }
+
+    /// Discount factor $P(0, T_m)$ of a tenor date.
+    #[must_use]
+    pub fn discount_factor(&self, m: usize) -> f64 {
+        self.initial_forwards[..m]
+            .iter()
+            .zip(self.tenors.windows(2))
+            .map(|(f, t)| 1.0 / (1.0 + (t[1] - t[0]) * f))
+            .product()
+    }
+
+    /// Simulate the forward rates at the tenor dates, with the paths split
+    /// across threads. Path `i` draws from stream `i` of a `ChaCha8`
+    /// generator seeded with `seed`, so the result is the same for any
+    /// number of threads.
+    #[must_use]
+    pub fn simulate(&self, m_paths: usize, seed: u64) -> LiborMarketModelPaths {
+        let n = self.dimension();
+        let loadings = principal_factor(&self.correlation, self.n_factors);
+        let correlation = &loadings * loadings.transpose();
+        let accruals: Vec<f64> = self.tenors.windows(2).map(|t| t[1] - t[0]).collect();
+
+        let forwards: Vec<Vec<Vec<f64>>> = (0..m_paths)
+            .into_par_iter()
+            .map(|p| {
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                rng.set_stream(p as u64);
+
+                let mut forwards = self.initial_forwards.clone();
+                let mut snapshots = Vec::with_capacity(n + 1);
+                snapshots.push(forwards.clone());
+
+                for m in 0..n {
+                    let dt = accruals[m] / self.steps_per_period as f64;
+
+                    for _ in 0..self.steps_per_period {
+                        let z: Vec<f64> = (0..loadings.ncols())
+                            .map(|_| StandardNormal.sample(&mut rng))
+                            .collect();
+                        let shocks: Vec<f64> = (m + 1..n)
+                            .map(|i| {
+                                let dw: f64 = z
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(k, z)| loadings[(i, k)] * z)
+                                    .sum();
+                                self.volatilities[i][m] * dt.sqrt() * dw
+                            })
+                            .collect();
+
+                        let step = |forwards: &[f64], drifts: &[f64]| -> Vec<f64> {
+                            let mut next = forwards.to_vec();
+                            for (i, shock) in (m + 1..n).zip(&shocks) {
+                                let sigma = self.volatilities[i][m];
+                                next[i] *= ((drifts[i] - sigma * sigma / 2.0) * dt + shock).exp();
+                            }
+                            next
+                        };
+
+                        let drift = self.drifts(&forwards, m, &correlation, &accruals);
+                        let predicted = step(&forwards, &drift);
+                        let corrected = self.drifts(&predicted, m, &correlation, &accruals);
+                        let average: Vec<f64> = drift
+                            .iter()
+                            .zip(&corrected)
+                            .map(|(a, b)| (a + b) / 2.0)
+                            .collect();
+
+                        forwards = step(&forwards, &average);
+                    }
+
+                    snapshots.push(forwards.clone());
+                }
+
+                snapshots
+            })
+            .collect();
+
+        let deflators = forwards
+            .iter()
+            .map(|snapshots| self.deflators(snapshots, &accruals))
+            .collect();
+
+        LiborMarketModelPaths {
+            times: self.tenors.clone(),
+            accruals,
+            forwards,
+            deflators,
+        }
+    }
+
+    /// Drifts of the forward rates still to fix during period `m`.
+    fn drifts(
+        &self,
+        forwards: &[f64],
+        m: usize,
+        correlation: &DMatrix<f64>,
+        accruals: &[f64],
+    ) -> Vec<f64> {
+        let n = self.dimension();
+        let terms: Vec<f64> = (0..n)
+            .map(|j| {
+                if j > m {
+                    accruals[j] * self.volatilities[j][m] * forwards[j]
+                        / (1.0 + accruals[j] * forwards[j])
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        (0..n)
+            .map(|i| {
+                if i <= m {
+                    return 0.0;
+                }
+
+                let sum: f64 = match self.measure {
+                    LiborMeasure::Spot => (m + 1..=i).map(|j| correlation[(i, j)] * terms[j]).sum(),
+                    LiborMeasure::Terminal => -(i + 1..n)
+                        .map(|j| correlation[(i, j)] * terms[j])
+                        .sum::<f64>(),
+                };
+
+                self.volatilities[i][m] * sum
+            })
+            .collect()
+    }
+
+    /// Deflators $N_0 / N_{T_m}$ of a path at the tenor dates.
+    fn deflators(&self, snapshots: &[Vec<f64>], accruals: &[f64]) -> Vec<f64> {
+        let n = self.dimension();
+
+        match self.measure {
+            // N(T_m) is the product of the growth over the fixed periods.
+            LiborMeasure::Spot => {
+                let mut numeraire = 1.0;
+                (0..=n)
+                    .map(|m| {
+                        if m > 0 {
+                            numeraire *= 1.0 + accruals[m - 1] * snapshots[m - 1][m - 1];
+                        }
+                        1.0 / numeraire
+                    })
+                    .collect()
+            }
+            // N(T_m) is the bond P(T_m, T_n).
+            LiborMeasure::Terminal => {
+                let bond = |m: usize| -> f64 {
+                    (m..n)
+                        .map(|j| 1.0 / (1.0 + accruals[j] * snapshots[m][j]))
+                        .product()
+                };
+                let initial = bond(0);
+
+                (0..=n).map(|m| initial / bond(m)).collect()
+            }
+        }
+    }
+}
+
+impl LiborMarketModelPaths {
+    /// Number of simulated paths.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.forwards.len()
+    }
+
+    /// Whether there are no simulated paths.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.forwards.is_empty()
+    }
+
+    /// Price at tenor date `m` of the zero-coupon bond maturing at tenor
+    /// date `k >= m`, on a path.
+    #[must_use]
+    pub fn zero_coupon_bond(&self, path: usize, m: usize, k: usize) -> f64 {
+        let forwards = &self.forwards[path][m];
+
+        (m..k)
+            .map(|j| 1.0 / (1.0 + self.accruals[j] * forwards[j]))
+            .product()
+    }
+
+    /// Swap rate and annuity at tenor date `m` of the swap over the periods
+    /// from `m` to the last tenor date, on a path.
+    #[must_use]
+    pub fn swap_rate(&self, path: usize, m: usize) -> (f64, f64) {
+        let n = self.accruals.len();
+        let annuity: f64 = (m..n)
+            .map(|j| self.accruals[j] * self.zero_coupon_bond(path, m, j + 1))
+            .sum();
+
+        ((1.0 - self.zero_coupon_bond(path, m, n)) / annuity, annuity)
+    }
+
+    /// Least-squares Monte Carlo price of a unit notional Bermudan swaption,
+    /// exercisable at the given tenor dates into the swap from the exercise
+    /// date to the last tenor date at the fixed rate `strike`.
+    ///
+    /// The deflated cash flows are regressed on the cubic polynomials of the
+    /// swap rate at each exercise date. With a single exercise date this is
+    /// the European swaption.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if there are no paths or exercise
+    ///   dates.
+    /// - `RustQuantError::InvalidArgument` if the exercise dates are not
+    ///   increasing tenor dates before the last.
+    pub fn bermudan_swaption(
+        &self,
+        strike: f64,
+        direction: SwapDirection,
+        exercise_dates: &[usize],
+    ) -> Result<PricingResult, RustQuantError> {
+        if self.is_empty() || exercise_dates.is_empty() {
+            return Err(RustQuantError::MissingInput(
+                "at least one path and one exercise date are required".to_string(),
+            ));
+        }
+        if exercise_dates.windows(2).any(|e| e[0] >= e[1])
+            || exercise_dates.iter().any(|e| *e >= self.accruals.len())
+        {
+            return Err(RustQuantError::InvalidArgument(
+                "the exercise dates must be increasing tenor dates before the last".to_string(),
+            ));
+        }
+
+        let sign = match direction {
+            SwapDirection::Payer => 1.0,
+            SwapDirection::Receiver => -1.0,
+        };
+        let exercise = |p: usize, m: usize| -> (f64, f64) {
+            let (rate, annuity) = self.swap_rate(p, m);
+            let value = sign * (rate - strike) * annuity * self.deflators[p][m];
+
+            (value, rate)
+        };
+
+        let n_paths = self.len();
+        let last = exercise_dates[exercise_dates.len() - 1];
+        let mut cash_flows: Vec<f64> = (0..n_paths).map(|p| exercise(p, last).0.max(0.0)).collect();
+
+        for &m in exercise_dates.iter().rev().skip(1) {
+            let values: Vec<(f64, f64)> = (0..n_paths).map(|p| exercise(p, m)).collect();
+            let in_the_money: Vec<usize> = (0..n_paths).filter(|&p| values[p].0 > 0.0).collect();
+
+            if in_the_money.len() < 4 {
+                continue;
+            }
+
+            let design = DMatrix::from_fn(in_the_money.len(), 4, |i, k| {
+                values[in_the_money[i]].1.powi(k as i32)
+            });
+            let targets: Vec<f64> = in_the_money.iter().map(|&p| cash_flows[p]).collect();
+
+            for (p, continuation) in in_the_money.iter().zip(fitted_values(design, &targets)) {
+                if values[*p].0 > continuation {
+                    cash_flows[*p] = values[*p].0;
+                }
+            }
+        }
+
+        let n = n_paths as f64;
+        let mean = cash_flows.iter().sum::<f64>() / n;
+        let variance = if n_paths > 1 {
+            cash_flows.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        Ok(PricingResult::new(mean)
+            .with_standard_error((variance / n).sqrt())
+            .with_iterations(n_paths))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_libor_market_model {
+    use super::*;
+    use crate::instruments::options::TypeFlag;
+    use crate::instruments::swaps::RateOptionModel;
+
+    const TENORS: [f64; 7] = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    const FORWARDS: [f64; 6] = [0.03, 0.032, 0.034, 0.035, 0.036, 0.037];
+
+    fn model(measure: LiborMeasure) -> LiborMarketModel {
+        LiborMarketModel::new(
+            &TENORS,
+            &FORWARDS,
+            &ForwardVolatility::TimeHomogeneous(vec![0.15, 0.18, 0.2, 0.19, 0.18]),
+            &ForwardCorrelation::Rebonato {
+                long_term: 0.4,
+                decay: 0.2,
+            },
+        )
+        .unwrap()
+        .with_measure(measure)
+        .with_steps_per_period(4)
+    }
+
+    #[test]
+    fn test_bonds_and_caplets_under_both_measures() {
+        let n_paths = 20_000;
+
+        for measure in [LiborMeasure::Spot, LiborMeasure::Terminal] {
+            let model = model(measure);
+            let paths = model.simulate(n_paths, 7);
+
+            let mean_and_error = |samples: Vec<f64>| {
+                let mean = samples.iter().sum::<f64>() / n_paths as f64;
+                let variance =
+                    samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n_paths - 1) as f64;
+                (mean, (variance / n_paths as f64).sqrt())
+            };
+
+            // Deflated zero-coupon bonds are martingales.
+            for m in 1..=6 {
+                let (mean, error) = mean_and_error(paths.deflators.iter().map(|d| d[m]).collect());
+                assert!((mean - model.discount_factor(m)).abs() < 4.0 * error + 1e-5);
+            }
+
+            // Caplets are priced by Black's formula with the root mean
+            // square volatility to the fixing.
+            for i in [1, 3, 5] {
+                let strike = FORWARDS[i];
+                let (mean, error) = mean_and_error(
+                    (0..n_paths)
+                        .map(|p| {
+                            let fixing = paths.forwards[p][i][i];
+                            (fixing - strike).max(0.0) * paths.deflators[p][i + 1]
+                        })
+                        .collect(),
+                );
+
+                let variance: f64 = (0..i).map(|m| model.volatility(i, m).powi(2)).sum();
+                let black = RateOptionModel::Black
+                    .undiscounted_price(
+                        TypeFlag::Call,
+                        FORWARDS[i],
+                        strike,
+                        (variance / TENORS[i]).sqrt(),
+                        TENORS[i],
+                    )
+                    .unwrap()
+                    * model.discount_factor(i + 1);
+
+                assert!((mean - black).abs() < 4.0 * error + 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_volatility_and_correlation_parameterizations() {
+        let model = model(LiborMeasure::Spot);
+
+        // Time homogeneous volatilities depend on the periods to the fixing.
+        assert_eq!(model.volatility(5, 4), 0.15);
+        assert_eq!(model.volatility(5, 0), 0.18);
+        assert_eq!(model.volatility(3, 0), 0.2);
+
+        let rebonato = ForwardCorrelation::Rebonato {
+            long_term: 0.4,
+            decay: 0.2,
+        }
+        .matrix(&TENORS[..6]);
+        assert!((rebonato[(0, 5)] - (0.4 + 0.6 * (-1.0_f64).exp())).abs() < 1e-15);
+        assert!((model.correlation() - &rebonato).amax() < 1e-12);
+
+        // A single factor moves all the rates together.
+        let one_factor = model.clone().with_factors(1).correlation();
+        assert!(one_factor.iter().all(|rho| (rho - 1.0).abs() < 1e-12));
+
+        // Three factors keep the unit diagonal, and most of the structure.
+        let three_factors = model.with_factors(3).correlation();
+        assert!((0..6).all(|i| (three_factors[(i, i)] - 1.0).abs() < 1e-12));
+        assert!((three_factors - &rebonato).amax() < 0.1);
+
+        // Invalid inputs.
+        let flat = ForwardVolatility::Constant(vec![0.2; 6]);
+        let exponential = ForwardCorrelation::Exponential { decay: 0.1 };
+        assert!(LiborMarketModel::new(&TENORS[1..], &FORWARDS[1..], &flat, &exponential).is_err());
+        assert!(LiborMarketModel::new(&TENORS, &FORWARDS[1..], &flat, &exponential).is_err());
+        assert!(LiborMarketModel::new(
+            &TENORS,
+            &FORWARDS,
+            &ForwardVolatility::Constant(vec![0.2; 3]),
+            &exponential
+        )
+        .is_err());
+        assert!(LiborMarketModel::new(
+            &TENORS,
+            &FORWARDS,
+            &flat,
+            &ForwardCorrelation::Matrix(DMatrix::identity(5, 5))
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_bermudan_swaption() {
+        let model = model(LiborMeasure::Spot).with_factors(3);
+        let paths = model.simulate(20_000, 11);
+        let strike = 0.035;
+
+        // The European swaption into a 4 year swap in 2 years, against the
+        // Black price with Rebonato's approximate swap rate volatility.
+        let european = paths
+            .bermudan_swaption(strike, SwapDirection::Payer, &[2])
+            .unwrap();
+
+        let annuity: f64 = (2..6).map(|j| model.discount_factor(j + 1)).sum();
+        let swap_rate = (model.discount_factor(2) - model.discount_factor(6)) / annuity;
+        let weights: Vec<f64> = (2..6)
+            .map(|j| model.discount_factor(j + 1) / annuity)
+            .collect();
+        let correlation = model.correlation();
+        let mut variance = 0.0;
+        for i in 2..6 {
+            for j in 2..6 {
+                let covariance: f64 = (0..2)
+                    .map(|m| model.volatility(i, m) * model.volatility(j, m))
+                    .sum();
+                variance += weights[i - 2]
+                    * weights[j - 2]
+                    * FORWARDS[i]
+                    * FORWARDS[j]
+                    * correlation[(i, j)]
+                    * covariance;
+            }
+        }
+        let black = annuity
+            * RateOptionModel::Black
+                .undiscounted_price(
+                    TypeFlag::Call,
+                    swap_rate,
+                    strike,
+                    (variance / 2.0).sqrt() / swap_rate,
+                    2.0,
+                )
+                .unwrap();
+
+        let error = european.standard_error.unwrap();
+        assert!((european.value - black).abs() < 4.0 * error + 0.01 * black);
+
+        // The Bermudan is worth more than any of its European swaptions.
+        let bermudan = paths
+            .bermudan_swaption(strike, SwapDirection::Payer, &[1, 2, 3, 4, 5])
+            .unwrap();
+        for m in 1..6 {
+            let european = paths
+                .bermudan_swaption(strike, SwapDirection::Payer, &[m])
+                .unwrap();
+            assert!(bermudan.value > european.value);
+        }
+
+        assert!(paths
+            .bermudan_swaption(strike, SwapDirection::Receiver, &[3, 2])
+            .is_err());
+        assert!(paths
+            .bermudan_swaption(strike, SwapDirection::Receiver, &[6])
+            .is_err());
+    }
+}
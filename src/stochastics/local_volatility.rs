@@ -0,0 +1,168 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::models::local_volatility::LocalVolatility;
+use crate::stochastics::process::{StochasticProcess, Trajectories};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, StandardNormal};
+use rayon::prelude::*;
+
+impl StochasticProcess for LocalVolatility {
+    fn drift(&self, x: f64, t: f64) -> f64 {
+        self.drift_rate(t) * x
+    }
+
+    fn diffusion(&self, x: f64, t: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+
+        self.local_volatility(t, x) * x
+    }
+
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
+    }
+}
+
+impl LocalVolatility {
+    /// Log-Euler scheme, with the paths split across threads:
+    ///
+    /// $$
+    /// \ln S_{t + \Delta t} = \ln S_t + \ln \frac{F(t + \Delta t)}{F(t)} -
+    /// \frac{1}{2} \sigma_{loc}^2(t, S_t) \Delta t + \sigma_{loc}(t, S_t) \sqrt{\Delta t} Z
+    /// $$
+    ///
+    /// Unlike the Euler-Maruyama scheme, the spot stays positive and the
+    /// forward is matched over each step whatever its size.
+    ///
+    /// # Arguments:
+    /// * `x_0` - The process' initial value at `t_0`.
+    /// * `t_0` - The initial time point.
+    /// * `t_n` - The terminal time point.
+    /// * `n_steps` - The number of time steps between `t_0` and `t_n`.
+    /// * `m_paths` - How many process trajectories to simulate.
+    /// * `seed` - The master seed of the random number generator; path `i`
+    ///   draws from stream `i` of a `ChaCha8` generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `t_0 >= t_n`.
+    #[must_use]
+    pub fn log_euler(
+        &self,
+        x_0: f64,
+        t_0: f64,
+        t_n: f64,
+        n_steps: usize,
+        m_paths: usize,
+        seed: u64,
+    ) -> Trajectories {
+        assert!(t_0 < t_n);
+
+        let dt = (t_n - t_0) / n_steps as f64;
+        let times: Vec<f64> = (0..=n_steps).map(|t| t_0 + dt * t as f64).collect();
+        let log_growth: Vec<f64> = times
+            .windows(2)
+            .map(|t| (self.forward(t[1]) / self.forward(t[0])).ln())
+            .collect();
+
+        let paths = (0..m_paths)
+            .into_par_iter()
+            .map(|i| {
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                rng.set_stream(i as u64);
+
+                let mut path = Vec::with_capacity(n_steps + 1);
+                path.push(x_0);
+
+                for (t, growth) in times.iter().zip(&log_growth) {
+                    let x = path[path.len() - 1];
+                    let sigma = self.local_volatility(*t, x);
+                    let z: f64 = StandardNormal.sample(&mut rng);
+
+                    path.push(
+                        x * (growth - 0.5 * sigma * sigma * dt + sigma * dt.sqrt() * z).exp(),
+                    );
+                }
+
+                path
+            })
+            .collect();
+
+        Trajectories { times, paths }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_local_volatility {
+    use super::*;
+    use crate::data::{VolatilitySlice, VolatilitySmile, VolatilitySurface};
+    use crate::math::distributions::{Distribution as _, Gaussian};
+    use crate::models::SVI;
+
+    #[test]
+    fn test_local_volatility_reprices_the_surface() {
+        let surface = VolatilitySurface::new(vec![
+            VolatilitySlice::new(
+                0.5,
+                101.0,
+                VolatilitySmile::Svi(SVI::new(0.01, 0.05, -0.4, 0.0, 0.2)),
+            )
+            .unwrap(),
+            VolatilitySlice::new(
+                1.0,
+                102.0,
+                VolatilitySmile::Svi(SVI::new(0.03, 0.1, -0.4, 0.0, 0.2)),
+            )
+            .unwrap(),
+        ])
+        .unwrap();
+        let model = LocalVolatility::new(100.0, surface.clone()).unwrap();
+
+        let n_paths = 40_000;
+        let trajectories = model.log_euler(100.0, 0.0, 1.0, 100, n_paths, 2);
+        assert_eq!(
+            trajectories.paths,
+            model.log_euler(100.0, 0.0, 1.0, 100, n_paths, 2).paths
+        );
+
+        let terminal: Vec<f64> = trajectories.paths.iter().map(|path| path[100]).collect();
+        let mean_and_error = |samples: Vec<f64>| {
+            let mean = samples.iter().sum::<f64>() / n_paths as f64;
+            let variance =
+                samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n_paths as f64 - 1.0);
+            (mean, (variance / n_paths as f64).sqrt())
+        };
+
+        // The spot is a martingale in the forward measure.
+        let (forward, error) = mean_and_error(terminal.clone());
+        assert!((forward - 102.0).abs() < 4.0 * error);
+
+        // Undiscounted calls against Black's formula with the implied
+        // volatility of the surface, within a few standard errors.
+        let normal = Gaussian::default();
+        for strike in [85.0, 100.0, 115.0] {
+            let (mean, error) =
+                mean_and_error(terminal.iter().map(|s| (s - strike).max(0.0)).collect());
+
+            // One year to expiry.
+            let sqrt_variance = surface.volatility(1.0, strike).unwrap();
+            let d_1 = (102.0 / strike).ln() / sqrt_variance + 0.5 * sqrt_variance;
+            let black = 102.0 * normal.cdf(d_1) - strike * normal.cdf(d_1 - sqrt_variance);
+
+            assert!((mean - black).abs() < 4.0 * error + 0.05);
+        }
+    }
+}
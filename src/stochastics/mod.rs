@@ -53,6 +53,10 @@ pub mod brownian_motion;
 /// Constant Elasticity of Variance process.
 pub mod constant_elasticity_of_variance;
 
+/// Processes driven by correlated Brownian motions.
+pub mod correlated;
+pub use correlated::*;
+
 /// Cox-Ingersoll-Ross process.
 pub mod cox_ingersoll_ross;
 
@@ -76,6 +80,7 @@ pub mod geometric_brownian_motion;
 
 /// Heston model process.
 pub mod heston;
+pub use heston::*;
 
 /// Ho-Lee process.
 pub mod ho_lee;
@@ -83,15 +88,39 @@ pub mod ho_lee;
 /// Hull-White model process.
 pub mod hull_white;
 
+/// Kou double exponential jump diffusion process.
+pub mod kou_jump_diffusion;
+
+/// LIBOR market model of forward rates.
+pub mod libor_market_model;
+pub use libor_market_model::*;
+
+/// Dupire local volatility process.
+pub mod local_volatility;
+
 /// Merton jump diffusion process.
 pub mod merton_jump_diffusion;
 
+/// Normal inverse Gaussian process.
+pub mod normal_inverse_gaussian;
+
+/// Numeraires and changes of measure for Monte Carlo pricing.
+pub mod numeraire;
+pub use numeraire::*;
+
+/// Sources of Brownian increments, including quasi-random paths.
+pub mod path_generator;
+pub use path_generator::*;
+
 /// Ornstein-Uhlenbeck process.
 pub mod ornstein_uhlenbeck;
 
 /// SABR model process.
 pub mod sabr;
 
+/// Variance gamma process.
+pub mod variance_gamma;
+
 /// Defines `Trajectories` and `StochasticProcess`.
 pub mod process;
 pub use process::*;
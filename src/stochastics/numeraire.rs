@@ -0,0 +1,238 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Numeraires and changes of measure for Monte Carlo pricing.
+//!
+//! Under the measure associated with a numeraire $N$, the price of a claim
+//! paying $V_T$ at time $T$ is
+//!
+//! $$
+//! V_0 = N_0 \mathbb{E}^N \left[ \frac{V_T}{N_T} \right]
+//! $$
+//!
+//! Changing from the risk-neutral (bank account) measure to the measure of a
+//! numeraire with volatility $\sigma_N(t)$ shifts the Brownian motion by
+//! $dW^N = dW^Q - \sigma_N(t) dt$, so a process with diffusion $\sigma_X$
+//! (driven by the same Brownian motion) picks up the drift adjustment
+//!
+//! $$
+//! \mu^N_X = \mu^Q_X + \sigma_X \sigma_N(t)
+//! $$
+//!
+//! For the $T$-maturity zero-coupon bond in a one-factor Gaussian short rate
+//! model (Vasicek, Hull-White) with short rate volatility $\sigma$ and mean
+//! reversion $a$, the bond volatility is
+//! $\sigma_N(t) = -\sigma B(t, T)$ with $B(t, T) = (1 - e^{-a (T - t)}) / a$.
+
+use crate::stochastics::{StochasticProcess, Trajectories};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Numeraire used to discount simulated payoffs.
+pub trait Numeraire: Sync {
+    /// Ratio $N_0 / N_T$ of the numeraire along a simulated short rate path.
+    fn deflator(&self, times: &[f64], short_rates: &[f64]) -> f64;
+
+    /// Volatility $\sigma_N(t)$ of the numeraire, relative to the
+    /// risk-neutral measure.
+    fn volatility(&self, t: f64) -> f64;
+
+    /// Drift adjustment for a process with diffusion `diffusion` at time `t`
+    /// when simulated under this numeraire's measure.
+    fn drift_adjustment(&self, diffusion: f64, t: f64) -> f64 {
+        diffusion * self.volatility(t)
+    }
+}
+
+/// Continuously-compounded bank account numeraire (the risk-neutral measure).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BankAccount;
+
+/// Zero-coupon bond numeraire maturing at the payoff date (the terminal
+/// forward measure), for a one-factor Gaussian short rate model.
+#[derive(Debug, Clone, Copy)]
+pub struct TerminalBond {
+    /// Maturity $T$ of the bond (in years).
+    pub maturity: f64,
+
+    /// Initial price $P(0, T)$ of the bond.
+    pub initial_price: f64,
+
+    /// Volatility $\sigma$ of the short rate.
+    pub short_rate_volatility: f64,
+
+    /// Mean reversion speed $a$ of the short rate.
+    pub mean_reversion: f64,
+}
+
+/// A process simulated under the measure of a given numeraire.
+pub struct MeasureAdjusted<'a, P: StochasticProcess, N: Numeraire> {
+    /// The process, specified under the risk-neutral measure.
+    pub process: &'a P,
+
+    /// The numeraire whose measure to simulate under.
+    pub numeraire: &'a N,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Numeraire for BankAccount {
+    /// $\exp(-\int_0^T r_s ds)$, integrated with the trapezoidal rule.
+    fn deflator(&self, times: &[f64], short_rates: &[f64]) -> f64 {
+        let integral: f64 = times
+            .windows(2)
+            .zip(short_rates.windows(2))
+            .map(|(t, r)| 0.5 * (r[0] + r[1]) * (t[1] - t[0]))
+            .sum();
+
+        f64::exp(-integral)
+    }
+
+    fn volatility(&self, _t: f64) -> f64 {
+        0.0
+    }
+}
+
+impl TerminalBond {
+    /// Create a new terminal bond numeraire.
+    #[must_use]
+    pub const fn new(
+        maturity: f64,
+        initial_price: f64,
+        short_rate_volatility: f64,
+        mean_reversion: f64,
+    ) -> Self {
+        Self {
+            maturity,
+            initial_price,
+            short_rate_volatility,
+            mean_reversion,
+        }
+    }
+}
+
+impl Numeraire for TerminalBond {
+    /// $P(0, T) / P(T, T) = P(0, T)$, independent of the path.
+    fn deflator(&self, _times: &[f64], _short_rates: &[f64]) -> f64 {
+        self.initial_price
+    }
+
+    fn volatility(&self, t: f64) -> f64 {
+        let tau = self.maturity - t;
+        let b = if self.mean_reversion.abs() < f64::EPSILON {
+            tau
+        } else {
+            (1.0 - f64::exp(-self.mean_reversion * tau)) / self.mean_reversion
+        };
+
+        -self.short_rate_volatility * b
+    }
+}
+
+impl<'a, P: StochasticProcess, N: Numeraire> MeasureAdjusted<'a, P, N> {
+    /// Simulate `process` under the measure of `numeraire`.
+    pub fn new(process: &'a P, numeraire: &'a N) -> Self {
+        Self { process, numeraire }
+    }
+}
+
+impl<P: StochasticProcess, N: Numeraire> StochasticProcess for MeasureAdjusted<'_, P, N> {
+    fn drift(&self, x: f64, t: f64) -> f64 {
+        self.process.drift(x, t)
+            + self
+                .numeraire
+                .drift_adjustment(self.process.diffusion(x, t), t)
+    }
+
+    fn diffusion(&self, x: f64, t: f64) -> f64 {
+        self.process.diffusion(x, t)
+    }
+
+    fn jump(&self, x: f64, t: f64) -> Option<f64> {
+        self.process.jump(x, t)
+    }
+}
+
+/// Monte Carlo price of a payoff on simulated short rate paths, deflated by
+/// the given numeraire. The paths must have been simulated under the
+/// numeraire's measure (see [`MeasureAdjusted`]).
+///
+/// Returns the price and its standard error.
+pub fn numeraire_price<N, F>(trajectories: &Trajectories, numeraire: &N, payoff: F) -> (f64, f64)
+where
+    N: Numeraire,
+    F: Fn(&[f64]) -> f64,
+{
+    let values: Vec<f64> = trajectories
+        .paths
+        .iter()
+        .map(|path| payoff(path) * numeraire.deflator(&trajectories.times, path))
+        .collect();
+
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+
+    (mean, (variance / n).sqrt())
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_numeraire {
+    use super::*;
+    use crate::models::ornstein_uhlenbeck::OrnsteinUhlenbeck;
+
+    #[test]
+    fn test_bank_account_and_terminal_bond_agree() {
+        // Vasicek short rate: dr = a (theta - r) dt + sigma dW.
+        let (r0, theta, sigma, a, maturity) = (0.03, 0.05, 0.02, 0.5, 2.0);
+        let vasicek = OrnsteinUhlenbeck::new(theta, sigma, a);
+
+        // Analytic Vasicek zero-coupon bond price P(0, T).
+        let b = (1.0 - f64::exp(-a * maturity)) / a;
+        let bond_price = f64::exp(
+            (theta - sigma * sigma / (2.0 * a * a)) * (b - maturity)
+                - sigma * sigma * b * b / (4.0 * a)
+                - b * r0,
+        );
+
+        // A call on the terminal short rate.
+        let payoff = |path: &[f64]| (path[path.len() - 1] - r0).max(0.0);
+
+        let bank_account = BankAccount;
+        let paths = vasicek.seedable_euler_maruyama(r0, 0.0, maturity, 100, 10_000, true, 1);
+        let (price_q, error_q) = numeraire_price(&paths, &bank_account, payoff);
+
+        let terminal_bond = TerminalBond::new(maturity, bond_price, sigma, a);
+        let forward_measure = MeasureAdjusted::new(&vasicek, &terminal_bond);
+        let paths =
+            forward_measure.seedable_euler_maruyama(r0, 0.0, maturity, 100, 10_000, true, 2);
+        let (price_t, error_t) = numeraire_price(&paths, &terminal_bond, payoff);
+
+        let combined_error = (error_q * error_q + error_t * error_t).sqrt();
+
+        assert!((price_q - price_t).abs() < 4.0 * combined_error);
+
+        // The bank account deflates the unit claim to the bond price.
+        let (unit, unit_error) = numeraire_price(
+            &vasicek.seedable_euler_maruyama(r0, 0.0, maturity, 100, 10_000, true, 3),
+            &bank_account,
+            |_| 1.0,
+        );
+
+        assert!((unit - bond_price).abs() < 4.0 * unit_error + 1e-4);
+    }
+}
@@ -0,0 +1,314 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Sources of the Brownian increments driving simulated paths.
+//!
+//! A [`PathGenerator`] returns the Brownian increments of one path at a
+//! time, and [`StochasticProcess::generate_with`] simulates any process from
+//! them. [`PseudoRandomPaths`] draws independent normal increments, while
+//! [`SobolBridgePaths`] maps the points of a Sobol' sequence to a
+//! [`BrownianBridge`], so that the best distributed first coordinates decide
+//! the coarse shape of the path (its terminal value, then its midpoint, and
+//! so on) and quasi-Monte Carlo converges with far fewer paths.
+//!
+//! [`StochasticProcess::generate_with`]: crate::stochastics::StochasticProcess::generate_with
+
+use crate::error::RustQuantError;
+use crate::math::{Distribution, Gaussian, SobolSequence};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand_distr::StandardNormal;
+use std::collections::VecDeque;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Trait for sources of Brownian increments.
+pub trait PathGenerator {
+    /// Returns the Brownian increments of the next path over the intervals
+    /// between consecutive `times`.
+    fn next_increments(&mut self, times: &[f64]) -> Vec<f64>;
+}
+
+/// Independent pseudo-random normal increments.
+#[derive(Debug, Clone)]
+pub struct PseudoRandomPaths {
+    /// Random number generator.
+    rng: StdRng,
+}
+
+/// Brownian bridge construction of a path on a time grid.
+///
+/// The first normal draw sets the terminal value, and each next one the
+/// value at the middle of an interval whose end points are already known,
+/// conditionally on them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrownianBridge {
+    /// Time points, from the start of the path.
+    times: Vec<f64>,
+
+    /// Construction steps, as `(point, left, right, left weight, right
+    /// weight, standard deviation)`.
+    steps: Vec<(usize, usize, usize, f64, f64, f64)>,
+}
+
+/// Sobol' points mapped to Brownian increments by a Brownian bridge.
+#[derive(Debug, Clone)]
+pub struct SobolBridgePaths {
+    /// Sobol' sequence, one dimension per time step.
+    sobol: SobolSequence,
+
+    /// Brownian bridge of the last time grid.
+    bridge: Option<BrownianBridge>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl PseudoRandomPaths {
+    /// Create a new pseudo-random path generator from a seed.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl PathGenerator for PseudoRandomPaths {
+    fn next_increments(&mut self, times: &[f64]) -> Vec<f64> {
+        times
+            .windows(2)
+            .map(|t| (t[1] - t[0]).sqrt() * self.rng.sample::<f64, _>(StandardNormal))
+            .collect()
+    }
+}
+
+impl BrownianBridge {
+    /// Create a new Brownian bridge on the time points, the first of which
+    /// is the start of the path.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the times are not increasing.
+    #[must_use]
+    pub fn new(times: &[f64]) -> Self {
+        assert!(
+            times.windows(2).all(|t| t[0] < t[1]),
+            "the time points must be increasing"
+        );
+
+        let n = times.len().saturating_sub(1);
+        let mut steps = Vec::with_capacity(n);
+
+        if n > 0 {
+            // The terminal value, from the start of the path.
+            steps.push((n, 0, 0, 0.0, 0.0, (times[n] - times[0]).sqrt()));
+
+            // The midpoints of the intervals, breadth first.
+            let mut intervals = VecDeque::from([(0, n)]);
+            while let Some((left, right)) = intervals.pop_front() {
+                if right - left < 2 {
+                    continue;
+                }
+
+                let point = (left + right) / 2;
+                let (t_l, t_m, t_r) = (times[left], times[point], times[right]);
+
+                steps.push((
+                    point,
+                    left,
+                    right,
+                    (t_r - t_m) / (t_r - t_l),
+                    (t_m - t_l) / (t_r - t_l),
+                    ((t_m - t_l) * (t_r - t_m) / (t_r - t_l)).sqrt(),
+                ));
+
+                intervals.push_back((left, point));
+                intervals.push_back((point, right));
+            }
+        }
+
+        Self {
+            times: times.to_vec(),
+            steps,
+        }
+    }
+
+    /// Number of normal draws (time steps) per path.
+    #[must_use]
+    pub fn dimension(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Brownian increments over the time steps, from one standard normal
+    /// draw per time step in order of importance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not one draw per time step.
+    #[must_use]
+    pub fn increments(&self, normals: &[f64]) -> Vec<f64> {
+        assert_eq!(normals.len(), self.dimension(), "one draw per time step");
+
+        let mut path = vec![0.0; self.times.len()];
+        for (&(point, left, right, w_l, w_r, std_dev), z) in self.steps.iter().zip(normals) {
+            path[point] = w_l * path[left] + w_r * path[right] + std_dev * z;
+        }
+
+        path.windows(2).map(|w| w[1] - w[0]).collect()
+    }
+}
+
+impl SobolBridgePaths {
+    /// Create a new quasi-random path generator for paths of `n_steps` time
+    /// steps.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the number of steps is zero or
+    ///   above [`SobolSequence::MAX_DIMENSION`].
+    pub fn new(n_steps: usize) -> Result<Self, RustQuantError> {
+        Ok(Self {
+            sobol: SobolSequence::new(n_steps)?,
+            bridge: None,
+        })
+    }
+
+    /// Randomize the Sobol' sequence by a scrambling drawn from the seed, so
+    /// that replications with different seeds give an error estimate.
+    #[must_use]
+    pub fn with_scrambling(mut self, seed: u64) -> Self {
+        self.sobol = self.sobol.with_scrambling(seed);
+        self
+    }
+}
+
+impl PathGenerator for SobolBridgePaths {
+    /// # Panics
+    ///
+    /// Panics if there is not one time step per dimension of the sequence.
+    fn next_increments(&mut self, times: &[f64]) -> Vec<f64> {
+        assert_eq!(
+            times.len(),
+            self.sobol.dimension() + 1,
+            "one time step per dimension of the Sobol' sequence"
+        );
+
+        if self
+            .bridge
+            .as_ref()
+            .is_none_or(|bridge| bridge.times != times)
+        {
+            self.bridge = Some(BrownianBridge::new(times));
+        }
+
+        let normal = Gaussian::default();
+        let normals: Vec<f64> = self
+            .sobol
+            .next_point()
+            .iter()
+            .map(|u| normal.inv_cdf(*u))
+            .collect();
+
+        self.bridge
+            .as_ref()
+            .map_or_else(Vec::new, |bridge| bridge.increments(&normals))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_path_generator {
+    use super::*;
+    use crate::models::geometric_brownian_motion::GeometricBrownianMotion;
+    use crate::stochastics::StochasticProcess;
+
+    #[test]
+    fn test_brownian_bridge_covariance() {
+        // Uneven time grid.
+        let times = [0.0, 0.1, 0.25, 0.3, 0.6, 0.7, 1.0];
+        let bridge = BrownianBridge::new(&times);
+        assert_eq!(bridge.dimension(), 6);
+
+        // The first draw sets the terminal value.
+        let mut normals = vec![0.0; 6];
+        normals[0] = 1.5;
+        let increments = bridge.increments(&normals);
+        assert_approx_equal!(increments.iter().sum::<f64>(), 1.5, 1e-14);
+
+        // The increments are independent, with variance the time step.
+        let mut rng = StdRng::seed_from_u64(1);
+        let n = 100_000;
+        let mut moments = vec![vec![0.0; 6]; 6];
+        for _ in 0..n {
+            let z: Vec<f64> = (0..6).map(|_| rng.sample(StandardNormal)).collect();
+            let dw = bridge.increments(&z);
+            for (i, row) in moments.iter_mut().enumerate() {
+                for (j, moment) in row.iter_mut().enumerate() {
+                    *moment += dw[i] * dw[j] / n as f64;
+                }
+            }
+        }
+
+        for (i, row) in moments.iter().enumerate() {
+            for (j, moment) in row.iter().enumerate() {
+                let expected = if i == j { times[i + 1] - times[i] } else { 0.0 };
+                assert!((moment - expected).abs() < 0.004);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sobol_bridge_paths_converge_faster() {
+        // Euler steps of geometric Brownian motion have the exact mean
+        // E[X_n] = x_0 (1 + mu dt)^n.
+        let gbm = GeometricBrownianMotion::new(0.05, 0.3);
+        let (n_steps, m_paths) = (64, 4096);
+        let expected = 10.0 * (1.0 + 0.05 / n_steps as f64).powi(n_steps as i32);
+
+        let error = |trajectories: crate::stochastics::Trajectories| {
+            let mean = trajectories
+                .paths
+                .iter()
+                .map(|path| path[n_steps])
+                .sum::<f64>()
+                / m_paths as f64;
+            (mean - expected).abs()
+        };
+
+        // The standard error of the pseudo-random estimate is about 0.05.
+        let mut sobol = SobolBridgePaths::new(n_steps).unwrap().with_scrambling(3);
+        let quasi_random = gbm.generate_with(10.0, 0.0, 1.0, n_steps, m_paths, &mut sobol);
+        assert!(error(quasi_random) < 0.005);
+
+        let mut pseudo = PseudoRandomPaths::new(3);
+        let pseudo_random = gbm.generate_with(10.0, 0.0, 1.0, n_steps, m_paths, &mut pseudo);
+        assert!(error(pseudo_random) < 0.2);
+    }
+
+    #[test]
+    fn test_sobol_bridge_paths_checks_the_grid() {
+        assert!(SobolBridgePaths::new(0).is_err());
+
+        let times = [0.0, 0.25, 0.5, 0.75, 1.0];
+        let mut sobol = SobolBridgePaths::new(4).unwrap();
+        assert_eq!(sobol.next_increments(&times).len(), 4);
+
+        // The second Sobol' point is the centre of the cube: no move.
+        let increments = sobol.next_increments(&times);
+        assert!(increments.iter().all(|dw| dw.abs() < 1e-9));
+
+        let result = std::panic::catch_unwind(move || sobol.next_increments(&[0.0, 1.0]));
+        assert!(result.is_err());
+    }
+}
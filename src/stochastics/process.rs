@@ -14,8 +14,10 @@
 //! Autonomous refers to processes where the drift and diffusion
 //! do not explicitly depend on the time `t`.
 
+use crate::stochastics::PathGenerator;
 use rand::prelude::Distribution;
-use rand::{rngs::StdRng, SeedableRng};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use rayon::prelude::*;
 // use statrs::distribution::Normal;
 
@@ -113,6 +115,13 @@ pub trait StochasticProcess: Sync {
     /// Base method for the process' jump term (if applicable).
     fn jump(&self, x: f64, t: f64) -> Option<f64>;
 
+    /// Exact scheme of the process, if its log-increments can be sampled
+    /// exactly. The simulation methods then use it instead of the
+    /// Euler-Maruyama discretisation.
+    fn exact_scheme(&self) -> Option<&dyn ExactLogIncrements> {
+        None
+    }
+
     /// Euler-Maruyama discretisation scheme.
     ///
     /// # Arguments:
@@ -131,6 +140,10 @@ pub trait StochasticProcess: Sync {
         m_paths: usize,
         parallel: bool,
     ) -> Trajectories {
+        if let Some(scheme) = self.exact_scheme() {
+            return exact_paths(scheme, x_0, t_0, t_n, n_steps, m_paths, None, parallel);
+        }
+
         assert!(t_0 < t_n);
 
         let dt: f64 = (t_n - t_0) / (n_steps as f64);
@@ -174,7 +187,8 @@ pub trait StochasticProcess: Sync {
     /// * `n_steps` - The number of time steps between `t_0` and `t_n`.
     /// * `m_paths` - How many process trajectories to simulate.
     /// * `parallel` - Run in parallel or not (recommended for > 1000 paths).
-    /// * `seed` - The seed for the random number generator.
+    /// * `seed` - The master seed of the random number generator; path `i`
+    ///   draws from stream `i` of a `ChaCha8` generator.
     fn seedable_euler_maruyama(
         &self,
         x_0: f64,
@@ -185,6 +199,19 @@ pub trait StochasticProcess: Sync {
         parallel: bool,
         seed: u64,
     ) -> Trajectories {
+        if let Some(scheme) = self.exact_scheme() {
+            return exact_paths(
+                scheme,
+                x_0,
+                t_0,
+                t_n,
+                n_steps,
+                m_paths,
+                Some(seed),
+                parallel,
+            );
+        }
+
         assert!(t_0 < t_n);
 
         let dt: f64 = (t_n - t_0) / (n_steps as f64);
@@ -193,8 +220,9 @@ pub trait StochasticProcess: Sync {
         let mut paths = vec![vec![x_0; n_steps + 1]; m_paths];
         let times: Vec<f64> = (0..=n_steps).map(|t| t_0 + dt * (t as f64)).collect();
 
-        let path_generator = |path: &mut Vec<f64>| {
-            let mut rng = StdRng::seed_from_u64(seed);
+        let path_generator = |(i, path): (usize, &mut Vec<f64>)| {
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+            rng.set_stream(i as u64);
             let scale = dt.sqrt();
             let dW: Vec<f64> = rand_distr::Normal::new(0.0, 1.0)
                 .unwrap()
@@ -211,19 +239,223 @@ pub trait StochasticProcess: Sync {
         };
 
         if parallel {
-            paths.par_iter_mut().for_each(path_generator);
+            paths.par_iter_mut().enumerate().for_each(path_generator);
         } else {
-            paths.iter_mut().for_each(path_generator);
+            paths.iter_mut().enumerate().for_each(path_generator);
         }
 
         Trajectories { times, paths }
     }
+
+    /// Euler-Maruyama discretisation scheme, with the paths split across
+    /// threads.
+    ///
+    /// Path `i` draws from stream `i` of a `ChaCha8` generator seeded with
+    /// `seed`. The streams are independent, and the paths only depend on
+    /// `seed` and their index, so the result is the same for any number of
+    /// threads.
+    ///
+    /// # Arguments:
+    /// * `x_0` - The process' initial value at `t_0`.
+    /// * `t_0` - The initial time point.
+    /// * `t_n` - The terminal time point.
+    /// * `n_steps` - The number of time steps between `t_0` and `t_n`.
+    /// * `m_paths` - How many process trajectories to simulate.
+    /// * `seed` - The master seed of the random number generator.
+    fn generate_parallel(
+        &self,
+        x_0: f64,
+        t_0: f64,
+        t_n: f64,
+        n_steps: usize,
+        m_paths: usize,
+        seed: u64,
+    ) -> Trajectories {
+        if let Some(scheme) = self.exact_scheme() {
+            return exact_paths(scheme, x_0, t_0, t_n, n_steps, m_paths, Some(seed), true);
+        }
+
+        assert!(t_0 < t_n);
+
+        let dt: f64 = (t_n - t_0) / (n_steps as f64);
+        let scale = dt.sqrt();
+
+        let times: Vec<f64> = (0..=n_steps).map(|t| t_0 + dt * (t as f64)).collect();
+
+        let paths = (0..m_paths)
+            .into_par_iter()
+            .map(|i| {
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                rng.set_stream(i as u64);
+
+                let mut path = Vec::with_capacity(n_steps + 1);
+                path.push(x_0);
+
+                let normal = rand_distr::StandardNormal;
+                for t in 0..n_steps {
+                    let x: f64 = path[t];
+                    let dW: f64 = normal.sample(&mut rng);
+
+                    path.push(
+                        x + self.drift(x, times[t]) * dt + self.diffusion(x, times[t]) * dW * scale,
+                    );
+                }
+
+                path
+            })
+            .collect();
+
+        Trajectories { times, paths }
+    }
+
+    /// Euler-Maruyama discretisation scheme, driven by the Brownian
+    /// increments of a path generator (e.g. quasi-random paths).
+    ///
+    /// # Arguments:
+    /// * `x_0` - The process' initial value at `t_0`.
+    /// * `t_0` - The initial time point.
+    /// * `t_n` - The terminal time point.
+    /// * `n_steps` - The number of time steps between `t_0` and `t_n`.
+    /// * `m_paths` - How many process trajectories to simulate.
+    /// * `generator` - The source of the Brownian increments.
+    fn generate_with<G: PathGenerator>(
+        &self,
+        x_0: f64,
+        t_0: f64,
+        t_n: f64,
+        n_steps: usize,
+        m_paths: usize,
+        generator: &mut G,
+    ) -> Trajectories
+    where
+        Self: Sized,
+    {
+        assert!(t_0 < t_n);
+
+        let dt: f64 = (t_n - t_0) / (n_steps as f64);
+        let times: Vec<f64> = (0..=n_steps).map(|t| t_0 + dt * (t as f64)).collect();
+
+        let paths = (0..m_paths)
+            .map(|_| {
+                let dW = generator.next_increments(&times);
+
+                let mut path = Vec::with_capacity(n_steps + 1);
+                path.push(x_0);
+
+                for t in 0..n_steps {
+                    let x = path[t];
+                    path.push(
+                        x + self.drift(x, times[t]) * dt + self.diffusion(x, times[t]) * dW[t],
+                    );
+                }
+
+                path
+            })
+            .collect();
+
+        Trajectories { times, paths }
+    }
+}
+
+/// Trait for price processes $S_t = S_0 e^{L_t}$ whose log-increments over a
+/// time step can be sampled exactly, such as the Lévy processes with jumps
+/// that the Brownian increments of the Euler-Maruyama scheme do not carry.
+///
+/// A `StochasticProcess` returning itself from `exact_scheme` is simulated
+/// with this scheme by all of its simulation methods (Euler-Maruyama, seeded
+/// and parallel alike), which honour their seed and `parallel` arguments.
+/// Its `drift`, `diffusion` and `jump` then only describe the dynamics, and
+/// are not used to simulate it.
+pub trait ExactLogIncrements: Sync {
+    /// Samples the log-increment $L_{t + \Delta t} - L_t$ over a time step
+    /// `dt`, drawing from the caller's random number generator. The
+    /// increment over a zero time step (e.g. a repeated time point) is zero.
+    fn sample_log_increment(&self, rng: &mut dyn RngCore, dt: f64) -> f64;
+
+    /// Exact simulation of the process, with the paths split across threads.
+    ///
+    /// # Arguments:
+    /// * `x_0` - The process' initial value at `t_0`.
+    /// * `t_0` - The initial time point.
+    /// * `t_n` - The terminal time point.
+    /// * `n_steps` - The number of time steps between `t_0` and `t_n`.
+    /// * `m_paths` - How many process trajectories to simulate.
+    /// * `seed` - The master seed of the random number generator; path `i`
+    ///   draws from stream `i` of a `ChaCha8` generator.
+    fn exact_simulation(
+        &self,
+        x_0: f64,
+        t_0: f64,
+        t_n: f64,
+        n_steps: usize,
+        m_paths: usize,
+        seed: u64,
+    ) -> Trajectories
+    where
+        Self: Sized,
+    {
+        exact_paths(self, x_0, t_0, t_n, n_steps, m_paths, Some(seed), true)
+    }
+}
+
+/// Paths of $x_0 e^{L_t}$, with the increments of $L$ drawn by an exact
+/// scheme. With a seed, path `i` draws from stream `i` of a `ChaCha8`
+/// generator, so the paths are the same with or without threads; otherwise
+/// each path draws from the thread-local generator.
+#[allow(clippy::too_many_arguments)]
+fn exact_paths(
+    scheme: &dyn ExactLogIncrements,
+    x_0: f64,
+    t_0: f64,
+    t_n: f64,
+    n_steps: usize,
+    m_paths: usize,
+    seed: Option<u64>,
+    parallel: bool,
+) -> Trajectories {
+    assert!(t_0 < t_n);
+
+    let dt: f64 = (t_n - t_0) / (n_steps as f64);
+    let times: Vec<f64> = (0..=n_steps).map(|t| t_0 + dt * (t as f64)).collect();
+
+    let path_generator = |i: usize| {
+        let mut path = Vec::with_capacity(n_steps + 1);
+        path.push(x_0);
+
+        let mut fill = |rng: &mut dyn RngCore| {
+            for t in 0..n_steps {
+                path.push(path[t] * scheme.sample_log_increment(rng, dt).exp());
+            }
+        };
+
+        match seed {
+            Some(seed) => {
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                rng.set_stream(i as u64);
+                fill(&mut rng);
+            }
+            None => fill(&mut rand::thread_rng()),
+        }
+
+        path
+    };
+
+    let paths = if parallel {
+        (0..m_paths).into_par_iter().map(path_generator).collect()
+    } else {
+        (0..m_paths).map(path_generator).collect()
+    };
+
+    Trajectories { times, paths }
 }
 
 #[cfg(test)]
 mod test_process {
     use crate::models::geometric_brownian_motion::GeometricBrownianMotion;
-    use crate::stochastics::process::StochasticProcess;
+    use crate::models::kou_jump_diffusion::KouJumpDiffusion;
+    use crate::models::normal_inverse_gaussian::NormalInverseGaussian;
+    use crate::models::variance_gamma::VarianceGamma;
+    use crate::stochastics::process::{ExactLogIncrements, StochasticProcess};
     use std::time::Instant;
 
     #[test]
@@ -269,7 +501,76 @@ mod test_process {
         // Check that using a different seed gives a different output.
         assert_ne!(output_first_seed.paths, output_different_seed.paths);
 
+        // Check that the paths are not copies of each other.
+        assert_ne!(output_first_seed.paths[0], output_first_seed.paths[1]);
+
+        // Nearby seeds do not share shifted paths.
+        let output_next_seed =
+            gbm.seedable_euler_maruyama(10.0, 0.0, 1.0, 125, 10000, true, 123456790);
+        assert_ne!(output_next_seed.paths[0], output_first_seed.paths[1]);
+
         // To see the output of this "test", run:
         // cargo test test_process -- --nocapture
     }
+
+    #[test]
+    fn test_generate_parallel() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+
+        let output = gbm.generate_parallel(10.0, 0.0, 1.0, 50, 20000, 42);
+        assert_eq!(output.times.len(), 51);
+        assert_eq!(output.paths.len(), 20000);
+        assert!(output.paths.iter().all(|path| path.len() == 51));
+
+        // The same seed gives the same paths, on any number of threads.
+        let single_thread = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap()
+            .install(|| gbm.generate_parallel(10.0, 0.0, 1.0, 50, 20000, 42));
+        assert_eq!(output.paths, single_thread.paths);
+
+        // A different seed gives different paths, and the paths are not
+        // copies of each other.
+        let different_seed = gbm.generate_parallel(10.0, 0.0, 1.0, 50, 20000, 43);
+        assert_ne!(output.paths, different_seed.paths);
+        assert_ne!(output.paths[0], output.paths[1]);
+
+        // E[X_T] = X_0 exp(mu T), within about three standard errors.
+        let mean = output.paths.iter().map(|path| path[50]).sum::<f64>() / 20000.0;
+        assert!((mean - 10.0 * 0.05_f64.exp()).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_exact_scheme() {
+        let model = KouJumpDiffusion::new(0.05, 0.15, 1.5, 0.35, 10.0, 5.0);
+
+        // The seeded paths are the same with or without threads.
+        let exact = model.exact_simulation(10.0, 0.0, 1.0, 20, 100, 7);
+        let serial = model.seedable_euler_maruyama(10.0, 0.0, 1.0, 20, 100, false, 7);
+        let parallel = model.generate_parallel(10.0, 0.0, 1.0, 20, 100, 7);
+        assert_eq!(exact.paths, serial.paths);
+        assert_eq!(exact.paths, parallel.paths);
+
+        // The unseeded paths honour `parallel` and differ between runs.
+        let first = model.euler_maruyama(10.0, 0.0, 1.0, 20, 100, false);
+        let second = model.euler_maruyama(10.0, 0.0, 1.0, 20, 100, true);
+        assert_eq!(second.paths.len(), 100);
+        assert_ne!(first.paths, second.paths);
+        assert!(first.paths.iter().flatten().all(|x| *x > 0.0));
+    }
+
+    #[test]
+    fn test_exact_scheme_zero_time_step() {
+        let mut rng = rand::thread_rng();
+        let schemes: [&dyn ExactLogIncrements; 3] = [
+            &KouJumpDiffusion::new(0.05, 0.15, 1.5, 0.35, 10.0, 5.0),
+            &VarianceGamma::new(0.2, 0.3, -0.15).with_drift(0.05),
+            &NormalInverseGaussian::new(0.05, 8.0, -3.0, 0.4),
+        ];
+
+        for scheme in schemes {
+            assert_eq!(scheme.sample_log_increment(&mut rng, 0.0), 0.0);
+        }
+    }
 }
@@ -0,0 +1,132 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::models::variance_gamma::VarianceGamma;
+use crate::stochastics::process::{ExactLogIncrements, StochasticProcess};
+use rand::{Rng, RngCore};
+use rand_distr::{Gamma, StandardNormal};
+
+/// A pure-jump process: the gamma time-changed increments come from its
+/// exact scheme.
+impl StochasticProcess for VarianceGamma {
+    fn drift(&self, x: f64, _t: f64) -> f64 {
+        self.mu * x
+    }
+
+    fn diffusion(&self, _x: f64, _t: f64) -> f64 {
+        0.0
+    }
+
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
+    }
+
+    fn exact_scheme(&self) -> Option<&dyn ExactLogIncrements> {
+        Some(self)
+    }
+}
+
+/// Exact simulation of the variance gamma process, by sampling the gamma
+/// time change over each time step and the Brownian motion at it:
+///
+/// $$
+/// \ln \frac{S_{t + \Delta t}}{S_t} = (\mu + \omega) \Delta t + \theta G + \sigma \sqrt{G} Z,
+/// \quad G \sim \Gamma(\Delta t / \nu, \nu)
+/// $$
+impl ExactLogIncrements for VarianceGamma {
+    fn sample_log_increment(&self, rng: &mut dyn RngCore, dt: f64) -> f64 {
+        if dt == 0.0 {
+            return 0.0;
+        }
+
+        let gamma = Gamma::new(dt / self.nu, self.nu).expect("positive parameters");
+        let g: f64 = rng.sample(gamma);
+        let z: f64 = rng.sample(StandardNormal);
+
+        (self.mu + self.drift_correction()) * dt + self.theta * g + self.sigma * g.sqrt() * z
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_variance_gamma {
+    use super::*;
+    use crate::instruments::{CharacteristicFunction, CosMethod, FourierPricer, TypeFlag};
+    use num::Complex;
+
+    #[test]
+    fn test_exact_simulation_prices_match_fourier() {
+        let (spot, r, t) = (100.0, 0.05, 1.0);
+        let model = VarianceGamma::new(0.2, 0.3, -0.15).with_drift(0.05);
+
+        let n_paths = 50_000;
+        let output = model.exact_simulation(spot, 0.0, t, 4, n_paths, 42);
+        let terminal: Vec<f64> = output.paths.iter().map(|path| path[4]).collect();
+
+        // The discounted price is a martingale.
+        let mean = terminal.iter().sum::<f64>() / n_paths as f64;
+        let std_dev = (terminal.iter().map(|s| (s - mean).powi(2)).sum::<f64>()
+            / (n_paths - 1) as f64)
+            .sqrt();
+        assert!((mean - spot * (r * t).exp()).abs() < 4.0 * std_dev / (n_paths as f64).sqrt());
+
+        // Calls priced by Monte Carlo and by the COS method agree.
+        for strike in [80.0, 100.0, 120.0] {
+            let payoffs: Vec<f64> = terminal
+                .iter()
+                .map(|s| (-r * t).exp() * (s - strike).max(0.0))
+                .collect();
+            let price = payoffs.iter().sum::<f64>() / n_paths as f64;
+            let error = (payoffs.iter().map(|p| (p - price).powi(2)).sum::<f64>()
+                / (n_paths - 1) as f64
+                / n_paths as f64)
+                .sqrt();
+
+            let cos = CosMethod::default().price(&model, spot, strike, r, 0.0, t, TypeFlag::Call);
+            assert!((price - cos).abs() < 4.0 * error);
+        }
+
+        // The same seed gives the same paths.
+        let again = model.seedable_euler_maruyama(spot, 0.0, t, 4, 10, false, 42);
+        assert_eq!(again.paths[..], output.paths[..10]);
+    }
+
+    #[test]
+    fn test_characteristic_function() {
+        let model = VarianceGamma::new(0.2, 0.3, -0.15).with_drift(0.05);
+        let one = Complex::new(1.0, 0.0);
+
+        // phi(0) = 1, and phi(-i) = E[S_t / F] = 1.
+        assert_approx_equal!(
+            (model.characteristic_function(Complex::new(0.0, 0.0), 0.5) - one).norm(),
+            0.0,
+            1e-14
+        );
+        assert_approx_equal!(
+            (model.characteristic_function(-Complex::i(), 0.5) - one).norm(),
+            0.0,
+            1e-12
+        );
+
+        // The closed-form cumulants agree with those of the characteristic
+        // function.
+        let log_phi = |u: f64| {
+            model
+                .characteristic_function(Complex::new(u, 0.0), 0.5)
+                .ln()
+        };
+        let h = 1e-4;
+        let (c1, c2, _) = model.cumulants(0.5);
+        assert_approx_equal!(c1, (log_phi(h) - log_phi(-h)).im / (2.0 * h), 1e-6);
+        assert_approx_equal!(c2, -(log_phi(h) + log_phi(-h)).re / (h * h), 1e-5);
+    }
+}
@@ -9,8 +9,14 @@
 
 //! This module defines a `Calendar` type and its methods.
 
-use crate::time::utilities::is_weekend;
-use time::Date;
+use crate::error::RustQuantError;
+use crate::instruments::currency::Currency;
+use crate::time::utilities::{
+    add_months, is_weekend, last_business_day_of_month, try_advance_business_days,
+};
+use crate::time::{DateRoller, DateRollingConvention, Period, TimeUnit};
+use std::collections::BTreeSet;
+use time::{Date, Duration, Weekday};
 
 /// Calendar metadata struct.
 pub struct CalendarMetadata {
@@ -25,11 +31,79 @@ pub struct CalendarMetadata {
     pub market_identifier_code: crate::iso::ISO_10383,
 }
 
+/// How a [`JointCalendar`] combines the business days of its calendars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JointCalendarRule {
+    /// A date is a holiday if it is a holiday in any of the calendars,
+    /// i.e. a business day only if it is one in all of them
+    /// (e.g. the payment dates of a cross-currency swap).
+    #[default]
+    JoinHolidays,
+
+    /// A date is a business day if it is one in any of the calendars,
+    /// i.e. a holiday only if it is a holiday in all of them.
+    JoinBusinessDays,
+}
+
+/// A calendar combining several calendars, with holidays or business days
+/// joined according to a [`JointCalendarRule`].
+pub struct JointCalendar {
+    /// The combined calendars.
+    calendars: Vec<Box<dyn Calendar>>,
+
+    /// How the calendars are combined.
+    rule: JointCalendarRule,
+
+    /// Names of the calendars, joined by " + ".
+    name: String,
+
+    /// ISO codes of the calendars, joined by "+".
+    iso_code: String,
+}
+
+/// A user-defined calendar, from an explicit list of holidays (e.g. loaded
+/// from a file or a vendor feed) and a configurable weekend.
+///
+/// Holidays can be added and removed after construction.
+pub struct CustomCalendar {
+    /// Name of the calendar.
+    name: String,
+
+    /// The holidays of the calendar.
+    holidays: BTreeSet<Date>,
+
+    /// The days of the weekend, Saturday and Sunday unless given.
+    weekend: Vec<Weekday>,
+
+    /// ISO 3166-1 country code.
+    country_code: crate::iso::ISO_3166,
+
+    /// ISO 10383 market identifier code.
+    market_identifier_code: crate::iso::ISO_10383,
+
+    /// Currency of the market, if known.
+    currency: Option<Currency>,
+}
+
+/// Iterator over the business days of a calendar from a start date
+/// (inclusive) to an end date (exclusive), see [`Calendar::business_day_iter`].
+pub struct BusinessDayIter<'a, C: Calendar> {
+    /// The calendar of the business days.
+    calendar: &'a C,
+
+    /// The next date to check.
+    date: Date,
+
+    /// The end date (exclusive).
+    end: Date,
+}
+
 /// Calendar trait.
 pub trait Calendar {
     /// Name of the calendar, typically the country name, but could also be
     /// a region/subdivision or a special calendar, such as a financial calendar (e.g. NYSE).
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &str;
 
     /// Check if the date is a holiday (but not a weekend).
     /// This is the primary method to implement for a calendar.
@@ -41,6 +115,17 @@ pub trait Calendar {
     /// Returns the ISO 10383 market identifier code.
     fn market_identifier_code(&self) -> crate::iso::ISO_10383;
 
+    /// Returns the currency of the market, if known.
+    fn currency(&self) -> Option<Currency> {
+        None
+    }
+
+    /// Returns the ISO code of the calendar, by default the ISO 3166-1
+    /// alpha-2 country code (e.g. "US").
+    fn iso_code(&self) -> &str {
+        self.country_code().alpha_2()
+    }
+
     /// Check if the date is a business day.
     /// A business day is a day that is not a holiday and not a weekend.
     fn is_business_day(&self, date: Date) -> bool {
@@ -84,12 +169,414 @@ pub trait Calendar {
 
         business_days
     }
+
+    /// Number of business days from `start_date` (inclusive) to `end_date`
+    /// (exclusive), e.g. the numerator of the Business/252 day count.
+    /// Negative if the end date is before the start date.
+    fn business_days_between(&self, start_date: Date, end_date: Date) -> i64
+    where
+        Self: Sized,
+    {
+        if end_date < start_date {
+            return -self.business_days_between(end_date, start_date);
+        }
+
+        self.business_day_iter(start_date, end_date).count() as i64
+    }
+
+    /// Iterator over the business days from `start_date` (inclusive) to
+    /// `end_date` (exclusive).
+    fn business_day_iter(&self, start_date: Date, end_date: Date) -> BusinessDayIter<'_, Self>
+    where
+        Self: Sized,
+    {
+        BusinessDayIter {
+            calendar: self,
+            date: start_date,
+            end: end_date,
+        }
+    }
+
+    /// Advance a date by a period, e.g. to a maturity date from a tenor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no business day can be found
+    /// (see [`Calendar::try_advance`]).
+    fn advance(
+        &self,
+        date: Date,
+        period: Period,
+        convention: DateRollingConvention,
+        end_of_month: bool,
+    ) -> Date
+    where
+        Self: Sized,
+    {
+        match self.try_advance(date, period, convention, end_of_month) {
+            Ok(new_date) => new_date,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Advance a date by a period, e.g. to a maturity date from a tenor.
+    ///
+    /// Business days are counted on the calendar, so the convention does not
+    /// apply to them. Other periods are added to the date, and the result is
+    /// rolled with the convention. With `end_of_month` set and the date on the
+    /// last business day of its month, periods of months and years instead end
+    /// on the last business day of the resulting month.
+    ///
+    /// # Errors
+    /// - `RustQuantError::NotConverged` if no business day can be found.
+    fn try_advance(
+        &self,
+        date: Date,
+        period: Period,
+        convention: DateRollingConvention,
+        end_of_month: bool,
+    ) -> Result<Date, RustQuantError>
+    where
+        Self: Sized,
+    {
+        let length = period.length;
+
+        let months = match period.unit {
+            TimeUnit::BusinessDays => {
+                return try_advance_business_days(date, length.into(), self);
+            }
+            TimeUnit::Days => {
+                return self.try_roll_date(date + Duration::days(length.into()), &convention);
+            }
+            TimeUnit::Weeks => {
+                return self.try_roll_date(date + Duration::weeks(length.into()), &convention);
+            }
+            TimeUnit::Months => length,
+            TimeUnit::Years => 12 * length,
+        };
+
+        let unadjusted = add_months(date, months);
+
+        if end_of_month && date == last_business_day_of_month(date, self)? {
+            return last_business_day_of_month(unadjusted, self);
+        }
+
+        self.try_roll_date(unadjusted, &convention)
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // IMPLEMENTATIONS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+impl JointCalendar {
+    /// New joint calendar from its component calendars, joining their
+    /// holidays (see [`JointCalendarRule::JoinHolidays`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `calendars` is empty.
+    #[must_use]
+    pub fn new(calendars: Vec<Box<dyn Calendar>>) -> Self {
+        Self::with_rule(calendars, JointCalendarRule::JoinHolidays)
+    }
+
+    /// New joint calendar from its component calendars, combined by the given rule.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `calendars` is empty.
+    #[must_use]
+    pub fn with_rule(calendars: Vec<Box<dyn Calendar>>, rule: JointCalendarRule) -> Self {
+        assert!(
+            !calendars.is_empty(),
+            "A joint calendar needs at least one calendar."
+        );
+
+        let name = calendars
+            .iter()
+            .map(|c| c.name())
+            .collect::<Vec<_>>()
+            .join(" + ");
+        let iso_code = calendars
+            .iter()
+            .map(|c| c.iso_code())
+            .collect::<Vec<_>>()
+            .join("+");
+
+        Self {
+            calendars,
+            rule,
+            name,
+            iso_code,
+        }
+    }
+
+    /// The component calendars.
+    #[must_use]
+    pub fn calendars(&self) -> &[Box<dyn Calendar>] {
+        &self.calendars
+    }
+
+    /// How the calendars are combined.
+    #[must_use]
+    pub fn rule(&self) -> JointCalendarRule {
+        self.rule
+    }
+}
+
+impl Calendar for JointCalendar {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        match self.rule {
+            JointCalendarRule::JoinHolidays => self.calendars.iter().any(|c| c.is_holiday(date)),
+            JointCalendarRule::JoinBusinessDays => {
+                self.calendars.iter().all(|c| c.is_holiday(date))
+            }
+        }
+    }
+
+    /// Combines the business days of the calendars, so that calendars with
+    /// their own weekends are respected.
+    fn is_business_day(&self, date: Date) -> bool {
+        match self.rule {
+            JointCalendarRule::JoinHolidays => {
+                self.calendars.iter().all(|c| c.is_business_day(date))
+            }
+            JointCalendarRule::JoinBusinessDays => {
+                self.calendars.iter().any(|c| c.is_business_day(date))
+            }
+        }
+    }
+
+    /// Country code of the first calendar.
+    fn country_code(&self) -> crate::iso::ISO_3166 {
+        self.calendars[0].country_code()
+    }
+
+    /// Market identifier code of the first calendar.
+    fn market_identifier_code(&self) -> crate::iso::ISO_10383 {
+        self.calendars[0].market_identifier_code()
+    }
+
+    /// The common currency of the calendars, or `None` if they differ.
+    fn currency(&self) -> Option<Currency> {
+        let currency = self.calendars[0].currency()?;
+
+        self.calendars
+            .iter()
+            .all(|c| c.currency() == Some(currency))
+            .then_some(currency)
+    }
+
+    fn iso_code(&self) -> &str {
+        &self.iso_code
+    }
+}
+
+/// Joint calendars serialize by the ISO codes of their calendars and their
+/// rule, e.g. `{"calendars": ["US", "GB"], "rule": "JoinHolidays"}`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for JointCalendar {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let codes: Vec<&str> = self.calendars.iter().map(|c| c.iso_code()).collect();
+
+        let mut state = serializer.serialize_struct("JointCalendar", 2)?;
+        state.serialize_field("calendars", &codes)?;
+        state.serialize_field("rule", &self.rule)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for JointCalendar {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Codes {
+            calendars: Vec<String>,
+            #[serde(default)]
+            rule: JointCalendarRule,
+        }
+
+        let codes = Codes::deserialize(deserializer)?;
+
+        if codes.calendars.is_empty() {
+            return Err(serde::de::Error::custom(
+                "a joint calendar needs at least one calendar",
+            ));
+        }
+
+        let calendars = codes
+            .calendars
+            .iter()
+            .map(|code| {
+                crate::time::calendar_from_code(code)
+                    .ok_or_else(|| serde::de::Error::custom(format!("unknown calendar: '{code}'")))
+            })
+            .collect::<Result<Vec<_>, D::Error>>()?;
+
+        Ok(Self::with_rule(calendars, codes.rule))
+    }
+}
+
+/// (De)serialization of boxed calendars by their ISO codes (see
+/// [`calendar_from_code`](crate::time::calendar_from_code)), e.g. "US" or
+/// "US+GB", for fields marked `#[serde(with = "serde_calendar")]`.
+#[cfg(feature = "serde")]
+pub mod serde_calendar {
+    use super::Calendar;
+
+    /// Serialize a calendar as its ISO code.
+    #[allow(clippy::borrowed_box)]
+    pub fn serialize<S: serde::Serializer>(
+        calendar: &Box<dyn Calendar>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(calendar.iso_code())
+    }
+
+    /// Deserialize a calendar from its ISO code.
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Box<dyn Calendar>, D::Error> {
+        let code: String = serde::Deserialize::deserialize(deserializer)?;
+
+        crate::time::calendar_from_code(&code)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown calendar: '{code}'")))
+    }
+}
+
+impl<C: Calendar> Iterator for BusinessDayIter<'_, C> {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.date < self.end {
+            let date = self.date;
+            self.date = date.next_day()?;
+
+            if self.calendar.is_business_day(date) {
+                return Some(date);
+            }
+        }
+
+        None
+    }
+}
+
+impl CustomCalendar {
+    /// New custom calendar without holidays, and with a Saturday and Sunday weekend.
+    #[must_use]
+    pub fn new(
+        name: &str,
+        country_code: crate::iso::ISO_3166,
+        market_identifier_code: crate::iso::ISO_10383,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            holidays: BTreeSet::new(),
+            weekend: vec![Weekday::Saturday, Weekday::Sunday],
+            country_code,
+            market_identifier_code,
+            currency: None,
+        }
+    }
+
+    /// Set the holidays of the calendar, replacing any existing ones.
+    #[must_use]
+    pub fn with_holidays<I: IntoIterator<Item = Date>>(mut self, holidays: I) -> Self {
+        self.holidays = holidays.into_iter().collect();
+        self
+    }
+
+    /// Set the days of the weekend, e.g. Friday and Saturday for most of the
+    /// Middle East. An empty slice means there is no weekend.
+    #[must_use]
+    pub fn with_weekend(mut self, weekend: &[Weekday]) -> Self {
+        self.weekend = weekend.to_vec();
+        self
+    }
+
+    /// Set the currency of the market.
+    #[must_use]
+    pub fn with_currency(mut self, currency: Currency) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Add a holiday to the calendar.
+    /// Returns `false` if the date was already a holiday.
+    pub fn add_holiday(&mut self, date: Date) -> bool {
+        self.holidays.insert(date)
+    }
+
+    /// Remove a holiday from the calendar.
+    /// Returns `false` if the date was not a holiday.
+    pub fn remove_holiday(&mut self, date: Date) -> bool {
+        self.holidays.remove(&date)
+    }
+
+    /// The holidays of the calendar, in chronological order.
+    pub fn holidays(&self) -> impl Iterator<Item = &Date> {
+        self.holidays.iter()
+    }
+
+    /// The days of the weekend.
+    #[must_use]
+    pub fn weekend(&self) -> &[Weekday] {
+        &self.weekend
+    }
+
+    /// Check if the date falls on the weekend of the calendar.
+    #[must_use]
+    pub fn is_weekend(&self, date: Date) -> bool {
+        self.weekend.contains(&date.weekday())
+    }
+}
+
+impl Calendar for CustomCalendar {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        self.holidays.contains(&date)
+    }
+
+    fn country_code(&self) -> crate::iso::ISO_3166 {
+        self.country_code
+    }
+
+    fn market_identifier_code(&self) -> crate::iso::ISO_10383 {
+        self.market_identifier_code
+    }
+
+    fn currency(&self) -> Option<Currency> {
+        self.currency
+    }
+
+    /// A business day is a day that is not a holiday and not on the
+    /// weekend of the calendar.
+    fn is_business_day(&self, date: Date) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+
+    fn all_holidays_between(&self, start_date: Date, end_date: Date) -> Vec<Date> {
+        if start_date > end_date {
+            return Vec::new();
+        }
+
+        self.holidays
+            .range(start_date..=end_date)
+            .copied()
+            .collect()
+    }
+}
+
 impl CalendarMetadata {
     /// New calendar metadata.
     pub fn new(
@@ -119,3 +606,293 @@ impl CalendarMetadata {
         self.market_identifier_code
     }
 }
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_calendar {
+    use super::*;
+    use crate::time::countries::{
+        europe::{
+            france::FranceCalendar, germany::GermanyCalendar, united_kingdom::UnitedKingdomCalendar,
+        },
+        north_america::united_states::UnitedStatesCalendar,
+    };
+    use crate::time::{DateRoller, DateRollingConvention};
+
+    #[test]
+    fn test_calendar_metadata() {
+        let calendar = UnitedStatesCalendar;
+
+        assert!(calendar.name().starts_with("United States"));
+        assert_eq!(calendar.currency(), Some(crate::iso::USD));
+        assert_eq!(calendar.iso_code(), "US");
+    }
+
+    #[test]
+    fn test_joint_calendar_metadata() {
+        let calendar = JointCalendar::new(vec![
+            Box::new(UnitedStatesCalendar),
+            Box::new(UnitedKingdomCalendar),
+        ]);
+
+        assert_eq!(
+            calendar.name(),
+            format!(
+                "{} + {}",
+                UnitedStatesCalendar.name(),
+                UnitedKingdomCalendar.name()
+            )
+        );
+        assert_eq!(calendar.iso_code(), "US+GB");
+        assert_eq!(calendar.currency(), None);
+
+        // Calendars sharing a currency keep it.
+        let euro = JointCalendar::new(vec![Box::new(FranceCalendar), Box::new(GermanyCalendar)]);
+
+        assert_eq!(euro.currency(), Some(crate::iso::EUR));
+
+        // Holidays of either calendar are holidays of the joint calendar:
+        // 4th July 2024 (US) and 26th August 2024 (UK summer bank holiday).
+        assert!(calendar.is_holiday(time::macros::date!(2024 - 07 - 04)));
+        assert!(calendar.is_holiday(time::macros::date!(2024 - 08 - 26)));
+        assert!(calendar.is_business_day(time::macros::date!(2024 - 08 - 27)));
+    }
+
+    #[test]
+    fn test_joint_calendar_rules() {
+        use time::macros::date;
+
+        let calendars = || -> Vec<Box<dyn Calendar>> {
+            vec![
+                Box::new(UnitedStatesCalendar),
+                Box::new(UnitedKingdomCalendar),
+            ]
+        };
+        let holidays = JointCalendar::new(calendars());
+        let business_days =
+            JointCalendar::with_rule(calendars(), JointCalendarRule::JoinBusinessDays);
+
+        assert_eq!(holidays.rule(), JointCalendarRule::JoinHolidays);
+        assert_eq!(business_days.rule(), JointCalendarRule::JoinBusinessDays);
+
+        // 4th July 2024 is a US holiday only, 25th December 2024 is a holiday
+        // in both, and 23rd November 2024 is a Saturday.
+        assert!(!holidays.is_business_day(date!(2024 - 07 - 04)));
+        assert!(business_days.is_business_day(date!(2024 - 07 - 04)));
+        assert!(!business_days.is_business_day(date!(2024 - 12 - 25)));
+        assert!(!business_days.is_business_day(date!(2024 - 11 - 23)));
+
+        // Rolling a New York + London payment over Independence Day.
+        assert_eq!(
+            holidays.roll_date(date!(2024 - 07 - 04), &DateRollingConvention::Following),
+            date!(2024 - 07 - 05)
+        );
+        assert_eq!(
+            business_days.roll_date(date!(2024 - 07 - 04), &DateRollingConvention::Following),
+            date!(2024 - 07 - 04)
+        );
+
+        // Boxing Day is a UK holiday, so only joining business days rolls
+        // Christmas to the 26th.
+        assert_eq!(
+            holidays.roll_date(date!(2024 - 12 - 25), &DateRollingConvention::Following),
+            date!(2024 - 12 - 27)
+        );
+        assert_eq!(
+            business_days.roll_date(date!(2024 - 12 - 25), &DateRollingConvention::Following),
+            date!(2024 - 12 - 26)
+        );
+    }
+
+    #[test]
+    fn test_custom_calendar() {
+        use time::macros::date;
+
+        let mut calendar =
+            CustomCalendar::new("Vendor Feed", crate::iso::SAUDI_ARABIA, crate::iso::XSAU)
+                .with_holidays([date!(2024 - 09 - 23), date!(2024 - 04 - 10)])
+                .with_weekend(&[Weekday::Friday, Weekday::Saturday])
+                .with_currency(crate::iso::SAR);
+
+        assert_eq!(calendar.name(), "Vendor Feed");
+        assert_eq!(calendar.iso_code(), "SA");
+        assert_eq!(calendar.currency(), Some(crate::iso::SAR));
+
+        // Friday and Saturday are the weekend, Sunday is a business day.
+        assert!(!calendar.is_business_day(date!(2024 - 09 - 20)));
+        assert!(!calendar.is_business_day(date!(2024 - 09 - 21)));
+        assert!(calendar.is_business_day(date!(2024 - 09 - 22)));
+        assert!(!calendar.is_business_day(date!(2024 - 09 - 23)));
+
+        // Rolling forward from Thursday's holiday skips the Friday/Saturday weekend.
+        assert!(calendar.add_holiday(date!(2024 - 09 - 19)));
+        assert!(!calendar.add_holiday(date!(2024 - 09 - 19)));
+        assert_eq!(
+            calendar.roll_date(date!(2024 - 09 - 19), &DateRollingConvention::Following),
+            date!(2024 - 09 - 22)
+        );
+
+        assert_eq!(
+            calendar.all_holidays_between(date!(2024 - 01 - 01), date!(2024 - 12 - 31)),
+            vec![
+                date!(2024 - 04 - 10),
+                date!(2024 - 09 - 19),
+                date!(2024 - 09 - 23),
+            ]
+        );
+
+        assert!(calendar.remove_holiday(date!(2024 - 09 - 23)));
+        assert!(!calendar.remove_holiday(date!(2024 - 09 - 23)));
+        assert!(calendar.is_business_day(date!(2024 - 09 - 23)));
+        assert_eq!(calendar.holidays().count(), 2);
+    }
+
+    #[test]
+    fn test_advance_by_period() {
+        use time::macros::date;
+
+        let calendar = UnitedStatesCalendar;
+        let following = DateRollingConvention::ModifiedFollowing;
+
+        // T+2 spot from Friday 12th January 2024 skips Martin Luther King Jr. Day.
+        assert_eq!(
+            calendar.advance(
+                date!(2024 - 01 - 12),
+                Period::business_days(2),
+                following,
+                false
+            ),
+            date!(2024 - 01 - 17)
+        );
+        assert_eq!(
+            calendar.advance(
+                date!(2024 - 01 - 17),
+                Period::business_days(-2),
+                following,
+                false
+            ),
+            date!(2024 - 01 - 12)
+        );
+
+        // Calendar days and weeks are rolled: 1W from 27th June is 4th July,
+        // and 2D is Saturday 29th June, which stays in June.
+        assert_eq!(
+            calendar.advance(date!(2024 - 06 - 27), Period::weeks(1), following, false),
+            date!(2024 - 07 - 05)
+        );
+        assert_eq!(
+            calendar.advance(date!(2024 - 06 - 27), Period::days(2), following, false),
+            date!(2024 - 06 - 28)
+        );
+
+        // 3M from 30th April 2024 (the last business day of April).
+        assert_eq!(
+            calendar.advance(date!(2024 - 04 - 30), Period::months(3), following, false),
+            date!(2024 - 07 - 30)
+        );
+        assert_eq!(
+            calendar.advance(date!(2024 - 04 - 30), Period::months(3), following, true),
+            date!(2024 - 07 - 31)
+        );
+
+        // 28th June 2024 is a Friday, the last business day of June, and
+        // 5Y later the month ends on a Saturday.
+        assert_eq!(
+            calendar.advance(date!(2024 - 06 - 28), Period::years(5), following, true),
+            date!(2029 - 06 - 29)
+        );
+        assert_eq!(
+            calendar.advance(date!(2024 - 08 - 30), Period::years(1), following, true),
+            date!(2025 - 08 - 29)
+        );
+
+        // Not at the end of the month, the rule does not apply.
+        assert_eq!(
+            calendar.advance(date!(2024 - 06 - 27), Period::months(1), following, true),
+            date!(2024 - 07 - 29)
+        );
+    }
+
+    #[test]
+    fn test_business_days_between() {
+        use time::macros::date;
+
+        let calendar = UnitedStatesCalendar;
+
+        // January 2024 has 23 weekdays, two of which are holidays
+        // (New Year's Day and Martin Luther King Jr. Day).
+        let (start, end) = (date!(2024 - 01 - 01), date!(2024 - 02 - 01));
+
+        assert_eq!(calendar.business_days_between(start, end), 21);
+        assert_eq!(calendar.business_days_between(end, start), -21);
+        assert_eq!(calendar.business_days_between(start, start), 0);
+
+        let days: Vec<Date> = calendar.business_day_iter(start, end).collect();
+
+        assert_eq!(days.len(), 21);
+        assert_eq!(days[0], date!(2024 - 01 - 02));
+        assert!(!days.contains(&date!(2024 - 01 - 15)));
+        assert!(days.iter().all(|day| calendar.is_business_day(*day)));
+
+        // The end date is excluded, the start date included.
+        assert_eq!(
+            calendar.business_days_between(date!(2024 - 01 - 02), date!(2024 - 01 - 03)),
+            1
+        );
+        assert_eq!(
+            calendar.all_business_days_between(start, end.previous_day().unwrap()),
+            days
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests_calendar_serde {
+    use super::*;
+    use crate::time::countries::{
+        europe::united_kingdom::UnitedKingdomCalendar,
+        north_america::united_states::UnitedStatesCalendar,
+    };
+
+    #[test]
+    fn test_calendars_serialize_by_code() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Conventions {
+            #[serde(with = "serde_calendar")]
+            calendar: Box<dyn Calendar>,
+            joint: JointCalendar,
+        }
+
+        let conventions = Conventions {
+            calendar: Box::new(UnitedStatesCalendar),
+            joint: JointCalendar::with_rule(
+                vec![
+                    Box::new(UnitedStatesCalendar),
+                    Box::new(UnitedKingdomCalendar),
+                ],
+                JointCalendarRule::JoinBusinessDays,
+            ),
+        };
+
+        let json = serde_json::to_string(&conventions).unwrap();
+        assert_eq!(
+            json,
+            r#"{"calendar":"US","joint":{"calendars":["US","GB"],"rule":"JoinBusinessDays"}}"#
+        );
+
+        let parsed: Conventions = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.calendar.iso_code(), "US");
+        assert_eq!(parsed.joint.iso_code(), "US+GB");
+        assert_eq!(parsed.joint.rule(), JointCalendarRule::JoinBusinessDays);
+
+        // The rule defaults to joining holidays.
+        let joint: JointCalendar = serde_json::from_str(r#"{"calendars":["US+GB"]}"#).unwrap();
+        assert_eq!(joint.rule(), JointCalendarRule::JoinHolidays);
+
+        assert!(serde_json::from_str::<JointCalendar>(r#"{"calendars":["XX"]}"#).is_err());
+        assert!(serde_json::from_str::<JointCalendar>(r#"{"calendars":[]}"#).is_err());
+    }
+}
@@ -39,6 +39,10 @@ impl Calendar for BotswanaCalendar {
         crate::iso::XBOT
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::BWP)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (_, m, d, wd, yd, em) = unpack_date(date, false);
 
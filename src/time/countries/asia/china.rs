@@ -39,6 +39,10 @@ impl Calendar for ChinaCalendar {
         crate::iso::XSHG
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::CNY)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (y, m, d, _wd, _yd, _em) = unpack_date(date, false);
 
@@ -39,6 +39,10 @@ impl Calendar for HongKongCalendar {
         crate::iso::XHKG
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::HKD)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (y, m, d, wd, yd, em) = unpack_date(date, false);
 
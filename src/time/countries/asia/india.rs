@@ -39,6 +39,10 @@ impl Calendar for IndiaCalendar {
         crate::iso::XBOM
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::INR)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (y, m, d, _wd, yd, em) = unpack_date(date, false);
 
@@ -0,0 +1,178 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::time::calendar::Calendar;
+use crate::time::holiday_rules::rosh_hashanah;
+use time::{Date, Duration, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Israel (Tel Aviv Stock Exchange) holiday calendar.
+///
+/// The working week runs from Sunday to Thursday. The Jewish holidays are
+/// computed from the arithmetic Hebrew calendar (see
+/// [`rosh_hashanah`]), and the exchange is also
+/// closed on the eve of the major festivals.
+pub struct IsraelCalendar;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for IsraelCalendar {
+    fn name(&self) -> &'static str {
+        "Israel"
+    }
+
+    fn country_code(&self) -> crate::iso::ISO_3166 {
+        crate::iso::ISRAEL
+    }
+
+    fn market_identifier_code(&self) -> crate::iso::ISO_10383 {
+        crate::iso::XTAE
+    }
+
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::ILS)
+    }
+
+    fn is_business_day(&self, date: Date) -> bool {
+        !matches!(date.weekday(), Weekday::Friday | Weekday::Saturday) && !self.is_holiday(date)
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        let Some(rosh_hashanah) = rosh_hashanah(date.year()) else {
+            return false;
+        };
+
+        // Offset (in days) from Rosh Hashanah of the same Gregorian year.
+        let offset = (date - rosh_hashanah).whole_days();
+
+        if (
+            // Purim
+            offset == -193
+            // Passover (eve and first day)
+            || offset == -164 || offset == -163
+            // Seventh day of Passover (eve and holiday)
+            || offset == -158 || offset == -157
+            // Independence Day
+            || date == independence_day(rosh_hashanah)
+            // Shavuot (eve and holiday)
+            || offset == -114 || offset == -113
+            // Rosh Hashanah (eve and two days)
+            || (-1..=1).contains(&offset)
+            // Yom Kippur (eve and holiday)
+            || offset == 8 || offset == 9
+            // Sukkot (eve and first day)
+            || offset == 13 || offset == 14
+            // Simchat Torah (eve and holiday)
+            || offset == 20 || offset == 21
+        ) {
+            return true;
+        }
+
+        false
+    }
+}
+
+/// Independence Day (5 Iyar), brought forward to Thursday when it falls on a
+/// Friday or Saturday, and postponed to Tuesday when it falls on a Monday.
+fn independence_day(rosh_hashanah: Date) -> Date {
+    let fifth_of_iyar = rosh_hashanah - Duration::days(143);
+
+    match fifth_of_iyar.weekday() {
+        Weekday::Friday => fifth_of_iyar - Duration::days(1),
+        Weekday::Saturday => fifth_of_iyar - Duration::days(2),
+        Weekday::Monday if fifth_of_iyar.year() >= 2004 => fifth_of_iyar + Duration::days(1),
+        _ => fifth_of_iyar,
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_israel {
+    use super::*;
+    use time::macros::date;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        let calendar = IsraelCalendar;
+        assert_eq!(calendar.name(), "Israel");
+    }
+
+    // Test to verify if weekends are not considered business days.
+    #[test]
+    fn test_is_weekend() {
+        let calendar = IsraelCalendar;
+        let fri = date!(2024 - 05 - 03);
+        let sat = date!(2024 - 05 - 04);
+        let sun = date!(2024 - 05 - 05);
+        assert!(!calendar.is_business_day(fri));
+        assert!(!calendar.is_business_day(sat));
+        assert!(calendar.is_business_day(sun));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for public holidays.
+    #[test]
+    fn test_is_public_holiday() {
+        let calendar = IsraelCalendar;
+        let purim = date!(2024 - 03 - 24);
+        let passover_eve = date!(2024 - 04 - 22);
+        let passover = date!(2024 - 04 - 23);
+        let seventh_day_of_passover = date!(2024 - 04 - 29);
+        let independence_day = date!(2024 - 05 - 14);
+        let shavuot_eve = date!(2024 - 06 - 11);
+        let shavuot = date!(2024 - 06 - 12);
+        let rosh_hashanah = date!(2024 - 10 - 03);
+        let yom_kippur_eve = date!(2024 - 10 - 11);
+        let sukkot = date!(2024 - 10 - 17);
+        let simchat_torah = date!(2024 - 10 - 24);
+
+        assert!(!calendar.is_business_day(purim));
+        assert!(!calendar.is_business_day(passover_eve));
+        assert!(!calendar.is_business_day(passover));
+        assert!(!calendar.is_business_day(seventh_day_of_passover));
+        assert!(!calendar.is_business_day(independence_day));
+        assert!(!calendar.is_business_day(shavuot_eve));
+        assert!(!calendar.is_business_day(shavuot));
+        assert!(!calendar.is_business_day(rosh_hashanah));
+        assert!(!calendar.is_business_day(yom_kippur_eve));
+        assert!(!calendar.is_business_day(sukkot));
+        assert!(!calendar.is_business_day(simchat_torah));
+
+        // Rosh Hashanah and Yom Kippur in other years.
+        assert!(!calendar.is_business_day(date!(2023 - 09 - 17)));
+        assert!(!calendar.is_business_day(date!(2025 - 10 - 02)));
+        // Independence Day brought forward from a Saturday.
+        assert!(!calendar.is_business_day(date!(2025 - 05 - 01)));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for regular business days.
+    #[test]
+    fn test_is_regular_business_day() {
+        let calendar = IsraelCalendar;
+        let regular_day1 = date!(2024 - 06 - 19);
+        let regular_day2 = date!(2024 - 07 - 03);
+        let regular_day3 = date!(2024 - 11 - 07);
+
+        assert!(calendar.is_business_day(regular_day1));
+        assert!(calendar.is_business_day(regular_day2));
+        assert!(calendar.is_business_day(regular_day3));
+    }
+}
@@ -0,0 +1,288 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::time::calendar::Calendar;
+use crate::time::holiday_rules::nth_weekday_of_month;
+use crate::time::utilities::is_weekend;
+use time::{Date, Duration, Month, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Malaysia (Bursa Malaysia, Kuala Lumpur) holiday calendar.
+///
+/// A holiday falling on a Sunday is replaced by the next working day.
+/// The lunar, Islamic and Hindu holidays are tabulated for 2020-2026.
+pub struct MalaysiaCalendar;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for MalaysiaCalendar {
+    fn name(&self) -> &'static str {
+        "Malaysia"
+    }
+
+    fn country_code(&self) -> crate::iso::ISO_3166 {
+        crate::iso::MALAYSIA
+    }
+
+    fn market_identifier_code(&self) -> crate::iso::ISO_10383 {
+        crate::iso::XKLS
+    }
+
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::MYR)
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        self.holidays(date.year()).contains(&date)
+    }
+}
+
+impl MalaysiaCalendar {
+    /// All holidays of the year, including the replacement holidays.
+    fn holidays(&self, year: i32) -> Vec<Date> {
+        use Month::{August, December, February, January, May, September};
+
+        let mut dates = [
+            // New Year's Day
+            (January, 1),
+            // Federal Territory Day
+            (February, 1),
+            // Labour Day
+            (May, 1),
+            // National Day
+            (August, 31),
+            // Malaysia Day
+            (September, 16),
+            // Christmas Day
+            (December, 25),
+        ]
+        .iter()
+        .chain(self.movable_holidays(year))
+        .filter_map(|&(month, day)| Date::from_calendar_date(year, month, day).ok())
+        .collect::<Vec<_>>();
+
+        // Birthday of the Yang di-Pertuan Agong (first Monday of June).
+        if year >= 2020 {
+            dates.extend(nth_weekday_of_month(year, Month::June, Weekday::Monday, 1));
+        }
+
+        dates.sort();
+        dates.dedup();
+
+        // Replacement holidays for those falling on a Sunday.
+        let sundays = dates
+            .iter()
+            .filter(|date| date.weekday() == Weekday::Sunday)
+            .copied()
+            .collect::<Vec<_>>();
+
+        for sunday in sundays {
+            let mut replacement = sunday + Duration::days(1);
+            while is_weekend(replacement) || dates.contains(&replacement) {
+                replacement += Duration::days(1);
+            }
+            dates.push(replacement);
+        }
+
+        dates
+    }
+
+    /// Thaipusam, Chinese New Year (two days), Nuzul Al-Quran,
+    /// Hari Raya Aidilfitri (two days), Wesak Day, Hari Raya Haji,
+    /// Awal Muharram, Maulidur Rasul and Deepavali.
+    fn movable_holidays(&self, year: i32) -> &'static [(Month, u8)] {
+        use Month::{
+            April, August, February, January, July, June, March, May, November, October, September,
+        };
+
+        match year {
+            2020 => &[
+                (February, 8),
+                (January, 25),
+                (January, 26),
+                (May, 10),
+                (May, 24),
+                (May, 25),
+                (May, 7),
+                (July, 31),
+                (August, 20),
+                (October, 29),
+                (November, 14),
+            ],
+            2021 => &[
+                (January, 28),
+                (February, 12),
+                (February, 13),
+                (April, 29),
+                (May, 13),
+                (May, 14),
+                (May, 26),
+                (July, 20),
+                (August, 10),
+                (October, 19),
+                (November, 4),
+            ],
+            2022 => &[
+                (January, 18),
+                (February, 1),
+                (February, 2),
+                (April, 19),
+                (May, 2),
+                (May, 3),
+                (May, 15),
+                (July, 10),
+                (July, 30),
+                (October, 10),
+                (October, 24),
+            ],
+            2023 => &[
+                (February, 5),
+                (January, 22),
+                (January, 23),
+                (April, 8),
+                (April, 22),
+                (April, 23),
+                (May, 4),
+                (June, 29),
+                (July, 19),
+                (September, 28),
+                (November, 12),
+            ],
+            2024 => &[
+                (January, 25),
+                (February, 10),
+                (February, 11),
+                (March, 28),
+                (April, 10),
+                (April, 11),
+                (May, 22),
+                (June, 17),
+                (July, 7),
+                (September, 16),
+                (October, 31),
+            ],
+            2025 => &[
+                (February, 11),
+                (January, 29),
+                (January, 30),
+                (March, 18),
+                (March, 31),
+                (April, 1),
+                (May, 12),
+                (June, 7),
+                (June, 27),
+                (September, 5),
+                (October, 20),
+            ],
+            2026 => &[
+                (February, 1),
+                (February, 17),
+                (February, 18),
+                (March, 7),
+                (March, 21),
+                (March, 22),
+                (May, 31),
+                (May, 27),
+                (June, 17),
+                (August, 26),
+                (November, 8),
+            ],
+            _ => &[],
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_malaysia {
+    use super::*;
+    use time::macros::date;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        let calendar = MalaysiaCalendar;
+        assert_eq!(calendar.name(), "Malaysia");
+    }
+
+    // Test to verify if weekends are not considered business days.
+    #[test]
+    fn test_is_weekend() {
+        let calendar = MalaysiaCalendar;
+        let sat = date!(2024 - 04 - 27);
+        let sun = date!(2024 - 04 - 28);
+        assert!(!calendar.is_business_day(sat));
+        assert!(!calendar.is_business_day(sun));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for public holidays.
+    #[test]
+    fn test_is_public_holiday() {
+        let calendar = MalaysiaCalendar;
+        let new_years_day = date!(2024 - 01 - 01);
+        let thaipusam = date!(2024 - 01 - 25);
+        let federal_territory_day = date!(2024 - 02 - 01);
+        let chinese_new_year_replacement = date!(2024 - 02 - 12);
+        let nuzul_al_quran = date!(2024 - 03 - 28);
+        let hari_raya_aidilfitri = date!(2024 - 04 - 10);
+        let hari_raya_aidilfitri_2 = date!(2024 - 04 - 11);
+        let labour_day = date!(2024 - 05 - 01);
+        let wesak_day = date!(2024 - 05 - 22);
+        let agong_birthday = date!(2024 - 06 - 03);
+        let hari_raya_haji = date!(2024 - 06 - 17);
+        let awal_muharram_replacement = date!(2024 - 07 - 08);
+        let malaysia_day = date!(2024 - 09 - 16);
+        let deepavali = date!(2024 - 10 - 31);
+        let christmas = date!(2024 - 12 - 25);
+
+        assert!(!calendar.is_business_day(new_years_day));
+        assert!(!calendar.is_business_day(thaipusam));
+        assert!(!calendar.is_business_day(federal_territory_day));
+        assert!(!calendar.is_business_day(chinese_new_year_replacement));
+        assert!(!calendar.is_business_day(nuzul_al_quran));
+        assert!(!calendar.is_business_day(hari_raya_aidilfitri));
+        assert!(!calendar.is_business_day(hari_raya_aidilfitri_2));
+        assert!(!calendar.is_business_day(labour_day));
+        assert!(!calendar.is_business_day(wesak_day));
+        assert!(!calendar.is_business_day(agong_birthday));
+        assert!(!calendar.is_business_day(hari_raya_haji));
+        assert!(!calendar.is_business_day(awal_muharram_replacement));
+        assert!(!calendar.is_business_day(malaysia_day));
+        assert!(!calendar.is_business_day(deepavali));
+        assert!(!calendar.is_business_day(christmas));
+
+        // Chinese New Year on a Sunday is replaced after the second day.
+        assert!(!calendar.is_business_day(date!(2023 - 01 - 24)));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for regular business days.
+    #[test]
+    fn test_is_regular_business_day() {
+        let calendar = MalaysiaCalendar;
+        let regular_day1 = date!(2024 - 06 - 19);
+        let regular_day2 = date!(2024 - 07 - 03);
+        let regular_day3 = date!(2024 - 11 - 07);
+
+        assert!(calendar.is_business_day(regular_day1));
+        assert!(calendar.is_business_day(regular_day2));
+        assert!(calendar.is_business_day(regular_day3));
+    }
+}
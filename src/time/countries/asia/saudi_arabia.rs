@@ -0,0 +1,198 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::time::calendar::Calendar;
+use crate::time::holiday_rules::islamic_dates_in_year;
+use time::{Date, Duration, Month, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Saudi Arabia (Saudi Exchange, Tadawul) holiday calendar.
+///
+/// The weekend is Friday and Saturday (Thursday and Friday before 29 June 2013). The Eid holidays follow the
+/// Umm al-Qura dates of Eid al-Fitr and Eid al-Adha, tabulated for 2020-2026
+/// and approximated by the arithmetic Islamic calendar in other years:
+/// the exchange is closed from the eve of Eid al-Fitr (or the Day of Arafah)
+/// to the third day after Eid.
+pub struct SaudiArabiaCalendar;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for SaudiArabiaCalendar {
+    fn name(&self) -> &'static str {
+        "Saudi Arabia"
+    }
+
+    fn country_code(&self) -> crate::iso::ISO_3166 {
+        crate::iso::SAUDI_ARABIA
+    }
+
+    fn market_identifier_code(&self) -> crate::iso::ISO_10383 {
+        crate::iso::XSAU
+    }
+
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::SAR)
+    }
+
+    fn is_business_day(&self, date: Date) -> bool {
+        // The weekend moved from Thursday-Friday on 29 June 2013.
+        let weekend = if date < time::macros::date!(2013 - 06 - 29) {
+            [Weekday::Thursday, Weekday::Friday]
+        } else {
+            [Weekday::Friday, Weekday::Saturday]
+        };
+
+        !weekend.contains(&date.weekday()) && !self.is_holiday(date)
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        let (y, m, d) = (date.year(), date.month(), date.day());
+
+        if (
+            // Founding Day
+            (y >= 2022 && d == 22 && m == Month::February)
+            // Eid al-Fitr (1 Shawwal)
+            || self.is_eid(date, self.eid_al_fitr(y), 10, 1)
+            // Eid al-Adha (10 Dhu al-Hijjah)
+            || self.is_eid(date, self.eid_al_adha(y), 12, 10)
+            // National Day
+            || (d == 23 && m == Month::September)
+        ) {
+            return true;
+        }
+
+        false
+    }
+}
+
+impl SaudiArabiaCalendar {
+    /// Checks if `date` is within the holiday around the given Eid, using the
+    /// arithmetic Islamic calendar date (`month`, `day`) outside the table.
+    fn is_eid(&self, date: Date, eid: Option<Date>, month: u8, day: u8) -> bool {
+        let within =
+            |eid: Date| (eid - Duration::days(1)..=eid + Duration::days(3)).contains(&date);
+
+        match eid {
+            Some(eid) => within(eid),
+            None => islamic_dates_in_year(date.year(), month, day)
+                .into_iter()
+                .any(within),
+        }
+    }
+
+    fn eid_al_fitr(&self, year: i32) -> Option<Date> {
+        use Month::{April, March, May};
+        let (day, month) = match year {
+            2020 => (24, May),
+            2021 => (13, May),
+            2022 => (2, May),
+            2023 => (21, April),
+            2024 => (10, April),
+            2025 => (30, March),
+            2026 => (20, March),
+            _ => return None,
+        };
+
+        Date::from_calendar_date(year, month, day).ok()
+    }
+
+    fn eid_al_adha(&self, year: i32) -> Option<Date> {
+        use Month::{July, June, May};
+        let (day, month) = match year {
+            2020 => (31, July),
+            2021 => (20, July),
+            2022 => (9, July),
+            2023 => (28, June),
+            2024 => (16, June),
+            2025 => (6, June),
+            2026 => (27, May),
+            _ => return None,
+        };
+
+        Date::from_calendar_date(year, month, day).ok()
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_saudi_arabia {
+    use super::*;
+    use time::macros::date;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        let calendar = SaudiArabiaCalendar;
+        assert_eq!(calendar.name(), "Saudi Arabia");
+    }
+
+    // Test to verify if weekends are not considered business days.
+    #[test]
+    fn test_is_weekend() {
+        let calendar = SaudiArabiaCalendar;
+        let fri = date!(2024 - 04 - 26);
+        let sat = date!(2024 - 04 - 27);
+        let sun = date!(2024 - 04 - 28);
+        assert!(!calendar.is_business_day(fri));
+        assert!(!calendar.is_business_day(sat));
+        assert!(calendar.is_business_day(sun));
+
+        // Thursday-Friday weekend before 2013.
+        assert!(!calendar.is_business_day(date!(2012 - 06 - 28)));
+        assert!(calendar.is_business_day(date!(2012 - 06 - 30)));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for public holidays.
+    #[test]
+    fn test_is_public_holiday() {
+        let calendar = SaudiArabiaCalendar;
+        let founding_day = date!(2024 - 02 - 22);
+        let eid_al_fitr_eve = date!(2024 - 04 - 09);
+        let eid_al_fitr = date!(2024 - 04 - 10);
+        let eid_al_fitr_3 = date!(2024 - 04 - 11);
+        let day_of_arafah = date!(2024 - 06 - 15);
+        let eid_al_adha_3 = date!(2024 - 06 - 18);
+        let eid_al_adha_4 = date!(2024 - 06 - 19);
+        let national_day = date!(2024 - 09 - 23);
+
+        assert!(!calendar.is_business_day(founding_day));
+        assert!(!calendar.is_business_day(eid_al_fitr_eve));
+        assert!(!calendar.is_business_day(eid_al_fitr));
+        assert!(!calendar.is_business_day(eid_al_fitr_3));
+        assert!(!calendar.is_business_day(day_of_arafah));
+        assert!(!calendar.is_business_day(eid_al_adha_3));
+        assert!(!calendar.is_business_day(eid_al_adha_4));
+        assert!(!calendar.is_business_day(national_day));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for regular business days.
+    #[test]
+    fn test_is_regular_business_day() {
+        let calendar = SaudiArabiaCalendar;
+        let regular_day1 = date!(2024 - 06 - 20);
+        let regular_day2 = date!(2024 - 07 - 03);
+        let regular_day3 = date!(2024 - 11 - 07);
+
+        assert!(calendar.is_business_day(regular_day1));
+        assert!(calendar.is_business_day(regular_day2));
+        assert!(calendar.is_business_day(regular_day3));
+    }
+}
@@ -39,6 +39,10 @@ impl Calendar for SingaporeCalendar {
         crate::iso::XSES
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::SGD)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (y, m, d, wd, yd, em) = unpack_date(date, false);
 
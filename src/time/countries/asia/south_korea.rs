@@ -0,0 +1,309 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::time::calendar::Calendar;
+use crate::time::holiday_rules::korean_lunar_date;
+use crate::time::utilities::{is_weekend, unpack_date};
+use time::{Date, Duration, Month, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// South Korea (Korea Exchange) holiday calendar.
+///
+/// The lunar holidays (Seollal, Buddha's Birthday and Chuseok) and their
+/// substitute holidays are computed with `holiday_rules::korean_lunar_date`,
+/// which is accurate for the years 1900-2100 only. Election
+/// days and temporary holidays are tabulated for 2020-2026 only.
+pub struct SouthKoreaCalendar;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for SouthKoreaCalendar {
+    fn name(&self) -> &'static str {
+        "South Korea"
+    }
+
+    fn country_code(&self) -> crate::iso::ISO_3166 {
+        crate::iso::KOREA_REPUBLIC
+    }
+
+    fn market_identifier_code(&self) -> crate::iso::ISO_10383 {
+        crate::iso::XKRX
+    }
+
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::KRW)
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        let (y, m, d, wd, _yd, _em) = unpack_date(date, false);
+
+        if (
+            // New Year's Day
+            (d == 1 && m == Month::January)
+            // Seollal (Lunar New Year)
+            || self.is_lunar_holiday(date, korean_lunar_date(y, 1, 1))
+            // Independence Movement Day
+            || self.is_national_day(date, 1, Month::March, 2021)
+            // Labour Day (exchange holiday)
+            || (d == 1 && m == Month::May)
+            // Children's Day
+            || self.is_national_day(date, 5, Month::May, 2014)
+            // Buddha's Birthday
+            || self.is_buddhas_birthday(date)
+            // Memorial Day
+            || (d == 6 && m == Month::June)
+            // Liberation Day
+            || self.is_national_day(date, 15, Month::August, 2021)
+            // Chuseok (Harvest Festival)
+            || self.is_lunar_holiday(date, korean_lunar_date(y, 8, 15))
+            // National Foundation Day
+            || self.is_national_day(date, 3, Month::October, 2021)
+            // Hangul Day
+            || self.is_national_day(date, 9, Month::October, 2021)
+            // Christmas Day
+            || self.is_national_day(date, 25, Month::December, 2023)
+            // Year-end closing (last business day of the year)
+            || (m == Month::December && (d == 31 || (wd == Weekday::Friday && d >= 29)))
+            // Elections and temporary holidays
+            || self.is_special_holiday(y, d, m)
+        ) {
+            return true;
+        }
+
+        false
+    }
+}
+
+impl SouthKoreaCalendar {
+    /// Fixed-date holiday, with a substitute holiday on the following Monday
+    /// when it falls on a weekend from `substitute_from` onwards.
+    fn is_national_day(&self, date: Date, day: u8, month: Month, substitute_from: i32) -> bool {
+        let (y, m, d) = (date.year(), date.month(), date.day());
+
+        if d == day && m == month {
+            return true;
+        }
+
+        if y < substitute_from || date.weekday() != Weekday::Monday {
+            return false;
+        }
+
+        [1, 2].iter().any(|&n| {
+            let previous = date - time::Duration::days(n);
+            previous.day() == day && previous.month() == month
+        })
+    }
+
+    /// Fixed-date holidays, against which the lunar holidays are substituted.
+    fn is_fixed_holiday(&self, date: Date) -> bool {
+        use Month::{August, December, January, June, March, May, October};
+        matches!(
+            (date.day(), date.month()),
+            (1, January)
+                | (1, March)
+                | (5, May)
+                | (6, June)
+                | (15, August)
+                | (3 | 9, October)
+                | (25, December)
+        )
+    }
+
+    /// The first `count` weekdays after `date` which are not fixed-date
+    /// holidays.
+    fn substitute_days(&self, date: Date, count: usize) -> Vec<Date> {
+        let mut days = Vec::with_capacity(count);
+        let mut next = date;
+
+        while days.len() < count {
+            next += Duration::days(1);
+            if !is_weekend(next) && !self.is_fixed_holiday(next) {
+                days.push(next);
+            }
+        }
+
+        days
+    }
+
+    /// Three-day holiday around a lunar date (Seollal or Chuseok), with a
+    /// substitute holiday from 2014 for each day falling on a Sunday or on a
+    /// fixed-date holiday.
+    fn is_lunar_holiday(&self, date: Date, lunar_date: Option<Date>) -> bool {
+        let Some(lunar_date) = lunar_date else {
+            return false;
+        };
+
+        let days = [
+            lunar_date - Duration::days(1),
+            lunar_date,
+            lunar_date + Duration::days(1),
+        ];
+
+        if days.contains(&date) {
+            return true;
+        }
+
+        let overlaps = days
+            .iter()
+            .filter(|&&day| day.weekday() == Weekday::Sunday || self.is_fixed_holiday(day))
+            .count();
+
+        date.year() >= 2014 && self.substitute_days(days[2], overlaps).contains(&date)
+    }
+
+    /// Buddha's Birthday (8th day of the 4th lunar month), with a substitute
+    /// holiday from 2023 when it falls on a weekend or a fixed-date holiday.
+    fn is_buddhas_birthday(&self, date: Date) -> bool {
+        let Some(birthday) = korean_lunar_date(date.year(), 4, 8) else {
+            return false;
+        };
+
+        date == birthday
+            || (date.year() >= 2023
+                && (is_weekend(birthday) || self.is_fixed_holiday(birthday))
+                && self.substitute_days(birthday, 1).contains(&date))
+    }
+
+    fn is_special_holiday(&self, year: i32, day: u8, month: Month) -> bool {
+        use Month::{April, January, June, March, October};
+        matches!(
+            (year, day, month),
+            // Legislative election
+            (2020, 15, April)
+                // Temporary holiday before Chuseok
+                | (2023, 2, October)
+                // Presidential election
+                | (2022, 9, March)
+                // Local elections
+                | (2022, 1, June)
+                // Legislative election
+                | (2024, 10, April)
+                // Armed Forces Day
+                | (2024, 1, October)
+                // Temporary holiday before Seollal
+                | (2025, 27, January)
+                // Presidential election
+                | (2025, 3, June)
+                // Local elections
+                | (2026, 3, June)
+        )
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_south_korea {
+    use super::*;
+    use time::macros::date;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        let calendar = SouthKoreaCalendar;
+        assert_eq!(calendar.name(), "South Korea");
+    }
+
+    // Test to verify if weekends are not considered business days.
+    #[test]
+    fn test_is_weekend() {
+        let calendar = SouthKoreaCalendar;
+        let sat = date!(2024 - 04 - 27);
+        let sun = date!(2024 - 04 - 28);
+        assert!(!calendar.is_business_day(sat));
+        assert!(!calendar.is_business_day(sun));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for public holidays.
+    #[test]
+    fn test_is_public_holiday() {
+        let calendar = SouthKoreaCalendar;
+        let new_years_day = date!(2024 - 01 - 01);
+        let seollal = date!(2024 - 02 - 09);
+        let seollal_substitute = date!(2024 - 02 - 12);
+        let independence_movement_day = date!(2024 - 03 - 01);
+        let election_day = date!(2024 - 04 - 10);
+        let labour_day = date!(2024 - 05 - 01);
+        let childrens_day_substitute = date!(2024 - 05 - 06);
+        let buddhas_birthday = date!(2024 - 05 - 15);
+        let memorial_day = date!(2024 - 06 - 06);
+        let liberation_day = date!(2024 - 08 - 15);
+        let chuseok = date!(2024 - 09 - 17);
+        let national_foundation_day = date!(2024 - 10 - 03);
+        let hangul_day = date!(2024 - 10 - 09);
+        let christmas = date!(2024 - 12 - 25);
+        let year_end = date!(2024 - 12 - 31);
+
+        assert!(!calendar.is_business_day(new_years_day));
+        assert!(!calendar.is_business_day(seollal));
+        assert!(!calendar.is_business_day(seollal_substitute));
+        assert!(!calendar.is_business_day(independence_movement_day));
+        assert!(!calendar.is_business_day(election_day));
+        assert!(!calendar.is_business_day(labour_day));
+        assert!(!calendar.is_business_day(childrens_day_substitute));
+        assert!(!calendar.is_business_day(buddhas_birthday));
+        assert!(!calendar.is_business_day(memorial_day));
+        assert!(!calendar.is_business_day(liberation_day));
+        assert!(!calendar.is_business_day(chuseok));
+        assert!(!calendar.is_business_day(national_foundation_day));
+        assert!(!calendar.is_business_day(hangul_day));
+        assert!(!calendar.is_business_day(christmas));
+        assert!(!calendar.is_business_day(year_end));
+
+        // Substitute holidays for national days falling on a weekend.
+        assert!(!calendar.is_business_day(date!(2025 - 03 - 03)));
+        assert!(!calendar.is_business_day(date!(2026 - 08 - 17)));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for regular business days.
+    #[test]
+    fn test_is_regular_business_day() {
+        let calendar = SouthKoreaCalendar;
+        let regular_day1 = date!(2024 - 06 - 19);
+        let regular_day2 = date!(2024 - 07 - 03);
+        let regular_day3 = date!(2024 - 12 - 30);
+
+        assert!(calendar.is_business_day(regular_day1));
+        assert!(calendar.is_business_day(regular_day2));
+        assert!(calendar.is_business_day(regular_day3));
+    }
+
+    // Test to verify the lunar holidays and their substitutes outside of 2024.
+    #[test]
+    fn test_lunar_holidays() {
+        let calendar = SouthKoreaCalendar;
+
+        // Seollal 2027 is a Sunday (a Saturday in China), substituted on the
+        // Tuesday.
+        for day in 6..=9 {
+            assert!(calendar.is_holiday(date!(2027 - 02 - 01).replace_day(day).unwrap()));
+        }
+        assert!(calendar.is_business_day(date!(2027 - 02 - 10)));
+
+        // Chuseok on a Sunday (2029) and on National Foundation Day (2028).
+        assert!(!calendar.is_business_day(date!(2029 - 09 - 24)));
+        assert!(!calendar.is_business_day(date!(2028 - 10 - 05)));
+
+        // Buddha's Birthday on Children's Day (2025) and on a Sunday (2029).
+        assert!(!calendar.is_business_day(date!(2025 - 05 - 06)));
+        assert!(!calendar.is_business_day(date!(2029 - 05 - 21)));
+        assert!(!calendar.is_business_day(date!(2030 - 05 - 09)));
+    }
+}
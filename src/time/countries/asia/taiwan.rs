@@ -0,0 +1,235 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::time::calendar::Calendar;
+use crate::time::holiday_rules::{chinese_lunar_date, chinese_new_year, solar_term};
+use crate::time::utilities::is_weekend;
+use time::{Date, Duration, Month, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Taiwan (Taiwan Stock Exchange) holiday calendar.
+///
+/// Holidays falling on a Saturday are observed on the preceding Friday, and
+/// those falling on a Sunday on the following Monday. The Lunar New Year
+/// holiday runs from New Year's Eve to the third day of the year, extended
+/// by one weekday for each of those days falling on a weekend.
+/// The lunar holidays follow the Chinese calendar and Tomb Sweeping Day the
+/// Qingming solar term, both computed in `holiday_rules` and accurate for
+/// the years 1900-2100 only.
+pub struct TaiwanCalendar;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for TaiwanCalendar {
+    fn name(&self) -> &'static str {
+        "Taiwan"
+    }
+
+    fn country_code(&self) -> crate::iso::ISO_3166 {
+        crate::iso::TAIWAN
+    }
+
+    fn market_identifier_code(&self) -> crate::iso::ISO_10383 {
+        crate::iso::XTAI
+    }
+
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::TWD)
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        let y = date.year();
+        let fixed = |day: u8, month: Month| Date::from_calendar_date(y, month, day).ok();
+
+        if (
+            // Founding Day of the Republic of China
+            self.is_observed(date, fixed(1, Month::January))
+            // Lunar New Year
+            || self.is_lunar_new_year(date)
+            // Peace Memorial Day
+            || self.is_observed(date, fixed(28, Month::February))
+            // Children's Day
+            || self.is_childrens_day(date)
+            // Tomb Sweeping Day
+            || self.is_observed(date, self.tomb_sweeping_day(y))
+            // Labour Day (exchange holiday)
+            || self.is_observed(date, fixed(1, Month::May))
+            // Dragon Boat Festival
+            || self.is_observed(date, chinese_lunar_date(y, 5, 5))
+            // Mid-Autumn Festival
+            || self.is_observed(date, chinese_lunar_date(y, 8, 15))
+            // National Day
+            || self.is_observed(date, fixed(10, Month::October))
+        ) {
+            return true;
+        }
+
+        false
+    }
+}
+
+impl TaiwanCalendar {
+    /// Checks if `date` is the `holiday` or the weekday it is observed on.
+    fn is_observed(&self, date: Date, holiday: Option<Date>) -> bool {
+        let Some(holiday) = holiday else {
+            return false;
+        };
+
+        let observed = match holiday.weekday() {
+            Weekday::Saturday => holiday - Duration::days(1),
+            Weekday::Sunday => holiday + Duration::days(1),
+            _ => holiday,
+        };
+
+        date == holiday || date == observed
+    }
+
+    fn is_lunar_new_year(&self, date: Date) -> bool {
+        let Some(new_year) = chinese_new_year(date.year()) else {
+            return false;
+        };
+
+        let eve = new_year - Duration::days(1);
+        let third_day = new_year + Duration::days(2);
+
+        if (eve..=third_day).contains(&date) {
+            return true;
+        }
+
+        // One compensatory weekday for each festival day on a weekend.
+        let compensation = (0..4)
+            .filter(|&n| is_weekend(eve + Duration::days(n)))
+            .count();
+
+        let mut next = third_day;
+        for _ in 0..compensation {
+            next += Duration::days(1);
+            while is_weekend(next) {
+                next += Duration::days(1);
+            }
+            if next == date {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Children's Day (4 April). When it coincides with Tomb Sweeping Day,
+    /// the day before is a holiday instead (the day after, on a Thursday).
+    fn is_childrens_day(&self, date: Date) -> bool {
+        let y = date.year();
+        let Ok(childrens_day) = Date::from_calendar_date(y, Month::April, 4) else {
+            return false;
+        };
+
+        if self.tomb_sweeping_day(y) != Some(childrens_day) {
+            return self.is_observed(date, Some(childrens_day));
+        }
+
+        let replacement = if childrens_day.weekday() == Weekday::Thursday {
+            childrens_day + Duration::days(1)
+        } else {
+            childrens_day - Duration::days(1)
+        };
+
+        self.is_observed(date, Some(replacement))
+    }
+
+    fn tomb_sweeping_day(&self, year: i32) -> Option<Date> {
+        solar_term(year, 15.0)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_taiwan {
+    use super::*;
+    use time::macros::date;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        let calendar = TaiwanCalendar;
+        assert_eq!(calendar.name(), "Taiwan");
+    }
+
+    // Test to verify if weekends are not considered business days.
+    #[test]
+    fn test_is_weekend() {
+        let calendar = TaiwanCalendar;
+        let sat = date!(2024 - 04 - 27);
+        let sun = date!(2024 - 04 - 28);
+        assert!(!calendar.is_business_day(sat));
+        assert!(!calendar.is_business_day(sun));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for public holidays.
+    #[test]
+    fn test_is_public_holiday() {
+        let calendar = TaiwanCalendar;
+        let new_years_day = date!(2024 - 01 - 01);
+        let new_years_eve = date!(2024 - 02 - 09);
+        let lunar_new_year_compensation = date!(2024 - 02 - 13);
+        let lunar_new_year_compensation_2 = date!(2024 - 02 - 14);
+        let peace_memorial_day = date!(2024 - 02 - 28);
+        let childrens_day = date!(2024 - 04 - 04);
+        let childrens_day_2 = date!(2024 - 04 - 05);
+        let labour_day = date!(2024 - 05 - 01);
+        let dragon_boat_festival = date!(2024 - 06 - 10);
+        let mid_autumn_festival = date!(2024 - 09 - 17);
+        let national_day = date!(2024 - 10 - 10);
+
+        assert!(!calendar.is_business_day(new_years_day));
+        assert!(!calendar.is_business_day(new_years_eve));
+        assert!(!calendar.is_business_day(lunar_new_year_compensation));
+        assert!(!calendar.is_business_day(lunar_new_year_compensation_2));
+        assert!(!calendar.is_business_day(peace_memorial_day));
+        assert!(!calendar.is_business_day(childrens_day));
+        assert!(!calendar.is_business_day(childrens_day_2));
+        assert!(!calendar.is_business_day(labour_day));
+        assert!(!calendar.is_business_day(dragon_boat_festival));
+        assert!(!calendar.is_business_day(mid_autumn_festival));
+        assert!(!calendar.is_business_day(national_day));
+
+        // Children's Day and Tomb Sweeping Day on a Friday.
+        assert!(!calendar.is_business_day(date!(2025 - 04 - 03)));
+        // Dragon Boat Festival on a Saturday.
+        assert!(!calendar.is_business_day(date!(2025 - 05 - 30)));
+        // Tomb Sweeping Day on Children's Day (2028) and on 5 April (2027).
+        assert!(!calendar.is_business_day(date!(2028 - 04 - 03)));
+        assert!(!calendar.is_business_day(date!(2028 - 04 - 04)));
+        assert!(!calendar.is_business_day(date!(2027 - 04 - 05)));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for regular business days.
+    #[test]
+    fn test_is_regular_business_day() {
+        let calendar = TaiwanCalendar;
+        let regular_day1 = date!(2024 - 06 - 19);
+        let regular_day2 = date!(2024 - 02 - 15);
+        let regular_day3 = date!(2024 - 11 - 07);
+
+        assert!(calendar.is_business_day(regular_day1));
+        assert!(calendar.is_business_day(regular_day2));
+        assert!(calendar.is_business_day(regular_day3));
+    }
+}
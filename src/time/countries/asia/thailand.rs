@@ -0,0 +1,218 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::time::calendar::Calendar;
+use crate::time::holiday_rules::thai_buddhist_full_moons;
+use crate::time::utilities::is_weekend;
+use time::{Date, Duration, Month};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Thailand (Stock Exchange of Thailand) holiday calendar.
+///
+/// A holiday falling on a weekend is substituted by the next working day.
+///
+/// The Buddhist lunar holidays (Makha Bucha, Visakha Bucha and
+/// Asarnha Bucha) are computed with `holiday_rules::thai_buddhist_full_moons`,
+/// which matches the official dates for 2015-2026 but approximates the Thai
+/// lunar calendar and can be a day off in other years.
+pub struct ThailandCalendar;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for ThailandCalendar {
+    fn name(&self) -> &'static str {
+        "Thailand"
+    }
+
+    fn country_code(&self) -> crate::iso::ISO_3166 {
+        crate::iso::THAILAND
+    }
+
+    fn market_identifier_code(&self) -> crate::iso::ISO_10383 {
+        crate::iso::XBKK
+    }
+
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::THB)
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        self.holidays(date.year()).contains(&date)
+    }
+}
+
+impl ThailandCalendar {
+    /// All holidays of the year, including the substitution holidays.
+    fn holidays(&self, year: i32) -> Vec<Date> {
+        use Month::{April, August, December, January, July, June, May, October};
+
+        let mut fixed = vec![
+            // New Year's Day
+            (January, 1),
+            // Chakri Memorial Day
+            (April, 6),
+            // Songkran Festival
+            (April, 13),
+            (April, 14),
+            (April, 15),
+            // Labour Day
+            (May, 1),
+            // Queen Mother's Birthday
+            (August, 12),
+            // Chulalongkorn Day
+            (October, 23),
+            // King Bhumibol's Birthday
+            (December, 5),
+            // Constitution Day
+            (December, 10),
+            // New Year's Eve
+            (December, 31),
+        ];
+
+        if year >= 2017 {
+            // King's Birthday and King Bhumibol Memorial Day
+            fixed.extend([(July, 28), (October, 13)]);
+        }
+        if year >= 2019 {
+            // Queen's Birthday
+            fixed.push((June, 3));
+        }
+        if year >= 2020 {
+            // Coronation Day
+            fixed.push((May, 4));
+        }
+
+        let mut dates = fixed
+            .iter()
+            .filter_map(|&(month, day)| Date::from_calendar_date(year, month, day).ok())
+            .chain(thai_buddhist_full_moons(year).into_iter().flatten())
+            .collect::<Vec<_>>();
+
+        dates.sort();
+        dates.dedup();
+
+        // Substitution holidays for those falling on a weekend, with a single
+        // substitution day for the Songkran Festival.
+        let is_songkran = |date: &Date| date.month() == April && (13..=15).contains(&date.day());
+
+        let mut weekend_holidays = dates
+            .iter()
+            .filter(|&&date| is_weekend(date) && !is_songkran(&date))
+            .copied()
+            .collect::<Vec<_>>();
+
+        if let Some(&songkran) = dates
+            .iter()
+            .find(|&&date| is_weekend(date) && is_songkran(&date))
+        {
+            weekend_holidays.push(songkran);
+        }
+
+        for holiday in weekend_holidays {
+            let mut substitute = holiday + Duration::days(1);
+            while is_weekend(substitute) || dates.contains(&substitute) {
+                substitute += Duration::days(1);
+            }
+            dates.push(substitute);
+        }
+
+        dates
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_thailand {
+    use super::*;
+    use time::macros::date;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        let calendar = ThailandCalendar;
+        assert_eq!(calendar.name(), "Thailand");
+    }
+
+    // Test to verify if weekends are not considered business days.
+    #[test]
+    fn test_is_weekend() {
+        let calendar = ThailandCalendar;
+        let sat = date!(2024 - 04 - 27);
+        let sun = date!(2024 - 04 - 28);
+        assert!(!calendar.is_business_day(sat));
+        assert!(!calendar.is_business_day(sun));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for public holidays.
+    #[test]
+    fn test_is_public_holiday() {
+        let calendar = ThailandCalendar;
+        let new_years_day = date!(2024 - 01 - 01);
+        let makha_bucha_substitute = date!(2024 - 02 - 26);
+        let chakri_day_substitute = date!(2024 - 04 - 08);
+        let songkran_substitute = date!(2024 - 04 - 16);
+        let labour_day = date!(2024 - 05 - 01);
+        let coronation_day_substitute = date!(2024 - 05 - 06);
+        let visakha_bucha = date!(2024 - 05 - 22);
+        let queens_birthday = date!(2024 - 06 - 03);
+        let asarnha_bucha_substitute = date!(2024 - 07 - 22);
+        let kings_birthday_substitute = date!(2024 - 07 - 29);
+        let queen_mothers_birthday = date!(2024 - 08 - 12);
+        let memorial_day = date!(2024 - 10 - 14);
+        let chulalongkorn_day = date!(2024 - 10 - 23);
+        let king_bhumibols_birthday = date!(2024 - 12 - 05);
+        let constitution_day = date!(2024 - 12 - 10);
+        let new_years_eve = date!(2024 - 12 - 31);
+
+        assert!(!calendar.is_business_day(new_years_day));
+        assert!(!calendar.is_business_day(makha_bucha_substitute));
+        assert!(!calendar.is_business_day(chakri_day_substitute));
+        assert!(!calendar.is_business_day(songkran_substitute));
+        assert!(!calendar.is_business_day(labour_day));
+        assert!(!calendar.is_business_day(coronation_day_substitute));
+        assert!(!calendar.is_business_day(visakha_bucha));
+        assert!(!calendar.is_business_day(queens_birthday));
+        assert!(!calendar.is_business_day(asarnha_bucha_substitute));
+        assert!(!calendar.is_business_day(kings_birthday_substitute));
+        assert!(!calendar.is_business_day(queen_mothers_birthday));
+        assert!(!calendar.is_business_day(memorial_day));
+        assert!(!calendar.is_business_day(chulalongkorn_day));
+        assert!(!calendar.is_business_day(king_bhumibols_birthday));
+        assert!(!calendar.is_business_day(constitution_day));
+        assert!(!calendar.is_business_day(new_years_eve));
+
+        // A single substitution day for the Songkran Festival.
+        assert!(calendar.is_business_day(date!(2024 - 04 - 17)));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for regular business days.
+    #[test]
+    fn test_is_regular_business_day() {
+        let calendar = ThailandCalendar;
+        let regular_day1 = date!(2024 - 06 - 19);
+        let regular_day2 = date!(2024 - 07 - 03);
+        let regular_day3 = date!(2024 - 11 - 07);
+
+        assert!(calendar.is_business_day(regular_day1));
+        assert!(calendar.is_business_day(regular_day2));
+        assert!(calendar.is_business_day(regular_day3));
+    }
+}
@@ -39,6 +39,10 @@ impl Calendar for AustriaCalendar {
         crate::iso::EXAA
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::EUR)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (y, m, d, _, yd, em) = unpack_date(date, false);
 
@@ -39,6 +39,10 @@ impl Calendar for CzechRepublicCalendar {
         crate::iso::XPRA
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::CZK)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (y, m, d, _wd, yd, em) = unpack_date(date, false);
 
@@ -39,6 +39,10 @@ impl Calendar for DenmarkCalendar {
         crate::iso::XCSE
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::DKK)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (y, m, d, _, yd, em) = unpack_date(date, false);
 
@@ -39,6 +39,10 @@ impl Calendar for GermanyCalendar {
         crate::iso::XFRA
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::EUR)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (_y, m, d, _wd, yd, em) = unpack_date(date, false);
 
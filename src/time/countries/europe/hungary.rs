@@ -39,6 +39,10 @@ impl Calendar for HungaryCalendar {
         crate::iso::XBUD
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::HUF)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (_y, m, d, _wd, yd, em) = unpack_date(date, false);
 
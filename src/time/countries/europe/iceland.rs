@@ -39,6 +39,10 @@ impl Calendar for IcelandCalendar {
         crate::iso::XICE
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::ISK)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (_y, m, d, wd, yd, em) = unpack_date(date, false);
 
@@ -39,6 +39,10 @@ impl Calendar for UnitedKingdomCalendar {
         crate::iso::XLON
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::GBP)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (y, m, d, wd, yd, em) = unpack_date(date, false);
 
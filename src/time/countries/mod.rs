@@ -25,8 +25,20 @@ pub mod asia {
     pub mod india;
     /// This module defines Indonesia holidays and calendars.
     pub mod indonesia;
+    /// This module defines Israel holidays and calendars.
+    pub mod israel;
+    /// This module defines Malaysia holidays and calendars.
+    pub mod malaysia;
+    /// This module defines Saudi Arabia holidays and calendars.
+    pub mod saudi_arabia;
     /// This module defines Singapore holidays and calendars.
     pub mod singapore;
+    /// This module defines South Korea holidays and calendars.
+    pub mod south_korea;
+    /// This module defines Taiwan holidays and calendars.
+    pub mod taiwan;
+    /// This module defines Thailand holidays and calendars.
+    pub mod thailand;
 }
 
 /// Calendars implemented for European countries.
@@ -76,3 +88,84 @@ pub mod south_america {
     /// This module defines Chile holidays and calendars.
     pub mod chile;
 }
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::time::{Calendar, JointCalendar};
+
+/// ISO codes of the country calendars, see [`calendar_from_code`].
+pub const CALENDAR_CODES: [&str; 28] = [
+    "AR", "AT", "AU", "BR", "BW", "CA", "CL", "CN", "CZ", "DE", "DK", "FI", "FR", "GB", "HK", "HU",
+    "ID", "IL", "IN", "IS", "KR", "MY", "NZ", "SA", "SG", "TH", "TW", "US",
+];
+
+/// Function to get a country calendar from its ISO code (see
+/// [`Calendar::iso_code`], e.g. "US"), or a joint calendar of its holidays
+/// from codes joined by "+" (e.g. "US+GB").
+/// Returns `None` if a code is unknown.
+pub fn calendar_from_code(code: &str) -> Option<Box<dyn Calendar>> {
+    if code.contains('+') {
+        let calendars = code
+            .split('+')
+            .map(calendar_from_code)
+            .collect::<Option<Vec<_>>>()?;
+
+        return Some(Box::new(JointCalendar::new(calendars)));
+    }
+
+    let calendar: Box<dyn Calendar> = match code.trim() {
+        "AR" => Box::new(south_america::argentina::ArgentinaCalendar),
+        "AT" => Box::new(europe::austria::AustriaCalendar),
+        "AU" => Box::new(oceania::australia::AustraliaCalendar),
+        "BR" => Box::new(south_america::brazil::BrazilCalendar),
+        "BW" => Box::new(africa::botswana::BotswanaCalendar),
+        "CA" => Box::new(north_america::canada::CanadaCalendar),
+        "CL" => Box::new(south_america::chile::ChileCalendar),
+        "CN" => Box::new(asia::china::ChinaCalendar),
+        "CZ" => Box::new(europe::czech_republic::CzechRepublicCalendar),
+        "DE" => Box::new(europe::germany::GermanyCalendar),
+        "DK" => Box::new(europe::denmark::DenmarkCalendar),
+        "FI" => Box::new(europe::finland::FinlandCalendar),
+        "FR" => Box::new(europe::france::FranceCalendar),
+        "GB" => Box::new(europe::united_kingdom::UnitedKingdomCalendar),
+        "HK" => Box::new(asia::hong_kong::HongKongCalendar),
+        "HU" => Box::new(europe::hungary::HungaryCalendar),
+        "ID" => Box::new(asia::indonesia::IndonesiaCalendar),
+        "IL" => Box::new(asia::israel::IsraelCalendar),
+        "IN" => Box::new(asia::india::IndiaCalendar),
+        "IS" => Box::new(europe::iceland::IcelandCalendar),
+        "KR" => Box::new(asia::south_korea::SouthKoreaCalendar),
+        "MY" => Box::new(asia::malaysia::MalaysiaCalendar),
+        "NZ" => Box::new(oceania::new_zealand::NewZealandCalendar),
+        "SA" => Box::new(asia::saudi_arabia::SaudiArabiaCalendar),
+        "SG" => Box::new(asia::singapore::SingaporeCalendar),
+        "TH" => Box::new(asia::thailand::ThailandCalendar),
+        "TW" => Box::new(asia::taiwan::TaiwanCalendar),
+        "US" => Box::new(north_america::united_states::UnitedStatesCalendar),
+        _ => return None,
+    };
+
+    Some(calendar)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_countries {
+    use super::*;
+
+    #[test]
+    fn test_calendar_from_code() {
+        for code in CALENDAR_CODES {
+            assert_eq!(calendar_from_code(code).unwrap().iso_code(), code);
+        }
+
+        assert_eq!(calendar_from_code("US+GB").unwrap().iso_code(), "US+GB");
+        assert!(calendar_from_code("XX").is_none());
+        assert!(calendar_from_code("US+XX").is_none());
+    }
+}
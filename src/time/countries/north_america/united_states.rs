@@ -39,6 +39,10 @@ impl Calendar for UnitedStatesCalendar {
         crate::iso::XNYS
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::USD)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (y, m, d, wd, _, _) = unpack_date(date, false);
 
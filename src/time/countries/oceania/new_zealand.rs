@@ -29,6 +29,10 @@ impl Calendar for NewZealandCalendar {
         crate::iso::XNZE
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::NZD)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (y, m, d, wd, yd, em) = unpack_date(date, false);
 
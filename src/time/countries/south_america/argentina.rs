@@ -39,6 +39,10 @@ impl Calendar for ArgentinaCalendar {
         crate::iso::XBUE
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::ARS)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (_, m, d, wd, yd, em) = unpack_date(date, false);
 
@@ -39,6 +39,10 @@ impl Calendar for BrazilCalendar {
         crate::iso::BVMF
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::BRL)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (y, m, d, _, yd, em) = unpack_date(date, false);
 
@@ -39,6 +39,10 @@ impl Calendar for ChileCalendar {
         crate::iso::XSGO
     }
 
+    fn currency(&self) -> Option<crate::instruments::currency::Currency> {
+        Some(crate::iso::CLP)
+    }
+
     fn is_holiday(&self, date: Date) -> bool {
         let (y, m, d, wd, yd, em) = unpack_date(date, false);
 
@@ -0,0 +1,25 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::error::RustQuantError;
+use crate::time::calendar::Calendar;
+use crate::time::utilities::last_business_day_of_month;
+use crate::time::DateRollingConvention;
+use time::Date;
+
+impl DateRollingConvention {
+    /// Adjust (roll) the date according: End of Month convention.
+    /// Any date rolls to the last business day of its month.
+    pub(crate) fn roll_date_end_of_month<C: Calendar>(
+        date: Date,
+        calendar: &C,
+    ) -> Result<Date, RustQuantError> {
+        last_business_day_of_month(date, calendar)
+    }
+}
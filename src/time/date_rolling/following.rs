@@ -7,6 +7,7 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use crate::error::RustQuantError;
 use crate::time::calendar::Calendar;
 use crate::time::utilities::next_business_day;
 use crate::time::DateRollingConvention;
@@ -14,7 +15,10 @@ use time::Date;
 
 impl DateRollingConvention {
     /// Adjust (roll) the date according: Following convention.
-    pub(crate) fn roll_date_following<C: Calendar>(date: Date, calendar: &C) -> Date {
+    pub(crate) fn roll_date_following<C: Calendar>(
+        date: Date,
+        calendar: &C,
+    ) -> Result<Date, RustQuantError> {
         next_business_day(date, calendar)
     }
 }
@@ -0,0 +1,33 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::error::RustQuantError;
+use crate::time::calendar::Calendar;
+use crate::time::utilities::{next_business_day, previous_business_day};
+use crate::time::DateRollingConvention;
+use time::Date;
+
+impl DateRollingConvention {
+    /// Adjust (roll) the date according: Half-month modified following convention.
+    pub(crate) fn roll_date_half_month_modified_following<C: Calendar>(
+        date: Date,
+        calendar: &C,
+    ) -> Result<Date, RustQuantError> {
+        let mut new_date = next_business_day(date, calendar)?;
+
+        let crosses_month = new_date.month() != date.month();
+        let crosses_mid_month = date.day() <= 15 && new_date.day() > 15;
+
+        if crosses_month || crosses_mid_month {
+            new_date = previous_business_day(date, calendar)?;
+        }
+
+        Ok(new_date)
+    }
+}
@@ -10,9 +10,15 @@
 /// Actual date rolling convention.
 pub mod actual;
 
+/// End of month date rolling convention.
+pub mod end_of_month;
+
 /// Following date rolling convention.
 pub mod following;
 
+/// Half-month modified following date rolling convention.
+pub mod half_month_modified_following;
+
 /// Modified following date rolling convention.
 pub mod modified_following;
 
@@ -22,6 +28,9 @@ pub mod modified_preceding;
 /// Modified preceding date rolling convention.
 pub mod modified_rolling;
 
+/// Nearest date rolling convention.
+pub mod nearest;
+
 /// Preceding date rolling convention.
 pub mod preceding;
 
@@ -29,8 +38,11 @@ pub mod preceding;
 // IMPORTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use crate::error::RustQuantError;
 use crate::time::calendar::Calendar;
+use crate::time::utilities::{is_last_day_of_month, previous_business_day};
 use std::fmt;
+use std::str::FromStr;
 use time::Date;
 
 /// Date rolling business day conventions.
@@ -43,10 +55,21 @@ use time::Date;
 /// time such that it falls in a business day, according with the
 /// same business calendar.
 /// """
+///
+/// Note: `Actual` and `Unadjusted` are the same convention (the date is not
+/// adjusted at all). `Unadjusted` is the name used by ISDA and most other
+/// libraries, while `Actual` is kept for backward compatibility.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DateRollingConvention {
     /// Actual: paid on the actual day, even if it is a non-business day.
+    /// Identical to [`DateRollingConvention::Unadjusted`].
     Actual,
 
+    /// Unadjusted: the date is not adjusted, even if it is a non-business day.
+    /// Identical to [`DateRollingConvention::Actual`].
+    Unadjusted,
+
     /// Following business day: the payment date is rolled to the next business day.
     Following,
 
@@ -71,30 +94,76 @@ pub enum DateRollingConvention {
     /// business day. The adjusted week date is used for the next coupon date.
     /// So adjustments are cumulative (excluding month change).
     ModifiedRolling,
+
+    /// Half-month modified following business day: as modified following,
+    /// but the payment date is also rolled to the previous business day if
+    /// rolling forward would cross the middle (the 15th) of the month.
+    HalfMonthModifiedFollowing,
+
+    /// Nearest business day: the payment date is rolled to the nearest
+    /// business day, or to the next business day if the previous and next
+    /// business days are equally far away.
+    Nearest,
+
+    /// End of month: the date is rolled to the last business day of its
+    /// month, e.g. for instruments paying on month ends.
+    EndOfMonth,
 }
 
 /// Date roller trait for rolling coupon/payment dates according to a given convention.
 pub trait DateRoller {
     /// Roll the date according to the given convention.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the calendar has no business day near the date
+    /// (see [`DateRoller::try_roll_date`]).
     fn roll_date(&self, date: Date, convention: &DateRollingConvention) -> Date;
 
+    /// Roll the date according to the given convention.
+    ///
+    /// # Errors
+    /// - `RustQuantError::NotConverged` if no business day is found within
+    ///   [`crate::time::MAX_BUSINESS_DAY_SEARCH`] days of the date.
+    fn try_roll_date(
+        &self,
+        date: Date,
+        convention: &DateRollingConvention,
+    ) -> Result<Date, RustQuantError>;
+
     /// Roll a list of dates according to the given convention.
     fn roll_dates(&self, dates: &[Date], convention: &DateRollingConvention) -> Vec<Date>;
+
+    /// Roll a list of dates according to the given convention, returning the
+    /// rolled dates sorted and without duplicates (e.g. when several dates in
+    /// a holiday cluster roll onto the same business day).
+    fn roll_dates_unique(&self, dates: &[Date], convention: &DateRollingConvention) -> Vec<Date>;
 }
 
 impl<C> DateRoller for C
 where
     C: Calendar,
 {
-    #[rustfmt::skip]
     fn roll_date(&self, date: Date, convention: &DateRollingConvention) -> Date {
+        match self.try_roll_date(date, convention) {
+            Ok(rolled) => rolled,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn try_roll_date(&self, date: Date, convention: &DateRollingConvention) -> Result<Date, RustQuantError> {
         match convention {
-            DateRollingConvention::Actual               => DateRollingConvention::roll_date_actual(date, self),
+            DateRollingConvention::Actual               => Ok(DateRollingConvention::roll_date_actual(date, self)),
+            DateRollingConvention::Unadjusted           => Ok(DateRollingConvention::roll_date_actual(date, self)),
             DateRollingConvention::Following            => DateRollingConvention::roll_date_following(date, self),
             DateRollingConvention::ModifiedFollowing    => DateRollingConvention::roll_date_modified_following(date, self),
             DateRollingConvention::Preceding            => DateRollingConvention::roll_date_preceding(date, self),
             DateRollingConvention::ModifiedPreceding    => DateRollingConvention::roll_date_modified_preceding(date, self),
             DateRollingConvention::ModifiedRolling      => DateRollingConvention::roll_date_modified_rolling(date, self),
+            DateRollingConvention::HalfMonthModifiedFollowing => DateRollingConvention::roll_date_half_month_modified_following(date, self),
+            DateRollingConvention::Nearest              => DateRollingConvention::roll_date_nearest(date, self),
+            DateRollingConvention::EndOfMonth           => DateRollingConvention::roll_date_end_of_month(date, self),
         }
     }
 
@@ -104,6 +173,76 @@ where
             .map(|&date| self.roll_date(date, convention))
             .collect()
     }
+
+    fn roll_dates_unique(&self, dates: &[Date], convention: &DateRollingConvention) -> Vec<Date> {
+        let mut rolled = self.roll_dates(dates, convention);
+
+        rolled.sort();
+        rolled.dedup();
+
+        rolled
+    }
+}
+
+/// A business day adjustment policy: a date rolling convention, an
+/// end-of-month rule, and the calendar the dates are adjusted against.
+///
+/// With the end-of-month rule, a date on the last day of its month is
+/// adjusted to the last business day of that month, whatever the convention
+/// (unless the convention does not adjust at all). Other dates are rolled by
+/// the convention.
+#[derive(Debug, Clone, Copy)]
+pub struct BusinessDayAdjustment<C: Calendar> {
+    /// The date rolling convention.
+    pub convention: DateRollingConvention,
+
+    /// Whether month-end dates are kept on the last business day of the month.
+    pub end_of_month: bool,
+
+    /// The calendar the dates are adjusted against.
+    pub calendar: C,
+}
+
+impl<C: Calendar> BusinessDayAdjustment<C> {
+    /// Create a new business day adjustment policy.
+    pub const fn new(calendar: C, convention: DateRollingConvention, end_of_month: bool) -> Self {
+        Self {
+            convention,
+            end_of_month,
+            calendar,
+        }
+    }
+
+    /// Adjust a date to a business day.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the calendar has no business day near the date
+    /// (see [`BusinessDayAdjustment::try_adjust`]).
+    pub fn adjust(&self, date: Date) -> Date {
+        match self.try_adjust(date) {
+            Ok(adjusted) => adjusted,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Adjust a date to a business day.
+    ///
+    /// # Errors
+    /// - `RustQuantError::NotConverged` if no business day is found within
+    ///   [`crate::time::MAX_BUSINESS_DAY_SEARCH`] days of the date.
+    pub fn try_adjust(&self, date: Date) -> Result<Date, RustQuantError> {
+        let adjusting = !matches!(
+            self.convention,
+            DateRollingConvention::Actual | DateRollingConvention::Unadjusted
+        );
+
+        if self.end_of_month && adjusting && is_last_day_of_month(date) {
+            return previous_business_day(date, &self.calendar);
+        }
+
+        self.calendar.try_roll_date(date, &self.convention)
+    }
 }
 
 impl Default for DateRollingConvention {
@@ -118,11 +257,446 @@ impl fmt::Display for DateRollingConvention {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Actual                => write!(f, "Actual"),
+            Self::Unadjusted            => write!(f, "Unadjusted"),
             Self::Following             => write!(f, "Following"),
             Self::ModifiedFollowing     => write!(f, "Modified Following"),
             Self::Preceding             => write!(f, "Preceding"),
             Self::ModifiedPreceding     => write!(f, "Modified Preceding"),
             Self::ModifiedRolling       => write!(f, "Modified Rolling"),
+            Self::HalfMonthModifiedFollowing => write!(f, "Half-Month Modified Following"),
+            Self::Nearest               => write!(f, "Nearest"),
+            Self::EndOfMonth            => write!(f, "End of Month"),
+        }
+    }
+}
+
+impl FromStr for DateRollingConvention {
+    type Err = RustQuantError;
+
+    /// Parse a date rolling convention from its name or common abbreviation
+    /// (e.g. "Unadjusted", "MODIFIED_FOLLOWING", "MF").
+    /// Case, spaces, hyphens and underscores are ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalised: String = s
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_'))
+            .collect::<String>()
+            .to_uppercase();
+
+        match normalised.as_str() {
+            "ACTUAL" => Ok(Self::Actual),
+            "UNADJUSTED" | "NONE" => Ok(Self::Unadjusted),
+            "FOLLOWING" | "F" => Ok(Self::Following),
+            "MODIFIEDFOLLOWING" | "MF" => Ok(Self::ModifiedFollowing),
+            "PRECEDING" | "P" => Ok(Self::Preceding),
+            "MODIFIEDPRECEDING" | "MP" => Ok(Self::ModifiedPreceding),
+            "MODIFIEDROLLING" | "MR" => Ok(Self::ModifiedRolling),
+            "HALFMONTHMODIFIEDFOLLOWING" | "HMMF" => Ok(Self::HalfMonthModifiedFollowing),
+            "NEAREST" => Ok(Self::Nearest),
+            "ENDOFMONTH" | "EOM" => Ok(Self::EndOfMonth),
+            _ => Err(RustQuantError::InvalidArgument(format!(
+                "unknown date rolling convention: '{s}'"
+            ))),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_date_rolling {
+    use super::*;
+    use crate::time::countries::north_america::united_states::UnitedStatesCalendar;
+    use time::macros::date;
+
+    #[test]
+    fn test_unadjusted_and_actual_are_identity() {
+        let calendar = UnitedStatesCalendar;
+
+        // A Saturday, a holiday, and a business day.
+        for date in [
+            date!(2024 - 03 - 30),
+            date!(2024 - 07 - 04),
+            date!(2024 - 07 - 05),
+        ] {
+            assert_eq!(
+                calendar.roll_date(date, &DateRollingConvention::Actual),
+                date
+            );
+            assert_eq!(
+                calendar.roll_date(date, &DateRollingConvention::Unadjusted),
+                date
+            );
         }
     }
+
+    #[test]
+    fn test_business_day_adjustment() {
+        let following = BusinessDayAdjustment::new(
+            UnitedStatesCalendar,
+            DateRollingConvention::Following,
+            true,
+        );
+
+        // Sunday 30th June 2024 is a month end: the last business day of June,
+        // where Following alone would roll into July.
+        assert_eq!(
+            following.adjust(date!(2024 - 06 - 30)),
+            date!(2024 - 06 - 28)
+        );
+        assert_eq!(
+            UnitedStatesCalendar
+                .roll_date(date!(2024 - 06 - 30), &DateRollingConvention::Following),
+            date!(2024 - 07 - 01)
+        );
+
+        // Other dates follow the convention, and business days are kept.
+        assert_eq!(
+            following.adjust(date!(2024 - 06 - 15)),
+            date!(2024 - 06 - 17)
+        );
+        assert_eq!(
+            following.adjust(date!(2024 - 07 - 31)),
+            date!(2024 - 07 - 31)
+        );
+
+        // Without the end-of-month rule, or without adjusting at all.
+        let no_eom = BusinessDayAdjustment::new(
+            UnitedStatesCalendar,
+            DateRollingConvention::Following,
+            false,
+        );
+        let unadjusted = BusinessDayAdjustment::new(
+            UnitedStatesCalendar,
+            DateRollingConvention::Unadjusted,
+            true,
+        );
+
+        assert_eq!(no_eom.adjust(date!(2024 - 06 - 30)), date!(2024 - 07 - 01));
+        assert_eq!(
+            unadjusted.adjust(date!(2024 - 06 - 30)),
+            date!(2024 - 06 - 30)
+        );
+    }
+
+    #[test]
+    fn test_half_month_modified_following() {
+        let calendar = UnitedStatesCalendar;
+        let convention = DateRollingConvention::HalfMonthModifiedFollowing;
+
+        // Saturday 15th June 2024 would roll past the middle of the month.
+        assert_eq!(
+            calendar.roll_date(date!(2024 - 06 - 15), &convention),
+            date!(2024 - 06 - 14)
+        );
+        assert_eq!(
+            calendar.roll_date(
+                date!(2024 - 06 - 15),
+                &DateRollingConvention::ModifiedFollowing
+            ),
+            date!(2024 - 06 - 17)
+        );
+
+        // Otherwise it is modified following.
+        assert_eq!(
+            calendar.roll_date(date!(2024 - 06 - 08), &convention),
+            date!(2024 - 06 - 10)
+        );
+        assert_eq!(
+            calendar.roll_date(date!(2024 - 06 - 16), &convention),
+            date!(2024 - 06 - 17)
+        );
+        assert_eq!(
+            calendar.roll_date(date!(2024 - 06 - 30), &convention),
+            date!(2024 - 06 - 28)
+        );
+    }
+
+    #[test]
+    fn test_nearest() {
+        let calendar = UnitedStatesCalendar;
+        let convention = DateRollingConvention::Nearest;
+
+        // Saturdays roll back to Friday, Sundays forward to Monday.
+        assert_eq!(
+            calendar.roll_date(date!(2024 - 06 - 15), &convention),
+            date!(2024 - 06 - 14)
+        );
+        assert_eq!(
+            calendar.roll_date(date!(2024 - 06 - 16), &convention),
+            date!(2024 - 06 - 17)
+        );
+
+        // Sunday 1st September 2024, before Labor Day: Friday is two days
+        // back and Tuesday two days forward, so the next business day wins.
+        assert_eq!(
+            calendar.roll_date(date!(2024 - 09 - 01), &convention),
+            date!(2024 - 09 - 03)
+        );
+
+        // Saturday 31st August 2024 is nearer to Friday.
+        assert_eq!(
+            calendar.roll_date(date!(2024 - 08 - 31), &convention),
+            date!(2024 - 08 - 30)
+        );
+    }
+
+    #[test]
+    fn test_end_of_month() {
+        let calendar = UnitedStatesCalendar;
+        let convention = DateRollingConvention::EndOfMonth;
+
+        // Any date of June 2024 rolls to Friday 28th June.
+        for date in [
+            date!(2024 - 06 - 03),
+            date!(2024 - 06 - 28),
+            date!(2024 - 06 - 30),
+        ] {
+            assert_eq!(calendar.roll_date(date, &convention), date!(2024 - 06 - 28));
+        }
+
+        // Tuesday 31st December 2024 is a business day.
+        assert_eq!(
+            calendar.roll_date(date!(2024 - 12 - 01), &convention),
+            date!(2024 - 12 - 31)
+        );
+    }
+
+    #[test]
+    fn test_roll_dates_unique() {
+        let calendar = UnitedStatesCalendar;
+
+        // Saturday and Sunday both roll onto Monday 8th January 2024.
+        let dates = [
+            date!(2024 - 01 - 10),
+            date!(2024 - 01 - 06),
+            date!(2024 - 01 - 07),
+            date!(2024 - 01 - 08),
+        ];
+
+        assert_eq!(
+            calendar.roll_dates(&dates, &DateRollingConvention::Following),
+            vec![
+                date!(2024 - 01 - 10),
+                date!(2024 - 01 - 08),
+                date!(2024 - 01 - 08),
+                date!(2024 - 01 - 08),
+            ]
+        );
+        assert_eq!(
+            calendar.roll_dates_unique(&dates, &DateRollingConvention::Following),
+            vec![date!(2024 - 01 - 08), date!(2024 - 01 - 10)]
+        );
+    }
+
+    #[test]
+    fn test_date_rolling_convention_from_str() {
+        assert!(matches!(
+            "UNADJUSTED".parse::<DateRollingConvention>(),
+            Ok(DateRollingConvention::Unadjusted)
+        ));
+        assert!(matches!(
+            "Actual".parse::<DateRollingConvention>(),
+            Ok(DateRollingConvention::Actual)
+        ));
+        assert!(matches!(
+            "modified_following".parse::<DateRollingConvention>(),
+            Ok(DateRollingConvention::ModifiedFollowing)
+        ));
+        assert!(matches!(
+            "MF".parse::<DateRollingConvention>(),
+            Ok(DateRollingConvention::ModifiedFollowing)
+        ));
+        assert!(matches!(
+            "nearest".parse::<DateRollingConvention>(),
+            Ok(DateRollingConvention::Nearest)
+        ));
+        assert!(matches!(
+            "EOM".parse::<DateRollingConvention>(),
+            Ok(DateRollingConvention::EndOfMonth)
+        ));
+        assert!(matches!(
+            "End of Month".parse::<DateRollingConvention>(),
+            Ok(DateRollingConvention::EndOfMonth)
+        ));
+        assert!(matches!(
+            "half-month modified following".parse::<DateRollingConvention>(),
+            Ok(DateRollingConvention::HalfMonthModifiedFollowing)
+        ));
+        assert!(matches!(
+            "backward".parse::<DateRollingConvention>(),
+            Err(RustQuantError::InvalidArgument(_))
+        ));
+
+        // Display names round-trip.
+        let display = DateRollingConvention::ModifiedPreceding.to_string();
+
+        assert!(matches!(
+            display.parse::<DateRollingConvention>(),
+            Ok(DateRollingConvention::ModifiedPreceding)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests_date_rolling_properties {
+    use super::*;
+    use crate::time::countries::{
+        asia::{hong_kong::HongKongCalendar, india::IndiaCalendar},
+        europe::{germany::GermanyCalendar, united_kingdom::UnitedKingdomCalendar},
+        north_america::united_states::UnitedStatesCalendar,
+        oceania::australia::AustraliaCalendar,
+        south_america::brazil::BrazilCalendar,
+    };
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use time::Duration;
+
+    const N_DATES: usize = 2_000;
+
+    fn conventions() -> [DateRollingConvention; 9] {
+        [
+            DateRollingConvention::Actual,
+            DateRollingConvention::Unadjusted,
+            DateRollingConvention::Following,
+            DateRollingConvention::ModifiedFollowing,
+            DateRollingConvention::Preceding,
+            DateRollingConvention::ModifiedPreceding,
+            DateRollingConvention::ModifiedRolling,
+            DateRollingConvention::HalfMonthModifiedFollowing,
+            DateRollingConvention::Nearest,
+        ]
+    }
+
+    fn is_adjusting(convention: &DateRollingConvention) -> bool {
+        !matches!(
+            convention,
+            DateRollingConvention::Actual | DateRollingConvention::Unadjusted
+        )
+    }
+
+    /// Random dates between 2000 and 2050, skewed towards month ends,
+    /// where the modified conventions differ from the plain ones.
+    fn random_dates(seed: u64) -> Vec<Date> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let start = Date::from_calendar_date(2000, time::Month::January, 1).unwrap();
+
+        (0..N_DATES)
+            .map(|_| {
+                let date = start + Duration::days(rng.gen_range(0..365 * 50));
+
+                if rng.gen_bool(0.5) {
+                    let last_day = time::util::days_in_year_month(date.year(), date.month());
+
+                    date.replace_day(rng.gen_range(last_day - 3..=last_day))
+                        .unwrap()
+                } else {
+                    date
+                }
+            })
+            .collect()
+    }
+
+    fn check_invariants<C: Calendar>(calendar: &C, seed: u64) {
+        for date in random_dates(seed) {
+            for convention in &conventions() {
+                let rolled = calendar.roll_date(date, convention);
+
+                // 1. Every adjusting convention lands on a business day,
+                //    and the unadjusting conventions leave the date alone.
+                if is_adjusting(convention) {
+                    assert!(
+                        calendar.is_business_day(rolled),
+                        "{convention}: {date} -> {rolled} is not a business day"
+                    );
+                } else {
+                    assert_eq!(rolled, date, "{convention} must not adjust {date}");
+                }
+
+                // 2. Rolling is idempotent, and business days are never moved.
+                assert_eq!(
+                    calendar.roll_date(rolled, convention),
+                    rolled,
+                    "{convention} is not idempotent at {date}"
+                );
+
+                if calendar.is_business_day(date) {
+                    assert_eq!(rolled, date, "{convention} moved business day {date}");
+                }
+
+                // 3. The modified conventions never change the month.
+                if matches!(
+                    convention,
+                    DateRollingConvention::ModifiedFollowing
+                        | DateRollingConvention::ModifiedPreceding
+                        | DateRollingConvention::ModifiedRolling
+                        | DateRollingConvention::HalfMonthModifiedFollowing
+                ) {
+                    assert_eq!(
+                        rolled.month(),
+                        date.month(),
+                        "{convention}: {date} -> {rolled} changed month"
+                    );
+                }
+            }
+
+            let following = calendar.roll_date(date, &DateRollingConvention::Following);
+            let preceding = calendar.roll_date(date, &DateRollingConvention::Preceding);
+
+            // 4. Following/preceding give the nearest business day on or
+            //    after/before the date, with no business day skipped.
+            assert!(preceding <= date && date <= following);
+
+            let mut day = date;
+            while day < following {
+                assert!(!calendar.is_business_day(day));
+                day += Duration::days(1);
+            }
+
+            let mut day = date;
+            while day > preceding {
+                assert!(!calendar.is_business_day(day));
+                day -= Duration::days(1);
+            }
+
+            // 5. The modified conventions agree with the plain ones,
+            //    unless the month would change.
+            let modified_following =
+                calendar.roll_date(date, &DateRollingConvention::ModifiedFollowing);
+            let modified_preceding =
+                calendar.roll_date(date, &DateRollingConvention::ModifiedPreceding);
+
+            if following.month() == date.month() {
+                assert_eq!(modified_following, following);
+            } else {
+                assert_eq!(modified_following, preceding);
+            }
+
+            if preceding.month() == date.month() {
+                assert_eq!(modified_preceding, preceding);
+            } else {
+                assert_eq!(modified_preceding, following);
+            }
+
+            // 6. Nearest picks whichever of the two is closer, preferring
+            //    following on ties.
+            let nearest = calendar.roll_date(date, &DateRollingConvention::Nearest);
+
+            assert!(nearest == following || nearest == preceding);
+            assert!((nearest - date).abs() <= (following - date).abs());
+            assert!((nearest - date).abs() <= (preceding - date).abs());
+        }
+    }
+
+    #[test]
+    fn test_roll_date_invariants() {
+        check_invariants(&UnitedStatesCalendar, 1);
+        check_invariants(&UnitedKingdomCalendar, 2);
+        check_invariants(&GermanyCalendar, 3);
+        check_invariants(&AustraliaCalendar, 4);
+        check_invariants(&BrazilCalendar, 5);
+        check_invariants(&HongKongCalendar, 6);
+        check_invariants(&IndiaCalendar, 7);
+    }
 }
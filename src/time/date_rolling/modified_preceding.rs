@@ -7,6 +7,7 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use crate::error::RustQuantError;
 use crate::time::calendar::Calendar;
 use crate::time::utilities::{next_business_day, previous_business_day};
 use crate::time::DateRollingConvention;
@@ -14,13 +15,16 @@ use time::Date;
 
 impl DateRollingConvention {
     /// Adjust (roll) the date according: Modified preceding convention.
-    pub(crate) fn roll_date_modified_preceding<C: Calendar>(date: Date, calendar: &C) -> Date {
-        let mut new_date = previous_business_day(date, calendar);
+    pub(crate) fn roll_date_modified_preceding<C: Calendar>(
+        date: Date,
+        calendar: &C,
+    ) -> Result<Date, RustQuantError> {
+        let mut new_date = previous_business_day(date, calendar)?;
 
         if new_date.month() != date.month() {
-            new_date = next_business_day(date, calendar);
+            new_date = next_business_day(date, calendar)?;
         }
 
-        new_date
+        Ok(new_date)
     }
 }
@@ -7,19 +7,26 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use crate::error::RustQuantError;
 use crate::time::calendar::Calendar;
+use crate::time::utilities::{next_business_day, previous_business_day};
 use crate::time::DateRollingConvention;
 use time::Date;
 
 impl DateRollingConvention {
     /// Adjust (roll) the date according: Modified rolling convention.
-    pub(crate) fn roll_date_modified_rolling<C: Calendar>(date: Date, calendar: &C) -> Date {
-        let mut new_date = date;
+    /// The date is rolled forward, unless that would change the month,
+    /// in which case it is rolled backward.
+    pub(crate) fn roll_date_modified_rolling<C: Calendar>(
+        date: Date,
+        calendar: &C,
+    ) -> Result<Date, RustQuantError> {
+        let mut new_date = next_business_day(date, calendar)?;
 
-        while !calendar.is_business_day(new_date) {
-            new_date = new_date.next_day().unwrap();
+        if new_date.month() != date.month() {
+            new_date = previous_business_day(date, calendar)?;
         }
 
-        new_date
+        Ok(new_date)
     }
 }
@@ -0,0 +1,31 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::error::RustQuantError;
+use crate::time::calendar::Calendar;
+use crate::time::utilities::{next_business_day, previous_business_day};
+use crate::time::DateRollingConvention;
+use time::Date;
+
+impl DateRollingConvention {
+    /// Adjust (roll) the date according: Nearest convention.
+    pub(crate) fn roll_date_nearest<C: Calendar>(
+        date: Date,
+        calendar: &C,
+    ) -> Result<Date, RustQuantError> {
+        let following = next_business_day(date, calendar)?;
+        let preceding = previous_business_day(date, calendar)?;
+
+        if following - date <= date - preceding {
+            Ok(following)
+        } else {
+            Ok(preceding)
+        }
+    }
+}
@@ -7,7 +7,7 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use crate::time::utilities::{contains_leap_year};
+use crate::time::utilities::{add_months, contains_leap_year};
 use crate::time::{days_between, DayCountConvention};
 use time::util::is_leap_year;
 use time::{Date, Month};
@@ -37,8 +37,90 @@ impl DayCountConvention {
     }
 
     // Actual/Actual ICMA day count factor calculation.
-    pub(crate) fn day_count_factor_actual_actual_icma(_start_date: Date, _end_date: Date) -> f64 {
-        todo!()
+    // Without a reference period, the accrual period is its own reference.
+    pub(crate) fn day_count_factor_actual_actual_icma(start_date: Date, end_date: Date) -> f64 {
+        Self::day_count_factor_actual_actual_icma_with_reference(
+            start_date, end_date, start_date, end_date,
+        )
+    }
+
+    // Actual/Actual ICMA day count factor calculation, relative to the
+    // regular coupon period [ref_start, ref_end] containing the accrual period.
+    // Long stubs are split into whole reference periods (as in QuantLib).
+    pub(crate) fn day_count_factor_actual_actual_icma_with_reference(
+        start_date: Date,
+        end_date: Date,
+        ref_start: Date,
+        ref_end: Date,
+    ) -> f64 {
+        if start_date >= end_date {
+            return 0.0;
+        }
+
+        let ref_days = days_between(ref_start, ref_end) as f64;
+        let months = (12.0 * ref_days / 365.0).round() as i32;
+
+        if months == 0 {
+            return days_between(start_date, end_date) as f64 / 365.0;
+        }
+
+        let period = f64::from(months) / 12.0;
+
+        if end_date <= ref_end {
+            if start_date >= ref_start {
+                // Regular or short period, inside the reference period.
+                return period * days_between(start_date, end_date) as f64 / ref_days;
+            }
+
+            // Long first period: accrue the part before the reference
+            // period against the previous (notional) reference period.
+            let previous_ref = add_months(ref_start, -months);
+
+            if end_date > ref_start {
+                return Self::day_count_factor_actual_actual_icma_with_reference(
+                    start_date,
+                    ref_start,
+                    previous_ref,
+                    ref_start,
+                ) + Self::day_count_factor_actual_actual_icma_with_reference(
+                    ref_start, end_date, ref_start, ref_end,
+                );
+            }
+
+            return Self::day_count_factor_actual_actual_icma_with_reference(
+                start_date,
+                end_date,
+                previous_ref,
+                ref_start,
+            );
+        }
+
+        // Long final period: accrue whole reference periods after `ref_end`.
+        let mut sum = Self::day_count_factor_actual_actual_icma_with_reference(
+            start_date.max(ref_start),
+            ref_end,
+            ref_start,
+            ref_end,
+        );
+
+        let mut i = 0;
+        loop {
+            let new_ref_start = add_months(ref_end, months * i);
+            let new_ref_end = add_months(ref_end, months * (i + 1));
+
+            if end_date < new_ref_end {
+                sum += Self::day_count_factor_actual_actual_icma_with_reference(
+                    new_ref_start,
+                    end_date,
+                    new_ref_start,
+                    new_ref_end,
+                );
+                return sum;
+            }
+
+            sum += period;
+            i += 1;
+        }
     }
 
     // Actual/Actual ISDA day count factor calculation.
@@ -102,6 +184,40 @@ mod TESTS_actual_actual {
         );
     }
 
+    #[test]
+    fn actual_actual_icma_with_reference() {
+        // Test cases from QuantLib: a short first semi-annual coupon.
+        assert_approx_equal!(
+            DayCountConvention::day_count_factor_actual_actual_icma_with_reference(
+                date!(1999 - 02 - 01),
+                date!(1999 - 07 - 01),
+                date!(1998 - 07 - 01),
+                date!(1999 - 07 - 01),
+            ),
+            0.410958904110,
+            RUSTQUANT_EPSILON
+        );
+
+        // A long first coupon.
+        assert_approx_equal!(
+            DayCountConvention::day_count_factor_actual_actual_icma_with_reference(
+                date!(2002 - 08 - 15),
+                date!(2003 - 07 - 15),
+                date!(2003 - 01 - 15),
+                date!(2003 - 07 - 15),
+            ),
+            0.915760869565,
+            RUSTQUANT_EPSILON
+        );
+
+        // A regular semi-annual coupon, without an explicit reference period.
+        assert_approx_equal!(
+            DayCountConvention::day_count_factor_actual_actual_icma(DATE_1, DATE_2),
+            0.5,
+            RUSTQUANT_EPSILON
+        );
+    }
+
     #[test]
     fn actual_actual_afb() {
         // Test cases from QuantLib.
@@ -9,7 +9,8 @@
 
 use crate::time::utilities::contains_leap_year;
 use crate::time::DayCountConvention;
-use time::Date;
+use time::util::is_leap_year;
+use time::{Date, Month};
 
 impl DayCountConvention {
     // Actual/360 day count factor calculation.
@@ -40,9 +41,24 @@ impl DayCountConvention {
         (end_date - start_date).whole_days() as f64 / 365.0
     }
 
-    // Actual/365L day count factor calculation.
+    // Actual/365L day count factor calculation, for non-annual coupons:
+    // the denominator is 366 if the end date is in a leap year.
     pub(crate) fn day_count_factor_actual_365_leap(start_date: Date, end_date: Date) -> f64 {
-        match contains_leap_year(start_date, end_date) {
+        match is_leap_year(end_date.year()) {
+            true => (end_date - start_date).whole_days() as f64 / 366.0,
+            false => (end_date - start_date).whole_days() as f64 / 365.0,
+        }
+    }
+
+    // Actual/365L day count factor calculation, for annual coupons:
+    // the denominator is 366 if 29 February falls in (start_date, end_date].
+    pub(crate) fn day_count_factor_actual_365_leap_annual(start_date: Date, end_date: Date) -> f64 {
+        let contains_leap_day = (start_date.year()..=end_date.year()).any(|year| {
+            Date::from_calendar_date(year, Month::February, 29)
+                .is_ok_and(|leap_day| start_date < leap_day && leap_day <= end_date)
+        });
+
+        match contains_leap_day {
             true => (end_date - start_date).whole_days() as f64 / 366.0,
             false => (end_date - start_date).whole_days() as f64 / 365.0,
         }
@@ -154,4 +170,33 @@ mod TESTS_actual_constant {
             assert_approx_equal!(dcf, expected[i - 1], RUSTQUANT_EPSILON);
         }
     }
+
+    #[test]
+    fn actual_365_leap() {
+        // Non-annual coupons: by the year of the end date.
+        let dcf = DayCountConvention::day_count_factor_actual_365_leap(
+            date!(2024 - 06 - 15),
+            date!(2024 - 12 - 15),
+        );
+        assert_approx_equal!(dcf, 183.0 / 366.0, RUSTQUANT_EPSILON);
+
+        let dcf = DayCountConvention::day_count_factor_actual_365_leap(
+            date!(2024 - 06 - 15),
+            date!(2025 - 01 - 15),
+        );
+        assert_approx_equal!(dcf, 214.0 / 365.0, RUSTQUANT_EPSILON);
+
+        // Annual coupons: by whether the period contains 29th February.
+        let dcf = DayCountConvention::day_count_factor_actual_365_leap_annual(
+            date!(2023 - 06 - 15),
+            date!(2024 - 06 - 15),
+        );
+        assert_approx_equal!(dcf, 1.0, RUSTQUANT_EPSILON);
+
+        let dcf = DayCountConvention::day_count_factor_actual_365_leap_annual(
+            date!(2024 - 03 - 01),
+            date!(2025 - 03 - 01),
+        );
+        assert_approx_equal!(dcf, 1.0, RUSTQUANT_EPSILON);
+    }
 }
@@ -0,0 +1,111 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::time::Calendar;
+use std::fmt;
+use time::Date;
+
+/// Business/252 day count convention, the standard for Brazilian (BRL)
+/// fixed income: the day count factor is the number of business days of a
+/// calendar in the period, divided by 252.
+///
+/// Unlike the [`DayCountConvention`](crate::time::DayCountConvention)
+/// variants, the day count depends on a calendar, which it holds.
+pub struct Business252<C: Calendar> {
+    /// The calendar of the business days.
+    calendar: C,
+}
+
+impl<C: Calendar> Business252<C> {
+    /// Number of business days in a year.
+    pub const BUSINESS_DAYS_PER_YEAR: f64 = 252.0;
+
+    /// New Business/252 day count on the given calendar.
+    pub fn new(calendar: C) -> Self {
+        Self { calendar }
+    }
+
+    /// The calendar of the business days.
+    pub fn calendar(&self) -> &C {
+        &self.calendar
+    }
+
+    /// Day count factor between two dates: the business days from the start
+    /// date (inclusive) to the end date (exclusive), divided by 252.
+    pub fn day_count_factor(&self, start_date: Date, end_date: Date) -> f64 {
+        self.calendar.business_days_between(start_date, end_date) as f64
+            / Self::BUSINESS_DAYS_PER_YEAR
+    }
+
+    /// Year fraction between two dates, the same as
+    /// [`Business252::day_count_factor`].
+    pub fn year_fraction(&self, start_date: Date, end_date: Date) -> f64 {
+        self.day_count_factor(start_date, end_date)
+    }
+
+    /// Day count factors between consecutive dates.
+    pub fn day_count_factors(&self, dates: &[Date]) -> Vec<f64> {
+        dates
+            .windows(2)
+            .map(|window| self.day_count_factor(window[0], window[1]))
+            .collect()
+    }
+
+    /// Compounding factor of an annual rate over a period, $(1 + r)^{n / 252}$
+    /// for $n$ business days, as for Brazilian DI rates.
+    pub fn compound_factor(&self, rate: f64, start_date: Date, end_date: Date) -> f64 {
+        (1.0 + rate).powf(self.day_count_factor(start_date, end_date))
+    }
+}
+
+impl<C: Calendar> fmt::Display for Business252<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Business / 252 ({})", self.calendar.name())
+    }
+}
+
+#[cfg(test)]
+mod tests_business_252 {
+    use super::*;
+    use crate::time::countries::south_america::brazil::BrazilCalendar;
+    use time::macros::date;
+
+    #[test]
+    fn test_business_252_day_count() {
+        let day_count = Business252::new(BrazilCalendar);
+
+        // Carnival Monday and Tuesday (12th and 13th February 2024) are holidays.
+        let (start, end) = (date!(2024 - 02 - 09), date!(2024 - 02 - 16));
+
+        assert_approx_equal!(day_count.year_fraction(start, end), 3.0 / 252.0, 1e-15);
+        assert_approx_equal!(day_count.year_fraction(end, start), -3.0 / 252.0, 1e-15);
+        assert_approx_equal!(
+            day_count.compound_factor(0.1, start, end),
+            1.1_f64.powf(3.0 / 252.0),
+            1e-15
+        );
+
+        // Factors add up over consecutive periods.
+        let dates = [
+            date!(2024 - 01 - 02),
+            date!(2024 - 04 - 01),
+            date!(2024 - 07 - 01),
+        ];
+        let factors = day_count.day_count_factors(&dates);
+
+        assert_eq!(factors.len(), 2);
+        assert_approx_equal!(
+            factors[0] + factors[1],
+            day_count.year_fraction(dates[0], dates[2]),
+            1e-15
+        );
+
+        assert_eq!(day_count.to_string(), "Business / 252 (Brazil)");
+    }
+}
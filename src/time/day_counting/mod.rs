@@ -13,6 +13,10 @@ pub mod actual_actual;
 /// Actual/xxx day count factor calculation.
 pub mod actual_constant;
 
+/// Business/252 day count factor calculation, on a calendar.
+pub mod business_252;
+pub use business_252::*;
+
 /// No-Leap day count factor calculation.
 pub mod no_leap;
 
@@ -51,6 +55,8 @@ use time::{Date, Duration};
 /// payment dates, the seller is eligible to some fraction of the coupon amount.
 /// """
 #[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DayCountConvention {
     /// The '1/1' day count, which always returns a day count of 1.
     One_One,
@@ -392,4 +398,141 @@ impl DayCountConvention {
             Self::Thirty_U_360          => Self::day_count_factor_thirty_u_360(start_date, end_date),
         }
     }
+
+    /// Year fraction between two dates under the convention.
+    /// The same as [`DayCountConvention::day_count_factor`].
+    ///
+    /// ```
+    /// use time::macros::date;
+    /// use RustQuant::time::day_counting::DayCountConvention;
+    ///
+    /// let fraction = DayCountConvention::Actual_360.year_fraction(date!(2024-01-15), date!(2024-07-15));
+    ///
+    /// assert_eq!(fraction, 182.0 / 360.0);
+    /// ```
+    #[must_use]
+    pub fn year_fraction(&self, start_date: Date, end_date: Date) -> f64 {
+        self.day_count_factor(start_date, end_date)
+    }
+
+    /// Year fraction between two dates under the convention, for an accrual
+    /// period within the regular coupon period from `reference_start` to
+    /// `reference_end`.
+    ///
+    /// The reference period is used by Actual/Actual ICMA, which accrues
+    /// against the length of the coupon period, and by Actual/365L, which
+    /// uses the annual rule (366 if the period contains 29th February) for
+    /// yearly coupons. The other conventions ignore it.
+    #[must_use]
+    pub fn year_fraction_with_reference(
+        &self,
+        start_date: Date,
+        end_date: Date,
+        reference_start: Date,
+        reference_end: Date,
+    ) -> f64 {
+        match self {
+            Self::Actual_Actual_ICMA => Self::day_count_factor_actual_actual_icma_with_reference(
+                start_date,
+                end_date,
+                reference_start,
+                reference_end,
+            ),
+            Self::Actual_365_Leap if (reference_end - reference_start).whole_days() > 360 => {
+                Self::day_count_factor_actual_365_leap_annual(start_date, end_date)
+            }
+            _ => self.day_count_factor(start_date, end_date),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_day_counting {
+    use super::*;
+    use time::macros::date;
+
+    #[test]
+    fn test_year_fractions() {
+        let (start, end) = (date!(2024 - 01 - 15), date!(2024 - 07 - 15));
+
+        let cases = [
+            (DayCountConvention::Actual_360, 182.0 / 360.0),
+            (DayCountConvention::Actual_365_Fixed, 182.0 / 365.0),
+            (DayCountConvention::Actual_Actual_ISDA, 182.0 / 366.0),
+            (DayCountConvention::Actual_365_Leap, 182.0 / 366.0),
+            (DayCountConvention::Thirty_U_360, 0.5),
+            (DayCountConvention::Thirty_E_360, 0.5),
+            (DayCountConvention::Thirty_E_360_ISDA, 0.5),
+        ];
+
+        for (convention, expected) in cases {
+            assert_approx_equal!(convention.year_fraction(start, end), expected, 1e-12);
+        }
+
+        // Actual/Actual ISDA splits the period at the year end.
+        assert_approx_equal!(
+            DayCountConvention::Actual_Actual_ISDA
+                .year_fraction(date!(2023 - 11 - 15), date!(2024 - 02 - 15)),
+            47.0 / 365.0 + 45.0 / 366.0,
+            1e-12
+        );
+
+        // 30E/360 ISDA treats the end of February as the 30th, 30E/360 does not.
+        let (start, end) = (date!(2023 - 02 - 28), date!(2023 - 03 - 31));
+
+        assert_approx_equal!(
+            DayCountConvention::Thirty_E_360.year_fraction(start, end),
+            32.0 / 360.0,
+            1e-12
+        );
+        assert_approx_equal!(
+            DayCountConvention::Thirty_E_360_ISDA.year_fraction(start, end),
+            30.0 / 360.0,
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_year_fractions_with_reference() {
+        // A short first period of a semi-annual bond.
+        let fraction = DayCountConvention::Actual_Actual_ICMA.year_fraction_with_reference(
+            date!(2024 - 03 - 01),
+            date!(2024 - 07 - 15),
+            date!(2024 - 01 - 15),
+            date!(2024 - 07 - 15),
+        );
+
+        assert_approx_equal!(fraction, 0.5 * 136.0 / 182.0, 1e-12);
+
+        // Annual Actual/365L counts 366 days only if 29th February is in the
+        // period, rather than if the period ends in a leap year.
+        let (start, end) = (date!(2023 - 02 - 01), date!(2024 - 02 - 01));
+
+        assert_approx_equal!(
+            DayCountConvention::Actual_365_Leap
+                .year_fraction_with_reference(start, end, start, end),
+            1.0,
+            1e-12
+        );
+        assert_approx_equal!(
+            DayCountConvention::Actual_365_Leap.year_fraction(start, end),
+            365.0 / 366.0,
+            1e-12
+        );
+
+        // Other conventions ignore the reference period.
+        assert_eq!(
+            DayCountConvention::Actual_360.year_fraction_with_reference(
+                start,
+                end,
+                date!(2020 - 01 - 01),
+                date!(2020 - 02 - 01),
+            ),
+            DayCountConvention::Actual_360.year_fraction(start, end)
+        );
+    }
 }
@@ -76,7 +76,8 @@ impl DayCountConvention {
         Self::thirty_360_numerator(y1, y2, m1, m2, d1, d2) / 360.0
     }
 
-    // 30U/360 day count factor calculation.
+    // 30U/360 (30/360 US) day count factor calculation.
+    // The end date is only moved from the end of February if the start date is too.
     pub(crate) fn day_count_factor_thirty_u_360(start_date: Date, end_date: Date) -> f64 {
         let (y1, m1, mut d1) = Self::thirty_360_unpack_date(start_date);
         let (y2, m2, mut d2) = Self::thirty_360_unpack_date(end_date);
@@ -85,7 +86,9 @@ impl DayCountConvention {
             d1 = 30;
         }
 
-        if d2 == 31 && d1 == 30 || is_last_day_of_february(end_date) {
+        if d2 == 31 && d1 == 30
+            || is_last_day_of_february(start_date) && is_last_day_of_february(end_date)
+        {
             d2 = 30;
         }
 
@@ -144,4 +147,27 @@ mod TESTS_thirty_360 {
 
         assert_approx_equal!(dcf, 1.515_068_493, RUSTQUANT_EPSILON);
     }
+
+    #[test]
+    fn thirty_u_360() {
+        // The end of February only counts as the 30th if the period starts
+        // at the end of February too.
+        let dcf = DayCountConvention::day_count_factor_thirty_u_360(
+            date!(2023 - 01 - 31),
+            date!(2023 - 02 - 28),
+        );
+        assert_approx_equal!(dcf, 28.0 / 360.0, RUSTQUANT_EPSILON);
+
+        let dcf = DayCountConvention::day_count_factor_thirty_u_360(
+            date!(2023 - 02 - 28),
+            date!(2024 - 02 - 29),
+        );
+        assert_approx_equal!(dcf, 1.0, RUSTQUANT_EPSILON);
+
+        let dcf = DayCountConvention::day_count_factor_thirty_u_360(
+            date!(2023 - 02 - 28),
+            date!(2023 - 03 - 31),
+        );
+        assert_approx_equal!(dcf, 30.0 / 360.0, RUSTQUANT_EPSILON);
+    }
 }
@@ -0,0 +1,673 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Rules for computing movable holidays.
+//!
+//! These are the building blocks of the country calendars, and can be used
+//! to build a [`CustomCalendar`](crate::time::CustomCalendar) from rules
+//! instead of lists of dates:
+//!
+//! ```
+//! use time::{Month, Weekday};
+//! use RustQuant::time::holiday_rules::*;
+//! use RustQuant::time::{Calendar, CustomCalendar};
+//!
+//! let holidays = (2024..=2026).flat_map(|year| {
+//!     [
+//!         easter_sunday(year, false).map(|easter| easter - time::Duration::days(2)),
+//!         nth_weekday_of_month(year, Month::September, Weekday::Monday, 1),
+//!         chinese_new_year(year),
+//!     ]
+//!     .into_iter()
+//!     .flatten()
+//! });
+//!
+//! let calendar = CustomCalendar::new(
+//!     "Example",
+//!     RustQuant::iso::UNITED_STATES_OF_AMERICA,
+//!     RustQuant::iso::XNYS,
+//! )
+//! .with_holidays(holidays);
+//!
+//! // Good Friday, Labor Day and the Lunar New Year.
+//! assert!(!calendar.is_business_day(time::macros::date!(2024 - 03 - 29)));
+//! assert!(!calendar.is_business_day(time::macros::date!(2025 - 09 - 01)));
+//! assert!(!calendar.is_business_day(time::macros::date!(2026 - 02 - 17)));
+//! ```
+
+use time::{Date, Duration, Month, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// WEEKDAY RULES
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Function to get the `n`-th (starting from 1) given weekday of a month,
+/// e.g. the third Monday of January.
+/// Returns `None` if the month has fewer than `n` such weekdays.
+pub fn nth_weekday_of_month(year: i32, month: Month, weekday: Weekday, n: u8) -> Option<Date> {
+    if n == 0 {
+        return None;
+    }
+
+    let first = Date::from_calendar_date(year, month, 1).ok()?;
+    let offset =
+        (7 + weekday.number_days_from_monday() - first.weekday().number_days_from_monday()) % 7;
+
+    first.replace_day(1 + offset + 7 * (n - 1)).ok()
+}
+
+/// Function to get the last given weekday of a month,
+/// e.g. the last Monday of May.
+pub fn last_weekday_of_month(year: i32, month: Month, weekday: Weekday) -> Option<Date> {
+    let last =
+        Date::from_calendar_date(year, month, time::util::days_in_year_month(year, month)).ok()?;
+    let offset =
+        (7 + last.weekday().number_days_from_monday() - weekday.number_days_from_monday()) % 7;
+
+    Some(last - Duration::days(i64::from(offset)))
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// EASTER
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Function to get Easter Sunday of a year, in the Gregorian calendar.
+///
+/// Western Easter uses the Gregorian computus (the "anonymous" algorithm),
+/// Orthodox Easter the Julian computus, converted to a Gregorian date.
+pub fn easter_sunday(year: i32, is_orthodox: bool) -> Option<Date> {
+    if is_orthodox {
+        let (a, b, c) = (year % 4, year % 7, year % 19);
+        let d = (19 * c + 15) % 30;
+        let e = (2 * a + 4 * b - d + 34) % 7;
+        let (month, day) = ((d + e + 114) / 31, (d + e + 114) % 31 + 1);
+
+        // Julian to Gregorian calendar offset, in days.
+        let offset = year / 100 - year / 400 - 2;
+
+        let julian_easter =
+            Date::from_calendar_date(year, Month::try_from(month as u8).ok()?, day as u8).ok()?;
+
+        return Some(julian_easter + Duration::days(i64::from(offset)));
+    }
+
+    let a = year % 19;
+    let (b, c) = (year / 100, year % 100);
+    let (d, e) = (b / 4, b % 4);
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let (month, day) = ((h + l - 7 * m + 114) / 31, (h + l - 7 * m + 114) % 31 + 1);
+
+    Date::from_calendar_date(year, Month::try_from(month as u8).ok()?, day as u8).ok()
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CHINESE LUNISOLAR CALENDAR
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Mean length of a lunation, in days.
+const SYNODIC_MONTH: f64 = 29.530_588_861;
+
+/// Time zone of the Chinese calendar (UTC+8), as a fraction of a day.
+const BEIJING_OFFSET: f64 = 8.0 / 24.0;
+
+/// Time zone of the Korean calendar (UTC+9), as a fraction of a day.
+const SEOUL_OFFSET: f64 = 9.0 / 24.0;
+
+/// Function to get the Chinese (Lunar) New Year, the first day of the
+/// first month of the Chinese calendar.
+///
+/// The month boundaries are computed astronomically (new moons and principal
+/// solar terms, in Beijing time), which is accurate for the years 1900-2100.
+pub fn chinese_new_year(year: i32) -> Option<Date> {
+    chinese_lunar_date(year, 1, 1)
+}
+
+/// Function to get the Gregorian date of a day of the Chinese calendar,
+/// e.g. the Mid-Autumn Festival is the 15th day of the 8th month.
+///
+/// The `year` is the Gregorian year in which the Chinese year starts and
+/// leap months are skipped. Returns `None` if the day does not exist.
+pub fn chinese_lunar_date(year: i32, month: u8, day: u8) -> Option<Date> {
+    lunisolar_date(year, month, day, BEIJING_OFFSET)
+}
+
+/// Function to get the Gregorian date of a day of the Korean calendar, the
+/// Chinese calendar in Korea Standard Time (UTC+9), e.g. Buddha's Birthday is
+/// the 8th day of the 4th month.
+///
+/// A new moon shortly before midnight in Beijing starts the month a day
+/// later in Korea (e.g. Buddha's Birthday 2023). Accurate for 1900-2100.
+pub fn korean_lunar_date(year: i32, month: u8, day: u8) -> Option<Date> {
+    lunisolar_date(year, month, day, SEOUL_OFFSET)
+}
+
+/// Day of the Chinese calendar computed in the time zone `offset`, as a
+/// fraction of a day east of UTC.
+fn lunisolar_date(year: i32, month: u8, day: u8, offset: f64) -> Option<Date> {
+    if !(1..=12).contains(&month) || !(1..=30).contains(&day) {
+        return None;
+    }
+
+    // Months 11 and 12 start the span of the following Gregorian year.
+    let months = lunar_months(if month <= 10 { year } else { year + 1 }, offset);
+
+    let index = months
+        .iter()
+        .position(|&(number, is_leap, _)| number == month && !is_leap)?;
+
+    let start = months[index].2;
+    let end = months
+        .get(index + 1)
+        .map_or_else(|| start + new_moon_day_length(start, offset), |next| next.2);
+
+    if start + i64::from(day) > end {
+        return None;
+    }
+
+    Date::from_julian_day(i32::try_from(start + i64::from(day) - 1).ok()?).ok()
+}
+
+/// Function to get the date (Beijing time) on which the apparent solar
+/// longitude reaches `longitude` degrees in a year, e.g. the Qingming solar
+/// term (Tomb Sweeping Day) is at 15 degrees.
+///
+/// Accurate to the day for the years 1900-2100.
+pub fn solar_term(year: i32, longitude: f64) -> Option<Date> {
+    Date::from_julian_day(i32::try_from(solar_term_day(year, longitude, BEIJING_OFFSET)).ok()?).ok()
+}
+
+/// Months of the Chinese calendar from the 11th month (containing the
+/// winter solstice) of the previous year to the next 11th month (exclusive),
+/// as (month number, is leap month, Julian day number of the first day).
+fn lunar_months(year: i32, offset: f64) -> Vec<(u8, bool, i64)> {
+    let first = new_moon_on_or_before(winter_solstice_day(year - 1, offset), offset);
+    let last = new_moon_on_or_before(winter_solstice_day(year, offset), offset);
+
+    // With 13 lunations, the first month without a principal term is leap.
+    let leap = if last - first == 13 {
+        (first + 1..last).find(|&k| !has_principal_term(k, offset))
+    } else {
+        None
+    };
+
+    let mut months = Vec::with_capacity(13);
+    let mut number = 10;
+
+    for k in first..last {
+        let is_leap = Some(k) == leap;
+        if !is_leap {
+            number = number % 12 + 1;
+        }
+        months.push((number, is_leap, new_moon_day(k, offset)));
+    }
+
+    months
+}
+
+/// Length of the month starting on the given day, used for the last month
+/// of a span.
+fn new_moon_day_length(start: i64, offset: f64) -> i64 {
+    let k = new_moon_on_or_before(start, offset);
+
+    new_moon_day(k + 1, offset) - new_moon_day(k, offset)
+}
+
+/// Julian day number of a moment (Julian date, UT) in the time zone `offset`.
+fn local_day(julian_date: f64, offset: f64) -> i64 {
+    (julian_date + 0.5 + offset).floor() as i64
+}
+
+/// Julian day number (local time) of the new moon with lunation number `k`.
+fn new_moon_day(k: i64, offset: f64) -> i64 {
+    local_day(new_moon(k), offset)
+}
+
+/// Lunation number of the last new moon on or before the given day.
+fn new_moon_on_or_before(day: i64, offset: f64) -> i64 {
+    let mut k = ((day as f64 - 2_451_550.1) / SYNODIC_MONTH).floor() as i64 + 1;
+
+    while new_moon_day(k, offset) > day {
+        k -= 1;
+    }
+    while new_moon_day(k + 1, offset) <= day {
+        k += 1;
+    }
+
+    k
+}
+
+/// Checks if the month starting at the new moon `k` contains a principal
+/// solar term (a multiple of 30 degrees of solar longitude).
+fn has_principal_term(k: i64, offset: f64) -> bool {
+    let longitude_at = |day: i64| solar_longitude(day as f64 - 0.5 - offset);
+
+    (longitude_at(new_moon_day(k, offset)) / 30.0).floor()
+        != (longitude_at(new_moon_day(k + 1, offset)) / 30.0).floor()
+}
+
+/// Julian day number (local time) of the December solstice of a year.
+fn winter_solstice_day(year: i32, offset: f64) -> i64 {
+    solar_term_day(year, 270.0, offset)
+}
+
+/// Julian day number (local time) on which the solar longitude reaches
+/// `longitude` degrees in a year.
+fn solar_term_day(year: i32, longitude: f64, offset: f64) -> i64 {
+    // Start from the March equinox, then solve for the solar longitude.
+    let mut julian_date = f64::from(
+        Date::from_calendar_date(year, Month::March, 20).map_or(0, |date| date.to_julian_day()),
+    ) + longitude.rem_euclid(360.0) / 360.0 * 365.242_2;
+
+    for _ in 0..10 {
+        let error = (longitude - solar_longitude(julian_date) + 540.0).rem_euclid(360.0) - 180.0;
+        julian_date += error / 360.0 * 365.242_2;
+    }
+
+    local_day(julian_date, offset)
+}
+
+/// Julian date (UT) of the new moon with lunation number `k`, counted from
+/// the new moon of 6 January 2000 (Meeus, Astronomical Algorithms, ch. 49).
+fn new_moon(k: i64) -> f64 {
+    let k = k as f64;
+    let t = k / 1236.85;
+    let (t2, t3, t4) = (t * t, t * t * t, t * t * t * t);
+
+    let jde = 2_451_550.097_66 + SYNODIC_MONTH * k + 0.000_154_37 * t2 - 0.000_000_150 * t3
+        + 0.000_000_000_73 * t4;
+
+    let e = 1.0 - 0.002_516 * t - 0.000_007_4 * t2;
+    let m = (2.5534 + 29.105_356_70 * k - 0.000_001_4 * t2 - 0.000_000_11 * t3).to_radians();
+    let mp = (201.5643 + 385.816_935_28 * k + 0.010_758_2 * t2 + 0.000_012_38 * t3
+        - 0.000_000_058 * t4)
+        .to_radians();
+    let f = (160.7108 + 390.670_502_84 * k - 0.001_611_8 * t2 - 0.000_002_27 * t3
+        + 0.000_000_011 * t4)
+        .to_radians();
+    let omega = (124.7746 - 1.563_755_88 * k + 0.002_067_2 * t2 + 0.000_002_15 * t3).to_radians();
+
+    let correction = -0.407_20 * mp.sin()
+        + 0.172_41 * e * m.sin()
+        + 0.016_08 * (2.0 * mp).sin()
+        + 0.010_39 * (2.0 * f).sin()
+        + 0.007_39 * e * (mp - m).sin()
+        - 0.005_14 * e * (mp + m).sin()
+        + 0.002_08 * e * e * (2.0 * m).sin()
+        - 0.001_11 * (mp - 2.0 * f).sin()
+        - 0.000_57 * (mp + 2.0 * f).sin()
+        + 0.000_56 * e * (2.0 * mp + m).sin()
+        - 0.000_42 * (3.0 * mp).sin()
+        + 0.000_42 * e * (m + 2.0 * f).sin()
+        + 0.000_38 * e * (m - 2.0 * f).sin()
+        - 0.000_24 * e * (2.0 * mp - m).sin()
+        - 0.000_17 * omega.sin()
+        - 0.000_07 * (mp + 2.0 * m).sin()
+        + 0.000_04 * (2.0 * mp - 2.0 * f).sin()
+        + 0.000_04 * (3.0 * m).sin()
+        + 0.000_03 * (mp + m - 2.0 * f).sin()
+        + 0.000_03 * (2.0 * mp + 2.0 * f).sin()
+        - 0.000_03 * (mp + m + 2.0 * f).sin()
+        + 0.000_03 * (mp - m + 2.0 * f).sin()
+        - 0.000_02 * (mp - m - 2.0 * f).sin()
+        - 0.000_02 * (3.0 * mp + m).sin()
+        + 0.000_02 * (4.0 * mp).sin();
+
+    // Terrestrial time to universal time (Delta T, in seconds).
+    let years = k / 12.3685;
+    let delta_t = 62.92 + 0.322_17 * years + 0.005_589 * years * years;
+
+    jde + correction - delta_t / 86_400.0
+}
+
+/// Apparent solar longitude in degrees at a Julian date
+/// (Meeus, Astronomical Algorithms, ch. 25, low accuracy).
+fn solar_longitude(julian_date: f64) -> f64 {
+    let t = (julian_date - 2_451_545.0) / 36_525.0;
+
+    let l0 = 280.466_46 + 36_000.769_83 * t + 0.000_303_2 * t * t;
+    let m = (357.529_11 + 35_999.050_29 * t - 0.000_153_7 * t * t).to_radians();
+    let c = (1.914_602 - 0.004_817 * t - 0.000_014 * t * t) * m.sin()
+        + (0.019_993 - 0.000_101 * t) * (2.0 * m).sin()
+        + 0.000_289 * (3.0 * m).sin();
+    let omega = (125.04 - 1_934.136 * t).to_radians();
+
+    (l0 + c - 0.005_69 - 0.004_78 * omega.sin()).rem_euclid(360.0)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// THAI LUNAR CALENDAR
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Julian date of the mean full moon of 21 January 2000.
+const MEAN_FULL_MOON: f64 = 2_451_564.862_66;
+
+/// Shift from a mean full moon to the Julian day number of the 15th day of
+/// the Thai month (calibrated to the official dates).
+const THAI_FULL_MOON_OFFSET: f64 = 0.15;
+
+/// Function to get the Buddhist full moon days of the Thai lunar calendar in
+/// a year: Makha Bucha, Visakha Bucha and Asarnha Bucha, the 15th days of the
+/// 3rd, 6th and 8th months (each a month later in a year with a leap month).
+///
+/// The arithmetic Thai calendar is approximated by mean lunations, with
+/// months alternately of 29 and 30 days and the leap day in the 7th month.
+/// This matches the official dates for 2015-2026, but can be a day off in
+/// other years.
+pub fn thai_buddhist_full_moons(year: i32) -> Option<[Date; 3]> {
+    let asarnha_bucha = thai_asarnha_bucha_day(year)?;
+    let days = asarnha_bucha - thai_asarnha_bucha_day(year - 1)?;
+
+    // 354 days in a common year, with a leap month of 30 days or a leap day.
+    let leap_month = i64::from(days > 370);
+    let leap_day = days - 354 - 30 * leap_month;
+
+    let visakha_bucha = asarnha_bucha - 59 - leap_day;
+    let makha_bucha = visakha_bucha - 88 - leap_month;
+
+    let to_date = |day: i64| Date::from_julian_day(i32::try_from(day).ok()?).ok();
+
+    Some([
+        to_date(makha_bucha)?,
+        to_date(visakha_bucha)?,
+        to_date(asarnha_bucha)?,
+    ])
+}
+
+/// Julian day number of Asarnha Bucha, the first (mean) full moon from
+/// 4 July.
+fn thai_asarnha_bucha_day(year: i32) -> Option<i64> {
+    let start = i64::from(
+        Date::from_calendar_date(year, Month::July, 4)
+            .ok()?
+            .to_julian_day(),
+    );
+    let day =
+        |k: i64| (MEAN_FULL_MOON + SYNODIC_MONTH * k as f64 + THAI_FULL_MOON_OFFSET).floor() as i64;
+
+    let mut k = ((start as f64 - MEAN_FULL_MOON) / SYNODIC_MONTH).floor() as i64;
+    while day(k) < start {
+        k += 1;
+    }
+
+    Some(day(k))
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ISLAMIC CALENDAR
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Function to convert a date of the (arithmetic) Islamic calendar to the
+/// Gregorian calendar, e.g. Eid al-Fitr is the 1st day of the 10th month.
+///
+/// The tabular calendar approximates the observed calendar, which depends on
+/// the sighting of the new moon, and can differ from it by one or two days.
+pub fn islamic_to_gregorian(year: i32, month: u8, day: u8) -> Option<Date> {
+    if year < 1 || !(1..=12).contains(&month) || !(1..=30).contains(&day) {
+        return None;
+    }
+
+    // Julian day number of the day before 1 Muharram 1 AH (16 July 622).
+    const ISLAMIC_EPOCH: i64 = 1_948_439;
+
+    // Months alternate between 30 and 29 days, with 11 leap years in 30.
+    let year = i64::from(year);
+    let julian_day = i64::from(day)
+        + (59 * (i64::from(month) - 1) + 1) / 2
+        + (year - 1) * 354
+        + (3 + 11 * year).div_euclid(30)
+        + ISLAMIC_EPOCH;
+
+    Date::from_julian_day(i32::try_from(julian_day).ok()?).ok()
+}
+
+/// Function to get all the occurrences of a day of the Islamic calendar in a
+/// Gregorian year (there can be two, as the Islamic year has 354 or 355 days).
+pub fn islamic_dates_in_year(gregorian_year: i32, month: u8, day: u8) -> Vec<Date> {
+    // The Islamic year starting in (roughly) the Gregorian year.
+    let hijri_year = (gregorian_year - 622) * 33 / 32;
+
+    (hijri_year - 1..=hijri_year + 2)
+        .filter_map(|year| islamic_to_gregorian(year, month, day))
+        .filter(|date| date.year() == gregorian_year)
+        .collect()
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// HEBREW CALENDAR
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Function to get Rosh Hashanah (1 Tishrei) falling in a Gregorian year,
+/// from the arithmetic Hebrew calendar (molad of Tishrei and the
+/// postponement rules). The other Jewish holidays are fixed offsets from it.
+pub fn rosh_hashanah(year: i32) -> Option<Date> {
+    // Julian day number of 1 Tishrei, Anno Mundi 1.
+    const HEBREW_EPOCH: i64 = 347_998;
+
+    // Days elapsed before the molad of Tishrei, with the "lo ADU" rule.
+    fn elapsed_days(year: i64) -> i64 {
+        let months = (235 * year - 234).div_euclid(19);
+        let parts = 12_084 + 13_753 * months;
+        let day = 29 * months + parts.div_euclid(25_920);
+
+        if (3 * (day + 1)).rem_euclid(7) < 3 {
+            day + 1
+        } else {
+            day
+        }
+    }
+
+    // Delay required to keep the lengths of adjacent years valid.
+    fn year_length_correction(year: i64) -> i64 {
+        let (previous, current, next) = (
+            elapsed_days(year - 1),
+            elapsed_days(year),
+            elapsed_days(year + 1),
+        );
+
+        if next - current == 356 {
+            2
+        } else if current - previous == 382 {
+            1
+        } else {
+            0
+        }
+    }
+
+    let hebrew_year = i64::from(year) + 3761;
+    let julian_day = HEBREW_EPOCH + elapsed_days(hebrew_year) + year_length_correction(hebrew_year);
+
+    Date::from_julian_day(i32::try_from(julian_day).ok()?).ok()
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_holiday_rules {
+    use super::*;
+    use crate::time::constants::EASTER_MONDAYS;
+    use time::macros::date;
+
+    #[test]
+    fn test_nth_weekday_of_month() {
+        assert_eq!(
+            nth_weekday_of_month(2024, Month::January, Weekday::Monday, 3),
+            Some(date!(2024 - 01 - 15))
+        );
+        assert_eq!(
+            nth_weekday_of_month(2024, Month::November, Weekday::Thursday, 4),
+            Some(date!(2024 - 11 - 28))
+        );
+        assert_eq!(
+            nth_weekday_of_month(2024, Month::February, Weekday::Thursday, 5),
+            Some(date!(2024 - 02 - 29))
+        );
+        assert_eq!(
+            nth_weekday_of_month(2024, Month::February, Weekday::Friday, 5),
+            None
+        );
+        assert_eq!(
+            last_weekday_of_month(2024, Month::May, Weekday::Monday),
+            Some(date!(2024 - 05 - 27))
+        );
+        assert_eq!(
+            last_weekday_of_month(2024, Month::August, Weekday::Saturday),
+            Some(date!(2024 - 08 - 31))
+        );
+    }
+
+    #[test]
+    fn test_easter_matches_table() {
+        for is_orthodox in [false, true] {
+            for year in 1901..=2199 {
+                let easter_monday = easter_sunday(year, is_orthodox).unwrap() + Duration::days(1);
+
+                assert_eq!(
+                    easter_monday.ordinal(),
+                    EASTER_MONDAYS[usize::from(is_orthodox)][(year - 1901) as usize],
+                    "{year}, orthodox: {is_orthodox}"
+                );
+            }
+        }
+
+        assert_eq!(easter_sunday(2024, false), Some(date!(2024 - 03 - 31)));
+        assert_eq!(easter_sunday(2024, true), Some(date!(2024 - 05 - 05)));
+    }
+
+    #[test]
+    fn test_chinese_new_year() {
+        let expected = [
+            date!(1990 - 01 - 27),
+            date!(1996 - 02 - 19),
+            date!(2006 - 01 - 29),
+            date!(2007 - 02 - 18),
+            date!(2014 - 01 - 31),
+            date!(2020 - 01 - 25),
+            date!(2023 - 01 - 22),
+            date!(2024 - 02 - 10),
+            date!(2025 - 01 - 29),
+            date!(2026 - 02 - 17),
+            // After the leap 11th month of 2033.
+            date!(2034 - 02 - 19),
+            date!(2050 - 01 - 23),
+        ];
+
+        for date in expected {
+            assert_eq!(chinese_new_year(date.year()), Some(date));
+        }
+    }
+
+    #[test]
+    fn test_chinese_lunar_date() {
+        // Dragon Boat (5/5) and Mid-Autumn (8/15) festivals.
+        assert_eq!(chinese_lunar_date(2024, 5, 5), Some(date!(2024 - 06 - 10)));
+        assert_eq!(chinese_lunar_date(2025, 5, 5), Some(date!(2025 - 05 - 31)));
+        assert_eq!(chinese_lunar_date(2017, 8, 15), Some(date!(2017 - 10 - 04)));
+        assert_eq!(chinese_lunar_date(2024, 8, 15), Some(date!(2024 - 09 - 17)));
+        // The 12th month falls in the next Gregorian year.
+        assert_eq!(
+            chinese_lunar_date(2024, 12, 29).unwrap() + Duration::days(1),
+            chinese_new_year(2025).unwrap()
+        );
+        assert_eq!(chinese_lunar_date(2024, 13, 1), None);
+
+        // Buddha's Birthday (4/8) 2023 is a day later in Korea.
+        assert_eq!(chinese_lunar_date(2023, 4, 8), Some(date!(2023 - 05 - 26)));
+        assert_eq!(korean_lunar_date(2023, 4, 8), Some(date!(2023 - 05 - 27)));
+    }
+
+    #[test]
+    fn test_solar_term() {
+        // Qingming, on 4 or 5 April.
+        for (year, day) in [
+            (2020, 4),
+            (2022, 5),
+            (2023, 5),
+            (2024, 4),
+            (2026, 5),
+            (2032, 4),
+        ] {
+            assert_eq!(
+                solar_term(year, 15.0),
+                Date::from_calendar_date(year, Month::April, day).ok()
+            );
+        }
+        assert_eq!(solar_term(2024, 270.0), Some(date!(2024 - 12 - 21)));
+    }
+
+    #[test]
+    fn test_thai_buddhist_full_moons() {
+        // Including the leap month of 2023 and the leap day of 2016.
+        let expected = [
+            [
+                date!(2016 - 02 - 22),
+                date!(2016 - 05 - 20),
+                date!(2016 - 07 - 19),
+            ],
+            [
+                date!(2020 - 02 - 08),
+                date!(2020 - 05 - 06),
+                date!(2020 - 07 - 05),
+            ],
+            [
+                date!(2022 - 02 - 16),
+                date!(2022 - 05 - 15),
+                date!(2022 - 07 - 13),
+            ],
+            [
+                date!(2023 - 03 - 06),
+                date!(2023 - 06 - 03),
+                date!(2023 - 08 - 01),
+            ],
+            [
+                date!(2025 - 02 - 12),
+                date!(2025 - 05 - 11),
+                date!(2025 - 07 - 10),
+            ],
+        ];
+
+        for dates in expected {
+            assert_eq!(thai_buddhist_full_moons(dates[0].year()), Some(dates));
+        }
+    }
+
+    #[test]
+    fn test_islamic_to_gregorian() {
+        // Eid al-Fitr and Eid al-Adha 1445 AH.
+        assert_eq!(
+            islamic_to_gregorian(1445, 10, 1),
+            Some(date!(2024 - 04 - 10))
+        );
+        assert_eq!(
+            islamic_to_gregorian(1445, 12, 10),
+            Some(date!(2024 - 06 - 17))
+        );
+        assert_eq!(islamic_to_gregorian(0, 1, 1), None);
+
+        // Two Eid al-Fitr in 2000 (1420 and 1421 AH).
+        assert_eq!(islamic_dates_in_year(2000, 10, 1).len(), 2);
+        assert_eq!(
+            islamic_dates_in_year(2024, 10, 1),
+            vec![date!(2024 - 04 - 10)]
+        );
+    }
+
+    #[test]
+    fn test_rosh_hashanah() {
+        assert_eq!(rosh_hashanah(2023), Some(date!(2023 - 09 - 16)));
+        assert_eq!(rosh_hashanah(2024), Some(date!(2024 - 10 - 03)));
+        assert_eq!(rosh_hashanah(2025), Some(date!(2025 - 09 - 23)));
+    }
+}
@@ -0,0 +1,293 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! IMM and CDS standard dates.
+//!
+//! IMM dates are the third Wednesday of March, June, September and December,
+//! the settlement dates of the quarterly interest rate futures. CDS dates are
+//! the 20th of the same months, on which standard credit default swaps roll
+//! and pay their coupons. Both are unadjusted dates.
+
+use crate::time::holiday_rules::nth_weekday_of_month;
+use time::{Date, Month, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMM DATES
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Function to get the IMM date (the third Wednesday) of a month.
+///
+/// # Panics
+///
+/// Panics if the year is outside the range supported by `time::Date`.
+pub fn imm_date(year: i32, month: Month) -> Date {
+    nth_weekday_of_month(year, month, Weekday::Wednesday, 3).unwrap()
+}
+
+/// Function to check if a date is an IMM date, i.e. the third Wednesday of
+/// March, June, September or December.
+pub fn is_imm_date(date: Date) -> bool {
+    is_quarter_month(date.month())
+        && date.weekday() == Weekday::Wednesday
+        && (15..=21).contains(&date.day())
+}
+
+/// Function to get the first IMM date strictly after a date.
+pub fn next_imm_date(date: Date) -> Date {
+    next_quarterly_date(date, imm_date)
+}
+
+/// Function to get the last IMM date strictly before a date.
+pub fn previous_imm_date(date: Date) -> Date {
+    previous_quarterly_date(date, imm_date)
+}
+
+/// Function to get the IMM dates between two dates (inclusive).
+pub fn imm_dates_between(start: Date, end: Date) -> Vec<Date> {
+    quarterly_dates_between(start, end, imm_date)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CDS DATES
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Function to get the CDS date (the 20th) of a month.
+///
+/// # Panics
+///
+/// Panics if the year is outside the range supported by `time::Date`.
+pub fn cds_date(year: i32, month: Month) -> Date {
+    Date::from_calendar_date(year, month, 20).unwrap()
+}
+
+/// Function to check if a date is a CDS date, i.e. the 20th of
+/// March, June, September or December.
+pub fn is_cds_date(date: Date) -> bool {
+    is_quarter_month(date.month()) && date.day() == 20
+}
+
+/// Function to get the first CDS date strictly after a date.
+pub fn next_cds_date(date: Date) -> Date {
+    next_quarterly_date(date, cds_date)
+}
+
+/// Function to get the last CDS date strictly before a date.
+pub fn previous_cds_date(date: Date) -> Date {
+    previous_quarterly_date(date, cds_date)
+}
+
+/// Function to get the CDS roll dates between two dates (inclusive).
+pub fn cds_roll_dates(start: Date, end: Date) -> Vec<Date> {
+    quarterly_dates_between(start, end, cds_date)
+}
+
+/// Function to get the standard maturity of a CDS traded on a date, for a
+/// tenor in years.
+///
+/// Since the ISDA 2015 convention, standard maturities roll twice a year on
+/// the 20th of March and September: a trade from 20th March up to 19th
+/// September matures on the 20th of June, and otherwise on the 20th of
+/// December, `tenor_years` after the roll.
+pub fn cds_maturity_date(trade_date: Date, tenor_years: i32) -> Date {
+    let year = trade_date.year();
+
+    let roll = if trade_date < cds_date(year, Month::March) {
+        cds_date(year - 1, Month::December)
+    } else if trade_date < cds_date(year, Month::September) {
+        cds_date(year, Month::June)
+    } else {
+        cds_date(year, Month::December)
+    };
+
+    roll.replace_year(roll.year() + tenor_years).unwrap()
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// HELPERS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// March, June, September and December.
+fn is_quarter_month(month: Month) -> bool {
+    (month as u8).is_multiple_of(3)
+}
+
+/// The quarter month on or after the month of `date`, as `(year, month)`.
+fn quarter_month_on_or_after(date: Date) -> (i32, u8) {
+    let month = date.month() as u8;
+
+    (date.year(), month.div_ceil(3) * 3)
+}
+
+/// Step `(year, month)` by a (possibly negative) number of quarters.
+fn add_quarters((year, month): (i32, u8), quarters: i32) -> (i32, Month) {
+    let total = year * 12 + i32::from(month) - 1 + 3 * quarters;
+
+    (
+        total.div_euclid(12),
+        Month::try_from((total.rem_euclid(12) + 1) as u8).unwrap(),
+    )
+}
+
+/// First quarterly date strictly after `date`.
+fn next_quarterly_date(date: Date, quarterly_date: fn(i32, Month) -> Date) -> Date {
+    let start = quarter_month_on_or_after(date);
+
+    (0..)
+        .map(|k| {
+            let (year, month) = add_quarters(start, k);
+            quarterly_date(year, month)
+        })
+        .find(|candidate| *candidate > date)
+        .unwrap()
+}
+
+/// Last quarterly date strictly before `date`.
+fn previous_quarterly_date(date: Date, quarterly_date: fn(i32, Month) -> Date) -> Date {
+    let start = quarter_month_on_or_after(date);
+
+    (0..)
+        .map(|k| {
+            let (year, month) = add_quarters(start, -k);
+            quarterly_date(year, month)
+        })
+        .find(|candidate| *candidate < date)
+        .unwrap()
+}
+
+/// Quarterly dates from `start` to `end` (inclusive).
+fn quarterly_dates_between(
+    start: Date,
+    end: Date,
+    quarterly_date: fn(i32, Month) -> Date,
+) -> Vec<Date> {
+    let mut dates = Vec::new();
+
+    if start > end {
+        return dates;
+    }
+
+    // The first date on or after the start.
+    let mut date = next_quarterly_date(start.previous_day().unwrap(), quarterly_date);
+
+    while date <= end {
+        dates.push(date);
+        date = next_quarterly_date(date, quarterly_date);
+    }
+
+    dates
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_imm {
+    use super::*;
+    use time::macros::date;
+
+    #[test]
+    fn test_imm_dates() {
+        assert_eq!(imm_date(2024, Month::March), date!(2024 - 03 - 20));
+        assert_eq!(imm_date(2024, Month::June), date!(2024 - 06 - 19));
+        assert_eq!(imm_date(2024, Month::September), date!(2024 - 09 - 18));
+        assert_eq!(imm_date(2024, Month::December), date!(2024 - 12 - 18));
+
+        // The month starts on a Wednesday.
+        assert_eq!(imm_date(2025, Month::October), date!(2025 - 10 - 15));
+
+        assert!(is_imm_date(date!(2024 - 06 - 19)));
+        assert!(!is_imm_date(date!(2024 - 06 - 12)));
+        assert!(!is_imm_date(date!(2024 - 07 - 17)));
+    }
+
+    #[test]
+    fn test_next_and_previous_imm_dates() {
+        assert_eq!(next_imm_date(date!(2024 - 01 - 10)), date!(2024 - 03 - 20));
+        assert_eq!(next_imm_date(date!(2024 - 03 - 20)), date!(2024 - 06 - 19));
+        assert_eq!(next_imm_date(date!(2024 - 12 - 19)), date!(2025 - 03 - 19));
+
+        assert_eq!(
+            previous_imm_date(date!(2024 - 03 - 20)),
+            date!(2023 - 12 - 20)
+        );
+        assert_eq!(
+            previous_imm_date(date!(2024 - 03 - 21)),
+            date!(2024 - 03 - 20)
+        );
+        assert_eq!(
+            previous_imm_date(date!(2024 - 08 - 01)),
+            date!(2024 - 06 - 19)
+        );
+
+        assert_eq!(
+            imm_dates_between(date!(2024 - 03 - 20), date!(2024 - 12 - 18)),
+            vec![
+                date!(2024 - 03 - 20),
+                date!(2024 - 06 - 19),
+                date!(2024 - 09 - 18),
+                date!(2024 - 12 - 18),
+            ]
+        );
+
+        for date in imm_dates_between(date!(2000 - 01 - 01), date!(2030 - 12 - 31)) {
+            assert!(is_imm_date(date));
+            assert_eq!(next_imm_date(previous_imm_date(date)), date);
+        }
+    }
+
+    #[test]
+    fn test_cds_dates() {
+        assert!(is_cds_date(date!(2024 - 09 - 20)));
+        assert!(!is_cds_date(date!(2024 - 08 - 20)));
+
+        assert_eq!(next_cds_date(date!(2024 - 09 - 20)), date!(2024 - 12 - 20));
+        assert_eq!(next_cds_date(date!(2024 - 12 - 21)), date!(2025 - 03 - 20));
+        assert_eq!(
+            previous_cds_date(date!(2024 - 01 - 05)),
+            date!(2023 - 12 - 20)
+        );
+
+        assert_eq!(
+            cds_roll_dates(date!(2024 - 03 - 21), date!(2025 - 03 - 20)),
+            vec![
+                date!(2024 - 06 - 20),
+                date!(2024 - 09 - 20),
+                date!(2024 - 12 - 20),
+                date!(2025 - 03 - 20),
+            ]
+        );
+        assert!(cds_roll_dates(date!(2024 - 03 - 21), date!(2024 - 06 - 19)).is_empty());
+    }
+
+    #[test]
+    fn test_cds_maturity_dates() {
+        // Semi-annual rolls on the 20th of March and September.
+        assert_eq!(
+            cds_maturity_date(date!(2024 - 03 - 19), 5),
+            date!(2028 - 12 - 20)
+        );
+        assert_eq!(
+            cds_maturity_date(date!(2024 - 03 - 20), 5),
+            date!(2029 - 06 - 20)
+        );
+        assert_eq!(
+            cds_maturity_date(date!(2024 - 09 - 19), 5),
+            date!(2029 - 06 - 20)
+        );
+        assert_eq!(
+            cds_maturity_date(date!(2024 - 09 - 20), 5),
+            date!(2029 - 12 - 20)
+        );
+        assert_eq!(
+            cds_maturity_date(date!(2024 - 12 - 31), 1),
+            date!(2025 - 12 - 20)
+        );
+    }
+}
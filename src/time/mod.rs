@@ -39,10 +39,29 @@ pub use frequency::*;
 pub mod holiday;
 pub use holiday::*;
 
+/// Rules for movable holidays (Easter, lunar and Islamic calendars).
+pub mod holiday_rules;
+
+/// IMM and CDS standard dates.
+pub mod imm;
+pub use imm::*;
+
 /// Utility functions for working with dates and times.
 pub mod utilities;
 pub use utilities::*;
 
+/// Periods of time, such as tenors.
+pub mod period;
+pub use period::*;
+
+/// Settlement dates with spot lags per currency.
+pub mod settlement;
+pub use settlement::*;
+
 /// The `Schedule` type.
 pub mod schedule;
 pub use schedule::*;
+
+/// Event-driven simulation clock.
+pub mod simulation_clock;
+pub use simulation_clock::*;
@@ -0,0 +1,481 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Periods of time, such as the tenors of instruments.
+//!
+//! Periods parse from market tenor strings ("ON", "1W", "3M", "10Y"),
+//! compare after normalization (so that "12M" equals "1Y"), and can be
+//! added to dates.
+
+use crate::error::RustQuantError;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+use time::{Date, Duration, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS AND ENUMS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Unit of a [`Period`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeUnit {
+    /// Business days of a calendar.
+    BusinessDays,
+
+    /// Calendar days.
+    Days,
+
+    /// Weeks.
+    Weeks,
+
+    /// Months.
+    Months,
+
+    /// Years.
+    Years,
+}
+
+/// A period of time, as a (possibly negative) number of units, e.g. 3 months.
+///
+/// Periods are equal if they are equal once normalized, e.g. `12M == 1Y`
+/// and `14D == 2W`.
+///
+/// ```
+/// use RustQuant::time::Period;
+///
+/// let tenor: Period = "12M".parse().unwrap();
+///
+/// assert_eq!(tenor, Period::years(1));
+/// assert_eq!(tenor.to_string(), "12M");
+/// assert!(Period::months(3) < "6M".parse().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Period {
+    /// Number of units of the period.
+    pub length: i32,
+
+    /// Unit of the period.
+    pub unit: TimeUnit,
+}
+
+/// A tenor, such as the maturity of a deposit or a swap.
+pub type Tenor = Period;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Period {
+    /// New period of `length` units.
+    #[must_use]
+    pub const fn new(length: i32, unit: TimeUnit) -> Self {
+        Self { length, unit }
+    }
+
+    /// A period of business days.
+    #[must_use]
+    pub const fn business_days(length: i32) -> Self {
+        Self::new(length, TimeUnit::BusinessDays)
+    }
+
+    /// A period of calendar days.
+    #[must_use]
+    pub const fn days(length: i32) -> Self {
+        Self::new(length, TimeUnit::Days)
+    }
+
+    /// A period of weeks.
+    #[must_use]
+    pub const fn weeks(length: i32) -> Self {
+        Self::new(length, TimeUnit::Weeks)
+    }
+
+    /// A period of months.
+    #[must_use]
+    pub const fn months(length: i32) -> Self {
+        Self::new(length, TimeUnit::Months)
+    }
+
+    /// A period of years.
+    #[must_use]
+    pub const fn years(length: i32) -> Self {
+        Self::new(length, TimeUnit::Years)
+    }
+
+    /// Normalized period: months in whole years become years, and days in
+    /// whole weeks become weeks. A zero period is zero days.
+    #[must_use]
+    pub const fn normalized(&self) -> Self {
+        match self.unit {
+            _ if self.length == 0 => Self::days(0),
+            TimeUnit::Months if self.length % 12 == 0 => Self::years(self.length / 12),
+            TimeUnit::Days if self.length % 7 == 0 => Self::weeks(self.length / 7),
+            _ => *self,
+        }
+    }
+
+    /// The period in its smallest exact unit: months for months and years,
+    /// days for days and weeks, or business days.
+    const fn in_base_unit(&self) -> (i32, TimeUnit) {
+        match self.unit {
+            TimeUnit::BusinessDays => (self.length, TimeUnit::BusinessDays),
+            TimeUnit::Days => (self.length, TimeUnit::Days),
+            TimeUnit::Weeks => (7 * self.length, TimeUnit::Days),
+            TimeUnit::Months => (self.length, TimeUnit::Months),
+            TimeUnit::Years => (12 * self.length, TimeUnit::Months),
+        }
+    }
+
+    /// Lower and upper bounds of the period in calendar days.
+    fn day_bounds(&self) -> (i64, i64) {
+        let (length, unit) = self.in_base_unit();
+        let length = i64::from(length);
+
+        match unit {
+            TimeUnit::Months => {
+                let (years, months) = (length / 12, length % 12);
+                let (low, high) = (365 * years + 28 * months, 366 * years + 31 * months);
+
+                if length < 0 {
+                    (high, low)
+                } else {
+                    (low, high)
+                }
+            }
+            _ => (length, length),
+        }
+    }
+
+    /// Sum of two periods, if they have compatible units (business days,
+    /// days and weeks, or months and years).
+    #[must_use]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let ((a, unit), (b, other_unit)) = (self.in_base_unit(), other.in_base_unit());
+
+        if self.length == 0 {
+            return Some(other);
+        }
+        if other.length == 0 {
+            return Some(self);
+        }
+        if self.unit == other.unit {
+            return Some(Self::new(self.length + other.length, self.unit));
+        }
+
+        (unit == other_unit).then(|| Self::new(a + b, unit))
+    }
+}
+
+impl PartialEq for Period {
+    fn eq(&self, other: &Self) -> bool {
+        self.in_base_unit() == other.in_base_unit() || self.length == 0 && other.length == 0
+    }
+}
+
+impl Eq for Period {}
+
+impl Hash for Period {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if self.length == 0 {
+            (0, TimeUnit::Days).hash(state);
+        } else {
+            self.in_base_unit().hash(state);
+        }
+    }
+}
+
+impl PartialOrd for Period {
+    /// Periods in the same base unit compare exactly. Periods of days and of
+    /// months compare by their bounds in days (a month is 28 to 31 days), and
+    /// are not comparable if the bounds overlap (e.g. 1M and 30D).
+    /// Business days only compare to business days.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let ((a, unit), (b, other_unit)) = (self.in_base_unit(), other.in_base_unit());
+
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+        if unit == other_unit {
+            return Some(a.cmp(&b));
+        }
+        if unit == TimeUnit::BusinessDays || other_unit == TimeUnit::BusinessDays {
+            return None;
+        }
+
+        let ((low, high), (other_low, other_high)) = (self.day_bounds(), other.day_bounds());
+
+        if high < other_low {
+            Some(Ordering::Less)
+        } else if low > other_high {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = match self.unit {
+            TimeUnit::BusinessDays => "BD",
+            TimeUnit::Days => "D",
+            TimeUnit::Weeks => "W",
+            TimeUnit::Months => "M",
+            TimeUnit::Years => "Y",
+        };
+
+        write!(f, "{}{}", self.length, unit)
+    }
+}
+
+impl FromStr for Period {
+    type Err = RustQuantError;
+
+    /// Parse a tenor such as "3M", "1w" or "10Y". "ON" (overnight) is one
+    /// business day, and "BD" is the unit of business days (e.g. "2BD").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RustQuantError::InvalidArgument(format!("invalid tenor: '{s}'"));
+
+        let tenor = s.trim().to_uppercase();
+
+        if tenor == "ON" || tenor == "O/N" {
+            return Ok(Self::business_days(1));
+        }
+
+        let split = tenor
+            .find(|c: char| c.is_ascii_alphabetic())
+            .ok_or_else(invalid)?;
+        let (length, unit) = tenor.split_at(split);
+        let length: i32 = length.parse().map_err(|_| invalid())?;
+
+        let unit = match unit {
+            "BD" => TimeUnit::BusinessDays,
+            "D" => TimeUnit::Days,
+            "W" => TimeUnit::Weeks,
+            "M" => TimeUnit::Months,
+            "Y" => TimeUnit::Years,
+            _ => return Err(invalid()),
+        };
+
+        Ok(Self::new(length, unit))
+    }
+}
+
+/// Periods serialize as their tenor strings, e.g. "3M".
+#[cfg(feature = "serde")]
+impl serde::Serialize for Period {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Period {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let tenor = String::deserialize(deserializer)?;
+
+        tenor.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Neg for Period {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.length, self.unit)
+    }
+}
+
+impl Mul<i32> for Period {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self::new(self.length * rhs, self.unit)
+    }
+}
+
+impl Add for Period {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the units are not compatible
+    /// (see [`Period::checked_add`]).
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .unwrap_or_else(|| panic!("cannot add periods {self} and {rhs}"))
+    }
+}
+
+impl Sub for Period {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the units are not compatible
+    /// (see [`Period::checked_add`]).
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Add<Period> for Date {
+    type Output = Date;
+
+    /// Add a period to a date without a calendar. Months and years clamp the
+    /// day to the end of the month, and business days skip weekends only
+    /// (see [`crate::time::Calendar::advance`] for holidays and conventions).
+    fn add(self, rhs: Period) -> Self::Output {
+        match rhs.unit {
+            TimeUnit::BusinessDays => {
+                let step = Duration::days(if rhs.length < 0 { -1 } else { 1 });
+                let mut date = self;
+
+                for _ in 0..rhs.length.unsigned_abs() {
+                    date += step;
+
+                    while matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday) {
+                        date += step;
+                    }
+                }
+
+                date
+            }
+            TimeUnit::Days => self + Duration::days(rhs.length.into()),
+            TimeUnit::Weeks => self + Duration::weeks(rhs.length.into()),
+            TimeUnit::Months => crate::time::add_months(self, rhs.length),
+            TimeUnit::Years => crate::time::add_months(self, 12 * rhs.length),
+        }
+    }
+}
+
+impl Sub<Period> for Date {
+    type Output = Date;
+
+    fn sub(self, rhs: Period) -> Self::Output {
+        self + -rhs
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_period {
+    use super::*;
+    use std::collections::HashSet;
+    use time::macros::date;
+
+    #[test]
+    fn test_parse_and_display_periods() {
+        let cases = [
+            ("ON", Period::business_days(1)),
+            ("2BD", Period::business_days(2)),
+            ("1d", Period::days(1)),
+            (" 1W ", Period::weeks(1)),
+            ("3M", Period::months(3)),
+            ("10Y", Period::years(10)),
+            ("-6M", Period::months(-6)),
+        ];
+
+        for (tenor, period) in cases {
+            let parsed: Period = tenor.parse().unwrap();
+
+            assert_eq!(parsed, period);
+            assert_eq!(parsed.unit, period.unit);
+        }
+
+        assert_eq!(Period::years(10).to_string(), "10Y");
+        assert_eq!(Period::business_days(2).to_string(), "2BD");
+
+        for tenor in ["", "M", "3", "3Q", "1.5Y", "ONN"] {
+            assert!(tenor.parse::<Period>().is_err());
+        }
+    }
+
+    #[test]
+    fn test_period_normalization_and_comparison() {
+        assert_eq!(Period::months(12), Period::years(1));
+        assert_eq!(Period::days(14), Period::weeks(2));
+        assert_eq!(Period::months(0), Period::days(0));
+        assert_eq!(Period::months(24).normalized().unit, TimeUnit::Years);
+        assert_eq!(Period::months(18).normalized(), Period::months(18));
+        assert_ne!(Period::business_days(5), Period::weeks(1));
+
+        let keys: HashSet<Period> = [Period::months(12), Period::years(1), Period::months(6)]
+            .into_iter()
+            .collect();
+        assert_eq!(keys.len(), 2);
+
+        assert!(Period::months(3) < Period::years(1));
+        assert!(Period::weeks(1) < Period::months(1));
+        assert!(Period::days(32) > Period::months(1));
+        assert!(Period::months(-1) < Period::days(0));
+        assert_eq!(Period::days(30).partial_cmp(&Period::months(1)), None);
+        assert_eq!(Period::business_days(1).partial_cmp(&Period::days(1)), None);
+    }
+
+    #[test]
+    fn test_period_arithmetic() {
+        assert_eq!(Period::years(1) + Period::months(6), Period::months(18));
+        assert_eq!(Period::weeks(1) - Period::days(2), Period::days(5));
+        assert_eq!(Period::months(3) * 4, Period::years(1));
+        assert_eq!(-Period::months(3), Period::months(-3));
+        assert_eq!(Period::months(1).checked_add(Period::days(1)), None);
+
+        // Month ends are clamped, and business days skip weekends.
+        assert_eq!(
+            date!(2024 - 01 - 31) + Period::months(1),
+            date!(2024 - 02 - 29)
+        );
+        assert_eq!(
+            date!(2024 - 02 - 29) + Period::years(1),
+            date!(2025 - 02 - 28)
+        );
+        assert_eq!(
+            date!(2024 - 03 - 15) - Period::weeks(2),
+            date!(2024 - 03 - 01)
+        );
+        assert_eq!(
+            date!(2024 - 01 - 05) + Period::business_days(1),
+            date!(2024 - 01 - 08)
+        );
+        assert_eq!(
+            date!(2024 - 01 - 08) - Period::business_days(1),
+            date!(2024 - 01 - 05)
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests_period_serde {
+    use super::*;
+
+    #[test]
+    fn test_period_serde_round_trip() {
+        let tenors = vec![
+            Period::business_days(1),
+            Period::months(3),
+            Period::years(10),
+        ];
+
+        let json = serde_json::to_string(&tenors).unwrap();
+        assert_eq!(json, r#"["1BD","3M","10Y"]"#);
+
+        let parsed: Vec<Period> = serde_json::from_str(r#"["ON","3M","10Y"]"#).unwrap();
+        assert_eq!(parsed, tenors);
+
+        assert!(serde_json::from_str::<Period>(r#""3Q""#).is_err());
+    }
+}
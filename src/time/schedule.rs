@@ -7,11 +7,16 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use crate::time::date_rolling::{DateRoller, DateRollingConvention};
+use crate::error::RustQuantError;
+use crate::time::date_rolling::{BusinessDayAdjustment, DateRoller, DateRollingConvention};
 use crate::time::day_counting::{DayCountConvention, DayCounter};
-use crate::time::Calendar;
+use crate::time::{
+    add_months, advance_business_days, days_between, is_last_day_of_month,
+    last_business_day_of_month, last_day_of_month, previous_business_day,
+    try_advance_business_days, Calendar, Frequency,
+};
 use std::fmt;
-use time::Date;
+use time::{Date, Duration};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // STRUCTS, ENUMS, AND TRAITS
@@ -26,13 +31,33 @@ use time::Date;
 ///
 /// The Schedule struct is used to represent these schedules,
 /// and pricing methods should be implemented using date/time functionality.
+///
+/// Every constructor, except [`Scheduler::generate_schedule_from_dates`],
+/// produces the same layout: `dates` starts with the effective date and ends
+/// with the termination date, so a schedule of `n` dates has `n - 1` coupon
+/// periods. `unadjusted_dates` and `payment_dates`
+/// are aligned with `dates` (the first payment date is never paid), and
+/// `day_count_factors[i]` is the factor of the period from `dates[i]` to
+/// `dates[i + 1]`, so it has one element fewer than `dates`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Schedule {
-    /// The dates of the schedule.
+    /// The dates of the schedule, starting with the effective date.
     pub dates: Vec<Date>,
 
-    /// The day count factors of the schedule.
+    /// The dates of the schedule before rolling.
+    pub unadjusted_dates: Vec<Date>,
+
+    /// The payment dates of the schedule, which may be rolled by a different
+    /// convention (and lagged) relative to the accrual dates.
+    pub payment_dates: Vec<Date>,
+
+    /// The day count factor of each period of the schedule.
     pub day_count_factors: Vec<f64>,
 
+    /// Number of business days between each fixing date and the start of
+    /// its period (see [`Schedule::fixing_dates`]). Zero unless given.
+    pub fixing_days: i64,
+
     /// The convention of the schedule.
     pub day_counting_convention: DayCountConvention,
 
@@ -40,6 +65,97 @@ pub struct Schedule {
     pub date_rolling_convention: DateRollingConvention,
 }
 
+/// A single coupon period of a [`Schedule`].
+///
+/// The accrual dates are given both before and after rolling, since some
+/// instruments accrue on unadjusted dates while paying on adjusted dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CouponPeriod {
+    /// Adjusted start date of the accrual period.
+    pub start: Date,
+
+    /// Adjusted end date of the accrual period.
+    pub end: Date,
+
+    /// Unadjusted start date of the accrual period.
+    pub unadjusted_start: Date,
+
+    /// Unadjusted end date of the accrual period.
+    pub unadjusted_end: Date,
+
+    /// Payment date of the period (the unadjusted end date rolled by the
+    /// payment convention and shifted by the payment lag).
+    pub payment: Date,
+}
+
+/// When the floating rate of a coupon period is fixed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FixingTiming {
+    /// Fixed before the start of the accrual period (IBOR-style).
+    #[default]
+    InAdvance,
+
+    /// Fixed before the end of the accrual period.
+    InArrears,
+}
+
+/// Where the irregular period of a generated schedule goes, when the
+/// effective and termination dates are not a whole number of periods apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StubPolicy {
+    /// Dates are generated backwards from the termination date, leaving a
+    /// short first period.
+    #[default]
+    ShortFront,
+
+    /// Dates are generated backwards from the termination date, and the short
+    /// first period is merged into the next one.
+    LongFront,
+
+    /// Dates are generated forwards from the effective date, leaving a short
+    /// last period.
+    ShortBack,
+
+    /// Dates are generated forwards from the effective date, and the short
+    /// last period is merged into the one before.
+    LongBack,
+}
+
+/// Builder of a regular [`Schedule`] from its effective and termination dates.
+///
+/// The accrual dates are generated at the given frequency on the side given by
+/// the stub policy, and rolled by the date rolling convention. The payment
+/// dates are the unadjusted dates rolled by the payment convention (the date
+/// rolling convention unless given) and shifted by the payment lag.
+///
+/// With the end-of-month flag, a schedule anchored on a month end (the last
+/// day, or the last business day, of its month) keeps every generated date on
+/// the month end, and month ends roll to the last business day of the month.
+///
+/// ```ignore
+/// let schedule = ScheduleBuilder::new(effective, termination, Frequency::Quarterly, &calendar)
+///     .date_rolling_convention(DateRollingConvention::ModifiedFollowing)
+///     .stub(StubPolicy::LongFront)
+///     .payment_lag(2)
+///     .end_of_month(true)
+///     .build()?;
+/// ```
+pub struct ScheduleBuilder<'a, C: Calendar> {
+    effective_date: Date,
+    termination_date: Date,
+    frequency: Frequency,
+    calendar: &'a C,
+    date_rolling_convention: DateRollingConvention,
+    payment_convention: Option<DateRollingConvention>,
+    payment_lag: i64,
+    day_counting_convention: DayCountConvention,
+    stub: StubPolicy,
+    end_of_month: bool,
+}
+
 /// The `Scheduler` trait.
 /// This trait is used to generate schedules for a `Calendar`.
 pub trait Scheduler {
@@ -47,6 +163,10 @@ pub trait Scheduler {
     /// For example, a list of coupon payment dates.
     ///
     /// Note: The effective date is not included in the dates input, and assumed to be today.
+    /// The schedule dates are the rolled `dates` only, and the first day count
+    /// factor is the one from today to the first date, so there are as many
+    /// day count factors as dates. See [`Scheduler::generate_schedule_from_today`]
+    /// for a schedule that starts with today.
     ///
     /// # Arguments
     ///
@@ -59,6 +179,79 @@ pub trait Scheduler {
         date_rolling_convention: DateRollingConvention,
         day_counting_convention: DayCountConvention,
     ) -> Schedule;
+
+    /// Generate a schedule from a slice of `Date`s, with today as the
+    /// effective date and the first date of the returned schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `dates` - A slice of `Date`s (such as coupon payment dates).
+    /// * `date_rolling_convention` - The date rolling convention.
+    /// * `day_counting_convention` - The day counting convention.
+    fn generate_schedule_from_today(
+        &self,
+        dates: &[Date],
+        date_rolling_convention: DateRollingConvention,
+        day_counting_convention: DayCountConvention,
+    ) -> Schedule;
+
+    /// Generate a schedule from a slice of `Date`s, with the effective date
+    /// given by the trade date shifted forward by a spot lag.
+    /// The effective date is the first date of the returned schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_date` - The trade date of the instrument.
+    /// * `spot_lag` - Number of business days between the trade date and the effective date (e.g. 2 for T+2).
+    /// * `fixing_days` - Number of business days between each fixing date and the start of its period.
+    /// * `dates` - A slice of `Date`s (such as coupon payment dates).
+    /// * `date_rolling_convention` - The date rolling convention.
+    /// * `day_counting_convention` - The day counting convention.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if `dates` is empty, or the dates
+    ///   are not strictly increasing from the effective date.
+    fn generate_schedule_with_spot_lag(
+        &self,
+        trade_date: Date,
+        spot_lag: i64,
+        fixing_days: i64,
+        dates: &[Date],
+        date_rolling_convention: DateRollingConvention,
+        day_counting_convention: DayCountConvention,
+    ) -> Result<Schedule, RustQuantError>;
+
+    /// Generate a schedule from a slice of `Date`s, rolling the accrual dates
+    /// and the payment dates with separate conventions.
+    ///
+    /// Payment dates are the unadjusted dates rolled by `payment_convention`
+    /// and then shifted forward by `payment_lag` business days.
+    /// With identical conventions and a zero lag, this is the same as
+    /// [`Schedule::from_dates`].
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if `dates` is empty, or the dates
+    ///   are not strictly increasing from the effective date.
+    /// - `RustQuantError::NotConverged` if a payment date cannot be lagged by
+    ///   business days.
+    ///
+    /// # Arguments
+    ///
+    /// * `effective_date` - The start date of the first period.
+    /// * `dates` - A slice of `Date`s (such as coupon period end dates).
+    /// * `accrual_convention` - The date rolling convention of the accrual dates.
+    /// * `payment_convention` - The date rolling convention of the payment dates.
+    /// * `payment_lag` - Number of business days between the accrual end and the payment.
+    /// * `day_counting_convention` - The day counting convention.
+    fn generate_schedule_with_payment_conventions(
+        &self,
+        effective_date: Date,
+        dates: &[Date],
+        accrual_convention: DateRollingConvention,
+        payment_convention: DateRollingConvention,
+        payment_lag: i64,
+        day_counting_convention: DayCountConvention,
+    ) -> Result<Schedule, RustQuantError>;
 }
 
 impl<C> Scheduler for C
@@ -84,12 +277,86 @@ where
         );
 
         Schedule {
+            payment_dates: rolled_dates.clone(),
             dates: rolled_dates,
+            unadjusted_dates: dates.to_vec(),
             day_count_factors,
+            fixing_days: 0,
             day_counting_convention,
             date_rolling_convention,
         }
     }
+
+    fn generate_schedule_from_today(
+        &self,
+        dates: &[Date],
+        date_rolling_convention: DateRollingConvention,
+        day_counting_convention: DayCountConvention,
+    ) -> Schedule {
+        let unadjusted_dates = std::iter::once(crate::time::today())
+            .chain(dates.iter().copied())
+            .collect();
+
+        Schedule::from_unadjusted_dates(
+            unadjusted_dates,
+            self,
+            date_rolling_convention,
+            day_counting_convention,
+        )
+    }
+
+    fn generate_schedule_with_spot_lag(
+        &self,
+        trade_date: Date,
+        spot_lag: i64,
+        fixing_days: i64,
+        dates: &[Date],
+        date_rolling_convention: DateRollingConvention,
+        day_counting_convention: DayCountConvention,
+    ) -> Result<Schedule, RustQuantError> {
+        let (termination_date, dates) = dates.split_last().ok_or_else(|| {
+            RustQuantError::InvalidArgument("schedule dates must not be empty".to_string())
+        })?;
+
+        let mut schedule = Schedule::from_dates(
+            try_advance_business_days(trade_date, spot_lag, self)?,
+            *termination_date,
+            dates,
+            self,
+            date_rolling_convention,
+            day_counting_convention,
+        )?;
+        schedule.fixing_days = fixing_days;
+
+        Ok(schedule)
+    }
+
+    fn generate_schedule_with_payment_conventions(
+        &self,
+        effective_date: Date,
+        dates: &[Date],
+        accrual_convention: DateRollingConvention,
+        payment_convention: DateRollingConvention,
+        payment_lag: i64,
+        day_counting_convention: DayCountConvention,
+    ) -> Result<Schedule, RustQuantError> {
+        let (termination_date, dates) = dates.split_last().ok_or_else(|| {
+            RustQuantError::InvalidArgument("schedule dates must not be empty".to_string())
+        })?;
+
+        let mut schedule = Schedule::from_dates(
+            effective_date,
+            *termination_date,
+            dates,
+            self,
+            accrual_convention,
+            day_counting_convention,
+        )?;
+
+        schedule.set_payment_dates(self, payment_convention, payment_lag)?;
+
+        Ok(schedule)
+    }
 }
 
 impl fmt::Display for Schedule {
@@ -112,6 +379,649 @@ impl fmt::Display for Schedule {
 // IMPLEMENTATIONS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+impl Schedule {
+    /// Generate a regular schedule from the effective date to the
+    /// termination date at the given frequency.
+    ///
+    /// Dates are generated backwards from the termination date, so any
+    /// irregular (short) period is at the front. The dates of the schedule
+    /// include both the effective and termination dates.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the effective date is not
+    ///   before the termination date, or the frequency is semi-monthly or
+    ///   semi-quarterly (which are not a whole number of months or weeks).
+    pub fn regular<C: Calendar>(
+        effective_date: Date,
+        termination_date: Date,
+        frequency: Frequency,
+        calendar: &C,
+        date_rolling_convention: DateRollingConvention,
+        day_counting_convention: DayCountConvention,
+    ) -> Result<Self, RustQuantError> {
+        Ok(Self::from_unadjusted_dates(
+            Self::unadjusted_dates(
+                effective_date,
+                termination_date,
+                frequency,
+                StubPolicy::ShortFront,
+            )?,
+            calendar,
+            date_rolling_convention,
+            day_counting_convention,
+        ))
+    }
+
+    /// Generate a regular schedule from the effective date to the
+    /// termination date at the given frequency, adjusting the dates with a
+    /// business day adjustment policy.
+    ///
+    /// As for [`Schedule::regular`], the dates are generated backwards from
+    /// the termination date. With the end-of-month rule and a termination
+    /// date on the last day (or last business day) of its month, every
+    /// generated date is also moved to the last day of its month before
+    /// being adjusted.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` as for [`Schedule::regular`].
+    /// - `RustQuantError::NotConverged` if a date cannot be adjusted to a business day.
+    pub fn regular_with_adjustment<C: Calendar>(
+        effective_date: Date,
+        termination_date: Date,
+        frequency: Frequency,
+        adjustment: &BusinessDayAdjustment<C>,
+        day_counting_convention: DayCountConvention,
+    ) -> Result<Self, RustQuantError> {
+        let mut unadjusted_dates = Self::unadjusted_dates(
+            effective_date,
+            termination_date,
+            frequency,
+            StubPolicy::ShortFront,
+        )?;
+
+        if adjustment.end_of_month {
+            Self::move_to_month_ends(
+                &mut unadjusted_dates,
+                termination_date,
+                frequency,
+                &adjustment.calendar,
+            );
+        }
+
+        let dates = unadjusted_dates
+            .iter()
+            .map(|date| adjustment.try_adjust(*date))
+            .collect::<Result<Vec<Date>, RustQuantError>>()?;
+
+        Ok(Self::from_rolled_dates(
+            unadjusted_dates,
+            dates,
+            &adjustment.calendar,
+            adjustment.convention,
+            day_counting_convention,
+        ))
+    }
+
+    /// Start building a regular schedule (see [`ScheduleBuilder`]).
+    #[must_use]
+    pub fn builder<C: Calendar>(
+        effective_date: Date,
+        termination_date: Date,
+        frequency: Frequency,
+        calendar: &C,
+    ) -> ScheduleBuilder<'_, C> {
+        ScheduleBuilder::new(effective_date, termination_date, frequency, calendar)
+    }
+
+    /// Unadjusted dates of a regular schedule, generated backwards from the
+    /// termination date for front stubs and forwards from the effective
+    /// date for back stubs.
+    fn unadjusted_dates(
+        effective_date: Date,
+        termination_date: Date,
+        frequency: Frequency,
+        stub: StubPolicy,
+    ) -> Result<Vec<Date>, RustQuantError> {
+        if effective_date >= termination_date {
+            return Err(RustQuantError::InvalidArgument(
+                "effective date must be before the termination date".to_string(),
+            ));
+        }
+
+        let backwards = matches!(stub, StubPolicy::ShortFront | StubPolicy::LongFront);
+        let (anchor, sign) = if backwards {
+            (termination_date, -1)
+        } else {
+            (effective_date, 1)
+        };
+
+        let step = |k: i32| match frequency {
+            Frequency::Daily => Ok(anchor + Duration::days((sign * k).into())),
+            Frequency::Weekly => Ok(anchor + Duration::weeks((sign * k).into())),
+            Frequency::BiWeekly => Ok(anchor + Duration::weeks(2 * i64::from(sign * k))),
+            Frequency::SemiMonthly | Frequency::SemiQuarterly => {
+                Err(RustQuantError::InvalidArgument(format!(
+                    "unsupported frequency for a regular schedule: {frequency:?}"
+                )))
+            }
+            _ => Ok(add_months(
+                anchor,
+                sign * k * (12 / frequency.times_in_year() as i32),
+            )),
+        };
+
+        let mut dates = vec![anchor];
+        let mut regular = false;
+
+        for k in 1.. {
+            let date = step(k)?;
+
+            let beyond = if backwards {
+                date <= effective_date
+            } else {
+                date >= termination_date
+            };
+
+            if beyond {
+                regular = date == effective_date || date == termination_date;
+                break;
+            }
+
+            dates.push(date);
+        }
+
+        if backwards {
+            dates.push(effective_date);
+            dates.reverse();
+        } else {
+            dates.push(termination_date);
+        }
+
+        // A long stub merges the short stub into its neighbouring period.
+        let n = dates.len();
+
+        if !regular && n > 2 {
+            match stub {
+                StubPolicy::LongFront => {
+                    dates.remove(1);
+                }
+                StubPolicy::LongBack => {
+                    dates.remove(n - 2);
+                }
+                StubPolicy::ShortFront | StubPolicy::ShortBack => {}
+            }
+        }
+
+        Ok(dates)
+    }
+
+    /// Move the generated dates (between the effective and termination dates)
+    /// to the last day of their month, if the schedule is anchored on a
+    /// month end and the frequency is a whole number of months.
+    fn move_to_month_ends<C: Calendar>(
+        unadjusted_dates: &mut Vec<Date>,
+        anchor: Date,
+        frequency: Frequency,
+        calendar: &C,
+    ) {
+        let monthly = !matches!(
+            frequency,
+            Frequency::Daily | Frequency::Weekly | Frequency::BiWeekly
+        );
+        let month_end = is_last_day_of_month(anchor)
+            || last_business_day_of_month(anchor, calendar).is_ok_and(|last| last == anchor);
+
+        let n = unadjusted_dates.len();
+
+        if monthly && month_end && n > 2 {
+            for date in &mut unadjusted_dates[1..n - 1] {
+                *date = last_day_of_month(*date);
+            }
+
+            // A stub within the month collapses onto its month end.
+            unadjusted_dates.dedup();
+        }
+    }
+
+    /// Wrap an explicit list of dates in a schedule, e.g. for irregular or
+    /// amortizing structures.
+    ///
+    /// The `dates` are the dates strictly between the effective and termination
+    /// dates, and the schedule dates are the effective date, then `dates`,
+    /// then the termination date.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if `dates` are not strictly increasing,
+    ///   or are not strictly between the effective and termination dates.
+    pub fn from_dates<C: Calendar>(
+        effective_date: Date,
+        termination_date: Date,
+        dates: &[Date],
+        calendar: &C,
+        date_rolling_convention: DateRollingConvention,
+        day_counting_convention: DayCountConvention,
+    ) -> Result<Self, RustQuantError> {
+        let all_dates: Vec<Date> = std::iter::once(effective_date)
+            .chain(dates.iter().copied())
+            .chain(std::iter::once(termination_date))
+            .collect();
+
+        if !all_dates.windows(2).all(|w| w[0] < w[1]) {
+            return Err(RustQuantError::InvalidArgument(
+                "schedule dates must be strictly increasing and between the \
+                effective and termination dates"
+                    .to_string(),
+            ));
+        }
+
+        Ok(Self::from_unadjusted_dates(
+            all_dates,
+            calendar,
+            date_rolling_convention,
+            day_counting_convention,
+        ))
+    }
+
+    /// Roll the (sorted) unadjusted dates and compute the day count factors
+    /// between consecutive rolled dates.
+    fn from_unadjusted_dates<C: Calendar>(
+        unadjusted_dates: Vec<Date>,
+        calendar: &C,
+        date_rolling_convention: DateRollingConvention,
+        day_counting_convention: DayCountConvention,
+    ) -> Self {
+        let dates = calendar.roll_dates(&unadjusted_dates, &date_rolling_convention);
+
+        Self::from_rolled_dates(
+            unadjusted_dates,
+            dates,
+            calendar,
+            date_rolling_convention,
+            day_counting_convention,
+        )
+    }
+
+    /// Wrap the unadjusted dates and their rolled dates in a schedule,
+    /// computing the day count factors between consecutive rolled dates.
+    fn from_rolled_dates<C: Calendar>(
+        unadjusted_dates: Vec<Date>,
+        dates: Vec<Date>,
+        calendar: &C,
+        date_rolling_convention: DateRollingConvention,
+        day_counting_convention: DayCountConvention,
+    ) -> Self {
+        let day_count_factors = calendar.day_count_factors(&dates, &day_counting_convention);
+
+        Self {
+            payment_dates: dates.clone(),
+            dates,
+            unadjusted_dates,
+            day_count_factors,
+            fixing_days: 0,
+            day_counting_convention,
+            date_rolling_convention,
+        }
+    }
+
+    /// Roll the unadjusted dates of the schedule by the payment convention,
+    /// then shift them forward by `payment_lag` business days.
+    fn set_payment_dates<C: Calendar>(
+        &mut self,
+        calendar: &C,
+        payment_convention: DateRollingConvention,
+        payment_lag: i64,
+    ) -> Result<(), RustQuantError> {
+        self.payment_dates = calendar.roll_dates(&self.unadjusted_dates, &payment_convention);
+        self.lag_payment_dates(calendar, payment_lag)
+    }
+
+    /// Shift the payment dates forward by `payment_lag` business days.
+    fn lag_payment_dates<C: Calendar>(
+        &mut self,
+        calendar: &C,
+        payment_lag: i64,
+    ) -> Result<(), RustQuantError> {
+        // A zero lag would otherwise roll dates the payment convention left alone.
+        if payment_lag != 0 {
+            for date in &mut self.payment_dates {
+                *date = try_advance_business_days(*date, payment_lag, calendar)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the sub-schedule of the periods that overlap the window from
+    /// `start` to `end`, e.g. the remaining periods of a seasoned bond from a
+    /// valuation date.
+    ///
+    /// A period overlaps the window if it ends after `start` and starts
+    /// before `end` (on the adjusted dates). Periods are never cut: the
+    /// periods at the boundaries are kept whole, so a period in progress on
+    /// `start` and any stub periods keep their original dates.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if `start` is not before `end`,
+    ///   or no period overlaps the window.
+    pub fn truncate(&self, start: Date, end: Date) -> Result<Self, RustQuantError> {
+        if start >= end {
+            return Err(RustQuantError::InvalidArgument(
+                "the window start must be before its end".to_string(),
+            ));
+        }
+
+        let overlapping: Vec<usize> = (1..self.dates.len())
+            .filter(|i| self.dates[*i] > start && self.dates[i - 1] < end)
+            .collect();
+
+        let (Some(first), Some(last)) = (overlapping.first(), overlapping.last()) else {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "no period of the schedule overlaps {start} to {end}"
+            )));
+        };
+
+        // Dates first - 1 ..= last, and the factors of periods first ..= last.
+        let dates = first - 1..=*last;
+
+        Ok(Self {
+            dates: self.dates[dates.clone()].to_vec(),
+            unadjusted_dates: self.unadjusted_dates[dates.clone()].to_vec(),
+            payment_dates: self.payment_dates[dates].to_vec(),
+            day_count_factors: self.day_count_factors[first - 1..*last].to_vec(),
+            fixing_days: self.fixing_days,
+            day_counting_convention: self.day_counting_convention,
+            date_rolling_convention: self.date_rolling_convention,
+        })
+    }
+
+    /// Returns the coupon periods between consecutive dates of the schedule.
+    /// A schedule of `n` dates has `n - 1` periods.
+    #[must_use]
+    pub fn coupon_periods(&self) -> Vec<CouponPeriod> {
+        (1..self.dates.len())
+            .map(|i| CouponPeriod {
+                start: self.dates[i - 1],
+                end: self.dates[i],
+                unadjusted_start: self.unadjusted_dates[i - 1],
+                unadjusted_end: self.unadjusted_dates[i],
+                payment: self.payment_dates[i],
+            })
+            .collect()
+    }
+
+    /// Returns the fixing date of each coupon period of the schedule
+    /// (see [`Schedule::coupon_periods`]).
+    ///
+    /// Each fixing date is `fixing_lag` business days of the calendar before
+    /// the adjusted start of the period, or before its adjusted end when
+    /// fixed in arrears. A zero lag fixes on the period date itself.
+    #[must_use]
+    pub fn fixing_dates<C: Calendar>(
+        &self,
+        calendar: &C,
+        fixing_lag: i64,
+        timing: FixingTiming,
+    ) -> Vec<Date> {
+        self.coupon_periods()
+            .iter()
+            .map(|period| {
+                let date = match timing {
+                    FixingTiming::InAdvance => period.start,
+                    FixingTiming::InArrears => period.end,
+                };
+
+                advance_business_days(date, -fixing_lag, calendar)
+            })
+            .collect()
+    }
+
+    /// Returns the year fraction of each coupon period of the schedule
+    /// (see [`Schedule::coupon_periods`]) under the given day count convention.
+    ///
+    /// The Actual/Actual ICMA convention needs the regular coupon period that
+    /// each accrual period belongs to. The regular period length is taken to be
+    /// the most common period length in the schedule (preferring the last
+    /// period's length on ties, since stubs are usually at the front).
+    /// An irregular first period is split into notional regular periods
+    /// ending on its end date, and any other irregular period into notional
+    /// regular periods starting on its start date. Each part accrues against
+    /// its own notional period.
+    #[must_use]
+    pub fn accrual_schedule(&self, convention: DayCountConvention) -> Vec<f64> {
+        let periods = self.coupon_periods();
+
+        if convention != DayCountConvention::Actual_Actual_ICMA {
+            return periods
+                .iter()
+                .map(|period| convention.day_count_factor(period.start, period.end))
+                .collect();
+        }
+
+        let months = |period: &CouponPeriod| -> i32 {
+            let days = days_between(period.unadjusted_start, period.unadjusted_end) as f64;
+
+            (12.0 * days / 365.0).round() as i32
+        };
+
+        // Most common period length, preferring later periods on ties.
+        let lengths: Vec<i32> = periods.iter().map(months).collect();
+        let regular = lengths
+            .iter()
+            .rev()
+            .max_by_key(|m| lengths.iter().filter(|n| n == m).count())
+            .copied()
+            .unwrap_or(12);
+
+        periods
+            .iter()
+            .enumerate()
+            .map(|(i, period)| {
+                if months(period) == regular {
+                    return DayCountConvention::day_count_factor_actual_actual_icma_with_reference(
+                        period.start,
+                        period.end,
+                        period.unadjusted_start,
+                        period.unadjusted_end,
+                    );
+                }
+
+                // Notional period k, counted away from the anchor date.
+                let notional = |k: i32| -> (Date, Date) {
+                    if i == 0 {
+                        (
+                            add_months(period.unadjusted_end, -regular * (k + 1)),
+                            add_months(period.unadjusted_end, -regular * k),
+                        )
+                    } else {
+                        (
+                            add_months(period.unadjusted_start, regular * k),
+                            add_months(period.unadjusted_start, regular * (k + 1)),
+                        )
+                    }
+                };
+
+                let mut fraction = 0.0;
+
+                for k in 0.. {
+                    let (ref_start, ref_end) = notional(k);
+                    let (start, end) = (period.start.max(ref_start), period.end.min(ref_end));
+
+                    if start >= end {
+                        break;
+                    }
+
+                    fraction +=
+                        DayCountConvention::day_count_factor_actual_actual_icma_with_reference(
+                            start, end, ref_start, ref_end,
+                        );
+                }
+
+                fraction
+            })
+            .collect()
+    }
+}
+
+impl<'a, C: Calendar> ScheduleBuilder<'a, C> {
+    /// New schedule builder, with unadjusted dates, a short front stub, no
+    /// payment lag and the default day count convention.
+    #[must_use]
+    pub fn new(
+        effective_date: Date,
+        termination_date: Date,
+        frequency: Frequency,
+        calendar: &'a C,
+    ) -> Self {
+        Self {
+            effective_date,
+            termination_date,
+            frequency,
+            calendar,
+            date_rolling_convention: DateRollingConvention::Unadjusted,
+            payment_convention: None,
+            payment_lag: 0,
+            day_counting_convention: DayCountConvention::default(),
+            stub: StubPolicy::default(),
+            end_of_month: false,
+        }
+    }
+
+    /// Set the date rolling convention of the accrual dates.
+    #[must_use]
+    pub fn date_rolling_convention(mut self, convention: DateRollingConvention) -> Self {
+        self.date_rolling_convention = convention;
+        self
+    }
+
+    /// Set the date rolling convention of the payment dates.
+    #[must_use]
+    pub fn payment_convention(mut self, convention: DateRollingConvention) -> Self {
+        self.payment_convention = Some(convention);
+        self
+    }
+
+    /// Set the number of business days between each accrual end and its payment.
+    #[must_use]
+    pub fn payment_lag(mut self, payment_lag: i64) -> Self {
+        self.payment_lag = payment_lag;
+        self
+    }
+
+    /// Set the day counting convention of the schedule.
+    #[must_use]
+    pub fn day_counting_convention(mut self, convention: DayCountConvention) -> Self {
+        self.day_counting_convention = convention;
+        self
+    }
+
+    /// Set the stub policy of the schedule.
+    #[must_use]
+    pub fn stub(mut self, stub: StubPolicy) -> Self {
+        self.stub = stub;
+        self
+    }
+
+    /// Set the end-of-month flag: a schedule anchored on a month end keeps
+    /// rolling to month ends (see [`ScheduleBuilder`]).
+    #[must_use]
+    pub fn end_of_month(mut self, end_of_month: bool) -> Self {
+        self.end_of_month = end_of_month;
+        self
+    }
+
+    /// Generate the schedule.
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` as for [`Schedule::regular`].
+    /// - `RustQuantError::NotConverged` if a month end cannot be adjusted to a
+    ///   business day, or a payment date cannot be lagged by business days.
+    pub fn build(&self) -> Result<Schedule, RustQuantError> {
+        let mut unadjusted_dates = Schedule::unadjusted_dates(
+            self.effective_date,
+            self.termination_date,
+            self.frequency,
+            self.stub,
+        )?;
+
+        if self.end_of_month {
+            // The anchor is the date the schedule is generated from.
+            let anchor = match self.stub {
+                StubPolicy::ShortFront | StubPolicy::LongFront => self.termination_date,
+                StubPolicy::ShortBack | StubPolicy::LongBack => self.effective_date,
+            };
+
+            Schedule::move_to_month_ends(
+                &mut unadjusted_dates,
+                anchor,
+                self.frequency,
+                self.calendar,
+            );
+        }
+
+        let dates = self.roll_dates(&unadjusted_dates, self.date_rolling_convention)?;
+        let payment_dates = self.roll_dates(
+            &unadjusted_dates,
+            self.payment_convention
+                .unwrap_or(self.date_rolling_convention),
+        )?;
+
+        let mut schedule = Schedule::from_rolled_dates(
+            unadjusted_dates,
+            dates,
+            self.calendar,
+            self.date_rolling_convention,
+            self.day_counting_convention,
+        );
+
+        schedule.payment_dates = payment_dates;
+        schedule.lag_payment_dates(self.calendar, self.payment_lag)?;
+
+        Ok(schedule)
+    }
+
+    /// Roll the dates by the convention, rolling month ends to the last
+    /// business day of the month with the end-of-month flag.
+    fn roll_dates(
+        &self,
+        dates: &[Date],
+        convention: DateRollingConvention,
+    ) -> Result<Vec<Date>, RustQuantError> {
+        let adjusting = !matches!(
+            convention,
+            DateRollingConvention::Actual | DateRollingConvention::Unadjusted
+        );
+
+        dates
+            .iter()
+            .map(|&date| {
+                if self.end_of_month && adjusting && is_last_day_of_month(date) {
+                    previous_business_day(date, self.calendar)
+                } else {
+                    self.calendar.try_roll_date(date, &convention)
+                }
+            })
+            .collect()
+    }
+}
+
+impl IntoIterator for &Schedule {
+    type Item = CouponPeriod;
+    type IntoIter = std::vec::IntoIter<CouponPeriod>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.coupon_periods().into_iter()
+    }
+}
+
+impl IntoIterator for Schedule {
+    type Item = CouponPeriod;
+    type IntoIter = std::vec::IntoIter<CouponPeriod>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.coupon_periods().into_iter()
+    }
+}
+
 // impl Schedule {
 //     /// Create a new schedule from a vector of dates.
 //     ///
@@ -291,3 +1201,917 @@ impl fmt::Display for Schedule {
 //         );
 //     }
 // }
+
+#[cfg(test)]
+mod tests_schedule {
+    use super::*;
+    use crate::time::countries::north_america::united_states::UnitedStatesCalendar;
+    use time::macros::date;
+
+    #[test]
+    fn test_schedule_from_dates_and_from_today() {
+        let calendar = UnitedStatesCalendar;
+        let today = crate::time::today();
+        let dates = [
+            date!(2030 - 01 - 01),
+            date!(2030 - 07 - 01),
+            date!(2031 - 01 - 01),
+        ];
+        let rolled = [
+            date!(2030 - 01 - 02),
+            date!(2030 - 07 - 01),
+            date!(2031 - 01 - 02),
+        ];
+
+        // The dates only, with a first day count factor from today.
+        let schedule = calendar.generate_schedule_from_dates(
+            &dates,
+            DateRollingConvention::Following,
+            DayCountConvention::Actual_365_Fixed,
+        );
+
+        assert_eq!(schedule.dates, rolled);
+        assert_eq!(schedule.unadjusted_dates, dates);
+        assert_eq!(schedule.day_count_factors.len(), dates.len());
+        assert_approx_equal!(
+            schedule.day_count_factors[0],
+            (rolled[0] - today).whole_days() as f64 / 365.0,
+            1e-12
+        );
+
+        // Today, then the dates, with one day count factor per period.
+        let schedule = calendar.generate_schedule_from_today(
+            &dates,
+            DateRollingConvention::Following,
+            DayCountConvention::Actual_365_Fixed,
+        );
+
+        assert_eq!(schedule.unadjusted_dates[0], today);
+        assert_eq!(schedule.dates[1..], rolled);
+        assert_eq!(schedule.day_count_factors.len(), dates.len());
+    }
+
+    #[test]
+    fn test_schedule_with_spot_lag() {
+        let calendar = UnitedStatesCalendar;
+
+        // Friday trade date, T+2 spot is the following Tuesday.
+        let schedule = calendar
+            .generate_schedule_with_spot_lag(
+                date!(2024 - 01 - 05),
+                2,
+                2,
+                &[date!(2024 - 04 - 09), date!(2024 - 07 - 09)],
+                DateRollingConvention::ModifiedFollowing,
+                DayCountConvention::Actual_360,
+            )
+            .unwrap();
+
+        assert_eq!(schedule.dates[0], date!(2024 - 01 - 09));
+        assert_eq!(schedule.day_count_factors.len(), schedule.dates.len() - 1);
+        assert_approx_equal!(schedule.day_count_factors[0], 91.0 / 360.0, 1e-12);
+        assert_approx_equal!(schedule.day_count_factors[1], 91.0 / 360.0, 1e-12);
+
+        // The first period fixes two business days before the spot date,
+        // i.e. on the trade date.
+        assert_eq!(
+            schedule.fixing_dates(&calendar, schedule.fixing_days, FixingTiming::InAdvance)[0],
+            date!(2024 - 01 - 05)
+        );
+
+        // No coupon dates, or a coupon date before the spot date.
+        for dates in [vec![], vec![date!(2024 - 01 - 08)]] {
+            assert!(calendar
+                .generate_schedule_with_spot_lag(
+                    date!(2024 - 01 - 05),
+                    2,
+                    2,
+                    &dates,
+                    DateRollingConvention::ModifiedFollowing,
+                    DayCountConvention::Actual_360,
+                )
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_schedule_coupon_periods() {
+        let calendar = UnitedStatesCalendar;
+
+        // Quarterly dates, two of which fall on weekends / holidays.
+        let dates = [
+            date!(2024 - 04 - 15),
+            date!(2024 - 07 - 15),
+            date!(2024 - 09 - 15),
+            date!(2024 - 12 - 25),
+        ];
+
+        // T+2 from Thursday 11th January skips Martin Luther King Jr. Day.
+        let schedule = calendar
+            .generate_schedule_with_spot_lag(
+                date!(2024 - 01 - 11),
+                2,
+                0,
+                &dates,
+                DateRollingConvention::ModifiedFollowing,
+                DayCountConvention::Actual_360,
+            )
+            .unwrap();
+
+        let periods: Vec<CouponPeriod> = (&schedule).into_iter().collect();
+
+        assert_eq!(periods.len(), schedule.dates.len() - 1);
+        assert_eq!(periods[0].unadjusted_start, date!(2024 - 01 - 16));
+
+        for window in periods.windows(2) {
+            assert_eq!(window[0].end, window[1].start);
+            assert_eq!(window[0].unadjusted_end, window[1].unadjusted_start);
+        }
+
+        for period in &schedule {
+            assert!(calendar.is_business_day(period.payment));
+            assert!(period.start < period.end);
+        }
+
+        assert_eq!(periods[2].unadjusted_end, date!(2024 - 09 - 15));
+        assert_eq!(periods[2].end, date!(2024 - 09 - 16));
+        assert_eq!(periods[3].payment, date!(2024 - 12 - 26));
+    }
+
+    #[test]
+    fn test_separate_accrual_and_payment_conventions() {
+        let calendar = UnitedStatesCalendar;
+
+        // 30 March 2024 is a Saturday, and 31 August 2024 is a Saturday
+        // before the Labor Day holiday on 2 September.
+        let dates = [
+            date!(2024 - 03 - 30),
+            date!(2024 - 06 - 28),
+            date!(2024 - 08 - 31),
+        ];
+
+        let schedule = calendar
+            .generate_schedule_with_payment_conventions(
+                date!(2024 - 01 - 31),
+                &dates,
+                DateRollingConvention::ModifiedFollowing,
+                DateRollingConvention::Following,
+                2,
+                DayCountConvention::Actual_360,
+            )
+            .unwrap();
+
+        let periods = schedule.coupon_periods();
+
+        // Accrual stays in the month, payment rolls into the next and is lagged.
+        assert_eq!(periods[0].end, date!(2024 - 03 - 29));
+        assert_eq!(periods[0].payment, date!(2024 - 04 - 03));
+        assert_eq!(periods[2].end, date!(2024 - 08 - 30));
+        assert_eq!(periods[2].payment, date!(2024 - 09 - 05));
+
+        for period in &schedule {
+            assert!(period.payment > period.end);
+            assert!(calendar.is_business_day(period.payment));
+        }
+    }
+
+    #[test]
+    fn test_unadjusted_and_preceding_payment_conventions() {
+        let calendar = UnitedStatesCalendar;
+
+        let dates = [
+            date!(2024 - 03 - 30),
+            date!(2024 - 06 - 28),
+            date!(2024 - 08 - 31),
+        ];
+        let payment_dates = |convention, payment_lag| {
+            calendar
+                .generate_schedule_with_payment_conventions(
+                    date!(2024 - 01 - 31),
+                    &dates,
+                    DateRollingConvention::ModifiedFollowing,
+                    convention,
+                    payment_lag,
+                    DayCountConvention::Actual_360,
+                )
+                .unwrap()
+                .coupon_periods()
+                .iter()
+                .map(|period| period.payment)
+                .collect::<Vec<Date>>()
+        };
+
+        // Without a lag, Actual pays on the unadjusted (weekend) dates.
+        assert_eq!(payment_dates(DateRollingConvention::Actual, 0), dates);
+
+        assert_eq!(
+            payment_dates(DateRollingConvention::Preceding, 0),
+            vec![
+                date!(2024 - 03 - 29),
+                date!(2024 - 06 - 28),
+                date!(2024 - 08 - 30),
+            ]
+        );
+
+        // The lag counts from the rolled date, skipping Labor Day.
+        assert_eq!(
+            payment_dates(DateRollingConvention::Preceding, 1),
+            vec![
+                date!(2024 - 04 - 01),
+                date!(2024 - 07 - 01),
+                date!(2024 - 09 - 03),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_identical_conventions_match_single_convention() {
+        let calendar = UnitedStatesCalendar;
+
+        let dates = [
+            date!(2024 - 03 - 30),
+            date!(2024 - 06 - 28),
+            date!(2024 - 08 - 31),
+        ];
+
+        let single = Schedule::from_dates(
+            date!(2024 - 01 - 31),
+            date!(2024 - 08 - 31),
+            &dates[..2],
+            &calendar,
+            DateRollingConvention::ModifiedFollowing,
+            DayCountConvention::Actual_360,
+        )
+        .unwrap();
+        let split = calendar
+            .generate_schedule_with_payment_conventions(
+                date!(2024 - 01 - 31),
+                &dates,
+                DateRollingConvention::ModifiedFollowing,
+                DateRollingConvention::ModifiedFollowing,
+                0,
+                DayCountConvention::Actual_360,
+            )
+            .unwrap();
+
+        assert_eq!(single.dates, split.dates);
+        assert_eq!(single.payment_dates, split.payment_dates);
+        assert_eq!(single.day_count_factors, split.day_count_factors);
+        assert_eq!(single.coupon_periods(), split.coupon_periods());
+    }
+
+    #[test]
+    fn test_fixing_dates() {
+        let calendar = UnitedStatesCalendar;
+
+        let schedule = Schedule::regular(
+            date!(2024 - 03 - 04),
+            date!(2024 - 09 - 04),
+            Frequency::Quarterly,
+            &calendar,
+            DateRollingConvention::ModifiedFollowing,
+            DayCountConvention::Actual_360,
+        )
+        .unwrap();
+
+        // Two business days before Monday 4th March is Thursday 29th February.
+        assert_eq!(
+            schedule.fixing_dates(&calendar, 2, FixingTiming::InAdvance),
+            vec![date!(2024 - 02 - 29), date!(2024 - 05 - 31)]
+        );
+
+        // In arrears, fixed before the period end. The second fixing also
+        // skips Labor Day (2nd September 2024).
+        assert_eq!(
+            schedule.fixing_dates(&calendar, 2, FixingTiming::InArrears),
+            vec![date!(2024 - 05 - 31), date!(2024 - 08 - 30)]
+        );
+
+        assert_eq!(
+            schedule.fixing_dates(&calendar, 0, FixingTiming::InArrears),
+            vec![date!(2024 - 06 - 04), date!(2024 - 09 - 04)]
+        );
+    }
+
+    #[test]
+    fn test_accrual_schedule_regular_annual() {
+        let calendar = UnitedStatesCalendar;
+
+        let schedule = Schedule::from_dates(
+            date!(2020 - 06 - 15),
+            date!(2024 - 06 - 15),
+            &[
+                date!(2021 - 06 - 15),
+                date!(2022 - 06 - 15),
+                date!(2023 - 06 - 15),
+            ],
+            &calendar,
+            DateRollingConvention::Actual,
+            DayCountConvention::Actual_Actual_ICMA,
+        )
+        .unwrap();
+
+        let icma = schedule.accrual_schedule(DayCountConvention::Actual_Actual_ICMA);
+        let act_365 = schedule.accrual_schedule(DayCountConvention::Actual_365_Fixed);
+
+        assert_eq!(icma.len(), 4);
+
+        for fraction in &icma {
+            assert_approx_equal!(*fraction, 1.0, 1e-12);
+        }
+        for fraction in &act_365 {
+            assert!((fraction - 1.0).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_accrual_schedule_icma_stubs() {
+        let calendar = UnitedStatesCalendar;
+
+        // Semi-annual, with a short first stub (1 Feb to 1 Jul, 150 days in a
+        // 181 day reference period) and a long last stub of nine months.
+        let schedule = Schedule::from_dates(
+            date!(1999 - 02 - 01),
+            date!(2001 - 04 - 01),
+            &[
+                date!(1999 - 07 - 01),
+                date!(2000 - 01 - 01),
+                date!(2000 - 07 - 01),
+            ],
+            &calendar,
+            DateRollingConvention::Actual,
+            DayCountConvention::Actual_Actual_ICMA,
+        )
+        .unwrap();
+
+        let fractions = schedule.accrual_schedule(DayCountConvention::Actual_Actual_ICMA);
+
+        assert_approx_equal!(fractions[0], 0.5 * 150.0 / 181.0, 1e-12);
+        assert_approx_equal!(fractions[1], 0.5, 1e-12);
+        assert_approx_equal!(fractions[2], 0.5, 1e-12);
+
+        // One full period, then 1 Jan to 1 Apr (90 days) of a 181 day period.
+        assert_approx_equal!(fractions[3], 0.5 + 0.5 * 90.0 / 181.0, 1e-12);
+    }
+
+    #[test]
+    fn test_accrual_schedule_icma_long_first_stub() {
+        let calendar = UnitedStatesCalendar;
+
+        // Semi-annual, with a sixteen month first stub from 1 March 1998.
+        let schedule = Schedule::from_dates(
+            date!(1998 - 03 - 01),
+            date!(2001 - 01 - 01),
+            &[
+                date!(1999 - 07 - 01),
+                date!(2000 - 01 - 01),
+                date!(2000 - 07 - 01),
+            ],
+            &calendar,
+            DateRollingConvention::Actual,
+            DayCountConvention::Actual_Actual_ICMA,
+        )
+        .unwrap();
+
+        let fractions = schedule.accrual_schedule(DayCountConvention::Actual_Actual_ICMA);
+
+        // Two full notional periods back to 1 July 1998, then 1 March to
+        // 1 July 1998 (122 days) of the 181 day notional period before.
+        assert_approx_equal!(fractions[0], 1.0 + 0.5 * 122.0 / 181.0, 1e-12);
+
+        for fraction in &fractions[1..] {
+            assert_approx_equal!(*fraction, 0.5, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_regular_schedule_matches_enumerated_dates() {
+        let calendar = UnitedStatesCalendar;
+
+        let schedule = Schedule::regular(
+            date!(2024 - 01 - 15),
+            date!(2025 - 01 - 15),
+            Frequency::Quarterly,
+            &calendar,
+            DateRollingConvention::ModifiedFollowing,
+            DayCountConvention::Actual_360,
+        )
+        .unwrap();
+
+        assert_eq!(
+            schedule.unadjusted_dates,
+            vec![
+                date!(2024 - 01 - 15),
+                date!(2024 - 04 - 15),
+                date!(2024 - 07 - 15),
+                date!(2024 - 10 - 15),
+                date!(2025 - 01 - 15),
+            ]
+        );
+        assert_eq!(schedule.day_count_factors.len(), 4);
+
+        // Martin Luther King Jr. Day (15th January 2024) rolls to the 16th.
+        assert_eq!(schedule.dates[0], date!(2024 - 01 - 16));
+        assert_approx_equal!(schedule.day_count_factors[0], 90.0 / 360.0, 1e-12);
+
+        // Generated backwards, so the stub is at the front.
+        let schedule = Schedule::regular(
+            date!(2024 - 03 - 01),
+            date!(2025 - 01 - 15),
+            Frequency::SemiAnnually,
+            &calendar,
+            DateRollingConvention::Unadjusted,
+            DayCountConvention::Actual_360,
+        )
+        .unwrap();
+
+        assert_eq!(
+            schedule.dates,
+            vec![
+                date!(2024 - 03 - 01),
+                date!(2024 - 07 - 15),
+                date!(2025 - 01 - 15),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_regular_schedule_with_adjustment() {
+        let adjustment = BusinessDayAdjustment::new(
+            UnitedStatesCalendar,
+            DateRollingConvention::Following,
+            true,
+        );
+
+        // Quarterly from the end of February 2024 keeps to month ends,
+        // adjusted to the last business day of each month.
+        let schedule = Schedule::regular_with_adjustment(
+            date!(2024 - 02 - 29),
+            date!(2025 - 02 - 28),
+            Frequency::Quarterly,
+            &adjustment,
+            DayCountConvention::Actual_360,
+        )
+        .unwrap();
+
+        assert_eq!(
+            schedule.unadjusted_dates,
+            vec![
+                date!(2024 - 02 - 29),
+                date!(2024 - 05 - 31),
+                date!(2024 - 08 - 31),
+                date!(2024 - 11 - 30),
+                date!(2025 - 02 - 28),
+            ]
+        );
+        assert_eq!(
+            schedule.dates,
+            vec![
+                date!(2024 - 02 - 29),
+                date!(2024 - 05 - 31),
+                date!(2024 - 08 - 30),
+                date!(2024 - 11 - 29),
+                date!(2025 - 02 - 28),
+            ]
+        );
+        assert_eq!(schedule.payment_dates, schedule.dates);
+        assert_approx_equal!(schedule.day_count_factors[1], 91.0 / 360.0, 1e-12);
+
+        // Without the end-of-month rule, the dates step back from the 28th.
+        let schedule = Schedule::regular_with_adjustment(
+            date!(2024 - 02 - 29),
+            date!(2025 - 02 - 28),
+            Frequency::Quarterly,
+            &BusinessDayAdjustment::new(
+                UnitedStatesCalendar,
+                DateRollingConvention::Following,
+                false,
+            ),
+            DayCountConvention::Actual_360,
+        )
+        .unwrap();
+
+        assert_eq!(schedule.unadjusted_dates[1], date!(2024 - 05 - 28));
+        assert_eq!(schedule.dates[2], date!(2024 - 08 - 28));
+    }
+
+    #[test]
+    fn test_truncate_schedule() {
+        let calendar = UnitedStatesCalendar;
+
+        let schedule = Schedule::regular(
+            date!(2015 - 06 - 15),
+            date!(2025 - 06 - 15),
+            Frequency::SemiAnnually,
+            &calendar,
+            DateRollingConvention::ModifiedFollowing,
+            DayCountConvention::Actual_360,
+        )
+        .unwrap();
+
+        // The last two years of a ten year schedule.
+        let remaining = schedule
+            .truncate(date!(2023 - 06 - 15), date!(2025 - 06 - 15))
+            .unwrap();
+
+        assert_eq!(
+            remaining.unadjusted_dates,
+            vec![
+                date!(2023 - 06 - 15),
+                date!(2023 - 12 - 15),
+                date!(2024 - 06 - 15),
+                date!(2024 - 12 - 15),
+                date!(2025 - 06 - 15),
+            ]
+        );
+        assert_eq!(remaining.dates, schedule.dates[16..]);
+        assert_eq!(
+            remaining.day_count_factors,
+            schedule.day_count_factors[16..]
+        );
+        assert_eq!(remaining.coupon_periods(), schedule.coupon_periods()[16..]);
+
+        // Valuing mid-period keeps the current period whole.
+        let seasoned = schedule
+            .truncate(date!(2024 - 03 - 01), date!(2025 - 06 - 15))
+            .unwrap();
+        let current = seasoned.coupon_periods()[0];
+
+        assert_eq!(current.unadjusted_start, date!(2023 - 12 - 15));
+        assert_eq!(current.unadjusted_end, date!(2024 - 06 - 15));
+        assert_eq!(seasoned.coupon_periods().len(), 3);
+
+        // No overlap, or an empty window.
+        assert!(schedule
+            .truncate(date!(2025 - 07 - 01), date!(2026 - 01 - 01))
+            .is_err());
+        assert!(schedule
+            .truncate(date!(2020 - 01 - 01), date!(2020 - 01 - 01))
+            .is_err());
+    }
+
+    #[test]
+    fn test_truncate_schedule_keeps_stubs() {
+        let calendar = UnitedStatesCalendar;
+
+        // Generated backwards, with a short front stub from 2nd March 2015.
+        let schedule = Schedule::regular(
+            date!(2015 - 03 - 02),
+            date!(2018 - 06 - 15),
+            Frequency::SemiAnnually,
+            &calendar,
+            DateRollingConvention::ModifiedFollowing,
+            DayCountConvention::Actual_360,
+        )
+        .unwrap();
+
+        let truncated = schedule
+            .truncate(date!(2015 - 04 - 01), date!(2016 - 01 - 01))
+            .unwrap();
+
+        assert_eq!(
+            truncated.unadjusted_dates,
+            vec![
+                date!(2015 - 03 - 02),
+                date!(2015 - 06 - 15),
+                date!(2015 - 12 - 15),
+                date!(2016 - 06 - 15),
+            ]
+        );
+        assert_eq!(truncated.day_count_factors, schedule.day_count_factors[..3]);
+    }
+
+    #[test]
+    fn test_from_dates_schedule_round_trips() {
+        let calendar = UnitedStatesCalendar;
+        let dates = [
+            date!(2024 - 02 - 20),
+            date!(2024 - 06 - 03),
+            date!(2024 - 09 - 30),
+        ];
+
+        let schedule = Schedule::from_dates(
+            date!(2024 - 01 - 05),
+            date!(2025 - 01 - 06),
+            &dates,
+            &calendar,
+            DateRollingConvention::Following,
+            DayCountConvention::Actual_365_Fixed,
+        )
+        .unwrap();
+
+        assert_eq!(schedule.unadjusted_dates.len(), dates.len() + 2);
+        assert_eq!(schedule.unadjusted_dates[1..=dates.len()], dates);
+        assert_eq!(schedule.coupon_periods().len(), dates.len() + 1);
+
+        // Unsorted, or out of bounds.
+        for dates in [
+            vec![date!(2024 - 06 - 03), date!(2024 - 02 - 20)],
+            vec![date!(2023 - 12 - 29)],
+            vec![date!(2025 - 01 - 06)],
+        ] {
+            assert!(Schedule::from_dates(
+                date!(2024 - 01 - 05),
+                date!(2025 - 01 - 06),
+                &dates,
+                &calendar,
+                DateRollingConvention::Following,
+                DayCountConvention::Actual_365_Fixed,
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn test_schedule_builder_stub_policies() {
+        let calendar = UnitedStatesCalendar;
+
+        let dates = |stub| {
+            Schedule::builder(
+                date!(2024 - 03 - 15),
+                date!(2026 - 01 - 15),
+                Frequency::SemiAnnually,
+                &calendar,
+            )
+            .stub(stub)
+            .build()
+            .unwrap()
+            .dates
+        };
+
+        assert_eq!(
+            dates(StubPolicy::ShortFront),
+            vec![
+                date!(2024 - 03 - 15),
+                date!(2024 - 07 - 15),
+                date!(2025 - 01 - 15),
+                date!(2025 - 07 - 15),
+                date!(2026 - 01 - 15),
+            ]
+        );
+        assert_eq!(
+            dates(StubPolicy::LongFront),
+            vec![
+                date!(2024 - 03 - 15),
+                date!(2025 - 01 - 15),
+                date!(2025 - 07 - 15),
+                date!(2026 - 01 - 15),
+            ]
+        );
+        assert_eq!(
+            dates(StubPolicy::ShortBack),
+            vec![
+                date!(2024 - 03 - 15),
+                date!(2024 - 09 - 15),
+                date!(2025 - 03 - 15),
+                date!(2025 - 09 - 15),
+                date!(2026 - 01 - 15),
+            ]
+        );
+        assert_eq!(
+            dates(StubPolicy::LongBack),
+            vec![
+                date!(2024 - 03 - 15),
+                date!(2024 - 09 - 15),
+                date!(2025 - 03 - 15),
+                date!(2026 - 01 - 15),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_schedule_builder_regular_schedule_has_no_stub() {
+        let calendar = UnitedStatesCalendar;
+
+        let schedules: Vec<Schedule> = [
+            StubPolicy::ShortFront,
+            StubPolicy::LongFront,
+            StubPolicy::ShortBack,
+            StubPolicy::LongBack,
+        ]
+        .into_iter()
+        .map(|stub| {
+            Schedule::builder(
+                date!(2024 - 01 - 15),
+                date!(2025 - 01 - 15),
+                Frequency::Quarterly,
+                &calendar,
+            )
+            .date_rolling_convention(DateRollingConvention::ModifiedFollowing)
+            .day_counting_convention(DayCountConvention::Actual_360)
+            .stub(stub)
+            .build()
+            .unwrap()
+        })
+        .collect();
+
+        let regular = Schedule::regular(
+            date!(2024 - 01 - 15),
+            date!(2025 - 01 - 15),
+            Frequency::Quarterly,
+            &calendar,
+            DateRollingConvention::ModifiedFollowing,
+            DayCountConvention::Actual_360,
+        )
+        .unwrap();
+
+        for schedule in &schedules {
+            assert_eq!(schedule.unadjusted_dates, regular.unadjusted_dates);
+            assert_eq!(schedule.dates, regular.dates);
+            assert_eq!(schedule.payment_dates, regular.payment_dates);
+            assert_eq!(schedule.day_count_factors, regular.day_count_factors);
+        }
+    }
+
+    #[test]
+    fn test_schedule_builder_payment_dates() {
+        let calendar = UnitedStatesCalendar;
+
+        let schedule = Schedule::builder(
+            date!(2024 - 03 - 15),
+            date!(2026 - 01 - 15),
+            Frequency::SemiAnnually,
+            &calendar,
+        )
+        .date_rolling_convention(DateRollingConvention::ModifiedFollowing)
+        .payment_lag(2)
+        .stub(StubPolicy::ShortBack)
+        .build()
+        .unwrap();
+
+        // Sunday 15th September 2024 accrues to the Monday, paid two days later.
+        let period = schedule.coupon_periods()[0];
+
+        assert_eq!(period.unadjusted_end, date!(2024 - 09 - 15));
+        assert_eq!(period.end, date!(2024 - 09 - 16));
+        assert_eq!(period.payment, date!(2024 - 09 - 18));
+
+        for period in &schedule {
+            assert!(calendar.is_business_day(period.end));
+            assert!(calendar.is_business_day(period.payment));
+        }
+
+        assert!(Schedule::builder(
+            date!(2026 - 01 - 15),
+            date!(2024 - 03 - 15),
+            Frequency::SemiAnnually,
+            &calendar,
+        )
+        .build()
+        .is_err());
+
+        // Without business days, the payment lag fails instead of panicking.
+        let no_business_days = crate::time::CustomCalendar::new(
+            "No Business Days",
+            crate::iso::UNITED_STATES_OF_AMERICA,
+            crate::iso::XNYS,
+        )
+        .with_weekend(&[
+            time::Weekday::Monday,
+            time::Weekday::Tuesday,
+            time::Weekday::Wednesday,
+            time::Weekday::Thursday,
+            time::Weekday::Friday,
+            time::Weekday::Saturday,
+            time::Weekday::Sunday,
+        ]);
+
+        assert!(matches!(
+            Schedule::builder(
+                date!(2024 - 03 - 15),
+                date!(2026 - 01 - 15),
+                Frequency::SemiAnnually,
+                &no_business_days,
+            )
+            .date_rolling_convention(DateRollingConvention::Unadjusted)
+            .payment_lag(2)
+            .build(),
+            Err(RustQuantError::NotConverged(_))
+        ));
+    }
+
+    #[test]
+    fn test_schedule_builder_end_of_month() {
+        let calendar = UnitedStatesCalendar;
+
+        let builder = |end_of_month| {
+            Schedule::builder(
+                date!(2024 - 05 - 31),
+                date!(2025 - 05 - 30),
+                Frequency::Quarterly,
+                &calendar,
+            )
+            .date_rolling_convention(DateRollingConvention::Following)
+            .end_of_month(end_of_month)
+        };
+
+        // Friday 30th May 2025 is the last business day of May, so the
+        // dates keep to month ends, rolled back to the last business day.
+        let schedule = builder(true).build().unwrap();
+
+        assert_eq!(
+            schedule.unadjusted_dates,
+            vec![
+                date!(2024 - 05 - 31),
+                date!(2024 - 08 - 31),
+                date!(2024 - 11 - 30),
+                date!(2025 - 02 - 28),
+                date!(2025 - 05 - 30),
+            ]
+        );
+        assert_eq!(
+            schedule.dates,
+            vec![
+                date!(2024 - 05 - 31),
+                date!(2024 - 08 - 30),
+                date!(2024 - 11 - 29),
+                date!(2025 - 02 - 28),
+                date!(2025 - 05 - 30),
+            ]
+        );
+        assert_eq!(schedule.payment_dates, schedule.dates);
+
+        // Without the flag, the dates drift off the month ends.
+        let schedule = builder(false).build().unwrap();
+
+        assert_eq!(schedule.unadjusted_dates[1], date!(2024 - 08 - 30));
+        assert_eq!(schedule.dates[2], date!(2024 - 12 - 02));
+
+        // Generated forwards from the last business day of June 2024, the
+        // short last period collapses onto the termination month end.
+        let schedule = Schedule::builder(
+            date!(2024 - 06 - 28),
+            date!(2025 - 06 - 30),
+            Frequency::Quarterly,
+            &calendar,
+        )
+        .date_rolling_convention(DateRollingConvention::ModifiedFollowing)
+        .stub(StubPolicy::ShortBack)
+        .end_of_month(true)
+        .build()
+        .unwrap();
+
+        assert_eq!(
+            schedule.dates,
+            vec![
+                date!(2024 - 06 - 28),
+                date!(2024 - 09 - 30),
+                date!(2024 - 12 - 31),
+                date!(2025 - 03 - 31),
+                date!(2025 - 06 - 30),
+            ]
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests_schedule_serde {
+    use super::*;
+    use crate::time::countries::north_america::united_states::UnitedStatesCalendar;
+    use time::macros::date;
+
+    #[test]
+    fn test_schedule_serde_round_trip() {
+        let schedule = Schedule::builder(
+            date!(2024 - 03 - 15),
+            date!(2026 - 01 - 15),
+            Frequency::SemiAnnually,
+            &UnitedStatesCalendar,
+        )
+        .date_rolling_convention(DateRollingConvention::ModifiedFollowing)
+        .day_counting_convention(DayCountConvention::Actual_360)
+        .payment_lag(2)
+        .build()
+        .unwrap();
+
+        let json = serde_json::to_string(&schedule).unwrap();
+        let parsed: Schedule = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.dates, schedule.dates);
+        assert_eq!(parsed.unadjusted_dates, schedule.unadjusted_dates);
+        assert_eq!(parsed.payment_dates, schedule.payment_dates);
+        assert_eq!(parsed.day_count_factors, schedule.day_count_factors);
+        assert_eq!(
+            parsed.day_counting_convention,
+            DayCountConvention::Actual_360
+        );
+        assert_eq!(
+            parsed.date_rolling_convention,
+            DateRollingConvention::ModifiedFollowing
+        );
+
+        // Conventions serialize by name, for configuration files.
+        assert_eq!(
+            serde_json::to_string(&DateRollingConvention::ModifiedFollowing).unwrap(),
+            r#""ModifiedFollowing""#
+        );
+        assert_eq!(
+            serde_json::from_str::<DayCountConvention>(r#""Thirty_E_360""#).unwrap(),
+            DayCountConvention::Thirty_E_360
+        );
+        assert_eq!(
+            serde_json::from_str::<StubPolicy>(r#""LongFront""#).unwrap(),
+            StubPolicy::LongFront
+        );
+    }
+}
@@ -0,0 +1,360 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Settlement (spot) dates from trade dates.
+//!
+//! Each currency settles on the business days of a calendar, after a spot lag
+//! of a number of business days (e.g. T+2). Currency pairs settle on the
+//! business days of both calendars, with their own spot lag where the market
+//! differs from the default (e.g. T+1 for USD/CAD).
+
+use crate::error::RustQuantError;
+use crate::instruments::currency::Currency;
+use crate::time::utilities::try_advance_business_days;
+use crate::time::{calendar_from_code, Calendar};
+use std::collections::HashMap;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Settlement date calculator, mapping currencies and currency pairs to their
+/// settlement calendars and spot lags.
+///
+/// ```ignore
+/// let settlement = SettlementCalculator::market_standard();
+///
+/// // T+2 on the US and German calendars.
+/// let spot = settlement.fx_spot_date(trade_date, &EUR, &USD)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct SettlementCalculator {
+    /// Calendar codes (see [`calendar_from_code`]), keyed by the
+    /// ISO 4217 alphabetic code of the currency, e.g. "USD" => "US".
+    calendars: HashMap<String, String>,
+
+    /// Spot lags of single currencies, e.g. for rates instruments.
+    spot_lags: HashMap<String, i64>,
+
+    /// Spot lags of currency pairs, keyed by e.g. "USD/CAD" (in either order).
+    pair_spot_lags: HashMap<String, i64>,
+
+    /// Spot lag of currencies and pairs without their own.
+    default_spot_lag: i64,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Default for SettlementCalculator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SettlementCalculator {
+    /// New settlement calculator without any currency, and a default spot lag of two days.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            calendars: HashMap::new(),
+            spot_lags: HashMap::new(),
+            pair_spot_lags: HashMap::new(),
+            default_spot_lag: 2,
+        }
+    }
+
+    /// New settlement calculator with the calendars of the currencies with a
+    /// country calendar, T+2 spot, T+0 for GBP and T+1 for USD/CAD.
+    ///
+    /// EUR settles on the German calendar.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a calendar code is unknown (which would be a bug).
+    #[must_use]
+    pub fn market_standard() -> Self {
+        let mut settlement = Self::new();
+
+        for (currency, code) in [
+            (crate::iso::ARS, "AR"),
+            (crate::iso::AUD, "AU"),
+            (crate::iso::BRL, "BR"),
+            (crate::iso::BWP, "BW"),
+            (crate::iso::CAD, "CA"),
+            (crate::iso::CLP, "CL"),
+            (crate::iso::CNY, "CN"),
+            (crate::iso::CZK, "CZ"),
+            (crate::iso::DKK, "DK"),
+            (crate::iso::EUR, "DE"),
+            (crate::iso::GBP, "GB"),
+            (crate::iso::HKD, "HK"),
+            (crate::iso::HUF, "HU"),
+            (crate::iso::IDR, "ID"),
+            (crate::iso::ILS, "IL"),
+            (crate::iso::INR, "IN"),
+            (crate::iso::ISK, "IS"),
+            (crate::iso::KRW, "KR"),
+            (crate::iso::MYR, "MY"),
+            (crate::iso::NZD, "NZ"),
+            (crate::iso::SAR, "SA"),
+            (crate::iso::SGD, "SG"),
+            (crate::iso::THB, "TH"),
+            (crate::iso::TWD, "TW"),
+            (crate::iso::USD, "US"),
+        ] {
+            settlement.set_calendar(&currency, code).unwrap();
+        }
+
+        settlement.set_spot_lag(&crate::iso::GBP, 0);
+        settlement.set_pair_spot_lag(&crate::iso::USD, &crate::iso::CAD, 1);
+
+        settlement
+    }
+
+    /// Set the calendar of a currency from its code (see [`calendar_from_code`]),
+    /// e.g. "US" or a joint calendar "US+GB".
+    ///
+    /// # Errors
+    /// - `RustQuantError::InvalidArgument` if the calendar code is unknown.
+    pub fn set_calendar(&mut self, currency: &Currency, code: &str) -> Result<(), RustQuantError> {
+        if calendar_from_code(code).is_none() {
+            return Err(RustQuantError::InvalidArgument(format!(
+                "unknown calendar: '{code}'"
+            )));
+        }
+
+        self.calendars
+            .insert(currency.code.alphabetic.to_string(), code.to_string());
+
+        Ok(())
+    }
+
+    /// Set the spot lag (in business days) of a currency.
+    pub fn set_spot_lag(&mut self, currency: &Currency, spot_lag: i64) {
+        self.spot_lags
+            .insert(currency.code.alphabetic.to_string(), spot_lag);
+    }
+
+    /// Set the spot lag (in business days) of a currency pair, in either order.
+    pub fn set_pair_spot_lag(&mut self, base: &Currency, quote: &Currency, spot_lag: i64) {
+        self.pair_spot_lags.insert(pair_key(base, quote), spot_lag);
+    }
+
+    /// Set the spot lag of currencies and pairs without their own.
+    pub fn set_default_spot_lag(&mut self, spot_lag: i64) {
+        self.default_spot_lag = spot_lag;
+    }
+
+    /// Get the spot lag of a currency.
+    #[must_use]
+    pub fn spot_lag(&self, currency: &Currency) -> i64 {
+        self.spot_lags
+            .get(currency.code.alphabetic)
+            .copied()
+            .unwrap_or(self.default_spot_lag)
+    }
+
+    /// Get the spot lag of a currency pair.
+    #[must_use]
+    pub fn pair_spot_lag(&self, base: &Currency, quote: &Currency) -> i64 {
+        self.pair_spot_lags
+            .get(&pair_key(base, quote))
+            .copied()
+            .unwrap_or(self.default_spot_lag)
+    }
+
+    /// Get the settlement calendar of a currency.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if the currency has no calendar.
+    pub fn calendar(&self, currency: &Currency) -> Result<Box<dyn Calendar>, RustQuantError> {
+        calendar_from_code(self.calendar_code(currency)?).ok_or_else(|| {
+            RustQuantError::ComputationError(format!(
+                "no calendar for {}",
+                currency.code.alphabetic
+            ))
+        })
+    }
+
+    /// Get the settlement calendar of a currency pair, a business day only
+    /// if it is one for both currencies.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a currency has no calendar.
+    pub fn pair_calendar(
+        &self,
+        base: &Currency,
+        quote: &Currency,
+    ) -> Result<Box<dyn Calendar>, RustQuantError> {
+        let code = format!(
+            "{}+{}",
+            self.calendar_code(base)?,
+            self.calendar_code(quote)?
+        );
+
+        calendar_from_code(&code)
+            .ok_or_else(|| RustQuantError::ComputationError(format!("no calendar for '{code}'")))
+    }
+
+    /// Get the spot date of a currency from a trade date, by its spot lag.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if the currency has no calendar.
+    /// - `RustQuantError::NotConverged` if no business day can be found.
+    pub fn spot_date(&self, trade_date: Date, currency: &Currency) -> Result<Date, RustQuantError> {
+        self.settlement_date(trade_date, currency, self.spot_lag(currency))
+    }
+
+    /// Get the settlement date of a currency from a trade date, by the given
+    /// number of business days (zero rolls a holiday to the next business day).
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if the currency has no calendar.
+    /// - `RustQuantError::NotConverged` if no business day can be found.
+    pub fn settlement_date(
+        &self,
+        trade_date: Date,
+        currency: &Currency,
+        settlement_lag: i64,
+    ) -> Result<Date, RustQuantError> {
+        let calendar = self.calendar(currency)?;
+
+        try_advance_business_days(trade_date, settlement_lag, calendar.as_ref())
+    }
+
+    /// Get the spot date of a currency pair from a trade date, by the spot lag
+    /// of the pair on the business days of both calendars.
+    ///
+    /// # Errors
+    /// - `RustQuantError::MissingInput` if a currency has no calendar.
+    /// - `RustQuantError::NotConverged` if no business day can be found.
+    pub fn fx_spot_date(
+        &self,
+        trade_date: Date,
+        base: &Currency,
+        quote: &Currency,
+    ) -> Result<Date, RustQuantError> {
+        let calendar = self.pair_calendar(base, quote)?;
+
+        try_advance_business_days(
+            trade_date,
+            self.pair_spot_lag(base, quote),
+            calendar.as_ref(),
+        )
+    }
+
+    /// Calendar code of a currency.
+    fn calendar_code(&self, currency: &Currency) -> Result<&str, RustQuantError> {
+        self.calendars
+            .get(currency.code.alphabetic)
+            .map(String::as_str)
+            .ok_or_else(|| {
+                RustQuantError::MissingInput(format!(
+                    "no settlement calendar for {}",
+                    currency.code.alphabetic
+                ))
+            })
+    }
+}
+
+/// Key of a currency pair, the same in either order.
+fn pair_key(base: &Currency, quote: &Currency) -> String {
+    let (first, second) = if base.code.alphabetic <= quote.code.alphabetic {
+        (base, quote)
+    } else {
+        (quote, base)
+    };
+
+    format!("{}/{}", first.code.alphabetic, second.code.alphabetic)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_settlement {
+    use super::*;
+    use crate::iso::{CAD, CHF, EUR, GBP, USD};
+    use time::macros::date;
+
+    #[test]
+    fn test_fx_spot_date() {
+        let settlement = SettlementCalculator::market_standard();
+
+        // Wednesday 3rd July 2024: T+2 skips Independence Day.
+        assert_eq!(
+            settlement
+                .fx_spot_date(date!(2024 - 07 - 03), &EUR, &USD)
+                .unwrap(),
+            date!(2024 - 07 - 08)
+        );
+
+        // Friday 28th June 2024: USD/CAD is T+1 and skips Canada Day.
+        assert_eq!(settlement.pair_spot_lag(&CAD, &USD), 1);
+        assert_eq!(
+            settlement
+                .fx_spot_date(date!(2024 - 06 - 28), &USD, &CAD)
+                .unwrap(),
+            date!(2024 - 07 - 02)
+        );
+
+        // GBP/USD uses the pair default, not the GBP spot lag.
+        assert_eq!(settlement.pair_spot_lag(&GBP, &USD), 2);
+    }
+
+    #[test]
+    fn test_spot_date() {
+        let mut settlement = SettlementCalculator::market_standard();
+
+        // Thursday 4th July 2024 is a US holiday.
+        assert_eq!(
+            settlement.spot_date(date!(2024 - 07 - 03), &USD).unwrap(),
+            date!(2024 - 07 - 08)
+        );
+        assert_eq!(
+            settlement.spot_date(date!(2024 - 07 - 03), &EUR).unwrap(),
+            date!(2024 - 07 - 05)
+        );
+
+        // GBP is T+0, rolled off the weekend.
+        assert_eq!(
+            settlement.spot_date(date!(2024 - 07 - 06), &GBP).unwrap(),
+            date!(2024 - 07 - 08)
+        );
+
+        settlement.set_spot_lag(&USD, 1);
+        assert_eq!(
+            settlement.spot_date(date!(2024 - 07 - 03), &USD).unwrap(),
+            date!(2024 - 07 - 05)
+        );
+    }
+
+    #[test]
+    fn test_missing_calendar() {
+        let mut settlement = SettlementCalculator::new();
+
+        assert!(matches!(
+            settlement.spot_date(date!(2024 - 07 - 03), &CHF),
+            Err(RustQuantError::MissingInput(_))
+        ));
+        assert!(matches!(
+            settlement.set_calendar(&CHF, "XX"),
+            Err(RustQuantError::InvalidArgument(_))
+        ));
+
+        settlement.set_calendar(&CHF, "DE").unwrap();
+        assert!(settlement
+            .fx_spot_date(date!(2024 - 07 - 03), &CHF, &USD)
+            .is_err());
+    }
+}
@@ -0,0 +1,183 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2022-2024 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! An event-driven simulation clock, to drive multi-period simulations and
+//! backtests over the dates of a business calendar.
+
+use crate::time::Calendar;
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Observer called by a [`SimulationClock`] at each date it visits.
+pub type ClockObserver<'a> = Box<dyn FnMut(Date) + 'a>;
+
+/// A clock that steps through a schedule of dates in chronological order,
+/// calling every registered observer at each date.
+///
+/// The clock is also an [`Iterator`] over its remaining dates, so it can be
+/// driven with a `for` loop as well as with [`SimulationClock::run`].
+pub struct SimulationClock<'a> {
+    /// The dates of the clock, in ascending order.
+    dates: Vec<Date>,
+
+    /// Index of the next date to visit.
+    position: usize,
+
+    /// Observers, called in the order they were registered.
+    observers: Vec<ClockObserver<'a>>,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<'a> SimulationClock<'a> {
+    /// Clock over an explicit set of dates.
+    /// The dates are sorted and duplicates are removed.
+    #[must_use]
+    pub fn from_dates(mut dates: Vec<Date>) -> Self {
+        dates.sort();
+        dates.dedup();
+
+        Self {
+            dates,
+            position: 0,
+            observers: Vec::new(),
+        }
+    }
+
+    /// Clock over the business days of a calendar between two dates (inclusive).
+    #[must_use]
+    pub fn business_days<C: Calendar + ?Sized>(start: Date, end: Date, calendar: &C) -> Self {
+        Self::from_dates(calendar.all_business_days_between(start, end))
+    }
+
+    /// Register an observer, called with each date the clock visits.
+    pub fn register<F: FnMut(Date) + 'a>(&mut self, observer: F) {
+        self.observers.push(Box::new(observer));
+    }
+
+    /// All the dates of the clock.
+    #[must_use]
+    pub fn dates(&self) -> &[Date] {
+        &self.dates
+    }
+
+    /// The most recently visited date, if the clock has started.
+    #[must_use]
+    pub fn current_date(&self) -> Option<Date> {
+        self.position.checked_sub(1).map(|i| self.dates[i])
+    }
+
+    /// Whether every date has been visited.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.dates.len()
+    }
+
+    /// Advance to the next date, calling the observers.
+    /// Returns `None` once every date has been visited.
+    pub fn advance(&mut self) -> Option<Date> {
+        let date = *self.dates.get(self.position)?;
+        self.position += 1;
+
+        for observer in &mut self.observers {
+            observer(date);
+        }
+
+        Some(date)
+    }
+
+    /// Advance through all the remaining dates.
+    pub fn run(&mut self) {
+        while self.advance().is_some() {}
+    }
+
+    /// Restart the clock from its first date (observers are kept).
+    pub fn reset(&mut self) {
+        self.position = 0;
+    }
+}
+
+impl Iterator for SimulationClock<'_> {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.advance()
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_simulation_clock {
+    use super::*;
+    use crate::time::countries::north_america::united_states::UnitedStatesCalendar;
+    use time::macros::date;
+
+    #[test]
+    fn test_clock_visits_business_days() {
+        let calendar = UnitedStatesCalendar;
+
+        // Friday 12th to Friday 19th January 2024.
+        // The weekend and Martin Luther King Jr. Day (15th) are skipped.
+        let clock =
+            SimulationClock::business_days(date!(2024 - 01 - 12), date!(2024 - 01 - 19), &calendar);
+
+        assert_eq!(
+            clock.collect::<Vec<Date>>(),
+            vec![
+                date!(2024 - 01 - 12),
+                date!(2024 - 01 - 16),
+                date!(2024 - 01 - 17),
+                date!(2024 - 01 - 18),
+                date!(2024 - 01 - 19),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clock_observers_in_order() {
+        let mut first = Vec::new();
+        let mut second = Vec::new();
+
+        let mut clock = SimulationClock::from_dates(vec![
+            date!(2024 - 03 - 01),
+            date!(2024 - 01 - 01),
+            date!(2024 - 02 - 01),
+            date!(2024 - 01 - 01),
+        ]);
+
+        assert_eq!(clock.current_date(), None);
+
+        clock.register(|d| first.push(d));
+        clock.register(|d| second.push(d));
+        clock.run();
+
+        assert!(clock.is_finished());
+        assert_eq!(clock.current_date(), Some(date!(2024 - 03 - 01)));
+        assert_eq!(clock.advance(), None);
+
+        drop(clock);
+
+        let expected = vec![
+            date!(2024 - 01 - 01),
+            date!(2024 - 02 - 01),
+            date!(2024 - 03 - 01),
+        ];
+
+        assert_eq!(first, expected);
+        assert_eq!(second, expected);
+    }
+}
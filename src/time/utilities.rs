@@ -9,7 +9,10 @@
 
 //! This module defines general calendar and holiday related functions.
 
-use crate::time::{calendar::Calendar, constants::EASTER_MONDAYS};
+use crate::error::RustQuantError;
+use crate::time::{
+    calendar::Calendar, holiday_rules::easter_sunday, DateRollingConvention, Period,
+};
 use time::{
     util::{days_in_year, days_in_year_month, is_leap_year},
     Date, Duration, Error, Month, Weekday,
@@ -35,14 +38,14 @@ pub fn unpack_date(date: Date, is_orthodox: bool) -> (i32, Month, u8, Weekday, u
     let wd = date.weekday();
     let yd = date.ordinal();
 
-    let em = easter_monday(y as usize, is_orthodox);
+    let em = easter_monday(y, is_orthodox);
 
     (y, m, d, wd, yd, em)
 }
 
-/// Returns the Easter Monday for the given year.
-fn easter_monday(year: usize, is_orthodox: bool) -> u16 {
-    EASTER_MONDAYS[usize::from(is_orthodox)][year - 1901]
+/// Returns the Easter Monday (day of the year) for the given year.
+fn easter_monday(year: i32, is_orthodox: bool) -> u16 {
+    easter_sunday(year, is_orthodox).map_or(0, |easter| easter.ordinal() + 1)
 }
 
 /// Checks if date is a weekend.
@@ -139,26 +142,199 @@ pub fn is_last_day_of_february(date: Date) -> bool {
     last_day_of_feb_non_leap || last_day_of_feb_leap
 }
 
+/// Function to check if date is the last day of its month.
+pub fn is_last_day_of_month(date: Date) -> bool {
+    date.day() == days_in_year_month(date.year(), date.month())
+}
+
+/// Function to get the last day of the month of a date.
+pub fn last_day_of_month(date: Date) -> Date {
+    date.replace_day(days_in_year_month(date.year(), date.month()))
+        .unwrap()
+}
+
+/// Maximum number of days searched for a business day, so that a
+/// pathological calendar (e.g. every day a holiday) cannot loop forever.
+pub const MAX_BUSINESS_DAY_SEARCH: i64 = 400;
+
 /// Function to get the next business day for a given date and calendar.
-pub fn next_business_day<C: Calendar>(date: Date, calendar: &C) -> Date {
+///
+/// # Errors
+/// - `RustQuantError::NotConverged` if there is no business day within
+///   [`MAX_BUSINESS_DAY_SEARCH`] days.
+pub fn next_business_day<C: Calendar + ?Sized>(
+    date: Date,
+    calendar: &C,
+) -> Result<Date, RustQuantError> {
+    search_business_day(date, calendar, Duration::days(1))
+}
+
+/// Function to get the previous business day for a given date and calendar.
+///
+/// # Errors
+/// - `RustQuantError::NotConverged` if there is no business day within
+///   [`MAX_BUSINESS_DAY_SEARCH`] days.
+pub fn previous_business_day<C: Calendar + ?Sized>(
+    date: Date,
+    calendar: &C,
+) -> Result<Date, RustQuantError> {
+    search_business_day(date, calendar, Duration::days(-1))
+}
+
+/// Step from `date` until a business day is found, up to the search limit.
+fn search_business_day<C: Calendar + ?Sized>(
+    date: Date,
+    calendar: &C,
+    step: Duration,
+) -> Result<Date, RustQuantError> {
     let mut new_date = date;
 
-    while !calendar.is_business_day(new_date) {
-        new_date = new_date.next_day().unwrap();
+    for _ in 0..=MAX_BUSINESS_DAY_SEARCH {
+        if calendar.is_business_day(new_date) {
+            return Ok(new_date);
+        }
+
+        new_date += step;
     }
 
-    new_date
+    Err(RustQuantError::NotConverged(format!(
+        "no business day within {MAX_BUSINESS_DAY_SEARCH} days of {date} in the {} calendar",
+        calendar.name()
+    )))
 }
 
-/// Function to get the previous business day for a given date and calendar.
-pub fn previous_business_day<C: Calendar>(date: Date, calendar: &C) -> Date {
+/// Function to add a (possibly negative) number of months to a date.
+/// The day of month is clamped to the last day of the resulting month,
+/// e.g. 31 January plus one month is 28/29 February.
+pub fn add_months(date: Date, months: i32) -> Date {
+    let total = date.year() * 12 + (date.month() as i32 - 1) + months;
+    let year = total.div_euclid(12);
+    let month = Month::try_from((total.rem_euclid(12) + 1) as u8).unwrap();
+    let day = date.day().min(days_in_year_month(year, month));
+
+    Date::from_calendar_date(year, month, day).unwrap()
+}
+
+/// Function to move a date by a number of business days for a given calendar.
+/// A positive number of days moves forward, a negative number moves backward.
+/// If `days` is zero, the date is rolled forward to the next business day.
+///
+/// # Panics
+///
+/// Panics if the calendar has no business days
+/// (see [`MAX_BUSINESS_DAY_SEARCH`]).
+pub fn advance_business_days<C: Calendar + ?Sized>(date: Date, days: i64, calendar: &C) -> Date {
+    match try_advance_business_days(date, days, calendar) {
+        Ok(new_date) => new_date,
+        Err(error) => panic!("{error}"),
+    }
+}
+
+/// Move a date by a number of business days, bounding each search for the
+/// next business day.
+pub(crate) fn try_advance_business_days<C: Calendar + ?Sized>(
+    date: Date,
+    days: i64,
+    calendar: &C,
+) -> Result<Date, RustQuantError> {
+    if days == 0 {
+        return next_business_day(date, calendar);
+    }
+
+    let step = if days > 0 {
+        Duration::days(1)
+    } else {
+        Duration::days(-1)
+    };
+
     let mut new_date = date;
 
-    while !calendar.is_business_day(new_date) {
-        new_date = new_date.previous_day().unwrap();
+    for _ in 0..days.abs() {
+        new_date = search_business_day(new_date + step, calendar, step)?;
     }
 
-    new_date
+    Ok(new_date)
+}
+
+/// Function to compute the fraction of a year between two dates, measured in
+/// business days rather than calendar days, for products that only accrue
+/// over business time.
+///
+/// The period `[start, end)` is split by calendar year, and the business days
+/// in each part are divided by the number of business days in that year, so a
+/// full calendar year is exactly one. A reversed period gives a negative fraction.
+/// Years without any business day (e.g. on a calendar of holidays only)
+/// contribute nothing.
+pub fn business_time_fraction<C: Calendar + ?Sized>(start: Date, end: Date, calendar: &C) -> f64 {
+    if end < start {
+        return -business_time_fraction(end, start, calendar);
+    }
+
+    // Business days in the half-open interval [from, to).
+    let business_days = |from: Date, to: Date| match to.previous_day() {
+        Some(last) if from <= last => calendar.all_business_days_between(from, last).len() as f64,
+        _ => 0.0,
+    };
+
+    (start.year()..=end.year())
+        .map(|year| {
+            let year_start = Date::from_calendar_date(year, Month::January, 1).unwrap();
+            let year_end = Date::from_calendar_date(year + 1, Month::January, 1).unwrap();
+
+            let from = start.max(year_start);
+            let to = end.min(year_end);
+
+            // A year without business days carries no business time.
+            let days_in_year = business_days(year_start, year_end);
+
+            if from < to && days_in_year > 0.0 {
+                business_days(from, to) / days_in_year
+            } else {
+                0.0
+            }
+        })
+        .sum()
+}
+
+/// Function to compute the end date of a market tenor (e.g. "3M") from a
+/// trade date, following the usual market rule:
+///
+/// 1. Move from the `reference` date to the spot date by `spot_lag` business days.
+/// 2. Add the tenor (e.g. "ON", "1W", "3M" or "10Y", see [`Period`]) to the spot date.
+/// 3. Roll the result with the given business day convention.
+///
+/// If `end_of_month` is set and the spot date is the last business day of its
+/// month, month and year tenors instead end on the last business day of the
+/// resulting month.
+///
+/// # Errors
+/// - `RustQuantError::InvalidArgument` if the tenor cannot be parsed.
+/// - `RustQuantError::NotConverged` if no business day can be found.
+pub fn tenor_to_date<C: Calendar>(
+    reference: Date,
+    spot_lag: i64,
+    tenor: &str,
+    calendar: &C,
+    convention: DateRollingConvention,
+    end_of_month: bool,
+) -> Result<Date, RustQuantError> {
+    let period: Period = tenor.parse()?;
+
+    let spot = try_advance_business_days(reference, spot_lag, calendar)?;
+
+    calendar.try_advance(spot, period, convention, end_of_month)
+}
+
+/// Function to get the last business day of the month of a date.
+///
+/// # Errors
+/// - `RustQuantError::NotConverged` if there is no business day within
+///   [`MAX_BUSINESS_DAY_SEARCH`] days of the end of the month.
+pub fn last_business_day_of_month<C: Calendar + ?Sized>(
+    date: Date,
+    calendar: &C,
+) -> Result<Date, RustQuantError> {
+    previous_business_day(last_day_of_month(date), calendar)
 }
 
 /// Function to generate a sequence of dates from a start date, end date.
@@ -516,4 +692,251 @@ mod test_utilities {
             Date::from_calendar_date(2024, Month::December, 2).unwrap()
         );
     }
+
+    #[test]
+    fn test_add_months() {
+        let jan_31 = Date::from_calendar_date(2024, Month::January, 31).unwrap();
+
+        assert_eq!(
+            add_months(jan_31, 1),
+            Date::from_calendar_date(2024, Month::February, 29).unwrap()
+        );
+        assert_eq!(
+            add_months(jan_31, 12),
+            Date::from_calendar_date(2025, Month::January, 31).unwrap()
+        );
+        assert_eq!(
+            add_months(jan_31, -2),
+            Date::from_calendar_date(2023, Month::November, 30).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_advance_business_days() {
+        let calendar = crate::time::countries::north_america::united_states::UnitedStatesCalendar;
+
+        // Friday 5th January 2024.
+        let friday = Date::from_calendar_date(2024, Month::January, 5).unwrap();
+
+        assert_eq!(
+            advance_business_days(friday, 2, &calendar),
+            Date::from_calendar_date(2024, Month::January, 9).unwrap()
+        );
+        assert_eq!(
+            advance_business_days(friday, -1, &calendar),
+            Date::from_calendar_date(2024, Month::January, 4).unwrap()
+        );
+
+        // Saturday rolls forward to Monday with no lag.
+        assert_eq!(
+            advance_business_days(friday + Duration::days(1), 0, &calendar),
+            Date::from_calendar_date(2024, Month::January, 8).unwrap()
+        );
+    }
+
+    /// A calendar on which every day is a holiday.
+    struct AllHolidayCalendar;
+
+    impl Calendar for AllHolidayCalendar {
+        fn name(&self) -> &'static str {
+            "All Holidays"
+        }
+
+        fn is_holiday(&self, _date: Date) -> bool {
+            true
+        }
+
+        fn country_code(&self) -> crate::iso::ISO_3166 {
+            crate::iso::UNITED_STATES_OF_AMERICA
+        }
+
+        fn market_identifier_code(&self) -> crate::iso::ISO_10383 {
+            crate::iso::XNYS
+        }
+    }
+
+    #[test]
+    fn test_business_day_search_is_bounded() {
+        use crate::time::{DateRoller, DateRollingConvention};
+
+        let date = Date::from_calendar_date(2024, Month::January, 5).unwrap();
+
+        assert!(matches!(
+            next_business_day(date, &AllHolidayCalendar),
+            Err(RustQuantError::NotConverged(_))
+        ));
+        assert!(matches!(
+            previous_business_day(date, &AllHolidayCalendar),
+            Err(RustQuantError::NotConverged(_))
+        ));
+
+        for convention in [
+            DateRollingConvention::Following,
+            DateRollingConvention::ModifiedFollowing,
+            DateRollingConvention::Preceding,
+            DateRollingConvention::ModifiedPreceding,
+            DateRollingConvention::ModifiedRolling,
+            DateRollingConvention::HalfMonthModifiedFollowing,
+            DateRollingConvention::Nearest,
+            DateRollingConvention::EndOfMonth,
+        ] {
+            assert!(matches!(
+                AllHolidayCalendar.try_roll_date(date, &convention),
+                Err(RustQuantError::NotConverged(_))
+            ));
+        }
+
+        assert!(tenor_to_date(
+            date,
+            2,
+            "1M",
+            &AllHolidayCalendar,
+            DateRollingConvention::ModifiedFollowing,
+            false
+        )
+        .is_err());
+
+        // Normal calendars are unaffected.
+        let calendar = crate::time::countries::north_america::united_states::UnitedStatesCalendar;
+        let saturday = Date::from_calendar_date(2024, Month::January, 6).unwrap();
+
+        assert_eq!(
+            next_business_day(saturday, &calendar).unwrap(),
+            Date::from_calendar_date(2024, Month::January, 8).unwrap()
+        );
+        assert_eq!(previous_business_day(saturday, &calendar).unwrap(), date);
+        assert_eq!(
+            calendar
+                .try_roll_date(saturday, &DateRollingConvention::ModifiedRolling)
+                .unwrap(),
+            calendar.roll_date(saturday, &DateRollingConvention::ModifiedRolling)
+        );
+    }
+
+    #[test]
+    fn test_business_time_fraction() {
+        let calendar = crate::time::countries::north_america::united_states::UnitedStatesCalendar;
+        let date = |y, m, d| Date::from_calendar_date(y, m, d).unwrap();
+
+        // Saturday 31st August to Tuesday 3rd September 2024 spans only the
+        // Labor Day long weekend.
+        assert_eq!(
+            business_time_fraction(
+                date(2024, Month::August, 31),
+                date(2024, Month::September, 3),
+                &calendar
+            ),
+            0.0
+        );
+
+        // A full calendar year is exactly one.
+        assert_approx_equal!(
+            business_time_fraction(
+                date(2023, Month::January, 1),
+                date(2024, Month::January, 1),
+                &calendar
+            ),
+            1.0,
+            1e-12
+        );
+
+        // A year straddling two calendar years is close to one.
+        let fraction = business_time_fraction(
+            date(2023, Month::July, 1),
+            date(2024, Month::July, 1),
+            &calendar,
+        );
+
+        assert!((fraction - 1.0).abs() < 0.01);
+        assert_approx_equal!(
+            business_time_fraction(
+                date(2024, Month::July, 1),
+                date(2023, Month::July, 1),
+                &calendar
+            ),
+            -fraction,
+            1e-12
+        );
+
+        // No business days at all gives a zero fraction, not NaN.
+        assert_eq!(
+            business_time_fraction(
+                date(2023, Month::July, 1),
+                date(2025, Month::July, 1),
+                &AllHolidayCalendar
+            ),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_tenor_to_date() {
+        let calendar = crate::time::countries::north_america::united_states::UnitedStatesCalendar;
+        let date = |y, m, d| Date::from_calendar_date(y, m, d).unwrap();
+
+        // T+2 spot is Thursday 29th February 2024, the last business day of the month.
+        let trade_date = date(2024, Month::February, 27);
+
+        assert_eq!(
+            tenor_to_date(
+                trade_date,
+                2,
+                "3M",
+                &calendar,
+                DateRollingConvention::ModifiedFollowing,
+                true
+            )
+            .unwrap(),
+            date(2024, Month::May, 31)
+        );
+        assert_eq!(
+            tenor_to_date(
+                trade_date,
+                2,
+                "3M",
+                &calendar,
+                DateRollingConvention::ModifiedFollowing,
+                false
+            )
+            .unwrap(),
+            date(2024, Month::May, 29)
+        );
+
+        // Spot is 4th April 2024, so 3M lands on Independence Day.
+        assert_eq!(
+            tenor_to_date(
+                date(2024, Month::April, 2),
+                2,
+                "3m",
+                &calendar,
+                DateRollingConvention::Following,
+                true
+            )
+            .unwrap(),
+            date(2024, Month::July, 5)
+        );
+
+        assert_eq!(
+            tenor_to_date(
+                date(2024, Month::April, 2),
+                2,
+                "1W",
+                &calendar,
+                DateRollingConvention::Following,
+                true
+            )
+            .unwrap(),
+            date(2024, Month::April, 11)
+        );
+
+        assert!(tenor_to_date(
+            trade_date,
+            2,
+            "3X",
+            &calendar,
+            DateRollingConvention::Following,
+            true
+        )
+        .is_err());
+    }
 }